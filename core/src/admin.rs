@@ -0,0 +1,196 @@
+use crate::{
+    db::{DbError, RepositoryManager, TypedRepositoryManager},
+    model::{
+        access_level::AccessLevel,
+        account::{self, ACCOUNT_ACCESS_REPOSITORY},
+    },
+};
+use axum::{
+    Json,
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION},
+    response::{IntoResponse, Response},
+    routing::MethodRouter,
+};
+use bevy::{log, prelude::*};
+use bevy_defer::{AccessError, AsyncAccess, AsyncWorld};
+use bevy_webgate::WebServerAppExt;
+use sea_orm::{DbErr, prelude::Uuid};
+use serde::Serialize;
+use thiserror::Error;
+
+/// REST API for the server operators, served on the same port as the metrics endpoint.
+/// Every request must carry `Authorization: Bearer <token>`,
+/// all requests are refused while the [`AdminApiToken`] is empty.
+pub struct AdminApiPlugin {
+    port: u16,
+}
+
+impl AdminApiPlugin {
+    pub fn new(port: u16) -> Self {
+        Self { port }
+    }
+}
+
+impl Plugin for AdminApiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AdminApiToken>()
+            .insert_resource(AdminApiPort(self.port));
+    }
+}
+
+#[derive(Clone, Copy, Resource)]
+struct AdminApiPort(u16);
+
+#[derive(Clone, Default, Resource)]
+pub struct AdminApiToken(String);
+
+impl AdminApiToken {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+
+    fn matches(&self, headers: &HeaderMap) -> bool {
+        let Some(provided) = headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+        else {
+            return false;
+        };
+        !self.0.is_empty()
+            && provided.len() == self.0.len()
+            && provided
+                .bytes()
+                .zip(self.0.bytes())
+                .fold(0, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+}
+
+pub trait AdminApiAppExt {
+    /// Adds a route under `/admin`.
+    ///
+    /// # Panics
+    ///
+    /// If the [`AdminApiPlugin`] wasn't added before, the route would never be served.
+    fn admin_route(&mut self, path: &str, method_router: MethodRouter) -> &mut Self;
+}
+
+impl AdminApiAppExt for App {
+    fn admin_route(&mut self, path: &str, method_router: MethodRouter) -> &mut Self {
+        let Some(AdminApiPort(port)) = self.world().get_resource::<AdminApiPort>().copied() else {
+            panic!("Admin route /admin{path} is added before the AdminApiPlugin");
+        };
+        self.port_route(port, &format!("/admin{path}"), method_router);
+        self
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum AdminApiError {
+    #[error("Missing or invalid admin token")]
+    Unauthorized,
+    #[error("{0}")]
+    NotFound(String),
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    Internal(String),
+}
+
+impl From<AccessError> for AdminApiError {
+    fn from(err: AccessError) -> Self {
+        Self::Internal(err.to_string())
+    }
+}
+
+impl From<DbErr> for AdminApiError {
+    fn from(err: DbErr) -> Self {
+        Self::Internal(err.to_string())
+    }
+}
+
+impl From<DbError> for AdminApiError {
+    fn from(err: DbError) -> Self {
+        Self::Internal(err.to_string())
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl IntoResponse for AdminApiError {
+    fn into_response(self) -> Response {
+        let status = match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        let body = ErrorBody {
+            error: self.to_string(),
+        };
+        (status, Json(body)).into_response()
+    }
+}
+
+pub type AdminApiResult<T> = Result<Json<T>, AdminApiError>;
+
+/// Checks the token of the request, every action is logged whether it's allowed or not.
+pub fn authorize(headers: &HeaderMap, action: impl std::fmt::Display) -> Result<(), AdminApiError> {
+    let authorized = AsyncWorld
+        .resource::<AdminApiToken>()
+        .get(|token| token.matches(headers))?;
+    if !authorized {
+        log::warn!("Admin API: refused {}", action);
+        return Err(AdminApiError::Unauthorized);
+    }
+    log::info!("Admin API: {}", action);
+    Ok(())
+}
+
+/// Bans and unbans go through the [`account::AccountAccessRepository`] on both servers,
+/// the login server refuses banned accounts on their next login.
+pub async fn set_account_access_level(
+    name: &str,
+    access_level: AccessLevel,
+) -> Result<(), AdminApiError> {
+    let repository = AsyncWorld
+        .resource::<RepositoryManager>()
+        .get(|repo_manager| {
+            repo_manager.typed_by_name::<Uuid, account::Entity>(ACCOUNT_ACCESS_REPOSITORY)
+        })?
+        .map_err(|err| AdminApiError::Internal(err.to_string()))?;
+    if !account::set_access_level(&repository, name, access_level).await? {
+        return Err(AdminApiError::NotFound(format!("Account {name} not found")));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(authorization: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, authorization.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_token_matches_bearer_only() {
+        let token = AdminApiToken::new("secret");
+        assert!(token.matches(&headers("Bearer secret")));
+        assert!(!token.matches(&headers("Bearer secret2")));
+        assert!(!token.matches(&headers("Basic secret")));
+        assert!(!token.matches(&HeaderMap::new()));
+    }
+
+    #[test]
+    fn test_empty_token_refuses_everything() {
+        let token = AdminApiToken::default();
+        assert!(!token.matches(&headers("Bearer ")));
+    }
+}
@@ -7,6 +7,15 @@ use redis::{Client, Connection, ConnectionLike, RedisResult};
 use std::time::Duration;
 use strum::{AsRefStr, Display};
 
+/// The logins closed mark expires after this time unless the game server refreshes it,
+/// a server that died in the middle of a shutdown doesn't stay closed.
+pub const LOGINS_CLOSED_TTL: Duration = Duration::from_secs(30);
+
+/// Set by the game server while it refuses new logins, e.g. in the last minutes before a shutdown.
+pub fn logins_closed_key(server_id: u8) -> String {
+    format!("gameserver:{server_id}:logins_closed")
+}
+
 pub struct RedisPlugin(RedisConfig);
 
 impl RedisPlugin {
@@ -3,9 +3,10 @@ use crate::utils::AllocatedReflectExt;
 use bevy::{platform::collections::HashMap, prelude::*};
 use bevy_mod_scripting::{bindings::InteropError, prelude::ScriptValue};
 use sea_orm::{
-    ColumnTrait, ConnectionTrait, DatabaseConnection, DbBackend, DeleteMany, DeleteResult,
-    EntityTrait, InsertResult, IntoActiveModel, PaginatorTrait, PrimaryKeyTrait, QueryFilter,
-    QuerySelect, Statement, TransactionTrait, UpdateMany, UpdateResult,
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DatabaseTransaction, DbBackend, DbErr,
+    DeleteMany, DeleteResult, EntityTrait, InsertResult, IntoActiveModel, PaginatorTrait,
+    PrimaryKeyTrait, QueryFilter, QuerySelect, Statement, TransactionTrait, UpdateMany,
+    UpdateResult,
     prelude::async_trait::async_trait,
     sea_query::{IntoCondition, OnConflict},
 };
@@ -223,6 +224,11 @@ where
         self.conn.get_database_backend()
     }
 
+    /// Starts a transaction on the repository connection.
+    pub async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        self.conn.begin().await
+    }
+
     /// Creates a Statement from raw SQL string using the repository's database backend.
     ///
     /// # Parameters
@@ -1,3 +1,4 @@
+pub mod admin;
 pub mod crypt;
 pub mod metrics;
 pub mod model;
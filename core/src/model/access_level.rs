@@ -25,12 +25,13 @@ use strum::{Display, EnumIter};
 )]
 #[repr(i16)]
 pub enum AccessLevel {
-    //FIXME: make everybody admins for testing #[default]
+    /// Account refused by the login server
+    Banned = -1,
+    #[default]
     Player,
     SupportGM,
     EventGM,
     HeadGM,
-    #[default]
     Admin,
 }
 
@@ -48,6 +49,10 @@ impl AccessLevel {
     pub fn admin(&self) -> bool {
         matches!(self, AccessLevel::Admin)
     }
+
+    pub fn banned(&self) -> bool {
+        matches!(self, AccessLevel::Banned)
+    }
 }
 
 impl From<AccessLevel> for Value {
@@ -0,0 +1,58 @@
+//! The `accounts` table belongs to the login server, but both servers ban and unban accounts.
+//! Only the columns needed for that are mapped here, so the game server never touches the rest.
+
+use super::access_level::AccessLevel;
+use crate::db::{DbError, DbRepository, PrimaryKeyColumns, Repository, UpdatableModel};
+use sea_orm::{entity::prelude::*, sea_query::Expr};
+
+/// Repository name of the shared accounts access, separate from the login server's own accounts
+pub const ACCOUNT_ACCESS_REPOSITORY: &str = "account_access";
+
+pub type AccountAccessRepository = DbRepository<Uuid, Entity>;
+
+#[derive(Clone, Debug, DeriveEntityModel, Eq, PartialEq)]
+#[sea_orm(table_name = "accounts")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub name: String,
+    pub access_level: AccessLevel,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::Id]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::AccessLevel]
+    }
+}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+/// Returns false when there is no account with that name.
+pub async fn set_access_level(
+    repository: &AccountAccessRepository,
+    name: &str,
+    access_level: AccessLevel,
+) -> Result<bool, DbError> {
+    let name = name.to_string();
+    let updated = repository
+        .update_many(|update| {
+            update
+                .col_expr(Column::AccessLevel, Expr::value(access_level))
+                .filter(Column::Name.eq(name))
+        })
+        .await?;
+    Ok(updated.rows_affected > 0)
+}
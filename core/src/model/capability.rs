@@ -0,0 +1,48 @@
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, Display, EnumIter, EnumString};
+
+/// What a GM command is allowed to do, each access level is granted a set of them in the config.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Hash,
+    PartialEq,
+    Eq,
+    Reflect,
+    AsRefStr,
+    EnumIter,
+    EnumString,
+    Display,
+    Serialize,
+    Deserialize,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Capability {
+    /// Open the admin menu and browse its lists
+    Menu,
+    Heal,
+    Resurrect,
+    Kill,
+    Pause,
+    SpawnItem,
+    SpawnNpc,
+    AddSkill,
+    Teleport,
+    Multisell,
+    SetLevel,
+    /// Grant noblesse and hero status
+    Status,
+    Immortal,
+    Doors,
+    /// Schedule, abort and restart the server
+    Shutdown,
+    /// Disconnect a character
+    Kick,
+    /// Ban and unban accounts
+    Ban,
+    Announce,
+    ReloadAssets,
+}
@@ -1,5 +1,7 @@
 pub mod access_level;
+pub mod account;
 pub mod base_class;
+pub mod capability;
 pub mod generic_number;
 pub mod race;
 pub mod session;
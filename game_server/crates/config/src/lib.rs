@@ -1,8 +1,14 @@
 use bevy::prelude::*;
 use bevy_common_assets::toml::TomlAssetPlugin;
 use derive_more::derive::{From, Into};
-use l2r_core::{assets::ASSET_DIR, utils::get_base_path};
+use l2r_core::{
+    assets::ASSET_DIR,
+    model::{access_level::AccessLevel, capability::Capability},
+    utils::get_base_path,
+};
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use strum::IntoEnumIterator;
 pub struct ConfigPlugin;
 
 const CONFIG_FILE: &str = "config.toml";
@@ -45,9 +51,19 @@ impl Default for MaxPlayers {
     }
 }
 
+/// Id the login server lists this server under
+#[derive(Clone, Copy, Debug, Deserialize, From, Into, Reflect, Serialize)]
+pub struct ServerId(u8);
+impl Default for ServerId {
+    fn default() -> Self {
+        Self(9)
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Reflect, Serialize)]
 #[serde(default)]
 pub struct GeneralConfig {
+    pub server_id: ServerId,
     pub max_players: MaxPlayers,
     pub database_url: DatabaseUrl,
     pub redis_url: RedisUrl,
@@ -91,6 +107,202 @@ impl Default for GameplayConfig {
     }
 }
 
+/// Characters stay in the world for `time` seconds after the connection drops,
+/// only when they are in combat if `only_in_combat` is set. Zero time disables it.
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+#[serde(default)]
+pub struct LinkdeadConfig {
+    pub time: u64,
+    pub only_in_combat: bool,
+}
+
+impl Default for LinkdeadConfig {
+    fn default() -> Self {
+        Self {
+            time: 30,
+            only_in_combat: true,
+        }
+    }
+}
+
+impl LinkdeadConfig {
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        (self.time > 0).then(|| std::time::Duration::from_secs(self.time))
+    }
+}
+
+/// Characters detached with the `.offline` command stay in the world for up to `max_time` seconds.
+/// Zero time disables the command.
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+#[serde(default)]
+pub struct OfflineConfig {
+    pub max_time: u64,
+}
+
+impl Default for OfflineConfig {
+    fn default() -> Self {
+        Self { max_time: 86400 }
+    }
+}
+
+impl OfflineConfig {
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        (self.max_time > 0).then(|| std::time::Duration::from_secs(self.max_time))
+    }
+}
+
+/// Changed characters are written to the database every `interval` seconds,
+/// at most `batch_size` of them per tick. Zero interval disables it.
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+#[serde(default)]
+pub struct AutosaveConfig {
+    pub interval: u64,
+    pub batch_size: usize,
+}
+
+impl Default for AutosaveConfig {
+    fn default() -> Self {
+        Self {
+            interval: 300,
+            batch_size: 20,
+        }
+    }
+}
+
+impl AutosaveConfig {
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        (self.interval > 0).then(|| std::time::Duration::from_secs(self.interval))
+    }
+}
+
+/// Limits for the async database bindings used by Lua scripts.
+/// `query_timeout` is in milliseconds, `max_concurrent` counts in-flight queries per script,
+/// and scripts listed in `read_only_scripts` by asset path (e.g. `scripts/runtime/skills/app.lua`)
+/// can't write to the database.
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+#[serde(default)]
+pub struct ScriptDbConfig {
+    pub query_timeout: u64,
+    pub max_concurrent: usize,
+    pub read_only_scripts: Vec<String>,
+}
+
+impl Default for ScriptDbConfig {
+    fn default() -> Self {
+        Self {
+            query_timeout: 5000,
+            max_concurrent: 8,
+            read_only_scripts: Vec::new(),
+        }
+    }
+}
+
+impl ScriptDbConfig {
+    pub fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.query_timeout)
+    }
+
+    pub fn is_read_only(&self, script: &str) -> bool {
+        self.read_only_scripts.iter().any(|name| name == script)
+    }
+}
+
+/// Timed effects and skill reuse timers are kept over logout,
+/// except for the effects of the abnormal kinds listed in `dropped_on_logout`.
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+#[serde(default)]
+pub struct EffectsConfig {
+    pub dropped_on_logout: Vec<String>,
+}
+
+impl Default for EffectsConfig {
+    fn default() -> Self {
+        Self {
+            dropped_on_logout: ["Hide", "Stealth", "Invincibility", "AbnormalInvincibility"]
+                .map(String::from)
+                .to_vec(),
+        }
+    }
+}
+
+impl EffectsConfig {
+    pub fn is_dropped_on_logout(&self, kind: &str) -> bool {
+        self.dropped_on_logout.iter().any(|dropped| dropped == kind)
+    }
+}
+
+/// Bearer token of the admin API, the API refuses every request while it's empty.
+#[derive(Clone, Debug, Default, Deserialize, Reflect, Serialize)]
+#[serde(default)]
+pub struct AdminApiConfig {
+    pub token: String,
+}
+
+/// Capabilities granted to each GM access level, players and banned accounts have none.
+#[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
+#[serde(default)]
+pub struct PermissionsConfig {
+    pub support_gm: Vec<Capability>,
+    pub event_gm: Vec<Capability>,
+    pub head_gm: Vec<Capability>,
+    pub admin: Vec<Capability>,
+}
+
+impl Default for PermissionsConfig {
+    fn default() -> Self {
+        let support_gm = vec![
+            Capability::Menu,
+            Capability::Heal,
+            Capability::Resurrect,
+            Capability::Teleport,
+        ];
+        let event_gm = [
+            support_gm.as_slice(),
+            &[
+                Capability::SpawnItem,
+                Capability::SpawnNpc,
+                Capability::Multisell,
+                Capability::Status,
+                Capability::Immortal,
+                Capability::Doors,
+            ],
+        ]
+        .concat();
+        let head_gm = [
+            event_gm.as_slice(),
+            &[
+                Capability::Kill,
+                Capability::Pause,
+                Capability::AddSkill,
+                Capability::SetLevel,
+            ],
+        ]
+        .concat();
+        Self {
+            support_gm,
+            event_gm,
+            head_gm,
+            admin: Capability::iter().collect(),
+        }
+    }
+}
+
+impl PermissionsConfig {
+    pub fn capabilities(&self, access: AccessLevel) -> &[Capability] {
+        match access {
+            AccessLevel::Banned | AccessLevel::Player => &[],
+            AccessLevel::SupportGM => &self.support_gm,
+            AccessLevel::EventGM => &self.event_gm,
+            AccessLevel::HeadGM => &self.head_gm,
+            AccessLevel::Admin => &self.admin,
+        }
+    }
+
+    pub fn allows(&self, access: AccessLevel, capability: Capability) -> bool {
+        self.capabilities(access).contains(&capability)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
 #[serde(default)]
 #[derive(Default)]
@@ -106,6 +318,13 @@ pub struct Config {
     general: GeneralConfig,
     skills: SkillsConfig,
     gameplay: GameplayConfig,
+    linkdead: LinkdeadConfig,
+    offline: OfflineConfig,
+    autosave: AutosaveConfig,
+    script_db: ScriptDbConfig,
+    effects: EffectsConfig,
+    admin_api: AdminApiConfig,
+    permissions: PermissionsConfig,
     gui: GuiConfig,
     #[serde(skip)]
     #[reflect(ignore)]
@@ -149,6 +368,34 @@ impl Config {
         &self.gameplay
     }
 
+    pub fn linkdead(&self) -> &LinkdeadConfig {
+        &self.linkdead
+    }
+
+    pub fn offline(&self) -> &OfflineConfig {
+        &self.offline
+    }
+
+    pub fn autosave(&self) -> &AutosaveConfig {
+        &self.autosave
+    }
+
+    pub fn script_db(&self) -> &ScriptDbConfig {
+        &self.script_db
+    }
+
+    pub fn effects(&self) -> &EffectsConfig {
+        &self.effects
+    }
+
+    pub fn admin_api(&self) -> &AdminApiConfig {
+        &self.admin_api
+    }
+
+    pub fn permissions(&self) -> &PermissionsConfig {
+        &self.permissions
+    }
+
     pub fn gui(&self) -> &GuiConfig {
         &self.gui
     }
@@ -156,6 +403,7 @@ impl Config {
     /// Merge another config into self, overriding fields if present in other.
     fn merge(&mut self, other: &Config) {
         // General
+        self.general.server_id = other.general.server_id;
         self.general.max_players = other.general.max_players;
         self.general.database_url = other.general.database_url.clone();
         self.general.redis_url = other.general.redis_url.clone();
@@ -169,6 +417,27 @@ impl Config {
         self.gameplay.npc_dmg_penalty = other.gameplay.npc_dmg_penalty.clone();
         self.gameplay.npc_crit_dmg_penalty = other.gameplay.npc_crit_dmg_penalty.clone();
         self.gameplay.npc_skill_dmg_penalty = other.gameplay.npc_skill_dmg_penalty.clone();
+        // Linkdead
+        self.linkdead.time = other.linkdead.time;
+        self.linkdead.only_in_combat = other.linkdead.only_in_combat;
+        // Offline
+        self.offline.max_time = other.offline.max_time;
+        // Autosave
+        self.autosave.interval = other.autosave.interval;
+        self.autosave.batch_size = other.autosave.batch_size;
+        // Script DB
+        self.script_db.query_timeout = other.script_db.query_timeout;
+        self.script_db.max_concurrent = other.script_db.max_concurrent;
+        self.script_db.read_only_scripts = other.script_db.read_only_scripts.clone();
+        // Effects
+        self.effects.dropped_on_logout = other.effects.dropped_on_logout.clone();
+        // Admin API
+        self.admin_api.token = other.admin_api.token.clone();
+        // Permissions
+        self.permissions.support_gm = other.permissions.support_gm.clone();
+        self.permissions.event_gm = other.permissions.event_gm.clone();
+        self.permissions.head_gm = other.permissions.head_gm.clone();
+        self.permissions.admin = other.permissions.admin.clone();
         // GUI
         self.gui.geodata_cells = other.gui.geodata_cells;
         self.gui.geodata_blocks = other.gui.geodata_blocks;
@@ -186,6 +455,9 @@ impl Config {
         }
         for (key, value) in std::env::vars().filter(|(key, _)| key.starts_with(ENV_PREFIX)) {
             match key.trim_start_matches(ENV_PREFIX).to_uppercase().as_str() {
+                "SERVER_ID" => {
+                    self.general.server_id = value.parse::<u8>().map(ServerId).unwrap_or_default()
+                }
                 "MAX_PLAYERS" => {
                     self.general.max_players =
                         value.parse::<usize>().map(MaxPlayers).unwrap_or_default()
@@ -236,6 +508,68 @@ impl Config {
                         self.gameplay.npc_skill_dmg_penalty = vec;
                     }
                 }
+                "LINKDEAD_TIME" => {
+                    self.linkdead.time = value.parse::<u64>().unwrap_or(self.linkdead.time)
+                }
+                "LINKDEAD_ONLY_IN_COMBAT" => {
+                    self.linkdead.only_in_combat = value
+                        .parse::<bool>()
+                        .unwrap_or(self.linkdead.only_in_combat)
+                }
+                "OFFLINE_MAX_TIME" => {
+                    self.offline.max_time = value.parse::<u64>().unwrap_or(self.offline.max_time)
+                }
+                "AUTOSAVE_INTERVAL" => {
+                    self.autosave.interval = value.parse::<u64>().unwrap_or(self.autosave.interval)
+                }
+                "AUTOSAVE_BATCH_SIZE" => {
+                    self.autosave.batch_size =
+                        value.parse::<usize>().unwrap_or(self.autosave.batch_size)
+                }
+                "SCRIPT_DB_QUERY_TIMEOUT" => {
+                    self.script_db.query_timeout =
+                        value.parse::<u64>().unwrap_or(self.script_db.query_timeout)
+                }
+                "SCRIPT_DB_MAX_CONCURRENT" => {
+                    self.script_db.max_concurrent = value
+                        .parse::<usize>()
+                        .unwrap_or(self.script_db.max_concurrent)
+                }
+                "SCRIPT_DB_READ_ONLY_SCRIPTS" => {
+                    self.script_db.read_only_scripts = value
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect();
+                }
+                "EFFECTS_DROPPED_ON_LOGOUT" => {
+                    self.effects.dropped_on_logout = value
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect();
+                }
+                "ADMIN_API_TOKEN" => self.admin_api.token = value,
+                "PERMISSIONS_SUPPORT_GM" => {
+                    if let Some(capabilities) = parse_capabilities(&key, &value) {
+                        self.permissions.support_gm = capabilities;
+                    }
+                }
+                "PERMISSIONS_EVENT_GM" => {
+                    if let Some(capabilities) = parse_capabilities(&key, &value) {
+                        self.permissions.event_gm = capabilities;
+                    }
+                }
+                "PERMISSIONS_HEAD_GM" => {
+                    if let Some(capabilities) = parse_capabilities(&key, &value) {
+                        self.permissions.head_gm = capabilities;
+                    }
+                }
+                "PERMISSIONS_ADMIN" => {
+                    if let Some(capabilities) = parse_capabilities(&key, &value) {
+                        self.permissions.admin = capabilities;
+                    }
+                }
                 "GUI_GEODATA_CELLS" => {
                     self.gui.geodata_cells = value.parse::<bool>().unwrap_or(self.gui.geodata_cells)
                 }
@@ -271,3 +605,84 @@ impl Config {
         }
     }
 }
+
+/// Comma separated capability names, `None` if any of them is unknown.
+/// An unknown name is logged and the whole setting is ignored rather than granting a partial set.
+fn parse_capabilities(key: &str, value: &str) -> Option<Vec<Capability>> {
+    let (capabilities, unknown): (Vec<_>, Vec<_>) = value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| Capability::from_str(s).map_err(|_| s))
+        .partition(Result::is_ok);
+    if !unknown.is_empty() {
+        let unknown: Vec<_> = unknown.into_iter().filter_map(Result::err).collect();
+        let known: Vec<_> = Capability::iter().map(|c| c.to_string()).collect();
+        error!(
+            "{} has unknown capabilities {:?}, the setting is ignored. Known capabilities: {}",
+            key,
+            unknown,
+            known.join(", ")
+        );
+        return None;
+    }
+    Some(capabilities.into_iter().filter_map(Result::ok).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn players_and_banned_accounts_have_no_capabilities() {
+        let permissions = PermissionsConfig::default();
+        for capability in Capability::iter() {
+            assert!(!permissions.allows(AccessLevel::Player, capability));
+            assert!(!permissions.allows(AccessLevel::Banned, capability));
+        }
+    }
+
+    #[test]
+    fn admin_is_allowed_everything_by_default() {
+        let permissions = PermissionsConfig::default();
+        for capability in Capability::iter() {
+            assert!(permissions.allows(AccessLevel::Admin, capability));
+        }
+    }
+
+    #[test]
+    fn levels_only_get_their_own_capabilities() {
+        let permissions = PermissionsConfig {
+            support_gm: vec![Capability::Menu],
+            event_gm: vec![Capability::SpawnItem],
+            head_gm: vec![],
+            admin: vec![Capability::Shutdown],
+        };
+        assert!(permissions.allows(AccessLevel::SupportGM, Capability::Menu));
+        assert!(!permissions.allows(AccessLevel::SupportGM, Capability::SpawnItem));
+        assert!(permissions.allows(AccessLevel::EventGM, Capability::SpawnItem));
+        assert!(!permissions.allows(AccessLevel::EventGM, Capability::Menu));
+        assert!(!permissions.allows(AccessLevel::HeadGM, Capability::Menu));
+        assert!(!permissions.allows(AccessLevel::Admin, Capability::Menu));
+    }
+
+    #[test]
+    fn capabilities_parse_from_the_env_value() {
+        assert_eq!(
+            parse_capabilities("L2R_PERMISSIONS_SUPPORT_GM", "menu, heal,,teleport"),
+            Some(vec![
+                Capability::Menu,
+                Capability::Heal,
+                Capability::Teleport
+            ])
+        );
+    }
+
+    #[test]
+    fn unknown_capability_ignores_the_setting() {
+        assert_eq!(
+            parse_capabilities("L2R_PERMISSIONS_SUPPORT_GM", "menu,teleprot"),
+            None
+        );
+    }
+}
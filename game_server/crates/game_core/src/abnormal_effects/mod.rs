@@ -13,6 +13,9 @@ use strum::IntoEnumIterator;
 mod kind;
 mod timers;
 
+/// Database model of the effects kept over logout
+pub mod model;
+
 pub use kind::*;
 pub use timers::*;
 
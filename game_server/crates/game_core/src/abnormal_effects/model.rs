@@ -0,0 +1,149 @@
+use super::{AbnormalEffect, AbnormalKind};
+use crate::{character, object_id::ObjectId, skills};
+use l2r_core::db::{PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::entity::prelude::*;
+use serde::{
+    Deserialize,
+    de::{
+        IntoDeserializer,
+        value::{self, StrDeserializer},
+    },
+};
+use std::time::Duration;
+
+/// Timed effect the character had when it was saved, with the time it had left.
+#[derive(Clone, Debug, DeriveEntityModel, PartialEq)]
+#[sea_orm(table_name = "character_effects")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub char_id: ObjectId,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub skill_id: skills::Id,
+    pub skill_level: i32,
+    pub magic_level: i32,
+    pub kind: String,
+    pub active: bool,
+    pub remaining_ms: i64,
+}
+
+impl Model {
+    pub fn new(char_id: ObjectId, effect: &AbnormalEffect, remaining: Duration) -> Self {
+        let skill = effect.skill();
+        Self {
+            char_id,
+            skill_id: skill.id(),
+            skill_level: skill.level().into(),
+            magic_level: skill.magic_level() as i32,
+            kind: effect.kind().to_string(),
+            active: effect.active(),
+            remaining_ms: remaining.as_millis() as i64,
+        }
+    }
+
+    /// `None` if the kind is not known anymore.
+    pub fn effect(&self) -> Option<(AbnormalEffect, Duration)> {
+        let kind: StrDeserializer<'_, value::Error> = self.kind.as_str().into_deserializer();
+        let kind = AbnormalKind::deserialize(kind).ok()?;
+        let skill = skills::Skill::new(self.skill_id, self.skill_level.into())
+            .with_magic_level(self.magic_level as u32);
+        Some((
+            AbnormalEffect::new(skill, kind, self.active),
+            Duration::from_millis(self.remaining_ms.max(0) as u64),
+        ))
+    }
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CharId, Column::SkillId]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[
+            Column::SkillLevel,
+            Column::MagicLevel,
+            Column::Kind,
+            Column::Active,
+            Column::RemainingMs,
+        ]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::CharId",
+        to = "character::model::Column::Id"
+    )]
+    Character,
+}
+
+impl Related<character::model::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Character.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::abnormal_effects::BuffKind;
+    use config::EffectsConfig;
+
+    fn model(kind: BuffKind) -> Model {
+        let skill = skills::Skill::new(skills::Id::new(1204), 2u32.into()).with_magic_level(40);
+        let effect = AbnormalEffect::new(skill, AbnormalKind::Buff(kind), true);
+        Model::new(ObjectId::default(), &effect, Duration::from_millis(1500))
+    }
+
+    #[test]
+    fn effect_survives_the_round_trip() {
+        let (effect, remaining) = model(BuffKind::AbilityChange).effect().unwrap();
+        assert_eq!(effect.skill().id(), skills::Id::new(1204));
+        assert_eq!(effect.skill().level(), 2u32.into());
+        assert_eq!(effect.skill().magic_level(), 40);
+        assert_eq!(effect.kind(), AbnormalKind::Buff(BuffKind::AbilityChange));
+        assert!(effect.active());
+        assert_eq!(remaining, Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn unknown_kind_is_dropped() {
+        let mut model = model(BuffKind::AbilityChange);
+        model.kind = "NoSuchKind".to_string();
+        assert!(model.effect().is_none());
+    }
+
+    #[test]
+    fn negative_remaining_time_is_clamped() {
+        let mut model = model(BuffKind::AbilityChange);
+        model.remaining_ms = -10;
+        let (_, remaining) = model.effect().unwrap();
+        assert_eq!(remaining, Duration::ZERO);
+    }
+
+    #[test]
+    fn stored_kind_matches_the_dropped_on_logout_names() {
+        let config = EffectsConfig::default();
+        for kind in [
+            BuffKind::Hide,
+            BuffKind::Stealth,
+            BuffKind::Invincibility,
+            BuffKind::AbnormalInvincibility,
+        ] {
+            assert!(config.is_dropped_on_logout(&model(kind).kind), "{kind}");
+        }
+        assert!(!config.is_dropped_on_logout(&model(BuffKind::AbilityChange).kind));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use chrono::Utc;
+use l2r_core::model::{access_level::AccessLevel, capability::Capability};
+use sea_orm::{ActiveValue::Set, DatabaseConnection, DbErr, entity::prelude::*};
+
+/// Database model of the audit log rows
+pub mod model;
+
+/// Account the admin API calls are recorded under, they are made with the API token.
+pub const ADMIN_API_ACCOUNT: &str = "admin_api";
+
+/// GM command attempt, triggered for the allowed and the denied ones alike.
+#[derive(Clone, Debug, Event)]
+pub struct GmAction {
+    pub account: String,
+    pub access_level: AccessLevel,
+    pub character_id: ObjectId,
+    pub character_name: String,
+    pub capability: Capability,
+    pub action: String,
+    pub allowed: bool,
+}
+
+impl GmAction {
+    /// Authorized admin API call, there is no character behind it.
+    pub fn admin_api(capability: Capability, action: String) -> Self {
+        Self {
+            account: ADMIN_API_ACCOUNT.to_string(),
+            access_level: AccessLevel::Admin,
+            character_id: ObjectId::default(),
+            character_name: String::new(),
+            capability,
+            action,
+            allowed: true,
+        }
+    }
+
+    pub async fn record(self, conn: &DatabaseConnection) -> Result<(), DbErr> {
+        model::ActiveModel {
+            account: Set(self.account),
+            access_level: Set(self.access_level),
+            character_id: Set(self.character_id),
+            character_name: Set(self.character_name),
+            capability: Set(self.capability.to_string()),
+            action: Set(self.action),
+            allowed: Set(self.allowed),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        }
+        .insert(conn)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::object_id::ObjectId;
+use l2r_core::{
+    db::{PrimaryKeyColumns, RepositoryModel, UpdatableModel},
+    model::access_level::AccessLevel,
+};
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel, PartialEq)]
+#[sea_orm(table_name = "gm_audit_log")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub account: String,
+    pub access_level: AccessLevel,
+    pub character_id: ObjectId,
+    pub character_name: String,
+    pub capability: String,
+    pub action: String,
+    pub allowed: bool,
+    pub created_at: DateTime,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::Id]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -7,10 +7,13 @@ use l2r_core::{
         html::{HtmlAsset, TeraHtmlTemplater},
     },
     chronicles::CHRONICLE,
+    model::capability::Capability,
 };
 use std::str::FromStr;
 use strum::{AsRefStr, Display, EnumDiscriminants, EnumIter, EnumString};
 
+pub mod audit;
+
 pub struct AdminMenuComponentsPlugin;
 impl Plugin for AdminMenuComponentsPlugin {
     fn build(&self, app: &mut App) {
@@ -125,6 +128,31 @@ pub enum AdminMenuCommand {
     MultiSell(multisell::Id),
     MultiSellList(u32),
     SetLevel(Level),
+    Noblesse,
+    Hero,
+    Shutdown(u32),
+    Restart(u32),
+    AbortShutdown,
+}
+
+impl AdminMenuCommand {
+    pub fn capability(&self) -> Capability {
+        match self {
+            Self::Main | Self::TpList(_) | Self::MultiSellList(_) => Capability::Menu,
+            Self::Heal => Capability::Heal,
+            Self::Resurrect => Capability::Resurrect,
+            Self::Kill => Capability::Kill,
+            Self::Pause => Capability::Pause,
+            Self::SpawnItem(..) => Capability::SpawnItem,
+            Self::AddSkill(..) => Capability::AddSkill,
+            Self::SpawnNpc(_) => Capability::SpawnNpc,
+            Self::Tp(_) => Capability::Teleport,
+            Self::MultiSell(_) => Capability::Multisell,
+            Self::SetLevel(_) => Capability::SetLevel,
+            Self::Noblesse | Self::Hero => Capability::Status,
+            Self::Shutdown(_) | Self::Restart(_) | Self::AbortShutdown => Capability::Shutdown,
+        }
+    }
 }
 
 impl FromStr for AdminMenuCommand {
@@ -144,6 +172,9 @@ impl FromStr for AdminMenuCommand {
             CommandVariants::Resurrect => Ok(AdminMenuCommand::Resurrect),
             CommandVariants::Kill => Ok(AdminMenuCommand::Kill),
             CommandVariants::Pause => Ok(AdminMenuCommand::Pause),
+            CommandVariants::Noblesse => Ok(AdminMenuCommand::Noblesse),
+            CommandVariants::Hero => Ok(AdminMenuCommand::Hero),
+            CommandVariants::AbortShutdown => Ok(AdminMenuCommand::AbortShutdown),
             CommandVariants::SpawnItem => {
                 if let Some(arg) = arg {
                     // Space-separated the item id and count
@@ -253,6 +284,49 @@ impl FromStr for AdminMenuCommand {
                     "Invalid or missing argument for set_level: {command}"
                 ))
             }
+            CommandVariants::Shutdown => arg
+                .and_then(|arg| arg.trim().parse::<u32>().ok())
+                .map(AdminMenuCommand::Shutdown)
+                .ok_or_else(|| format!("Invalid or missing seconds for shutdown: {command}")),
+            CommandVariants::Restart => arg
+                .and_then(|arg| arg.trim().parse::<u32>().ok())
+                .map(AdminMenuCommand::Restart)
+                .ok_or_else(|| format!("Invalid or missing seconds for restart: {command}")),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capability(command: &str) -> Capability {
+        command
+            .parse::<AdminMenuCommand>()
+            .unwrap_or_else(|err| panic!("{command}: {err}"))
+            .capability()
+    }
+
+    #[test]
+    fn menu_pages_need_the_menu_capability() {
+        assert_eq!(capability("main"), Capability::Menu);
+        assert_eq!(capability("tp_list 2"), Capability::Menu);
+        assert_eq!(capability("multi_sell_list"), Capability::Menu);
+    }
+
+    #[test]
+    fn commands_map_to_their_capability() {
+        assert_eq!(capability("heal"), Capability::Heal);
+        assert_eq!(capability("kill"), Capability::Kill);
+        assert_eq!(capability("spawn_item 57 10"), Capability::SpawnItem);
+        assert_eq!(capability("noblesse"), Capability::Status);
+        assert_eq!(capability("hero"), Capability::Status);
+    }
+
+    #[test]
+    fn server_control_needs_the_shutdown_capability() {
+        assert_eq!(capability("shutdown 60"), Capability::Shutdown);
+        assert_eq!(capability("restart 60"), Capability::Shutdown);
+        assert_eq!(capability("abort_shutdown"), Capability::Shutdown);
+    }
+}
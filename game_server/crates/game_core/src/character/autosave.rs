@@ -0,0 +1,10 @@
+use strum::Display;
+
+#[derive(Clone, Copy, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum AutosaveMetric {
+    AutosaveQueueDepth,
+    AutosaveLatencyMs,
+    AutosaveSaved,
+    AutosaveFailed,
+}
@@ -23,7 +23,9 @@ pub struct Bundle {
     pub id: ObjectId,
     pub name: Name,
     pub title: NameTitle,
-    pub session_id: SessionId,
+    /// Restored detached characters have no session, see [`super::Detached`].
+    #[bundle(ignore)]
+    pub session_id: Option<SessionId>,
     pub movable: Movable,
     #[reflect(ignore)]
     pub collider: Collider,
@@ -56,6 +58,10 @@ pub struct Bundle {
     pub defence_effects: DefenceEffects,
     pub abnormal_effects: AbnormalEffects,
     pub targetable: Targetable,
+    #[bundle(ignore)]
+    pub noblesse: bool,
+    #[bundle(ignore)]
+    pub hero: bool,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -63,7 +69,7 @@ impl Bundle {
     pub fn new(
         db_model: Model,
         item_models: Vec<items::model::Model>,
-        session_id: SessionId,
+        session_id: Option<SessionId>,
         world: &mut World,
     ) -> Self {
         // let mut items_state: SystemState<ItemsDataQuery> = SystemState::new(world);
@@ -163,6 +169,8 @@ impl Bundle {
             defence_effects: DefenceEffects::default(),
             abnormal_effects: AbnormalEffects::default(),
             targetable: Targetable,
+            noblesse: db_model.noblesse,
+            hero: db_model.hero,
         }
     }
 
@@ -179,6 +187,15 @@ impl Bundle {
 
         character.set_folder::<Item>(items_folder);
         commands.entity(char_entity).insert(character);
+        if let Some(session_id) = self.session_id {
+            commands.entity(char_entity).insert(session_id);
+        }
+        if self.noblesse {
+            commands.entity(char_entity).insert(super::Noblesse);
+        }
+        if self.hero {
+            commands.entity(char_entity).insert(super::Hero);
+        }
         char_entity
     }
 
@@ -198,5 +215,7 @@ impl Bundle {
         self.primal_stats = character.primal_stats.clone();
         self.vitals_stats = character.vitals_stats.clone();
         self.paper_doll = character.paperdoll.clone();
+        self.noblesse = character.noblesse;
+        self.hero = character.hero;
     }
 }
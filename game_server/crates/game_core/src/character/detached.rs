@@ -0,0 +1,44 @@
+use crate::utils::ReflectableDateTime;
+use bevy::prelude::*;
+use chrono::{NaiveDateTime, TimeDelta};
+use sea_orm::prelude::Uuid;
+use std::time::Duration;
+
+/// Character staying in the world without a session, visible to others as usual.
+/// Leaves the world when the time is over, or is picked up when the account logs in again.
+#[derive(Clone, Component, Debug)]
+pub struct Detached {
+    account_id: Uuid,
+    until: ReflectableDateTime,
+}
+
+impl Detached {
+    pub fn new(account_id: Uuid, duration: Duration) -> Self {
+        let until = TimeDelta::from_std(duration)
+            .ok()
+            .and_then(|duration| ReflectableDateTime::now().checked_add_signed(duration))
+            .unwrap_or(NaiveDateTime::MAX);
+        Self::restored(account_id, until.into())
+    }
+
+    /// Detached character brought back from the database on startup.
+    pub fn restored(account_id: Uuid, until: ReflectableDateTime) -> Self {
+        Self { account_id, until }
+    }
+
+    pub fn account_id(&self) -> Uuid {
+        self.account_id
+    }
+
+    pub fn until(&self) -> ReflectableDateTime {
+        self.until
+    }
+
+    pub fn expired(&self) -> bool {
+        ReflectableDateTime::now() >= self.until
+    }
+}
+
+/// Detaches the target character from its session, the connection is closed afterwards.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct DetachCharacter;
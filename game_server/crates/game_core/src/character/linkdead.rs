@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use sea_orm::prelude::Uuid;
+use std::time::Duration;
+
+/// Character left in the world after its connection dropped.
+/// Despawned when the timer is finished, unless the account logs in again and picks it up.
+#[derive(Clone, Component, Debug)]
+pub struct Linkdead {
+    account_id: Uuid,
+    timer: Timer,
+}
+
+impl Linkdead {
+    pub fn new(account_id: Uuid, duration: Duration) -> Self {
+        Self {
+            account_id,
+            timer: Timer::new(duration, TimerMode::Once),
+        }
+    }
+
+    pub fn account_id(&self) -> Uuid {
+        self.account_id
+    }
+
+    pub fn tick(&mut self, delta: Duration) -> bool {
+        self.timer.tick(delta).finished()
+    }
+}
+
+/// Linkdead character picked up by a new session, it gets the world sent again on enter.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Reattached;
@@ -12,16 +12,24 @@ pub mod model;
 pub mod skills;
 
 mod appearance;
+mod autosave;
 mod bundle;
 mod delete_timer;
+mod detached;
+mod linkdead;
 mod query;
+mod status;
 mod table;
 
 pub use appearance::*;
+pub use autosave::*;
 pub use bundle::*;
 pub use delete_timer::*;
+pub use detached::*;
+pub use linkdead::*;
 pub use model::CharacterRepository;
 pub use query::*;
+pub use status::*;
 pub use table::*;
 
 #[derive(Debug, bevy::prelude::Event)]
@@ -32,9 +40,15 @@ impl Plugin for CharacterComponentsPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<model::Model>()
             .register_type::<skills::Model>()
-            .register_type::<Table>();
+            .register_type::<Table>()
+            .register_type::<Reattached>()
+            .register_type::<Noblesse>()
+            .register_type::<Hero>();
 
-        app.add_event::<CharacterSave>();
+        app.add_event::<CharacterSave>()
+            .add_event::<DetachCharacter>()
+            .add_event::<SetNoblesse>()
+            .add_event::<SetHero>();
     }
 }
 
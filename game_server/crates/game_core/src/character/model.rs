@@ -7,7 +7,11 @@ use l2r_core::{
     db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel},
     model::race::Race,
 };
-use sea_orm::{ActiveValue::Set, IntoActiveModel, entity::prelude::*};
+use sea_orm::{
+    ActiveValue::{NotSet, Set},
+    IntoActiveModel,
+    entity::prelude::*,
+};
 use spatial::GameVec3;
 use std::fmt;
 
@@ -33,6 +37,10 @@ pub struct Model {
     pub x: i32,
     pub y: i32,
     pub z: i32,
+    pub noblesse: bool,
+    pub hero: bool,
+    /// Set while the character stays in the world without a session.
+    pub detached_until: Option<ReflectableDateTime>,
 }
 
 impl PrimaryKeyColumns for Model {
@@ -57,6 +65,8 @@ impl UpdatableModel for Model {
             Column::Sp,
             Column::Vitals,
             Column::IsLastActive,
+            Column::Noblesse,
+            Column::Hero,
         ]
     }
 }
@@ -106,6 +116,20 @@ impl Model {
         active_model.sp = Set(update.sp);
         active_model.vitals = Set(update.vitals);
         active_model.is_last_active = Set(update.is_last_active);
+        active_model.noblesse = Set(update.noblesse);
+        active_model.hero = Set(update.hero);
+        active_model
+    }
+
+    /// Writes only the in-world state without reading the row first,
+    /// the last active flag is left to the full save.
+    pub fn autosave(id: ObjectId, update: ModelUpdate) -> ActiveModel {
+        let mut active_model = Self {
+            id,
+            ..Default::default()
+        }
+        .update(update);
+        active_model.is_last_active = NotSet;
         active_model
     }
 }
@@ -148,4 +172,6 @@ pub struct ModelUpdate {
     pub sp: i32,
     pub vitals: VitalsStats,
     pub is_last_active: bool,
+    pub noblesse: bool,
+    pub hero: bool,
 }
@@ -2,9 +2,14 @@ use crate::{
     action::wait_kind::Sit,
     attack::{Dead, InCombat},
     character::model::ModelUpdate,
+    fishing::Fishing,
+    instance_zone::InstanceExit,
     items::PaperDoll,
     object_id::ObjectId,
+    recipe::RecipeShop,
+    skills::SkillList,
     stats::*,
+    vehicle::InVehicle,
 };
 use avian3d::prelude::*;
 use bevy::{ecs::query::QueryData, prelude::*};
@@ -39,17 +44,33 @@ pub struct Query<'a> {
     pub dead: Option<&'a Dead>,
     pub in_combat: Option<&'a InCombat>,
     pub sitting: Option<&'a Sit>,
+    pub skill_list: Option<&'a SkillList>,
+    pub recipe_shop: Option<&'a RecipeShop>,
+    pub noblesse: Has<super::Noblesse>,
+    pub hero: Has<super::Hero>,
+    pub instance_exit: Option<&'a InstanceExit>,
+    pub in_vehicle: Option<&'a InVehicle>,
+    pub fishing: Option<&'a Fishing>,
 }
 
 impl<'a, 'b> From<&'a QueryItem<'a, 'b>> for ModelUpdate {
     fn from(character: &'a QueryItem<'a, 'b>) -> Self {
         Self {
             title: character.title.to_string().clone(),
-            position: GameVec3::from(character.transform.translation),
+            // Characters inside of an instance or on board are saved at their exit
+            position: GameVec3::from(
+                character
+                    .instance_exit
+                    .map(|exit| **exit)
+                    .or(character.in_vehicle.map(|in_vehicle| in_vehicle.exit))
+                    .unwrap_or(character.transform.translation),
+            ),
             exp: character.progress_stats.exp() as i64,
             sp: character.progress_stats.sp() as i32,
             vitals: character.vitals_stats.clone(),
             is_last_active: true,
+            noblesse: character.noblesse,
+            hero: character.hero,
         }
     }
 }
@@ -0,0 +1,60 @@
+use crate::{items, skills};
+use bevy::prelude::*;
+
+/// Nobles may take part in the Grand Olympiad.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Noblesse;
+
+/// Heroes of the Grand Olympiad glow, talk in the hero chat and may take a hero weapon.
+#[derive(Clone, Component, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Hero;
+
+/// Makes the character a noble or takes the noblesse away.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct SetNoblesse(pub bool);
+
+/// Makes the character a hero or takes the hero status away.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct SetHero(pub bool);
+
+/// Keeps the rest of the buffs when the character dies, the blessing itself is gone.
+pub const NOBLESSE_BLESSING: skills::Id = skills::Id::new(1323);
+
+pub const NOBLESSE_SKILLS: [skills::Id; 8] = [
+    skills::Id::new(325),  // Strider Siege Assault
+    skills::Id::new(326),  // Wyvern Aegis
+    skills::Id::new(327),  // Build Advanced Headquarters
+    NOBLESSE_BLESSING,     // Noblesse Blessing
+    skills::Id::new(1324), // Summon CP Potion
+    skills::Id::new(1325), // Fortune of Noblesse
+    skills::Id::new(1326), // Harmony of Noblesse
+    skills::Id::new(1327), // Symphony of Noblesse
+];
+
+pub const HERO_SKILLS: [skills::Id; 5] = [
+    skills::Id::new(395),  // Heroic Miracle
+    skills::Id::new(396),  // Heroic Berserker
+    skills::Id::new(1374), // Heroic Valor
+    skills::Id::new(1375), // Heroic Grandeur
+    skills::Id::new(1376), // Heroic Dread
+];
+
+/// Infinity weapons the Monument of Heroes gives out, a hero may own only one of them.
+pub const HERO_WEAPONS: [items::Id; 14] = [
+    items::Id::new(6611), // Infinity Blade
+    items::Id::new(6612), // Infinity Cleaver
+    items::Id::new(6613), // Infinity Axe
+    items::Id::new(6614), // Infinity Rod
+    items::Id::new(6615), // Infinity Crusher
+    items::Id::new(6616), // Infinity Scepter
+    items::Id::new(6617), // Infinity Stinger
+    items::Id::new(6618), // Infinity Fang
+    items::Id::new(6619), // Infinity Bow
+    items::Id::new(6620), // Infinity Wing
+    items::Id::new(6621), // Infinity Spear
+    items::Id::new(9388), // Infinity Rapier
+    items::Id::new(9389), // Infinity Sword
+    items::Id::new(9390), // Infinity Shooter
+];
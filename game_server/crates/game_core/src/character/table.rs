@@ -87,7 +87,7 @@ impl Table {
                         .unwrap_or_default()
                 });
 
-            let bundle = character::Bundle::new(char, db_items, session_id, world);
+            let bundle = character::Bundle::new(char, db_items, Some(session_id), world);
 
             chars_with_items.push(CharWithItems {
                 character: bundle,
@@ -0,0 +1,60 @@
+use crate::{clan::castle::CastleId, npc, teleport};
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_common_assets::json::JsonAssetPlugin;
+use derive_more::From;
+use map::DoorId;
+use serde::{Deserialize, Serialize};
+
+pub struct CastleDataComponentsPlugin;
+impl Plugin for CastleDataComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(JsonAssetPlugin::<CastleData>::new(&["json"]));
+
+        app.register_type::<CastleDataHandle>()
+            .register_type::<CastleNpcs>();
+    }
+}
+
+#[derive(Default, Deref, DerefMut, Reflect, Resource)]
+pub struct CastleDataHandle(Handle<CastleData>);
+
+#[derive(Asset, Clone, Debug, Deref, Deserialize, From, PartialEq, Reflect, Serialize)]
+pub struct CastleData(HashMap<CastleId, CastleNpcs>);
+
+impl CastleData {
+    /// Castle the chamberlain, the doorman or the teleporter serves.
+    pub fn castle_of_npc(&self, npc_id: npc::Id) -> Option<CastleId> {
+        self.0
+            .iter()
+            .find(|(_, npcs)| npcs.serves(npc_id))
+            .map(|(castle_id, _)| *castle_id)
+    }
+
+    /// Castle whose manor the manager serves, managers serve everyone, not only the owner.
+    pub fn castle_of_manor_manager(&self, npc_id: npc::Id) -> Option<CastleId> {
+        self.0
+            .iter()
+            .find(|(_, npcs)| npcs.manor_managers.contains(&npc_id))
+            .map(|(castle_id, _)| *castle_id)
+    }
+}
+
+/// NPCs working for the castle owner.
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct CastleNpcs {
+    pub chamberlain: npc::Id,
+    /// Doors opened and closed by each of the doormen.
+    pub doormen: HashMap<npc::Id, Vec<DoorId>>,
+    pub teleporter: npc::Id,
+    pub teleports: Vec<teleport::Id>,
+    #[serde(default)]
+    pub manor_managers: Vec<npc::Id>,
+}
+
+impl CastleNpcs {
+    pub fn serves(&self, npc_id: npc::Id) -> bool {
+        self.chamberlain == npc_id
+            || self.teleporter == npc_id
+            || self.doormen.contains_key(&npc_id)
+    }
+}
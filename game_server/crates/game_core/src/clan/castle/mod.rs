@@ -1,3 +1,49 @@
-mod model;
+use crate::clan;
+use bevy::{platform::collections::HashMap, prelude::*};
+use thiserror::Error;
 
-pub use model::{CastleId, Model as CastleModel};
+pub mod model;
+pub mod siege;
+
+mod data;
+
+pub use data::*;
+pub use model::{CastleId, CastleRepository, Model as CastleModel};
+
+pub struct CastleComponentsPlugin;
+impl Plugin for CastleComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(siege::SiegeComponentsPlugin)
+            .add_plugins(CastleDataComponentsPlugin);
+
+        app.register_type::<CastleId>()
+            .register_type::<CastleModel>()
+            .register_type::<Castles>();
+
+        app.init_resource::<Castles>();
+    }
+}
+
+pub const MIN_TAX_PERCENT: i32 = 0;
+pub const MAX_TAX_PERCENT: i32 = 15;
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum CastleError {
+    #[error("Tax rate must be between {MIN_TAX_PERCENT} and {MAX_TAX_PERCENT} percent")]
+    InvalidTax,
+    #[error("Castle treasury does not have enough adena")]
+    NotEnoughTreasury,
+}
+
+/// Castles loaded from the database at startup, kept in sync with it on every change.
+#[derive(Clone, Debug, Default, Deref, DerefMut, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct Castles(HashMap<CastleId, CastleModel>);
+
+impl Castles {
+    pub fn owned_by(&self, clan_id: clan::Id) -> Option<&CastleModel> {
+        self.0
+            .values()
+            .find(|castle| castle.owner_clan_id == Some(clan_id))
+    }
+}
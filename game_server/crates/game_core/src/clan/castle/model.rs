@@ -1,5 +1,14 @@
+use super::{
+    CastleError, MAX_TAX_PERCENT, MIN_TAX_PERCENT,
+    siege::{
+        DEFAULT_SIEGE_HOUR, REGISTRATION_CLOSE_HOURS, SIEGE_CYCLE_DAYS, SIEGE_HOURS,
+        SIEGE_LENGTH_HOURS, SiegeError,
+    },
+};
+use crate::{clan, utils::ReflectableDateTime};
 use bevy::prelude::*;
-use chrono::NaiveDateTime;
+use chrono::{Datelike, NaiveDateTime, NaiveTime, TimeDelta, Timelike};
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use sea_orm::{
     self as sea_orm, ActiveModelBehavior, DeriveEntityModel, DeriveRelation, EnumIter,
@@ -46,17 +55,20 @@ impl From<CastleId> for i32 {
     }
 }
 
-#[derive(Clone, Component, Debug, DeriveEntityModel, Eq, PartialEq)]
+pub type CastleRepository = DbRepository<i32, Entity>;
+
+#[derive(Clone, Component, Debug, DeriveEntityModel, Eq, PartialEq, Reflect)]
 #[sea_orm(table_name = "castle")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: i32,
     pub name: String,
+    pub owner_clan_id: Option<clan::Id>,
     pub tax_percent: i32,
     pub treasury: i64,
-    pub siege_date: NaiveDateTime,
+    pub siege_date: ReflectableDateTime,
     pub reg_time_over: bool,
-    pub reg_time_end: NaiveDateTime,
+    pub reg_time_end: ReflectableDateTime,
     pub show_npc_crest: bool,
     pub ticket_buy_count: i16,
 }
@@ -83,18 +95,205 @@ impl Model {
         Self {
             id: id.into(),
             name: id.to_string(),
+            owner_clan_id: None,
             tax_percent: config.tax_percent,
             treasury: config.treasury,
-            siege_date: config.siege_date,
+            siege_date: config.siege_date.into(),
             reg_time_over: config.reg_time_over,
-            reg_time_end: config.reg_time_end,
+            reg_time_end: config.reg_time_end.into(),
             show_npc_crest: config.show_npc_crest,
             ticket_buy_count: config.ticket_buy_count,
         }
     }
+
+    /// Castle without an owner, with the siege scheduled in two weeks.
+    pub fn with_next_siege(id: CastleId, now: NaiveDateTime) -> Self {
+        let siege_date = next_siege_date(now);
+        Self::new(
+            id,
+            CastleConfig {
+                tax_percent: 0,
+                treasury: 0,
+                siege_date,
+                reg_time_over: false,
+                reg_time_end: siege_date - TimeDelta::hours(REGISTRATION_CLOSE_HOURS),
+                show_npc_crest: false,
+                ticket_buy_count: 0,
+            },
+        )
+    }
+
+    pub fn registration_open(&self, now: NaiveDateTime) -> bool {
+        now < *self.reg_time_end
+    }
+
+    pub fn siege_end(&self) -> NaiveDateTime {
+        *self.siege_date + TimeDelta::hours(SIEGE_LENGTH_HOURS)
+    }
+
+    pub fn schedule_next_siege(&mut self, now: NaiveDateTime) {
+        let siege_date = next_siege_date(now);
+        self.siege_date = siege_date.into();
+        self.reg_time_end = (siege_date - TimeDelta::hours(REGISTRATION_CLOSE_HOURS)).into();
+        self.reg_time_over = false;
+    }
+
+    /// Owner can pick the hour of the siege once, while the registration is open.
+    pub fn set_siege_time(
+        &mut self,
+        time: NaiveDateTime,
+        now: NaiveDateTime,
+    ) -> Result<(), SiegeError> {
+        if self.reg_time_over
+            || !self.registration_open(now)
+            || time.date() != self.siege_date.date()
+            || time.minute() != 0
+            || !SIEGE_HOURS.contains(&time.hour())
+        {
+            return Err(SiegeError::InvalidTime);
+        }
+
+        let siege_date = time.with_second(0).unwrap_or(time);
+        self.siege_date = siege_date.into();
+        self.reg_time_end = (siege_date - TimeDelta::hours(REGISTRATION_CLOSE_HOURS)).into();
+        self.reg_time_over = true;
+        Ok(())
+    }
+
+    /// Tax added on top of the price of goods bought in the castle territory.
+    pub fn tax(&self, price: u64) -> u64 {
+        price * self.tax_percent.clamp(MIN_TAX_PERCENT, MAX_TAX_PERCENT) as u64 / 100
+    }
+
+    pub fn set_tax_percent(&mut self, tax_percent: i32) -> Result<(), CastleError> {
+        if !(MIN_TAX_PERCENT..=MAX_TAX_PERCENT).contains(&tax_percent) {
+            return Err(CastleError::InvalidTax);
+        }
+        self.tax_percent = tax_percent;
+        Ok(())
+    }
+
+    pub fn deposit(&mut self, amount: u64) {
+        self.treasury = self
+            .treasury
+            .saturating_add(i64::try_from(amount).unwrap_or(i64::MAX));
+    }
+
+    pub fn withdraw(&mut self, amount: u64) -> Result<(), CastleError> {
+        let amount = i64::try_from(amount).map_err(|_| CastleError::NotEnoughTreasury)?;
+        if amount > self.treasury {
+            return Err(CastleError::NotEnoughTreasury);
+        }
+        self.treasury -= amount;
+        Ok(())
+    }
+
+    pub fn castle_id(&self) -> Option<CastleId> {
+        u8::try_from(self.id)
+            .ok()
+            .and_then(|id| CastleId::try_from_primitive(id).ok())
+    }
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::Id]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[
+            Column::OwnerClanId,
+            Column::TaxPercent,
+            Column::Treasury,
+            Column::SiegeDate,
+            Column::RegTimeOver,
+            Column::RegTimeEnd,
+            Column::ShowNpcCrest,
+            Column::TicketBuyCount,
+        ]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+/// Sieges take place on sunday, two weeks after the given time.
+pub fn next_siege_date(after: NaiveDateTime) -> NaiveDateTime {
+    let date = (after + TimeDelta::days(SIEGE_CYCLE_DAYS)).date();
+    let days_to_sunday = (7 - date.weekday().num_days_from_sunday()) % 7;
+    let date = date + TimeDelta::days(days_to_sunday.into());
+    date.and_hms_opt(DEFAULT_SIEGE_HOUR, 0, 0)
+        .unwrap_or_else(|| date.and_time(NaiveTime::MIN))
 }
 
 #[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
 pub enum Relation {}
 
 impl ActiveModelBehavior for ActiveModel {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_time::now;
+    use chrono::Weekday;
+
+    #[test]
+    fn test_next_siege_date() {
+        let siege_date = next_siege_date(now());
+        assert_eq!(siege_date.weekday(), Weekday::Sun);
+        assert_eq!(siege_date.hour(), DEFAULT_SIEGE_HOUR);
+        assert!(siege_date >= now() + TimeDelta::days(SIEGE_CYCLE_DAYS));
+        assert!(siege_date < now() + TimeDelta::days(SIEGE_CYCLE_DAYS + 7));
+    }
+
+    #[test]
+    fn test_set_siege_time() {
+        let mut castle = Model::with_next_siege(CastleId::Gludio, now());
+        let date = castle.siege_date.date();
+
+        let wrong_hour = date.and_hms_opt(18, 0, 0).unwrap();
+        assert_eq!(
+            castle.set_siege_time(wrong_hour, now()),
+            Err(SiegeError::InvalidTime)
+        );
+
+        let time = date.and_hms_opt(16, 0, 0).unwrap();
+        castle.set_siege_time(time, now()).unwrap();
+        assert_eq!(*castle.siege_date, time);
+        assert_eq!(
+            *castle.reg_time_end,
+            time - TimeDelta::hours(REGISTRATION_CLOSE_HOURS)
+        );
+
+        let other_time = date.and_hms_opt(20, 0, 0).unwrap();
+        assert_eq!(
+            castle.set_siege_time(other_time, now()),
+            Err(SiegeError::InvalidTime)
+        );
+    }
+
+    #[test]
+    fn test_tax_and_treasury() {
+        let mut castle = Model::with_next_siege(CastleId::Gludio, now());
+        assert_eq!(castle.tax(1000), 0);
+
+        castle.set_tax_percent(10).unwrap();
+        assert_eq!(castle.tax(1000), 100);
+        assert_eq!(
+            castle.set_tax_percent(MAX_TAX_PERCENT + 1),
+            Err(CastleError::InvalidTax)
+        );
+        assert_eq!(castle.tax_percent, 10);
+
+        castle.deposit(castle.tax(1000));
+        assert_eq!(castle.treasury, 100);
+        assert_eq!(castle.withdraw(101), Err(CastleError::NotEnoughTreasury));
+        castle.withdraw(100).unwrap();
+        assert_eq!(castle.treasury, 0);
+    }
+}
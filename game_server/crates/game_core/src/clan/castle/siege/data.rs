@@ -0,0 +1,64 @@
+use crate::{clan::castle::CastleId, npc};
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_common_assets::json::JsonAssetPlugin;
+use derive_more::From;
+use map::DoorId;
+use serde::{Deserialize, Serialize};
+use spatial::{GameVec3, Heading};
+
+pub struct SiegeDataComponentsPlugin;
+impl Plugin for SiegeDataComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(JsonAssetPlugin::<SiegeData>::new(&["json"]));
+
+        app.register_type::<SiegeDataHandle>()
+            .register_type::<CastleSiegeData>()
+            .register_type::<SiegeNpcSpawn>();
+    }
+}
+
+#[derive(Default, Deref, DerefMut, Reflect, Resource)]
+pub struct SiegeDataHandle(Handle<SiegeData>);
+
+#[derive(Asset, Clone, Debug, Deref, Deserialize, From, PartialEq, Reflect, Serialize)]
+pub struct SiegeData(HashMap<CastleId, CastleSiegeData>);
+
+impl SiegeData {
+    /// Castle the messenger or the artefact belongs to.
+    pub fn castle_of_npc(&self, npc_id: npc::Id) -> Option<CastleId> {
+        self.0
+            .iter()
+            .find(|(_, data)| data.messenger == npc_id || data.artefacts.contains(&npc_id))
+            .map(|(castle_id, _)| *castle_id)
+    }
+
+    pub fn castle_of_door(&self, door_id: DoorId) -> Option<CastleId> {
+        self.0
+            .iter()
+            .find(|(_, data)| data.doors.contains(&door_id))
+            .map(|(castle_id, _)| *castle_id)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct CastleSiegeData {
+    pub messenger: npc::Id,
+    pub artefacts: Vec<npc::Id>,
+    pub doors: Vec<DoorId>,
+    /// Defenders and towers spawned when the siege starts.
+    pub npcs: Vec<SiegeNpcSpawn>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct SiegeNpcSpawn {
+    pub id: npc::Id,
+    pub loc: GameVec3,
+    #[serde(default)]
+    pub heading: Heading,
+}
+
+impl SiegeNpcSpawn {
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(self.loc.into()).with_rotation(Quat::from(self.heading))
+    }
+}
@@ -0,0 +1,472 @@
+use super::{CastleId, Castles};
+use crate::{
+    clan::{self, Clan, ClanLevel},
+    spawner::SpawnFolder,
+};
+use bevy::{platform::collections::HashMap, prelude::*};
+use chrono::NaiveDateTime;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sea_orm::{
+    TryGetError, TryGetable, Value,
+    sea_query::{ArrayType, ColumnType, ValueType, ValueTypeErr},
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub mod model;
+
+mod data;
+
+pub use data::*;
+
+pub struct SiegeComponentsPlugin;
+impl Plugin for SiegeComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(SiegeDataComponentsPlugin);
+
+        app.register_type::<SiegeSide>()
+            .register_type::<SiegeClan>()
+            .register_type::<Siege>()
+            .register_type::<Sieges>()
+            .register_type::<SiegeNpcs>()
+            .register_type::<model::Model>();
+
+        app.init_resource::<Sieges>();
+    }
+}
+
+pub const SIEGE_LENGTH_HOURS: i64 = 2;
+pub const SIEGE_CYCLE_DAYS: i64 = 14;
+/// Registration closes this many hours before the siege starts.
+pub const REGISTRATION_CLOSE_HOURS: i64 = 24;
+/// Hours of the siege day the castle owner can choose from.
+pub const SIEGE_HOURS: [u32; 2] = [16, 20];
+pub const DEFAULT_SIEGE_HOUR: u32 = 20;
+pub const MIN_CLAN_LEVEL: ClanLevel = 5;
+pub const MAX_ATTACKER_CLANS: usize = 500;
+pub const MAX_DEFENDER_CLANS: usize = 500;
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum SiegeError {
+    #[error("Siege registration is closed")]
+    RegistrationClosed,
+    #[error("Clan level is too low")]
+    ClanLevelTooLow,
+    #[error("Castle owner is registered on the defending side automatically")]
+    OwnerIsDefender,
+    #[error("Clan already owns a castle")]
+    OwnsCastle,
+    #[error("Clan is already registered for another siege")]
+    RegisteredElsewhere,
+    #[error("Clan is already registered on the attacking side")]
+    AlreadyAttacker,
+    #[error("Clan is already registered on the defending side")]
+    AlreadyDefender,
+    #[error("No more attackers may be registered")]
+    AttackersFull,
+    #[error("No more defenders may be registered")]
+    DefendersFull,
+    #[error("Clan is not registered for the siege")]
+    NotRegistered,
+    #[error("Siege time can not be set")]
+    InvalidTime,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    IntoPrimitive,
+    PartialEq,
+    Reflect,
+    Serialize,
+    TryFromPrimitive,
+)]
+#[repr(i16)]
+pub enum SiegeSide {
+    Attacker = 1,
+    Defender = 2,
+    /// Defender waiting for the approval of the castle owner.
+    DefenderPending = 3,
+}
+
+impl SiegeSide {
+    pub fn is_defending(&self) -> bool {
+        matches!(self, SiegeSide::Defender | SiegeSide::DefenderPending)
+    }
+}
+
+impl From<SiegeSide> for Value {
+    fn from(side: SiegeSide) -> Self {
+        Value::SmallInt(Some(side.into()))
+    }
+}
+
+impl TryGetable for SiegeSide {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &sea_orm::QueryResult,
+        idx: I,
+    ) -> Result<Self, TryGetError> {
+        let value: i16 = res.try_get_by(idx)?;
+        SiegeSide::try_from_primitive(value).map_err(|_| {
+            TryGetError::DbErr(sea_orm::DbErr::Type(format!(
+                "Failed to convert {value} to SiegeSide"
+            )))
+        })
+    }
+}
+
+impl ValueType for SiegeSide {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        if let Value::SmallInt(Some(val)) = v {
+            SiegeSide::try_from_primitive(val).map_err(|_| ValueTypeErr)
+        } else {
+            Err(ValueTypeErr)
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(SiegeSide).to_string()
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::SmallInteger
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::SmallInt
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Reflect)]
+pub struct SiegeClan {
+    pub clan_id: clan::Id,
+    pub side: SiegeSide,
+}
+
+impl SiegeClan {
+    pub fn into_model(self, castle_id: CastleId) -> model::Model {
+        model::Model {
+            castle_id: castle_id.into(),
+            clan_id: self.clan_id,
+            side: self.side,
+        }
+    }
+}
+
+impl From<model::Model> for SiegeClan {
+    fn from(model: model::Model) -> Self {
+        Self {
+            clan_id: model.clan_id,
+            side: model.side,
+        }
+    }
+}
+
+/// Clans registered for the siege of a single castle.
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+pub struct Siege {
+    clans: Vec<SiegeClan>,
+    in_progress: bool,
+}
+
+impl Siege {
+    pub fn new(clans: Vec<SiegeClan>) -> Self {
+        Self {
+            clans,
+            in_progress: false,
+        }
+    }
+
+    pub fn in_progress(&self) -> bool {
+        self.in_progress
+    }
+
+    pub fn clans(&self) -> &[SiegeClan] {
+        &self.clans
+    }
+
+    pub fn side_of(&self, clan_id: clan::Id) -> Option<SiegeSide> {
+        self.clans
+            .iter()
+            .find(|clan| clan.clan_id == clan_id)
+            .map(|clan| clan.side)
+    }
+
+    pub fn attackers(&self) -> impl Iterator<Item = &SiegeClan> {
+        self.clans
+            .iter()
+            .filter(|clan| clan.side == SiegeSide::Attacker)
+    }
+
+    /// Defenders including the ones waiting for approval.
+    pub fn defenders(&self) -> impl Iterator<Item = &SiegeClan> {
+        self.clans.iter().filter(|clan| clan.side.is_defending())
+    }
+
+    /// Registers the clan, defenders have to be approved by the castle owner afterwards.
+    pub fn register(&mut self, clan: &Clan, attacker: bool) -> Result<SiegeClan, SiegeError> {
+        if clan.level < MIN_CLAN_LEVEL {
+            return Err(SiegeError::ClanLevelTooLow);
+        }
+
+        match self.side_of(clan.id) {
+            Some(SiegeSide::Attacker) => return Err(SiegeError::AlreadyAttacker),
+            Some(_) => return Err(SiegeError::AlreadyDefender),
+            None => {}
+        }
+
+        let side = if attacker {
+            if self.attackers().count() >= MAX_ATTACKER_CLANS {
+                return Err(SiegeError::AttackersFull);
+            }
+            SiegeSide::Attacker
+        } else {
+            if self.defenders().count() >= MAX_DEFENDER_CLANS {
+                return Err(SiegeError::DefendersFull);
+            }
+            SiegeSide::DefenderPending
+        };
+
+        let siege_clan = SiegeClan {
+            clan_id: clan.id,
+            side,
+        };
+        self.clans.push(siege_clan);
+        Ok(siege_clan)
+    }
+
+    pub fn unregister(&mut self, clan_id: clan::Id) -> Result<SiegeClan, SiegeError> {
+        let index = self
+            .clans
+            .iter()
+            .position(|clan| clan.clan_id == clan_id)
+            .ok_or(SiegeError::NotRegistered)?;
+        Ok(self.clans.remove(index))
+    }
+
+    /// Moves the defender between the approved and the waiting list.
+    pub fn confirm_defender(
+        &mut self,
+        clan_id: clan::Id,
+        approved: bool,
+    ) -> Result<SiegeClan, SiegeError> {
+        let clan = self
+            .clans
+            .iter_mut()
+            .find(|clan| clan.clan_id == clan_id && clan.side.is_defending())
+            .ok_or(SiegeError::NotRegistered)?;
+
+        clan.side = if approved {
+            SiegeSide::Defender
+        } else {
+            SiegeSide::DefenderPending
+        };
+        Ok(*clan)
+    }
+
+    /// Starts the siege, defenders which were not approved in time do not take part in it.
+    pub fn start(&mut self) {
+        self.clans
+            .retain(|clan| clan.side != SiegeSide::DefenderPending);
+        self.in_progress = true;
+    }
+
+    /// Finishes the siege and clears the registrations for the next one.
+    pub fn finish(&mut self) {
+        self.clans.clear();
+        self.in_progress = false;
+    }
+}
+
+#[derive(Clone, Debug, Default, Deref, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct Sieges(HashMap<CastleId, Siege>);
+
+impl Sieges {
+    pub fn insert(&mut self, castle_id: CastleId, siege: Siege) {
+        self.0.insert(castle_id, siege);
+    }
+
+    pub fn siege_mut(&mut self, castle_id: CastleId) -> &mut Siege {
+        self.0.entry(castle_id).or_default()
+    }
+
+    /// Castle whose siege the clan is registered for.
+    pub fn registered_for(&self, clan_id: clan::Id) -> Option<CastleId> {
+        self.0
+            .iter()
+            .find(|(_, siege)| siege.side_of(clan_id).is_some())
+            .map(|(castle_id, _)| *castle_id)
+    }
+
+    pub fn register(
+        &mut self,
+        castles: &Castles,
+        castle_id: CastleId,
+        clan: &Clan,
+        attacker: bool,
+        now: NaiveDateTime,
+    ) -> Result<SiegeClan, SiegeError> {
+        let castle = castles
+            .get(&castle_id)
+            .ok_or(SiegeError::RegistrationClosed)?;
+
+        if !castle.registration_open(now) || self.siege_mut(castle_id).in_progress() {
+            return Err(SiegeError::RegistrationClosed);
+        }
+
+        if castle.owner_clan_id == Some(clan.id) {
+            return Err(SiegeError::OwnerIsDefender);
+        }
+
+        if castles.owned_by(clan.id).is_some() {
+            return Err(SiegeError::OwnsCastle);
+        }
+
+        if let Some(registered) = self.registered_for(clan.id)
+            && registered != castle_id
+        {
+            return Err(SiegeError::RegisteredElsewhere);
+        }
+
+        self.siege_mut(castle_id).register(clan, attacker)
+    }
+}
+
+/// Folder for the defenders and towers spawned for the siege of the castle.
+#[derive(Clone, Component, Copy, Debug, Deref, Reflect)]
+#[require(SpawnFolder)]
+pub struct SiegeNpcs(CastleId);
+
+impl From<CastleId> for SiegeNpcs {
+    fn from(castle_id: CastleId) -> Self {
+        Self(castle_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{clan::castle::CastleModel, object_id::ObjectId, utils::test_time::now};
+    use chrono::TimeDelta;
+
+    fn clan(id: u32, level: ClanLevel) -> Clan {
+        Clan {
+            id: id.into(),
+            name: format!("clan{id}"),
+            level,
+            leader_id: ObjectId::from(id),
+            leader_name: format!("leader{id}"),
+        }
+    }
+
+    fn castles() -> Castles {
+        let mut castles = Castles::default();
+        castles.insert(
+            CastleId::Gludio,
+            CastleModel::with_next_siege(CastleId::Gludio, now()),
+        );
+        castles.insert(
+            CastleId::Dion,
+            CastleModel::with_next_siege(CastleId::Dion, now()),
+        );
+        castles
+    }
+
+    #[test]
+    fn test_register_sides() {
+        let mut siege = Siege::default();
+
+        let attacker = siege.register(&clan(1, 5), true).unwrap();
+        assert_eq!(attacker.side, SiegeSide::Attacker);
+
+        let defender = siege.register(&clan(2, 5), false).unwrap();
+        assert_eq!(defender.side, SiegeSide::DefenderPending);
+
+        assert_eq!(
+            siege.register(&clan(1, 5), false),
+            Err(SiegeError::AlreadyAttacker)
+        );
+        assert_eq!(
+            siege.register(&clan(2, 5), true),
+            Err(SiegeError::AlreadyDefender)
+        );
+        assert_eq!(
+            siege.register(&clan(3, 4), true),
+            Err(SiegeError::ClanLevelTooLow)
+        );
+    }
+
+    #[test]
+    fn test_confirm_defender() {
+        let mut siege = Siege::default();
+        siege.register(&clan(1, 5), true).unwrap();
+        siege.register(&clan(2, 5), false).unwrap();
+
+        assert_eq!(
+            siege.confirm_defender(1u32.into(), true),
+            Err(SiegeError::NotRegistered)
+        );
+        assert_eq!(
+            siege.confirm_defender(2u32.into(), true).unwrap().side,
+            SiegeSide::Defender
+        );
+        assert_eq!(
+            siege.confirm_defender(2u32.into(), false).unwrap().side,
+            SiegeSide::DefenderPending
+        );
+    }
+
+    #[test]
+    fn test_start_drops_pending_defenders() {
+        let mut siege = Siege::default();
+        siege.register(&clan(1, 5), true).unwrap();
+        siege.register(&clan(2, 5), false).unwrap();
+        siege.register(&clan(3, 5), false).unwrap();
+        siege.confirm_defender(3u32.into(), true).unwrap();
+
+        siege.start();
+
+        assert!(siege.in_progress());
+        assert_eq!(siege.side_of(2u32.into()), None);
+        assert_eq!(siege.side_of(3u32.into()), Some(SiegeSide::Defender));
+
+        siege.finish();
+        assert!(!siege.in_progress());
+        assert!(siege.clans().is_empty());
+    }
+
+    #[test]
+    fn test_register_for_castle() {
+        let mut castles = castles();
+        let mut sieges = Sieges::default();
+
+        sieges
+            .register(&castles, CastleId::Gludio, &clan(1, 5), true, now())
+            .unwrap();
+        assert_eq!(
+            sieges.register(&castles, CastleId::Dion, &clan(1, 5), true, now()),
+            Err(SiegeError::RegisteredElsewhere)
+        );
+
+        castles.get_mut(&CastleId::Dion).unwrap().owner_clan_id = Some(2u32.into());
+        assert_eq!(
+            sieges.register(&castles, CastleId::Dion, &clan(2, 5), true, now()),
+            Err(SiegeError::OwnerIsDefender)
+        );
+        assert_eq!(
+            sieges.register(&castles, CastleId::Gludio, &clan(2, 5), true, now()),
+            Err(SiegeError::OwnsCastle)
+        );
+
+        let closed = *castles[&CastleId::Gludio].reg_time_end + TimeDelta::minutes(1);
+        assert_eq!(
+            sieges.register(&castles, CastleId::Gludio, &clan(3, 5), true, closed),
+            Err(SiegeError::RegistrationClosed)
+        );
+    }
+}
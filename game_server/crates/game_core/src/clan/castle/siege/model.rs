@@ -0,0 +1,91 @@
+use super::SiegeSide;
+use crate::clan;
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::{Condition, entity::prelude::*, sea_query::SimpleExpr};
+
+pub type CastleSiegeClansRepository = DbRepository<SiegeClanPK, Entity>;
+
+/// Clan registered for the next siege of the castle.
+#[derive(Clone, Copy, Debug, DeriveEntityModel, PartialEq, Reflect)]
+#[sea_orm(table_name = "castle_siege_clans")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub castle_id: i32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub clan_id: clan::Id,
+    pub side: SiegeSide,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CastleId, Column::ClanId]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::Side]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "clan::castle::model::Entity",
+        from = "Column::CastleId",
+        to = "clan::castle::model::Column::Id"
+    )]
+    Castle,
+    #[sea_orm(
+        belongs_to = "clan::model::Entity",
+        from = "Column::ClanId",
+        to = "clan::model::Column::Id"
+    )]
+    Clan,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SiegeClanPK {
+    pub castle_id: i32,
+    pub clan_id: clan::Id,
+}
+
+impl From<&Model> for SiegeClanPK {
+    fn from(model: &Model) -> Self {
+        SiegeClanPK {
+            castle_id: model.castle_id,
+            clan_id: model.clan_id,
+        }
+    }
+}
+
+impl From<SiegeClanPK> for Condition {
+    fn from(pk: SiegeClanPK) -> Self {
+        Condition::all()
+            .add(Column::CastleId.eq(pk.castle_id))
+            .add(Column::ClanId.eq(pk.clan_id))
+    }
+}
+
+impl From<SiegeClanPK> for SimpleExpr {
+    fn from(value: SiegeClanPK) -> Self {
+        Column::CastleId
+            .eq(value.castle_id)
+            .and(Column::ClanId.eq(value.clan_id))
+    }
+}
+
+impl From<SiegeClanPK> for (i32, clan::Id) {
+    fn from(pk: SiegeClanPK) -> Self {
+        (pk.castle_id, pk.clan_id)
+    }
+}
@@ -1,10 +1,65 @@
 pub mod castle;
+pub mod model;
 
-use bevy::prelude::*;
+mod id;
+
+use crate::object_id::ObjectId;
+use bevy::{platform::collections::HashMap, prelude::*};
+pub use id::Id;
 use serde::{Deserialize, Serialize};
 
+pub struct ClanComponentsPlugin;
+impl Plugin for ClanComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(castle::CastleComponentsPlugin);
+
+        app.register_type::<Id>()
+            .register_type::<Clan>()
+            .register_type::<Clans>()
+            .register_type::<model::Model>();
+
+        app.init_resource::<Clans>();
+    }
+}
+
 pub type ClanLevel = u8;
 
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct Clan {
+    pub id: Id,
+    pub name: String,
+    pub level: ClanLevel,
+    pub leader_id: ObjectId,
+    pub leader_name: String,
+}
+
+impl Clan {
+    pub fn new(model: model::Model, leader_name: String) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            level: model.level.clamp(0, ClanLevel::MAX as i16) as ClanLevel,
+            leader_id: model.leader_id,
+            leader_name,
+        }
+    }
+}
+
+/// All clans of the server, loaded once at startup.
+#[derive(Clone, Debug, Default, Deref, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct Clans(HashMap<Id, Clan>);
+
+impl Clans {
+    pub fn insert(&mut self, clan: Clan) {
+        self.0.insert(clan.id, clan);
+    }
+
+    pub fn led_by(&self, char_id: ObjectId) -> Option<&Clan> {
+        self.0.values().find(|clan| clan.leader_id == char_id)
+    }
+}
+
 #[derive(
     Clone, Debug, Default, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize, Reflect,
 )]
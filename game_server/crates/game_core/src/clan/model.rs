@@ -0,0 +1,46 @@
+use crate::{character, object_id::ObjectId};
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::entity::prelude::*;
+
+pub type ClansRepository = DbRepository<super::Id, Entity>;
+
+#[derive(Clone, Debug, DeriveEntityModel, PartialEq, Reflect)]
+#[sea_orm(table_name = "clans")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: super::Id,
+    pub name: String,
+    pub leader_id: ObjectId,
+    pub level: i16,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::Id]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::Name, Column::LeaderId, Column::Level]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::LeaderId",
+        to = "character::model::Column::Id"
+    )]
+    Leader,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,4 +1,7 @@
-use crate::network::packets::{client::GameClientPacket, server::GameServerPacket};
+use crate::network::{
+    config::GameLenSerializer,
+    packets::{client::GameClientPacket, server::GameServerPacket},
+};
 use l2r_core::{
     crypt::{blowfish::BlowfishKey, crypt_engine::CryptEngine},
     packets::{ClientPacketBuffer, L2rSerializeError, L2rServerPacket, L2rServerPackets},
@@ -54,17 +57,15 @@ impl GameCryptEngine {
     }
 }
 
-impl CryptEngine<GameClientPacket, GameServerPacket> for GameCryptEngine {
-    fn encrypt(&mut self, mut packet: GameServerPacket) -> Result<Vec<u8>, L2rSerializeError> {
-        packet = match packet {
-            GameServerPacket::KeyPacket(p) => {
-                let bytes = p.build(self.get_out_key()).buffer();
-                #[cfg(debug_assertions)]
-                log_trace_byte_table(&bytes, "KeyPacket");
-                return Ok(bytes.to_vec());
-            }
-            _ => packet,
-        };
+impl GameCryptEngine {
+    /// Encrypts a single packet with the out key, the key packet is sent as is.
+    fn encrypt_body(&mut self, packet: GameServerPacket) -> Vec<u8> {
+        if let GameServerPacket::KeyPacket(p) = packet {
+            let bytes = p.build(self.get_out_key()).buffer();
+            #[cfg(debug_assertions)]
+            log_trace_byte_table(&bytes, "KeyPacket");
+            return bytes.to_vec();
+        }
 
         let mut buffer = packet.buffer();
 
@@ -86,7 +87,23 @@ impl CryptEngine<GameClientPacket, GameServerPacket> for GameCryptEngine {
         log_trace_byte_table(&buffer, "Encrypted");
 
         self.shift_out_key(buffer.len());
-        Ok(buffer.into())
+        buffer.into()
+    }
+}
+
+impl CryptEngine<GameClientPacket, GameServerPacket> for GameCryptEngine {
+    /// Packets of a batch are encrypted one by one in order, each keeps its own frame.
+    fn encrypt(&mut self, packet: GameServerPacket) -> Result<Vec<u8>, L2rSerializeError> {
+        match packet {
+            GameServerPacket::PacketBatch(batch) => {
+                let mut bytes = Vec::new();
+                for packet in batch.into_inner() {
+                    bytes.extend(self.encrypt(packet)?);
+                }
+                Ok(bytes)
+            }
+            packet => GameLenSerializer::frame(self.encrypt_body(packet)),
+        }
     }
 
     fn decrypt(&mut self, packet: &[u8]) -> Result<GameClientPacket, L2rSerializeError> {
@@ -111,3 +128,26 @@ impl CryptEngine<GameClientPacket, GameServerPacket> for GameCryptEngine {
         GameClientPacket::try_from(buffer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::packets::server::{ActionFail, PacketBatch};
+
+    #[test]
+    fn test_batch_is_encrypted_as_consecutive_packets() {
+        let packets = vec![ActionFail.into(), ActionFail.into(), ActionFail.into()];
+
+        let mut single = GameCryptEngine::default();
+        let mut batched = single.clone();
+        let mut expected = Vec::new();
+        for packet in packets.clone() {
+            expected.extend(single.encrypt(packet).unwrap());
+        }
+
+        let bytes = batched.encrypt(PacketBatch::new(packets).into()).unwrap();
+
+        assert_eq!(bytes, expected);
+        assert_eq!(batched.get_out_key(), single.get_out_key());
+    }
+}
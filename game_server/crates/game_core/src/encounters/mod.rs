@@ -13,6 +13,7 @@ pub struct EncountersComponentsPlugin;
 impl Plugin for EncountersComponentsPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<KnownEntities>()
+            .register_type::<KnownBy>()
             .register_type::<EnteredWorld>();
 
         app.add_event::<KnownAdded>()
@@ -39,9 +40,20 @@ impl Component for KnownEntities {
                 return;
             };
 
-            world
-                .commands()
-                .trigger_targets(KnownEntitiesRemoved::new(object_id), context.entity);
+            // Entities known to the removed one are not known by it anymore
+            let known = world
+                .entity(context.entity)
+                .get::<KnownEntities>()
+                .map(|known| known.iter().copied().collect::<Vec<_>>())
+                .unwrap_or_default();
+            for known_entity in known {
+                if let Some(mut known_by) = world.get_mut::<KnownBy>(known_entity) {
+                    known_by.remove(&context.entity);
+                }
+            }
+
+            let removed = KnownEntitiesRemoved::new(object_id, world.entity(context.entity));
+            world.commands().trigger_targets(removed, context.entity);
         })
     }
 }
@@ -61,6 +73,12 @@ impl KnownEntities {
     }
 }
 
+/// Entities that have this one in their [`KnownEntities`], kept in sync by the encounters
+/// so that broadcasts find the observers without scanning every knower.
+#[derive(Clone, Component, Debug, Default, Deref, DerefMut, Reflect)]
+#[reflect(Component)]
+pub struct KnownBy(EntityHashSet);
+
 #[derive(Debug, Event)]
 pub struct KnownAdded(Entity);
 
@@ -77,25 +95,51 @@ impl ContainsEntity for KnownAdded {
 }
 
 #[derive(Event)]
-pub struct KnownRemoved(ObjectId);
+pub struct KnownRemoved {
+    entity: Entity,
+    object_id: ObjectId,
+}
+
 impl KnownRemoved {
-    pub fn new(object_id: ObjectId) -> Self {
-        Self(object_id)
+    pub fn new(entity: Entity, object_id: ObjectId) -> Self {
+        Self { entity, object_id }
     }
 
     pub fn object_id(&self) -> ObjectId {
-        self.0
+        self.object_id
+    }
+}
+
+impl ContainsEntity for KnownRemoved {
+    fn entity(&self) -> Entity {
+        self.entity
     }
 }
 
 #[derive(Event)]
-pub struct KnownEntitiesRemoved(ObjectId);
+pub struct KnownEntitiesRemoved {
+    object_id: ObjectId,
+    known_by: Vec<Entity>,
+}
+
 impl KnownEntitiesRemoved {
-    pub fn new(object_id: ObjectId) -> Self {
-        Self(object_id)
+    /// Remembers who knew the entity, it may be despawned by the time the event is handled.
+    pub fn new(object_id: ObjectId, entity: EntityRef) -> Self {
+        let known_by = entity
+            .get::<KnownBy>()
+            .map(|known_by| known_by.iter().copied().collect())
+            .unwrap_or_default();
+        Self {
+            object_id,
+            known_by,
+        }
     }
 
     pub fn object_id(&self) -> ObjectId {
-        self.0
+        self.object_id
+    }
+
+    pub fn known_by(&self) -> &[Entity] {
+        &self.known_by
     }
 }
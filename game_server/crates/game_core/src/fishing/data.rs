@@ -0,0 +1,197 @@
+use crate::items;
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_common_assets::json::JsonAssetPlugin;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use strum::Display;
+
+pub struct FishingDataComponentsPlugin;
+impl Plugin for FishingDataComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(JsonAssetPlugin::<FishingData>::new(&["json"]));
+
+        app.register_type::<FishingDataHandle>()
+            .register_type::<FishGroup>()
+            .register_type::<LureGrade>()
+            .register_type::<Lure>()
+            .register_type::<FishTemplate>()
+            .register_type::<FishLevels>();
+    }
+}
+
+#[derive(Default, Deref, DerefMut, Reflect, Resource)]
+pub struct FishingDataHandle(Handle<FishingData>);
+
+/// Fish are told apart by the lure color they prefer.
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, Hash, PartialEq, Reflect, Serialize)]
+pub enum FishGroup {
+    /// Fast fish, they take the green lures.
+    Nimble,
+    /// They take the yellow lures.
+    Ugly,
+    /// They take the purple lures.
+    Fat,
+}
+
+impl FishGroup {
+    /// Fish type shown by the client when the line is cast.
+    pub fn client_id(&self) -> u32 {
+        match self {
+            FishGroup::Nimble => 0,
+            FishGroup::Ugly => 1,
+            FishGroup::Fat => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Display, Eq, Hash, PartialEq, Reflect, Serialize)]
+pub enum LureGrade {
+    /// Only the fish intended for beginners take such a lure.
+    Beginner,
+    Low,
+    Normal,
+    High,
+}
+
+impl LureGrade {
+    /// The higher the grade of the lure, the sooner a fish takes the bait.
+    pub fn bite_time(&self) -> Duration {
+        Duration::from_secs(match self {
+            LureGrade::Beginner => 7,
+            LureGrade::Low => 12,
+            LureGrade::Normal => 9,
+            LureGrade::High => 6,
+        })
+    }
+
+    /// Lure type shown by the client in the fish combat window.
+    pub fn client_id(&self) -> u8 {
+        match self {
+            LureGrade::Beginner => 0,
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct Lure {
+    pub group: FishGroup,
+    pub grade: LureGrade,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct FishTemplate {
+    pub level: u32,
+    pub group: FishGroup,
+    pub hp: u32,
+    /// HP the fish regains every second it fights.
+    pub hp_regen: u32,
+    /// Chance in percents the fish changes its mode every second.
+    pub guts: u32,
+    /// Seconds the fish fights before it gets away.
+    pub combat_time: u32,
+}
+
+/// Levels of the fish taking the bait, both inclusive.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct FishLevels {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl FishLevels {
+    pub fn contains(&self, level: u32) -> bool {
+        (self.min..=self.max).contains(&level)
+    }
+}
+
+#[derive(Asset, Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct FishingData {
+    pub lures: HashMap<items::Id, Lure>,
+    pub fish: HashMap<items::Id, FishTemplate>,
+    /// Catch tables of the fishing zones by their names, the fish levels depend on the lure grade.
+    pub places: HashMap<String, HashMap<LureGrade, FishLevels>>,
+}
+
+impl FishingData {
+    pub fn lure(&self, item_id: items::Id) -> Option<&Lure> {
+        self.lures.get(&item_id)
+    }
+
+    pub fn fish(&self, item_id: items::Id) -> Option<&FishTemplate> {
+        self.fish.get(&item_id)
+    }
+
+    /// Fish that take the lure at the fishing place, sorted by the item id.
+    pub fn catch_table(&self, place: &str, lure: &Lure) -> Vec<items::Id> {
+        let Some(levels) = self
+            .places
+            .get(place)
+            .and_then(|grades| grades.get(&lure.grade))
+        else {
+            return Vec::new();
+        };
+
+        let mut table = self
+            .fish
+            .iter()
+            .filter(|(_, fish)| fish.group == lure.group && levels.contains(fish.level))
+            .map(|(item_id, _)| *item_id)
+            .collect::<Vec<_>>();
+        table.sort();
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn test_data_json() -> &'static str {
+        r#"{
+            "lures": {
+                "6519": { "group": "Nimble", "grade": "Low" },
+                "7808": { "group": "Fat", "grade": "Beginner" }
+            },
+            "fish": {
+                "6411": { "level": 1, "group": "Nimble", "hp": 150, "hp_regen": 5, "guts": 20, "combat_time": 25 },
+                "6413": { "level": 1, "group": "Fat", "hp": 180, "hp_regen": 4, "guts": 15, "combat_time": 25 },
+                "6414": { "level": 2, "group": "Nimble", "hp": 180, "hp_regen": 6, "guts": 20, "combat_time": 26 },
+                "6417": { "level": 3, "group": "Nimble", "hp": 210, "hp_regen": 6, "guts": 20, "combat_time": 27 }
+            },
+            "places": {
+                "fishing_place_13": {
+                    "Beginner": { "min": 1, "max": 1 },
+                    "Low": { "min": 1, "max": 2 }
+                }
+            }
+        }"#
+    }
+
+    #[test]
+    fn test_catch_table_by_lure() {
+        let data: FishingData =
+            serde_json::from_str(test_data_json()).expect("Failed to deserialize FishingData");
+
+        let low_green = data.lure(6519.into()).unwrap();
+        assert_eq!(
+            data.catch_table("fishing_place_13", low_green),
+            vec![6411.into(), 6414.into()]
+        );
+
+        let beginner_purple = data.lure(7808.into()).unwrap();
+        assert_eq!(
+            data.catch_table("fishing_place_13", beginner_purple),
+            vec![6413.into()]
+        );
+    }
+
+    #[test]
+    fn test_catch_table_unknown_place() {
+        let data: FishingData =
+            serde_json::from_str(test_data_json()).expect("Failed to deserialize FishingData");
+
+        let low_green = data.lure(6519.into()).unwrap();
+        assert!(data.catch_table("fishing_place_1", low_green).is_empty());
+    }
+}
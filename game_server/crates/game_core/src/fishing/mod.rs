@@ -0,0 +1,358 @@
+use crate::{
+    items,
+    skills::{self, Skill},
+};
+use bevy::prelude::*;
+use std::time::Duration;
+
+mod data;
+
+pub use data::*;
+
+pub struct FishingComponentsPlugin;
+impl Plugin for FishingComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(FishingDataComponentsPlugin);
+
+        app.register_type::<FishingSkillUse>()
+            .register_type::<Fishing>()
+            .register_type::<FishCombat>()
+            .register_type::<FishMode>();
+    }
+}
+
+/// Casts the line, the fish bites a while later.
+pub const FISHING_SKILL: skills::Id = skills::Id::new(1312);
+/// Deals damage to the fighting fish.
+pub const PUMPING_SKILL: skills::Id = skills::Id::new(1313);
+/// Deals damage to the resting fish.
+pub const REELING_SKILL: skills::Id = skills::Id::new(1314);
+/// Pumping and reeling levels above it by the penalty gap deal only half of the damage.
+pub const EXPERTISE_SKILL: skills::Id = skills::Id::new(1315);
+
+/// Pumping or reeling this many levels above the expertise is penalized.
+pub const EXPERTISE_PENALTY_GAP: u32 = 3;
+
+/// How far from the angler the bobber falls.
+pub const CAST_DISTANCE: f32 = 200.0;
+
+/// Fishing guild skill used by the character, inserted by the skill scripts once the cast is over.
+#[derive(Clone, Component, Copy, Debug, Deref, Reflect)]
+#[reflect(Component)]
+pub struct FishingSkillUse(pub Skill);
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum FishMode {
+    /// HP of the fish stays still, reeling hurts it.
+    #[default]
+    Resting,
+    /// Fish regains HP, pumping hurts it.
+    Fighting,
+}
+
+impl FishMode {
+    pub fn changed(&self) -> Self {
+        match self {
+            FishMode::Resting => FishMode::Fighting,
+            FishMode::Fighting => FishMode::Resting,
+        }
+    }
+}
+
+impl From<FishMode> for u8 {
+    fn from(mode: FishMode) -> Self {
+        match mode {
+            FishMode::Resting => 0,
+            FishMode::Fighting => 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FishingAction {
+    Pumping,
+    Reeling,
+}
+
+impl FishingAction {
+    pub fn from_skill(skill_id: skills::Id) -> Option<Self> {
+        match skill_id {
+            PUMPING_SKILL => Some(FishingAction::Pumping),
+            REELING_SKILL => Some(FishingAction::Reeling),
+            _ => None,
+        }
+    }
+
+    /// Damage dealt to the fish by the skill level, before the shots and the penalty.
+    pub fn power(level: skills::Level) -> u32 {
+        20 + 10 * (*level).saturating_sub(1)
+    }
+
+    /// Animation shown by the client in the fish combat window.
+    pub fn client_id(&self) -> u8 {
+        match self {
+            FishingAction::Reeling => 1,
+            FishingAction::Pumping => 2,
+        }
+    }
+
+    fn hurts(&self, mode: FishMode) -> bool {
+        matches!(
+            (self, mode),
+            (FishingAction::Pumping, FishMode::Fighting)
+                | (FishingAction::Reeling, FishMode::Resting)
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FishingHit {
+    /// Fish resisted, nothing happened.
+    Resisted,
+    /// Fish lost the HP.
+    Damaged(u32),
+    /// Wrong skill for the mode of the fish, it regained the HP.
+    Regained(u32),
+}
+
+impl FishingHit {
+    /// Result shown by the client in the fish combat window.
+    pub fn client_id(&self) -> u8 {
+        match self {
+            FishingHit::Resisted => 0,
+            FishingHit::Damaged(_) => 1,
+            FishingHit::Regained(_) => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FishingOutcome {
+    Caught,
+    GotAway,
+}
+
+/// Fish on the hook fighting the angler.
+#[derive(Clone, Debug, Reflect)]
+pub struct FishCombat {
+    pub fish: items::Id,
+    max_hp: u32,
+    hp: u32,
+    hp_regen: u32,
+    guts: u32,
+    mode: FishMode,
+    time_left: u32,
+    tick: Timer,
+}
+
+impl FishCombat {
+    pub fn new(fish: items::Id, template: &FishTemplate) -> Self {
+        Self {
+            fish,
+            max_hp: template.hp,
+            hp: template.hp,
+            hp_regen: template.hp_regen,
+            guts: template.guts,
+            mode: FishMode::default(),
+            time_left: template.combat_time,
+            tick: Timer::new(Duration::from_secs(1), TimerMode::Repeating),
+        }
+    }
+
+    pub fn hp(&self) -> u32 {
+        self.hp
+    }
+
+    pub fn mode(&self) -> FishMode {
+        self.mode
+    }
+
+    /// Seconds before the fish gets away.
+    pub fn time_left(&self) -> u32 {
+        self.time_left
+    }
+
+    /// Returns the number of the combat seconds passed.
+    pub fn update(&mut self, delta: Duration) -> u32 {
+        self.tick.tick(delta);
+        self.tick.times_finished_this_tick()
+    }
+
+    /// One second of the combat, the fish changes its mode if the roll is below its guts.
+    pub fn second_passed(&mut self, roll: u32) -> Option<FishingOutcome> {
+        self.time_left = self.time_left.saturating_sub(1);
+        if self.mode == FishMode::Fighting {
+            self.hp = (self.hp + self.hp_regen).min(self.max_hp * 2);
+        }
+
+        // The fish is too strong or the angler is too slow
+        if self.hp >= self.max_hp * 2 || self.time_left == 0 {
+            return Some(FishingOutcome::GotAway);
+        }
+
+        if roll < self.guts {
+            self.mode = self.mode.changed();
+        }
+        None
+    }
+
+    /// Pumps or reels the fish in, the resisting fish ignores the try.
+    pub fn hit(
+        &mut self,
+        action: FishingAction,
+        damage: u32,
+        resisted: bool,
+    ) -> (FishingHit, Option<FishingOutcome>) {
+        if resisted {
+            return (FishingHit::Resisted, None);
+        }
+
+        if !action.hurts(self.mode) {
+            self.hp = (self.hp + damage).min(self.max_hp * 2);
+            let outcome = (self.hp >= self.max_hp * 2).then_some(FishingOutcome::GotAway);
+            return (FishingHit::Regained(damage), outcome);
+        }
+
+        self.hp = self.hp.saturating_sub(damage);
+        let outcome = (self.hp == 0).then_some(FishingOutcome::Caught);
+        (FishingHit::Damaged(damage), outcome)
+    }
+}
+
+/// Angler with the line cast, waiting for a bite or fighting the fish.
+#[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Fishing {
+    pub lure: items::Id,
+    pub lure_grade: LureGrade,
+    pub group: FishGroup,
+    /// Where the bobber floats.
+    pub bobber: Vec3,
+    /// Fish taking the bait, none if nothing in the place likes the lure.
+    bite: Option<items::Id>,
+    wait: Timer,
+    combat: Option<FishCombat>,
+}
+
+impl Fishing {
+    pub fn new(lure: items::Id, lure_data: &Lure, bobber: Vec3, bite: Option<items::Id>) -> Self {
+        Self {
+            lure,
+            lure_grade: lure_data.grade,
+            group: lure_data.group,
+            bobber,
+            bite,
+            wait: Timer::new(lure_data.grade.bite_time(), TimerMode::Once),
+            combat: None,
+        }
+    }
+
+    pub fn combat(&self) -> Option<&FishCombat> {
+        self.combat.as_ref()
+    }
+
+    pub fn combat_mut(&mut self) -> Option<&mut FishCombat> {
+        self.combat.as_mut()
+    }
+
+    pub fn bite(&self) -> Option<items::Id> {
+        self.bite
+    }
+
+    /// Waits for the bite, true once the wait is over.
+    pub fn wait(&mut self, delta: Duration) -> bool {
+        if self.combat.is_some() {
+            return false;
+        }
+        self.wait.tick(delta);
+        self.wait.just_finished()
+    }
+
+    pub fn hook(&mut self, template: &FishTemplate) {
+        if let Some(fish) = self.bite {
+            self.combat = Some(FishCombat::new(fish, template));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template() -> FishTemplate {
+        FishTemplate {
+            level: 1,
+            group: FishGroup::Nimble,
+            hp: 100,
+            hp_regen: 10,
+            guts: 30,
+            combat_time: 20,
+        }
+    }
+
+    #[test]
+    fn test_reeling_resting_fish() {
+        let mut combat = FishCombat::new(6411.into(), &template());
+
+        assert_eq!(
+            combat.hit(FishingAction::Reeling, 60, false),
+            (FishingHit::Damaged(60), None)
+        );
+        assert_eq!(
+            combat.hit(FishingAction::Reeling, 60, false),
+            (FishingHit::Damaged(60), Some(FishingOutcome::Caught))
+        );
+        assert_eq!(combat.hp(), 0);
+    }
+
+    #[test]
+    fn test_pumping_resting_fish() {
+        let mut combat = FishCombat::new(6411.into(), &template());
+
+        assert_eq!(
+            combat.hit(FishingAction::Pumping, 30, false),
+            (FishingHit::Regained(30), None)
+        );
+        assert_eq!(combat.hp(), 130);
+        assert_eq!(
+            combat.hit(FishingAction::Pumping, 30, true),
+            (FishingHit::Resisted, None)
+        );
+        assert_eq!(combat.hp(), 130);
+    }
+
+    #[test]
+    fn test_fighting_fish_regains_hp() {
+        let mut combat = FishCombat::new(6411.into(), &template());
+
+        // Low roll changes the mode
+        assert_eq!(combat.second_passed(0), None);
+        assert_eq!(combat.mode(), FishMode::Fighting);
+
+        assert_eq!(combat.second_passed(99), None);
+        assert_eq!(combat.hp(), 110);
+        assert_eq!(combat.mode(), FishMode::Fighting);
+
+        assert_eq!(
+            combat.hit(FishingAction::Pumping, 50, false),
+            (FishingHit::Damaged(50), None)
+        );
+        assert_eq!(combat.hp(), 60);
+    }
+
+    #[test]
+    fn test_fish_gets_away() {
+        let mut combat = FishCombat::new(6411.into(), &template());
+        combat.second_passed(0);
+
+        assert_eq!(
+            combat.hit(FishingAction::Reeling, 100, false),
+            (FishingHit::Regained(100), Some(FishingOutcome::GotAway))
+        );
+
+        let mut combat = FishCombat::new(6411.into(), &template());
+        let outcome = (0..20).find_map(|_| combat.second_passed(99));
+        assert_eq!(outcome, Some(FishingOutcome::GotAway));
+        assert_eq!(combat.time_left(), 0);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use thiserror::Error;
+
+pub mod model;
+
+pub struct FriendComponentsPlugin;
+impl Plugin for FriendComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Friends>()
+            .register_type::<BlockList>()
+            .register_type::<FriendInvite>()
+            .register_type::<model::Model>();
+    }
+}
+
+pub const FRIEND_LIST_LIMIT: usize = 128;
+pub const BLOCK_LIST_LIMIT: usize = 128;
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RelationEntry {
+    pub id: ObjectId,
+    pub name: String,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum RelationError {
+    #[error("Character is already in the list")]
+    AlreadyAdded,
+    #[error("List is full")]
+    ListIsFull,
+}
+
+/// Ordered list of characters with a name for each of them, used by both friend and block lists.
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+pub struct RelationList(Vec<RelationEntry>);
+
+impl RelationList {
+    pub fn contains(&self, id: ObjectId) -> bool {
+        self.0.iter().any(|entry| entry.id == id)
+    }
+
+    pub fn by_name(&self, name: &str) -> Option<&RelationEntry> {
+        self.0
+            .iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RelationEntry> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn add(&mut self, entry: RelationEntry, limit: usize) -> Result<(), RelationError> {
+        if self.contains(entry.id) {
+            return Err(RelationError::AlreadyAdded);
+        }
+        if self.0.len() >= limit {
+            return Err(RelationError::ListIsFull);
+        }
+        self.0.push(entry);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, id: ObjectId) -> Option<RelationEntry> {
+        let index = self.0.iter().position(|entry| entry.id == id)?;
+        Some(self.0.remove(index))
+    }
+}
+
+/// Friends of the character, the relation is mutual and stored for both sides.
+#[derive(Clone, Component, Debug, Default, Deref, DerefMut, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Friends(RelationList);
+
+impl Friends {
+    pub fn add(&mut self, entry: RelationEntry) -> Result<(), RelationError> {
+        self.0.add(entry, FRIEND_LIST_LIMIT)
+    }
+}
+
+/// Characters whose whispers, trade and party requests are refused.
+#[derive(Clone, Component, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct BlockList {
+    pub blocked: RelationList,
+    /// Refuse everyone, regardless of the list.
+    pub block_all: bool,
+}
+
+impl BlockList {
+    pub fn add(&mut self, entry: RelationEntry) -> Result<(), RelationError> {
+        self.blocked.add(entry, BLOCK_LIST_LIMIT)
+    }
+
+    /// Whether the character refuses whispers and requests from the given one.
+    pub fn blocks(&self, id: ObjectId) -> bool {
+        self.block_all || self.blocked.contains(id)
+    }
+}
+
+/// Pending friend invitation, inserted on the invited character until the answer comes.
+#[derive(Clone, Component, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct FriendInvite {
+    pub requestor: Entity,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: u32, name: &str) -> RelationEntry {
+        RelationEntry {
+            id: ObjectId::from(id),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_add_rejects_duplicates() {
+        let mut friends = Friends::default();
+        assert_eq!(friends.add(entry(1, "Alice")), Ok(()));
+        assert_eq!(
+            friends.add(entry(1, "Alice")),
+            Err(RelationError::AlreadyAdded)
+        );
+        assert_eq!(friends.len(), 1);
+        assert_eq!(
+            friends.by_name("alice").map(|entry| entry.id),
+            Some(ObjectId::from(1u32))
+        );
+    }
+
+    #[test]
+    fn test_add_respects_limit() {
+        let mut friends = Friends::default();
+        for id in 0..FRIEND_LIST_LIMIT as u32 {
+            assert_eq!(friends.add(entry(id, "Friend")), Ok(()));
+        }
+        assert_eq!(
+            friends.add(entry(1000, "Extra")),
+            Err(RelationError::ListIsFull)
+        );
+    }
+
+    #[test]
+    fn test_block_all() {
+        let mut block_list = BlockList::default();
+        block_list.add(entry(1, "Spammer")).unwrap();
+        assert!(block_list.blocks(ObjectId::from(1u32)));
+        assert!(!block_list.blocks(ObjectId::from(2u32)));
+
+        block_list.block_all = true;
+        assert!(block_list.blocks(ObjectId::from(2u32)));
+
+        block_list.block_all = false;
+        assert!(block_list.blocked.remove(ObjectId::from(1u32)).is_some());
+        assert!(!block_list.blocks(ObjectId::from(1u32)));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{character, object_id::ObjectId};
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::{Condition, entity::prelude::*, sea_query::SimpleExpr};
+
+pub type CharacterFriendsRepository = DbRepository<FriendPK, Entity>;
+
+/// Relation of the character to another one, `blocked` rows belong to the block list.
+#[derive(Clone, Copy, Debug, DeriveEntityModel, PartialEq, Reflect)]
+#[sea_orm(table_name = "character_friends")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub char_id: ObjectId,
+    #[sea_orm(primary_key)]
+    pub friend_id: ObjectId,
+    #[sea_orm(primary_key)]
+    pub blocked: bool,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CharId, Column::FriendId, Column::Blocked]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::Blocked]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::CharId",
+        to = "character::model::Column::Id"
+    )]
+    Character,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FriendPK {
+    pub char_id: ObjectId,
+    pub friend_id: ObjectId,
+    pub blocked: bool,
+}
+
+impl From<&Model> for FriendPK {
+    fn from(model: &Model) -> Self {
+        FriendPK {
+            char_id: model.char_id,
+            friend_id: model.friend_id,
+            blocked: model.blocked,
+        }
+    }
+}
+
+impl From<FriendPK> for Condition {
+    fn from(pk: FriendPK) -> Self {
+        Condition::all()
+            .add(Column::CharId.eq(pk.char_id))
+            .add(Column::FriendId.eq(pk.friend_id))
+            .add(Column::Blocked.eq(pk.blocked))
+    }
+}
+
+impl From<FriendPK> for SimpleExpr {
+    fn from(value: FriendPK) -> Self {
+        Column::CharId
+            .eq(value.char_id)
+            .and(Column::FriendId.eq(value.friend_id))
+            .and(Column::Blocked.eq(value.blocked))
+    }
+}
+
+impl From<FriendPK> for (ObjectId, ObjectId, bool) {
+    fn from(pk: FriendPK) -> Self {
+        (pk.char_id, pk.friend_id, pk.blocked)
+    }
+}
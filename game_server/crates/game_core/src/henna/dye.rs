@@ -0,0 +1,65 @@
+use crate::{
+    items,
+    stats::{ClassId, PrimalStat},
+};
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_common_assets::json::JsonAssetPlugin;
+use derive_more::From;
+use serde::{Deserialize, Serialize};
+
+pub struct HennaDyesComponentsPlugin;
+impl Plugin for HennaDyesComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(JsonAssetPlugin::<HennaDyes>::new(&["json"]));
+
+        app.register_type::<HennaDyesHandle>()
+            .register_type::<Dye>()
+            .register_type::<DyeCost>();
+    }
+}
+
+#[derive(Default, Deref, DerefMut, Reflect, Resource)]
+pub struct HennaDyesHandle(Handle<HennaDyes>);
+
+#[derive(Asset, Clone, Debug, Deref, Deserialize, From, PartialEq, Reflect, Serialize)]
+pub struct HennaDyes(HashMap<super::Id, Dye>);
+impl HennaDyes {
+    /// Symbols which can be drawn by the given class, ordered by id.
+    pub fn for_class(&self, class_id: ClassId) -> Vec<(super::Id, &Dye)> {
+        let mut dyes = self
+            .0
+            .iter()
+            .filter(|(_, dye)| dye.allowed_for(class_id))
+            .map(|(id, dye)| (*id, dye))
+            .collect::<Vec<_>>();
+        dyes.sort_by_key(|(id, _)| *id);
+        dyes
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct Dye {
+    pub name: String,
+    pub item_id: items::Id,
+    pub stats: HashMap<PrimalStat, i32>,
+    pub wear: DyeCost,
+    pub cancel: DyeCost,
+    pub classes: Vec<ClassId>,
+}
+
+impl Dye {
+    pub fn allowed_for(&self, class_id: ClassId) -> bool {
+        self.classes.contains(&class_id)
+    }
+
+    pub fn stat(&self, stat: PrimalStat) -> i32 {
+        self.stats.get(&stat).copied().unwrap_or_default()
+    }
+}
+
+/// Amount of dyes and adena taken by the symbol maker to draw or remove a symbol.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Reflect, Serialize)]
+pub struct DyeCost {
+    pub count: u64,
+    pub fee: u64,
+}
@@ -0,0 +1,187 @@
+use crate::stats::{PrimalStat, StatKind, StatModifier, StatModifiers, StatsOperation};
+use bevy::{platform::collections::HashMap, prelude::*};
+use strum::IntoEnumIterator;
+
+mod dye;
+mod id;
+pub mod model;
+
+pub use dye::*;
+pub use id::*;
+
+pub struct HennaComponentsPlugin;
+impl Plugin for HennaComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HennaDyesComponentsPlugin);
+
+        app.register_type::<Id>()
+            .register_type::<Hennas>()
+            .register_type::<model::Model>();
+    }
+}
+
+pub const HENNA_SLOTS: usize = 3;
+/// Summary bonus of all drawn symbols can't exceed this value for a single stat.
+pub const MAX_HENNA_STAT_BONUS: i32 = 5;
+const HENNA_MODIFIER_SOURCE: &str = "henna:";
+
+/// Symbols drawn on the character, by slot.
+#[derive(Clone, Component, Copy, Debug, Default, Deref, DerefMut, PartialEq, Reflect)]
+pub struct Hennas([Option<Id>; HENNA_SLOTS]);
+impl Hennas {
+    /// Slots become available with class transfers: two after the first one and all after the second.
+    pub fn available_slots(profession_level: u8) -> usize {
+        match profession_level {
+            0 => 0,
+            1 => 2,
+            _ => HENNA_SLOTS,
+        }
+    }
+
+    pub fn free_slot(&self, available_slots: usize) -> Option<usize> {
+        self.0
+            .iter()
+            .take(available_slots)
+            .position(|slot| slot.is_none())
+    }
+
+    pub fn free_slots(&self, available_slots: usize) -> usize {
+        self.0
+            .iter()
+            .take(available_slots)
+            .filter(|slot| slot.is_none())
+            .count()
+    }
+
+    pub fn slot_of(&self, id: Id) -> Option<usize> {
+        self.0.iter().position(|slot| *slot == Some(id))
+    }
+
+    pub fn equipped(&self) -> impl Iterator<Item = (usize, Id)> + '_ {
+        self.0
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, id)| id.map(|id| (slot, id)))
+    }
+
+    pub fn set(&mut self, slot: usize, id: Option<Id>) {
+        if let Some(entry) = self.0.get_mut(slot) {
+            *entry = id;
+        }
+    }
+
+    /// Summary bonus of all drawn symbols, positive part is capped by [`MAX_HENNA_STAT_BONUS`].
+    pub fn stat_bonuses(&self, dyes: &HennaDyes) -> HashMap<PrimalStat, i32> {
+        let mut bonuses = HashMap::new();
+        for (_, id) in self.equipped() {
+            let Some(dye) = dyes.get(&id) else {
+                continue;
+            };
+            for (stat, value) in dye.stats.iter() {
+                *bonuses.entry(*stat).or_insert(0) += *value;
+            }
+        }
+        for value in bonuses.values_mut() {
+            *value = (*value).min(MAX_HENNA_STAT_BONUS);
+        }
+        bonuses
+    }
+
+    pub fn stat_bonus(&self, dyes: &HennaDyes, stat: PrimalStat) -> i32 {
+        self.stat_bonuses(dyes)
+            .get(&stat)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Replaces previous symbol modifiers with the current ones.
+    pub fn apply_modifiers(&self, dyes: &HennaDyes, modifiers: &mut StatModifiers) {
+        modifiers.remove_modifier_contains(HENNA_MODIFIER_SOURCE);
+        let bonuses = self.stat_bonuses(dyes);
+        for stat in PrimalStat::iter() {
+            let Some(value) = bonuses.get(&stat).copied().filter(|value| *value != 0) else {
+                continue;
+            };
+            modifiers.add_modifier(
+                format!("{HENNA_MODIFIER_SOURCE}{stat:?}").to_lowercase(),
+                StatModifier {
+                    stat: StatKind::Primal(stat),
+                    operation: StatsOperation::Add(value as f32),
+                    priority: 0,
+                },
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{items, stats::ClassId};
+
+    fn dye(stats: &[(PrimalStat, i32)]) -> Dye {
+        Dye {
+            name: "Test Symbol".to_string(),
+            item_id: items::Id::new(4445),
+            stats: stats.iter().copied().collect(),
+            wear: DyeCost::default(),
+            cancel: DyeCost::default(),
+            classes: vec![ClassId::Warrior],
+        }
+    }
+
+    fn dyes() -> HennaDyes {
+        HennaDyes::from(HashMap::from([
+            (
+                Id::from(1u32),
+                dye(&[(PrimalStat::STR, 4), (PrimalStat::CON, -4)]),
+            ),
+            (
+                Id::from(2u32),
+                dye(&[(PrimalStat::STR, 3), (PrimalStat::DEX, -3)]),
+            ),
+        ]))
+    }
+
+    #[test]
+    fn test_free_slot_respects_available_slots() {
+        let mut hennas = Hennas::default();
+        assert_eq!(hennas.free_slot(0), None);
+        assert_eq!(hennas.free_slot(2), Some(0));
+
+        hennas.set(0, Some(Id::from(1u32)));
+        hennas.set(1, Some(Id::from(2u32)));
+        assert_eq!(hennas.free_slot(2), None);
+        assert_eq!(hennas.free_slot(HENNA_SLOTS), Some(2));
+        assert_eq!(hennas.slot_of(Id::from(2u32)), Some(1));
+    }
+
+    #[test]
+    fn test_stat_bonuses_are_capped() {
+        let mut hennas = Hennas::default();
+        hennas.set(0, Some(Id::from(1u32)));
+        hennas.set(1, Some(Id::from(2u32)));
+
+        let dyes = dyes();
+        assert_eq!(
+            hennas.stat_bonus(&dyes, PrimalStat::STR),
+            MAX_HENNA_STAT_BONUS
+        );
+        assert_eq!(hennas.stat_bonus(&dyes, PrimalStat::CON), -4);
+        assert_eq!(hennas.stat_bonus(&dyes, PrimalStat::INT), 0);
+    }
+
+    #[test]
+    fn test_apply_modifiers_replaces_previous() {
+        let dyes = dyes();
+        let mut modifiers = StatModifiers::default();
+        let mut hennas = Hennas::default();
+        hennas.set(0, Some(Id::from(1u32)));
+        hennas.apply_modifiers(&dyes, &mut modifiers);
+        assert_eq!(modifiers.len(), 2);
+
+        hennas.set(0, None);
+        hennas.apply_modifiers(&dyes, &mut modifiers);
+        assert!(modifiers.is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{character, object_id::ObjectId, stats::SubClassVariant};
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::{Condition, entity::prelude::*, sea_query::SimpleExpr};
+
+pub type CharacterHennasRepository = DbRepository<HennaPK, Entity>;
+
+#[derive(Clone, Copy, Debug, DeriveEntityModel, PartialEq, Reflect)]
+#[sea_orm(table_name = "character_hennas")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub char_id: ObjectId,
+    #[sea_orm(primary_key)]
+    pub slot: i16,
+    #[sea_orm(primary_key)]
+    pub class_variant: SubClassVariant,
+    pub henna_id: super::Id,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CharId, Column::Slot, Column::ClassVariant]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::HennaId]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::CharId",
+        to = "character::model::Column::Id"
+    )]
+    Character,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HennaPK {
+    pub char_id: ObjectId,
+    pub slot: i16,
+    pub class_variant: SubClassVariant,
+}
+
+impl From<&Model> for HennaPK {
+    fn from(model: &Model) -> Self {
+        HennaPK {
+            char_id: model.char_id,
+            slot: model.slot,
+            class_variant: model.class_variant,
+        }
+    }
+}
+
+impl From<HennaPK> for Condition {
+    fn from(pk: HennaPK) -> Self {
+        Condition::all()
+            .add(Column::CharId.eq(pk.char_id))
+            .add(Column::Slot.eq(pk.slot))
+            .add(Column::ClassVariant.eq(pk.class_variant))
+    }
+}
+
+impl From<HennaPK> for SimpleExpr {
+    fn from(value: HennaPK) -> Self {
+        Column::CharId
+            .eq(value.char_id)
+            .and(Column::Slot.eq(value.slot))
+            .and(Column::ClassVariant.eq(value.class_variant))
+    }
+}
+
+impl From<HennaPK> for (ObjectId, i16, SubClassVariant) {
+    fn from(pk: HennaPK) -> Self {
+        (pk.char_id, pk.slot, pk.class_variant)
+    }
+}
@@ -0,0 +1,71 @@
+use super::InstanceTemplateId;
+use crate::{npc, stats::Level};
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_common_assets::json::JsonAssetPlugin;
+use derive_more::From;
+use map::DoorId;
+use serde::{Deserialize, Serialize};
+use spatial::{GameVec3, Heading};
+
+pub struct InstanceTemplatesComponentsPlugin;
+impl Plugin for InstanceTemplatesComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(JsonAssetPlugin::<InstanceTemplates>::new(&["json"]));
+
+        app.register_type::<InstanceTemplatesHandle>()
+            .register_type::<InstanceTemplate>()
+            .register_type::<InstanceNpcSpawn>();
+    }
+}
+
+#[derive(Default, Deref, DerefMut, Reflect, Resource)]
+pub struct InstanceTemplatesHandle(Handle<InstanceTemplates>);
+
+#[derive(Asset, Clone, Debug, Deref, Deserialize, From, PartialEq, Reflect, Serialize)]
+pub struct InstanceTemplates(HashMap<InstanceTemplateId, InstanceTemplate>);
+
+fn default_members() -> usize {
+    1
+}
+
+/// What every running copy of the instance is made of and who may enter it.
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct InstanceTemplate {
+    pub name: String,
+    pub min_level: Level,
+    pub max_level: Level,
+    /// Party members needed to enter, one lets the characters in alone.
+    #[serde(default = "default_members")]
+    pub min_members: usize,
+    #[serde(default = "default_members")]
+    pub max_members: usize,
+    /// Minutes the instance runs.
+    pub duration: u64,
+    /// Minutes the instance is kept with nobody inside.
+    pub empty_timeout: u64,
+    /// Minutes before the character may enter a new instance of the template.
+    pub reentry_delay: u64,
+    pub enter: GameVec3,
+    /// Doors of the main world copied into the instance.
+    #[serde(default)]
+    pub doors: Vec<DoorId>,
+    /// Names of the main world zones copied into the instance.
+    #[serde(default)]
+    pub zones: Vec<String>,
+    #[serde(default)]
+    pub npcs: Vec<InstanceNpcSpawn>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct InstanceNpcSpawn {
+    pub id: npc::Id,
+    pub loc: GameVec3,
+    #[serde(default)]
+    pub heading: Heading,
+}
+
+impl InstanceNpcSpawn {
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(self.loc.into()).with_rotation(Quat::from(self.heading))
+    }
+}
@@ -1,5 +1,412 @@
-use bevy::prelude::*;
+use crate::{object_id::ObjectId, spawner::SpawnFolder, stats::Level, utils::ReflectableDateTime};
+use bevy::{platform::collections::HashMap, prelude::*};
+use chrono::{NaiveDateTime, TimeDelta};
 use derive_more::{From, Into};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
 
-#[derive(Clone, Copy, Debug, Deref, Eq, From, Hash, Into, PartialEq, Reflect)]
+mod data;
+pub mod model;
+
+pub use data::*;
+
+pub struct InstanceZoneComponentsPlugin;
+impl Plugin for InstanceZoneComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(InstanceTemplatesComponentsPlugin);
+
+        app.register_type::<InstanceZoneId>()
+            .register_type::<InstanceTemplateId>()
+            .register_type::<InstanceZone>()
+            .register_type::<InstanceExit>()
+            .register_type::<InstanceZones>()
+            .register_type::<model::Model>();
+
+        app.add_event::<EnterInstanceZone>()
+            .add_event::<LeaveInstanceZone>();
+    }
+}
+
+/// Minutes before the end of the instance its members are warned at.
+pub const END_ANNOUNCEMENTS: [i64; 3] = [5, 3, 1];
+
+/// Running copy of an instance template. Entities inside of the instance carry its id,
+/// they know and hear only each other, the main world entities have no id at all.
+#[derive(Clone, Component, Copy, Debug, Deref, Eq, From, Hash, Into, PartialEq, Reflect)]
+#[reflect(Component)]
 pub struct InstanceZoneId(u32);
+
+impl fmt::Display for InstanceZoneId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deref,
+    Deserialize,
+    Eq,
+    From,
+    Hash,
+    Into,
+    PartialEq,
+    Reflect,
+    Serialize,
+)]
+pub struct InstanceTemplateId(u32);
+
+impl fmt::Display for InstanceTemplateId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Root of the running instance, copied doors, zones and NPCs are its children
+/// and are despawned together with it.
+#[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component)]
+#[require(SpawnFolder)]
+pub struct InstanceZone {
+    pub id: InstanceZoneId,
+    pub template_id: InstanceTemplateId,
+    /// Characters let in, they may come back while the instance runs.
+    pub members: Vec<ObjectId>,
+    pub ends_at: ReflectableDateTime,
+    /// Set once the last character leaves, the instance is closed at this time.
+    pub closes_at: Option<ReflectableDateTime>,
+    empty_timeout: u64,
+    announced: Option<i64>,
+}
+
+impl InstanceZone {
+    pub fn new(
+        id: InstanceZoneId,
+        template_id: InstanceTemplateId,
+        template: &InstanceTemplate,
+        members: Vec<ObjectId>,
+        now: NaiveDateTime,
+    ) -> Self {
+        Self {
+            id,
+            template_id,
+            members,
+            ends_at: ReflectableDateTime::new(now + TimeDelta::minutes(template.duration as i64)),
+            closes_at: None,
+            empty_timeout: template.empty_timeout,
+            announced: None,
+        }
+    }
+
+    pub fn member(&self, char_id: ObjectId) -> bool {
+        self.members.contains(&char_id)
+    }
+
+    /// Keeps track of the time the instance is empty, returns whether it has to be closed.
+    pub fn update(&mut self, occupied: bool, now: NaiveDateTime) -> bool {
+        if occupied {
+            self.closes_at = None;
+        } else if self.closes_at.is_none() {
+            self.closes_at = Some(ReflectableDateTime::new(
+                now + TimeDelta::minutes(self.empty_timeout as i64),
+            ));
+        }
+        now >= *self.ends_at.as_ref()
+            || self
+                .closes_at
+                .is_some_and(|closes_at| now >= *closes_at.as_ref())
+    }
+
+    /// Minutes left to warn the members about, each of [`END_ANNOUNCEMENTS`] is told once.
+    pub fn end_announcement(&mut self, now: NaiveDateTime) -> Option<i64> {
+        let minutes_left = ((*self.ends_at.as_ref() - now).num_seconds() + 59) / 60;
+        if END_ANNOUNCEMENTS.contains(&minutes_left) && self.announced != Some(minutes_left) {
+            self.announced = Some(minutes_left);
+            return Some(minutes_left);
+        }
+        None
+    }
+}
+
+/// Where the character is returned to once it leaves the instance. It is also the position
+/// saved for the character, so nobody logs in inside of an instance that no longer runs.
+#[derive(Clone, Component, Copy, Debug, Deref, From, Reflect)]
+#[reflect(Component)]
+pub struct InstanceExit(Vec3);
+
+/// Character asks to enter the instance of the template, together with its party.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct EnterInstanceZone(pub InstanceTemplateId);
+
+/// Character is moved out of the instance it is in.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct LeaveInstanceZone;
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum InstanceZoneError {
+    #[error("Instance template {0} is not known")]
+    UnknownTemplate(InstanceTemplateId),
+    #[error("Only a party may enter")]
+    NotInParty,
+    #[error("Party has more members than may enter")]
+    PartyTooLarge,
+    #[error("Level of the character {0} does not match")]
+    Level(ObjectId),
+    #[error("Character {0} may not re-enter yet")]
+    Reentry(ObjectId),
+}
+
+/// Re-entry times of the characters, inserted once loaded from the database.
+/// Running instances live in the world as [`InstanceZone`] entities.
+#[derive(Clone, Debug, Default, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct InstanceZones {
+    last_id: u32,
+    reentries: HashMap<ObjectId, HashMap<InstanceTemplateId, ReflectableDateTime>>,
+}
+
+impl InstanceZones {
+    pub fn new(reentries: impl IntoIterator<Item = model::Model>) -> Self {
+        let mut zones = Self::default();
+        for model in reentries {
+            zones.reentries.entry(model.char_id).or_default().insert(
+                InstanceTemplateId(model.template_id as u32),
+                model.reenter_at,
+            );
+        }
+        zones
+    }
+
+    pub fn next_id(&mut self) -> InstanceZoneId {
+        self.last_id += 1;
+        InstanceZoneId(self.last_id)
+    }
+
+    /// Time the character may enter a new instance of the template.
+    pub fn reenter_at(
+        &self,
+        char_id: ObjectId,
+        template_id: InstanceTemplateId,
+    ) -> Option<NaiveDateTime> {
+        self.reentries
+            .get(&char_id)
+            .and_then(|reentries| reentries.get(&template_id))
+            .map(|reenter_at| *reenter_at.as_ref())
+    }
+
+    /// Checks if the characters with their levels may enter a new instance of the template together.
+    pub fn check_entry(
+        &self,
+        template_id: InstanceTemplateId,
+        template: &InstanceTemplate,
+        entrants: &[(ObjectId, Level)],
+        now: NaiveDateTime,
+    ) -> Result<(), InstanceZoneError> {
+        if entrants.len() < template.min_members {
+            return Err(InstanceZoneError::NotInParty);
+        }
+        if entrants.len() > template.max_members {
+            return Err(InstanceZoneError::PartyTooLarge);
+        }
+        for (char_id, level) in entrants {
+            if *level < template.min_level || *level > template.max_level {
+                return Err(InstanceZoneError::Level(*char_id));
+            }
+            if self
+                .reenter_at(*char_id, template_id)
+                .is_some_and(|reenter_at| now < reenter_at)
+            {
+                return Err(InstanceZoneError::Reentry(*char_id));
+            }
+        }
+        Ok(())
+    }
+
+    /// Starts the re-entry delay for the characters who entered the template,
+    /// returns the models to be saved.
+    pub fn lock_reentry(
+        &mut self,
+        template_id: InstanceTemplateId,
+        template: &InstanceTemplate,
+        char_ids: &[ObjectId],
+        now: NaiveDateTime,
+    ) -> Vec<model::Model> {
+        let reenter_at =
+            ReflectableDateTime::new(now + TimeDelta::minutes(template.reentry_delay as i64));
+        char_ids
+            .iter()
+            .map(|char_id| {
+                self.reentries
+                    .entry(*char_id)
+                    .or_default()
+                    .insert(template_id, reenter_at);
+                model::Model {
+                    char_id: *char_id,
+                    template_id: *template_id as i32,
+                    reenter_at,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use spatial::GameVec3;
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 5, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    fn template() -> InstanceTemplate {
+        InstanceTemplate {
+            name: "Hall of the Abyss".to_string(),
+            min_level: Level::from(18u32),
+            max_level: Level::from(28u32),
+            min_members: 1,
+            max_members: 2,
+            duration: 30,
+            empty_timeout: 5,
+            reentry_delay: 60,
+            enter: GameVec3::default(),
+            doors: vec![],
+            zones: vec![],
+            npcs: vec![],
+        }
+    }
+
+    #[test]
+    fn test_entry_checks() {
+        let template_id = InstanceTemplateId(57);
+        let template = template();
+        let zones = InstanceZones::default();
+        let char_id = ObjectId::from(1u32);
+
+        assert_eq!(
+            zones.check_entry(template_id, &template, &[], now()),
+            Err(InstanceZoneError::NotInParty)
+        );
+        assert_eq!(
+            zones.check_entry(
+                template_id,
+                &template,
+                &[(char_id, Level::from(20u32)); 3],
+                now()
+            ),
+            Err(InstanceZoneError::PartyTooLarge)
+        );
+        assert_eq!(
+            zones.check_entry(
+                template_id,
+                &template,
+                &[(char_id, Level::from(30u32))],
+                now()
+            ),
+            Err(InstanceZoneError::Level(char_id))
+        );
+        assert_eq!(
+            zones.check_entry(
+                template_id,
+                &template,
+                &[(char_id, Level::from(20u32))],
+                now()
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_reentry_delay() {
+        let template_id = InstanceTemplateId(57);
+        let template = template();
+        let mut zones = InstanceZones::default();
+        let char_id = ObjectId::from(1u32);
+        let entrants = [(char_id, Level::from(20u32))];
+
+        let models = zones.lock_reentry(template_id, &template, &[char_id], now());
+        assert_eq!(models.len(), 1);
+        assert_eq!(
+            zones.check_entry(template_id, &template, &entrants, now()),
+            Err(InstanceZoneError::Reentry(char_id))
+        );
+        assert_eq!(
+            zones.check_entry(
+                InstanceTemplateId(58),
+                &template,
+                &entrants,
+                now() + TimeDelta::minutes(1)
+            ),
+            Ok(())
+        );
+
+        let zones = InstanceZones::new(models);
+        assert_eq!(
+            zones.check_entry(
+                template_id,
+                &template,
+                &entrants,
+                now() + TimeDelta::minutes(60)
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_instance_closes() {
+        let template = template();
+        let mut zone = InstanceZone::new(
+            InstanceZoneId(1),
+            InstanceTemplateId(57),
+            &template,
+            vec![],
+            now(),
+        );
+
+        assert!(!zone.update(true, now()));
+        assert!(!zone.update(false, now()));
+        assert!(!zone.update(true, now() + TimeDelta::minutes(6)));
+        assert!(!zone.update(false, now() + TimeDelta::minutes(6)));
+        assert!(zone.update(false, now() + TimeDelta::minutes(11)));
+
+        assert!(zone.update(true, now() + TimeDelta::minutes(30)));
+    }
+
+    #[test]
+    fn test_end_announcements() {
+        let template = template();
+        let mut zone = InstanceZone::new(
+            InstanceZoneId(1),
+            InstanceTemplateId(57),
+            &template,
+            vec![],
+            now(),
+        );
+
+        assert_eq!(zone.end_announcement(now() + TimeDelta::minutes(20)), None);
+        assert_eq!(
+            zone.end_announcement(now() + TimeDelta::minutes(25)),
+            Some(5)
+        );
+        assert_eq!(
+            zone.end_announcement(now() + TimeDelta::seconds(25 * 60 + 1)),
+            None
+        );
+        assert_eq!(
+            zone.end_announcement(now() + TimeDelta::seconds(28 * 60 + 30)),
+            None
+        );
+        assert_eq!(
+            zone.end_announcement(now() + TimeDelta::minutes(29)),
+            Some(1)
+        );
+    }
+}
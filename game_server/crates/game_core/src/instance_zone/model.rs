@@ -0,0 +1,90 @@
+use crate::{character, object_id::ObjectId, utils::ReflectableDateTime};
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::{Condition, entity::prelude::*, sea_query::SimpleExpr};
+
+pub type CharacterInstanceTimesRepository = DbRepository<InstanceTimePK, Entity>;
+
+/// Time the character may enter a new instance of the template again.
+#[derive(Clone, Copy, Debug, DeriveEntityModel, PartialEq, Reflect)]
+#[sea_orm(table_name = "character_instance_times")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub char_id: ObjectId,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub template_id: i32,
+    pub reenter_at: ReflectableDateTime,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CharId, Column::TemplateId]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::ReenterAt]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::CharId",
+        to = "character::model::Column::Id"
+    )]
+    Character,
+}
+
+impl Related<character::model::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Character.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InstanceTimePK {
+    pub char_id: ObjectId,
+    pub template_id: i32,
+}
+
+impl From<&Model> for InstanceTimePK {
+    fn from(model: &Model) -> Self {
+        InstanceTimePK {
+            char_id: model.char_id,
+            template_id: model.template_id,
+        }
+    }
+}
+
+impl From<InstanceTimePK> for Condition {
+    fn from(pk: InstanceTimePK) -> Self {
+        Condition::all()
+            .add(Column::CharId.eq(pk.char_id))
+            .add(Column::TemplateId.eq(pk.template_id))
+    }
+}
+
+impl From<InstanceTimePK> for SimpleExpr {
+    fn from(value: InstanceTimePK) -> Self {
+        Column::CharId
+            .eq(value.char_id)
+            .and(Column::TemplateId.eq(value.template_id))
+    }
+}
+
+impl From<InstanceTimePK> for (ObjectId, i32) {
+    fn from(pk: InstanceTimePK) -> Self {
+        (pk.char_id, pk.template_id)
+    }
+}
@@ -31,6 +31,14 @@ pub struct DestroyItemRequest {
     pub count: u64,
 }
 
+/// Items taken out of the inventory and the items given for them, persisted as one transaction.
+#[derive(Clone, Debug, Event)]
+pub struct ExchangeItems {
+    pub kind: journal::ItemTransactionKind,
+    pub taken: Vec<(ObjectId, u64)>,
+    pub given: Vec<(Id, u64)>,
+}
+
 #[derive(Clone, Copy, Debug, Event)]
 pub struct DropIfPossible {
     pub item_oid: ObjectId,
@@ -23,7 +23,8 @@ impl Plugin for InventoryComponentsPlugin {
         app.add_event::<AddInInventory>()
             .add_event::<InventoryLoad>()
             .add_event::<DropIfPossible>()
-            .add_event::<DestroyItemRequest>();
+            .add_event::<DestroyItemRequest>()
+            .add_event::<ExchangeItems>();
     }
 }
 
@@ -298,6 +298,7 @@ impl PaperDoll {
         let desired_slot = match body_part {
             BodyPart::LeftHand => {
                 if !item_info.kind().ammo()
+                    && !item_info.kind().lure()
                     && let Some(rh_oid) = self.get(DollSlot::RightHand)
                 {
                     let rh_info = items_data.info_by_object_id(rh_oid).ok()?;
@@ -222,6 +222,11 @@ impl ItemInfo {
     }
 
     pub fn ammo_matches(&self, ammo: &Self) -> bool {
+        // Lures are of no grade, any fishing rod takes them
+        if self.kind.fishing_rod() {
+            return ammo.kind.lure();
+        }
+
         if self.grade.arrow_grade() != ammo.grade {
             return false;
         }
@@ -0,0 +1,354 @@
+use super::{Id, ItemLocationVariant, UniqueItem};
+use crate::object_id::ObjectId;
+use bevy::{platform::collections::HashMap, prelude::*};
+use chrono::{NaiveDateTime, Utc};
+use num_enum::TryFromPrimitive;
+use sea_orm::{
+    ActiveValue::Set, ConnectionTrait, DatabaseConnection, DbErr, FromJsonQueryResult,
+    IntoActiveModel, QueryOrder, TransactionTrait, entity::prelude::*, sea_query::OnConflict,
+};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+/// Database model of the journal entries
+pub mod model;
+
+#[derive(Clone, Copy, Debug, Display, EnumString, Eq, PartialEq)]
+#[strum(serialize_all = "snake_case")]
+pub enum ItemTransactionKind {
+    Create,
+    Obtain,
+    Drop,
+    Destroy,
+    Multisell,
+    Equip,
+    Unequip,
+}
+
+/// State of a single item after the transaction, a count of zero means the item is gone.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ItemDelta {
+    pub object_id: ObjectId,
+    pub item_id: Id,
+    pub owner_id: Option<ObjectId>,
+    pub count: u64,
+    pub enchant_level: i16,
+    pub location: i16,
+    pub location_data: i32,
+    pub coordinates: Option<[i32; 3]>,
+}
+
+impl ItemDelta {
+    pub fn removed(unique_item: UniqueItem) -> Self {
+        Self {
+            count: 0,
+            ..Self::from(unique_item)
+        }
+    }
+
+    pub fn is_removed(&self) -> bool {
+        self.count == 0
+    }
+
+    fn model(&self) -> Result<super::model::Model, DbErr> {
+        let location = ItemLocationVariant::try_from_primitive(self.location).map_err(|_| {
+            DbErr::Type(format!(
+                "Failed to convert {} to ItemLocation",
+                self.location
+            ))
+        })?;
+        let (x, y, z) = match self.coordinates {
+            Some([x, y, z]) => (Some(x), Some(y), Some(z)),
+            None => (None, None, None),
+        };
+        Ok(super::model::Model {
+            object_id: self.object_id,
+            owner_id: self.owner_id,
+            item_id: self.item_id,
+            count: self.count as i64,
+            enchant_level: self.enchant_level,
+            location,
+            location_data: self.location_data,
+            x,
+            y,
+            z,
+            ..Default::default()
+        })
+    }
+
+    /// Only the columns the journal tracks are overwritten, mana, time and elements are kept.
+    fn on_conflict() -> OnConflict {
+        use super::model::Column;
+
+        OnConflict::column(Column::ObjectId)
+            .update_columns([
+                Column::OwnerId,
+                Column::Count,
+                Column::EnchantLevel,
+                Column::Location,
+                Column::LocationData,
+                Column::X,
+                Column::Y,
+                Column::Z,
+            ])
+            .to_owned()
+    }
+}
+
+impl From<&super::model::Model> for ItemDelta {
+    fn from(model: &super::model::Model) -> Self {
+        Self {
+            object_id: model.object_id,
+            item_id: model.item_id,
+            owner_id: model.owner_id,
+            count: model.count(),
+            enchant_level: model.enchant_level,
+            location: model.location.into(),
+            location_data: model.location_data,
+            coordinates: model
+                .x
+                .zip(model.y)
+                .zip(model.z)
+                .map(|((x, y), z)| [x, y, z]),
+        }
+    }
+}
+
+impl From<UniqueItem> for ItemDelta {
+    fn from(unique_item: UniqueItem) -> Self {
+        Self {
+            owner_id: unique_item.item().owner(),
+            ..Self::from(&super::model::Model::from(unique_item))
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deref, Deserialize, FromJsonQueryResult, PartialEq, Serialize)]
+pub struct ItemDeltas(pub Vec<ItemDelta>);
+
+/// Inventory mutation persisted as one unit: the journal entry is written first,
+/// then all deltas are applied to the items table in a single database transaction.
+#[derive(Clone, Debug, Event)]
+pub struct ItemTransaction {
+    pub kind: ItemTransactionKind,
+    pub deltas: Vec<ItemDelta>,
+}
+
+impl ItemTransaction {
+    pub fn new(kind: ItemTransactionKind, deltas: Vec<ItemDelta>) -> Self {
+        Self { kind, deltas }
+    }
+
+    /// Writes the journal entry, the deltas are applied later by [`apply_pending`].
+    pub async fn write<C: ConnectionTrait>(self, conn: &C) -> Result<i64, DbErr> {
+        let entry = model::ActiveModel {
+            kind: Set(self.kind.to_string()),
+            deltas: Set(ItemDeltas(self.deltas)),
+            created_at: Set(Utc::now().naive_utc()),
+            applied_at: Set(None),
+            failed_at: Set(None),
+            error: Set(None),
+            ..Default::default()
+        }
+        .insert(conn)
+        .await?;
+        Ok(entry.id)
+    }
+}
+
+/// Writes the journal entries of several transactions at once, none of them is written if one fails.
+pub async fn write_batch(
+    conn: &DatabaseConnection,
+    transactions: Vec<ItemTransaction>,
+) -> Result<(), DbErr> {
+    let txn = conn.begin().await?;
+    for transaction in transactions {
+        transaction.write(&txn).await?;
+    }
+    txn.commit().await
+}
+
+/// Inserted once the entries left unapplied by the previous run are replayed.
+#[derive(Default, Resource)]
+pub struct ItemJournalReplayed;
+
+/// Entries handled by a single [`apply_pending`] run
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AppliedEntries {
+    pub applied: usize,
+    pub failed: usize,
+}
+
+async fn apply(
+    conn: &DatabaseConnection,
+    entry: &model::Model,
+    deltas: &[ItemDelta],
+) -> Result<(), DbErr> {
+    let txn = conn.begin().await?;
+    for delta in deltas {
+        if delta.is_removed() {
+            super::model::Entity::delete_by_id(delta.object_id)
+                .exec(&txn)
+                .await?;
+        } else {
+            super::model::Entity::insert(delta.model()?.into_active_model())
+                .on_conflict(ItemDelta::on_conflict())
+                .exec(&txn)
+                .await?;
+        }
+    }
+    let mut entry = entry.clone().into_active_model();
+    entry.applied_at = Set(Some(Utc::now().naive_utc()));
+    entry.update(&txn).await?;
+    txn.commit().await
+}
+
+/// Entries that can't be applied are kept with their error and never applied again,
+/// applying them later would overwrite whatever the items got since.
+async fn dead_letter(
+    conn: &DatabaseConnection,
+    entry: &model::Model,
+    err: &DbErr,
+) -> Result<(), DbErr> {
+    let mut entry = entry.clone().into_active_model();
+    entry.failed_at = Set(Some(Utc::now().naive_utc()));
+    entry.error = Set(Some(err.to_string()));
+    entry.update(conn).await?;
+    Ok(())
+}
+
+/// Applies the written entries in the order they were written, this is also how
+/// the entries the previous run didn't apply are replayed on start.
+/// Items changed by a later applied entry already have a newer state and are skipped.
+/// An entry failing to apply is dead-lettered and the next one goes on.
+pub async fn apply_pending(conn: &DatabaseConnection) -> Result<AppliedEntries, DbErr> {
+    let pending = model::Entity::find()
+        .filter(model::Column::AppliedAt.is_null())
+        .filter(model::Column::FailedAt.is_null())
+        .order_by_asc(model::Column::Id)
+        .all(conn)
+        .await?;
+    let mut applied_entries = AppliedEntries::default();
+    let Some(first) = pending.first() else {
+        return Ok(applied_entries);
+    };
+    let applied = model::Entity::find()
+        .filter(model::Column::Id.gt(first.id))
+        .filter(model::Column::AppliedAt.is_not_null())
+        .all(conn)
+        .await?;
+    let changed_at = last_changes(&applied);
+
+    for entry in pending.iter() {
+        match apply(conn, entry, &pending_deltas(entry, &changed_at)).await {
+            Ok(()) => applied_entries.applied += 1,
+            Err(err) => {
+                error!(
+                    "Item transaction {} ({}) failed and won't be applied: {:?}",
+                    entry.id, entry.kind, err
+                );
+                dead_letter(conn, entry, &err).await?;
+                applied_entries.failed += 1;
+            }
+        }
+    }
+    Ok(applied_entries)
+}
+
+/// Deletes the entries applied before the given time, failed ones are kept for inspection.
+/// Applied entries only matter to pending entries written before them, so they can go once
+/// nothing older can be pending anymore.
+pub async fn compact(
+    conn: &DatabaseConnection,
+    applied_before: NaiveDateTime,
+) -> Result<u64, DbErr> {
+    let deleted = model::Entity::delete_many()
+        .filter(model::Column::AppliedAt.lt(applied_before))
+        .exec(conn)
+        .await?;
+    Ok(deleted.rows_affected)
+}
+
+/// Id of the last entry that changed each item
+fn last_changes(entries: &[model::Model]) -> HashMap<ObjectId, i64> {
+    let mut changed_at = HashMap::default();
+    for entry in entries {
+        for delta in entry.deltas.iter() {
+            let id = changed_at.entry(delta.object_id).or_insert(entry.id);
+            *id = (*id).max(entry.id);
+        }
+    }
+    changed_at
+}
+
+fn pending_deltas(entry: &model::Model, changed_at: &HashMap<ObjectId, i64>) -> Vec<ItemDelta> {
+    entry
+        .deltas
+        .iter()
+        .filter(|delta| {
+            changed_at
+                .get(&delta.object_id)
+                .is_none_or(|&id| id < entry.id)
+        })
+        .copied()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items::ItemLocation;
+
+    fn delta(object_id: u32, count: u64) -> ItemDelta {
+        ItemDelta {
+            object_id: ObjectId::from(object_id),
+            item_id: Id::new(57),
+            owner_id: None,
+            count,
+            enchant_level: 0,
+            location: ItemLocationVariant::Inventory.into(),
+            location_data: 0,
+            coordinates: None,
+        }
+    }
+
+    fn entry(id: i64, deltas: Vec<ItemDelta>) -> model::Model {
+        model::Model {
+            id,
+            kind: ItemTransactionKind::Obtain.to_string(),
+            deltas: ItemDeltas(deltas),
+            created_at: Utc::now().naive_utc(),
+            applied_at: None,
+            failed_at: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_replay_skips_items_changed_later() {
+        let applied = [entry(3, vec![delta(1, 10)]), entry(5, vec![delta(1, 20)])];
+        let changed_at = last_changes(&applied);
+
+        let crashed = entry(2, vec![delta(1, 5), delta(2, 5)]);
+        assert_eq!(pending_deltas(&crashed, &changed_at), vec![delta(2, 5)]);
+
+        let last = entry(6, vec![delta(1, 0)]);
+        assert_eq!(pending_deltas(&last, &changed_at), vec![delta(1, 0)]);
+    }
+
+    #[test]
+    fn test_delta_keeps_world_coordinates() {
+        let mut item = super::super::model::Model::new(
+            ObjectId::from(1u32),
+            Id::new(57),
+            100,
+            ItemLocation::World(Vec3::ZERO),
+            None,
+        );
+        item.set_owner_id(ObjectId::from(2u32));
+
+        let delta = ItemDelta::from(&item);
+        assert_eq!(delta.coordinates, Some([0, 0, 0]));
+        assert_eq!(delta.model().unwrap(), item);
+    }
+}
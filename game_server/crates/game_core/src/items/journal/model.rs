@@ -0,0 +1,40 @@
+use super::ItemDeltas;
+use l2r_core::db::{PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, DeriveEntityModel, PartialEq)]
+#[sea_orm(table_name = "item_journal")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+    pub kind: String,
+    pub deltas: ItemDeltas,
+    pub created_at: DateTime,
+    pub applied_at: Option<DateTime>,
+    /// Set with the error when the entry couldn't be applied, it isn't tried again
+    pub failed_at: Option<DateTime>,
+    pub error: Option<String>,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::Id]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::AppliedAt, Column::FailedAt, Column::Error]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
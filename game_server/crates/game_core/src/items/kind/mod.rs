@@ -78,6 +78,14 @@ impl Kind {
         matches!(self, Kind::Consumable(ConsumableKind::Ammo(_)))
     }
 
+    pub fn lure(&self) -> bool {
+        matches!(self, Kind::Etc(EtcKind::Lure))
+    }
+
+    pub fn fishing_rod(&self) -> bool {
+        matches!(self, Kind::Weapon(weapon) if weapon.kind == WeaponKind::FishingRod)
+    }
+
     pub fn category_name(&self) -> String {
         match self {
             Kind::Weapon(weapon) => format!("Weapon: {}", weapon.kind),
@@ -167,6 +175,7 @@ impl TryFrom<&Kind> for super::BodyPart {
             Armor(armor_type) => Ok((*armor_type).into()),
             Jewelry(jewelry_type) => Ok((*jewelry_type).into()),
             Consumable(ConsumableKind::Ammo(_)) => Ok(BodyPart::LeftHand),
+            Etc(EtcKind::Lure) => Ok(BodyPart::LeftHand),
 
             _ => Err("This item don't have body part"),
         }
@@ -11,6 +11,8 @@ mod id;
 mod inventory;
 mod item;
 mod item_info;
+/// Write-ahead journal of the inventory mutations
+pub mod journal;
 pub mod kind;
 mod location;
 /// Database model related components and types
@@ -57,6 +59,7 @@ impl Plugin for ItemsComponentsPlugin {
 }
 
 pub const ITEMS_OPERATION_STACK: usize = 3;
+pub const ADENA_ID: Id = Id::new(57);
 
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
@@ -10,19 +10,29 @@ pub mod clan;
 pub mod consts;
 pub mod crypt;
 pub mod encounters;
+pub mod fishing;
+pub mod friend;
+pub mod henna;
 pub mod instance_zone;
 /// Module containing all item related plugins and systems.
 pub mod items;
+pub mod macros;
+pub mod manor;
 pub mod movement;
 pub mod multisell;
 pub mod network;
 pub mod npc;
 pub mod object_id;
+pub mod olympiad;
 pub mod path_finding;
 pub mod player_specific;
+pub mod raid_boss;
+pub mod recipe;
+pub mod seven_signs;
 pub mod shortcut;
 pub mod skills;
 pub mod spawner;
 pub mod stats;
 pub mod teleport;
 pub mod utils;
+pub mod vehicle;
@@ -0,0 +1,298 @@
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sea_orm::FromJsonQueryResult;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+mod id;
+pub mod model;
+
+pub use id::*;
+
+pub struct MacroComponentsPlugin;
+impl Plugin for MacroComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Id>()
+            .register_type::<Macros>()
+            .register_type::<Macro>()
+            .register_type::<MacroCommand>()
+            .register_type::<model::Model>();
+    }
+}
+
+/// Client shows up to 48 macros in the macro window.
+pub const MACRO_LIMIT: usize = 48;
+pub const MACRO_COMMANDS_LIMIT: usize = 12;
+pub const MAX_DESCRIPTION_LENGTH: usize = 32;
+pub const MAX_COMMANDS_LENGTH: usize = 255;
+/// Ids of newly created macros start from here, the client sends 0 for a new macro.
+pub const FIRST_MACRO_ID: u32 = 1000;
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Eq,
+    IntoPrimitive,
+    PartialEq,
+    Reflect,
+    Serialize,
+    TryFromPrimitive,
+)]
+#[repr(u8)]
+pub enum MacroCommandKind {
+    Skill = 1,
+    Action = 3,
+    #[default]
+    Text = 4,
+    Shortcut = 5,
+    Item = 6,
+    Delay = 7,
+}
+
+/// Single line of the macro, `d1` and `d2` depend on the kind:
+/// skill / action / item id, shortcut page and slot or delay in seconds.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct MacroCommand {
+    pub index: u8,
+    pub kind: MacroCommandKind,
+    pub d1: u32,
+    pub d2: u8,
+    pub command: String,
+}
+
+#[derive(
+    Clone, Debug, Default, Deserialize, FromJsonQueryResult, PartialEq, Reflect, Serialize,
+)]
+pub struct MacroCommands(pub Vec<MacroCommand>);
+
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+pub struct Macro {
+    pub id: Id,
+    pub name: String,
+    pub description: String,
+    pub acronym: String,
+    pub icon: u8,
+    pub commands: Vec<MacroCommand>,
+}
+
+impl Macro {
+    pub fn validate(&self) -> Result<(), MacroError> {
+        if self.name.is_empty() {
+            return Err(MacroError::EmptyName);
+        }
+        if self.description.chars().count() > MAX_DESCRIPTION_LENGTH {
+            return Err(MacroError::DescriptionTooLong);
+        }
+        let commands_length: usize = self
+            .commands
+            .iter()
+            .map(|command| command.command.chars().count())
+            .sum();
+        if self.commands.len() > MACRO_COMMANDS_LIMIT || commands_length > MAX_COMMANDS_LENGTH {
+            return Err(MacroError::InvalidCommands);
+        }
+        Ok(())
+    }
+
+    pub fn into_model(self, char_id: ObjectId) -> model::Model {
+        model::Model {
+            char_id,
+            macro_id: self.id,
+            name: self.name,
+            description: self.description,
+            acronym: self.acronym,
+            icon: self.icon as i16,
+            commands: MacroCommands(self.commands),
+        }
+    }
+}
+
+impl From<model::Model> for Macro {
+    fn from(model: model::Model) -> Self {
+        Self {
+            id: model.macro_id,
+            name: model.name,
+            description: model.description,
+            acronym: model.acronym,
+            icon: model.icon as u8,
+            commands: model.commands.0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum MacroError {
+    #[error("Macro name is empty")]
+    EmptyName,
+    #[error("Macro description is too long")]
+    DescriptionTooLong,
+    #[error("Macro commands are invalid")]
+    InvalidCommands,
+    #[error("Macro name is already used")]
+    NameIsTaken,
+    #[error("Too many macros")]
+    LimitReached,
+}
+
+/// Macros of the character, the revision is increased on every change
+/// so the client knows its macro window is outdated.
+#[derive(Clone, Component, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Macros {
+    revision: u32,
+    macros: Vec<Macro>,
+}
+
+impl Macros {
+    pub fn new(macros: Vec<Macro>) -> Self {
+        Self {
+            revision: 1,
+            macros,
+        }
+    }
+
+    pub fn revision(&self) -> u32 {
+        self.revision
+    }
+
+    pub fn macros(&self) -> &[Macro] {
+        &self.macros
+    }
+
+    pub fn get(&self, id: Id) -> Option<&Macro> {
+        self.macros.iter().find(|macro_| macro_.id == id)
+    }
+
+    fn next_id(&self) -> Id {
+        let mut id = Id::from(FIRST_MACRO_ID);
+        while self.get(id).is_some() {
+            id = Id::from(u32::from(id) + 1);
+        }
+        id
+    }
+
+    /// Adds a new macro or replaces the one with the same id, returns the stored macro.
+    pub fn register(&mut self, mut new_macro: Macro) -> Result<&Macro, MacroError> {
+        new_macro.validate()?;
+
+        let existing = if u32::from(new_macro.id) == 0 {
+            None
+        } else {
+            self.macros
+                .iter()
+                .position(|macro_| macro_.id == new_macro.id)
+        };
+        let name_is_taken = self
+            .macros
+            .iter()
+            .enumerate()
+            .any(|(index, macro_)| Some(index) != existing && macro_.name == new_macro.name);
+        if name_is_taken {
+            return Err(MacroError::NameIsTaken);
+        }
+
+        let index = match existing {
+            Some(index) => {
+                self.macros[index] = new_macro;
+                index
+            }
+            None => {
+                if self.macros.len() >= MACRO_LIMIT {
+                    return Err(MacroError::LimitReached);
+                }
+                new_macro.id = self.next_id();
+                self.macros.push(new_macro);
+                self.macros.len() - 1
+            }
+        };
+        self.revision += 1;
+        Ok(&self.macros[index])
+    }
+
+    pub fn remove(&mut self, id: Id) -> Option<Macro> {
+        let index = self.macros.iter().position(|macro_| macro_.id == id)?;
+        self.revision += 1;
+        Some(self.macros.remove(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_macro(name: &str) -> Macro {
+        Macro {
+            name: name.to_string(),
+            commands: vec![MacroCommand {
+                index: 1,
+                kind: MacroCommandKind::Text,
+                command: "/sit".to_string(),
+                ..default()
+            }],
+            ..default()
+        }
+    }
+
+    #[test]
+    fn test_register_assigns_ids() {
+        let mut macros = Macros::new(Vec::new());
+        let first = macros.register(new_macro("first")).unwrap().id;
+        let second = macros.register(new_macro("second")).unwrap().id;
+        assert_eq!(first, Id::from(FIRST_MACRO_ID));
+        assert_eq!(second, Id::from(FIRST_MACRO_ID + 1));
+        assert_eq!(macros.revision(), 3);
+    }
+
+    #[test]
+    fn test_register_replaces_existing() {
+        let mut macros = Macros::new(Vec::new());
+        let id = macros.register(new_macro("first")).unwrap().id;
+        let edited = Macro {
+            id,
+            acronym: "F".to_string(),
+            ..new_macro("first")
+        };
+        assert_eq!(macros.register(edited).unwrap().acronym, "F");
+        assert_eq!(macros.macros().len(), 1);
+        assert_eq!(
+            macros.register(new_macro("first")),
+            Err(MacroError::NameIsTaken)
+        );
+    }
+
+    #[test]
+    fn test_register_respects_limit() {
+        let mut macros = Macros::new(Vec::new());
+        for index in 0..MACRO_LIMIT {
+            assert!(macros.register(new_macro(&index.to_string())).is_ok());
+        }
+        let revision = macros.revision();
+        assert_eq!(
+            macros.register(new_macro("extra")),
+            Err(MacroError::LimitReached)
+        );
+        assert_eq!(macros.revision(), revision);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(new_macro("").validate(), Err(MacroError::EmptyName));
+        let long_description = Macro {
+            description: "d".repeat(MAX_DESCRIPTION_LENGTH + 1),
+            ..new_macro("macro")
+        };
+        assert_eq!(
+            long_description.validate(),
+            Err(MacroError::DescriptionTooLong)
+        );
+        let long_commands = Macro {
+            commands: vec![MacroCommand::default(); MACRO_COMMANDS_LIMIT + 1],
+            ..new_macro("macro")
+        };
+        assert_eq!(long_commands.validate(), Err(MacroError::InvalidCommands));
+    }
+}
@@ -0,0 +1,94 @@
+use super::MacroCommands;
+use crate::{character, object_id::ObjectId};
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::{Condition, entity::prelude::*, sea_query::SimpleExpr};
+
+pub type CharacterMacrosRepository = DbRepository<MacroPK, Entity>;
+
+#[derive(Clone, Debug, DeriveEntityModel, PartialEq, Reflect)]
+#[sea_orm(table_name = "character_macros")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub char_id: ObjectId,
+    #[sea_orm(primary_key)]
+    pub macro_id: super::Id,
+    pub name: String,
+    pub description: String,
+    pub acronym: String,
+    pub icon: i16,
+    pub commands: MacroCommands,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CharId, Column::MacroId]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[
+            Column::Name,
+            Column::Description,
+            Column::Acronym,
+            Column::Icon,
+            Column::Commands,
+        ]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::CharId",
+        to = "character::model::Column::Id"
+    )]
+    Character,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MacroPK {
+    pub char_id: ObjectId,
+    pub macro_id: super::Id,
+}
+
+impl From<&Model> for MacroPK {
+    fn from(model: &Model) -> Self {
+        MacroPK {
+            char_id: model.char_id,
+            macro_id: model.macro_id,
+        }
+    }
+}
+
+impl From<MacroPK> for Condition {
+    fn from(pk: MacroPK) -> Self {
+        Condition::all()
+            .add(Column::CharId.eq(pk.char_id))
+            .add(Column::MacroId.eq(pk.macro_id))
+    }
+}
+
+impl From<MacroPK> for SimpleExpr {
+    fn from(value: MacroPK) -> Self {
+        Column::CharId
+            .eq(value.char_id)
+            .and(Column::MacroId.eq(value.macro_id))
+    }
+}
+
+impl From<MacroPK> for (ObjectId, super::Id) {
+    fn from(pk: MacroPK) -> Self {
+        (pk.char_id, pk.macro_id)
+    }
+}
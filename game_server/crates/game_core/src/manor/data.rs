@@ -0,0 +1,67 @@
+use crate::{clan::castle::CastleId, items};
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_common_assets::json::JsonAssetPlugin;
+use derive_more::From;
+use serde::{Deserialize, Serialize};
+
+pub struct SeedsDataComponentsPlugin;
+impl Plugin for SeedsDataComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(JsonAssetPlugin::<Seeds>::new(&["json"]));
+
+        app.register_type::<SeedsHandle>()
+            .register_type::<SeedInfo>();
+    }
+}
+
+pub const DEFAULT_SEED_LIMIT: u64 = 10_000;
+pub const DEFAULT_CROP_LIMIT: u64 = 5_000;
+
+#[derive(Default, Deref, DerefMut, Reflect, Resource)]
+pub struct SeedsHandle(Handle<Seeds>);
+
+/// Seeds of all the manors, keyed by the seed item.
+#[derive(Asset, Clone, Debug, Deref, Deserialize, From, PartialEq, Reflect, Serialize)]
+pub struct Seeds(HashMap<items::Id, SeedInfo>);
+
+impl Seeds {
+    pub fn of_castle(&self, castle_id: CastleId) -> impl Iterator<Item = (items::Id, &SeedInfo)> {
+        self.0
+            .iter()
+            .filter(move |(_, seed)| seed.castle == castle_id)
+            .map(|(seed_id, seed)| (*seed_id, seed))
+    }
+
+    /// Any seed of the castle manor growing the crop, crops share limits and prices.
+    pub fn by_crop(&self, castle_id: CastleId, crop_id: items::Id) -> Option<&SeedInfo> {
+        self.0
+            .values()
+            .find(|seed| seed.castle == castle_id && seed.crop_id == crop_id)
+    }
+}
+
+fn default_seed_limit() -> u64 {
+    DEFAULT_SEED_LIMIT
+}
+
+fn default_crop_limit() -> u64 {
+    DEFAULT_CROP_LIMIT
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct SeedInfo {
+    /// Seed is sown only in the territory of this castle.
+    pub castle: CastleId,
+    pub crop_id: items::Id,
+    pub mature_id: items::Id,
+    /// Seeding and harvesting are most successful on monsters of about this level.
+    pub level: u32,
+    #[serde(default)]
+    pub alternative: bool,
+    /// Maximum amount of seeds the manor can produce for one period.
+    #[serde(default = "default_seed_limit")]
+    pub limit_seeds: u64,
+    /// Maximum amount of crops the manor can procure for one period.
+    #[serde(default = "default_crop_limit")]
+    pub limit_crops: u64,
+}
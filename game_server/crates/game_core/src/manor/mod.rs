@@ -0,0 +1,458 @@
+use crate::{clan::castle::CastleId, items, object_id::ObjectId};
+use bevy::{platform::collections::HashMap, prelude::*};
+use chrono::NaiveTime;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sea_orm::{
+    TryGetError, TryGetable, Value,
+    sea_query::{ArrayType, ColumnType, ValueType, ValueTypeErr},
+};
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+use thiserror::Error;
+
+pub mod model;
+
+mod data;
+
+pub use data::*;
+
+pub struct ManorComponentsPlugin;
+impl Plugin for ManorComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(SeedsDataComponentsPlugin);
+
+        app.register_type::<ManorMode>()
+            .register_type::<ManorKind>()
+            .register_type::<ManorEntry>()
+            .register_type::<CastleManor>()
+            .register_type::<Manor>()
+            .register_type::<Seeded>()
+            .register_type::<VisitedManor>()
+            .register_type::<model::Model>();
+
+        app.init_resource::<Manor>();
+    }
+}
+
+/// Settings of the current period are rolled over to the next one at this hour.
+pub const MAINTENANCE_HOUR: u32 = 20;
+pub const MAINTENANCE_MINUTES: u32 = 6;
+/// Settings of the next period are approved and paid by the castle at this time.
+pub const APPROVE_HOUR: u32 = 4;
+pub const APPROVE_MINUTE: u32 = 30;
+/// Prices are limited to this range around the reference price of the item.
+pub const MIN_PRICE_PERCENT: u64 = 60;
+pub const MAX_PRICE_MULTIPLIER: u64 = 10;
+/// Sowing and harvesting chance drops by 5% for each level of difference above this one.
+pub const MAX_LEVEL_DIFF: u32 = 5;
+
+/// Price range the castle lord may set for the item of the given reference price.
+pub fn price_range(reference_price: u64) -> RangeInclusive<u64> {
+    reference_price * MIN_PRICE_PERCENT / 100..=reference_price * MAX_PRICE_MULTIPLIER
+}
+
+/// Chance in percents to sow or harvest on the monster of the given level difference.
+pub fn success_chance(level_diff: u32) -> u32 {
+    let penalty = level_diff.saturating_sub(MAX_LEVEL_DIFF) * 5;
+    90u32.saturating_sub(penalty).max(1)
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum ManorMode {
+    /// Next period is approved, manor serves seeds and procures crops.
+    #[default]
+    Approved,
+    /// Current period is closing, nothing can be bought or sold.
+    Maintenance,
+    /// Castle lords may change the settings of the next period.
+    Modifiable,
+}
+
+impl ManorMode {
+    pub fn at(time: NaiveTime) -> Self {
+        let maintenance = NaiveTime::from_hms_opt(MAINTENANCE_HOUR, 0, 0).unwrap_or_default();
+        let modifiable =
+            NaiveTime::from_hms_opt(MAINTENANCE_HOUR, MAINTENANCE_MINUTES, 0).unwrap_or_default();
+        let approve = NaiveTime::from_hms_opt(APPROVE_HOUR, APPROVE_MINUTE, 0).unwrap_or_default();
+
+        if time >= maintenance && time < modifiable {
+            ManorMode::Maintenance
+        } else if time >= approve && time < maintenance {
+            ManorMode::Approved
+        } else {
+            ManorMode::Modifiable
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Eq,
+    Hash,
+    IntoPrimitive,
+    PartialEq,
+    Reflect,
+    Serialize,
+    TryFromPrimitive,
+)]
+#[repr(i16)]
+pub enum ManorKind {
+    /// Seeds sold by the manor manager.
+    Production = 1,
+    /// Crops bought by the manor manager.
+    Procure = 2,
+}
+
+impl From<ManorKind> for Value {
+    fn from(kind: ManorKind) -> Self {
+        Value::SmallInt(Some(kind.into()))
+    }
+}
+
+impl TryGetable for ManorKind {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &sea_orm::QueryResult,
+        idx: I,
+    ) -> Result<Self, TryGetError> {
+        let value: i16 = res.try_get_by(idx)?;
+        ManorKind::try_from_primitive(value).map_err(|_| {
+            TryGetError::DbErr(sea_orm::DbErr::Type(format!(
+                "Failed to convert {value} to ManorKind"
+            )))
+        })
+    }
+}
+
+impl ValueType for ManorKind {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        if let Value::SmallInt(Some(val)) = v {
+            ManorKind::try_from_primitive(val).map_err(|_| ValueTypeErr)
+        } else {
+            Err(ValueTypeErr)
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(ManorKind).to_string()
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::SmallInteger
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::SmallInt
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum ManorError {
+    #[error("Manor is under maintenance")]
+    UnderMaintenance,
+    #[error("Manor settings can not be changed now")]
+    NotModifiable,
+    #[error("Seed does not belong to the manor")]
+    UnknownSeed,
+    #[error("Seed quantity is incorrect")]
+    InvalidSeedAmount,
+    #[error("Seed price is incorrect")]
+    InvalidSeedPrice,
+    #[error("Crop does not belong to the manor")]
+    UnknownCrop,
+    #[error("Crop quantity is incorrect")]
+    InvalidCropAmount,
+    #[error("Crop price is incorrect")]
+    InvalidCropPrice,
+    #[error("Manor does not have enough seeds")]
+    NotEnoughSeeds,
+    #[error("Manor can not accept any more crops")]
+    NotEnoughProcure,
+}
+
+/// Character used a seed item on the selected monster.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct UseSeed {
+    pub item_object_id: ObjectId,
+    pub item_id: items::Id,
+}
+
+/// Character used a harvester on the selected monster corpse.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct UseHarvester;
+
+/// Castle lord opened the seed production or the crop procure settings of the next period.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct ShowManorSetting {
+    pub castle_id: CastleId,
+    pub kind: ManorKind,
+}
+
+/// Seed sown on the monster, the sower harvests the crop after the monster dies.
+#[derive(Clone, Component, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Seeded {
+    pub seed_id: items::Id,
+    pub sower: ObjectId,
+}
+
+/// Manor manager the character talked to, seeds are bought and crops are sold there.
+#[derive(Clone, Component, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct VisitedManor {
+    pub castle_id: CastleId,
+    pub npc: Entity,
+}
+
+/// Seed production or crop procure setting of the castle lord.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Reflect)]
+pub struct ManorEntry {
+    pub item_id: items::Id,
+    /// Left to be sold or procured in the period.
+    pub amount: u64,
+    pub start_amount: u64,
+    pub price: u64,
+}
+
+impl ManorEntry {
+    pub fn new(item_id: items::Id, start_amount: u64, price: u64) -> Self {
+        Self {
+            item_id,
+            amount: start_amount,
+            start_amount,
+            price,
+        }
+    }
+
+    fn into_model(self, castle_id: CastleId, kind: ManorKind, next_period: bool) -> model::Model {
+        model::Model {
+            castle_id: castle_id.into(),
+            item_id: self.item_id,
+            next_period,
+            kind,
+            amount: self.amount as i64,
+            start_amount: self.start_amount as i64,
+            price: self.price as i64,
+        }
+    }
+}
+
+impl From<&model::Model> for ManorEntry {
+    fn from(model: &model::Model) -> Self {
+        Self {
+            item_id: model.item_id,
+            amount: model.amount.max(0) as u64,
+            start_amount: model.start_amount.max(0) as u64,
+            price: model.price.max(0) as u64,
+        }
+    }
+}
+
+/// Manor settings of a single castle for the current and the next period.
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+pub struct CastleManor {
+    production: Vec<ManorEntry>,
+    procure: Vec<ManorEntry>,
+    next_production: Vec<ManorEntry>,
+    next_procure: Vec<ManorEntry>,
+}
+
+impl CastleManor {
+    pub fn production(&self, next_period: bool) -> &[ManorEntry] {
+        if next_period {
+            &self.next_production
+        } else {
+            &self.production
+        }
+    }
+
+    pub fn procure(&self, next_period: bool) -> &[ManorEntry] {
+        if next_period {
+            &self.next_procure
+        } else {
+            &self.procure
+        }
+    }
+
+    pub fn set_next_production(&mut self, production: Vec<ManorEntry>) {
+        self.next_production = production;
+    }
+
+    pub fn set_next_procure(&mut self, procure: Vec<ManorEntry>) {
+        self.next_procure = procure;
+    }
+
+    /// Takes seeds from the production of the current period, returns the price to pay.
+    pub fn buy_seeds(&mut self, seed_id: items::Id, count: u64) -> Result<u64, ManorError> {
+        let entry = self
+            .production
+            .iter_mut()
+            .find(|entry| entry.item_id == seed_id)
+            .ok_or(ManorError::UnknownSeed)?;
+        if count == 0 || entry.amount < count {
+            return Err(ManorError::NotEnoughSeeds);
+        }
+        let price = entry
+            .price
+            .checked_mul(count)
+            .ok_or(ManorError::InvalidSeedAmount)?;
+        entry.amount -= count;
+        Ok(price)
+    }
+
+    /// Puts crops into the procure of the current period, returns the price to pay out.
+    pub fn sell_crops(&mut self, crop_id: items::Id, count: u64) -> Result<u64, ManorError> {
+        let entry = self
+            .procure
+            .iter_mut()
+            .find(|entry| entry.item_id == crop_id)
+            .ok_or(ManorError::UnknownCrop)?;
+        if count == 0 || entry.amount < count {
+            return Err(ManorError::NotEnoughProcure);
+        }
+        let price = entry
+            .price
+            .checked_mul(count)
+            .ok_or(ManorError::InvalidCropAmount)?;
+        entry.amount -= count;
+        Ok(price)
+    }
+
+    /// Adena the castle pays in advance for the crops procured in the next period.
+    pub fn next_procure_cost(&self) -> u64 {
+        self.next_procure
+            .iter()
+            .map(|entry| entry.start_amount.saturating_mul(entry.price))
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Adena paid for the crops nobody sold in the current period.
+    pub fn procure_refund(&self) -> u64 {
+        self.procure
+            .iter()
+            .map(|entry| entry.amount.saturating_mul(entry.price))
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn reset_next(&mut self) {
+        self.next_production.clear();
+        self.next_procure.clear();
+    }
+
+    /// Next period becomes the current one, its settings are kept for the following period.
+    pub fn rollover(&mut self) {
+        self.production = self.next_production.clone();
+        self.procure = self.next_procure.clone();
+        for entry in self
+            .next_production
+            .iter_mut()
+            .chain(self.next_procure.iter_mut())
+        {
+            entry.amount = entry.start_amount;
+        }
+    }
+
+    pub fn models(&self, castle_id: CastleId) -> Vec<model::Model> {
+        let periods = [
+            (&self.production, ManorKind::Production, false),
+            (&self.procure, ManorKind::Procure, false),
+            (&self.next_production, ManorKind::Production, true),
+            (&self.next_procure, ManorKind::Procure, true),
+        ];
+        periods
+            .into_iter()
+            .flat_map(|(entries, kind, next_period)| {
+                entries
+                    .iter()
+                    .map(move |entry| entry.into_model(castle_id, kind, next_period))
+            })
+            .collect()
+    }
+
+    pub fn from_models<'a>(models: impl IntoIterator<Item = &'a model::Model>) -> Self {
+        let mut manor = Self::default();
+        for model in models {
+            let entries = match (model.kind, model.next_period) {
+                (ManorKind::Production, false) => &mut manor.production,
+                (ManorKind::Procure, false) => &mut manor.procure,
+                (ManorKind::Production, true) => &mut manor.next_production,
+                (ManorKind::Procure, true) => &mut manor.next_procure,
+            };
+            entries.push(ManorEntry::from(model));
+        }
+        manor
+    }
+}
+
+/// Manors of all the castles, loaded from the database at startup.
+#[derive(Clone, Debug, Default, Deref, DerefMut, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct Manor {
+    #[deref]
+    castles: HashMap<CastleId, CastleManor>,
+    mode: ManorMode,
+}
+
+impl Manor {
+    pub fn new(castles: HashMap<CastleId, CastleManor>, mode: ManorMode) -> Self {
+        Self { castles, mode }
+    }
+
+    pub fn mode(&self) -> ManorMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: ManorMode) {
+        self.mode = mode;
+    }
+
+    pub fn under_maintenance(&self) -> bool {
+        self.mode == ManorMode::Maintenance
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manor_mode() {
+        let at = |hour, minute| ManorMode::at(NaiveTime::from_hms_opt(hour, minute, 0).unwrap());
+        assert_eq!(at(12, 0), ManorMode::Approved);
+        assert_eq!(at(4, 30), ManorMode::Approved);
+        assert_eq!(at(20, 3), ManorMode::Maintenance);
+        assert_eq!(at(20, 6), ManorMode::Modifiable);
+        assert_eq!(at(2, 0), ManorMode::Modifiable);
+    }
+
+    #[test]
+    fn test_castle_manor_period() {
+        let seed_id = items::Id::from(5016);
+        let crop_id = items::Id::from(5073);
+        let mut manor = CastleManor::default();
+        manor.set_next_production(vec![ManorEntry::new(seed_id, 100, 10)]);
+        manor.set_next_procure(vec![ManorEntry::new(crop_id, 50, 60)]);
+        assert_eq!(manor.next_procure_cost(), 3000);
+
+        assert_eq!(manor.buy_seeds(seed_id, 1), Err(ManorError::UnknownSeed));
+        manor.rollover();
+
+        assert_eq!(manor.buy_seeds(seed_id, 30), Ok(300));
+        assert_eq!(
+            manor.buy_seeds(seed_id, 71),
+            Err(ManorError::NotEnoughSeeds)
+        );
+        assert_eq!(manor.sell_crops(crop_id, 20), Ok(1200));
+        assert_eq!(manor.procure_refund(), 1800);
+
+        manor.rollover();
+        assert_eq!(manor.production(false)[0].amount, 100);
+        assert_eq!(manor.procure(false)[0].amount, 50);
+
+        let models = manor.models(CastleId::Gludio);
+        assert_eq!(models.len(), 4);
+        assert_eq!(CastleManor::from_models(models.iter()), manor);
+    }
+}
@@ -0,0 +1,99 @@
+use super::ManorKind;
+use crate::{clan, items};
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::{Condition, entity::prelude::*, sea_query::SimpleExpr};
+
+pub type CastleManorRepository = DbRepository<ManorPK, Entity>;
+
+/// Seed produced or crop procured by the castle manor for the current or the next period.
+#[derive(Clone, Copy, Debug, DeriveEntityModel, PartialEq, Reflect)]
+#[sea_orm(table_name = "castle_manor")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub castle_id: i32,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub item_id: items::Id,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub next_period: bool,
+    pub kind: ManorKind,
+    pub amount: i64,
+    pub start_amount: i64,
+    pub price: i64,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CastleId, Column::ItemId, Column::NextPeriod]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[
+            Column::Kind,
+            Column::Amount,
+            Column::StartAmount,
+            Column::Price,
+        ]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "clan::castle::model::Entity",
+        from = "Column::CastleId",
+        to = "clan::castle::model::Column::Id"
+    )]
+    Castle,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ManorPK {
+    pub castle_id: i32,
+    pub item_id: items::Id,
+    pub next_period: bool,
+}
+
+impl From<&Model> for ManorPK {
+    fn from(model: &Model) -> Self {
+        ManorPK {
+            castle_id: model.castle_id,
+            item_id: model.item_id,
+            next_period: model.next_period,
+        }
+    }
+}
+
+impl From<ManorPK> for Condition {
+    fn from(pk: ManorPK) -> Self {
+        Condition::all()
+            .add(Column::CastleId.eq(pk.castle_id))
+            .add(Column::ItemId.eq(pk.item_id))
+            .add(Column::NextPeriod.eq(pk.next_period))
+    }
+}
+
+impl From<ManorPK> for SimpleExpr {
+    fn from(value: ManorPK) -> Self {
+        Column::CastleId
+            .eq(value.castle_id)
+            .and(Column::ItemId.eq(value.item_id))
+            .and(Column::NextPeriod.eq(value.next_period))
+    }
+}
+
+impl From<ManorPK> for (i32, items::Id, bool) {
+    fn from(pk: ManorPK) -> Self {
+        (pk.castle_id, pk.item_id, pk.next_period)
+    }
+}
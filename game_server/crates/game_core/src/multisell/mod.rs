@@ -4,14 +4,18 @@ pub mod admin_shop;
 mod entry;
 mod good;
 mod id;
+mod npc;
 
 pub use entry::*;
 pub use good::*;
 pub use id::*;
+pub use npc::*;
 
 pub struct MultisellComponentsPlugin;
 impl Plugin for MultisellComponentsPlugin {
     fn build(&self, app: &mut App) {
+        app.add_plugins(NpcMultisellsComponentsPlugin);
+
         app.register_type::<Id>()
             .register_type::<Entry>()
             .register_type::<Good>();
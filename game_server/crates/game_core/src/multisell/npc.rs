@@ -0,0 +1,58 @@
+use crate::{items, npc};
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_common_assets::json::JsonAssetPlugin;
+use derive_more::From;
+use serde::{Deserialize, Serialize};
+
+pub struct NpcMultisellsComponentsPlugin;
+impl Plugin for NpcMultisellsComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(JsonAssetPlugin::<NpcMultisells>::new(&["json"]));
+
+        app.register_type::<NpcMultisellsHandle>()
+            .register_type::<NpcMultisell>()
+            .register_type::<NpcMultisellEntry>()
+            .register_type::<Ingredient>()
+            .register_type::<ViewedMultisell>();
+    }
+}
+
+#[derive(Default, Deref, DerefMut, Reflect, Resource)]
+pub struct NpcMultisellsHandle(Handle<NpcMultisells>);
+
+#[derive(Asset, Clone, Debug, Deref, Deserialize, From, PartialEq, Reflect, Serialize)]
+pub struct NpcMultisells(HashMap<super::Id, NpcMultisell>);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct NpcMultisell {
+    pub npcs: Vec<npc::Id>,
+    pub entries: Vec<NpcMultisellEntry>,
+}
+
+impl NpcMultisell {
+    pub fn sold_by(&self, npc_id: npc::Id) -> bool {
+        self.npcs.contains(&npc_id)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct NpcMultisellEntry {
+    pub rewards: Vec<Ingredient>,
+    pub requirements: Vec<Ingredient>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct Ingredient {
+    pub item_id: items::Id,
+    pub count: u64,
+    /// Castle tax of the territory is added on top of the count.
+    #[serde(default)]
+    pub tax: bool,
+}
+
+/// Multisell opened by the character at the NPC, only its entries can be chosen.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+pub struct ViewedMultisell {
+    pub id: super::Id,
+    pub npc: Entity,
+}
@@ -2,7 +2,14 @@ use crate::{
     crypt::GameCryptEngine,
     network::packets::{client::GameClientPacket, server::GameServerPacket},
 };
-use bevy_slinet::{ServerConfig, protocols::tcp::TcpProtocol, serializer::SerializerAdapter};
+use bevy_slinet::{
+    ServerConfig,
+    packet_length_serializer::{
+        PacketLengthDeserializationError, PacketLengthSerializer, PacketTooLargeError,
+    },
+    protocols::tcp::TcpProtocol,
+    serializer::SerializerAdapter,
+};
 use l2r_core::packets::{L2rLenSerializer, L2rSerializeError, L2rSerializer};
 use std::sync::{Arc, Mutex};
 
@@ -24,5 +31,111 @@ impl ServerConfig for GameServerNetworkConfig {
             GameCryptEngine::default()
         ))))
     }
-    type LengthSerializer = L2rLenSerializer;
+    type LengthSerializer = GameLenSerializer;
+}
+
+/// Outgoing packets are framed by the [`GameCryptEngine`] with [`GameLenSerializer::frame`],
+/// so a batch of packets goes out in one write with every packet keeping its own length.
+/// Incoming packets are read the usual way.
+#[derive(Default)]
+pub struct GameLenSerializer(L2rLenSerializer);
+
+impl GameLenSerializer {
+    /// Prepends the length header to a single packet, encrypted or not.
+    pub fn frame(body: Vec<u8>) -> Result<Vec<u8>, L2rSerializeError> {
+        let length = L2rLenSerializer
+            .serialize_packet_length(body.len())
+            .map_err(|err| {
+                L2rSerializeError::with_source("Packet is too large".into(), err, vec![])
+            })?;
+        let mut framed = Vec::with_capacity(length.len() + body.len());
+        framed.extend(length);
+        framed.extend(body);
+        Ok(framed)
+    }
+}
+
+impl PacketLengthSerializer for GameLenSerializer {
+    type Error = PacketTooLargeError;
+    const SIZE: usize = L2rLenSerializer::SIZE;
+
+    /// Every packet is already framed by [`GameLenSerializer::frame`].
+    fn serialize_packet_length(&self, _length: usize) -> Result<Vec<u8>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    fn deserialize_packet_length(
+        &self,
+        buffer: &[u8],
+    ) -> Result<usize, PacketLengthDeserializationError<Self::Error>> {
+        self.0.deserialize_packet_length(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network::packets::server::{ActionFail, KeyPacket, PacketBatch};
+    use bevy_slinet::serializer::MutableSerializer;
+    use l2r_core::packets::L2rServerPacket;
+
+    type GameSerializer = L2rSerializer<GameCryptEngine, GameClientPacket, GameServerPacket>;
+
+    /// Bytes written to the socket for a packet, length header first like the tcp protocol does.
+    fn write(serializer: &mut GameSerializer, packet: GameServerPacket) -> Vec<u8> {
+        let body = serializer.serialize(packet).unwrap();
+        let mut bytes = GameLenSerializer::default()
+            .serialize_packet_length(body.len())
+            .unwrap();
+        bytes.extend(body);
+        bytes
+    }
+
+    /// Splits written bytes by the length headers the client reads.
+    fn frames(mut bytes: &[u8]) -> Vec<Vec<u8>> {
+        let length_serializer = GameLenSerializer::default();
+        let mut frames = Vec::new();
+        while !bytes.is_empty() {
+            let length = length_serializer.deserialize_packet_length(bytes).unwrap();
+            let end = GameLenSerializer::SIZE + length;
+            frames.push(bytes[GameLenSerializer::SIZE..end].to_vec());
+            bytes = &bytes[end..];
+        }
+        frames
+    }
+
+    #[test]
+    fn test_unencrypted_first_packet_keeps_its_length() {
+        let crypt_engine = GameCryptEngine::default();
+        let mut serializer = GameSerializer::new(crypt_engine.clone());
+
+        let bytes = write(&mut serializer, KeyPacket::new().into());
+
+        let expected = KeyPacket::new()
+            .build(crypt_engine.get_out_key())
+            .buffer()
+            .to_vec();
+        assert_eq!(
+            u16::from_le_bytes([bytes[0], bytes[1]]) as usize,
+            expected.len() + GameLenSerializer::SIZE
+        );
+        assert_eq!(frames(&bytes), vec![expected]);
+    }
+
+    #[test]
+    fn test_batch_keeps_a_length_per_packet() {
+        let mut serializer = GameSerializer::new(GameCryptEngine::default());
+
+        let bytes = write(
+            &mut serializer,
+            PacketBatch::new(vec![
+                KeyPacket::new().into(),
+                ActionFail.into(),
+                ActionFail.into(),
+            ])
+            .into(),
+        );
+
+        assert_eq!(frames(&bytes).len(), 3);
+    }
 }
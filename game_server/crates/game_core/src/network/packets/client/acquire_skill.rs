@@ -0,0 +1,56 @@
+use crate::skills::{self, AcquireSkillType};
+use bevy::prelude::*;
+use l2r_core::packets::{ClientPacketBuffer, L2rSerializeError};
+use std::convert::TryFrom;
+
+fn skill_type(buffer: &mut ClientPacketBuffer) -> Result<AcquireSkillType, L2rSerializeError> {
+    let skill_type = buffer.u32()?;
+    AcquireSkillType::try_from(skill_type)
+        .map_err(|err| L2rSerializeError::new(err.to_string(), buffer.as_slice()))
+}
+
+/// Player selected a skill in the learning window and wants to know its cost.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestAcquireSkillInfo {
+    pub id: skills::Id,
+    pub level: skills::Level,
+    pub skill_type: AcquireSkillType,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestAcquireSkillInfo {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let id = skills::Id::from(buffer.u32()?);
+        let level = skills::Level::from(buffer.u32()?);
+        let skill_type = skill_type(&mut buffer)?;
+        Ok(Self {
+            id,
+            level,
+            skill_type,
+        })
+    }
+}
+
+/// Player confirmed learning the skill level.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestAcquireSkill {
+    pub id: skills::Id,
+    pub level: skills::Level,
+    pub skill_type: AcquireSkillType,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestAcquireSkill {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let id = skills::Id::from(buffer.u32()?);
+        let level = skills::Level::from(buffer.u32()?);
+        let skill_type = skill_type(&mut buffer)?;
+        Ok(Self {
+            id,
+            level,
+            skill_type,
+        })
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{items, npc, object_id::ObjectId};
 use bevy::prelude::*;
 use enum_from_str::EnumFromArgsDeserialize;
-use l2r_core::packets::{ClientPacketBuffer, L2rSerializeError};
+use l2r_core::{
+    model::capability::Capability,
+    packets::{ClientPacketBuffer, L2rSerializeError},
+};
 use std::{convert::TryFrom, str::FromStr};
 use strum::EnumIter;
 
@@ -43,6 +46,31 @@ pub enum DoubleSlashCommand {
     Close,
 }
 
+impl DoubleSlashCommand {
+    /// `Summon` ids at or above the offset are npcs, lower ones are items.
+    pub const SUMMON_NPC_ID_OFFSET: u32 = 1_000_000;
+
+    /// Capability the initiator needs, unknown commands need none since they do nothing.
+    pub fn capability(&self) -> Option<Capability> {
+        match self {
+            Self::Unknown => None,
+            Self::Admin => Some(Capability::Menu),
+            Self::Spawn { .. } => Some(Capability::SpawnNpc),
+            Self::GoTo { .. }
+            | Self::TeleportTo { .. }
+            | Self::InstantMove
+            | Self::Teleport { .. } => Some(Capability::Teleport),
+            Self::Item { .. } => Some(Capability::SpawnItem),
+            Self::Summon { id, .. } if *id < Self::SUMMON_NPC_ID_OFFSET => {
+                Some(Capability::SpawnItem)
+            }
+            Self::Summon { .. } => Some(Capability::SpawnNpc),
+            Self::Immortal => Some(Capability::Immortal),
+            Self::Open | Self::Close => Some(Capability::Doors),
+        }
+    }
+}
+
 impl TryFrom<ClientPacketBuffer> for DoubleSlashCommand {
     type Error = L2rSerializeError;
 
@@ -51,3 +79,73 @@ impl TryFrom<ClientPacketBuffer> for DoubleSlashCommand {
         Ok(Self::from_str(&command).unwrap_or_default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_command_needs_no_capability() {
+        assert_eq!(DoubleSlashCommand::Unknown.capability(), None);
+    }
+
+    #[test]
+    fn movement_commands_need_teleport() {
+        for command in [
+            DoubleSlashCommand::GoTo {
+                target_obj_id: ObjectId::default(),
+            },
+            DoubleSlashCommand::TeleportTo {
+                target_name: "Target".to_string(),
+            },
+            DoubleSlashCommand::InstantMove,
+            DoubleSlashCommand::Teleport { x: 0.0, z: 0.0 },
+        ] {
+            assert_eq!(command.capability(), Some(Capability::Teleport));
+        }
+    }
+
+    #[test]
+    fn summon_splits_items_and_npcs_at_the_offset() {
+        let item = DoubleSlashCommand::Summon { id: 57, count: 1 };
+        let npc = DoubleSlashCommand::Summon {
+            id: DoubleSlashCommand::SUMMON_NPC_ID_OFFSET,
+            count: 1,
+        };
+        assert_eq!(item.capability(), Some(Capability::SpawnItem));
+        assert_eq!(npc.capability(), Some(Capability::SpawnNpc));
+    }
+
+    #[test]
+    fn other_commands_map_to_their_capability() {
+        let npc_id = npc::Id::default();
+        assert_eq!(
+            DoubleSlashCommand::Admin.capability(),
+            Some(Capability::Menu)
+        );
+        assert_eq!(
+            DoubleSlashCommand::Spawn { npc_id }.capability(),
+            Some(Capability::SpawnNpc)
+        );
+        assert_eq!(
+            DoubleSlashCommand::Item {
+                id: items::Id::default(),
+                count: 1
+            }
+            .capability(),
+            Some(Capability::SpawnItem)
+        );
+        assert_eq!(
+            DoubleSlashCommand::Immortal.capability(),
+            Some(Capability::Immortal)
+        );
+        assert_eq!(
+            DoubleSlashCommand::Open.capability(),
+            Some(Capability::Doors)
+        );
+        assert_eq!(
+            DoubleSlashCommand::Close.capability(),
+            Some(Capability::Doors)
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use derive_more::{From, Into};
+use l2r_core::packets::{ClientPacketBuffer, L2rSerializeError};
+use std::convert::TryFrom;
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestFriendInvite(pub String);
+
+impl TryFrom<ClientPacketBuffer> for RequestFriendInvite {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(buffer.str()?))
+    }
+}
+
+/// Answer of the invited character, any non-zero response accepts the invitation.
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestAnswerFriendInvite(pub bool);
+
+impl TryFrom<ClientPacketBuffer> for RequestAnswerFriendInvite {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(buffer.u32()? != 0))
+    }
+}
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestFriendDel(pub String);
+
+impl TryFrom<ClientPacketBuffer> for RequestFriendDel {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(buffer.str()?))
+    }
+}
+
+/// Private message sent through the friend list window.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestSendFriendMsg {
+    pub message: String,
+    pub receiver: String,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestSendFriendMsg {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let message = buffer.str()?;
+        let receiver = buffer.str()?;
+        Ok(Self { message, receiver })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub enum RequestBlock {
+    Block(String),
+    Unblock(String),
+    List,
+    BlockAll,
+    UnblockAll,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestBlock {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        match buffer.u32()? {
+            0 => Ok(Self::Block(buffer.str()?)),
+            1 => Ok(Self::Unblock(buffer.str()?)),
+            2 => Ok(Self::List),
+            3 => Ok(Self::BlockAll),
+            4 => Ok(Self::UnblockAll),
+            other => Err(L2rSerializeError::new(
+                format!("Unknown block request type: {other}"),
+                buffer.as_slice(),
+            )),
+        }
+    }
+}
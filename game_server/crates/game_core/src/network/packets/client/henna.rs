@@ -0,0 +1,76 @@
+use crate::henna;
+use bevy::prelude::*;
+use derive_more::{From, Into};
+use l2r_core::packets::{ClientPacketBuffer, L2rSerializeError};
+use std::convert::TryFrom;
+
+/// Player asks for the list of symbols which can be drawn by the symbol maker.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestHennaItemList;
+
+impl TryFrom<ClientPacketBuffer> for RequestHennaItemList {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let _unknown = buffer.u32()?;
+        Ok(Self)
+    }
+}
+
+/// Player selected a symbol in the draw list and wants to see how it changes stats.
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestHennaItemInfo(pub henna::Id);
+
+impl TryFrom<ClientPacketBuffer> for RequestHennaItemInfo {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(henna::Id::from(buffer.u32()?)))
+    }
+}
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestHennaEquip(pub henna::Id);
+
+impl TryFrom<ClientPacketBuffer> for RequestHennaEquip {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(henna::Id::from(buffer.u32()?)))
+    }
+}
+
+/// Player asks for the list of drawn symbols which can be removed.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestHennaRemoveList;
+
+impl TryFrom<ClientPacketBuffer> for RequestHennaRemoveList {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let _unknown = buffer.u32()?;
+        Ok(Self)
+    }
+}
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestHennaItemRemoveInfo(pub henna::Id);
+
+impl TryFrom<ClientPacketBuffer> for RequestHennaItemRemoveInfo {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(henna::Id::from(buffer.u32()?)))
+    }
+}
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestHennaRemove(pub henna::Id);
+
+impl TryFrom<ClientPacketBuffer> for RequestHennaRemove {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(henna::Id::from(buffer.u32()?)))
+    }
+}
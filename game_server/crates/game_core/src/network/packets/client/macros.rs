@@ -0,0 +1,60 @@
+use crate::macros::{self, Macro, MacroCommand, MacroCommandKind};
+use bevy::prelude::*;
+use derive_more::{From, Into};
+use l2r_core::packets::{ClientPacketBuffer, L2rSerializeError};
+use num_enum::TryFromPrimitive;
+use std::convert::TryFrom;
+
+/// Creates a new macro or edits an existing one, the id is 0 for a new macro.
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestMakeMacro(pub Macro);
+
+impl TryFrom<ClientPacketBuffer> for RequestMakeMacro {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let id = macros::Id::from(buffer.u32()?);
+        let name = buffer.str()?;
+        let description = buffer.str()?;
+        let acronym = buffer.str()?;
+        let icon = buffer.u8()?;
+        let count = buffer.u8()?;
+
+        let mut commands = Vec::with_capacity((count as usize).min(macros::MACRO_COMMANDS_LIMIT));
+        for _ in 0..count {
+            let index = buffer.u8()?;
+            let kind = MacroCommandKind::try_from_primitive(buffer.u8()?)
+                .map_err(|err| L2rSerializeError::new(err.to_string(), buffer.as_slice()))?;
+            let d1 = buffer.u32()?;
+            let d2 = buffer.u8()?;
+            let command = buffer.str()?;
+            commands.push(MacroCommand {
+                index,
+                kind,
+                d1,
+                d2,
+                command,
+            });
+        }
+
+        Ok(Self(Macro {
+            id,
+            name,
+            description,
+            acronym,
+            icon,
+            commands,
+        }))
+    }
+}
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestDeleteMacro(pub macros::Id);
+
+impl TryFrom<ClientPacketBuffer> for RequestDeleteMacro {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(macros::Id::from(buffer.u32()?)))
+    }
+}
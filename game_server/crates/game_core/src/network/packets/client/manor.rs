@@ -0,0 +1,105 @@
+use crate::items;
+use bevy::prelude::*;
+use l2r_core::packets::{ClientPacketBuffer, L2rSerializeError};
+use std::convert::TryFrom;
+
+/// Upper bound of the list lengths, a manor has less seeds than that.
+const MAX_MANOR_ENTRIES: u32 = 64;
+
+/// Seeds bought from the manor manager.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestBuySeed {
+    pub manor_id: u32,
+    pub items: Vec<(items::Id, u64)>,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestBuySeed {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let manor_id = buffer.u32()?;
+        let count = buffer.u32()?;
+        let mut items = Vec::with_capacity(count.min(MAX_MANOR_ENTRIES) as usize);
+        for _ in 0..count {
+            let item_id = items::Id::from(buffer.u32()?);
+            let count = buffer.u64()?;
+            items.push((item_id, count));
+        }
+        Ok(Self { manor_id, items })
+    }
+}
+
+/// Crops sold to the manor manager.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestBuyProcure {
+    pub manor_id: u32,
+    pub items: Vec<(items::Id, u64)>,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestBuyProcure {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let manor_id = buffer.u32()?;
+        let count = buffer.u32()?;
+        let mut items = Vec::with_capacity(count.min(MAX_MANOR_ENTRIES) as usize);
+        for _ in 0..count {
+            let _service = buffer.u32()?;
+            let item_id = items::Id::from(buffer.u32()?);
+            let count = buffer.u64()?;
+            items.push((item_id, count));
+        }
+        Ok(Self { manor_id, items })
+    }
+}
+
+/// Seed production of the next period set by the castle lord.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestSetSeed {
+    pub manor_id: u32,
+    /// Seed, amount to produce and price.
+    pub entries: Vec<(items::Id, u64, u64)>,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestSetSeed {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let manor_id = buffer.u32()?;
+        let count = buffer.u32()?;
+        let mut entries = Vec::with_capacity(count.min(MAX_MANOR_ENTRIES) as usize);
+        for _ in 0..count {
+            let item_id = items::Id::from(buffer.u32()?);
+            let amount = buffer.u64()?;
+            let price = buffer.u64()?;
+            entries.push((item_id, amount, price));
+        }
+        Ok(Self { manor_id, entries })
+    }
+}
+
+/// Crop procure of the next period set by the castle lord.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestSetCrop {
+    pub manor_id: u32,
+    /// Crop, amount to procure and price.
+    pub entries: Vec<(items::Id, u64, u64)>,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestSetCrop {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let manor_id = buffer.u32()?;
+        let count = buffer.u32()?;
+        let mut entries = Vec::with_capacity(count.min(MAX_MANOR_ENTRIES) as usize);
+        for _ in 0..count {
+            let item_id = items::Id::from(buffer.u32()?);
+            let amount = buffer.u64()?;
+            let price = buffer.u64()?;
+            let _reward_kind = buffer.u8()?;
+            entries.push((item_id, amount, price));
+        }
+        Ok(Self { manor_id, entries })
+    }
+}
@@ -2,6 +2,7 @@ use bevy::{log, prelude::*};
 use l2r_core::packets::{ClientPacketBuffer, ClientPacketId, L2rClientPacket, L2rSerializeError};
 use std::convert::TryFrom;
 
+mod acquire_skill;
 mod action;
 mod attack;
 mod auth_login;
@@ -10,9 +11,14 @@ mod cannot_move_anymore;
 mod char_creation;
 mod character_select;
 mod double_slash_command;
+mod friend;
+mod henna;
+mod macros;
+mod manor;
 mod move_backward_to_location;
 mod multisell_choose;
 mod protocol_verision;
+mod recipe;
 mod request_action_use;
 pub mod request_auto_shots;
 mod request_destroy_item;
@@ -21,12 +27,16 @@ mod request_drop_item;
 mod request_magic_skill_use;
 mod request_restart_point;
 mod say;
+mod seven_signs;
 mod shortcut_delete;
 mod shortcut_registration;
+mod siege;
 mod single_slash_command;
 mod use_item;
 mod validate_position;
+mod vehicle;
 
+pub use acquire_skill::*;
 pub use action::*;
 pub use attack::*;
 pub use auth_login::*;
@@ -35,9 +45,14 @@ pub use cannot_move_anymore::*;
 pub use char_creation::*;
 pub use character_select::*;
 pub use double_slash_command::*;
+pub use friend::*;
+pub use henna::*;
+pub use macros::*;
+pub use manor::*;
 pub use move_backward_to_location::*;
 pub use multisell_choose::*;
 pub use protocol_verision::*;
+pub use recipe::*;
 pub use request_action_use::*;
 pub use request_destroy_item::*;
 pub use request_dispel::*;
@@ -45,11 +60,14 @@ pub use request_drop_item::*;
 pub use request_magic_skill_use::*;
 pub use request_restart_point::*;
 pub use say::*;
+pub use seven_signs::*;
 pub use shortcut_delete::*;
 pub use shortcut_registration::*;
+pub use siege::*;
 pub use single_slash_command::*;
 pub use use_item::*;
 pub use validate_position::*;
+pub use vehicle::*;
 
 #[derive(Clone, Debug, Reflect)]
 pub enum GameClientPacket {
@@ -91,6 +109,49 @@ pub enum GameClientPacket {
     RequestShortcutRegistration(shortcut_registration::RequestShortcutRegistration),
     RequestShortcutDelete(shortcut_delete::RequestShortcutDelete),
     RequestAutoShots(request_auto_shots::RequestAutoShots),
+    RequestHennaEquip(henna::RequestHennaEquip),
+    RequestHennaRemoveList(henna::RequestHennaRemoveList),
+    RequestHennaItemRemoveInfo(henna::RequestHennaItemRemoveInfo),
+    RequestHennaRemove(henna::RequestHennaRemove),
+    RequestHennaItemList(henna::RequestHennaItemList),
+    RequestHennaItemInfo(henna::RequestHennaItemInfo),
+    RequestRecipeBookOpen(recipe::RequestRecipeBookOpen),
+    RequestRecipeBookDestroy(recipe::RequestRecipeBookDestroy),
+    RequestRecipeItemMakeInfo(recipe::RequestRecipeItemMakeInfo),
+    RequestRecipeItemMakeSelf(recipe::RequestRecipeItemMakeSelf),
+    RequestRecipeShopManageList,
+    RequestRecipeShopMessageSet(recipe::RequestRecipeShopMessageSet),
+    RequestRecipeShopListSet(recipe::RequestRecipeShopListSet),
+    RequestRecipeShopManageQuit,
+    RequestRecipeShopManageCancel,
+    RequestRecipeShopMakeInfo(recipe::RequestRecipeShopMakeInfo),
+    RequestRecipeShopMakeItem(recipe::RequestRecipeShopMakeItem),
+    RequestRecipeShopManagePrev,
+    RequestFriendInvite(friend::RequestFriendInvite),
+    RequestAnswerFriendInvite(friend::RequestAnswerFriendInvite),
+    RequestFriendList,
+    RequestFriendDel(friend::RequestFriendDel),
+    RequestSendFriendMsg(friend::RequestSendFriendMsg),
+    RequestBlock(friend::RequestBlock),
+    RequestMakeMacro(macros::RequestMakeMacro),
+    RequestDeleteMacro(macros::RequestDeleteMacro),
+    RequestSiegeAttackerList(siege::RequestSiegeAttackerList),
+    RequestSiegeDefenderList(siege::RequestSiegeDefenderList),
+    RequestJoinSiege(siege::RequestJoinSiege),
+    RequestConfirmSiegeWaitingList(siege::RequestConfirmSiegeWaitingList),
+    RequestSetCastleSiegeTime(siege::RequestSetCastleSiegeTime),
+    RequestBuySeed(manor::RequestBuySeed),
+    RequestBuyProcure(manor::RequestBuyProcure),
+    RequestSetSeed(manor::RequestSetSeed),
+    RequestSetCrop(manor::RequestSetCrop),
+    RequestSsqStatus(seven_signs::RequestSsqStatus),
+    ObserverReturn,
+    RequestGetOnVehicle(vehicle::RequestGetOnVehicle),
+    RequestGetOffVehicle(vehicle::RequestGetOffVehicle),
+    RequestMoveToLocationInVehicle(vehicle::RequestMoveToLocationInVehicle),
+    CannotMoveAnymoreInVehicle(vehicle::CannotMoveAnymoreInVehicle),
+    RequestAcquireSkillInfo(acquire_skill::RequestAcquireSkillInfo),
+    RequestAcquireSkill(acquire_skill::RequestAcquireSkill),
 }
 
 pub struct GameClientPacketCodes;
@@ -159,8 +220,8 @@ impl GameClientPacketCodes {
     const _REQUEST_MAGIC_LIST: ClientPacketId = ClientPacketId::new(0x4F);
     const _REQUEST_SKILL_LIST: ClientPacketId = ClientPacketId::new(0x50);
     const _MOVE_WITH_DELTA: ClientPacketId = ClientPacketId::new(0x52);
-    const _REQUEST_GET_ON_VEHICLE: ClientPacketId = ClientPacketId::new(0x53);
-    const _REQUEST_GET_OFF_VEHICLE: ClientPacketId = ClientPacketId::new(0x54);
+    const REQUEST_GET_ON_VEHICLE: ClientPacketId = ClientPacketId::new(0x53);
+    const REQUEST_GET_OFF_VEHICLE: ClientPacketId = ClientPacketId::new(0x54);
     const _ANSWER_TRADE_REQUEST: ClientPacketId = ClientPacketId::new(0x55);
     const REQUEST_ACTION_USE: ClientPacketId = ClientPacketId::new(0x56);
     const REQUEST_RESTART: ClientPacketId = ClientPacketId::new(0x57);
@@ -175,23 +236,23 @@ impl GameClientPacketCodes {
     const _REQUEST_PLEDGE_INFO: ClientPacketId = ClientPacketId::new(0x65);
     const _REQUEST_PLEDGE_EXTENDED_INFO: ClientPacketId = ClientPacketId::new(0x66);
     const _REQUEST_PLEDGE_CREST: ClientPacketId = ClientPacketId::new(0x67);
-    const _REQUEST_SEND_FRIEND_MSG: ClientPacketId = ClientPacketId::new(0x6B);
+    const REQUEST_SEND_FRIEND_MSG: ClientPacketId = ClientPacketId::new(0x6B);
     const REQUEST_SHOW_MAP: ClientPacketId = ClientPacketId::new(0x6C);
     const _REQUEST_RECORD_INFO: ClientPacketId = ClientPacketId::new(0x6E);
-    const _REQUEST_HENNA_EQUIP: ClientPacketId = ClientPacketId::new(0x6F);
-    const _REQUEST_HENNA_REMOVE_LIST: ClientPacketId = ClientPacketId::new(0x70);
-    const _REQUEST_HENNA_ITEM_REMOVE_INFO: ClientPacketId = ClientPacketId::new(0x71);
-    const _REQUEST_HENNA_REMOVE: ClientPacketId = ClientPacketId::new(0x72);
-    const _REQUEST_ACQUIRE_SKILL_INFO: ClientPacketId = ClientPacketId::new(0x73);
+    const REQUEST_HENNA_EQUIP: ClientPacketId = ClientPacketId::new(0x6F);
+    const REQUEST_HENNA_REMOVE_LIST: ClientPacketId = ClientPacketId::new(0x70);
+    const REQUEST_HENNA_ITEM_REMOVE_INFO: ClientPacketId = ClientPacketId::new(0x71);
+    const REQUEST_HENNA_REMOVE: ClientPacketId = ClientPacketId::new(0x72);
+    const REQUEST_ACQUIRE_SKILL_INFO: ClientPacketId = ClientPacketId::new(0x73);
     const DOUBLE_SLASH_COMMAND: ClientPacketId = ClientPacketId::new(0x74);
-    const _REQUEST_MOVE_TO_LOCATION_IN_VEHICLE: ClientPacketId = ClientPacketId::new(0x75);
-    const _CANNOT_MOVE_ANYMORE_IN_VEHICLE: ClientPacketId = ClientPacketId::new(0x76);
-    const _REQUEST_FRIEND_INVITE: ClientPacketId = ClientPacketId::new(0x77);
-    const _REQUEST_ANSWER_FRIEND_INVITE: ClientPacketId = ClientPacketId::new(0x78);
-    const _REQUEST_FRIEND_LIST: ClientPacketId = ClientPacketId::new(0x79);
-    const _REQUEST_FRIEND_DEL: ClientPacketId = ClientPacketId::new(0x7A);
+    const REQUEST_MOVE_TO_LOCATION_IN_VEHICLE: ClientPacketId = ClientPacketId::new(0x75);
+    const CANNOT_MOVE_ANYMORE_IN_VEHICLE: ClientPacketId = ClientPacketId::new(0x76);
+    const REQUEST_FRIEND_INVITE: ClientPacketId = ClientPacketId::new(0x77);
+    const REQUEST_ANSWER_FRIEND_INVITE: ClientPacketId = ClientPacketId::new(0x78);
+    const REQUEST_FRIEND_LIST: ClientPacketId = ClientPacketId::new(0x79);
+    const REQUEST_FRIEND_DEL: ClientPacketId = ClientPacketId::new(0x7A);
     const _CHARACTER_RESTORE: ClientPacketId = ClientPacketId::new(0x7B);
-    const _REQUEST_ACQUIRE_SKILL: ClientPacketId = ClientPacketId::new(0x7C);
+    const REQUEST_ACQUIRE_SKILL: ClientPacketId = ClientPacketId::new(0x7C);
     const REQUEST_RESTART_POINT: ClientPacketId = ClientPacketId::new(0x7D);
     const _REQUEST_GM_COMMAND: ClientPacketId = ClientPacketId::new(0x7E);
     const _REQUEST_PARTY_MATCH_CONFIG: ClientPacketId = ClientPacketId::new(0x7F);
@@ -227,47 +288,49 @@ impl GameClientPacketCodes {
     const _REQUEST_SKILL_COOL_TIME: ClientPacketId = ClientPacketId::new(0xA6);
     const _REQUEST_PACKAGE_SENDABLE_ITEM_LIST: ClientPacketId = ClientPacketId::new(0xA7);
     const _REQUEST_PACKAGE_SEND: ClientPacketId = ClientPacketId::new(0xA8);
-    const _REQUEST_BLOCK: ClientPacketId = ClientPacketId::new(0xA9);
+    const REQUEST_BLOCK: ClientPacketId = ClientPacketId::new(0xA9);
     const _REQUEST_SIEGE_INFO: ClientPacketId = ClientPacketId::new(0xAA);
-    const _REQUEST_SIEGE_ATTACKER_LIST: ClientPacketId = ClientPacketId::new(0xAB);
-    const _REQUEST_SIEGE_DEFENDER_LIST: ClientPacketId = ClientPacketId::new(0xAC);
-    const _REQUEST_JOIN_SIEGE: ClientPacketId = ClientPacketId::new(0xAD);
-    const _REQUEST_CONFIRM_SIEGE_WAITING_LIST: ClientPacketId = ClientPacketId::new(0xAE);
-    const _REQUEST_SET_CASTLE_SIEGE_TIME: ClientPacketId = ClientPacketId::new(0xAF);
+    const REQUEST_SIEGE_ATTACKER_LIST: ClientPacketId = ClientPacketId::new(0xAB);
+    const REQUEST_SIEGE_DEFENDER_LIST: ClientPacketId = ClientPacketId::new(0xAC);
+    const REQUEST_JOIN_SIEGE: ClientPacketId = ClientPacketId::new(0xAD);
+    const REQUEST_CONFIRM_SIEGE_WAITING_LIST: ClientPacketId = ClientPacketId::new(0xAE);
+    const REQUEST_SET_CASTLE_SIEGE_TIME: ClientPacketId = ClientPacketId::new(0xAF);
     const MULTI_SELL_CHOOSE: ClientPacketId = ClientPacketId::new(0xB0);
     const NET_PING: ClientPacketId = ClientPacketId::new(0xB1);
     const _REQUEST_REMAIN_TIME: ClientPacketId = ClientPacketId::new(0xB2);
     const SINGLE_SLASH_COMMAND: ClientPacketId = ClientPacketId::new(0xB3);
     const _SNOOP_QUIT: ClientPacketId = ClientPacketId::new(0xB4);
-    const _REQUEST_RECIPE_BOOK_OPEN: ClientPacketId = ClientPacketId::new(0xB5);
-    const _REQUEST_RECIPE_BOOK_DESTROY: ClientPacketId = ClientPacketId::new(0xB6);
-    const _REQUEST_RECIPE_ITEM_MAKE_INFO: ClientPacketId = ClientPacketId::new(0xB7);
-    const _REQUEST_RECIPE_ITEM_MAKE_SELF: ClientPacketId = ClientPacketId::new(0xB8);
-    const _REQUEST_RECIPE_SHOP_MANAGE_LIST: ClientPacketId = ClientPacketId::new(0xB9);
-    const _REQUEST_RECIPE_SHOP_MESSAGE_SET: ClientPacketId = ClientPacketId::new(0xBA);
-    const _REQUEST_RECIPE_SHOP_LIST_SET: ClientPacketId = ClientPacketId::new(0xBB);
-    const _REQUEST_RECIPE_SHOP_MANAGE_QUIT: ClientPacketId = ClientPacketId::new(0xBC);
-    const _REQUEST_RECIPE_SHOP_MANAGE_CANCEL: ClientPacketId = ClientPacketId::new(0xBD);
-    const _REQUEST_RECIPE_SHOP_MAKE_INFO: ClientPacketId = ClientPacketId::new(0xBE);
-    const _REQUEST_RECIPE_SHOP_MAKE_ITEM: ClientPacketId = ClientPacketId::new(0xBF);
-    const _REQUEST_RECIPE_SHOP_MANAGE_PREV: ClientPacketId = ClientPacketId::new(0xC0);
-    const _OBSERVER_RETURN: ClientPacketId = ClientPacketId::new(0xC1);
+    const REQUEST_RECIPE_BOOK_OPEN: ClientPacketId = ClientPacketId::new(0xB5);
+    const REQUEST_RECIPE_BOOK_DESTROY: ClientPacketId = ClientPacketId::new(0xB6);
+    const REQUEST_RECIPE_ITEM_MAKE_INFO: ClientPacketId = ClientPacketId::new(0xB7);
+    const REQUEST_RECIPE_ITEM_MAKE_SELF: ClientPacketId = ClientPacketId::new(0xB8);
+    const REQUEST_RECIPE_SHOP_MANAGE_LIST: ClientPacketId = ClientPacketId::new(0xB9);
+    const REQUEST_RECIPE_SHOP_MESSAGE_SET: ClientPacketId = ClientPacketId::new(0xBA);
+    const REQUEST_RECIPE_SHOP_LIST_SET: ClientPacketId = ClientPacketId::new(0xBB);
+    const REQUEST_RECIPE_SHOP_MANAGE_QUIT: ClientPacketId = ClientPacketId::new(0xBC);
+    const REQUEST_RECIPE_SHOP_MANAGE_CANCEL: ClientPacketId = ClientPacketId::new(0xBD);
+    const REQUEST_RECIPE_SHOP_MAKE_INFO: ClientPacketId = ClientPacketId::new(0xBE);
+    const REQUEST_RECIPE_SHOP_MAKE_ITEM: ClientPacketId = ClientPacketId::new(0xBF);
+    const REQUEST_RECIPE_SHOP_MANAGE_PREV: ClientPacketId = ClientPacketId::new(0xC0);
+    const OBSERVER_RETURN: ClientPacketId = ClientPacketId::new(0xC1);
     const _REQUEST_EVALUATE: ClientPacketId = ClientPacketId::new(0xC2);
-    const _REQUEST_HENNA_ITEM_LIST: ClientPacketId = ClientPacketId::new(0xC3);
-    const _REQUEST_HENNA_ITEM_INFO: ClientPacketId = ClientPacketId::new(0xC4);
-    const _REQUEST_BUY_SEED: ClientPacketId = ClientPacketId::new(0xC5);
+    const REQUEST_HENNA_ITEM_LIST: ClientPacketId = ClientPacketId::new(0xC3);
+    const REQUEST_HENNA_ITEM_INFO: ClientPacketId = ClientPacketId::new(0xC4);
+    const REQUEST_BUY_SEED: ClientPacketId = ClientPacketId::new(0xC5);
     const _DLG_ANSWER: ClientPacketId = ClientPacketId::new(0xC6);
     const _REQUEST_PREVIEW_ITEM: ClientPacketId = ClientPacketId::new(0xC7);
-    const _REQUEST_SSQ_STATUS: ClientPacketId = ClientPacketId::new(0xC8);
+    const REQUEST_SSQ_STATUS: ClientPacketId = ClientPacketId::new(0xC8);
     const _REQUEST_PETITION_FEEDBACK: ClientPacketId = ClientPacketId::new(0xC9);
     const _GAME_GUARD_REPLY: ClientPacketId = ClientPacketId::new(0xCB);
     const _REQUEST_PLEDGE_POWER: ClientPacketId = ClientPacketId::new(0xCC);
-    const _REQUEST_MAKE_MACRO: ClientPacketId = ClientPacketId::new(0xCD);
-    const _REQUEST_DELETE_MACRO: ClientPacketId = ClientPacketId::new(0xCE);
-    const _REQUEST_BUY_PROCURE: ClientPacketId = ClientPacketId::new(0xCF);
+    const REQUEST_MAKE_MACRO: ClientPacketId = ClientPacketId::new(0xCD);
+    const REQUEST_DELETE_MACRO: ClientPacketId = ClientPacketId::new(0xCE);
+    const REQUEST_BUY_PROCURE: ClientPacketId = ClientPacketId::new(0xCF);
     // Ex-packets
     const REQUEST_GO_TO_LOBBY: ClientPacketId = ClientPacketId::new_ex(0x36);
     const REQUEST_MANOR_LIST: ClientPacketId = ClientPacketId::new_ex(0x01);
+    const REQUEST_SET_SEED: ClientPacketId = ClientPacketId::new_ex(0x03);
+    const REQUEST_SET_CROP: ClientPacketId = ClientPacketId::new_ex(0x04);
     const REQUEST_KEY_MAPPING: ClientPacketId = ClientPacketId::new_ex(0x21);
     const REQUEST_DISPEL: ClientPacketId = ClientPacketId::new_ex(0x4B);
     const REQUEST_AUTO_SOULSHOT: ClientPacketId = ClientPacketId::new_ex(0x0D);
@@ -362,6 +425,153 @@ impl TryFrom<ClientPacketBuffer> for GameClientPacket {
             GameClientPacketCodes::REQUEST_AUTO_SOULSHOT => Ok(Self::RequestAutoShots(
                 request_auto_shots::RequestAutoShots::try_from(buffer)?,
             )),
+            GameClientPacketCodes::REQUEST_HENNA_EQUIP => Ok(Self::RequestHennaEquip(
+                henna::RequestHennaEquip::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_HENNA_REMOVE_LIST => Ok(Self::RequestHennaRemoveList(
+                henna::RequestHennaRemoveList::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_HENNA_ITEM_REMOVE_INFO => {
+                Ok(Self::RequestHennaItemRemoveInfo(
+                    henna::RequestHennaItemRemoveInfo::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::REQUEST_HENNA_REMOVE => Ok(Self::RequestHennaRemove(
+                henna::RequestHennaRemove::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_HENNA_ITEM_LIST => Ok(Self::RequestHennaItemList(
+                henna::RequestHennaItemList::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_HENNA_ITEM_INFO => Ok(Self::RequestHennaItemInfo(
+                henna::RequestHennaItemInfo::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_RECIPE_BOOK_OPEN => Ok(Self::RequestRecipeBookOpen(
+                recipe::RequestRecipeBookOpen::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_RECIPE_BOOK_DESTROY => Ok(
+                Self::RequestRecipeBookDestroy(recipe::RequestRecipeBookDestroy::try_from(buffer)?),
+            ),
+            GameClientPacketCodes::REQUEST_RECIPE_ITEM_MAKE_INFO => {
+                Ok(Self::RequestRecipeItemMakeInfo(
+                    recipe::RequestRecipeItemMakeInfo::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::REQUEST_RECIPE_ITEM_MAKE_SELF => {
+                Ok(Self::RequestRecipeItemMakeSelf(
+                    recipe::RequestRecipeItemMakeSelf::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::REQUEST_RECIPE_SHOP_MANAGE_LIST => {
+                Ok(Self::RequestRecipeShopManageList)
+            }
+            GameClientPacketCodes::REQUEST_RECIPE_SHOP_MESSAGE_SET => {
+                Ok(Self::RequestRecipeShopMessageSet(
+                    recipe::RequestRecipeShopMessageSet::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::REQUEST_RECIPE_SHOP_LIST_SET => Ok(
+                Self::RequestRecipeShopListSet(recipe::RequestRecipeShopListSet::try_from(buffer)?),
+            ),
+            GameClientPacketCodes::REQUEST_RECIPE_SHOP_MANAGE_QUIT => {
+                Ok(Self::RequestRecipeShopManageQuit)
+            }
+            GameClientPacketCodes::REQUEST_RECIPE_SHOP_MANAGE_CANCEL => {
+                Ok(Self::RequestRecipeShopManageCancel)
+            }
+            GameClientPacketCodes::REQUEST_RECIPE_SHOP_MAKE_INFO => {
+                Ok(Self::RequestRecipeShopMakeInfo(
+                    recipe::RequestRecipeShopMakeInfo::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::REQUEST_RECIPE_SHOP_MAKE_ITEM => {
+                Ok(Self::RequestRecipeShopMakeItem(
+                    recipe::RequestRecipeShopMakeItem::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::REQUEST_RECIPE_SHOP_MANAGE_PREV => {
+                Ok(Self::RequestRecipeShopManagePrev)
+            }
+            GameClientPacketCodes::REQUEST_FRIEND_INVITE => Ok(Self::RequestFriendInvite(
+                friend::RequestFriendInvite::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_ANSWER_FRIEND_INVITE => {
+                Ok(Self::RequestAnswerFriendInvite(
+                    friend::RequestAnswerFriendInvite::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::REQUEST_FRIEND_LIST => Ok(Self::RequestFriendList),
+            GameClientPacketCodes::REQUEST_FRIEND_DEL => Ok(Self::RequestFriendDel(
+                friend::RequestFriendDel::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_SEND_FRIEND_MSG => Ok(Self::RequestSendFriendMsg(
+                friend::RequestSendFriendMsg::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_BLOCK => {
+                Ok(Self::RequestBlock(friend::RequestBlock::try_from(buffer)?))
+            }
+            GameClientPacketCodes::REQUEST_MAKE_MACRO => Ok(Self::RequestMakeMacro(
+                macros::RequestMakeMacro::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_DELETE_MACRO => Ok(Self::RequestDeleteMacro(
+                macros::RequestDeleteMacro::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_SIEGE_ATTACKER_LIST => Ok(
+                Self::RequestSiegeAttackerList(siege::RequestSiegeAttackerList::try_from(buffer)?),
+            ),
+            GameClientPacketCodes::REQUEST_SIEGE_DEFENDER_LIST => Ok(
+                Self::RequestSiegeDefenderList(siege::RequestSiegeDefenderList::try_from(buffer)?),
+            ),
+            GameClientPacketCodes::REQUEST_JOIN_SIEGE => Ok(Self::RequestJoinSiege(
+                siege::RequestJoinSiege::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_CONFIRM_SIEGE_WAITING_LIST => {
+                Ok(Self::RequestConfirmSiegeWaitingList(
+                    siege::RequestConfirmSiegeWaitingList::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::REQUEST_SET_CASTLE_SIEGE_TIME => {
+                Ok(Self::RequestSetCastleSiegeTime(
+                    siege::RequestSetCastleSiegeTime::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::REQUEST_BUY_SEED => Ok(Self::RequestBuySeed(
+                manor::RequestBuySeed::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_BUY_PROCURE => Ok(Self::RequestBuyProcure(
+                manor::RequestBuyProcure::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_SET_SEED => Ok(Self::RequestSetSeed(
+                manor::RequestSetSeed::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_SET_CROP => Ok(Self::RequestSetCrop(
+                manor::RequestSetCrop::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_SSQ_STATUS => Ok(Self::RequestSsqStatus(
+                seven_signs::RequestSsqStatus::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::OBSERVER_RETURN => Ok(Self::ObserverReturn),
+            GameClientPacketCodes::REQUEST_GET_ON_VEHICLE => Ok(Self::RequestGetOnVehicle(
+                vehicle::RequestGetOnVehicle::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_GET_OFF_VEHICLE => Ok(Self::RequestGetOffVehicle(
+                vehicle::RequestGetOffVehicle::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_MOVE_TO_LOCATION_IN_VEHICLE => {
+                Ok(Self::RequestMoveToLocationInVehicle(
+                    vehicle::RequestMoveToLocationInVehicle::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::CANNOT_MOVE_ANYMORE_IN_VEHICLE => {
+                Ok(Self::CannotMoveAnymoreInVehicle(
+                    vehicle::CannotMoveAnymoreInVehicle::try_from(buffer)?,
+                ))
+            }
+            GameClientPacketCodes::REQUEST_ACQUIRE_SKILL_INFO => Ok(Self::RequestAcquireSkillInfo(
+                acquire_skill::RequestAcquireSkillInfo::try_from(buffer)?,
+            )),
+            GameClientPacketCodes::REQUEST_ACQUIRE_SKILL => Ok(Self::RequestAcquireSkill(
+                acquire_skill::RequestAcquireSkill::try_from(buffer)?,
+            )),
             _ => {
                 // Its possible to throw an error here, but we can just log it and return an unknown packet
                 // because if we throw an error here, the client will disconnect
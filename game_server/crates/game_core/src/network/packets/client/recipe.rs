@@ -0,0 +1,126 @@
+use crate::{
+    object_id::ObjectId,
+    recipe::{self, RecipeBookKind},
+};
+use bevy::prelude::*;
+use derive_more::{From, Into};
+use l2r_core::packets::{ClientPacketBuffer, L2rSerializeError};
+use std::convert::TryFrom;
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestRecipeBookOpen(pub RecipeBookKind);
+
+impl TryFrom<ClientPacketBuffer> for RequestRecipeBookOpen {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(RecipeBookKind::from(buffer.u32()?)))
+    }
+}
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestRecipeBookDestroy(pub recipe::Id);
+
+impl TryFrom<ClientPacketBuffer> for RequestRecipeBookDestroy {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(recipe::Id::from(buffer.u32()?)))
+    }
+}
+
+/// Player selected a recipe in the book and wants to see the craft window.
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestRecipeItemMakeInfo(pub recipe::Id);
+
+impl TryFrom<ClientPacketBuffer> for RequestRecipeItemMakeInfo {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(recipe::Id::from(buffer.u32()?)))
+    }
+}
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestRecipeItemMakeSelf(pub recipe::Id);
+
+impl TryFrom<ClientPacketBuffer> for RequestRecipeItemMakeSelf {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(recipe::Id::from(buffer.u32()?)))
+    }
+}
+
+/// Message shown above the crafter while the manufacture shop is opened.
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestRecipeShopMessageSet(pub String);
+
+impl TryFrom<ClientPacketBuffer> for RequestRecipeShopMessageSet {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(buffer.str()?))
+    }
+}
+
+/// Recipes posted to the manufacture shop with the fee for each craft.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestRecipeShopListSet {
+    pub entries: Vec<(recipe::Id, u64)>,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestRecipeShopListSet {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let count = buffer.u32()?;
+        let mut entries =
+            Vec::with_capacity(count.min(recipe::DWARVEN_RECIPE_LIMIT as u32) as usize);
+        for _ in 0..count {
+            let id = recipe::Id::from(buffer.u32()?);
+            let fee = buffer.u64()?;
+            entries.push((id, fee));
+        }
+        Ok(Self { entries })
+    }
+}
+
+/// Player clicked on a recipe in the manufacture shop of another player.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestRecipeShopMakeInfo {
+    pub seller: ObjectId,
+    pub recipe_id: recipe::Id,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestRecipeShopMakeInfo {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let seller = ObjectId::from(buffer.u32()?);
+        let recipe_id = recipe::Id::from(buffer.u32()?);
+        Ok(Self { seller, recipe_id })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestRecipeShopMakeItem {
+    pub seller: ObjectId,
+    pub recipe_id: recipe::Id,
+    pub fee: u64,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestRecipeShopMakeItem {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let seller = ObjectId::from(buffer.u32()?);
+        let recipe_id = recipe::Id::from(buffer.u32()?);
+        let fee = buffer.u64()?;
+        Ok(Self {
+            seller,
+            recipe_id,
+            fee,
+        })
+    }
+}
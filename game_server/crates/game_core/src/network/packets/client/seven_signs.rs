@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use derive_more::{From, Into};
+use l2r_core::packets::{ClientPacketBuffer, L2rSerializeError};
+use std::convert::TryFrom;
+
+/// Page of the Seven Signs record, sent when the record book is used or the status shown at the priest.
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestSsqStatus(pub u8);
+
+impl TryFrom<ClientPacketBuffer> for RequestSsqStatus {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(buffer.u8()?))
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    clan::{self, castle::CastleId},
+    utils::ReflectableDateTime,
+};
+use bevy::prelude::*;
+use chrono::DateTime;
+use derive_more::{From, Into};
+use l2r_core::packets::{ClientPacketBuffer, L2rSerializeError};
+use num_enum::TryFromPrimitive;
+use std::convert::TryFrom;
+
+fn read_castle_id(buffer: &mut ClientPacketBuffer) -> Result<CastleId, L2rSerializeError> {
+    let castle_id = buffer.u32()?;
+    u8::try_from(castle_id)
+        .ok()
+        .and_then(|id| CastleId::try_from_primitive(id).ok())
+        .ok_or_else(|| {
+            L2rSerializeError::new(format!("Unknown castle id: {castle_id}"), buffer.as_slice())
+        })
+}
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestSiegeAttackerList(pub CastleId);
+
+impl TryFrom<ClientPacketBuffer> for RequestSiegeAttackerList {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(read_castle_id(&mut buffer)?))
+    }
+}
+
+#[derive(Clone, Debug, From, Into, PartialEq, Reflect)]
+pub struct RequestSiegeDefenderList(pub CastleId);
+
+impl TryFrom<ClientPacketBuffer> for RequestSiegeDefenderList {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        Ok(Self(read_castle_id(&mut buffer)?))
+    }
+}
+
+/// Registration of the clan for the siege or the cancellation of it, sent by the clan leader.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestJoinSiege {
+    pub castle_id: CastleId,
+    pub attacker: bool,
+    pub join: bool,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestJoinSiege {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let castle_id = read_castle_id(&mut buffer)?;
+        let attacker = buffer.bool_from_u32()?;
+        let join = buffer.bool_from_u32()?;
+        Ok(Self {
+            castle_id,
+            attacker,
+            join,
+        })
+    }
+}
+
+/// Castle owner approves or declines the clan waiting to defend the castle.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestConfirmSiegeWaitingList {
+    pub castle_id: CastleId,
+    pub clan_id: clan::Id,
+    pub approved: bool,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestConfirmSiegeWaitingList {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let castle_id = read_castle_id(&mut buffer)?;
+        let clan_id = clan::Id::from(buffer.u32()?);
+        let approved = buffer.bool_from_u32()?;
+        Ok(Self {
+            castle_id,
+            clan_id,
+            approved,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestSetCastleSiegeTime {
+    pub castle_id: CastleId,
+    pub time: ReflectableDateTime,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestSetCastleSiegeTime {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let castle_id = read_castle_id(&mut buffer)?;
+        let seconds = buffer.u32()?;
+        let time = DateTime::from_timestamp(seconds.into(), 0)
+            .map(|time| ReflectableDateTime::new(time.naive_utc()))
+            .ok_or_else(|| {
+                L2rSerializeError::new(format!("Invalid siege time: {seconds}"), buffer.as_slice())
+            })?;
+        Ok(Self { castle_id, time })
+    }
+}
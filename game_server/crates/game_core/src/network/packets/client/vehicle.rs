@@ -0,0 +1,91 @@
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{ClientPacketBuffer, L2rSerializeError};
+use spatial::GameVec3;
+use std::convert::TryFrom;
+
+/// Player boards the docked vehicle, the position is relative to the vehicle.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestGetOnVehicle {
+    pub vehicle_oid: ObjectId,
+    pub position: Vec3,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestGetOnVehicle {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let vehicle_oid = ObjectId::from(buffer.u32()?);
+        let position = GameVec3::try_from(&mut buffer)?.into();
+        Ok(Self {
+            vehicle_oid,
+            position,
+        })
+    }
+}
+
+/// Player goes ashore at the location in the world.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestGetOffVehicle {
+    pub vehicle_oid: ObjectId,
+    pub location: Vec3,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestGetOffVehicle {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let vehicle_oid = ObjectId::from(buffer.u32()?);
+        let location = GameVec3::try_from(&mut buffer)?.into();
+        Ok(Self {
+            vehicle_oid,
+            location,
+        })
+    }
+}
+
+/// Passenger walks across the deck, positions are relative to the vehicle.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct RequestMoveToLocationInVehicle {
+    pub vehicle_oid: ObjectId,
+    pub target: Vec3,
+    pub origin: Vec3,
+}
+
+impl TryFrom<ClientPacketBuffer> for RequestMoveToLocationInVehicle {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let vehicle_oid = ObjectId::from(buffer.u32()?);
+        let target = GameVec3::try_from(&mut buffer)?.into();
+        let origin = GameVec3::try_from(&mut buffer)?.into();
+        Ok(Self {
+            vehicle_oid,
+            target,
+            origin,
+        })
+    }
+}
+
+/// Sent by client when the passenger stops on the deck.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct CannotMoveAnymoreInVehicle {
+    pub vehicle_oid: ObjectId,
+    pub position: Vec3,
+    pub heading: i32,
+}
+
+impl TryFrom<ClientPacketBuffer> for CannotMoveAnymoreInVehicle {
+    type Error = L2rSerializeError;
+
+    fn try_from(mut buffer: ClientPacketBuffer) -> Result<Self, Self::Error> {
+        let vehicle_oid = ObjectId::from(buffer.u32()?);
+        let position = GameVec3::try_from(&mut buffer)?.into();
+        let heading = buffer.i32()?;
+        Ok(Self {
+            vehicle_oid,
+            position,
+            heading,
+        })
+    }
+}
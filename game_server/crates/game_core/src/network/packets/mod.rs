@@ -7,4 +7,9 @@ use strum::Display;
 #[strum(serialize_all = "snake_case")]
 pub enum GameServerPacketMetric {
     PacketsSent,
+    /// Broadcast packets encrypted from the shared buffer instead of being serialized again.
+    SerializationsSaved,
+    SerializedBytesSaved,
+    /// Packets written to the socket together with the other packets of the tick.
+    WritesSaved,
 }
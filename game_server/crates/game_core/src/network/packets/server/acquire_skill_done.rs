@@ -0,0 +1,15 @@
+use super::GameServerPacketCodes;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Closes the skill learning window once the skill is learned.
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct AcquireSkillDone;
+
+impl L2rServerPacket for AcquireSkillDone {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::ACQUIRE_SKILL_DONE.to_le_bytes());
+        buffer
+    }
+}
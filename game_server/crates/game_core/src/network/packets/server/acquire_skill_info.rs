@@ -0,0 +1,57 @@
+use super::GameServerPacketCodes;
+use crate::{
+    items,
+    skills::{self, AcquireSkillType},
+};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Cost of learning the skill level shown before the character confirms it.
+#[derive(Clone, Debug, Reflect)]
+pub struct AcquireSkillInfo {
+    id: skills::Id,
+    level: skills::Level,
+    sp: u32,
+    skill_type: AcquireSkillType,
+    items: Vec<(items::Id, u64)>,
+}
+
+impl AcquireSkillInfo {
+    /// Requirement type the client expects for the items consumed by learning.
+    const ITEM_REQUIREMENT: u32 = 4;
+
+    pub fn new(
+        id: skills::Id,
+        level: skills::Level,
+        sp: u32,
+        skill_type: AcquireSkillType,
+        items: Vec<(items::Id, u64)>,
+    ) -> Self {
+        Self {
+            id,
+            level,
+            sp,
+            skill_type,
+            items,
+        }
+    }
+}
+
+impl L2rServerPacket for AcquireSkillInfo {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::ACQUIRE_SKILL_INFO.to_le_bytes());
+        buffer.u32(self.id.into());
+        buffer.u32(self.level.into());
+        buffer.u32(self.sp);
+        buffer.u32(self.skill_type.into());
+        buffer.u32_from_usize(self.items.len());
+        for (item_id, count) in self.items {
+            buffer.u32(Self::ITEM_REQUIREMENT);
+            buffer.u32(item_id.into());
+            buffer.u64(count);
+            buffer.u32(0);
+        }
+        buffer
+    }
+}
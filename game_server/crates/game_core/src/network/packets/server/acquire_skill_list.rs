@@ -0,0 +1,46 @@
+use super::GameServerPacketCodes;
+use crate::skills::{self, AcquireSkillType};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+#[derive(Clone, Debug, Reflect)]
+pub struct AcquireSkillListEntry {
+    pub id: skills::Id,
+    pub next_level: skills::Level,
+    pub max_level: skills::Level,
+    pub sp: u32,
+    pub requirements: u32,
+}
+
+/// Skills the character may learn next from the skill tree.
+#[derive(Clone, Debug, Reflect)]
+pub struct AcquireSkillList {
+    skill_type: AcquireSkillType,
+    entries: Vec<AcquireSkillListEntry>,
+}
+
+impl AcquireSkillList {
+    pub fn new(skill_type: AcquireSkillType, entries: Vec<AcquireSkillListEntry>) -> Self {
+        Self {
+            skill_type,
+            entries,
+        }
+    }
+}
+
+impl L2rServerPacket for AcquireSkillList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::ACQUIRE_SKILL_LIST.to_le_bytes());
+        buffer.u32(self.skill_type.into());
+        buffer.u32_from_usize(self.entries.len());
+        for entry in self.entries {
+            buffer.u32(entry.id.into());
+            buffer.u32(entry.next_level.into());
+            buffer.u32(entry.max_level.into());
+            buffer.u32(entry.sp);
+            buffer.u32(entry.requirements);
+        }
+        buffer
+    }
+}
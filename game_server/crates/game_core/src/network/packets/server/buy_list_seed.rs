@@ -0,0 +1,42 @@
+use super::GameServerPacketCodes;
+use crate::{clan::castle::CastleId, manor::ManorEntry};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Seeds produced by the manor in the current period, offered by the manor manager.
+#[derive(Clone, Debug, Reflect)]
+pub struct BuyListSeed {
+    adena: u64,
+    castle_id: CastleId,
+    seeds: Vec<ManorEntry>,
+}
+
+impl BuyListSeed {
+    pub fn new(adena: u64, castle_id: CastleId, seeds: Vec<ManorEntry>) -> Self {
+        Self {
+            adena,
+            castle_id,
+            seeds,
+        }
+    }
+}
+
+impl L2rServerPacket for BuyListSeed {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::BUY_LIST_SEED.to_le_bytes());
+        buffer.u64(self.adena);
+        buffer.u32(u8::from(self.castle_id).into());
+        buffer.u16_from_usize(self.seeds.len());
+        for seed in self.seeds {
+            buffer.u16(4); // item type 1
+            buffer.u32(0); // object id
+            buffer.u32(seed.item_id.into());
+            buffer.u64(seed.amount);
+            buffer.u16(4); // item type 2
+            buffer.u16(0);
+            buffer.u64(seed.price);
+        }
+        buffer
+    }
+}
@@ -0,0 +1,46 @@
+use super::GameServerPacketCodes;
+use crate::clan::{Clan, castle::CastleId};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+#[derive(Clone, Debug, Reflect)]
+pub struct CastleSiegeAttackerList {
+    castle_id: CastleId,
+    attackers: Vec<Clan>,
+}
+
+impl CastleSiegeAttackerList {
+    pub fn new(castle_id: CastleId, attackers: Vec<Clan>) -> Self {
+        Self {
+            castle_id,
+            attackers,
+        }
+    }
+}
+
+impl L2rServerPacket for CastleSiegeAttackerList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::CASTLE_SIEGE_ATTACKER_LIST.to_le_bytes());
+        buffer.u32(u8::from(self.castle_id).into());
+        buffer.u32(0);
+        buffer.u32(1);
+        buffer.u32(0);
+        buffer.u32_from_usize(self.attackers.len());
+        buffer.u32_from_usize(self.attackers.len());
+        for clan in self.attackers {
+            buffer.u32(*clan.id);
+            buffer.str(&clan.name);
+            buffer.str(&clan.leader_name);
+            // crest id and registration time
+            buffer.u32(0);
+            buffer.u32(0);
+            // alliance id, name, leader name and crest id
+            buffer.u32(0);
+            buffer.str("");
+            buffer.str("");
+            buffer.u32(0);
+        }
+        buffer
+    }
+}
@@ -0,0 +1,61 @@
+use super::GameServerPacketCodes;
+use crate::clan::{Clan, castle::CastleId};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+#[derive(Clone, Copy, Debug, Reflect)]
+#[repr(u32)]
+pub enum CastleSiegeDefenderType {
+    Owner = 1,
+    Pending = 2,
+    Defender = 3,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct CastleSiegeDefender {
+    pub clan: Clan,
+    pub kind: CastleSiegeDefenderType,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct CastleSiegeDefenderList {
+    castle_id: CastleId,
+    defenders: Vec<CastleSiegeDefender>,
+}
+
+impl CastleSiegeDefenderList {
+    pub fn new(castle_id: CastleId, defenders: Vec<CastleSiegeDefender>) -> Self {
+        Self {
+            castle_id,
+            defenders,
+        }
+    }
+}
+
+impl L2rServerPacket for CastleSiegeDefenderList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::CASTLE_SIEGE_DEFENDER_LIST.to_le_bytes());
+        buffer.u32(u8::from(self.castle_id).into());
+        buffer.u32(0);
+        buffer.u32(1);
+        buffer.u32(0);
+        buffer.u32_from_usize(self.defenders.len());
+        buffer.u32_from_usize(self.defenders.len());
+        for defender in self.defenders {
+            buffer.u32(*defender.clan.id);
+            buffer.str(&defender.clan.name);
+            buffer.str(&defender.clan.leader_name);
+            // crest id and registration time
+            buffer.u32(0);
+            buffer.u32(0);
+            buffer.u32(defender.kind as u32);
+            // alliance id, name, leader name and crest id
+            buffer.u32(0);
+            buffer.str("");
+            buffer.str("");
+            buffer.u32(0);
+        }
+        buffer
+    }
+}
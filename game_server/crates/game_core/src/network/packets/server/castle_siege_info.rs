@@ -0,0 +1,70 @@
+use super::GameServerPacketCodes;
+use crate::{
+    clan::{self, castle::CastleId},
+    utils::ReflectableDateTime,
+};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+#[derive(Clone, Debug, Reflect)]
+pub struct CastleSiegeInfoOwner {
+    pub clan_id: clan::Id,
+    pub clan_name: String,
+    pub leader_name: String,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct CastleSiegeInfo {
+    castle_id: CastleId,
+    owner: Option<CastleSiegeInfoOwner>,
+    /// Whether the receiver leads the owning clan and can pick the siege time.
+    is_owner_leader: bool,
+    now: ReflectableDateTime,
+    siege_date: ReflectableDateTime,
+}
+
+impl CastleSiegeInfo {
+    pub fn new(
+        castle_id: CastleId,
+        owner: Option<CastleSiegeInfoOwner>,
+        is_owner_leader: bool,
+        now: ReflectableDateTime,
+        siege_date: ReflectableDateTime,
+    ) -> Self {
+        Self {
+            castle_id,
+            owner,
+            is_owner_leader,
+            now,
+            siege_date,
+        }
+    }
+}
+
+impl L2rServerPacket for CastleSiegeInfo {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::CASTLE_SIEGE_INFO.to_le_bytes());
+        buffer.u32(u8::from(self.castle_id).into());
+        buffer.u32_from_bool(self.is_owner_leader);
+        match self.owner {
+            Some(owner) => {
+                buffer.u32(*owner.clan_id);
+                buffer.str(&owner.clan_name);
+                buffer.str(&owner.leader_name);
+            }
+            None => {
+                buffer.u32(0);
+                buffer.str("");
+                buffer.str("");
+            }
+        }
+        // alliance id and name
+        buffer.u32(0);
+        buffer.str("");
+        buffer.u32(self.now.and_utc().timestamp() as u32);
+        buffer.u32(self.siege_date.and_utc().timestamp() as u32);
+        buffer.u32(0);
+        buffer
+    }
+}
@@ -40,6 +40,12 @@ pub struct CharInfo {
     pub dead: bool,
     pub standing: bool,
     pub in_party_match_room: bool,
+    pub private_store_type: u8,
+    pub noblesse: bool,
+    pub hero: bool,
+    pub vehicle_oid: Option<ObjectId>,
+    /// Where the bobber floats while the character is fishing.
+    pub fishing: Option<GameVec3>,
     //TODO: для дебага
     pub entity: Entity,
 }
@@ -68,7 +74,7 @@ impl L2rServerPacket for CharInfo {
 
         buffer.extend(GameServerPacketCodes::CHAR_INFO.to_le_bytes());
         buffer.extend(converted_pos.to_le_bytes());
-        buffer.u32(0); // vehicle id
+        buffer.u32(self.vehicle_oid.unwrap_or_default().into());
         buffer.u32(u32::from(self.object_id));
         buffer.str(&self.name);
         buffer.u32(self.race.into());
@@ -120,7 +126,7 @@ impl L2rServerPacket for CharInfo {
         buffer.bool(self.dead);
         buffer.bool(self.invisible);
         buffer.u8(0); // mount type 1 - strider, 2 - wyvern, 3 - great wolf, 0 - none
-        buffer.u8(0); // private store type
+        buffer.u8(self.private_store_type);
         buffer.u16(0); // cubics size
         // TODO: extend with cubic-ids u16 later
         buffer.bool(self.in_party_match_room);
@@ -133,12 +139,10 @@ impl L2rServerPacket for CharInfo {
         buffer.u8(0); // enchant effect
         buffer.u8(0); // team id
         buffer.u32(0); // clan crest large id
-        buffer.bool(false); // noble
-        buffer.bool(false); // hero
-        buffer.bool(false); // fishing mode
-        buffer.i32(0); // fishing x
-        buffer.i32(0); // fishing y
-        buffer.i32(0); // fishing z
+        buffer.bool(self.noblesse);
+        buffer.bool(self.hero); // hero aura
+        buffer.bool(self.fishing.is_some()); // fishing mode
+        buffer.extend(self.fishing.unwrap_or_default().to_le_bytes()); // fishing x, y, z
         buffer.u32(u32::MAX); // name color
         buffer.i32(rotation_heading.into());
         buffer.u32(0); // pledge class
@@ -203,6 +207,14 @@ impl CharInfo {
             in_party_match_room: false,
             invisible,
             standing: query.sitting.is_none(),
+            private_store_type: query
+                .recipe_shop
+                .map(|shop| shop.store_type())
+                .unwrap_or_default(),
+            noblesse: query.noblesse,
+            hero: query.hero,
+            vehicle_oid: query.in_vehicle.map(|in_vehicle| in_vehicle.vehicle_oid),
+            fishing: query.fishing.map(|fishing| GameVec3::from(fishing.bobber)),
             entity: query.entity,
         }
     }
@@ -56,7 +56,7 @@ impl CharSelectionInfo {
 pub struct CharInfoData {
     pub name: String,
     pub title: String,
-    pub session_id: SessionId,
+    pub session_id: Option<SessionId>,
     pub clan_id: u32,
     pub builder_level: u32,
     pub race: Race,
@@ -94,7 +94,7 @@ impl CharInfoData {
         buffer.str(&self.name);
         buffer.u32(0);
         buffer.str(&self.title);
-        buffer.u32_from_usize(*self.session_id);
+        buffer.u32_from_usize(self.session_id.map(|id| *id).unwrap_or_default());
         buffer.u32(self.clan_id);
         buffer.u32(self.builder_level);
         buffer.u32(self.appearance.gender.into());
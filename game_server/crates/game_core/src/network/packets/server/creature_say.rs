@@ -57,7 +57,6 @@ impl CreatureSay {
         match self.chat_type {
             chat::Kind::General => BroadcastScope::Radius(1250.0),
             chat::Kind::NpcGeneral => BroadcastScope::Radius(1250.0),
-            chat::Kind::Boat => BroadcastScope::Radius(1250.0),
             chat::Kind::Shout => BroadcastScope::InRegion,
             chat::Kind::NpcShout => BroadcastScope::InRegion,
             chat::Kind::Trade => BroadcastScope::InRegion,
@@ -70,6 +69,9 @@ impl CreatureSay {
             chat::Kind::Clan => {
                 BroadcastScope::Entities(self.recievers.clone().unwrap_or_default())
             }
+            chat::Kind::Boat => {
+                BroadcastScope::Entities(self.recievers.clone().unwrap_or_default())
+            }
             chat::Kind::Gm => BroadcastScope::All,
             chat::Kind::Announcement => BroadcastScope::All,
             chat::Kind::CriticalAnnounce => BroadcastScope::All,
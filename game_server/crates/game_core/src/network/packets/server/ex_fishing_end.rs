@@ -0,0 +1,27 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Angler reels the line in, with the fish or without.
+#[derive(Clone, Debug, Reflect)]
+pub struct ExFishingEnd {
+    object_id: ObjectId,
+    caught: bool,
+}
+
+impl L2rServerPacket for ExFishingEnd {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::EX_FISHING_END.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.bool(self.caught);
+        buffer
+    }
+}
+
+impl ExFishingEnd {
+    pub fn new(object_id: ObjectId, caught: bool) -> Self {
+        Self { object_id, caught }
+    }
+}
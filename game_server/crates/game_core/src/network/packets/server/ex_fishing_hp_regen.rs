@@ -0,0 +1,54 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Updates the fish combat window every second and after every pumping or reeling.
+#[derive(Clone, Debug, Reflect)]
+pub struct ExFishingHpRegen {
+    object_id: ObjectId,
+    time_left: u32,
+    fish_hp: u32,
+    mode: u8,
+    hit: u8,
+    animation: u8,
+    penalty: u32,
+}
+
+impl L2rServerPacket for ExFishingHpRegen {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::EX_FISHING_HP_REGEN.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.u32(self.time_left);
+        buffer.u32(self.fish_hp);
+        buffer.u8(self.mode);
+        buffer.u8(self.hit);
+        buffer.u8(self.animation);
+        buffer.u32(self.penalty);
+        buffer.u8(0); // hp bar color
+        buffer
+    }
+}
+
+impl ExFishingHpRegen {
+    pub fn new(object_id: ObjectId, time_left: u32, fish_hp: u32, mode: u8) -> Self {
+        Self {
+            object_id,
+            time_left,
+            fish_hp,
+            mode,
+            hit: 0,
+            animation: 0,
+            penalty: 0,
+        }
+    }
+
+    /// Shows the result of the pumping or reeling.
+    pub fn with_hit(mut self, hit: u8, animation: u8, penalty: u32) -> Self {
+        self.hit = hit;
+        self.animation = animation;
+        self.penalty = penalty;
+        self
+    }
+}
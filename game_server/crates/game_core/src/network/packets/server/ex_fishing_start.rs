@@ -0,0 +1,36 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use spatial::GameVec3;
+
+/// Angler casts the line, the bobber falls at the location.
+#[derive(Clone, Debug, Reflect)]
+pub struct ExFishingStart {
+    object_id: ObjectId,
+    fish_type: u32,
+    bobber: Vec3,
+}
+
+impl L2rServerPacket for ExFishingStart {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::EX_FISHING_START.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.u32(self.fish_type);
+        buffer.extend(GameVec3::from(self.bobber).to_le_bytes());
+        buffer.bool(false); // night lure
+        buffer.bool(false); // show fish rank result button
+        buffer
+    }
+}
+
+impl ExFishingStart {
+    pub fn new(object_id: ObjectId, fish_type: u32, bobber: Vec3) -> Self {
+        Self {
+            object_id,
+            fish_type,
+            bobber,
+        }
+    }
+}
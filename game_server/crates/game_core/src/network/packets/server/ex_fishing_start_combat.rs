@@ -0,0 +1,40 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Fish took the bait, opens the fish combat window.
+#[derive(Clone, Debug, Reflect)]
+pub struct ExFishingStartCombat {
+    object_id: ObjectId,
+    time_left: u32,
+    fish_hp: u32,
+    mode: u8,
+    lure_type: u8,
+}
+
+impl L2rServerPacket for ExFishingStartCombat {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::EX_FISHING_START_COMBAT.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.u32(self.time_left);
+        buffer.u32(self.fish_hp);
+        buffer.u8(self.mode);
+        buffer.u8(self.lure_type);
+        buffer.bool(false); // deceptive mode
+        buffer
+    }
+}
+
+impl ExFishingStartCombat {
+    pub fn new(object_id: ObjectId, time_left: u32, fish_hp: u32, mode: u8, lure_type: u8) -> Self {
+        Self {
+            object_id,
+            time_left,
+            fish_hp,
+            mode,
+            lure_type,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use super::GameServerPacketCodes;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Hides the gauges of the opponents once the match is over.
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct ExOlympiadMatchEnd;
+impl L2rServerPacket for ExOlympiadMatchEnd {
+    fn buffer(self) -> ServerPacketBuffer {
+        GameServerPacketCodes::EX_OLYMPIAD_MATCH_END
+            .to_le_bytes()
+            .as_slice()
+            .into()
+    }
+}
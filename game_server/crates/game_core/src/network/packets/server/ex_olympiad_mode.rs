@@ -0,0 +1,25 @@
+use super::GameServerPacketCodes;
+use crate::olympiad::OlympiadSide;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Olympiad interface of the client, shows the gauges of the opponents.
+#[derive(Clone, Copy, Debug, Default, Reflect)]
+pub enum ExOlympiadMode {
+    #[default]
+    Normal,
+    Participant(OlympiadSide),
+    Spectator,
+}
+impl L2rServerPacket for ExOlympiadMode {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::EX_OLYMPIAD_MODE.to_le_bytes());
+        buffer.u8(match self {
+            ExOlympiadMode::Normal => 0,
+            ExOlympiadMode::Participant(side) => side as u8,
+            ExOlympiadMode::Spectator => 3,
+        });
+        buffer
+    }
+}
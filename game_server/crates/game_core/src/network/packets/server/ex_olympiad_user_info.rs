@@ -0,0 +1,56 @@
+use super::GameServerPacketCodes;
+use crate::{
+    object_id::ObjectId,
+    olympiad::OlympiadSide,
+    stats::{ClassId, VitalsStat, VitalsStats},
+};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Gauge of the opponent fighting in the stadium.
+#[derive(Clone, Debug, Reflect)]
+pub struct ExOlympiadUserInfo {
+    side: OlympiadSide,
+    object_id: ObjectId,
+    name: String,
+    class_id: ClassId,
+    hp: u32,
+    max_hp: u32,
+    cp: u32,
+    max_cp: u32,
+}
+impl L2rServerPacket for ExOlympiadUserInfo {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::EX_OLYMPIAD_USER_INFO.to_le_bytes());
+        buffer.u8(self.side as u8);
+        buffer.u32(self.object_id.into());
+        buffer.str(&self.name);
+        buffer.u32(self.class_id.into());
+        buffer.u32(self.hp);
+        buffer.u32(self.max_hp);
+        buffer.u32(self.cp);
+        buffer.u32(self.max_cp);
+        buffer
+    }
+}
+impl ExOlympiadUserInfo {
+    pub fn new(
+        side: OlympiadSide,
+        object_id: ObjectId,
+        name: &Name,
+        class_id: ClassId,
+        vitals: &VitalsStats,
+    ) -> Self {
+        Self {
+            side,
+            object_id,
+            name: name.to_string(),
+            class_id,
+            hp: vitals.get(VitalsStat::Hp) as u32,
+            max_hp: vitals.get(VitalsStat::MaxHp) as u32,
+            cp: vitals.get(VitalsStat::Cp) as u32,
+            max_cp: vitals.get(VitalsStat::MaxCp) as u32,
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use super::GameServerPacketCodes;
+use crate::clan::castle::CastleId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use sea_orm::Iterable;
+
+/// Castles having a manor, shown in the manor info window.
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct ExSendManorList;
+
+impl L2rServerPacket for ExSendManorList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::EX_SEND_MANOR_LIST.to_le_bytes());
+        buffer.u32_from_usize(CastleId::iter().count());
+        for castle_id in CastleId::iter() {
+            buffer.u32(u8::from(castle_id).into());
+            buffer.str(&castle_id.to_string().to_lowercase());
+        }
+        buffer
+    }
+}
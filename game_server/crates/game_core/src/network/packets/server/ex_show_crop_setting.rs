@@ -0,0 +1,44 @@
+use super::{GameServerPacketCodes, ManorSetting};
+use crate::{clan::castle::CastleId, manor::price_range};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Crop procure settings opened by the castle lord at the chamberlain.
+#[derive(Clone, Debug, Reflect)]
+pub struct ExShowCropSetting {
+    castle_id: CastleId,
+    crops: Vec<ManorSetting>,
+}
+
+impl ExShowCropSetting {
+    pub fn new(castle_id: CastleId, crops: Vec<ManorSetting>) -> Self {
+        Self { castle_id, crops }
+    }
+}
+
+impl L2rServerPacket for ExShowCropSetting {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::EX_SHOW_CROP_SETTING.to_le_bytes());
+        buffer.u32(u8::from(self.castle_id).into());
+        buffer.u32_from_usize(self.crops.len());
+        for crop in self.crops {
+            let prices = price_range(crop.reference_price);
+            buffer.u32(crop.item_id.into());
+            buffer.u32(crop.level);
+            buffer.u8(1);
+            buffer.u32(0); // reward 1
+            buffer.u8(1);
+            buffer.u32(0); // reward 2
+            buffer.u32(crop.limit as u32);
+            buffer.u32(0);
+            buffer.u32(*prices.start() as u32);
+            buffer.u32(*prices.end() as u32);
+            for period in [crop.current, crop.next] {
+                ManorSetting::write_period(&mut buffer, period);
+                buffer.u8(0); // reward kind, crops are paid with adena
+            }
+        }
+        buffer
+    }
+}
@@ -0,0 +1,67 @@
+use super::GameServerPacketCodes;
+use crate::{
+    clan::castle::CastleId,
+    items,
+    manor::{ManorEntry, price_range},
+};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Seed or crop of the manor with the lord settings of both periods.
+#[derive(Clone, Debug, Reflect)]
+pub struct ManorSetting {
+    pub item_id: items::Id,
+    pub level: u32,
+    pub limit: u64,
+    pub reference_price: u64,
+    pub current: Option<ManorEntry>,
+    pub next: Option<ManorEntry>,
+}
+
+impl ManorSetting {
+    pub(super) fn write_period(buffer: &mut ServerPacketBuffer, entry: Option<ManorEntry>) {
+        let (start_amount, price) = entry
+            .map(|entry| (entry.start_amount, entry.price))
+            .unwrap_or_default();
+        buffer.u64(start_amount);
+        buffer.u64(price);
+    }
+}
+
+/// Seed production settings opened by the castle lord at the chamberlain.
+#[derive(Clone, Debug, Reflect)]
+pub struct ExShowSeedSetting {
+    castle_id: CastleId,
+    seeds: Vec<ManorSetting>,
+}
+
+impl ExShowSeedSetting {
+    pub fn new(castle_id: CastleId, seeds: Vec<ManorSetting>) -> Self {
+        Self { castle_id, seeds }
+    }
+}
+
+impl L2rServerPacket for ExShowSeedSetting {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::EX_SHOW_SEED_SETTING.to_le_bytes());
+        buffer.u32(u8::from(self.castle_id).into());
+        buffer.u32_from_usize(self.seeds.len());
+        for seed in self.seeds {
+            let prices = price_range(seed.reference_price);
+            buffer.u32(seed.item_id.into());
+            buffer.u32(seed.level);
+            buffer.u8(1);
+            buffer.u32(0); // reward 1
+            buffer.u8(1);
+            buffer.u32(0); // reward 2
+            buffer.u32(seed.limit as u32);
+            buffer.u32(seed.reference_price as u32);
+            buffer.u32(*prices.start() as u32);
+            buffer.u32(*prices.end() as u32);
+            ManorSetting::write_period(&mut buffer, seed.current);
+            ManorSetting::write_period(&mut buffer, seed.next);
+        }
+        buffer
+    }
+}
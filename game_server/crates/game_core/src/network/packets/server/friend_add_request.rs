@@ -0,0 +1,25 @@
+use super::GameServerPacketCodes;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Asks the invited character to accept the friendship of the requestor.
+#[derive(Clone, Debug, Reflect)]
+pub struct FriendAddRequest {
+    requestor_name: String,
+}
+
+impl FriendAddRequest {
+    pub fn new(requestor_name: String) -> Self {
+        Self { requestor_name }
+    }
+}
+
+impl L2rServerPacket for FriendAddRequest {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::FRIEND_ADD_REQUEST.to_le_bytes());
+        buffer.str(&self.requestor_name);
+        buffer.u32(0);
+        buffer
+    }
+}
@@ -0,0 +1,34 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use spatial::GameVec3;
+
+/// Puts the character ashore at the position in the world.
+#[derive(Clone, Debug, Reflect)]
+pub struct GetOffVehicle {
+    object_id: ObjectId,
+    vehicle_oid: ObjectId,
+    location: Vec3,
+}
+
+impl L2rServerPacket for GetOffVehicle {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::GET_OFF_VEHICLE.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.u32(self.vehicle_oid.into());
+        buffer.extend(GameVec3::from(self.location).to_le_bytes());
+        buffer
+    }
+}
+
+impl GetOffVehicle {
+    pub fn new(object_id: ObjectId, vehicle_oid: ObjectId, location: Vec3) -> Self {
+        Self {
+            object_id,
+            vehicle_oid,
+            location,
+        }
+    }
+}
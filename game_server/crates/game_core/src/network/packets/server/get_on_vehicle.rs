@@ -0,0 +1,34 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use spatial::GameVec3;
+
+/// Puts the character on board at the position relative to the vehicle.
+#[derive(Clone, Debug, Reflect)]
+pub struct GetOnVehicle {
+    object_id: ObjectId,
+    vehicle_oid: ObjectId,
+    position: Vec3,
+}
+
+impl L2rServerPacket for GetOnVehicle {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::GET_ON_VEHICLE.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.u32(self.vehicle_oid.into());
+        buffer.extend(GameVec3::from(self.position).to_le_bytes());
+        buffer
+    }
+}
+
+impl GetOnVehicle {
+    pub fn new(object_id: ObjectId, vehicle_oid: ObjectId, position: Vec3) -> Self {
+        Self {
+            object_id,
+            vehicle_oid,
+            position,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use super::GameServerPacketCodes;
+use crate::henna::{self, DyeCost, HENNA_SLOTS};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+#[derive(Clone, Debug, Reflect)]
+pub struct HennaEquipListEntry {
+    pub id: henna::Id,
+    pub dye: henna::Dye,
+    /// Character has enough dyes in inventory to draw the symbol.
+    pub allowed: bool,
+}
+
+/// Symbols which can be drawn by the symbol maker for the character class.
+#[derive(Clone, Debug, Reflect)]
+pub struct HennaEquipList {
+    adena: u64,
+    entries: Vec<HennaEquipListEntry>,
+}
+
+impl HennaEquipList {
+    pub fn new(adena: u64, entries: Vec<HennaEquipListEntry>) -> Self {
+        Self { adena, entries }
+    }
+}
+
+impl L2rServerPacket for HennaEquipList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::HENNA_EQUIP_LIST.to_le_bytes());
+        buffer.u64(self.adena);
+        buffer.u32_from_usize(HENNA_SLOTS);
+        buffer.u32_from_usize(self.entries.len());
+        for entry in self.entries {
+            let DyeCost { count, fee } = entry.dye.wear;
+            buffer.u32(*entry.id);
+            buffer.u32(*entry.dye.item_id);
+            buffer.u64(count);
+            buffer.u64(fee);
+            buffer.u32_from_bool(entry.allowed);
+        }
+        buffer
+    }
+}
@@ -0,0 +1,52 @@
+use super::GameServerPacketCodes;
+use crate::{
+    henna::{self, Hennas},
+    stats::PrimalStat,
+};
+use bevy::{platform::collections::HashMap, prelude::*};
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Order in which the client expects symbol stat bonuses.
+pub(super) const HENNA_STATS_ORDER: [PrimalStat; 6] = [
+    PrimalStat::INT,
+    PrimalStat::STR,
+    PrimalStat::CON,
+    PrimalStat::MEN,
+    PrimalStat::DEX,
+    PrimalStat::WIT,
+];
+
+#[derive(Clone, Debug, Reflect)]
+pub struct HennaInfo {
+    bonuses: HashMap<PrimalStat, i32>,
+    available_slots: usize,
+    hennas: Vec<henna::Id>,
+}
+
+impl HennaInfo {
+    pub fn new(hennas: &Hennas, dyes: &henna::HennaDyes, available_slots: usize) -> Self {
+        Self {
+            bonuses: hennas.stat_bonuses(dyes),
+            available_slots,
+            hennas: hennas.equipped().map(|(_, id)| id).collect(),
+        }
+    }
+}
+
+impl L2rServerPacket for HennaInfo {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::HENNA_INFO.to_le_bytes());
+        for stat in HENNA_STATS_ORDER {
+            let bonus = self.bonuses.get(&stat).copied().unwrap_or_default();
+            buffer.i8(bonus as i8);
+        }
+        buffer.u32_from_usize(self.available_slots);
+        buffer.u32_from_usize(self.hennas.len());
+        for id in self.hennas {
+            buffer.u32(*id);
+            buffer.u32(1); // Symbol is active
+        }
+        buffer
+    }
+}
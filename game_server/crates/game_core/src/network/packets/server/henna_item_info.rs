@@ -0,0 +1,67 @@
+use super::{GameServerPacketCodes, henna_info::HENNA_STATS_ORDER};
+use crate::{
+    henna,
+    stats::{PrimalStat, PrimalStats},
+};
+use bevy::{platform::collections::HashMap, prelude::*};
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Shows how character stats change after drawing the symbol.
+#[derive(Clone, Debug, Reflect)]
+pub struct HennaItemInfo {
+    id: henna::Id,
+    dye: henna::Dye,
+    adena: u64,
+    allowed: bool,
+    stats: PrimalStats,
+    bonus_change: HashMap<PrimalStat, i32>,
+}
+
+impl HennaItemInfo {
+    /// `bonus_change` is a difference between current summary symbol bonuses and bonuses after drawing.
+    pub fn new(
+        id: henna::Id,
+        dye: henna::Dye,
+        adena: u64,
+        allowed: bool,
+        stats: PrimalStats,
+        bonus_change: HashMap<PrimalStat, i32>,
+    ) -> Self {
+        Self {
+            id,
+            dye,
+            adena,
+            allowed,
+            stats,
+            bonus_change,
+        }
+    }
+}
+
+impl L2rServerPacket for HennaItemInfo {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::HENNA_ITEM_INFO.to_le_bytes());
+        buffer.u32(*self.id);
+        buffer.u32(*self.dye.item_id);
+        buffer.u64(self.dye.wear.count);
+        buffer.u64(self.dye.wear.fee);
+        buffer.u32_from_bool(self.allowed);
+        buffer.u64(self.adena);
+        write_stats_change(&mut buffer, &self.stats, &self.bonus_change);
+        buffer
+    }
+}
+
+pub(super) fn write_stats_change(
+    buffer: &mut ServerPacketBuffer,
+    stats: &PrimalStats,
+    bonus_change: &HashMap<PrimalStat, i32>,
+) {
+    for stat in HENNA_STATS_ORDER {
+        let current = stats[stat];
+        let change = bonus_change.get(&stat).copied().unwrap_or_default();
+        buffer.u32(current);
+        buffer.u8(current.saturating_add_signed(change) as u8);
+    }
+}
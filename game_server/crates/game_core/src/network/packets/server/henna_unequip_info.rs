@@ -0,0 +1,51 @@
+use super::{GameServerPacketCodes, henna_item_info::write_stats_change};
+use crate::{
+    henna,
+    stats::{PrimalStat, PrimalStats},
+};
+use bevy::{platform::collections::HashMap, prelude::*};
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Shows how character stats change after removing the symbol.
+#[derive(Clone, Debug, Reflect)]
+pub struct HennaUnequipInfo {
+    id: henna::Id,
+    dye: henna::Dye,
+    adena: u64,
+    stats: PrimalStats,
+    bonus_change: HashMap<PrimalStat, i32>,
+}
+
+impl HennaUnequipInfo {
+    /// `bonus_change` is a difference between current summary symbol bonuses and bonuses after removal.
+    pub fn new(
+        id: henna::Id,
+        dye: henna::Dye,
+        adena: u64,
+        stats: PrimalStats,
+        bonus_change: HashMap<PrimalStat, i32>,
+    ) -> Self {
+        Self {
+            id,
+            dye,
+            adena,
+            stats,
+            bonus_change,
+        }
+    }
+}
+
+impl L2rServerPacket for HennaUnequipInfo {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::HENNA_UNEQUIP_INFO.to_le_bytes());
+        buffer.u32(*self.id);
+        buffer.u32(*self.dye.item_id);
+        buffer.u64(self.dye.cancel.count);
+        buffer.u64(self.dye.cancel.fee);
+        buffer.u32(1); // Symbol can be removed
+        buffer.u64(self.adena);
+        write_stats_change(&mut buffer, &self.stats, &self.bonus_change);
+        buffer
+    }
+}
@@ -0,0 +1,35 @@
+use super::GameServerPacketCodes;
+use crate::henna;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Drawn symbols which can be removed by the symbol maker.
+#[derive(Clone, Debug, Reflect)]
+pub struct HennaUnequipList {
+    adena: u64,
+    hennas: Vec<(henna::Id, henna::Dye)>,
+}
+
+impl HennaUnequipList {
+    pub fn new(adena: u64, hennas: Vec<(henna::Id, henna::Dye)>) -> Self {
+        Self { adena, hennas }
+    }
+}
+
+impl L2rServerPacket for HennaUnequipList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::HENNA_UNEQUIP_LIST.to_le_bytes());
+        buffer.u64(self.adena);
+        buffer.u32(0);
+        buffer.u32_from_usize(self.hennas.len());
+        for (id, dye) in self.hennas {
+            buffer.u32(*id);
+            buffer.u32(*dye.item_id);
+            buffer.u64(dye.cancel.count);
+            buffer.u64(dye.cancel.fee);
+            buffer.u32(1); // Symbol can be removed
+        }
+        buffer
+    }
+}
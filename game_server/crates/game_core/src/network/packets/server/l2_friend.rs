@@ -0,0 +1,44 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[repr(u32)]
+pub enum L2FriendAction {
+    Add = 1,
+    Remove = 3,
+}
+
+/// Adds or removes a single entry of the friend list window.
+#[derive(Clone, Debug, Reflect)]
+pub struct L2Friend {
+    action: L2FriendAction,
+    id: ObjectId,
+    name: String,
+    online: bool,
+}
+
+impl L2Friend {
+    pub fn new(action: L2FriendAction, id: ObjectId, name: String, online: bool) -> Self {
+        Self {
+            action,
+            id,
+            name,
+            online,
+        }
+    }
+}
+
+impl L2rServerPacket for L2Friend {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::L2_FRIEND.to_le_bytes());
+        buffer.u32(self.action as u32);
+        buffer.u32(self.id.into());
+        buffer.str(&self.name);
+        buffer.u32_from_bool(self.online);
+        buffer.u32(if self.online { self.id.into() } else { 0 });
+        buffer
+    }
+}
@@ -0,0 +1,37 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+#[derive(Clone, Debug, Reflect)]
+pub struct L2FriendListEntry {
+    pub id: ObjectId,
+    pub name: String,
+    pub online: bool,
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct L2FriendList {
+    friends: Vec<L2FriendListEntry>,
+}
+
+impl L2FriendList {
+    pub fn new(friends: Vec<L2FriendListEntry>) -> Self {
+        Self { friends }
+    }
+}
+
+impl L2rServerPacket for L2FriendList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::L2_FRIEND_LIST.to_le_bytes());
+        buffer.u32_from_usize(self.friends.len());
+        for friend in self.friends {
+            buffer.u32(friend.id.into());
+            buffer.str(&friend.name);
+            buffer.u32_from_bool(friend.online);
+            buffer.u32(if friend.online { friend.id.into() } else { 0 });
+        }
+        buffer
+    }
+}
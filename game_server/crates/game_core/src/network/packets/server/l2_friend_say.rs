@@ -0,0 +1,33 @@
+use super::GameServerPacketCodes;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Private message sent through the friend list window.
+#[derive(Clone, Debug, Reflect)]
+pub struct L2FriendSay {
+    receiver: String,
+    sender: String,
+    message: String,
+}
+
+impl L2FriendSay {
+    pub fn new(receiver: String, sender: String, message: String) -> Self {
+        Self {
+            receiver,
+            sender,
+            message,
+        }
+    }
+}
+
+impl L2rServerPacket for L2FriendSay {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::L2_FRIEND_SAY.to_le_bytes());
+        buffer.u32(0);
+        buffer.str(&self.receiver);
+        buffer.str(&self.sender);
+        buffer.str(&self.message);
+        buffer
+    }
+}
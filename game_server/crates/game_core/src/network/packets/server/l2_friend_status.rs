@@ -0,0 +1,29 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Friend logged in or out.
+#[derive(Clone, Debug, Reflect)]
+pub struct L2FriendStatus {
+    online: bool,
+    name: String,
+    id: ObjectId,
+}
+
+impl L2FriendStatus {
+    pub fn new(online: bool, name: String, id: ObjectId) -> Self {
+        Self { online, name, id }
+    }
+}
+
+impl L2rServerPacket for L2FriendStatus {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::L2_FRIEND_STATUS.to_le_bytes());
+        buffer.u32_from_bool(self.online);
+        buffer.str(&self.name);
+        buffer.u32(self.id.into());
+        buffer
+    }
+}
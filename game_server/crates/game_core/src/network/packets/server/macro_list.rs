@@ -0,0 +1,50 @@
+use super::GameServerPacketCodes;
+use crate::macros::Macro;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Client receives macros one by one, each packet carries the total count
+/// and the revision of the macro list. Empty list is sent without a macro.
+#[derive(Clone, Debug, Reflect)]
+pub struct MacroList {
+    revision: u32,
+    count: u8,
+    macro_: Option<Macro>,
+}
+
+impl MacroList {
+    pub fn new(revision: u32, count: u8, macro_: Option<Macro>) -> Self {
+        Self {
+            revision,
+            count,
+            macro_,
+        }
+    }
+}
+
+impl L2rServerPacket for MacroList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::MACRO_LIST.to_le_bytes());
+        buffer.u32(self.revision);
+        buffer.u8(0);
+        buffer.u8(self.count);
+        buffer.bool(self.macro_.is_some());
+        if let Some(macro_) = self.macro_ {
+            buffer.u32(macro_.id.into());
+            buffer.str(&macro_.name);
+            buffer.str(&macro_.description);
+            buffer.str(&macro_.acronym);
+            buffer.u8(macro_.icon);
+            buffer.u8_from_usize(macro_.commands.len());
+            for command in macro_.commands {
+                buffer.u8(command.index);
+                buffer.u8(command.kind.into());
+                buffer.u32(command.d1);
+                buffer.u8(command.d2);
+                buffer.str(&command.command);
+            }
+        }
+        buffer
+    }
+}
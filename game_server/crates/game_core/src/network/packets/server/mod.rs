@@ -9,10 +9,17 @@ use derive_more::derive::From;
 use l2r_core::packets::{L2rServerPacket, L2rServerPackets, ServerPacketBuffer, ServerPacketId};
 
 mod abnormal_status_update;
+mod acquire_skill_done;
+mod acquire_skill_info;
+mod acquire_skill_list;
 mod action_fail;
 mod attack;
 mod attack_stance_start;
 mod attack_stance_stop;
+mod buy_list_seed;
+mod castle_siege_attacker_list;
+mod castle_siege_defender_list;
+mod castle_siege_info;
 mod change_move_type;
 mod change_wait_type;
 mod char_create_fail;
@@ -30,49 +37,99 @@ mod drop_item;
 mod etc_status_update;
 mod ex_basic_action_list;
 mod ex_br_extra_user_info;
+mod ex_fishing_end;
+mod ex_fishing_hp_regen;
+mod ex_fishing_start;
+mod ex_fishing_start_combat;
+mod ex_olympiad_match_end;
+mod ex_olympiad_mode;
+mod ex_olympiad_user_info;
 mod ex_rotation;
+mod ex_send_manor_list;
+mod ex_show_crop_setting;
+mod ex_show_seed_setting;
+mod friend_add_request;
 mod get_item;
+mod get_off_vehicle;
+mod get_on_vehicle;
+mod henna_equip_list;
+mod henna_info;
+mod henna_item_info;
+mod henna_unequip_info;
+mod henna_unequip_list;
 mod inventory_update;
 mod item_list;
 mod key_packet;
+mod l2_friend;
+mod l2_friend_list;
+mod l2_friend_say;
+mod l2_friend_status;
 mod logout_ok;
+mod macro_list;
 mod magic_skill_canceled;
 mod magic_skill_launched;
 mod magic_skill_use;
 mod move_to_location;
+mod move_to_location_in_vehicle;
 mod move_to_pawn;
 mod multisell_list;
 mod net_ping;
 mod new_character_create_menu;
 mod npc_html_message;
 mod npc_info;
+mod observer_end;
+mod observer_start;
+mod packet_batch;
 mod play_sound;
+mod recipe_book_item_list;
+mod recipe_item_make_info;
+mod recipe_shop_item_info;
+mod recipe_shop_manage_list;
+mod recipe_shop_msg;
+mod recipe_shop_sell_list;
 mod response_auto_shots;
 mod restart;
 mod revive;
 mod select_target;
+mod sell_list_procure;
+mod serialized_packet;
 mod setup_gauge;
 mod shortcut_init;
 mod shortcut_registered;
 mod show_map;
+mod skill_cool_time;
 mod skill_list;
 mod social_action;
 mod spawn_item;
 mod ssq_info;
+mod ssq_status;
 mod static_object_info;
 mod status_update;
 mod stop_move;
+mod stop_move_in_vehicle;
 mod system_message;
 mod target_unselected;
 mod teleport_to_location;
 mod user_info;
 mod validate_location;
+mod validate_location_in_vehicle;
+mod vehicle_check_location;
+mod vehicle_departure;
+mod vehicle_info;
+mod vehicle_started;
 
 pub use abnormal_status_update::*;
+pub use acquire_skill_done::*;
+pub use acquire_skill_info::*;
+pub use acquire_skill_list::*;
 pub use action_fail::*;
 pub use attack::*;
 pub use attack_stance_start::*;
 pub use attack_stance_stop::*;
+pub use buy_list_seed::*;
+pub use castle_siege_attacker_list::*;
+pub use castle_siege_defender_list::*;
+pub use castle_siege_info::*;
 pub use change_move_type::*;
 pub use change_wait_type::*;
 pub use char_create_fail::*;
@@ -90,42 +147,85 @@ pub use drop_item::*;
 pub use etc_status_update::*;
 pub use ex_basic_action_list::*;
 pub use ex_br_extra_user_info::*;
+pub use ex_fishing_end::*;
+pub use ex_fishing_hp_regen::*;
+pub use ex_fishing_start::*;
+pub use ex_fishing_start_combat::*;
+pub use ex_olympiad_match_end::*;
+pub use ex_olympiad_mode::*;
+pub use ex_olympiad_user_info::*;
 pub use ex_rotation::*;
+pub use ex_send_manor_list::*;
+pub use ex_show_crop_setting::*;
+pub use ex_show_seed_setting::*;
+pub use friend_add_request::*;
 pub use get_item::*;
+pub use get_off_vehicle::*;
+pub use get_on_vehicle::*;
+pub use henna_equip_list::*;
+pub use henna_info::*;
+pub use henna_item_info::*;
+pub use henna_unequip_info::*;
+pub use henna_unequip_list::*;
 pub use inventory_update::*;
 pub use item_list::*;
 pub use key_packet::*;
+pub use l2_friend::*;
+pub use l2_friend_list::*;
+pub use l2_friend_say::*;
+pub use l2_friend_status::*;
 pub use logout_ok::*;
+pub use macro_list::*;
 pub use magic_skill_launched::*;
 pub use magic_skill_use::*;
 pub use move_to_location::*;
+pub use move_to_location_in_vehicle::*;
 pub use move_to_pawn::*;
 pub use multisell_list::*;
 pub use net_ping::*;
 pub use new_character_create_menu::*;
 pub use npc_html_message::*;
 pub use npc_info::*;
+pub use observer_end::*;
+pub use observer_start::*;
+pub use packet_batch::*;
 pub use play_sound::*;
+pub use recipe_book_item_list::*;
+pub use recipe_item_make_info::*;
+pub use recipe_shop_item_info::*;
+pub use recipe_shop_manage_list::*;
+pub use recipe_shop_msg::*;
+pub use recipe_shop_sell_list::*;
 pub use response_auto_shots::*;
 pub use restart::*;
 pub use revive::*;
 pub use select_target::*;
+pub use sell_list_procure::*;
+pub use serialized_packet::*;
 pub use setup_gauge::*;
 pub use shortcut_init::*;
 pub use shortcut_registered::*;
 pub use show_map::*;
+pub use skill_cool_time::*;
 pub use social_action::*;
 pub use spawn_item::*;
 pub use ssq_info::*;
+pub use ssq_status::*;
 pub use static_object_info::*;
 pub use status_update::*;
 pub use stop_move::*;
+pub use stop_move_in_vehicle::*;
 use strum::{Display, EnumDiscriminants};
 pub use system_message::*;
 pub use target_unselected::*;
 pub use teleport_to_location::*;
 pub use user_info::*;
 pub use validate_location::*;
+pub use validate_location_in_vehicle::*;
+pub use vehicle_check_location::*;
+pub use vehicle_departure::*;
+pub use vehicle_info::*;
+pub use vehicle_started::*;
 
 pub struct GameServerPacketCodes;
 impl GameServerPacketCodes {
@@ -223,7 +323,7 @@ impl GameServerPacketCodes {
     const _PLEDGE_SHOW_MEMBER_LIST_DELETE: ServerPacketId = ServerPacketId::new(0x5D);
     const _MAGIC_LIST: ServerPacketId = ServerPacketId::new(0x5E);
     const SKILL_LIST: ServerPacketId = ServerPacketId::new(0x5F);
-    const _VEHICLE_INFO: ServerPacketId = ServerPacketId::new(0x60);
+    const VEHICLE_INFO: ServerPacketId = ServerPacketId::new(0x60);
     const _FINISH_ROTATING: ServerPacketId = ServerPacketId::new(0x61);
     const SYSTEM_MESSAGE: ServerPacketId = ServerPacketId::new(0x62);
     const _START_PLEDGE_WAR: ServerPacketId = ServerPacketId::new(0x63);
@@ -235,30 +335,30 @@ impl GameServerPacketCodes {
     const _SET_PLEDGE_CREST: ServerPacketId = ServerPacketId::new(0x69);
     const _PLEDGE_CREST: ServerPacketId = ServerPacketId::new(0x6A);
     const SETUP_GAUGE: ServerPacketId = ServerPacketId::new(0x6B);
-    const _VEHICLE_DEPARTURE: ServerPacketId = ServerPacketId::new(0x6C);
-    const _VEHICLE_CHECK_LOCATION: ServerPacketId = ServerPacketId::new(0x6D);
-    const _GET_ON_VEHICLE: ServerPacketId = ServerPacketId::new(0x6E);
-    const _GET_OFF_VEHICLE: ServerPacketId = ServerPacketId::new(0x6F);
+    const VEHICLE_DEPARTURE: ServerPacketId = ServerPacketId::new(0x6C);
+    const VEHICLE_CHECK_LOCATION: ServerPacketId = ServerPacketId::new(0x6D);
+    const GET_ON_VEHICLE: ServerPacketId = ServerPacketId::new(0x6E);
+    const GET_OFF_VEHICLE: ServerPacketId = ServerPacketId::new(0x6F);
     const _TRADE_REQUEST: ServerPacketId = ServerPacketId::new(0x70);
     const RESTART_RESPONSE: ServerPacketId = ServerPacketId::new(0x71);
     const MOVE_TO_PAWN: ServerPacketId = ServerPacketId::new(0x72);
     const SSQ_INFO: ServerPacketId = ServerPacketId::new(0x73);
     const _GAME_GUARD_QUERY: ServerPacketId = ServerPacketId::new(0x74);
-    const _L2_FRIEND_LIST: ServerPacketId = ServerPacketId::new(0x75);
-    const _L2_FRIEND: ServerPacketId = ServerPacketId::new(0x76);
-    const _L2_FRIEND_STATUS: ServerPacketId = ServerPacketId::new(0x77);
-    const _L2_FRIEND_SAY: ServerPacketId = ServerPacketId::new(0x78);
+    const L2_FRIEND_LIST: ServerPacketId = ServerPacketId::new(0x75);
+    const L2_FRIEND: ServerPacketId = ServerPacketId::new(0x76);
+    const L2_FRIEND_STATUS: ServerPacketId = ServerPacketId::new(0x77);
+    const L2_FRIEND_SAY: ServerPacketId = ServerPacketId::new(0x78);
     const VALIDATE_LOCATION: ServerPacketId = ServerPacketId::new(0x79);
     const _START_ROTATING: ServerPacketId = ServerPacketId::new(0x7A);
     const _SHOW_BOARD: ServerPacketId = ServerPacketId::new(0x7B);
     const _CHOOSE_INVENTORY_ITEM: ServerPacketId = ServerPacketId::new(0x7C);
     const _DUMMY: ServerPacketId = ServerPacketId::new(0x7D);
-    const _MOVE_TO_LOCATION_IN_VEHICLE: ServerPacketId = ServerPacketId::new(0x7E);
-    const _STOP_MOVE_IN_VEHICLE: ServerPacketId = ServerPacketId::new(0x7F);
-    const _VALIDATE_LOCATION_IN_VEHICLE: ServerPacketId = ServerPacketId::new(0x80);
+    const MOVE_TO_LOCATION_IN_VEHICLE: ServerPacketId = ServerPacketId::new(0x7E);
+    const STOP_MOVE_IN_VEHICLE: ServerPacketId = ServerPacketId::new(0x7F);
+    const VALIDATE_LOCATION_IN_VEHICLE: ServerPacketId = ServerPacketId::new(0x80);
     const _TRADE_UPDATE: ServerPacketId = ServerPacketId::new(0x81);
     const _TRADE_PRESS_OTHER_OK: ServerPacketId = ServerPacketId::new(0x82);
-    const _FRIEND_ADD_REQUEST: ServerPacketId = ServerPacketId::new(0x83);
+    const FRIEND_ADD_REQUEST: ServerPacketId = ServerPacketId::new(0x83);
     const LOG_OUT_OK: ServerPacketId = ServerPacketId::new(0x84);
     const ABNORMAL_STATUS_UPDATE: ServerPacketId = ServerPacketId::new(0x85);
     const _QUEST_LIST: ServerPacketId = ServerPacketId::new(0x86);
@@ -271,11 +371,11 @@ impl GameServerPacketCodes {
     const _GIVE_NICK_NAME_DONE: ServerPacketId = ServerPacketId::new(0x8D);
     const _PLEDGE_SHOW_INFO_UPDATE: ServerPacketId = ServerPacketId::new(0x8E);
     const _CLIENT_ACTION: ServerPacketId = ServerPacketId::new(0x8F);
-    const _ACQUIRE_SKILL_LIST: ServerPacketId = ServerPacketId::new(0x90);
-    const _ACQUIRE_SKILL_INFO: ServerPacketId = ServerPacketId::new(0x91);
+    const ACQUIRE_SKILL_LIST: ServerPacketId = ServerPacketId::new(0x90);
+    const ACQUIRE_SKILL_INFO: ServerPacketId = ServerPacketId::new(0x91);
     const _SERVER_OBJECT_INFO: ServerPacketId = ServerPacketId::new(0x92);
     const _GM_HIDE: ServerPacketId = ServerPacketId::new(0x93);
-    const _ACQUIRE_SKILL_DONE: ServerPacketId = ServerPacketId::new(0x94);
+    const ACQUIRE_SKILL_DONE: ServerPacketId = ServerPacketId::new(0x94);
     const _GM_VIEW_CHARACTER_INFO: ServerPacketId = ServerPacketId::new(0x95);
     const _GM_VIEW_PLEDGE_INFO: ServerPacketId = ServerPacketId::new(0x96);
     const _GM_VIEW_SKILL_INFO: ServerPacketId = ServerPacketId::new(0x97);
@@ -319,18 +419,18 @@ impl GameServerPacketCodes {
     const _PRIVATE_STORE_BUY_MANAGE_LIST: ServerPacketId = ServerPacketId::new(0xBD);
     const _PRIVATE_STORE_BUY_LIST: ServerPacketId = ServerPacketId::new(0xBE);
     const _PRIVATE_STORE_BUY_MSG: ServerPacketId = ServerPacketId::new(0xBF);
-    const _VEHICLE_START: ServerPacketId = ServerPacketId::new(0xC0);
+    const VEHICLE_START: ServerPacketId = ServerPacketId::new(0xC0);
     const _REQUEST_TIME_CHECK: ServerPacketId = ServerPacketId::new(0xC1);
     const _START_ALLIANCE_WAR: ServerPacketId = ServerPacketId::new(0xC2);
     const _REPLY_START_ALLIANCE_WAR: ServerPacketId = ServerPacketId::new(0xC3);
     const _STOP_ALLIANCE_WAR: ServerPacketId = ServerPacketId::new(0xC4);
     const _REPLY_STOP_ALLIANCE_WAR: ServerPacketId = ServerPacketId::new(0xC5);
     const _SURRENDER_ALLIANCE_WAR: ServerPacketId = ServerPacketId::new(0xC6);
-    const _SKILL_COOL_TIME: ServerPacketId = ServerPacketId::new(0xC7);
+    const SKILL_COOL_TIME: ServerPacketId = ServerPacketId::new(0xC7);
     const _PACKAGE_TO_LIST: ServerPacketId = ServerPacketId::new(0xC8);
-    const _CASTLE_SIEGE_INFO: ServerPacketId = ServerPacketId::new(0xC9);
-    const _CASTLE_SIEGE_ATTACKER_LIST: ServerPacketId = ServerPacketId::new(0xCA);
-    const _CASTLE_SIEGE_DEFENDER_LIST: ServerPacketId = ServerPacketId::new(0xCB);
+    const CASTLE_SIEGE_INFO: ServerPacketId = ServerPacketId::new(0xC9);
+    const CASTLE_SIEGE_ATTACKER_LIST: ServerPacketId = ServerPacketId::new(0xCA);
+    const CASTLE_SIEGE_DEFENDER_LIST: ServerPacketId = ServerPacketId::new(0xCB);
     const _NICK_NAME_CHANGED: ServerPacketId = ServerPacketId::new(0xCC);
     const _PLEDGE_STATUS_CHANGED: ServerPacketId = ServerPacketId::new(0xCD);
     const _RELATION_CHANGED: ServerPacketId = ServerPacketId::new(0xCE);
@@ -347,26 +447,26 @@ impl GameServerPacketCodes {
     const NET_PING: ServerPacketId = ServerPacketId::new(0xD9);
     const _DICE: ServerPacketId = ServerPacketId::new(0xDA);
     const _SNOOP: ServerPacketId = ServerPacketId::new(0xDB);
-    const _RECIPE_BOOK_ITEM_LIST: ServerPacketId = ServerPacketId::new(0xDC);
-    const _RECIPE_ITEM_MAKE_INFO: ServerPacketId = ServerPacketId::new(0xDD);
-    const _RECIPE_SHOP_MANAGE_LIST: ServerPacketId = ServerPacketId::new(0xDE);
-    const _RECIPE_SHOP_SELL_LIST: ServerPacketId = ServerPacketId::new(0xDF);
-    const _RECIPE_SHOP_ITEM_INFO: ServerPacketId = ServerPacketId::new(0xE0);
-    const _RECIPE_SHOP_MSG: ServerPacketId = ServerPacketId::new(0xE1);
+    const RECIPE_BOOK_ITEM_LIST: ServerPacketId = ServerPacketId::new(0xDC);
+    const RECIPE_ITEM_MAKE_INFO: ServerPacketId = ServerPacketId::new(0xDD);
+    const RECIPE_SHOP_MANAGE_LIST: ServerPacketId = ServerPacketId::new(0xDE);
+    const RECIPE_SHOP_SELL_LIST: ServerPacketId = ServerPacketId::new(0xDF);
+    const RECIPE_SHOP_ITEM_INFO: ServerPacketId = ServerPacketId::new(0xE0);
+    const RECIPE_SHOP_MSG: ServerPacketId = ServerPacketId::new(0xE1);
     const _SHOW_CALC: ServerPacketId = ServerPacketId::new(0xE2);
     const _MON_RACE_INFO: ServerPacketId = ServerPacketId::new(0xE3);
-    const _HENNA_ITEM_INFO: ServerPacketId = ServerPacketId::new(0xE4);
-    const _HENNA_INFO: ServerPacketId = ServerPacketId::new(0xE5);
-    const _HENNA_UNEQUIP_LIST: ServerPacketId = ServerPacketId::new(0xE6);
-    const _HENNA_UNEQUIP_INFO: ServerPacketId = ServerPacketId::new(0xE7);
-    const _MACRO_LIST: ServerPacketId = ServerPacketId::new(0xE8);
-    const _BUY_LIST_SEED: ServerPacketId = ServerPacketId::new(0xE9);
+    const HENNA_ITEM_INFO: ServerPacketId = ServerPacketId::new(0xE4);
+    const HENNA_INFO: ServerPacketId = ServerPacketId::new(0xE5);
+    const HENNA_UNEQUIP_LIST: ServerPacketId = ServerPacketId::new(0xE6);
+    const HENNA_UNEQUIP_INFO: ServerPacketId = ServerPacketId::new(0xE7);
+    const MACRO_LIST: ServerPacketId = ServerPacketId::new(0xE8);
+    const BUY_LIST_SEED: ServerPacketId = ServerPacketId::new(0xE9);
     const _SHOW_TOWN_MAP: ServerPacketId = ServerPacketId::new(0xEA);
-    const _OBSERVER_START: ServerPacketId = ServerPacketId::new(0xEB);
-    const _OBSERVER_END: ServerPacketId = ServerPacketId::new(0xEC);
+    const OBSERVER_START: ServerPacketId = ServerPacketId::new(0xEB);
+    const OBSERVER_END: ServerPacketId = ServerPacketId::new(0xEC);
     const _CHAIR_SIT: ServerPacketId = ServerPacketId::new(0xED);
-    const _HENNA_EQUIP_LIST: ServerPacketId = ServerPacketId::new(0xEE);
-    const _SELL_LIST_PROCURE: ServerPacketId = ServerPacketId::new(0xEF);
+    const HENNA_EQUIP_LIST: ServerPacketId = ServerPacketId::new(0xEE);
+    const SELL_LIST_PROCURE: ServerPacketId = ServerPacketId::new(0xEF);
     const _GM_HENNA_INFO: ServerPacketId = ServerPacketId::new(0xF0);
     const _RADAR_CONTROL: ServerPacketId = ServerPacketId::new(0xF1);
     const _CLIENT_SET_TIME: ServerPacketId = ServerPacketId::new(0xF2);
@@ -378,7 +478,7 @@ impl GameServerPacketCodes {
     const _SHOW_XMAS_SEAL: ServerPacketId = ServerPacketId::new(0xF8);
     const ETC_STATUS_UPDATE: ServerPacketId = ServerPacketId::new(0xF9);
     const _SHORT_BUFF_STATUS_UPDATE: ServerPacketId = ServerPacketId::new(0xFA);
-    const _SSQ_STATUS: ServerPacketId = ServerPacketId::new(0xFB);
+    const SSQ_STATUS: ServerPacketId = ServerPacketId::new(0xFB);
     const _PETITION_VOTE: ServerPacketId = ServerPacketId::new(0xFC);
     const _AGIT_DECO_INFO: ServerPacketId = ServerPacketId::new(0xFD);
     // ex packets
@@ -412,22 +512,22 @@ impl GameServerPacketCodes {
     const _EX_PLEDGE_EMBLEM: ServerPacketId = ServerPacketId::new_ex(0x1B);
     const _EX_EVENT_MATCH_TEAM_INFO: ServerPacketId = ServerPacketId::new_ex(0x1C);
     const _EX_EVENT_MATCH_CREATE: ServerPacketId = ServerPacketId::new_ex(0x1D);
-    const _EX_FISHING_START: ServerPacketId = ServerPacketId::new_ex(0x1E);
-    const _EX_FISHING_END: ServerPacketId = ServerPacketId::new_ex(0x1F);
+    const EX_FISHING_START: ServerPacketId = ServerPacketId::new_ex(0x1E);
+    const EX_FISHING_END: ServerPacketId = ServerPacketId::new_ex(0x1F);
     const _EX_SHOW_QUEST_INFO: ServerPacketId = ServerPacketId::new_ex(0x20);
     const _EX_SHOW_QUEST_MARK: ServerPacketId = ServerPacketId::new_ex(0x21);
-    const _EX_SEND_MANOR_LIST: ServerPacketId = ServerPacketId::new_ex(0x22);
+    const EX_SEND_MANOR_LIST: ServerPacketId = ServerPacketId::new_ex(0x22);
     const _EX_SHOW_SEED_INFO: ServerPacketId = ServerPacketId::new_ex(0x23);
     const _EX_SHOW_CROP_INFO: ServerPacketId = ServerPacketId::new_ex(0x24);
     const _EX_SHOW_MANOR_DEFAULT_INFO: ServerPacketId = ServerPacketId::new_ex(0x25);
-    const _EX_SHOW_SEED_SETTING: ServerPacketId = ServerPacketId::new_ex(0x26);
-    const _EX_FISHING_START_COMBAT: ServerPacketId = ServerPacketId::new_ex(0x27);
-    const _EX_FISHING_HP_REGEN: ServerPacketId = ServerPacketId::new_ex(0x28);
+    const EX_SHOW_SEED_SETTING: ServerPacketId = ServerPacketId::new_ex(0x26);
+    const EX_FISHING_START_COMBAT: ServerPacketId = ServerPacketId::new_ex(0x27);
+    const EX_FISHING_HP_REGEN: ServerPacketId = ServerPacketId::new_ex(0x28);
     const _EX_ENCHANT_SKILL_LIST: ServerPacketId = ServerPacketId::new_ex(0x29);
     const _EX_ENCHANT_SKILL_INFO: ServerPacketId = ServerPacketId::new_ex(0x2A);
-    const _EX_SHOW_CROP_SETTING: ServerPacketId = ServerPacketId::new_ex(0x2B);
+    const EX_SHOW_CROP_SETTING: ServerPacketId = ServerPacketId::new_ex(0x2B);
     const _EX_SHOW_SELL_CROP_LIST: ServerPacketId = ServerPacketId::new_ex(0x2C);
-    const _EX_OLYMPIAD_MATCH_END: ServerPacketId = ServerPacketId::new_ex(0x2D);
+    const EX_OLYMPIAD_MATCH_END: ServerPacketId = ServerPacketId::new_ex(0x2D);
     const _EX_MAIL_ARRIVED: ServerPacketId = ServerPacketId::new_ex(0x2E);
     const _EX_STORAGE_MAX_COUNT: ServerPacketId = ServerPacketId::new_ex(0x2F);
     const _EX_EVENT_MATCH_MANAGE: ServerPacketId = ServerPacketId::new_ex(0x30);
@@ -506,9 +606,9 @@ impl GameServerPacketCodes {
     const _EX_RESPONSE_FREE_SERVER: ServerPacketId = ServerPacketId::new_ex(0x77);
     const _EX_SHOW_PROCURE_CROP_DETAIL: ServerPacketId = ServerPacketId::new_ex(0x78);
     const _EX_HERO_LIST: ServerPacketId = ServerPacketId::new_ex(0x79);
-    const _EX_OLYMPIAD_USER_INFO: ServerPacketId = ServerPacketId::new_ex(0x7A);
+    const EX_OLYMPIAD_USER_INFO: ServerPacketId = ServerPacketId::new_ex(0x7A);
     const _EX_OLYMPIAD_SPELLED_INFO: ServerPacketId = ServerPacketId::new_ex(0x7B);
-    const _EX_OLYMPIAD_MODE: ServerPacketId = ServerPacketId::new_ex(0x7C);
+    const EX_OLYMPIAD_MODE: ServerPacketId = ServerPacketId::new_ex(0x7C);
     const _EX_SHOW_FORTRESS_MAP_INFO: ServerPacketId = ServerPacketId::new_ex(0x7D);
     const _EX_PVP_MATCH_RECORD: ServerPacketId = ServerPacketId::new_ex(0x7E);
     const _EX_PVP_MATCH_USER_DIE: ServerPacketId = ServerPacketId::new_ex(0x7F);
@@ -666,6 +766,7 @@ pub enum GameServerPacket {
     SelectTarget(SelectTarget),
     SetupGauge(SetupGauge),
     ShowMap(ShowMap),
+    SkillCoolTime(SkillCoolTime),
     SkillList(SkillList),
     SocialAction(SocialAction),
     SpawnItem(SpawnItem),
@@ -683,6 +784,55 @@ pub enum GameServerPacket {
     ShortcutInit(ShortcutInit),
     AbnormalStatusUpdate(AbnormalStatusUpdate),
     ResponseAutoShots(ResponseAutoShots),
+    HennaInfo(HennaInfo),
+    HennaEquipList(HennaEquipList),
+    HennaItemInfo(HennaItemInfo),
+    HennaUnequipList(HennaUnequipList),
+    HennaUnequipInfo(HennaUnequipInfo),
+    RecipeBookItemList(RecipeBookItemList),
+    RecipeItemMakeInfo(RecipeItemMakeInfo),
+    RecipeShopManageList(RecipeShopManageList),
+    RecipeShopSellList(RecipeShopSellList),
+    RecipeShopItemInfo(RecipeShopItemInfo),
+    RecipeShopMsg(RecipeShopMsg),
+    FriendAddRequest(FriendAddRequest),
+    L2FriendList(L2FriendList),
+    L2Friend(L2Friend),
+    L2FriendStatus(L2FriendStatus),
+    L2FriendSay(L2FriendSay),
+    MacroList(MacroList),
+    CastleSiegeInfo(CastleSiegeInfo),
+    CastleSiegeAttackerList(CastleSiegeAttackerList),
+    CastleSiegeDefenderList(CastleSiegeDefenderList),
+    ExSendManorList(ExSendManorList),
+    BuyListSeed(BuyListSeed),
+    SellListProcure(SellListProcure),
+    ExShowSeedSetting(ExShowSeedSetting),
+    ExShowCropSetting(ExShowCropSetting),
+    SsqStatus(SsqStatus),
+    ObserverStart(ObserverStart),
+    ObserverEnd(ObserverEnd),
+    ExOlympiadMode(ExOlympiadMode),
+    ExOlympiadUserInfo(ExOlympiadUserInfo),
+    ExOlympiadMatchEnd(ExOlympiadMatchEnd),
+    GetOffVehicle(GetOffVehicle),
+    GetOnVehicle(GetOnVehicle),
+    MoveToLocationInVehicle(MoveToLocationInVehicle),
+    StopMoveInVehicle(StopMoveInVehicle),
+    ValidateLocationInVehicle(ValidateLocationInVehicle),
+    VehicleCheckLocation(VehicleCheckLocation),
+    VehicleDeparture(VehicleDeparture),
+    VehicleInfo(VehicleInfo),
+    VehicleStarted(VehicleStarted),
+    AcquireSkillDone(AcquireSkillDone),
+    AcquireSkillInfo(AcquireSkillInfo),
+    AcquireSkillList(AcquireSkillList),
+    ExFishingEnd(ExFishingEnd),
+    ExFishingHpRegen(ExFishingHpRegen),
+    ExFishingStart(ExFishingStart),
+    ExFishingStartCombat(ExFishingStartCombat),
+    SerializedPacket(SerializedPacket),
+    PacketBatch(PacketBatch),
 }
 impl Default for GameServerPacket {
     fn default() -> Self {
@@ -736,6 +886,7 @@ l2r_core::impl_buffer!(
     SetupGauge,
     ShowMap,
     SocialAction,
+    SkillCoolTime,
     SkillList,
     SpawnItem,
     StaticObjectInfo,
@@ -750,7 +901,56 @@ l2r_core::impl_buffer!(
     ShortcutRegistered,
     ShortcutInit,
     AbnormalStatusUpdate,
-    ResponseAutoShots
+    ResponseAutoShots,
+    HennaInfo,
+    HennaEquipList,
+    HennaItemInfo,
+    HennaUnequipList,
+    HennaUnequipInfo,
+    RecipeBookItemList,
+    RecipeItemMakeInfo,
+    RecipeShopManageList,
+    RecipeShopSellList,
+    RecipeShopItemInfo,
+    RecipeShopMsg,
+    FriendAddRequest,
+    L2FriendList,
+    L2Friend,
+    L2FriendStatus,
+    L2FriendSay,
+    MacroList,
+    CastleSiegeInfo,
+    CastleSiegeAttackerList,
+    CastleSiegeDefenderList,
+    ExSendManorList,
+    BuyListSeed,
+    SellListProcure,
+    ExShowSeedSetting,
+    ExShowCropSetting,
+    SsqStatus,
+    ObserverStart,
+    ObserverEnd,
+    ExOlympiadMode,
+    ExOlympiadUserInfo,
+    ExOlympiadMatchEnd,
+    GetOffVehicle,
+    GetOnVehicle,
+    MoveToLocationInVehicle,
+    StopMoveInVehicle,
+    ValidateLocationInVehicle,
+    VehicleCheckLocation,
+    VehicleDeparture,
+    VehicleInfo,
+    VehicleStarted,
+    AcquireSkillDone,
+    AcquireSkillInfo,
+    AcquireSkillList,
+    ExFishingEnd,
+    ExFishingHpRegen,
+    ExFishingStart,
+    ExFishingStartCombat,
+    SerializedPacket,
+    PacketBatch
 );
 
 #[derive(Clone, Debug, Deref, DerefMut, Event, From, Reflect)]
@@ -808,6 +1008,7 @@ impl Plugin for GameServerPacketPlugin {
             .register_type::<SetupGauge>()
             .register_type::<SetupGaugeColor>()
             .register_type::<ShowMap>()
+            .register_type::<SkillCoolTime>()
             .register_type::<SocialAction>()
             .register_type::<Social>()
             .register_type::<SpawnItem>()
@@ -825,6 +1026,55 @@ impl Plugin for GameServerPacketPlugin {
             .register_type::<ShortcutInit>()
             .register_type::<AbnormalStatusUpdate>()
             .register_type::<ShotState>()
+            .register_type::<HennaInfo>()
+            .register_type::<HennaEquipList>()
+            .register_type::<HennaItemInfo>()
+            .register_type::<HennaUnequipList>()
+            .register_type::<HennaUnequipInfo>()
+            .register_type::<RecipeBookItemList>()
+            .register_type::<RecipeItemMakeInfo>()
+            .register_type::<RecipeShopManageList>()
+            .register_type::<RecipeShopSellList>()
+            .register_type::<RecipeShopItemInfo>()
+            .register_type::<RecipeShopMsg>()
+            .register_type::<FriendAddRequest>()
+            .register_type::<L2FriendList>()
+            .register_type::<L2Friend>()
+            .register_type::<L2FriendStatus>()
+            .register_type::<L2FriendSay>()
+            .register_type::<MacroList>()
+            .register_type::<CastleSiegeInfo>()
+            .register_type::<CastleSiegeAttackerList>()
+            .register_type::<CastleSiegeDefenderList>()
+            .register_type::<ExSendManorList>()
+            .register_type::<BuyListSeed>()
+            .register_type::<SellListProcure>()
+            .register_type::<ExShowSeedSetting>()
+            .register_type::<ExShowCropSetting>()
+            .register_type::<SsqStatus>()
+            .register_type::<ObserverStart>()
+            .register_type::<ObserverEnd>()
+            .register_type::<ExOlympiadMode>()
+            .register_type::<ExOlympiadUserInfo>()
+            .register_type::<ExOlympiadMatchEnd>()
+            .register_type::<GetOffVehicle>()
+            .register_type::<GetOnVehicle>()
+            .register_type::<MoveToLocationInVehicle>()
+            .register_type::<StopMoveInVehicle>()
+            .register_type::<ValidateLocationInVehicle>()
+            .register_type::<VehicleCheckLocation>()
+            .register_type::<VehicleDeparture>()
+            .register_type::<VehicleInfo>()
+            .register_type::<VehicleStarted>()
+            .register_type::<AcquireSkillDone>()
+            .register_type::<AcquireSkillInfo>()
+            .register_type::<AcquireSkillList>()
+            .register_type::<ExFishingEnd>()
+            .register_type::<ExFishingHpRegen>()
+            .register_type::<ExFishingStart>()
+            .register_type::<ExFishingStartCombat>()
+            .register_type::<SerializedPacket>()
+            .register_type::<PacketBatch>()
             .register_type::<ResponseAutoShots>();
     }
 }
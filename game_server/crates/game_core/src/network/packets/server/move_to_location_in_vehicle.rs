@@ -0,0 +1,37 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use spatial::GameVec3;
+
+/// Moves the passenger across the deck, positions are relative to the vehicle.
+#[derive(Clone, Debug, Reflect)]
+pub struct MoveToLocationInVehicle {
+    object_id: ObjectId,
+    vehicle_oid: ObjectId,
+    origin: Vec3,
+    target: Vec3,
+}
+
+impl L2rServerPacket for MoveToLocationInVehicle {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::MOVE_TO_LOCATION_IN_VEHICLE.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.u32(self.vehicle_oid.into());
+        buffer.extend(GameVec3::from(self.target).to_le_bytes());
+        buffer.extend(GameVec3::from(self.origin).to_le_bytes());
+        buffer
+    }
+}
+
+impl MoveToLocationInVehicle {
+    pub fn new(object_id: ObjectId, vehicle_oid: ObjectId, origin: Vec3, target: Vec3) -> Self {
+        Self {
+            object_id,
+            vehicle_oid,
+            origin,
+            target,
+        }
+    }
+}
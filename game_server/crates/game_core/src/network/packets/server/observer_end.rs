@@ -0,0 +1,24 @@
+use super::GameServerPacketCodes;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Returns the camera to the character left at the position.
+#[derive(Clone, Debug, Reflect)]
+pub struct ObserverEnd {
+    position: Vec3,
+}
+impl L2rServerPacket for ObserverEnd {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::OBSERVER_END.to_le_bytes());
+        buffer.i32(self.position.x as i32);
+        buffer.i32(self.position.y as i32);
+        buffer.i32(self.position.z as i32);
+        buffer
+    }
+}
+impl ObserverEnd {
+    pub fn new(position: Vec3) -> Self {
+        Self { position }
+    }
+}
@@ -0,0 +1,27 @@
+use super::GameServerPacketCodes;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Moves the camera of the character to watch the place.
+#[derive(Clone, Debug, Reflect)]
+pub struct ObserverStart {
+    position: Vec3,
+}
+impl L2rServerPacket for ObserverStart {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::OBSERVER_START.to_le_bytes());
+        buffer.i32(self.position.x as i32);
+        buffer.i32(self.position.y as i32);
+        buffer.i32(self.position.z as i32);
+        buffer.u8(0);
+        buffer.u8(0xC0);
+        buffer.u8(0);
+        buffer
+    }
+}
+impl ObserverStart {
+    pub fn new(position: Vec3) -> Self {
+        Self { position }
+    }
+}
@@ -0,0 +1,29 @@
+use super::GameServerPacket;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, L2rServerPackets, ServerPacketBuffer};
+
+/// Packets of one tick for the same session, written to the socket at once.
+/// Every packet is still encrypted and framed on its own.
+#[derive(Clone, Debug, Default, Deref, Reflect)]
+pub struct PacketBatch(Vec<GameServerPacket>);
+
+impl PacketBatch {
+    pub fn new(packets: Vec<GameServerPacket>) -> Self {
+        Self(packets)
+    }
+
+    pub fn into_inner(self) -> Vec<GameServerPacket> {
+        self.0
+    }
+}
+
+impl L2rServerPacket for PacketBatch {
+    /// Bodies of all the packets one after another, without the framing.
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        for packet in self.0 {
+            buffer.extend_from_slice(&packet.buffer());
+        }
+        buffer
+    }
+}
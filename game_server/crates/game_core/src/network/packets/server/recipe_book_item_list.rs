@@ -0,0 +1,37 @@
+use super::GameServerPacketCodes;
+use crate::recipe::{self, RecipeBookKind};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Recipes registered in the dwarven or common recipe book.
+#[derive(Clone, Debug, Reflect)]
+pub struct RecipeBookItemList {
+    kind: RecipeBookKind,
+    max_mp: u32,
+    recipes: Vec<recipe::Id>,
+}
+
+impl RecipeBookItemList {
+    pub fn new(kind: RecipeBookKind, max_mp: u32, recipes: Vec<recipe::Id>) -> Self {
+        Self {
+            kind,
+            max_mp,
+            recipes,
+        }
+    }
+}
+
+impl L2rServerPacket for RecipeBookItemList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::RECIPE_BOOK_ITEM_LIST.to_le_bytes());
+        buffer.u32(self.kind.into());
+        buffer.u32(self.max_mp);
+        buffer.u32_from_usize(self.recipes.len());
+        for (index, id) in self.recipes.into_iter().enumerate() {
+            buffer.u32(*id);
+            buffer.u32_from_usize(index + 1);
+        }
+        buffer
+    }
+}
@@ -0,0 +1,63 @@
+use super::GameServerPacketCodes;
+use crate::recipe::{self, RecipeBookKind};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Result of the last craft attempt shown in the craft window.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum CraftResult {
+    #[default]
+    Info,
+    Success,
+    Failure,
+}
+
+impl From<CraftResult> for i32 {
+    fn from(value: CraftResult) -> Self {
+        match value {
+            CraftResult::Info => -1,
+            CraftResult::Success => 1,
+            CraftResult::Failure => 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Reflect)]
+pub struct RecipeItemMakeInfo {
+    id: recipe::Id,
+    kind: RecipeBookKind,
+    current_mp: u32,
+    max_mp: u32,
+    result: CraftResult,
+}
+
+impl RecipeItemMakeInfo {
+    pub fn new(
+        id: recipe::Id,
+        kind: RecipeBookKind,
+        current_mp: u32,
+        max_mp: u32,
+        result: CraftResult,
+    ) -> Self {
+        Self {
+            id,
+            kind,
+            current_mp,
+            max_mp,
+            result,
+        }
+    }
+}
+
+impl L2rServerPacket for RecipeItemMakeInfo {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::RECIPE_ITEM_MAKE_INFO.to_le_bytes());
+        buffer.u32(*self.id);
+        buffer.u32(self.kind.into());
+        buffer.u32(self.current_mp);
+        buffer.u32(self.max_mp);
+        buffer.i32(self.result.into());
+        buffer
+    }
+}
@@ -0,0 +1,45 @@
+use super::{CraftResult, GameServerPacketCodes};
+use crate::{object_id::ObjectId, recipe};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Craft window of a recipe from another player's manufacture shop.
+#[derive(Clone, Debug, Reflect)]
+pub struct RecipeShopItemInfo {
+    seller: ObjectId,
+    id: recipe::Id,
+    current_mp: u32,
+    max_mp: u32,
+    result: CraftResult,
+}
+
+impl RecipeShopItemInfo {
+    pub fn new(
+        seller: ObjectId,
+        id: recipe::Id,
+        current_mp: u32,
+        max_mp: u32,
+        result: CraftResult,
+    ) -> Self {
+        Self {
+            seller,
+            id,
+            current_mp,
+            max_mp,
+            result,
+        }
+    }
+}
+
+impl L2rServerPacket for RecipeShopItemInfo {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::RECIPE_SHOP_ITEM_INFO.to_le_bytes());
+        buffer.u32(self.seller.into());
+        buffer.u32(*self.id);
+        buffer.u32(self.current_mp);
+        buffer.u32(self.max_mp);
+        buffer.i32(self.result.into());
+        buffer
+    }
+}
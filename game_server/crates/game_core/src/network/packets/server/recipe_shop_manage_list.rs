@@ -0,0 +1,57 @@
+use super::GameServerPacketCodes;
+use crate::{
+    object_id::ObjectId,
+    recipe::{self, RecipeBookKind, RecipeShopEntry},
+};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Manufacture shop setup window: recipes from the book and the ones already posted.
+#[derive(Clone, Debug, Reflect)]
+pub struct RecipeShopManageList {
+    seller: ObjectId,
+    adena: u64,
+    kind: RecipeBookKind,
+    recipes: Vec<recipe::Id>,
+    entries: Vec<RecipeShopEntry>,
+}
+
+impl RecipeShopManageList {
+    pub fn new(
+        seller: ObjectId,
+        adena: u64,
+        kind: RecipeBookKind,
+        recipes: Vec<recipe::Id>,
+        entries: Vec<RecipeShopEntry>,
+    ) -> Self {
+        Self {
+            seller,
+            adena,
+            kind,
+            recipes,
+            entries,
+        }
+    }
+}
+
+impl L2rServerPacket for RecipeShopManageList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::RECIPE_SHOP_MANAGE_LIST.to_le_bytes());
+        buffer.u32(self.seller.into());
+        buffer.u32(self.adena.min(u32::MAX as u64) as u32);
+        buffer.u32(self.kind.into());
+        buffer.u32_from_usize(self.recipes.len());
+        for (index, id) in self.recipes.into_iter().enumerate() {
+            buffer.u32(*id);
+            buffer.u32_from_usize(index + 1);
+        }
+        buffer.u32_from_usize(self.entries.len());
+        for entry in self.entries {
+            buffer.u32(*entry.id);
+            buffer.u32(0);
+            buffer.u64(entry.fee);
+        }
+        buffer
+    }
+}
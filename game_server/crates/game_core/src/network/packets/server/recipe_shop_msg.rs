@@ -0,0 +1,27 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Message shown above the character with an opened manufacture shop.
+#[derive(Clone, Debug, Reflect)]
+pub struct RecipeShopMsg {
+    object_id: ObjectId,
+    message: String,
+}
+
+impl RecipeShopMsg {
+    pub fn new(object_id: ObjectId, message: String) -> Self {
+        Self { object_id, message }
+    }
+}
+
+impl L2rServerPacket for RecipeShopMsg {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::RECIPE_SHOP_MSG.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.str(&self.message);
+        buffer
+    }
+}
@@ -0,0 +1,50 @@
+use super::GameServerPacketCodes;
+use crate::{object_id::ObjectId, recipe::RecipeShopEntry};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Recipes posted in the manufacture shop, shown to the buyer.
+#[derive(Clone, Debug, Reflect)]
+pub struct RecipeShopSellList {
+    seller: ObjectId,
+    current_mp: u32,
+    max_mp: u32,
+    buyer_adena: u64,
+    entries: Vec<RecipeShopEntry>,
+}
+
+impl RecipeShopSellList {
+    pub fn new(
+        seller: ObjectId,
+        current_mp: u32,
+        max_mp: u32,
+        buyer_adena: u64,
+        entries: Vec<RecipeShopEntry>,
+    ) -> Self {
+        Self {
+            seller,
+            current_mp,
+            max_mp,
+            buyer_adena,
+            entries,
+        }
+    }
+}
+
+impl L2rServerPacket for RecipeShopSellList {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::RECIPE_SHOP_SELL_LIST.to_le_bytes());
+        buffer.u32(self.seller.into());
+        buffer.u32(self.current_mp);
+        buffer.u32(self.max_mp);
+        buffer.u64(self.buyer_adena);
+        buffer.u32_from_usize(self.entries.len());
+        for entry in self.entries {
+            buffer.u32(*entry.id);
+            buffer.u32(0);
+            buffer.u64(entry.fee);
+        }
+        buffer
+    }
+}
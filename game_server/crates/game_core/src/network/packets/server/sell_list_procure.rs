@@ -0,0 +1,38 @@
+use super::GameServerPacketCodes;
+use crate::{items, object_id::ObjectId};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Crops of the character the manor manager procures in the current period.
+#[derive(Clone, Debug, Reflect)]
+pub struct SellListProcure {
+    adena: u64,
+    /// Object id, item id and count of the crops in the inventory.
+    crops: Vec<(ObjectId, items::Id, u64)>,
+}
+
+impl SellListProcure {
+    pub fn new(adena: u64, crops: Vec<(ObjectId, items::Id, u64)>) -> Self {
+        Self { adena, crops }
+    }
+}
+
+impl L2rServerPacket for SellListProcure {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::SELL_LIST_PROCURE.to_le_bytes());
+        buffer.u64(self.adena);
+        buffer.u32(0); // lease
+        buffer.u16_from_usize(self.crops.len());
+        for (object_id, item_id, count) in self.crops {
+            buffer.u16(4); // item type 1
+            buffer.u32(object_id.into());
+            buffer.u32(item_id.into());
+            buffer.u64(count);
+            buffer.u16(4); // item type 2
+            buffer.u16(0);
+            buffer.u64(0); // crops are paid by the manor, not by the reference price
+        }
+        buffer
+    }
+}
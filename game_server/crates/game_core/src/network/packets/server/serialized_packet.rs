@@ -0,0 +1,45 @@
+use super::{GameServerPacket, GameServerPacketKind};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, L2rServerPackets, ServerPacketBuffer};
+use std::sync::Arc;
+
+/// Packet body serialized once and shared by every recipient of a broadcast,
+/// only the encryption is done per session.
+#[derive(Clone, Debug, Reflect)]
+pub struct SerializedPacket {
+    kind: GameServerPacketKind,
+    #[reflect(ignore)]
+    body: Arc<[u8]>,
+}
+
+impl SerializedPacket {
+    pub fn kind(&self) -> GameServerPacketKind {
+        self.kind
+    }
+
+    pub fn len(&self) -> usize {
+        self.body.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.body.is_empty()
+    }
+}
+
+impl From<GameServerPacket> for SerializedPacket {
+    fn from(packet: GameServerPacket) -> Self {
+        match packet {
+            GameServerPacket::SerializedPacket(serialized) => serialized,
+            packet => Self {
+                kind: GameServerPacketKind::from(&packet),
+                body: packet.buffer().to_vec().into(),
+            },
+        }
+    }
+}
+
+impl L2rServerPacket for SerializedPacket {
+    fn buffer(self) -> ServerPacketBuffer {
+        ServerPacketBuffer::from(self.body.as_ref())
+    }
+}
@@ -0,0 +1,64 @@
+use super::GameServerPacketCodes;
+use crate::skills::{self, SkillList, SkillReuseTimers};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use std::fmt;
+
+/// Reuse timers shown on the skill icons, sent whole since the client replaces its list.
+#[derive(Clone, Reflect)]
+pub struct SkillCoolTime(Vec<SkillCoolTimeEntry>);
+
+#[derive(Clone, Reflect)]
+struct SkillCoolTimeEntry {
+    skill_id: skills::Id,
+    level: skills::Level,
+    reuse_secs: u32,
+    remaining_secs: u32,
+}
+
+impl fmt::Debug for SkillCoolTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "<{:?}> SkillCoolTime [skills: {}]",
+            GameServerPacketCodes::SKILL_COOL_TIME,
+            self.0.len(),
+        )
+    }
+}
+
+impl SkillCoolTime {
+    /// Skills not in the list yet are sent with their own id and the default level.
+    pub fn new(reuse_timers: &SkillReuseTimers, skill_list: Option<&SkillList>) -> Self {
+        Self(
+            reuse_timers
+                .iter()
+                .filter(|(_, timer)| !timer.finished())
+                .map(|(skill_id, timer)| {
+                    let skill = skill_list.and_then(|skill_list| skill_list.get(skill_id));
+                    SkillCoolTimeEntry {
+                        skill_id: skill.map_or(*skill_id, |skill| skill.display_id()),
+                        level: skill.map(|skill| skill.level()).unwrap_or_default(),
+                        reuse_secs: timer.duration().as_secs() as u32,
+                        remaining_secs: timer.remaining().as_secs() as u32,
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+impl L2rServerPacket for SkillCoolTime {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::SKILL_COOL_TIME.to_le_bytes());
+        buffer.u32_from_usize(self.0.len());
+        for entry in self.0 {
+            buffer.u32(entry.skill_id.into());
+            buffer.u32(entry.level.into());
+            buffer.u32(entry.reuse_secs);
+            buffer.u32(entry.remaining_secs);
+        }
+        buffer
+    }
+}
@@ -44,6 +44,7 @@ pub enum Social {
     Charm = 14,
     Shyness = 15,
     LevelUp = 2122,
+    Hero = 20016,
 }
 
 impl TryFrom<CoreAction> for Social {
@@ -1,34 +1,28 @@
 use super::GameServerPacketCodes;
+use crate::seven_signs::Cabal;
 use bevy::prelude::Reflect;
 use core::fmt;
 use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
-use num_enum::IntoPrimitive;
-
-#[derive(Clone, Copy, Debug, Default, IntoPrimitive, Reflect)]
-#[repr(u16)]
-pub enum SevenSigns {
-    DAWN = 1,
-    #[default]
-    DUSK = 2,
-}
 
+/// Sky of the world, turns to the colours of the side owning the seals
+/// during the seal validation period.
 #[derive(Clone, Default, Reflect)]
-pub struct SSQInfo(SevenSigns);
+pub struct SSQInfo(Cabal);
 impl fmt::Debug for SSQInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "winning side: {:?}", self.0)
+        write!(f, "sky: {:?}", self.0)
     }
 }
 impl L2rServerPacket for SSQInfo {
     fn buffer(self) -> ServerPacketBuffer {
         let mut buffer = ServerPacketBuffer::default();
         buffer.extend(GameServerPacketCodes::SSQ_INFO.to_le_bytes());
-        buffer.u16(self.0.into());
+        buffer.u16(256 + i16::from(self.0) as u16);
         buffer
     }
 }
 impl SSQInfo {
-    pub fn new(ssq_winner: SevenSigns) -> Self {
-        SSQInfo(ssq_winner)
+    pub fn new(sky: Cabal) -> Self {
+        SSQInfo(sky)
     }
 }
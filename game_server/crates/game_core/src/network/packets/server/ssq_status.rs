@@ -0,0 +1,207 @@
+use super::GameServerPacketCodes;
+use crate::{
+    object_id::ObjectId,
+    seven_signs::{Cabal, SEAL_CLAIM_PERCENT, SEAL_RETAIN_PERCENT, Seal, SevenSigns, SsqPeriod},
+};
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use strum::IntoEnumIterator;
+use system_messages::Id as SmId;
+
+/// Festival of darkness is not held, its levels are shown with no scores.
+const FESTIVAL_LEVEL_SCORES: [u32; 5] = [60, 70, 100, 120, 150];
+
+#[derive(Clone, Copy, Debug, Reflect)]
+pub struct SsqScore {
+    pub stone_score: u64,
+    pub festival_score: u64,
+    pub percent: u8,
+}
+
+impl SsqScore {
+    fn new(seven_signs: &SevenSigns, cabal: Cabal) -> Self {
+        Self {
+            stone_score: seven_signs.score(cabal),
+            festival_score: 0,
+            percent: seven_signs.score_percent(cabal),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Reflect)]
+enum SsqStatusPage {
+    Overview {
+        cycle: u32,
+        participant: Option<(Cabal, Seal, [u64; 3], u64)>,
+        dusk: SsqScore,
+        dawn: SsqScore,
+    },
+    Festivals,
+    /// Owner of each seal with the share of dusk and dawn members voting for it.
+    Seals(Vec<(Seal, Cabal, u8, u8)>),
+    /// Predicted owner of each seal with the message explaining it.
+    Predictions {
+        winner: Cabal,
+        seals: Vec<(Seal, Cabal, u32)>,
+    },
+}
+
+/// Page of the Seven Signs record the character opened with the seven signs record book
+/// or at the priest.
+#[derive(Clone, Debug, Reflect)]
+pub struct SsqStatus {
+    period: SsqPeriod,
+    page: SsqStatusPage,
+}
+
+impl SsqStatus {
+    pub fn new(page: u8, seven_signs: &SevenSigns, char_id: ObjectId) -> Option<Self> {
+        let page = match page {
+            1 => SsqStatusPage::Overview {
+                cycle: seven_signs.state().cycle.max(0) as u32,
+                participant: seven_signs.participant(char_id).map(|participant| {
+                    (
+                        participant.cabal,
+                        participant.seal,
+                        participant.stones(),
+                        participant.ancient_adena.max(0) as u64,
+                    )
+                }),
+                dusk: SsqScore::new(seven_signs, Cabal::Dusk),
+                dawn: SsqScore::new(seven_signs, Cabal::Dawn),
+            },
+            2 => SsqStatusPage::Festivals,
+            3 => SsqStatusPage::Seals(
+                Seal::iter()
+                    .map(|seal| {
+                        (
+                            seal,
+                            seven_signs.seal_owner(seal),
+                            seven_signs.seal_percent(seal, Cabal::Dusk),
+                            seven_signs.seal_percent(seal, Cabal::Dawn),
+                        )
+                    })
+                    .collect(),
+            ),
+            4 => {
+                let winner = seven_signs.leading_cabal();
+                let seals = Seal::iter()
+                    .map(|seal| {
+                        let owner = seven_signs.seal_owner(seal);
+                        let predicted = seven_signs.predicted_owner(seal, winner);
+                        let sm_id = if winner == Cabal::None {
+                            SmId::IfCurrentTrendsContinueItWillEndInATie
+                        } else if predicted == Cabal::None && owner == Cabal::None {
+                            SmId::SinceTheSealWasNotOwnedDuringThePreviousPeriodAndSinceLessThan35PercentOfPeopleHaveVoted
+                        } else if predicted == Cabal::None {
+                            SmId::AlthoughTheSealWasOwnedDuringThePreviousPeriodLessThan10OfPeopleHaveVoted
+                        } else if predicted == owner {
+                            SmId::SinceTheSealWasOwnedDuringThePreviousPeriodAnd10OrMorePeopleHaveParticipated
+                        } else {
+                            SmId::AlthoughTheSealWasNotOwned35OrMorePeopleHaveParticipated
+                        };
+                        (seal, predicted, sm_id as u32)
+                    })
+                    .collect();
+                SsqStatusPage::Predictions { winner, seals }
+            }
+            _ => return None,
+        };
+        Some(Self {
+            period: seven_signs.period(),
+            page,
+        })
+    }
+}
+
+fn write_score(buffer: &mut ServerPacketBuffer, score: SsqScore) {
+    buffer.u64(score.stone_score);
+    buffer.u64(score.festival_score);
+    buffer.u64(score.stone_score + score.festival_score);
+    buffer.u8(score.percent);
+}
+
+impl L2rServerPacket for SsqStatus {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::SSQ_STATUS.to_le_bytes());
+        let page = match self.page {
+            SsqStatusPage::Overview { .. } => 1,
+            SsqStatusPage::Festivals => 2,
+            SsqStatusPage::Seals(_) => 3,
+            SsqStatusPage::Predictions { .. } => 4,
+        };
+        buffer.u8(page);
+        buffer.u8(i16::from(self.period) as u8);
+
+        match self.page {
+            SsqStatusPage::Overview {
+                cycle,
+                participant,
+                dusk,
+                dawn,
+            } => {
+                buffer.u32(cycle);
+                let period_sm_id = match self.period {
+                    SsqPeriod::Competition => SmId::TheSsqCompetitionPeriodIsUnderway,
+                    SsqPeriod::SealValidation => SmId::ThisIsTheSealValidationPeriod,
+                };
+                buffer.u32(period_sm_id as u32);
+                buffer.u32(SmId::UntilNextMondayAt600PM as u32);
+                match participant {
+                    Some((cabal, seal, stones, ancient_adena)) => {
+                        buffer.u8(i16::from(cabal) as u8);
+                        buffer.u8(i16::from(seal) as u8);
+                        for count in stones {
+                            buffer.u64(count);
+                        }
+                        buffer.u64(ancient_adena);
+                    }
+                    None => {
+                        buffer.u8(0);
+                        buffer.u8(0);
+                        for _ in 0..4 {
+                            buffer.u64(0);
+                        }
+                    }
+                }
+                write_score(&mut buffer, dusk);
+                write_score(&mut buffer, dawn);
+            }
+            SsqStatusPage::Festivals => {
+                buffer.u16(1);
+                buffer.u8(FESTIVAL_LEVEL_SCORES.len() as u8);
+                for (index, level_score) in FESTIVAL_LEVEL_SCORES.into_iter().enumerate() {
+                    buffer.u8(index as u8 + 1);
+                    buffer.u32(level_score);
+                    // dusk and dawn scores with no party members
+                    for _ in 0..2 {
+                        buffer.u64(0);
+                        buffer.u8(0);
+                    }
+                }
+            }
+            SsqStatusPage::Seals(seals) => {
+                buffer.u8(SEAL_RETAIN_PERCENT);
+                buffer.u8(SEAL_CLAIM_PERCENT);
+                buffer.u8(seals.len() as u8);
+                for (seal, owner, dusk_percent, dawn_percent) in seals {
+                    buffer.u8(i16::from(seal) as u8);
+                    buffer.u8(i16::from(owner) as u8);
+                    buffer.u8(dusk_percent);
+                    buffer.u8(dawn_percent);
+                }
+            }
+            SsqStatusPage::Predictions { winner, seals } => {
+                buffer.u8(i16::from(winner) as u8);
+                buffer.u8(seals.len() as u8);
+                for (seal, owner, sm_id) in seals {
+                    buffer.u8(i16::from(seal) as u8);
+                    buffer.u8(i16::from(owner) as u8);
+                    buffer.u16(sm_id as u16);
+                }
+            }
+        }
+        buffer
+    }
+}
@@ -0,0 +1,42 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use spatial::{GameVec3, Heading};
+
+/// Stops the passenger at the position relative to the vehicle.
+#[derive(Clone, Debug, Reflect)]
+pub struct StopMoveInVehicle {
+    object_id: ObjectId,
+    vehicle_oid: ObjectId,
+    position: Vec3,
+    heading: Heading,
+}
+
+impl L2rServerPacket for StopMoveInVehicle {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::STOP_MOVE_IN_VEHICLE.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.u32(self.vehicle_oid.into());
+        buffer.extend(GameVec3::from(self.position).to_le_bytes());
+        buffer.i32(self.heading.into());
+        buffer
+    }
+}
+
+impl StopMoveInVehicle {
+    pub fn new(
+        object_id: ObjectId,
+        vehicle_oid: ObjectId,
+        position: Vec3,
+        heading: Heading,
+    ) -> Self {
+        Self {
+            object_id,
+            vehicle_oid,
+            position,
+            heading,
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
     character,
     items::{self, ItemsQuery},
     object_id::ObjectId,
+    recipe::{RecipeBook, RecipeBookKind},
     stats::*,
 };
 use bevy::prelude::*;
@@ -42,6 +43,13 @@ pub struct UserInfo {
     pub collision_height: f64,
     pub position: GameVec3,
     pub equipped_items: Vec<(ObjectId, items::Id, items::AugumentId)>,
+    pub private_store_type: u8,
+    pub dwarven_craft: bool,
+    pub noblesse: bool,
+    pub hero: bool,
+    pub vehicle_oid: Option<ObjectId>,
+    /// Where the bobber floats while the character is fishing.
+    pub fishing: Option<GameVec3>,
     //TODO: для дебага
     pub entity: Entity,
 }
@@ -109,6 +117,21 @@ impl UserInfo {
             collision_height,
             position: GameVec3::from(character.transform.translation),
             equipped_items,
+            private_store_type: character
+                .recipe_shop
+                .map(|shop| shop.store_type())
+                .unwrap_or_default(),
+            dwarven_craft: character
+                .skill_list
+                .is_some_and(|skills| RecipeBook::craft_level(RecipeBookKind::Dwarven, skills) > 0),
+            noblesse: character.noblesse,
+            hero: character.hero,
+            vehicle_oid: character
+                .in_vehicle
+                .map(|in_vehicle| in_vehicle.vehicle_oid),
+            fishing: character
+                .fishing
+                .map(|fishing| GameVec3::from(fishing.bobber)),
             entity: character.entity,
         }
     }
@@ -143,7 +166,7 @@ impl L2rServerPacket for UserInfo {
 
         buffer.extend(GameServerPacketCodes::USER_INFO.to_le_bytes());
         buffer.extend(self.position.to_le_bytes());
-        buffer.u32(0); // stub for vehicle id
+        buffer.u32(self.vehicle_oid.unwrap_or_default().into());
         buffer.u32(self.object_id.into());
         buffer.str(&self.name);
         buffer.u32(self.race.into());
@@ -221,8 +244,8 @@ impl L2rServerPacket for UserInfo {
         buffer.u32(0); // ally crest id
         buffer.u32(0); // relation
         buffer.u8(12u8); // mount type
-        buffer.u8(0u8); // private store type
-        buffer.bool(false); // has dwarven craft
+        buffer.u8(self.private_store_type);
+        buffer.bool(self.dwarven_craft);
         buffer.u32(self.pvp_stats.pk_kills);
         buffer.u32(self.pvp_stats.pvp_kills);
         buffer.u16(0); // cubics size
@@ -241,12 +264,10 @@ impl L2rServerPacket for UserInfo {
         buffer.u8(0u8); // mount effect
         buffer.u8(0u8); // team id
         buffer.u32(0); // clan crest large id
-        buffer.bool(false); // noble
-        buffer.bool(false); // hero
-        buffer.bool(false); // fishing mode
-        buffer.i32(0); // fishing x
-        buffer.i32(0); // fishing y
-        buffer.i32(0); // fishing z
+        buffer.bool(self.noblesse);
+        buffer.bool(self.hero); // hero aura
+        buffer.bool(self.fishing.is_some()); // fishing mode
+        buffer.extend(self.fishing.unwrap_or_default().to_le_bytes()); // fishing x, y, z
         buffer.u32(u32::MAX); // name color
         buffer.bool(self.movable.is_running()); // running
         buffer.u32(0); // pledge class
@@ -0,0 +1,42 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use spatial::{GameVec3, Heading};
+
+/// Corrects the position of the passenger relative to the vehicle.
+#[derive(Clone, Debug, Reflect)]
+pub struct ValidateLocationInVehicle {
+    object_id: ObjectId,
+    vehicle_oid: ObjectId,
+    position: Vec3,
+    heading: Heading,
+}
+
+impl L2rServerPacket for ValidateLocationInVehicle {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::VALIDATE_LOCATION_IN_VEHICLE.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.u32(self.vehicle_oid.into());
+        buffer.extend(GameVec3::from(self.position).to_le_bytes());
+        buffer.i32(self.heading.into());
+        buffer
+    }
+}
+
+impl ValidateLocationInVehicle {
+    pub fn new(
+        object_id: ObjectId,
+        vehicle_oid: ObjectId,
+        position: Vec3,
+        heading: Heading,
+    ) -> Self {
+        Self {
+            object_id,
+            vehicle_oid,
+            position,
+            heading,
+        }
+    }
+}
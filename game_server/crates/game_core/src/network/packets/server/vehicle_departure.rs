@@ -0,0 +1,37 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use spatial::GameVec3;
+
+/// Sends the vehicle to the next point of its route.
+#[derive(Clone, Debug, Reflect)]
+pub struct VehicleDeparture {
+    object_id: ObjectId,
+    speed: u32,
+    rotation_speed: u32,
+    destination: Vec3,
+}
+
+impl L2rServerPacket for VehicleDeparture {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::VEHICLE_DEPARTURE.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.u32(self.speed);
+        buffer.u32(self.rotation_speed);
+        buffer.extend(GameVec3::from(self.destination).to_le_bytes());
+        buffer
+    }
+}
+
+impl VehicleDeparture {
+    pub fn new(object_id: ObjectId, speed: u32, rotation_speed: u32, destination: Vec3) -> Self {
+        Self {
+            object_id,
+            speed,
+            rotation_speed,
+            destination,
+        }
+    }
+}
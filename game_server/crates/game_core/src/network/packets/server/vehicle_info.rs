@@ -0,0 +1,32 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+use spatial::{GameVec3, Heading};
+
+/// Shows the vehicle to the character.
+#[derive(Clone, Debug, Reflect)]
+pub struct VehicleInfo {
+    object_id: ObjectId,
+    transform: Transform,
+}
+
+impl L2rServerPacket for VehicleInfo {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::VEHICLE_INFO.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.extend(GameVec3::from(self.transform.translation).to_le_bytes());
+        buffer.i32(Heading::from(self.transform.rotation).into());
+        buffer
+    }
+}
+
+impl VehicleInfo {
+    pub fn new(object_id: ObjectId, transform: Transform) -> Self {
+        Self {
+            object_id,
+            transform,
+        }
+    }
+}
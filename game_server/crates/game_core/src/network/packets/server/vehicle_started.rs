@@ -0,0 +1,27 @@
+use super::GameServerPacketCodes;
+use crate::object_id::ObjectId;
+use bevy::prelude::*;
+use l2r_core::packets::{L2rServerPacket, ServerPacketBuffer};
+
+/// Starts or stops the engines of the vehicle, leaving or reaching the dock.
+#[derive(Clone, Debug, Reflect)]
+pub struct VehicleStarted {
+    object_id: ObjectId,
+    started: bool,
+}
+
+impl L2rServerPacket for VehicleStarted {
+    fn buffer(self) -> ServerPacketBuffer {
+        let mut buffer = ServerPacketBuffer::default();
+        buffer.extend(GameServerPacketCodes::VEHICLE_START.to_le_bytes());
+        buffer.u32(self.object_id.into());
+        buffer.u32_from_bool(self.started);
+        buffer
+    }
+}
+
+impl VehicleStarted {
+    pub fn new(object_id: ObjectId, started: bool) -> Self {
+        Self { object_id, started }
+    }
+}
@@ -1,5 +1,7 @@
 use super::{
-    config::GameServerNetworkConfig, packets::server::GameServerPacket, protocol::Version,
+    config::GameServerNetworkConfig,
+    packets::server::{GameServerPacket, PacketBatch},
+    protocol::Version,
 };
 use crate::{character, network::packets::server::GameServerPacketKind};
 use bevy::prelude::*;
@@ -9,6 +11,7 @@ use l2r_core::model::session::{L2rSession, ServerSessions, SessionId};
 use std::time::{Duration, Instant, SystemTime};
 
 #[derive(Clone, Component, Debug)]
+#[require(OutgoingPackets)]
 pub struct GameServerSession {
     connection: ServerConnection<GameServerNetworkConfig>,
     protocol_version: Version,
@@ -17,6 +20,26 @@ pub struct GameServerSession {
     current_ping: Option<Duration>,
 }
 
+/// Packets sent to the session during the tick, written to the socket at once when the tick is over.
+#[derive(Clone, Component, Debug, Default, Deref, DerefMut)]
+pub struct OutgoingPackets(Vec<GameServerPacket>);
+
+impl OutgoingPackets {
+    /// Takes the packets of the tick, a few of them are sent as one [`PacketBatch`].
+    pub fn take(&mut self) -> Option<GameServerPacket> {
+        match self.0.len() {
+            0 => None,
+            1 => self.0.pop(),
+            _ => Some(PacketBatch::new(std::mem::take(&mut self.0)).into()),
+        }
+    }
+}
+
+/// Closes the session once the packets queued for it are written,
+/// so the last packets of a kick or a shutdown still reach the client.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct DisconnectSession;
+
 impl PartialEq for GameServerSession {
     fn eq(&self, other: &Self) -> bool {
         self.connection.id() == other.connection.id()
@@ -1,4 +1,11 @@
-use crate::{object_id::ObjectId, teleport::TeleportListKind};
+use crate::{
+    instance_zone::InstanceTemplateId,
+    items,
+    object_id::ObjectId,
+    olympiad::CompetitionType,
+    seven_signs::{Cabal, Seal},
+    teleport::TeleportListKind,
+};
 use bevy::reflect::Reflect;
 use std::str::FromStr;
 use strum::{Display, EnumDiscriminants, EnumIter, EnumString};
@@ -50,6 +57,187 @@ impl FromStr for ChatCommand {
     }
 }
 
+/// Symbol maker dialog actions.
+#[derive(Clone, Copy, Debug, Display, EnumString, Eq, Hash, PartialEq, Reflect)]
+#[strum(serialize_all = "snake_case")]
+pub enum HennaCommand {
+    Draw,
+    Remove,
+}
+
+/// Fisherman dialog actions.
+#[derive(Clone, Copy, Debug, Display, EnumString, Eq, Hash, PartialEq, Reflect)]
+#[strum(serialize_all = "snake_case")]
+pub enum FishingCommand {
+    Skills,
+}
+
+/// Castle siege dialog actions of the messengers and the holy artifacts.
+#[derive(Clone, Copy, Debug, Display, EnumString, Eq, Hash, PartialEq, Reflect)]
+#[strum(serialize_all = "snake_case")]
+pub enum SiegeCommand {
+    Info,
+    Seal,
+}
+
+/// Castle functions of the chamberlain, the doormen and the teleporter, served to the castle owner.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq, Reflect)]
+#[strum(serialize_all = "snake_case")]
+pub enum CastleCommand {
+    Manage,
+    Tax(i32),
+    Withdraw(u64),
+    OpenDoors,
+    CloseDoors,
+    Teleports,
+    Tp(crate::teleport::Id),
+    SeedSetting,
+    CropSetting,
+}
+
+impl FromStr for CastleCommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, ' ');
+        let command = parts.next().unwrap_or("");
+        let arg = parts.next().map(str::trim);
+
+        match (command, arg) {
+            ("manage", None) => Ok(CastleCommand::Manage),
+            ("open_doors", None) => Ok(CastleCommand::OpenDoors),
+            ("close_doors", None) => Ok(CastleCommand::CloseDoors),
+            ("teleports", None) => Ok(CastleCommand::Teleports),
+            ("seed_setting", None) => Ok(CastleCommand::SeedSetting),
+            ("crop_setting", None) => Ok(CastleCommand::CropSetting),
+            ("tax", Some(arg)) => arg
+                .parse::<i32>()
+                .map(CastleCommand::Tax)
+                .map_err(|_| format!("Invalid tax rate: {arg}")),
+            ("withdraw", Some(arg)) => arg
+                .parse::<u64>()
+                .map(CastleCommand::Withdraw)
+                .map_err(|_| format!("Invalid amount: {arg}")),
+            ("tp", Some(arg)) => arg
+                .parse::<crate::teleport::Id>()
+                .map(CastleCommand::Tp)
+                .map_err(|_| format!("Invalid TP ID: {arg}")),
+            _ => Err(format!("Invalid castle command: {s}")),
+        }
+    }
+}
+
+/// Manor manager dialog actions, open the seed shop or the crop sale window.
+#[derive(Clone, Copy, Debug, Display, EnumString, Eq, Hash, PartialEq, Reflect)]
+#[strum(serialize_all = "snake_case")]
+pub enum ManorCommand {
+    BuySeeds,
+    SellCrops,
+}
+
+/// Seven Signs dialog actions of the priests of dawn and the priestesses of dusk.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq, Reflect)]
+#[strum(serialize_all = "snake_case")]
+pub enum SsqCommand {
+    Status(u8),
+    Join(Cabal, Seal),
+    Contribute,
+    Reward,
+}
+
+impl FromStr for SsqCommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let command = parts.next().unwrap_or("");
+        let args = parts.collect::<Vec<_>>();
+
+        match (command, args.as_slice()) {
+            ("contribute", []) => Ok(SsqCommand::Contribute),
+            ("reward", []) => Ok(SsqCommand::Reward),
+            ("status", [page]) => page
+                .parse::<u8>()
+                .map(SsqCommand::Status)
+                .map_err(|_| format!("Invalid status page: {page}")),
+            ("join", [cabal, seal]) => {
+                let cabal =
+                    Cabal::from_str(cabal).map_err(|_| format!("Invalid cabal: {cabal}"))?;
+                let seal = Seal::from_str(seal).map_err(|_| format!("Invalid seal: {seal}"))?;
+                Ok(SsqCommand::Join(cabal, seal))
+            }
+            _ => Err(format!("Invalid seven signs command: {s}")),
+        }
+    }
+}
+
+/// Grand Olympiad manager dialog actions.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq, Reflect)]
+#[strum(serialize_all = "snake_case")]
+pub enum OlympiadCommand {
+    Register(CompetitionType),
+    Unregister,
+    Points,
+    /// Watches the match in the stadium with the given number.
+    Observe(usize),
+    /// Hero takes the weapon from the Monument of Heroes.
+    HeroWeapon(items::Id),
+}
+
+impl FromStr for OlympiadCommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let command = parts.next().unwrap_or("");
+        let args = parts.collect::<Vec<_>>();
+
+        match (command, args.as_slice()) {
+            ("unregister", []) => Ok(OlympiadCommand::Unregister),
+            ("points", []) => Ok(OlympiadCommand::Points),
+            ("register", [kind]) => CompetitionType::from_str(kind)
+                .map(OlympiadCommand::Register)
+                .map_err(|_| format!("Invalid competition type: {kind}")),
+            ("observe", [stadium]) => stadium
+                .parse::<usize>()
+                .map(OlympiadCommand::Observe)
+                .map_err(|_| format!("Invalid stadium: {stadium}")),
+            ("hero_weapon", [item_id]) => item_id
+                .parse::<items::Id>()
+                .map(OlympiadCommand::HeroWeapon)
+                .map_err(|_| format!("Invalid hero weapon: {item_id}")),
+            _ => Err(format!("Invalid olympiad command: {s}")),
+        }
+    }
+}
+
+/// Instance entry dialog actions.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Reflect)]
+pub enum InstanceCommand {
+    /// Enters a new instance of the template, or the running one the character is a member of.
+    Enter(InstanceTemplateId),
+    Leave,
+}
+
+impl FromStr for InstanceCommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let command = parts.next().unwrap_or("");
+        let args = parts.collect::<Vec<_>>();
+
+        match (command, args.as_slice()) {
+            ("leave", []) => Ok(InstanceCommand::Leave),
+            ("enter", [template_id]) => template_id
+                .parse::<u32>()
+                .map(|template_id| InstanceCommand::Enter(template_id.into()))
+                .map_err(|_| format!("Invalid instance template: {template_id}")),
+            _ => Err(format!("Invalid instance command: {s}")),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Reflect)]
 pub struct NpcAction {
     pub npc_oid: ObjectId,
@@ -86,6 +274,18 @@ pub enum NpcCommand {
     Chat(ChatCommand),
     Quest(String),
     Multisell(u32),
+    Henna(HennaCommand),
+    Siege(SiegeCommand),
+    Castle(CastleCommand),
+    Manor(ManorCommand),
+    Ssq(SsqCommand),
+    /// Ziggurat gatekeeper teleport into the necropolis.
+    Necro(crate::teleport::Id),
+    /// Ziggurat gatekeeper teleport into the catacomb.
+    Cata(crate::teleport::Id),
+    Olympiad(OlympiadCommand),
+    Instance(InstanceCommand),
+    Fishing(FishingCommand),
 }
 
 impl FromStr for NpcCommand {
@@ -149,6 +349,110 @@ impl FromStr for NpcCommand {
                     "Invalid or missing argument for multisell command: {command}"
                 ))
             }
+
+            NpcCommandVariants::Henna => {
+                if let Some(arg) = arg {
+                    return HennaCommand::from_str(arg)
+                        .map(NpcCommand::Henna)
+                        .map_err(|_| format!("Invalid henna command: {arg}"));
+                }
+
+                Err(format!(
+                    "Invalid or missing argument for henna command: {command}"
+                ))
+            }
+
+            NpcCommandVariants::Siege => {
+                if let Some(arg) = arg {
+                    return SiegeCommand::from_str(arg)
+                        .map(NpcCommand::Siege)
+                        .map_err(|_| format!("Invalid siege command: {arg}"));
+                }
+
+                Err(format!(
+                    "Invalid or missing argument for siege command: {command}"
+                ))
+            }
+
+            NpcCommandVariants::Castle => {
+                if let Some(arg) = arg {
+                    return CastleCommand::from_str(arg).map(NpcCommand::Castle);
+                }
+
+                Err(format!(
+                    "Invalid or missing argument for castle command: {command}"
+                ))
+            }
+
+            NpcCommandVariants::Manor => {
+                if let Some(arg) = arg {
+                    return ManorCommand::from_str(arg)
+                        .map(NpcCommand::Manor)
+                        .map_err(|_| format!("Invalid manor command: {arg}"));
+                }
+
+                Err(format!(
+                    "Invalid or missing argument for manor command: {command}"
+                ))
+            }
+
+            NpcCommandVariants::Ssq => {
+                if let Some(arg) = arg {
+                    return SsqCommand::from_str(arg).map(NpcCommand::Ssq);
+                }
+
+                Err(format!(
+                    "Invalid or missing argument for seven signs command: {command}"
+                ))
+            }
+
+            NpcCommandVariants::Necro | NpcCommandVariants::Cata => {
+                let Some(arg) = arg else {
+                    return Err(format!(
+                        "Invalid or missing argument for ziggurat command: {command}"
+                    ));
+                };
+                let id = arg
+                    .trim()
+                    .parse::<crate::teleport::Id>()
+                    .map_err(|_| format!("Invalid TP ID: {arg}"))?;
+                Ok(match variant {
+                    NpcCommandVariants::Necro => NpcCommand::Necro(id),
+                    _ => NpcCommand::Cata(id),
+                })
+            }
+
+            NpcCommandVariants::Olympiad => {
+                if let Some(arg) = arg {
+                    return OlympiadCommand::from_str(arg).map(NpcCommand::Olympiad);
+                }
+
+                Err(format!(
+                    "Invalid or missing argument for olympiad command: {command}"
+                ))
+            }
+
+            NpcCommandVariants::Instance => {
+                if let Some(arg) = arg {
+                    return InstanceCommand::from_str(arg).map(NpcCommand::Instance);
+                }
+
+                Err(format!(
+                    "Invalid or missing argument for instance command: {command}"
+                ))
+            }
+
+            NpcCommandVariants::Fishing => {
+                if let Some(arg) = arg {
+                    return FishingCommand::from_str(arg)
+                        .map(NpcCommand::Fishing)
+                        .map_err(|_| format!("Invalid fishing command: {arg}"));
+                }
+
+                Err(format!(
+                    "Invalid or missing argument for fishing command: {command}"
+                ))
+            }
         }
     }
 }
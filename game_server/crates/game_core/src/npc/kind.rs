@@ -76,6 +76,7 @@ pub enum Kind {
     SepulcherNpc,
     Servitor,
     SignsPriest,
+    SymbolMaker,
     TamedBeast,
     Teleporter(Option<TeleporterInfo>),
     TerrainObject,
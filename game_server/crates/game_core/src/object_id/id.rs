@@ -57,9 +57,8 @@ impl Component for ObjectId {
                 return;
             };
 
-            world
-                .commands()
-                .trigger_targets(KnownEntitiesRemoved::new(object_id), context.entity);
+            let removed = KnownEntitiesRemoved::new(object_id, world.entity(context.entity));
+            world.commands().trigger_targets(removed, context.entity);
         })
     }
 
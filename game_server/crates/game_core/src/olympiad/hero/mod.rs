@@ -0,0 +1,3 @@
+pub mod model;
+
+pub type Hero = model::Model;
@@ -0,0 +1,59 @@
+use crate::{character, object_id::ObjectId, stats::ClassId};
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::entity::prelude::*;
+
+pub type HeroesRepository = DbRepository<ObjectId, Entity>;
+
+/// Noble who has been the hero of the class, only heroes of the last month are active.
+#[derive(Clone, Copy, Debug, DeriveEntityModel, Eq, PartialEq, Reflect)]
+#[sea_orm(table_name = "heroes")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub char_id: ObjectId,
+    pub class_id: ClassId,
+    /// Months the noble has been the hero.
+    pub count: i32,
+    pub active: bool,
+}
+
+impl Model {
+    pub fn new(char_id: ObjectId, class_id: ClassId) -> Self {
+        Self {
+            char_id,
+            class_id,
+            count: 0,
+            active: false,
+        }
+    }
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CharId]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::ClassId, Column::Count, Column::Active]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::CharId",
+        to = "character::model::Column::Id"
+    )]
+    Character,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,601 @@
+use crate::{abnormal_effects::AbnormalEffects, object_id::ObjectId, stats::ClassId};
+use bevy::{platform::collections::HashMap, prelude::*};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike};
+use strum::{Display, EnumString};
+use thiserror::Error;
+
+pub mod hero;
+pub mod model;
+pub mod noble;
+
+pub use hero::Hero;
+pub use noble::Noble;
+
+pub struct OlympiadComponentsPlugin;
+impl Plugin for OlympiadComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CompetitionType>()
+            .register_type::<OlympiadSide>()
+            .register_type::<OlympiadParticipant>()
+            .register_type::<Observing>()
+            .register_type::<Olympiad>()
+            .register_type::<model::Model>()
+            .register_type::<noble::model::Model>()
+            .register_type::<hero::model::Model>();
+    }
+}
+
+/// Matches are fought every day from this hour till midnight.
+pub const COMPETITION_START_HOUR: u32 = 18;
+/// Points every noble starts the month with.
+pub const START_POINTS: i32 = 10;
+/// Points every noble is given each week.
+pub const WEEKLY_POINTS: i32 = 3;
+/// Loser gives away this share of the lower points of both opponents.
+pub const POINTS_TRANSFER_DIVIDER: i32 = 5;
+pub const MAX_POINTS_TRANSFER: i32 = 10;
+/// Nobles of the same class waiting for a class-based match before one starts.
+pub const CLASSED_MIN_PARTICIPANTS: usize = 5;
+/// Nobles waiting for a non-class match before one starts.
+pub const NON_CLASSED_MIN_PARTICIPANTS: usize = 9;
+/// Matches and wins a noble needs in the month to become a hero.
+pub const HERO_MIN_MATCHES: i32 = 9;
+pub const HERO_MIN_WINS: i32 = 1;
+
+pub const TELEPORT_SECONDS: u64 = 20;
+pub const PREPARATION_SECONDS: u64 = 60;
+pub const FIGHT_SECONDS: u64 = 360;
+pub const RETURN_SECONDS: u64 = 20;
+/// Remaining seconds of the countdowns told to the opponents.
+pub const COUNTDOWN_ANNOUNCEMENTS: [u64; 9] = [60, 30, 20, 10, 5, 4, 3, 2, 1];
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum OlympiadError {
+    #[error("Olympiad matches are not fought now")]
+    NotInCompetition,
+    #[error("Noble is already registered for the {0} competition")]
+    AlreadyRegistered(CompetitionType),
+    #[error("Noble is not registered for any competition")]
+    NotRegistered,
+    #[error("Noble has no points left to fight for")]
+    NoPoints,
+}
+
+#[derive(Clone, Copy, Debug, Display, EnumString, Eq, Hash, PartialEq, Reflect)]
+#[strum(serialize_all = "snake_case")]
+pub enum CompetitionType {
+    /// Opponents are of the same class.
+    Classed,
+    /// Opponents are of any class.
+    NonClassed,
+}
+
+/// Side of the stadium the opponent fights on, values match the client ones.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Reflect)]
+#[repr(u8)]
+pub enum OlympiadSide {
+    One = 1,
+    Two = 2,
+}
+
+impl OlympiadSide {
+    pub fn index(self) -> usize {
+        self as usize - 1
+    }
+}
+
+/// Character fighting in the stadium, with what it has to get back once the match is over.
+#[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct OlympiadParticipant {
+    pub stadium: Entity,
+    pub side: OlympiadSide,
+    pub return_position: Vec3,
+    /// Effects stripped for the match, restored afterwards.
+    pub effects: AbnormalEffects,
+}
+
+/// Character watching the match in the stadium.
+#[derive(Clone, Component, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Observing {
+    pub stadium: Entity,
+    pub return_position: Vec3,
+}
+
+/// Character asks to watch the match in the stadium with the given number.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct ObserveStadium(pub usize);
+
+pub fn competition_open(time: NaiveTime) -> bool {
+    time.hour() >= COMPETITION_START_HOUR
+}
+
+/// First day of the month after the given time, when heroes are chosen.
+pub fn next_month_start(after: NaiveDateTime) -> NaiveDateTime {
+    let date = after.date();
+    let (year, month) = match date.month() {
+        12 => (date.year() + 1, 1),
+        month => (date.year(), month + 1),
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .unwrap_or(date)
+        .and_time(NaiveTime::MIN)
+}
+
+/// Points the loser gives to the winner.
+pub fn points_transfer(winner_points: i32, loser_points: i32) -> i32 {
+    (winner_points.min(loser_points) / POINTS_TRANSFER_DIVIDER).clamp(1, MAX_POINTS_TRANSFER)
+}
+
+/// Nobles with their points and heroes of the Grand Olympiad, inserted once loaded from the database.
+/// Waiting lists are kept only in memory.
+#[derive(Clone, Debug, Default, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct Olympiad {
+    state: model::Model,
+    nobles: HashMap<ObjectId, Noble>,
+    heroes: HashMap<ObjectId, Hero>,
+    classed: Vec<(ObjectId, ClassId)>,
+    non_classed: Vec<ObjectId>,
+}
+
+impl Olympiad {
+    pub fn new(
+        state: model::Model,
+        nobles: impl IntoIterator<Item = Noble>,
+        heroes: impl IntoIterator<Item = Hero>,
+    ) -> Self {
+        Self {
+            state,
+            nobles: nobles
+                .into_iter()
+                .map(|noble| (noble.char_id, noble))
+                .collect(),
+            heroes: heroes
+                .into_iter()
+                .map(|hero| (hero.char_id, hero))
+                .collect(),
+            ..default()
+        }
+    }
+
+    pub fn state(&self) -> &model::Model {
+        &self.state
+    }
+
+    pub fn noble(&self, char_id: ObjectId) -> Option<&Noble> {
+        self.nobles.get(&char_id)
+    }
+
+    pub fn nobles(&self) -> impl Iterator<Item = &Noble> {
+        self.nobles.values()
+    }
+
+    pub fn heroes(&self) -> impl Iterator<Item = &Hero> {
+        self.heroes.values()
+    }
+
+    pub fn is_hero(&self, char_id: ObjectId) -> bool {
+        self.heroes.get(&char_id).is_some_and(|hero| hero.active)
+    }
+
+    pub fn registered(&self, char_id: ObjectId) -> Option<CompetitionType> {
+        if self.classed.iter().any(|(id, _)| *id == char_id) {
+            Some(CompetitionType::Classed)
+        } else if self.non_classed.contains(&char_id) {
+            Some(CompetitionType::NonClassed)
+        } else {
+            None
+        }
+    }
+
+    /// Nobles waiting for the competition.
+    pub fn waiting(&self, kind: CompetitionType) -> usize {
+        match kind {
+            CompetitionType::Classed => self.classed.len(),
+            CompetitionType::NonClassed => self.non_classed.len(),
+        }
+    }
+
+    /// Puts the noble on the waiting list, nobles fighting for the first time get the start points.
+    pub fn register(
+        &mut self,
+        char_id: ObjectId,
+        class_id: ClassId,
+        kind: CompetitionType,
+        now: NaiveDateTime,
+    ) -> Result<&Noble, OlympiadError> {
+        if !competition_open(now.time()) {
+            return Err(OlympiadError::NotInCompetition);
+        }
+        if let Some(registered) = self.registered(char_id) {
+            return Err(OlympiadError::AlreadyRegistered(registered));
+        }
+        let noble = self
+            .nobles
+            .entry(char_id)
+            .or_insert_with(|| Noble::new(char_id, class_id));
+        if noble.points <= 0 {
+            return Err(OlympiadError::NoPoints);
+        }
+        match kind {
+            CompetitionType::Classed => self.classed.push((char_id, noble.class_id)),
+            CompetitionType::NonClassed => self.non_classed.push(char_id),
+        }
+        Ok(noble)
+    }
+
+    pub fn unregister(&mut self, char_id: ObjectId) -> Result<(), OlympiadError> {
+        if self.registered(char_id).is_none() {
+            return Err(OlympiadError::NotRegistered);
+        }
+        self.classed.retain(|(id, _)| *id != char_id);
+        self.non_classed.retain(|id| *id != char_id);
+        Ok(())
+    }
+
+    /// Takes the first two nobles off the waiting list which has enough of them,
+    /// class-based matches go first.
+    pub fn next_pair(&mut self) -> Option<(CompetitionType, [ObjectId; 2])> {
+        let mut classes: HashMap<ClassId, usize> = HashMap::new();
+        for (_, class_id) in self.classed.iter() {
+            *classes.entry(*class_id).or_default() += 1;
+        }
+        let full_class = self
+            .classed
+            .iter()
+            .map(|(_, class_id)| *class_id)
+            .find(|class_id| classes[class_id] >= CLASSED_MIN_PARTICIPANTS);
+        if let Some(class_id) = full_class {
+            let mut pair = self
+                .classed
+                .iter()
+                .filter(|(_, id)| *id == class_id)
+                .map(|(char_id, _)| *char_id);
+            let pair = [pair.next()?, pair.next()?];
+            self.classed.retain(|(char_id, _)| !pair.contains(char_id));
+            return Some((CompetitionType::Classed, pair));
+        }
+
+        if self.non_classed.len() >= NON_CLASSED_MIN_PARTICIPANTS {
+            let pair = [self.non_classed.remove(0), self.non_classed.remove(0)];
+            return Some((CompetitionType::NonClassed, pair));
+        }
+        None
+    }
+
+    /// Counts the match for both opponents, the loser gives points to the winner.
+    /// Returns the points given, nobody gives any in a tie.
+    pub fn finish_match(&mut self, opponents: [ObjectId; 2], winner: Option<ObjectId>) -> i32 {
+        let Some(winner) = winner else {
+            for char_id in opponents {
+                if let Some(noble) = self.nobles.get_mut(&char_id) {
+                    noble.competitions_done += 1;
+                    noble.competitions_drawn += 1;
+                }
+            }
+            return 0;
+        };
+        let Some(loser) = opponents.into_iter().find(|char_id| *char_id != winner) else {
+            return 0;
+        };
+        let (Some(winner_points), Some(loser_points)) = (
+            self.nobles.get(&winner).map(|noble| noble.points),
+            self.nobles.get(&loser).map(|noble| noble.points),
+        ) else {
+            return 0;
+        };
+
+        let points = points_transfer(winner_points, loser_points);
+        if let Some(noble) = self.nobles.get_mut(&winner) {
+            noble.points += points;
+            noble.competitions_done += 1;
+            noble.competitions_won += 1;
+        }
+        if let Some(noble) = self.nobles.get_mut(&loser) {
+            noble.points = (noble.points - points).max(0);
+            noble.competitions_done += 1;
+            noble.competitions_lost += 1;
+        }
+        points
+    }
+
+    pub fn weekly_points_due(&self, now: NaiveDateTime) -> bool {
+        now >= *self.state.weekly_points
+    }
+
+    pub fn add_weekly_points(&mut self, now: NaiveDateTime) {
+        for noble in self.nobles.values_mut() {
+            noble.points += WEEKLY_POINTS;
+        }
+        self.state.weekly_points = (now + TimeDelta::weeks(1)).into();
+    }
+
+    pub fn month_over(&self, now: NaiveDateTime) -> bool {
+        now >= *self.state.month_end
+    }
+
+    /// Noble of each class with the most points among those who fought enough becomes the hero,
+    /// heroes of the previous month step down and everybody starts over.
+    /// Returns the new heroes.
+    pub fn end_month(&mut self, now: NaiveDateTime) -> Vec<Hero> {
+        let mut best: HashMap<ClassId, &Noble> = HashMap::new();
+        for noble in self.nobles.values().filter(|noble| {
+            noble.competitions_done >= HERO_MIN_MATCHES && noble.competitions_won >= HERO_MIN_WINS
+        }) {
+            let better = best.get(&noble.class_id).is_none_or(|current| {
+                (noble.points, noble.competitions_won) > (current.points, current.competitions_won)
+            });
+            if better {
+                best.insert(noble.class_id, noble);
+            }
+        }
+        let chosen = best
+            .values()
+            .map(|noble| (noble.char_id, noble.class_id))
+            .collect::<Vec<_>>();
+
+        for hero in self.heroes.values_mut() {
+            hero.active = false;
+        }
+        let mut new_heroes = Vec::with_capacity(chosen.len());
+        for (char_id, class_id) in chosen {
+            let hero = self
+                .heroes
+                .entry(char_id)
+                .or_insert_with(|| Hero::new(char_id, class_id));
+            hero.class_id = class_id;
+            hero.count += 1;
+            hero.active = true;
+            new_heroes.push(*hero);
+        }
+
+        for noble in self.nobles.values_mut() {
+            noble.reset();
+        }
+        self.classed.clear();
+        self.non_classed.clear();
+        self.state.cycle += 1;
+        self.state.month_end = next_month_start(now).into();
+        self.state.weekly_points = (now + TimeDelta::weeks(1)).into();
+        new_heroes
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Reflect)]
+pub enum GameStage {
+    /// Opponents are told they will be moved to the stadium.
+    Teleport,
+    /// Opponents are in the stadium waiting for the fight to start.
+    Preparation,
+    Fight,
+    /// Match is over, opponents are moved back when the countdown ends.
+    Return,
+}
+
+impl GameStage {
+    pub fn duration(self) -> u64 {
+        match self {
+            GameStage::Teleport => TELEPORT_SECONDS,
+            GameStage::Preparation => PREPARATION_SECONDS,
+            GameStage::Fight => FIGHT_SECONDS,
+            GameStage::Return => RETURN_SECONDS,
+        }
+    }
+}
+
+/// Match of two nobles in a stadium, counted down by seconds.
+#[derive(Clone, Debug, Reflect)]
+pub struct OlympiadGame {
+    pub kind: CompetitionType,
+    /// Opponents in the order of their sides.
+    pub opponents: [ObjectId; 2],
+    stage: GameStage,
+    remaining: u64,
+}
+
+impl OlympiadGame {
+    pub fn new(kind: CompetitionType, opponents: [ObjectId; 2]) -> Self {
+        Self {
+            kind,
+            opponents,
+            stage: GameStage::Teleport,
+            remaining: GameStage::Teleport.duration(),
+        }
+    }
+
+    pub fn stage(&self) -> GameStage {
+        self.stage
+    }
+
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    pub fn side(&self, char_id: ObjectId) -> Option<OlympiadSide> {
+        match self.opponents.iter().position(|id| *id == char_id)? {
+            0 => Some(OlympiadSide::One),
+            _ => Some(OlympiadSide::Two),
+        }
+    }
+
+    pub fn opponent(&self, char_id: ObjectId) -> Option<ObjectId> {
+        self.side(char_id)?;
+        self.opponents.into_iter().find(|id| *id != char_id)
+    }
+
+    /// Counts down a second, returns the next stage once the current one is over.
+    /// Nothing follows the return, the game is over then.
+    pub fn tick(&mut self) -> Option<GameStage> {
+        self.remaining = self.remaining.saturating_sub(1);
+        if self.remaining > 0 {
+            return None;
+        }
+        let next = match self.stage {
+            GameStage::Teleport => GameStage::Preparation,
+            GameStage::Preparation => GameStage::Fight,
+            GameStage::Fight | GameStage::Return => GameStage::Return,
+        };
+        self.start(next);
+        Some(next)
+    }
+
+    /// Ends the fight before its time runs out.
+    pub fn finish(&mut self) {
+        self.start(GameStage::Return);
+    }
+
+    pub fn announce(&self) -> bool {
+        self.stage != GameStage::Fight && COUNTDOWN_ANNOUNCEMENTS.contains(&self.remaining)
+    }
+
+    fn start(&mut self, stage: GameStage) {
+        self.stage = stage;
+        self.remaining = stage.duration();
+    }
+}
+
+/// Matches being fought in the stadiums.
+#[derive(Clone, Debug, Default, Deref, DerefMut, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct OlympiadGames(HashMap<Entity, OlympiadGame>);
+
+impl OlympiadGames {
+    pub fn of_character(&self, char_id: ObjectId) -> Option<(Entity, &OlympiadGame)> {
+        self.0
+            .iter()
+            .find(|(_, game)| game.side(char_id).is_some())
+            .map(|(stadium, game)| (*stadium, game))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 7, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_next_month_start() {
+        assert_eq!(next_month_start(at(15, 12)), at(1, 0) + TimeDelta::days(31));
+        let december = NaiveDate::from_ymd_opt(2024, 12, 31)
+            .unwrap()
+            .and_hms_opt(23, 0, 0)
+            .unwrap();
+        assert_eq!(
+            next_month_start(december),
+            NaiveDate::from_ymd_opt(2025, 1, 1)
+                .unwrap()
+                .and_time(NaiveTime::MIN)
+        );
+    }
+
+    #[test]
+    fn test_points_transfer() {
+        assert_eq!(points_transfer(10, 10), 2);
+        assert_eq!(points_transfer(100, 3), 1);
+        assert_eq!(points_transfer(200, 150), MAX_POINTS_TRANSFER);
+    }
+
+    #[test]
+    fn test_matchmaking_and_heroes() {
+        let mut olympiad = Olympiad::new(model::Model::new(at(1, 0)), [], []);
+        let ids = (1..=CLASSED_MIN_PARTICIPANTS as u32)
+            .map(ObjectId::from)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            olympiad
+                .register(
+                    ids[0],
+                    ClassId::Duelist,
+                    CompetitionType::Classed,
+                    at(1, 12)
+                )
+                .err(),
+            Some(OlympiadError::NotInCompetition)
+        );
+        for char_id in ids.iter() {
+            assert!(
+                olympiad
+                    .register(
+                        *char_id,
+                        ClassId::Duelist,
+                        CompetitionType::Classed,
+                        at(1, 19)
+                    )
+                    .is_ok()
+            );
+        }
+        assert_eq!(
+            olympiad
+                .register(
+                    ids[0],
+                    ClassId::Duelist,
+                    CompetitionType::NonClassed,
+                    at(1, 19)
+                )
+                .err(),
+            Some(OlympiadError::AlreadyRegistered(CompetitionType::Classed))
+        );
+
+        let (kind, pair) = olympiad.next_pair().unwrap();
+        assert_eq!(kind, CompetitionType::Classed);
+        assert_eq!(pair, [ids[0], ids[1]]);
+        assert!(olympiad.next_pair().is_none());
+        assert_eq!(olympiad.unregister(ids[2]), Ok(()));
+        assert_eq!(
+            olympiad.unregister(ids[2]),
+            Err(OlympiadError::NotRegistered)
+        );
+
+        for _ in 0..HERO_MIN_MATCHES {
+            olympiad.finish_match(pair, Some(pair[0]));
+        }
+        assert_eq!(olympiad.finish_match([ids[3], ids[4]], None), 0);
+        assert!(olympiad.noble(pair[0]).unwrap().points > START_POINTS);
+        assert_eq!(
+            olympiad.noble(pair[1]).unwrap().competitions_lost,
+            HERO_MIN_MATCHES
+        );
+
+        assert!(olympiad.month_over(at(31, 23) + TimeDelta::hours(1)));
+        let heroes = olympiad.end_month(at(31, 23) + TimeDelta::hours(1));
+        assert_eq!(heroes.len(), 1);
+        assert!(olympiad.is_hero(pair[0]));
+        assert!(!olympiad.is_hero(pair[1]));
+        assert_eq!(olympiad.noble(pair[0]).unwrap().points, START_POINTS);
+        assert_eq!(olympiad.state().cycle, 2);
+    }
+
+    #[test]
+    fn test_game_stages() {
+        let mut game = OlympiadGame::new(
+            CompetitionType::NonClassed,
+            [ObjectId::from(1u32), ObjectId::from(2u32)],
+        );
+        assert_eq!(game.side(ObjectId::from(2u32)), Some(OlympiadSide::Two));
+        assert_eq!(
+            game.opponent(ObjectId::from(1u32)),
+            Some(ObjectId::from(2u32))
+        );
+
+        for _ in 1..TELEPORT_SECONDS {
+            assert_eq!(game.tick(), None);
+        }
+        assert_eq!(game.tick(), Some(GameStage::Preparation));
+        assert!(game.announce());
+        game.tick();
+        assert_eq!(game.stage(), GameStage::Preparation);
+
+        game.finish();
+        assert_eq!(game.stage(), GameStage::Return);
+        assert_eq!(game.remaining(), RETURN_SECONDS);
+    }
+}
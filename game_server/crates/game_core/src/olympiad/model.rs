@@ -0,0 +1,59 @@
+use super::next_month_start;
+use crate::utils::ReflectableDateTime;
+use bevy::prelude::*;
+use chrono::{NaiveDateTime, TimeDelta};
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::{
+    self as sea_orm, ActiveModelBehavior, DeriveEntityModel, DeriveRelation, EnumIter,
+    entity::prelude::*,
+};
+
+pub type OlympiadRepository = DbRepository<i32, Entity>;
+
+/// Olympiad state is kept in the single row with this id.
+pub const STATE_ID: i32 = 1;
+
+/// Current month of the Grand Olympiad with the time the nobles get their weekly points.
+#[derive(Clone, Debug, Default, DeriveEntityModel, Eq, PartialEq, Reflect)]
+#[sea_orm(table_name = "olympiad")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: i32,
+    pub cycle: i32,
+    pub month_end: ReflectableDateTime,
+    pub weekly_points: ReflectableDateTime,
+}
+
+impl Model {
+    pub fn new(now: NaiveDateTime) -> Self {
+        Self {
+            id: STATE_ID,
+            cycle: 1,
+            month_end: next_month_start(now).into(),
+            weekly_points: (now + TimeDelta::weeks(1)).into(),
+        }
+    }
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::Id]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::Cycle, Column::MonthEnd, Column::WeeklyPoints]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
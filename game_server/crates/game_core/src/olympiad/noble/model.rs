@@ -0,0 +1,76 @@
+use crate::{character, object_id::ObjectId, olympiad::START_POINTS, stats::ClassId};
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::entity::prelude::*;
+
+pub type OlympiadNoblesRepository = DbRepository<ObjectId, Entity>;
+
+/// Noble who fought in the Grand Olympiad, with the points and matches of the current month.
+#[derive(Clone, Copy, Debug, DeriveEntityModel, Eq, PartialEq, Reflect)]
+#[sea_orm(table_name = "olympiad_nobles")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub char_id: ObjectId,
+    pub class_id: ClassId,
+    pub points: i32,
+    pub competitions_done: i32,
+    pub competitions_won: i32,
+    pub competitions_lost: i32,
+    pub competitions_drawn: i32,
+}
+
+impl Model {
+    pub fn new(char_id: ObjectId, class_id: ClassId) -> Self {
+        Self {
+            char_id,
+            class_id,
+            points: START_POINTS,
+            competitions_done: 0,
+            competitions_won: 0,
+            competitions_lost: 0,
+            competitions_drawn: 0,
+        }
+    }
+
+    /// Starts the new month over with the start points.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.char_id, self.class_id);
+    }
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CharId]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[
+            Column::ClassId,
+            Column::Points,
+            Column::CompetitionsDone,
+            Column::CompetitionsWon,
+            Column::CompetitionsLost,
+            Column::CompetitionsDrawn,
+        ]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::CharId",
+        to = "character::model::Column::Id"
+    )]
+    Character,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,145 @@
+use crate::{npc, skills, stats::Level, utils::ReflectableDateTime};
+use bevy::{platform::collections::HashMap, prelude::*};
+use chrono::NaiveDateTime;
+use std::time::Duration;
+
+pub mod model;
+
+pub struct RaidBossComponentsPlugin;
+impl Plugin for RaidBossComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<RaidBosses>()
+            .register_type::<model::Model>();
+    }
+}
+
+/// Attackers this many levels above the boss are cursed instead of hurting it.
+pub const RAID_CURSE_LEVEL_GAP: u32 = 8;
+
+/// Petrifies the over-leveled attacker of the raid or grand boss.
+pub const RAID_CURSE: skills::Id = skills::Id::new(4515);
+
+pub const RAID_CURSE_DURATION: Duration = Duration::from_secs(30);
+
+pub fn raid_curse(attacker: Level, boss: Level) -> bool {
+    *attacker > *boss + RAID_CURSE_LEVEL_GAP
+}
+
+/// Raid and grand bosses known to the server, inserted once loaded from the database.
+/// Bosses which never spawned yet are added with their first spawn.
+#[derive(Clone, Debug, Default, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct RaidBosses(HashMap<npc::Id, model::Model>);
+
+impl RaidBosses {
+    pub fn new(models: impl IntoIterator<Item = model::Model>) -> Self {
+        Self(
+            models
+                .into_iter()
+                .map(|model| (npc::Id::from(model.npc_id as u32), model))
+                .collect(),
+        )
+    }
+
+    pub fn get(&self, npc_id: npc::Id) -> Option<&model::Model> {
+        self.0.get(&npc_id)
+    }
+
+    /// Time left till the dead boss may respawn, none once it is alive or the time is up.
+    pub fn respawn_delay(&self, npc_id: npc::Id, now: NaiveDateTime) -> Option<Duration> {
+        let respawn_at = *self.0.get(&npc_id)?.respawn_at?.as_ref();
+        (respawn_at - now)
+            .to_std()
+            .ok()
+            .filter(|delay| !delay.is_zero())
+    }
+
+    /// Health and mana the boss had when it was last seen alive, a respawned boss has full ones.
+    pub fn spawned(&self, npc_id: npc::Id) -> Option<(f64, f64)> {
+        self.0
+            .get(&npc_id)
+            .filter(|boss| boss.respawn_at.is_none())
+            .map(|boss| (boss.hp, boss.mp))
+    }
+
+    /// Keeps the current health and mana of the living boss, returns the model to be saved
+    /// if anything has changed.
+    pub fn update_vitals(&mut self, npc_id: npc::Id, hp: f64, mp: f64) -> Option<model::Model> {
+        let model = model::Model {
+            npc_id: *npc_id as i32,
+            respawn_at: None,
+            hp,
+            mp,
+        };
+        if self.0.get(&npc_id) == Some(&model) {
+            return None;
+        }
+        self.0.insert(npc_id, model);
+        Some(model)
+    }
+
+    pub fn died(&mut self, npc_id: npc::Id, respawn_at: NaiveDateTime) -> model::Model {
+        let model = model::Model {
+            npc_id: *npc_id as i32,
+            respawn_at: Some(ReflectableDateTime::new(respawn_at)),
+            hp: 0.0,
+            mp: 0.0,
+        };
+        self.0.insert(npc_id, model);
+        model
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeDelta};
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 5, 10)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_respawn_delay() {
+        let npc_id = npc::Id::from(25001u32);
+        let mut bosses = RaidBosses::default();
+        assert_eq!(bosses.respawn_delay(npc_id, now()), None);
+
+        let model = bosses.died(npc_id, now() + TimeDelta::hours(12));
+        assert_eq!(bosses.spawned(npc_id), None);
+
+        let bosses = RaidBosses::new([model]);
+        assert_eq!(
+            bosses.respawn_delay(npc_id, now() + TimeDelta::hours(2)),
+            Some(Duration::from_secs(10 * 3600))
+        );
+        assert_eq!(
+            bosses.respawn_delay(npc_id, now() + TimeDelta::hours(12)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_vitals_kept() {
+        let npc_id = npc::Id::from(25001u32);
+        let mut bosses = RaidBosses::default();
+
+        assert!(bosses.update_vitals(npc_id, 1000.0, 500.0).is_some());
+        assert!(bosses.update_vitals(npc_id, 1000.0, 500.0).is_none());
+        assert!(bosses.update_vitals(npc_id, 800.0, 500.0).is_some());
+        assert_eq!(bosses.spawned(npc_id), Some((800.0, 500.0)));
+
+        bosses.died(npc_id, now());
+        let model = bosses.update_vitals(npc_id, 1000.0, 500.0).unwrap();
+        assert_eq!(model.respawn_at, None);
+    }
+
+    #[test]
+    fn test_raid_curse() {
+        assert!(!raid_curse(Level::from(48u32), Level::from(40u32)));
+        assert!(raid_curse(Level::from(49u32), Level::from(40u32)));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::utils::ReflectableDateTime;
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::entity::prelude::*;
+
+pub type RaidBossesRepository = DbRepository<i32, Entity>;
+
+/// State of the raid or grand boss, kept while its region is not loaded and between restarts.
+#[derive(Clone, Copy, Debug, DeriveEntityModel, PartialEq, Reflect)]
+#[sea_orm(table_name = "raid_bosses")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub npc_id: i32,
+    /// Set while the boss is dead.
+    pub respawn_at: Option<ReflectableDateTime>,
+    pub hp: f64,
+    pub mp: f64,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::NpcId]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::RespawnAt, Column::Hp, Column::Mp]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
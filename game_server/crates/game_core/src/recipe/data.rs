@@ -0,0 +1,78 @@
+use crate::items;
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_common_assets::json::JsonAssetPlugin;
+use derive_more::From;
+use serde::{Deserialize, Serialize};
+
+pub struct RecipesDataComponentsPlugin;
+impl Plugin for RecipesDataComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(JsonAssetPlugin::<Recipes>::new(&["json"]));
+
+        app.register_type::<RecipesHandle>()
+            .register_type::<RecipeInfo>()
+            .register_type::<RecipeItem>()
+            .register_type::<RecipeBookKind>();
+    }
+}
+
+#[derive(Default, Deref, DerefMut, Reflect, Resource)]
+pub struct RecipesHandle(Handle<Recipes>);
+
+#[derive(Asset, Clone, Debug, Deref, Deserialize, From, PartialEq, Reflect, Serialize)]
+pub struct Recipes(HashMap<super::Id, RecipeInfo>);
+impl Recipes {
+    /// Recipe which is learned by using the given recipe item.
+    pub fn by_item(&self, item_id: items::Id) -> Option<(super::Id, &RecipeInfo)> {
+        self.0
+            .iter()
+            .find(|(_, recipe)| recipe.item_id == item_id)
+            .map(|(id, recipe)| (*id, recipe))
+    }
+}
+
+/// Dwarven recipes require Create Item skill, common ones - Create Common Item.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Reflect, Serialize)]
+pub enum RecipeBookKind {
+    #[default]
+    Dwarven,
+    Common,
+}
+
+impl From<RecipeBookKind> for u32 {
+    fn from(value: RecipeBookKind) -> Self {
+        match value {
+            RecipeBookKind::Dwarven => 0,
+            RecipeBookKind::Common => 1,
+        }
+    }
+}
+
+impl From<u32> for RecipeBookKind {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => RecipeBookKind::Dwarven,
+            _ => RecipeBookKind::Common,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct RecipeInfo {
+    pub name: String,
+    /// Recipe item, which registers this recipe in the book when used.
+    pub item_id: items::Id,
+    pub kind: RecipeBookKind,
+    /// Required level of the Create Item skill.
+    pub level: u32,
+    pub success_rate: u32,
+    pub mp_cost: u32,
+    pub materials: Vec<RecipeItem>,
+    pub product: RecipeItem,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Reflect, Serialize)]
+pub struct RecipeItem {
+    pub id: items::Id,
+    pub count: u64,
+}
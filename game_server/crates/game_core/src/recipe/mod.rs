@@ -0,0 +1,217 @@
+use crate::{
+    items,
+    object_id::ObjectId,
+    skills::{self, SkillList},
+};
+use bevy::prelude::*;
+use thiserror::Error;
+
+mod data;
+mod id;
+pub mod model;
+
+pub use data::*;
+pub use id::*;
+
+pub struct RecipeComponentsPlugin;
+impl Plugin for RecipeComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RecipesDataComponentsPlugin);
+
+        app.register_type::<Id>()
+            .register_type::<RecipeBook>()
+            .register_type::<RecipeShop>()
+            .register_type::<RecipeShopEntry>()
+            .register_type::<model::Model>();
+    }
+}
+
+pub const DWARVEN_CRAFT_SKILL_ID: u32 = 172;
+pub const COMMON_CRAFT_SKILL_ID: u32 = 1320;
+pub const DWARVEN_RECIPE_LIMIT: usize = 50;
+pub const COMMON_RECIPE_LIMIT: usize = 50;
+pub const MAX_SHOP_MESSAGE_LENGTH: usize = 29;
+/// Private store type sent in UserInfo / CharInfo for an opened manufacture shop.
+pub const MANUFACTURE_STORE_TYPE: u8 = 5;
+
+/// Character used a recipe item, it should be registered in the recipe book.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct UseRecipe {
+    pub item_object_id: ObjectId,
+    pub item_id: items::Id,
+}
+
+/// Character clicked on another character with an opened manufacture shop.
+#[derive(Clone, Copy, Debug, Deref, Event)]
+pub struct RecipeShopVisit(pub Entity);
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum RecipeBookError {
+    #[error("Recipe is already registered")]
+    AlreadyRegistered,
+    #[error("Recipe book is full")]
+    BookIsFull,
+}
+
+/// Recipes registered by the character, in order of registration.
+#[derive(Clone, Component, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct RecipeBook {
+    dwarven: Vec<Id>,
+    common: Vec<Id>,
+}
+
+impl RecipeBook {
+    pub fn limit(kind: RecipeBookKind) -> usize {
+        match kind {
+            RecipeBookKind::Dwarven => DWARVEN_RECIPE_LIMIT,
+            RecipeBookKind::Common => COMMON_RECIPE_LIMIT,
+        }
+    }
+
+    pub fn recipes(&self, kind: RecipeBookKind) -> &[Id] {
+        match kind {
+            RecipeBookKind::Dwarven => &self.dwarven,
+            RecipeBookKind::Common => &self.common,
+        }
+    }
+
+    fn recipes_mut(&mut self, kind: RecipeBookKind) -> &mut Vec<Id> {
+        match kind {
+            RecipeBookKind::Dwarven => &mut self.dwarven,
+            RecipeBookKind::Common => &mut self.common,
+        }
+    }
+
+    pub fn contains(&self, id: Id) -> bool {
+        self.dwarven.contains(&id) || self.common.contains(&id)
+    }
+
+    pub fn register(&mut self, kind: RecipeBookKind, id: Id) -> Result<(), RecipeBookError> {
+        if self.contains(id) {
+            return Err(RecipeBookError::AlreadyRegistered);
+        }
+        let recipes = self.recipes_mut(kind);
+        if recipes.len() >= Self::limit(kind) {
+            return Err(RecipeBookError::BookIsFull);
+        }
+        recipes.push(id);
+        Ok(())
+    }
+
+    /// Returns the kind of the book the recipe was removed from.
+    pub fn remove(&mut self, id: Id) -> Option<RecipeBookKind> {
+        for kind in [RecipeBookKind::Dwarven, RecipeBookKind::Common] {
+            let recipes = self.recipes_mut(kind);
+            if let Some(index) = recipes.iter().position(|recipe| *recipe == id) {
+                recipes.remove(index);
+                return Some(kind);
+            }
+        }
+        None
+    }
+
+    /// Level of the Create Item skill, which limits the recipes the character can use.
+    pub fn craft_level(kind: RecipeBookKind, skills: &SkillList) -> u32 {
+        let skill_id = match kind {
+            RecipeBookKind::Dwarven => DWARVEN_CRAFT_SKILL_ID,
+            RecipeBookKind::Common => COMMON_CRAFT_SKILL_ID,
+        };
+        skills
+            .get(&skills::Id::from(skill_id))
+            .map(|skill| *skill.level())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct RecipeShopEntry {
+    pub id: Id,
+    pub fee: u64,
+}
+
+/// Manufacture shop of the character, it's opened while the character sits with posted recipes.
+#[derive(Clone, Component, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct RecipeShop {
+    pub kind: RecipeBookKind,
+    pub message: String,
+    pub entries: Vec<RecipeShopEntry>,
+    pub opened: bool,
+}
+
+impl RecipeShop {
+    pub fn fee(&self, id: Id) -> Option<u64> {
+        self.entries
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.fee)
+    }
+
+    pub fn store_type(&self) -> u8 {
+        if self.opened {
+            MANUFACTURE_STORE_TYPE
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_rejects_duplicates() {
+        let mut book = RecipeBook::default();
+        assert_eq!(
+            book.register(RecipeBookKind::Dwarven, Id::from(1u32)),
+            Ok(())
+        );
+        assert_eq!(
+            book.register(RecipeBookKind::Common, Id::from(1u32)),
+            Err(RecipeBookError::AlreadyRegistered)
+        );
+        assert_eq!(book.recipes(RecipeBookKind::Dwarven), &[Id::from(1u32)]);
+        assert!(book.recipes(RecipeBookKind::Common).is_empty());
+    }
+
+    #[test]
+    fn test_register_respects_limit() {
+        let mut book = RecipeBook::default();
+        for id in 0..COMMON_RECIPE_LIMIT as u32 {
+            assert_eq!(book.register(RecipeBookKind::Common, Id::from(id)), Ok(()));
+        }
+        assert_eq!(
+            book.register(RecipeBookKind::Common, Id::from(1000u32)),
+            Err(RecipeBookError::BookIsFull)
+        );
+        assert_eq!(
+            book.register(RecipeBookKind::Dwarven, Id::from(1000u32)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_remove_returns_book_kind() {
+        let mut book = RecipeBook::default();
+        book.register(RecipeBookKind::Common, Id::from(7u32))
+            .unwrap();
+        assert_eq!(book.remove(Id::from(7u32)), Some(RecipeBookKind::Common));
+        assert_eq!(book.remove(Id::from(7u32)), None);
+    }
+
+    #[test]
+    fn test_shop_fee() {
+        let shop = RecipeShop {
+            entries: vec![RecipeShopEntry {
+                id: Id::from(3u32),
+                fee: 100,
+            }],
+            ..default()
+        };
+        assert_eq!(shop.fee(Id::from(3u32)), Some(100));
+        assert_eq!(shop.fee(Id::from(4u32)), None);
+        assert_eq!(shop.store_type(), 0);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{character, object_id::ObjectId};
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::{Condition, entity::prelude::*, sea_query::SimpleExpr};
+
+pub type CharacterRecipesRepository = DbRepository<RecipePK, Entity>;
+
+#[derive(Clone, Copy, Debug, DeriveEntityModel, PartialEq, Reflect)]
+#[sea_orm(table_name = "character_recipes")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub char_id: ObjectId,
+    #[sea_orm(primary_key)]
+    pub recipe_id: super::Id,
+    pub dwarven: bool,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CharId, Column::RecipeId]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::Dwarven]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::CharId",
+        to = "character::model::Column::Id"
+    )]
+    Character,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecipePK {
+    pub char_id: ObjectId,
+    pub recipe_id: super::Id,
+}
+
+impl From<&Model> for RecipePK {
+    fn from(model: &Model) -> Self {
+        RecipePK {
+            char_id: model.char_id,
+            recipe_id: model.recipe_id,
+        }
+    }
+}
+
+impl From<RecipePK> for Condition {
+    fn from(pk: RecipePK) -> Self {
+        Condition::all()
+            .add(Column::CharId.eq(pk.char_id))
+            .add(Column::RecipeId.eq(pk.recipe_id))
+    }
+}
+
+impl From<RecipePK> for SimpleExpr {
+    fn from(value: RecipePK) -> Self {
+        Column::CharId
+            .eq(value.char_id)
+            .and(Column::RecipeId.eq(value.recipe_id))
+    }
+}
+
+impl From<RecipePK> for (ObjectId, super::Id) {
+    fn from(pk: RecipePK) -> Self {
+        (pk.char_id, pk.recipe_id)
+    }
+}
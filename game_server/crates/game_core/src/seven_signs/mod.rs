@@ -0,0 +1,618 @@
+use crate::{items, object_id::ObjectId};
+use bevy::{platform::collections::HashMap, prelude::*};
+use chrono::{Datelike, NaiveDateTime, NaiveTime, TimeDelta, Weekday};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sea_orm::{
+    TryGetError, TryGetable, Value,
+    sea_query::{ArrayType, ColumnType, ValueType, ValueTypeErr},
+};
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
+use thiserror::Error;
+
+pub mod model;
+pub mod participant;
+
+pub use participant::Participant;
+
+pub struct SevenSignsComponentsPlugin;
+impl Plugin for SevenSignsComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Cabal>()
+            .register_type::<Seal>()
+            .register_type::<SsqPeriod>()
+            .register_type::<SevenSigns>()
+            .register_type::<model::Model>()
+            .register_type::<participant::model::Model>();
+    }
+}
+
+/// Periods change every monday at this hour.
+pub const PERIOD_CHANGE_WEEKDAY: Weekday = Weekday::Mon;
+pub const PERIOD_CHANGE_HOUR: u32 = 18;
+/// Side keeps the seal it owned with at least this share of its members voting for it.
+pub const SEAL_RETAIN_PERCENT: u8 = 10;
+/// Winning side claims the seal with at least this share of its members voting for it.
+pub const SEAL_CLAIM_PERCENT: u8 = 35;
+/// Seal stone scores of both sides are scaled to this total.
+pub const MAX_STONE_SCORE: u64 = 500;
+pub const ANCIENT_ADENA_ID: items::Id = items::Id::new(5575);
+/// Lets those who do not own a castle join the Lords of Dawn instead of paying the fee.
+pub const CERTIFICATE_OF_APPROVAL_ID: items::Id = items::Id::new(6388);
+pub const DAWN_JOIN_FEE: u64 = 50_000;
+
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum SevenSignsError {
+    #[error("This may only be done during the competition period")]
+    NotCompetition,
+    #[error("This may only be done during the seal validation period")]
+    NotValidation,
+    #[error("Side must be either the Lords of Dawn or the Revolutionaries of Dusk")]
+    InvalidCabal,
+    #[error("Character already participates in the Seven Signs")]
+    AlreadyJoined,
+    #[error("Character does not participate in the Seven Signs")]
+    NotJoined,
+    #[error("Castle owners may only join the Lords of Dawn")]
+    CastleOwnerDusk,
+    #[error("No seal stones to contribute")]
+    NothingToContribute,
+    #[error("Only the winning side is rewarded")]
+    NotWinner,
+    #[error("No ancient adena to collect")]
+    NothingToCollect,
+}
+
+/// Side of the Seven Signs competition, values match the client ones.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    EnumString,
+    Eq,
+    Hash,
+    IntoPrimitive,
+    PartialEq,
+    Reflect,
+    Serialize,
+    TryFromPrimitive,
+)]
+#[strum(serialize_all = "snake_case")]
+#[repr(i16)]
+pub enum Cabal {
+    #[default]
+    None = 0,
+    Dusk = 1,
+    Dawn = 2,
+}
+
+impl Cabal {
+    pub fn opponent(self) -> Self {
+        match self {
+            Cabal::None => Cabal::None,
+            Cabal::Dusk => Cabal::Dawn,
+            Cabal::Dawn => Cabal::Dusk,
+        }
+    }
+}
+
+impl From<Cabal> for Value {
+    fn from(cabal: Cabal) -> Self {
+        Value::SmallInt(Some(cabal.into()))
+    }
+}
+
+impl TryGetable for Cabal {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &sea_orm::QueryResult,
+        idx: I,
+    ) -> Result<Self, TryGetError> {
+        let value: i16 = res.try_get_by(idx)?;
+        Cabal::try_from_primitive(value).map_err(|_| {
+            TryGetError::DbErr(sea_orm::DbErr::Type(format!(
+                "Failed to convert {value} to Cabal"
+            )))
+        })
+    }
+}
+
+impl ValueType for Cabal {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        if let Value::SmallInt(Some(val)) = v {
+            Cabal::try_from_primitive(val).map_err(|_| ValueTypeErr)
+        } else {
+            Err(ValueTypeErr)
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(Cabal).to_string()
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::SmallInteger
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::SmallInt
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    EnumIter,
+    EnumString,
+    Eq,
+    Hash,
+    IntoPrimitive,
+    PartialEq,
+    Reflect,
+    Serialize,
+    TryFromPrimitive,
+)]
+#[strum(serialize_all = "snake_case")]
+#[repr(i16)]
+pub enum Seal {
+    /// Opens the necropolises to its owners.
+    Avarice = 1,
+    /// Opens the catacombs to its owners.
+    Gnosis = 2,
+    Strife = 3,
+}
+
+impl From<Seal> for Value {
+    fn from(seal: Seal) -> Self {
+        Value::SmallInt(Some(seal.into()))
+    }
+}
+
+impl TryGetable for Seal {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &sea_orm::QueryResult,
+        idx: I,
+    ) -> Result<Self, TryGetError> {
+        let value: i16 = res.try_get_by(idx)?;
+        Seal::try_from_primitive(value).map_err(|_| {
+            TryGetError::DbErr(sea_orm::DbErr::Type(format!(
+                "Failed to convert {value} to Seal"
+            )))
+        })
+    }
+}
+
+impl ValueType for Seal {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        if let Value::SmallInt(Some(val)) = v {
+            Seal::try_from_primitive(val).map_err(|_| ValueTypeErr)
+        } else {
+            Err(ValueTypeErr)
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(Seal).to_string()
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::SmallInteger
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::SmallInt
+    }
+}
+
+/// Weekly period of the Seven Signs, values match the client ones.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Eq,
+    Hash,
+    IntoPrimitive,
+    PartialEq,
+    Reflect,
+    Serialize,
+    TryFromPrimitive,
+)]
+#[repr(i16)]
+pub enum SsqPeriod {
+    /// Participants join the sides and contribute seal stones.
+    #[default]
+    Competition = 1,
+    /// Winners own the seals and enjoy their effects.
+    SealValidation = 3,
+}
+
+impl From<SsqPeriod> for Value {
+    fn from(period: SsqPeriod) -> Self {
+        Value::SmallInt(Some(period.into()))
+    }
+}
+
+impl TryGetable for SsqPeriod {
+    fn try_get_by<I: sea_orm::ColIdx>(
+        res: &sea_orm::QueryResult,
+        idx: I,
+    ) -> Result<Self, TryGetError> {
+        let value: i16 = res.try_get_by(idx)?;
+        SsqPeriod::try_from_primitive(value).map_err(|_| {
+            TryGetError::DbErr(sea_orm::DbErr::Type(format!(
+                "Failed to convert {value} to SsqPeriod"
+            )))
+        })
+    }
+}
+
+impl ValueType for SsqPeriod {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        if let Value::SmallInt(Some(val)) = v {
+            SsqPeriod::try_from_primitive(val).map_err(|_| ValueTypeErr)
+        } else {
+            Err(ValueTypeErr)
+        }
+    }
+
+    fn type_name() -> String {
+        stringify!(SsqPeriod).to_string()
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::SmallInteger
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::SmallInt
+    }
+}
+
+/// Dungeons guarded by the Ziggurat gatekeepers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Reflect)]
+pub enum SsqDungeon {
+    Necropolis,
+    Catacomb,
+}
+
+impl SsqDungeon {
+    /// Seal opening the dungeon during the seal validation period.
+    pub fn seal(self) -> Seal {
+        match self {
+            SsqDungeon::Necropolis => Seal::Avarice,
+            SsqDungeon::Catacomb => Seal::Gnosis,
+        }
+    }
+}
+
+/// First period change after the given time.
+pub fn next_period_change(after: NaiveDateTime) -> NaiveDateTime {
+    let date = after.date();
+    let days = (7 + PERIOD_CHANGE_WEEKDAY.num_days_from_monday()
+        - date.weekday().num_days_from_monday())
+        % 7;
+    let date = date + TimeDelta::days(days.into());
+    let change = date
+        .and_hms_opt(PERIOD_CHANGE_HOUR, 0, 0)
+        .unwrap_or_else(|| date.and_time(NaiveTime::MIN));
+    if change > after {
+        change
+    } else {
+        change + TimeDelta::weeks(1)
+    }
+}
+
+/// State of the Seven Signs with all the participants of the current cycle,
+/// inserted once loaded from the database.
+#[derive(Clone, Debug, Default, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct SevenSigns {
+    state: model::Model,
+    participants: HashMap<ObjectId, Participant>,
+}
+
+impl SevenSigns {
+    pub fn new(state: model::Model, participants: impl IntoIterator<Item = Participant>) -> Self {
+        Self {
+            state,
+            participants: participants
+                .into_iter()
+                .map(|participant| (participant.char_id, participant))
+                .collect(),
+        }
+    }
+
+    pub fn state(&self) -> &model::Model {
+        &self.state
+    }
+
+    pub fn period(&self) -> SsqPeriod {
+        self.state.period
+    }
+
+    pub fn participant(&self, char_id: ObjectId) -> Option<&Participant> {
+        self.participants.get(&char_id)
+    }
+
+    pub fn seal_owner(&self, seal: Seal) -> Cabal {
+        self.state.seal_owner(seal)
+    }
+
+    pub fn members(&self, cabal: Cabal) -> usize {
+        self.participants
+            .values()
+            .filter(|participant| participant.cabal == cabal)
+            .count()
+    }
+
+    /// Share of the side members fighting for the seal.
+    pub fn seal_percent(&self, seal: Seal, cabal: Cabal) -> u8 {
+        let members = self.members(cabal);
+        if members == 0 {
+            return 0;
+        }
+        let votes = self
+            .participants
+            .values()
+            .filter(|participant| participant.cabal == cabal && participant.seal == seal)
+            .count();
+        (votes * 100 / members) as u8
+    }
+
+    /// Points of the seal stones contributed by the side.
+    pub fn stone_score(&self, cabal: Cabal) -> u64 {
+        self.participants
+            .values()
+            .filter(|participant| participant.cabal == cabal)
+            .map(Participant::contribution_score)
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Stone score of the side scaled to [`MAX_STONE_SCORE`] for both sides.
+    pub fn score(&self, cabal: Cabal) -> u64 {
+        let total = self
+            .stone_score(Cabal::Dawn)
+            .saturating_add(self.stone_score(Cabal::Dusk));
+        if total == 0 {
+            return 0;
+        }
+        self.stone_score(cabal).saturating_mul(MAX_STONE_SCORE) / total
+    }
+
+    pub fn score_percent(&self, cabal: Cabal) -> u8 {
+        let total = self.score(Cabal::Dawn) + self.score(Cabal::Dusk);
+        if total == 0 {
+            return 0;
+        }
+        (self.score(cabal) * 100 / total) as u8
+    }
+
+    /// Side winning the competition if it ended now, nobody wins a tie.
+    pub fn leading_cabal(&self) -> Cabal {
+        let dawn = self.score(Cabal::Dawn);
+        let dusk = self.score(Cabal::Dusk);
+        match dawn.cmp(&dusk) {
+            std::cmp::Ordering::Greater => Cabal::Dawn,
+            std::cmp::Ordering::Less => Cabal::Dusk,
+            std::cmp::Ordering::Equal => Cabal::None,
+        }
+    }
+
+    /// Owner of the seal after the competition won by the given side.
+    pub fn predicted_owner(&self, seal: Seal, winner: Cabal) -> Cabal {
+        let claims = |cabal: Cabal| {
+            cabal != Cabal::None && self.seal_percent(seal, cabal) >= SEAL_CLAIM_PERCENT
+        };
+        let retains = |cabal: Cabal| self.seal_percent(seal, cabal) >= SEAL_RETAIN_PERCENT;
+
+        match self.seal_owner(seal) {
+            Cabal::None if claims(winner) => winner,
+            Cabal::None => Cabal::None,
+            owner if owner == winner && retains(owner) => owner,
+            owner if owner == winner => Cabal::None,
+            _ if claims(winner) => winner,
+            owner if retains(owner) => owner,
+            _ => Cabal::None,
+        }
+    }
+
+    /// Sky turns to the side of the winner for the seal validation period.
+    pub fn sky(&self) -> Cabal {
+        match self.state.period {
+            SsqPeriod::Competition => Cabal::None,
+            SsqPeriod::SealValidation => self.state.winner,
+        }
+    }
+
+    /// Participants enter the dungeons during the competition,
+    /// only the owners of the seal opening the dungeon do during the validation.
+    pub fn can_enter(&self, char_id: ObjectId, dungeon: SsqDungeon) -> bool {
+        let Some(participant) = self.participant(char_id) else {
+            return false;
+        };
+        match self.state.period {
+            SsqPeriod::Competition => true,
+            SsqPeriod::SealValidation => participant.cabal == self.seal_owner(dungeon.seal()),
+        }
+    }
+
+    pub fn join(
+        &mut self,
+        char_id: ObjectId,
+        cabal: Cabal,
+        seal: Seal,
+        castle_owner: bool,
+    ) -> Result<&Participant, SevenSignsError> {
+        if self.state.period != SsqPeriod::Competition {
+            return Err(SevenSignsError::NotCompetition);
+        }
+        if cabal == Cabal::None {
+            return Err(SevenSignsError::InvalidCabal);
+        }
+        if self.participants.contains_key(&char_id) {
+            return Err(SevenSignsError::AlreadyJoined);
+        }
+        if castle_owner && cabal == Cabal::Dusk {
+            return Err(SevenSignsError::CastleOwnerDusk);
+        }
+        Ok(self
+            .participants
+            .entry(char_id)
+            .or_insert(Participant::new(char_id, cabal, seal)))
+    }
+
+    /// Puts the seal stones into the score of the participant side, returns the points added.
+    pub fn contribute(
+        &mut self,
+        char_id: ObjectId,
+        stones: [u64; 3],
+    ) -> Result<u64, SevenSignsError> {
+        if self.state.period != SsqPeriod::Competition {
+            return Err(SevenSignsError::NotCompetition);
+        }
+        let participant = self
+            .participants
+            .get_mut(&char_id)
+            .ok_or(SevenSignsError::NotJoined)?;
+        if stones.iter().all(|count| *count == 0) {
+            return Err(SevenSignsError::NothingToContribute);
+        }
+        Ok(participant.add_stones(stones))
+    }
+
+    /// Takes the ancient adena earned by the participant of the winning side.
+    pub fn collect_reward(&mut self, char_id: ObjectId) -> Result<u64, SevenSignsError> {
+        if self.state.period != SsqPeriod::SealValidation {
+            return Err(SevenSignsError::NotValidation);
+        }
+        let winner = self.state.winner;
+        let participant = self
+            .participants
+            .get_mut(&char_id)
+            .ok_or(SevenSignsError::NotJoined)?;
+        if participant.cabal != winner {
+            return Err(SevenSignsError::NotWinner);
+        }
+        let reward = participant.ancient_adena.max(0) as u64;
+        if reward == 0 {
+            return Err(SevenSignsError::NothingToCollect);
+        }
+        participant.ancient_adena = 0;
+        Ok(reward)
+    }
+
+    pub fn period_over(&self, now: NaiveDateTime) -> bool {
+        now >= *self.state.period_end
+    }
+
+    /// Competition ends with the seals given away, seal validation ends with a new cycle
+    /// where everybody has to join again.
+    pub fn next_period(&mut self, now: NaiveDateTime) -> SsqPeriod {
+        match self.state.period {
+            SsqPeriod::Competition => {
+                let winner = self.leading_cabal();
+                for seal in Seal::iter() {
+                    let owner = self.predicted_owner(seal, winner);
+                    self.state.set_seal_owner(seal, owner);
+                }
+                self.state.winner = winner;
+                self.state.period = SsqPeriod::SealValidation;
+            }
+            SsqPeriod::SealValidation => {
+                self.participants.clear();
+                self.state.cycle += 1;
+                self.state.winner = Cabal::None;
+                self.state.period = SsqPeriod::Competition;
+            }
+        }
+        self.state.period_end = next_period_change(now).into();
+        self.state.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        // 2024-07-01 is a monday
+        NaiveDate::from_ymd_opt(2024, 7, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_next_period_change() {
+        assert_eq!(next_period_change(at(1, 12)), at(1, 18));
+        assert_eq!(next_period_change(at(1, 18)), at(8, 18));
+        assert_eq!(next_period_change(at(4, 10)), at(8, 18));
+    }
+
+    #[test]
+    fn test_competition_cycle() {
+        let dawn = ObjectId::from(1u32);
+        let dusk = ObjectId::from(2u32);
+        let mut seven_signs = SevenSigns::new(model::Model::new(at(1, 18)), []);
+
+        assert_eq!(
+            seven_signs.join(dusk, Cabal::Dusk, Seal::Avarice, true),
+            Err(SevenSignsError::CastleOwnerDusk)
+        );
+        assert!(
+            seven_signs
+                .join(dawn, Cabal::Dawn, Seal::Gnosis, true)
+                .is_ok()
+        );
+        assert!(
+            seven_signs
+                .join(dusk, Cabal::Dusk, Seal::Avarice, false)
+                .is_ok()
+        );
+        assert_eq!(
+            seven_signs.join(dawn, Cabal::Dusk, Seal::Avarice, false),
+            Err(SevenSignsError::AlreadyJoined)
+        );
+        assert!(seven_signs.can_enter(dusk, SsqDungeon::Catacomb));
+
+        assert_eq!(seven_signs.contribute(dawn, [1, 1, 1]), Ok(18));
+        assert_eq!(seven_signs.contribute(dusk, [2, 0, 0]), Ok(6));
+        assert_eq!(seven_signs.leading_cabal(), Cabal::Dawn);
+        assert_eq!(seven_signs.score(Cabal::Dawn), 375);
+
+        assert_eq!(
+            seven_signs.next_period(at(8, 18)),
+            SsqPeriod::SealValidation
+        );
+        assert_eq!(seven_signs.sky(), Cabal::Dawn);
+        assert_eq!(seven_signs.seal_owner(Seal::Gnosis), Cabal::Dawn);
+        // Only the winner may claim a seal nobody owned
+        assert_eq!(seven_signs.seal_owner(Seal::Avarice), Cabal::None);
+        assert!(seven_signs.can_enter(dawn, SsqDungeon::Catacomb));
+        assert!(!seven_signs.can_enter(dusk, SsqDungeon::Catacomb));
+
+        assert_eq!(
+            seven_signs.collect_reward(dusk),
+            Err(SevenSignsError::NotWinner)
+        );
+        assert_eq!(seven_signs.collect_reward(dawn), Ok(18));
+        assert_eq!(
+            seven_signs.collect_reward(dawn),
+            Err(SevenSignsError::NothingToCollect)
+        );
+
+        assert_eq!(seven_signs.next_period(at(15, 18)), SsqPeriod::Competition);
+        assert_eq!(seven_signs.state().cycle, 2);
+        assert!(seven_signs.participant(dawn).is_none());
+        assert_eq!(seven_signs.sky(), Cabal::None);
+        assert_eq!(seven_signs.seal_owner(Seal::Gnosis), Cabal::Dawn);
+    }
+}
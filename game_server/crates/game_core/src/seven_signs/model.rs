@@ -0,0 +1,89 @@
+use super::{Cabal, Seal, SsqPeriod, next_period_change};
+use crate::utils::ReflectableDateTime;
+use bevy::prelude::*;
+use chrono::NaiveDateTime;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::{
+    self as sea_orm, ActiveModelBehavior, DeriveEntityModel, DeriveRelation, EnumIter,
+    entity::prelude::*,
+};
+
+pub type SevenSignsRepository = DbRepository<i32, Entity>;
+
+/// Seven Signs state is kept in the single row with this id.
+pub const STATE_ID: i32 = 1;
+
+/// Current cycle and period of the Seven Signs with the owners of the seals.
+#[derive(Clone, Debug, Default, DeriveEntityModel, Eq, PartialEq, Reflect)]
+#[sea_orm(table_name = "seven_signs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: i32,
+    pub cycle: i32,
+    pub period: SsqPeriod,
+    pub period_end: ReflectableDateTime,
+    pub winner: Cabal,
+    pub avarice_owner: Cabal,
+    pub gnosis_owner: Cabal,
+    pub strife_owner: Cabal,
+}
+
+impl Model {
+    /// First cycle starting with the competition, nobody owns the seals.
+    pub fn new(now: NaiveDateTime) -> Self {
+        Self {
+            id: STATE_ID,
+            cycle: 1,
+            period: SsqPeriod::Competition,
+            period_end: next_period_change(now).into(),
+            ..default()
+        }
+    }
+
+    pub fn seal_owner(&self, seal: Seal) -> Cabal {
+        match seal {
+            Seal::Avarice => self.avarice_owner,
+            Seal::Gnosis => self.gnosis_owner,
+            Seal::Strife => self.strife_owner,
+        }
+    }
+
+    pub fn set_seal_owner(&mut self, seal: Seal, cabal: Cabal) {
+        match seal {
+            Seal::Avarice => self.avarice_owner = cabal,
+            Seal::Gnosis => self.gnosis_owner = cabal,
+            Seal::Strife => self.strife_owner = cabal,
+        }
+    }
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::Id]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[
+            Column::Cycle,
+            Column::Period,
+            Column::PeriodEnd,
+            Column::Winner,
+            Column::AvariceOwner,
+            Column::GnosisOwner,
+            Column::StrifeOwner,
+        ]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
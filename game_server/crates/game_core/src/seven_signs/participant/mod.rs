@@ -0,0 +1,16 @@
+use crate::items;
+
+pub mod model;
+
+pub type Participant = model::Model;
+
+pub const BLUE_SEAL_STONE_ID: items::Id = items::Id::new(6360);
+pub const GREEN_SEAL_STONE_ID: items::Id = items::Id::new(6361);
+pub const RED_SEAL_STONE_ID: items::Id = items::Id::new(6362);
+
+/// Seal stones in the order of their counts kept by the participant, with the points of each.
+pub const SEAL_STONES: [(items::Id, u64); 3] = [
+    (BLUE_SEAL_STONE_ID, 3),
+    (GREEN_SEAL_STONE_ID, 5),
+    (RED_SEAL_STONE_ID, 10),
+];
@@ -0,0 +1,111 @@
+use super::SEAL_STONES;
+use crate::{
+    character,
+    object_id::ObjectId,
+    seven_signs::{Cabal, Seal},
+};
+use bevy::prelude::*;
+use l2r_core::db::{DbRepository, PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::entity::prelude::*;
+
+pub type SevenSignsParticipantsRepository = DbRepository<ObjectId, Entity>;
+
+/// Character who joined a side of the current Seven Signs cycle, with the seal stones contributed.
+#[derive(Clone, Copy, Debug, DeriveEntityModel, Eq, PartialEq, Reflect)]
+#[sea_orm(table_name = "seven_signs_participants")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub char_id: ObjectId,
+    pub cabal: Cabal,
+    pub seal: Seal,
+    pub blue_stones: i64,
+    pub green_stones: i64,
+    pub red_stones: i64,
+    /// Ancient adena to be collected if the side wins.
+    pub ancient_adena: i64,
+}
+
+impl Model {
+    pub fn new(char_id: ObjectId, cabal: Cabal, seal: Seal) -> Self {
+        Self {
+            char_id,
+            cabal,
+            seal,
+            blue_stones: 0,
+            green_stones: 0,
+            red_stones: 0,
+            ancient_adena: 0,
+        }
+    }
+
+    pub fn stones(&self) -> [u64; 3] {
+        [self.blue_stones, self.green_stones, self.red_stones].map(|count| count.max(0) as u64)
+    }
+
+    /// Points of all the seal stones contributed in this cycle.
+    pub fn contribution_score(&self) -> u64 {
+        score(self.stones())
+    }
+
+    /// Adds the seal stones, the points they are worth are also earned as ancient adena.
+    pub fn add_stones(&mut self, stones: [u64; 3]) -> u64 {
+        let counts = [
+            &mut self.blue_stones,
+            &mut self.green_stones,
+            &mut self.red_stones,
+        ];
+        for (count, added) in counts.into_iter().zip(stones) {
+            *count = count.saturating_add(i64::try_from(added).unwrap_or(i64::MAX));
+        }
+        let points = score(stones);
+        self.ancient_adena = self
+            .ancient_adena
+            .saturating_add(i64::try_from(points).unwrap_or(i64::MAX));
+        points
+    }
+}
+
+fn score(stones: [u64; 3]) -> u64 {
+    stones
+        .into_iter()
+        .zip(SEAL_STONES)
+        .map(|(count, (_, points))| count.saturating_mul(points))
+        .fold(0, u64::saturating_add)
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CharId]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[
+            Column::Cabal,
+            Column::Seal,
+            Column::BlueStones,
+            Column::GreenStones,
+            Column::RedStones,
+            Column::AncientAdena,
+        ]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::CharId",
+        to = "character::model::Column::Id"
+    )]
+    Character,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
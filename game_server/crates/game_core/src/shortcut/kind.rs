@@ -1,4 +1,4 @@
-use crate::{action::model::ActionId, object_id::ObjectId, skills};
+use crate::{action::model::ActionId, macros, object_id::ObjectId, skills};
 use bevy::prelude::*;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use sea_orm::{
@@ -25,7 +25,7 @@ pub enum ShortcutKind {
     Item(ObjectId),
     Skill(skills::Id, skills::Level),
     Action(ActionId),
-    Macro(u32),
+    Macro(macros::Id),
     Recipe(u32),
     Bookmark(u32),
 }
@@ -41,7 +41,7 @@ impl ShortcutKind {
             ShortcutKindVariant::Item => Ok(ShortcutKind::Item(ObjectId::from(value))),
             ShortcutKindVariant::Skill => Ok(ShortcutKind::Skill(skills::Id::from(value), level)),
             ShortcutKindVariant::Action => Ok(ShortcutKind::Action(ActionId::try_from(value)?)),
-            ShortcutKindVariant::Macro => Ok(ShortcutKind::Macro(value)),
+            ShortcutKindVariant::Macro => Ok(ShortcutKind::Macro(macros::Id::from(value))),
             ShortcutKindVariant::Recipe => Ok(ShortcutKind::Recipe(value)),
             ShortcutKindVariant::Bookmark => Ok(ShortcutKind::Bookmark(value)),
         }
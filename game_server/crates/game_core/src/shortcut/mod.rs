@@ -1,4 +1,7 @@
-use super::{network::packets::client::RequestShortcutRegistration, object_id::ObjectId};
+use super::{
+    network::packets::{client::RequestShortcutRegistration, server::ShortcutInit},
+    object_id::ObjectId,
+};
 use crate::stats::SubClassVariant;
 use bevy::prelude::*;
 use l2r_core::packets::ServerPacketBuffer;
@@ -17,6 +20,7 @@ impl Plugin for ShortcutComponentsPlugin {
         app.register_type::<ShortcutKind>()
             .register_type::<ShortcutTargetKind>()
             .register_type::<Shortcut>()
+            .register_type::<Shortcuts>()
             .register_type::<model::Model>();
     }
 }
@@ -61,7 +65,7 @@ impl Shortcut {
                 skill_id.into()
             }
             ShortcutKind::Action(action_id) => action_id.into(),
-            ShortcutKind::Macro(macro_id) => macro_id as i32,
+            ShortcutKind::Macro(macro_id) => u32::from(macro_id) as i32,
             ShortcutKind::Recipe(recipe_id) => recipe_id as i32,
             ShortcutKind::Bookmark(bookmark_id) => bookmark_id as i32,
             _ => 0,
@@ -101,7 +105,7 @@ impl Shortcut {
                 buffer.u32(self.target as u32);
             }
             ShortcutKind::Macro(macro_id) => {
-                buffer.u32(macro_id);
+                buffer.u32(macro_id.into());
                 buffer.u32(self.target as u32);
             }
             ShortcutKind::Recipe(recipe_id) => {
@@ -118,6 +122,63 @@ impl Shortcut {
     }
 }
 
+/// Shortcuts of the character's current sub class.
+/// Changes are kept here and written to the database by the autosave.
+#[derive(Clone, Component, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Shortcuts {
+    class_variant: SubClassVariant,
+    shortcuts: Vec<Shortcut>,
+}
+
+impl Shortcuts {
+    pub fn new(class_variant: SubClassVariant, shortcuts: Vec<Shortcut>) -> Self {
+        Self {
+            class_variant,
+            shortcuts,
+        }
+    }
+
+    pub fn class_variant(&self) -> SubClassVariant {
+        self.class_variant
+    }
+
+    /// Puts the shortcut into its slot, replacing the one that was there
+    pub fn register(&mut self, shortcut: Shortcut) {
+        self.delete(shortcut.slot_id);
+        self.shortcuts.push(shortcut);
+    }
+
+    pub fn delete(&mut self, slot_id: SlotId) -> Option<Shortcut> {
+        let index = self
+            .shortcuts
+            .iter()
+            .position(|shortcut| shortcut.slot_id == slot_id)?;
+        Some(self.shortcuts.swap_remove(index))
+    }
+
+    pub fn retain(&mut self, keep: impl FnMut(&Shortcut) -> bool) {
+        self.shortcuts.retain(keep);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Shortcut> {
+        self.shortcuts.iter()
+    }
+
+    pub fn models(&self, char_id: ObjectId) -> Vec<model::Model> {
+        self.shortcuts
+            .iter()
+            .map(|shortcut| shortcut.into_model(char_id))
+            .collect()
+    }
+}
+
+impl From<&Shortcuts> for ShortcutInit {
+    fn from(shortcuts: &Shortcuts) -> Self {
+        ShortcutInit::from(shortcuts.shortcuts.clone())
+    }
+}
+
 impl From<model::Model> for Shortcut {
     fn from(model: model::Model) -> Self {
         Self {
@@ -134,3 +195,37 @@ impl From<model::Model> for Shortcut {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shortcut(slot: u32, kind: ShortcutKind) -> Shortcut {
+        Shortcut {
+            class_variant: SubClassVariant::Main,
+            kind,
+            target: ShortcutTargetKind::Character,
+            reuse_group: -1,
+            slot_id: SlotId::new(slot, 0),
+        }
+    }
+
+    #[test]
+    fn test_register_replaces_shortcut_in_slot() {
+        let mut shortcuts = Shortcuts::default();
+        shortcuts.register(shortcut(1, ShortcutKind::Recipe(10)));
+        shortcuts.register(shortcut(2, ShortcutKind::Recipe(20)));
+        shortcuts.register(shortcut(1, ShortcutKind::Bookmark(30)));
+
+        assert_eq!(shortcuts.iter().count(), 2);
+        assert!(
+            shortcuts
+                .iter()
+                .any(|s| s.slot_id == SlotId::new(1, 0) && s.kind == ShortcutKind::Bookmark(30))
+        );
+
+        assert!(shortcuts.delete(SlotId::new(2, 0)).is_some());
+        assert!(shortcuts.delete(SlotId::new(2, 0)).is_none());
+        assert_eq!(shortcuts.models(ObjectId::test_data()).len(), 1);
+    }
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+/// Skill tree the skill is learned from, the client sends it back in the learning requests.
+#[derive(Clone, Copy, Debug, Default, Eq, IntoPrimitive, PartialEq, Reflect, TryFromPrimitive)]
+#[repr(u32)]
+pub enum AcquireSkillType {
+    #[default]
+    Class,
+    Transform,
+    Fishing,
+    Pledge,
+    SubPledge,
+    Transfer,
+    Subclass,
+    Collect,
+}
@@ -30,6 +30,10 @@ use std::{
 )]
 pub struct Id(u32);
 impl Id {
+    pub const fn new(value: u32) -> Self {
+        Id(value)
+    }
+
     pub fn from_skill_script(s: ScriptValue) -> Result<Self, InteropError> {
         match s.clone() {
             ScriptValue::Map(skill_map) => {
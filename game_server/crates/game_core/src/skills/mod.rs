@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 
+mod acquire;
 mod id;
 mod kind;
 mod level;
@@ -9,6 +10,10 @@ mod reuse_timers;
 mod skill;
 mod tree;
 
+/// Database model of the reuse timers kept over logout
+pub mod reuse_model;
+
+pub use acquire::*;
 pub use id::*;
 pub use kind::*;
 pub use level::*;
@@ -22,10 +27,12 @@ pub struct SkillsComponentsPlugin;
 impl Plugin for SkillsComponentsPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Level>()
+            .register_type::<AcquireSkillType>()
             .register_type::<Kind>()
             .register_type::<Skill>()
             .register_type::<SkillList>()
             .register_type::<SkillTreesHandlers>()
+            .register_type::<FishingSkillTreeHandle>()
             .register_type::<SkillReuseTimers>();
 
         l2r_core::register_optional_types!(app, Id);
@@ -0,0 +1,50 @@
+use crate::{character, object_id::ObjectId, skills};
+use l2r_core::db::{PrimaryKeyColumns, RepositoryModel, UpdatableModel};
+use sea_orm::entity::prelude::*;
+
+/// Time the character may use the skill again, it keeps running while the character is offline.
+#[derive(Clone, Copy, Debug, DeriveEntityModel, PartialEq)]
+#[sea_orm(table_name = "character_skill_reuse")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub char_id: ObjectId,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub skill_id: skills::Id,
+    pub reuse_until: DateTime,
+}
+
+impl PrimaryKeyColumns for Model {
+    type Column = Column;
+
+    fn pk_columns() -> &'static [Self::Column] {
+        &[Column::CharId, Column::SkillId]
+    }
+}
+
+impl UpdatableModel for Model {
+    type Column = Column;
+
+    fn update_columns() -> &'static [Self::Column] {
+        &[Column::ReuseUntil]
+    }
+}
+
+impl RepositoryModel for Model {}
+
+#[derive(Clone, Copy, Debug, DeriveRelation, EnumIter)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "character::model::Entity",
+        from = "Column::CharId",
+        to = "character::model::Column::Id"
+    )]
+    Character,
+}
+
+impl Related<character::model::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Character.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -35,6 +35,11 @@ impl Skill {
         }
     }
 
+    pub fn with_magic_level(mut self, magic_level: u32) -> Self {
+        self.magic_level = magic_level;
+        self
+    }
+
     pub fn enable(&mut self) {
         self.disabled = false;
     }
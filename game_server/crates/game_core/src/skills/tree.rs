@@ -36,6 +36,33 @@ pub struct SkillTreeNode {
     pub requirements: LearnRequirements,
 }
 
+impl SkillTreeNode {
+    pub fn level_required(&self) -> Option<stats::Level> {
+        self.requirements.0.iter().find_map(|req| match req {
+            LearnRequirement::Level(level) => Some(*level),
+            _ => None,
+        })
+    }
+
+    pub fn sp(&self) -> stats::Sp {
+        self.requirements
+            .0
+            .iter()
+            .map(|req| match req {
+                LearnRequirement::Sp(sp) => *sp,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = (items::Id, u64)> + '_ {
+        self.requirements.0.iter().filter_map(|req| match req {
+            LearnRequirement::Item(item) => Some(*item),
+            _ => None,
+        })
+    }
+}
+
 impl From<&SkillTreeNode> for super::Skill {
     fn from(node: &SkillTreeNode) -> Self {
         Self::new(node.skill_id, node.skill_level)
@@ -57,6 +84,41 @@ impl SkillTree {
             .collect()
     }
 
+    pub fn node(&self, skill_id: super::Id, skill_level: super::Level) -> Option<&SkillTreeNode> {
+        self.0
+            .iter()
+            .find(|node| node.skill_id == skill_id && node.skill_level == skill_level)
+    }
+
+    pub fn max_level(&self, skill_id: super::Id) -> Option<super::Level> {
+        self.0
+            .iter()
+            .filter(|node| node.skill_id == skill_id)
+            .map(|node| node.skill_level)
+            .max()
+    }
+
+    /// Next levels of the skills the character is high enough to learn.
+    pub fn learnable(
+        &self,
+        skill_list: &super::SkillList,
+        level: stats::Level,
+    ) -> Vec<&SkillTreeNode> {
+        self.0
+            .iter()
+            .filter(|node| {
+                let known_level = skill_list
+                    .get(&node.skill_id)
+                    .map(|skill| *skill.level())
+                    .unwrap_or_default();
+                *node.skill_level == known_level + 1
+                    && node
+                        .level_required()
+                        .is_none_or(|required| required <= level)
+            })
+            .collect()
+    }
+
     pub fn auto_skill_on_level(&self, level: stats::Level) -> Vec<SkillTreeNode> {
         self.0
             .iter()
@@ -105,6 +167,11 @@ impl SkillTreesHandlers {
     }
 }
 
+/// Skills of the fishing guild, the same for all classes.
+#[derive(Clone, Default, Deref, DerefMut, From, Reflect, Resource)]
+#[reflect(Resource)]
+pub struct FishingSkillTreeHandle(Handle<SkillTree>);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,6 +227,30 @@ mod tests {
         assert_eq!(skills.len(), 3);
     }
 
+    #[test]
+    fn test_skill_tree_learnable() {
+        let skill_tree: SkillTree =
+            serde_json::from_str(test_data_json()).expect("Failed to deserialize SkillTree");
+
+        let mut skill_list = super::super::SkillList::default();
+        skill_list.add_skill(super::super::Skill::new(4.into(), 2.into()));
+
+        let learnable_ids = |level: u32| {
+            skill_tree
+                .learnable(&skill_list, level.into())
+                .into_iter()
+                .map(|node| *node.skill_id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(learnable_ids(1), vec![1, 3, 4, 5]);
+        assert_eq!(learnable_ids(0), vec![3, 4]);
+
+        let node = skill_tree.node(5.into(), 1.into()).unwrap();
+        assert_eq!(node.sp(), 50);
+        assert_eq!(node.items().collect::<Vec<_>>(), vec![(123.into(), 1)]);
+    }
+
     #[test]
     fn test_skill_tree_skills_without_level_requirements() {
         let skill_tree: SkillTree =
@@ -34,14 +34,18 @@ impl SpawnerTimer {
         }
     }
     pub fn reset(&mut self) {
+        let mut duration = self.default_duration;
         if let Some(random) = self.random {
             let random = random as i32;
             let random_time = rand::thread_rng().gen_range(-random..random) as i64;
             let default_seconds = self.default_duration.as_secs() as i64;
-            let seconds = (default_seconds + random_time).max(1) as u64;
-            self.timer = Timer::new(Duration::from_secs(seconds), TimerMode::Once);
+            duration = Duration::from_secs((default_seconds + random_time).max(1) as u64);
         }
-        self.timer.reset();
+        self.timer = Timer::new(duration, TimerMode::Once);
+    }
+    /// Holds the next spawn back for the duration, the following ones use the usual delay.
+    pub fn delay(&mut self, duration: Duration) {
+        self.timer = Timer::new(duration, TimerMode::Once);
     }
 }
 impl<'de> Deserialize<'de> for SpawnerTimer {
@@ -115,6 +119,11 @@ pub struct BannedSpawnZone;
 #[require(Name::new("BannedSpawnZones".to_string()))]
 pub struct BannedSpawnZones;
 
+/// NPCs spawned on an entity with this component become its children instead of the region ones,
+/// so they are despawned together with it.
+#[derive(Component, Default, Reflect)]
+pub struct SpawnFolder;
+
 fn default_chase_range() -> u32 {
     1000
 }
@@ -25,6 +25,17 @@ impl ClassTree {
         current_id
     }
 
+    /// Number of class transfers made to reach the given class, 0 for starting classes.
+    pub fn profession_level(&self, id: ClassId) -> u8 {
+        let mut level = 0;
+        let mut current_id = id;
+        while let Some(Some(parent_id)) = self.0.get(&current_id) {
+            current_id = *parent_id;
+            level += 1;
+        }
+        level
+    }
+
     pub fn get_base_class(&self, class_id: ClassId) -> BaseClass {
         let base_class = self.get_base_class_id(class_id);
         match base_class {
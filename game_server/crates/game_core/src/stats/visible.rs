@@ -3,6 +3,7 @@ use bevy::prelude::*;
 #[repr(u8)]
 #[derive(Clone, Component, Copy, Debug, Default, Eq, PartialEq, Reflect)]
 #[reflect(Component, Default)]
+#[require(crate::encounters::KnownBy)]
 pub enum EncountersVisibility {
     Hidden,
     #[default]
@@ -112,6 +112,20 @@ impl Nullable for ReflectableDateTime {
     }
 }
 
+/// Fixed calendar for the castle tests that depend on dates.
+#[cfg(test)]
+pub(crate) mod test_time {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    /// Wednesday noon, clear of siege days.
+    pub(crate) fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 7, 3)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
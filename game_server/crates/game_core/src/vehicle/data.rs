@@ -0,0 +1,108 @@
+use crate::items;
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_common_assets::json::JsonAssetPlugin;
+use derive_more::{From, Into};
+use serde::{Deserialize, Serialize};
+use spatial::{GameVec3, Heading};
+use std::fmt;
+
+pub struct BoatRoutesComponentsPlugin;
+impl Plugin for BoatRoutesComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(JsonAssetPlugin::<BoatRoutes>::new(&["json"]));
+
+        app.register_type::<BoatRoutesHandle>()
+            .register_type::<BoatRoute>()
+            .register_type::<BoatDock>()
+            .register_type::<BoatAnnouncement>()
+            .register_type::<BoatWaypoint>();
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deref,
+    Deserialize,
+    Eq,
+    From,
+    Hash,
+    Into,
+    PartialEq,
+    Reflect,
+    Serialize,
+)]
+pub struct BoatRouteId(u32);
+
+impl fmt::Display for BoatRouteId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Default, Deref, DerefMut, Reflect, Resource)]
+pub struct BoatRoutesHandle(Handle<BoatRoutes>);
+
+#[derive(Asset, Clone, Debug, Deref, Deserialize, From, PartialEq, Reflect, Serialize)]
+pub struct BoatRoutes(HashMap<BoatRouteId, BoatRoute>);
+
+/// Boat sailing from dock to dock in turn, it starts anchored at the first one.
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct BoatRoute {
+    pub name: String,
+    pub docks: Vec<BoatDock>,
+}
+
+impl BoatRoute {
+    pub fn dock(&self, index: usize) -> Option<&BoatDock> {
+        self.docks.get(index)
+    }
+
+    pub fn next_dock(&self, index: usize) -> usize {
+        (index + 1) % self.docks.len().max(1)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct BoatDock {
+    pub name: String,
+    pub loc: GameVec3,
+    #[serde(default)]
+    pub heading: Heading,
+    /// Ticket every passenger pays with when the boat leaves the dock.
+    pub ticket: items::Id,
+    /// Where passengers without a ticket are put ashore.
+    pub oust: GameVec3,
+    /// Seconds the boat anchors at the dock.
+    pub anchor_time: u64,
+    /// System message told around the dock once the boat arrives.
+    pub arrival: Option<u32>,
+    /// System messages told around the dock before the boat leaves, the earliest first.
+    #[serde(default)]
+    pub announcements: Vec<BoatAnnouncement>,
+    /// System message told around the dock once the boat leaves.
+    pub departure: Option<u32>,
+    /// Way to the next dock, it ends at the dock itself.
+    pub path: Vec<BoatWaypoint>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct BoatAnnouncement {
+    /// Seconds before the departure.
+    pub before: u64,
+    pub message: u32,
+}
+
+fn default_rotation_speed() -> u32 {
+    800
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Reflect, Serialize)]
+pub struct BoatWaypoint {
+    pub loc: GameVec3,
+    pub speed: u32,
+    #[serde(default = "default_rotation_speed")]
+    pub rotation_speed: u32,
+}
@@ -0,0 +1,249 @@
+use crate::{
+    object_id::ObjectId,
+    stats::{EncountersVisibility, Movable, MovementStat},
+};
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use physics::GameLayer;
+use std::time::Duration;
+
+mod data;
+
+pub use data::*;
+
+pub struct VehicleComponentsPlugin;
+impl Plugin for VehicleComponentsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(BoatRoutesComponentsPlugin);
+
+        app.register_type::<BoatRouteId>()
+            .register_type::<Boat>()
+            .register_type::<Passengers>()
+            .register_type::<Boarded>()
+            .register_type::<InVehicle>();
+    }
+}
+
+/// Characters board the docked boat only this close to it.
+pub const BOARDING_DISTANCE: f32 = 1000.0;
+
+/// Passengers may not walk further from the middle of the deck.
+pub const DECK_RADIUS: f32 = 1000.0;
+
+/// Boat sailing its route. It anchors at every dock for a while, passengers board it there
+/// and pay with their tickets once it leaves.
+#[derive(Clone, Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Boat {
+    pub route_id: BoatRouteId,
+    dock: usize,
+    /// Set while the boat anchors at the dock, it leaves once the timer is finished.
+    anchor: Option<Timer>,
+    announced: usize,
+    /// Waypoint the boat sails to right now.
+    course: Option<BoatWaypoint>,
+}
+
+impl Boat {
+    pub fn new(route_id: BoatRouteId, dock: &BoatDock) -> Self {
+        let mut boat = Self {
+            route_id,
+            dock: 0,
+            anchor: None,
+            announced: 0,
+            course: None,
+        };
+        boat.anchor(0, dock);
+        boat
+    }
+
+    /// Dock the boat anchors at, or the one it sails from.
+    pub fn dock(&self) -> usize {
+        self.dock
+    }
+
+    pub fn is_docked(&self) -> bool {
+        self.anchor.is_some()
+    }
+
+    pub fn anchor(&mut self, index: usize, dock: &BoatDock) {
+        self.dock = index;
+        self.anchor = Some(Timer::new(
+            Duration::from_secs(dock.anchor_time),
+            TimerMode::Once,
+        ));
+        self.announced = 0;
+        self.course = None;
+    }
+
+    pub fn course(&self) -> Option<&BoatWaypoint> {
+        self.course.as_ref()
+    }
+
+    pub fn set_course(&mut self, waypoint: BoatWaypoint) {
+        self.course = Some(waypoint);
+    }
+
+    /// Counts down the time at the dock, returns the announcements due
+    /// and whether the boat leaves now.
+    pub fn update(&mut self, delta: Duration, dock: &BoatDock) -> (Vec<u32>, bool) {
+        let Some(anchor) = self.anchor.as_mut() else {
+            return (vec![], false);
+        };
+        anchor.tick(delta);
+
+        let remaining = anchor.remaining();
+        let due = dock
+            .announcements
+            .iter()
+            .skip(self.announced)
+            .take_while(|announcement| remaining <= Duration::from_secs(announcement.before))
+            .map(|announcement| announcement.message)
+            .collect::<Vec<_>>();
+        self.announced += due.len();
+
+        let leaves = anchor.finished();
+        if leaves {
+            self.anchor = None;
+        }
+        (due, leaves)
+    }
+}
+
+#[derive(Bundle)]
+pub struct BoatBundle {
+    pub boat: Boat,
+    pub name: Name,
+    pub object_id: ObjectId,
+    pub transform: Transform,
+    pub movable: Movable,
+    pub collider: Collider,
+    pub layers: CollisionLayers,
+    pub visibility: EncountersVisibility,
+}
+
+impl BoatBundle {
+    const COLLIDER_SIZE: f32 = 100.0;
+
+    pub fn new(route_id: BoatRouteId, route: &BoatRoute, object_id: ObjectId) -> Option<Self> {
+        let dock = route.dock(0)?;
+
+        let mut movable = Movable::default();
+        movable.set_move_type(MovementStat::Swim);
+
+        Some(Self {
+            boat: Boat::new(route_id, dock),
+            name: Name::new(route.name.clone()),
+            object_id,
+            transform: Transform::from_translation(dock.loc.into())
+                .with_rotation(Quat::from(dock.heading)),
+            movable,
+            collider: Collider::cuboid(
+                Self::COLLIDER_SIZE,
+                Self::COLLIDER_SIZE,
+                Self::COLLIDER_SIZE,
+            ),
+            layers: GameLayer::vehicle(),
+            visibility: EncountersVisibility::Visible,
+        })
+    }
+}
+
+#[derive(Clone, Component, Debug, Default, Reflect)]
+#[reflect(Component)]
+#[relationship_target(relationship = Boarded)]
+pub struct Passengers(Vec<Entity>);
+
+/// Character rides the vehicle, its world position follows the vehicle.
+#[derive(Clone, Component, Copy, Debug, Deref, PartialEq, Reflect)]
+#[reflect(Component)]
+#[relationship(relationship_target = Passengers)]
+pub struct Boarded(pub Entity);
+
+/// Position of the passenger relative to the vehicle it rides.
+#[derive(Clone, Component, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct InVehicle {
+    pub vehicle_oid: ObjectId,
+    pub position: Vec3,
+    /// Where the passenger boarded, it is also the position saved for the character,
+    /// so nobody logs in in the middle of the sea.
+    pub exit: Vec3,
+}
+
+impl InVehicle {
+    pub fn new(vehicle_oid: ObjectId, position: Vec3, exit: Vec3) -> Self {
+        Self {
+            vehicle_oid,
+            position,
+            exit,
+        }
+    }
+
+    /// Position in the world on board of the vehicle at the transform.
+    pub fn translation(&self, vehicle: &Transform) -> Vec3 {
+        vehicle.translation + self.position
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::items;
+    use spatial::GameVec3;
+
+    fn dock() -> BoatDock {
+        BoatDock {
+            name: "Talking Island Harbor".to_string(),
+            loc: GameVec3::default(),
+            heading: Default::default(),
+            ticket: items::Id::new(1074),
+            oust: GameVec3::default(),
+            anchor_time: 600,
+            arrival: Some(979),
+            announcements: vec![
+                BoatAnnouncement {
+                    before: 600,
+                    message: 980,
+                },
+                BoatAnnouncement {
+                    before: 300,
+                    message: 981,
+                },
+                BoatAnnouncement {
+                    before: 60,
+                    message: 982,
+                },
+            ],
+            departure: Some(985),
+            path: vec![],
+        }
+    }
+
+    #[test]
+    fn test_anchoring() {
+        let dock = dock();
+        let mut boat = Boat::new(BoatRouteId(1), &dock);
+        assert!(boat.is_docked());
+
+        assert_eq!(boat.update(Duration::ZERO, &dock), (vec![980], false));
+        assert_eq!(
+            boat.update(Duration::from_secs(200), &dock),
+            (vec![], false)
+        );
+        assert_eq!(
+            boat.update(Duration::from_secs(350), &dock),
+            (vec![981, 982], false)
+        );
+        assert_eq!(boat.update(Duration::from_secs(50), &dock), (vec![], true));
+        assert!(!boat.is_docked());
+        assert_eq!(
+            boat.update(Duration::from_secs(600), &dock),
+            (vec![], false)
+        );
+
+        boat.anchor(1, &dock);
+        assert_eq!(boat.dock(), 1);
+        assert_eq!(boat.update(Duration::ZERO, &dock), (vec![980], false));
+    }
+}
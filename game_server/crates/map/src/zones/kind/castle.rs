@@ -4,8 +4,14 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Reflect, Serialize)]
 pub struct CastleKind {
+    castle_id: u8,
     spawn_points: SpawnPoints,
 }
+impl CastleKind {
+    pub fn castle_id(&self) -> u8 {
+        self.castle_id
+    }
+}
 impl SpawnPointsGetter for CastleKind {
     fn spawn_points(&self) -> &SpawnPoints {
         &self.spawn_points
@@ -12,6 +12,7 @@ pub enum GameLayer {
     EnvironmentPassable,
     Sensor,
     Item,
+    Vehicle,
 }
 
 impl GameLayer {
@@ -43,6 +44,12 @@ impl GameLayer {
         CollisionLayers::new([Self::Item], LayerMask::NONE)
     }
 
+    /// Collision layers for boats
+    /// Passengers ride on top of them, so vehicles are only visible to spatial queries
+    pub fn vehicle() -> CollisionLayers {
+        CollisionLayers::new([Self::Vehicle], LayerMask::NONE)
+    }
+
     pub fn solid_environment_mask() -> LayerMask {
         LayerMask::from([Self::Environment])
     }
@@ -59,6 +66,7 @@ impl GameLayer {
             Self::Item,
             Self::Environment,
             Self::EnvironmentPassable,
+            Self::Vehicle,
         ])
     }
 
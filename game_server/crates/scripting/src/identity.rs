@@ -0,0 +1,75 @@
+//! Which script is running right now, so bindings don't have to trust what scripts say about themselves.
+
+use bevy::prelude::*;
+use bevy_mod_scripting::{
+    bindings::InteropError,
+    core::{event::IntoCallbackLabel, handler::event_handler, script::ScriptAttachment},
+    lua::{LuaContext, LuaScriptingPlugin},
+};
+use std::cell::RefCell;
+
+thread_local! {
+    static CALLING_SCRIPT: RefCell<Option<CallingScript>> = const { RefCell::new(None) };
+}
+
+/// Script whose code runs on this thread.
+#[derive(Clone, Debug)]
+pub struct CallingScript {
+    /// Asset path of the script
+    pub path: String,
+    /// Where the script is attached, callbacks meant for it only are sent there
+    pub attachment: ScriptAttachment,
+}
+
+/// Script whose code runs on this thread.
+/// Scripts run on the thread of the system handling them and the identity is set
+/// right before their code is loaded or a callback of theirs is called.
+pub fn calling_script() -> Option<CallingScript> {
+    CALLING_SCRIPT.with_borrow(|script| script.clone())
+}
+
+pub(crate) fn set_calling_script(
+    attachment: &ScriptAttachment,
+    _context: &mut LuaContext,
+) -> Result<(), InteropError> {
+    let handle = match attachment {
+        ScriptAttachment::EntityScript(_, handle) | ScriptAttachment::StaticScript(handle) => {
+            handle
+        }
+    };
+    let path = handle
+        .path()
+        .map(|path| path.to_string())
+        .unwrap_or_else(|| format!("{:?}", handle.id()));
+    CALLING_SCRIPT.set(Some(CallingScript {
+        path,
+        attachment: attachment.clone(),
+    }));
+    Ok(())
+}
+
+/// Forgets the calling script once dropped, even if the script code panicked.
+struct CallingScriptScope;
+
+impl Drop for CallingScriptScope {
+    fn drop(&mut self) {
+        CALLING_SCRIPT.set(None);
+    }
+}
+
+/// Calls the `L` callback of the scripts, none of them stays the calling script afterwards.
+/// Use it instead of [`event_handler`] for callbacks of scripts that can call bindings.
+pub fn scoped_event_handler<L>(world: &mut World)
+where
+    L: IntoCallbackLabel + Send + Sync + 'static,
+{
+    let _scope = CallingScriptScope;
+    if let Err(err) = world.run_system_cached(event_handler::<L, LuaScriptingPlugin>) {
+        error!("Failed to call scripts: {}", err);
+    }
+}
+
+/// Scripts are loaded outside of the callback handlers, their identity is dropped at the end of the frame.
+pub(crate) fn reset_calling_script(_world: &mut World) {
+    CALLING_SCRIPT.set(None);
+}
@@ -8,9 +8,11 @@ use bevy_mod_scripting::{
 };
 use l2r_core::utils::get_base_path;
 
+pub mod identity;
 pub mod runtime;
 pub mod utils;
 
+pub use identity::{CallingScript, calling_script, scoped_event_handler};
 pub use runtime::*;
 
 const PACKAGE_NAME: &str = "package";
@@ -49,6 +51,16 @@ impl Plugin for CustomScriptingPlugin {
             .context_initializers
             .push(base_path_configurator);
 
+        // Bindings tell scripts apart by the script that is running, not by what it passes in
+        lua_scripting
+            .scripting_plugin
+            .context_initializers
+            .push(identity::set_calling_script);
+        lua_scripting
+            .scripting_plugin
+            .context_pre_handling_initializers
+            .push(identity::set_calling_script);
+
         // Filter to exclude duplicate type registrations
         // This prevents warning messages about duplicate entries in the types global
         // These short type names appear in multiple modules causing conflicts
@@ -79,6 +91,8 @@ impl Plugin for CustomScriptingPlugin {
             .add_plugins(lua_scripting)
             .add_plugins(RuntimeScriptPlugin);
 
+        app.add_systems(Last, identity::reset_calling_script);
+
         app.register_type::<ScriptComponent>();
 
         l2r_core::register_optional_types!(app, Vec3, Entity, Timer, i32);
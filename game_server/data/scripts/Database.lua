@@ -0,0 +1,81 @@
+local Logger = req("data.scripts.Logger")
+
+--[[
+    Database queries of scripts run off the main thread, results come back through on_db_result.
+    Queries over the per-script limit wait here until one of the running queries finishes.
+]]
+---@class Database
+local Database = {}
+
+-- Callbacks of running queries by query handle
+local pending = {}
+
+-- Queries waiting for a free slot, in the order they were made
+local queued = {}
+
+local function start(request)
+    local ok, started = pcall(request.binding, { args = request.args })
+    if not ok then
+        Logger.error("Database query failed to start: " .. tostring(started))
+        request.on_result(nil, tostring(started))
+        return true
+    end
+    if started.busy then
+        return false
+    end
+    pending[started.handle] = request.on_result
+    return true
+end
+
+local function request(binding, args, on_result)
+    local query = { binding = binding, args = args, on_result = on_result or function() end }
+    if #queued > 0 or not start(query) then
+        table.insert(queued, query)
+    end
+end
+
+--- Runs a raw SQL query
+---@param args table sql, params (optional) and return_multiple (optional)
+---@param on_result fun(value: any, err: string|nil)|nil Called once the query is done
+function Database.query_raw(args, on_result)
+    request(DatabaseOps.query_raw_async, args, on_result)
+end
+
+--- Finds a model by its repository key
+---@param args table Repository name and key
+---@param on_result fun(value: any, err: string|nil)|nil Called once the query is done
+function Database.find_by_id(args, on_result)
+    request(DatabaseOps.find_by_id_async, args, on_result)
+end
+
+--- Writes a model to its repository
+---@param model any The model to write
+---@param on_result fun(value: any, err: string|nil)|nil Called once the query is done
+function Database.create_or_update(model, on_result)
+    request(DatabaseOps.create_or_update_async, model, on_result)
+end
+
+--- Delivers a query result to its callback, only results of this script's queries arrive here
+---@param handle number The query handle
+---@param result table { ok, value | error }
+function Database.on_db_result(handle, result)
+    local on_result = pending[handle]
+    if not on_result then
+        return
+    end
+    pending[handle] = nil
+    -- Delivered here, nobody else polls for it
+    DatabaseOps.poll(handle)
+
+    while #queued > 0 and start(queued[1]) do
+        table.remove(queued, 1)
+    end
+
+    if result.ok then
+        on_result(result.value, nil)
+    else
+        on_result(nil, result.error)
+    end
+end
+
+return Database
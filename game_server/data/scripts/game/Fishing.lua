@@ -0,0 +1,12 @@
+---@class Fishing
+local Fishing = {}
+
+-- Inserts a FishingSkillUse component, the fishing plugin casts the line, pumps or reels the fish
+---@param entity Entity The angler
+---@param skill_ref any The used fishing guild skill
+function Fishing.insert_component(entity, skill_ref)
+    local skill_use = construct(types.FishingSkillUse, { _1 = skill_ref })
+    world.insert_component(entity, types.FishingSkillUse, skill_use)
+end
+
+return Fishing
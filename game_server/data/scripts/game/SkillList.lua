@@ -1,5 +1,6 @@
 local Logger = req("data.scripts.Logger")
 local Skills = req("data.scripts.game.Skills")
+local Database = req("data.scripts.Database")
 Logger.set_script_name("game.SkillList")
 
 ---@class SkillList
@@ -43,14 +44,19 @@ end
 
 --- Loads character skills from database
 ---@param char_id ObjectId The character's ObjectId
----@return table character_skills List of skills from database
-function SkillList.load_skills_from_db(char_id)
-    local character_skills = DatabaseOps.query_raw({
+---@param on_loaded fun(character_skills: table) Called with the list of skills from database
+function SkillList.load_skills_from_db(char_id, on_loaded)
+    Database.query_raw({
         sql = "SELECT skill_id, skill_level FROM character_skills WHERE char_id = $1",
         params = { char_id._1 },
         return_multiple = true
-    })
-    return character_skills
+    }, function(character_skills, err)
+        if err then
+            Logger.error("Failed to load skills of character " .. tostring(char_id._1) .. ": " .. err)
+            return
+        end
+        on_loaded(character_skills)
+    end)
 end
 
 --- Syncs current skill list to database with optimal diff-based updates
@@ -59,17 +65,7 @@ end
 ---@param skill_list SkillList The current SkillList component
 ---@param sub_class_variant SubClassVariant The SubClassVariant enum
 ---@param skills_storage SkillsStorage The skills storage for lazy loading
----@return number updated_count Number of skills updated
 function SkillList.sync_skills_to_db(char_id, skill_list, sub_class_variant, skills_storage)
-    -- Load existing skills from database
-    local db_skills = SkillList.load_skills_from_db(char_id)
-
-    -- Build a map of database skills for quick lookup
-    local db_skills_map = {}
-    for _, db_skill in ipairs(db_skills) do
-        db_skills_map[db_skill.skill_id] = db_skill.skill_level
-    end
-
     -- Build a map of current skills from the skill list
     local current_skills_map = {}
 
@@ -79,54 +75,60 @@ function SkillList.sync_skills_to_db(char_id, skill_list, sub_class_variant, ski
         end
     end
 
-    local updated_count = 0
-    local char_skill_model_type = world.get_type_by_name("game_core::character::skills::Model")
+    -- Load existing skills from database
+    SkillList.load_skills_from_db(char_id, function(db_skills)
+        -- Build a map of database skills for quick lookup
+        local db_skills_map = {}
+        for _, db_skill in ipairs(db_skills) do
+            db_skills_map[db_skill.skill_id] = db_skill.skill_level
+        end
 
-    -- Find skills to upsert (new or level changed)
-    for skill_id, skill_level in pairs(current_skills_map) do
-        local db_level = db_skills_map[skill_id]
-        if not db_level or db_level ~= skill_level then
-            -- Preload skill handler from storage (lazy loading)
-            if skills_storage then
-                local handler = skills_storage.get(skill_id)
-                if not handler then
-                    Logger.warn("Skill handler not found for skill ID " .. tostring(skill_id) .. " during DB sync")
+        local updated_count = 0
+        local char_skill_model_type = world.get_type_by_name("game_core::character::skills::Model")
+        local skill_id_type = world.get_type_by_name("game_core::skills::id::Id")
+
+        -- Find skills to upsert (new or level changed)
+        for skill_id, skill_level in pairs(current_skills_map) do
+            local db_level = db_skills_map[skill_id]
+            if not db_level or db_level ~= skill_level then
+                -- Preload skill handler from storage (lazy loading)
+                if skills_storage then
+                    local handler = skills_storage.get(skill_id)
+                    if not handler then
+                        Logger.warn("Skill handler not found for skill ID " .. tostring(skill_id) .. " during DB sync")
+                    end
                 end
-            end
 
-            -- Skill is new or level changed, upsert it
-            local skill_id_type = world.get_type_by_name("game_core::skills::id::Id")
-            local skill_id_obj = construct(skill_id_type, { _1 = skill_id })
+                -- Skill is new or level changed, upsert it
+                local skill_id_obj = construct(skill_id_type, { _1 = skill_id })
 
-            local char_skill_model = construct(char_skill_model_type, {
-                char_id = char_id,
-                skill_id = skill_id_obj,
-                sub_class = sub_class_variant,
-                skill_level = skill_level
-            })
+                local char_skill_model = construct(char_skill_model_type, {
+                    char_id = char_id,
+                    skill_id = skill_id_obj,
+                    sub_class = sub_class_variant,
+                    skill_level = skill_level
+                })
 
-            local success = DatabaseOps.create_or_update(char_skill_model)
-            if success then
+                Database.create_or_update(char_skill_model, function(_, err)
+                    if err then
+                        Logger.error("Failed to sync skill " .. tostring(skill_id) .. " to database: " .. err)
+                    end
+                end)
                 updated_count = updated_count + 1
-            else
-                Logger.error("Failed to sync skill " .. tostring(skill_id) .. " to database")
             end
         end
-    end
 
-    if updated_count > 0 then
-        Logger.debug("Synced " ..
-            tostring(updated_count) .. " skill changes to database for character " .. tostring(char_id._1))
-    end
-
-    return updated_count
+        if updated_count > 0 then
+            Logger.debug("Syncing " ..
+                tostring(updated_count) .. " skill changes to database for character " .. tostring(char_id._1))
+        end
+    end)
 end
 
 --- Saves a skill to the database for a character
 ---@param char_id ObjectId The character's ObjectId
 ---@param skill_node SkillTreeNode The skill node from skill tree
 ---@param sub_class_variant SubClassVariant The SubClassVariant enum
----@return boolean success Whether the save was successful
 function SkillList.save_skill_to_db(char_id, skill_node, sub_class_variant)
     local char_skill_model_type = world.get_type_by_name("game_core::character::skills::Model")
     local char_skill_model = construct(char_skill_model_type, {
@@ -136,11 +138,12 @@ function SkillList.save_skill_to_db(char_id, skill_node, sub_class_variant)
         skill_level = skill_node.skill_level._1
     })
 
-    local success = DatabaseOps.create_or_update(char_skill_model)
-    if not success then
-        Logger.error("Failed to save skill " .. tostring(skill_node.skill_id._1) .. " to database")
-    end
-    return success
+    local skill_id = skill_node.skill_id._1
+    Database.create_or_update(char_skill_model, function(_, err)
+        if err then
+            Logger.error("Failed to save skill " .. tostring(skill_id) .. " to database: " .. err)
+        end
+    end)
 end
 
 --- Grants auto-skills to a character and saves them to the database
@@ -4,6 +4,7 @@ require("data.scripts.Utils")
 require("data.scripts.runtime.skills.types")
 local LuaApp = req("data.scripts.LuaApp")
 local Logger = req("data.scripts.Logger")
+local Database = req("data.scripts.Database")
 Logger.set_script_name("SkillsApp")
 
 
@@ -22,6 +23,10 @@ function on_packet_received(entity, packet)
     end
 end
 
+function on_db_result(handle, result)
+    Database.on_db_result(handle, result)
+end
+
 function on_script_loaded()
     Logger.info("Loaded plugins:")
     for _, plugin in pairs(app.plugins) do
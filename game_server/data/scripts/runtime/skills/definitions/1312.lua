@@ -0,0 +1,42 @@
+require("data.scripts.Utils")
+local Magic = req("data.scripts.runtime.skills.definitions.common.Magic")
+local Target = req("data.scripts.runtime.skills.definitions.common.Target")
+local Fishing = req("data.scripts.game.Fishing")
+
+---@type SkillDefinition
+local definition = {
+    id = 1312,
+    levels = 1,
+    name = "Fishing",
+    description = "Casts the line in the fishing grounds. Requires a fishing rod and a lure.",
+    kind = "Active",
+    tables = {
+        magicLevel = { 1 },
+        mpConsume = { 0 },
+        mpInitialConsume = { 0 },
+    },
+    other = {
+        hitTime = 1000,
+        reuseDelay = 1000,
+        icon = "icon.skill1312",
+        isMagic = false,
+        targetType = "Self",
+    },
+}
+
+---@type SkillHandler
+local Skill = {
+    definition = definition,
+
+    pend = function(entity, skill_ref, shift_pressed, ctrl_pressed)
+        Target.pend_on_self(entity, skill_ref)
+    end,
+    on_pending = function(entity, pending_skill)
+        Magic.on_pending_skill(entity, pending_skill, definition)
+    end,
+    launch = function(entity, target_entity, skill_ref)
+        Fishing.insert_component(entity, skill_ref)
+    end,
+}
+
+return Skill
@@ -0,0 +1,42 @@
+require("data.scripts.Utils")
+local Magic = req("data.scripts.runtime.skills.definitions.common.Magic")
+local Target = req("data.scripts.runtime.skills.definitions.common.Target")
+local Fishing = req("data.scripts.game.Fishing")
+
+---@type SkillDefinition
+local definition = {
+    id = 1314,
+    levels = 27,
+    name = "Reeling",
+    description = "Reels the resting fish on the hook in, dealing damage to it.",
+    kind = "Active",
+    tables = {
+        magicLevel = { 1, 4, 7, 10, 13, 16, 19, 22, 25, 28, 31, 34, 37, 40, 43, 46, 49, 52, 55, 58, 61, 64, 67, 70, 73, 76, 79 },
+        mpConsume = { 2, 2, 3, 3, 3, 4, 4, 4, 5, 5, 5, 6, 6, 6, 7, 7, 7, 8, 8, 8, 9, 9, 9, 10, 10, 10, 11 },
+        mpInitialConsume = { 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0 },
+    },
+    other = {
+        hitTime = 1000,
+        reuseDelay = 1000,
+        icon = "icon.skill1314",
+        isMagic = false,
+        targetType = "Self",
+    },
+}
+
+---@type SkillHandler
+local Skill = {
+    definition = definition,
+
+    pend = function(entity, skill_ref, shift_pressed, ctrl_pressed)
+        Target.pend_on_self(entity, skill_ref)
+    end,
+    on_pending = function(entity, pending_skill)
+        Magic.on_pending_skill(entity, pending_skill, definition)
+    end,
+    launch = function(entity, target_entity, skill_ref)
+        Fishing.insert_component(entity, skill_ref)
+    end,
+}
+
+return Skill
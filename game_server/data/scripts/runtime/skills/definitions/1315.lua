@@ -0,0 +1,24 @@
+require("data.scripts.Utils")
+
+---@type SkillDefinition
+local definition = {
+    id = 1315,
+    levels = 27,
+    name = "Fishing Expertise",
+    description = "Pumping and Reeling three or more levels above it deal only half of the damage.",
+    kind = "Passive",
+    tables = {
+        magicLevel = { 1, 4, 7, 10, 13, 16, 19, 22, 25, 28, 31, 34, 37, 40, 43, 46, 49, 52, 55, 58, 61, 64, 67, 70, 73, 76, 79 },
+    },
+    other = { icon = "icon.skill1315" },
+}
+
+---@type SkillHandler
+local Skill = {
+    definition = definition,
+    -- Checked by the fishing plugin, nothing to modify
+    apply_passive = function(entity, skill_ref)
+    end,
+}
+
+return Skill
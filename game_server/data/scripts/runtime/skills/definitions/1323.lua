@@ -0,0 +1,49 @@
+require("data.scripts.Utils")
+local Magic = req("data.scripts.runtime.skills.definitions.common.Magic")
+local Target = req("data.scripts.runtime.skills.definitions.common.Target")
+local AbnormalEffects = req("data.scripts.game.AbnormalEffects")
+
+---@type SkillDefinition
+local definition = {
+    id = 1323,
+    levels = 1,
+    name = "Noblesse Blessing",
+    description = "The target keeps the rest of the buffs and debuffs when it dies. The blessing itself is gone after the death.",
+    kind = "Active",
+    tables = {
+        magicLevel = { 75 },
+        mpConsume = { 80 },
+        mpInitialConsume = { 20 },
+    },
+    other = {
+        abnormalTime = 3600000, -- 60 minutes in milliseconds
+        abnormalKind = "PreserveAbnormal",
+        castRange = 400,
+        effectRange = 900,
+        hitTime = 1500,
+        reuseDelay = 2000,
+        icon = "icon.skill1323",
+        isMagic = true,
+        targetType = "One",
+        priority = 1,
+    },
+}
+
+
+---@type SkillHandler
+local Skill = {
+    definition = definition,
+    pend = function(entity, skill_ref, shift_pressed, ctrl_pressed)
+        Target.pend_skill_on_self_or_ally(entity, skill_ref, ctrl_pressed, shift_pressed, definition)
+    end,
+    on_pending = function(entity, pending_skill)
+        Magic.on_pending_skill(entity, pending_skill, definition)
+    end,
+    launch = function(entity, target_entity, skill_ref)
+        Magic.launch_buff_skill(entity, target_entity, skill_ref, definition)
+    end,
+    apply_abnormal = function(target_entity, skill_ref)
+        AbnormalEffects.apply_stat_modifiers(target_entity, definition, skill_ref.level._1)
+    end,
+}
+return Skill
@@ -11,16 +11,11 @@ local SystemMessage = req("data.scripts.packets.SystemMessage")
 local packets = {}
 
 ---@param entity table The entity object
----@param packet table The packet data
+---@param char_id ObjectId The character's ObjectId
+---@param skill_list SkillList The entity's SkillList component
+---@param character_skills table List of skills from database
 ---@param skills_storage table The skills storage
-local function handle_enter_world(entity, packet, skills_storage)
-    if not skills_storage then
-        Logger.error("- EnterWorld - SkillsStorage not available - cannot process skill packet")
-        return
-    end
-    local char_id = world.get_component(entity, types.ObjectId)
-    local character_skills = SkillList.load_skills_from_db(char_id)
-
+local function load_skills(entity, char_id, skill_list, character_skills, skills_storage)
     local sub_class = world.get_component(entity, types.SubClass)
     local class_id = sub_class or world.get_component(entity, types.BaseClass)
     if not class_id then
@@ -40,7 +35,6 @@ local function handle_enter_world(entity, packet, skills_storage)
         return
     end
     local class_tree_nodes = skill_tree._1
-    local skill_list = world.get_component(entity, types.SkillList)
 
     -- If no skills in DB, grant auto-skills for character's level
     if table_count(character_skills) == 0 then
@@ -64,6 +58,25 @@ local function handle_enter_world(entity, packet, skills_storage)
     end
 end
 
+---@param entity table The entity object
+---@param packet table The packet data
+---@param skills_storage table The skills storage
+local function handle_enter_world(entity, packet, skills_storage)
+    if not skills_storage then
+        Logger.error("- EnterWorld - SkillsStorage not available - cannot process skill packet")
+        return
+    end
+    local char_id = world.get_component(entity, types.ObjectId)
+    SkillList.load_skills_from_db(char_id, function(character_skills)
+        -- The character may have left while the skills were loading
+        local exists, skill_list = pcall(world.get_component, entity, types.SkillList)
+        if not exists or not skill_list then
+            return
+        end
+        load_skills(entity, char_id, skill_list, character_skills, skills_storage)
+    end)
+end
+
 ---@param entity table The entity object
 ---@param packet table The packet data
 local function handle_cancel_target(entity, packet)
@@ -23,6 +23,7 @@ use game_core::{
     npc::{DialogRequest, SendNpcInfoDialog},
     object_id::ObjectIdManager,
     player_specific::next_intention::NextIntention,
+    recipe::{RecipeShop, RecipeShopVisit},
 };
 
 pub(crate) struct ActionPacketPlugin;
@@ -59,6 +60,7 @@ struct ActionHandleParams<'w, 's> {
             Has<Targetable>,
         ),
     >,
+    recipe_shops: Query<'w, 's, Ref<'static, RecipeShop>>,
     object_id_manager: Res<'w, ObjectIdManager>,
     commands: Commands<'w, 's>,
 }
@@ -116,6 +118,18 @@ fn handle_action_packet(
     if let Some(curr_selected) = selected_target
         && **curr_selected == packet_target_entity
     {
+        if !packet.shift_pressed
+            && params
+                .recipe_shops
+                .get(packet_target_entity)
+                .is_ok_and(|shop| shop.opened)
+        {
+            params
+                .commands
+                .trigger_targets(RecipeShopVisit(packet_target_entity), entity);
+            return Ok(());
+        }
+
         return handle_selected_target_action(
             packet.shift_pressed,
             entity,
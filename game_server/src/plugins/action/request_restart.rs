@@ -1,16 +1,22 @@
+use avian3d::prelude::*;
 use bevy::prelude::*;
 use bevy_slinet::server::PacketReceiveEvent;
 use game_core::{
+    attack::InCombat,
     character,
+    clan::castle::{CastleId, siege::Sieges},
     network::{
         config::GameServerNetworkConfig,
         packets::{
             client::GameClientPacket,
-            server::{GameServerPacket, Restart, SendCharSelectionInfo},
+            server::{GameServerPacket, Restart, SendCharSelectionInfo, SystemMessage},
         },
     },
 };
 use l2r_core::model::session::ServerSessions;
+use map::{Zone, ZoneKind};
+use physics::GameLayer;
+use system_messages::Id as SmId;
 
 pub(crate) struct RequestRestartPlugin;
 impl Plugin for RequestRestartPlugin {
@@ -23,6 +29,11 @@ fn handle(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     sessions: Res<ServerSessions>,
     mut character_tables: Query<Mut<character::Table>>,
+    in_combat: Query<(), With<InCombat>>,
+    transforms: Query<Ref<Transform>>,
+    zones: Query<Ref<Zone>>,
+    sieges: Res<Sieges>,
+    spatial_query: SpatialQuery,
     mut commands: Commands,
 ) -> Result<()> {
     let event = receive.event();
@@ -34,21 +45,60 @@ fn handle(
     let mut character_table = character_tables.get_mut(session_entity)?;
     let character_entity = character_table.character()?;
 
+    let refusal = if in_combat.contains(character_entity) {
+        Some(SmId::YouCannotRestartWhileInCombat)
+    } else if in_siege_castle(
+        transforms.get(character_entity)?.translation,
+        &spatial_query,
+        &zones,
+        &sieges,
+    ) {
+        Some(SmId::YouMayNotRestartInThisLocation)
+    } else {
+        None
+    };
+
+    if let Some(refusal) = refusal {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(refusal)),
+            character_entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(Restart::new(false)), session_entity);
+        return Ok(());
+    }
+
     commands.trigger_targets(character::CharacterSave, character_entity);
 
     commands.entity(character_entity).try_despawn();
 
     character_table.unset_character();
 
-    // TODO: check if restart is allowed (eg. not in combat, not in a siege, etc.)
-    let allowed = true;
-
-    commands.trigger_targets(
-        GameServerPacket::from(Restart::new(allowed)),
-        session_entity,
-    );
+    commands.trigger_targets(GameServerPacket::from(Restart::new(true)), session_entity);
 
     commands.trigger_targets(SendCharSelectionInfo, session_entity);
 
     Ok(())
 }
+
+/// Whether the position is inside a castle whose siege is in progress.
+fn in_siege_castle(
+    position: Vec3,
+    spatial_query: &SpatialQuery,
+    zones: &Query<Ref<Zone>>,
+    sieges: &Sieges,
+) -> bool {
+    let filter = SpatialQueryFilter::from_mask(GameLayer::Sensor);
+    spatial_query
+        .point_intersections(position, &filter)
+        .into_iter()
+        .filter_map(|entity| zones.get(entity).ok())
+        .filter_map(|zone| match zone.kind() {
+            ZoneKind::Castle(kind) => CastleId::try_from(kind.castle_id()).ok(),
+            _ => None,
+        })
+        .any(|castle_id| {
+            sieges
+                .get(&castle_id)
+                .is_some_and(|siege| siege.in_progress())
+        })
+}
@@ -3,6 +3,7 @@ use bevy::{ecs::relationship::Relationship, prelude::*};
 use bevy_slinet::server::PacketReceiveEvent;
 use game_core::{
     character::Character,
+    instance_zone::{InstanceExit, LeaveInstanceZone},
     network::{
         config::GameServerNetworkConfig,
         packets::{client::GameClientPacket, server::TeleportToLocation},
@@ -33,13 +34,20 @@ fn handle(
     world_map: Res<WorldMap>,
     respawn_zones: Query<(Ref<Zone>, Ref<Collider>, Ref<DespawnChildOf>), With<RespawnZone>>,
     spawn_points_zones: Query<Ref<Zone>, Or<(With<RespawnPoints>, With<Town>)>>,
-    characters: Query<(Ref<ObjectId>, Ref<Transform>), With<Character>>,
+    characters: Query<(Ref<ObjectId>, Ref<Transform>, Has<InstanceExit>), With<Character>>,
     mut commands: Commands,
 ) -> Result<()> {
     let event = receive.event();
     if let GameClientPacket::RequestRestartPoint(ref _packet) = event.packet {
         let character_entity = receive_params.character(&event.connection.id())?;
-        let (object_id, char_transform) = characters.get(character_entity)?;
+        let (object_id, char_transform, in_instance) = characters.get(character_entity)?;
+
+        // Characters who die inside of an instance are taken out of it
+        if in_instance {
+            commands.trigger_targets(Resurrect, character_entity);
+            commands.trigger_targets(LeaveInstanceZone, character_entity);
+            return Ok(());
+        }
 
         let region_id = RegionId::from(char_transform.translation);
         if let Some(region_entity) = world_map.get(&region_id).copied() {
@@ -0,0 +1,218 @@
+use super::authorize_audited;
+use axum::{Json, extract::Path, http::HeaderMap};
+use bevy::prelude::*;
+use bevy_defer::AsyncWorld;
+use game_core::{
+    account::Account,
+    character::{self, Character, Detached},
+    network::{
+        packets::server::TeleportToLocation,
+        session::{DisconnectSession, GameServerSession},
+    },
+    object_id::ObjectId,
+    teleport::TeleportType,
+};
+use l2r_core::{
+    admin::{AdminApiError, AdminApiResult, set_account_access_level},
+    model::{access_level::AccessLevel, capability::Capability, session::L2rSession},
+    plugins::custom_hierarchy::DespawnChildOf,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize)]
+pub(super) struct CharacterInfo {
+    object_id: ObjectId,
+    name: String,
+    account: Option<String>,
+    location: [f32; 3],
+    detached: bool,
+}
+
+#[derive(Serialize)]
+pub(super) struct SessionInfo {
+    id: usize,
+    account: Option<String>,
+    character: Option<String>,
+    ping_ms: Option<u128>,
+}
+
+#[derive(Serialize)]
+pub(super) struct AccountAction {
+    account: String,
+    kicked: usize,
+}
+
+#[derive(Deserialize)]
+pub(super) struct Location {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+pub(super) async fn list(headers: HeaderMap) -> AdminApiResult<Vec<CharacterInfo>> {
+    authorize_audited(&headers, Capability::Menu, "list characters")?;
+    let characters = AsyncWorld.run(|world| {
+        let accounts = world
+            .query::<(Entity, &Account)>()
+            .iter(world)
+            .map(|(entity, account)| (entity, account.name().to_string()))
+            .collect::<Vec<_>>();
+        world
+            .query_filtered::<(
+                &ObjectId,
+                &Name,
+                &Transform,
+                Option<&DespawnChildOf>,
+                Has<Detached>,
+            ), With<Character>>()
+            .iter(world)
+            .map(
+                |(object_id, name, transform, session, detached)| CharacterInfo {
+                    object_id: *object_id,
+                    name: name.to_string(),
+                    account: session.and_then(|session| {
+                        accounts
+                            .iter()
+                            .find(|(entity, _)| *entity == **session)
+                            .map(|(_, account)| account.clone())
+                    }),
+                    location: transform.translation.to_array(),
+                    detached,
+                },
+            )
+            .collect()
+    });
+    Ok(Json(characters))
+}
+
+pub(super) async fn sessions(headers: HeaderMap) -> AdminApiResult<Vec<SessionInfo>> {
+    authorize_audited(&headers, Capability::Menu, "list sessions")?;
+    let sessions = AsyncWorld.run(|world| {
+        let names = world
+            .query_filtered::<(Entity, &Name), With<Character>>()
+            .iter(world)
+            .map(|(entity, name)| (entity, name.to_string()))
+            .collect::<Vec<_>>();
+        world
+            .query::<(
+                &GameServerSession,
+                Option<&Account>,
+                Option<&character::Table>,
+            )>()
+            .iter(world)
+            .map(|(session, account, table)| SessionInfo {
+                id: *session.id(),
+                account: account.map(|account| account.name().to_string()),
+                character: table
+                    .and_then(|table| table.character().ok())
+                    .and_then(|character| {
+                        names
+                            .iter()
+                            .find(|(entity, _)| *entity == character)
+                            .map(|(_, name)| name.clone())
+                    }),
+                ping_ms: session.current_ping().map(|ping| ping.as_millis()),
+            })
+            .collect()
+    });
+    Ok(Json(sessions))
+}
+
+pub(super) async fn kick(headers: HeaderMap, Path(name): Path<String>) -> AdminApiResult<()> {
+    authorize_audited(&headers, Capability::Kick, format!("kick character {name}"))?;
+    let kicked = AsyncWorld.run(|world| {
+        let session_entity = world
+            .query_filtered::<(&Name, &DespawnChildOf), With<Character>>()
+            .iter(world)
+            .find(|(character_name, _)| character_name.as_str() == name)
+            .map(|(_, session)| **session)
+            .filter(|session_entity| world.get::<GameServerSession>(*session_entity).is_some());
+        if let Some(session_entity) = session_entity {
+            world.trigger_targets(DisconnectSession, session_entity);
+        }
+        session_entity.is_some()
+    });
+    if !kicked {
+        return Err(AdminApiError::NotFound(format!(
+            "Character {name} has no session"
+        )));
+    }
+    Ok(Json(()))
+}
+
+pub(super) async fn teleport(
+    headers: HeaderMap,
+    Path(name): Path<String>,
+    Json(location): Json<Location>,
+) -> AdminApiResult<()> {
+    authorize_audited(
+        &headers,
+        Capability::Teleport,
+        format!(
+            "teleport character {name} to {} {} {}",
+            location.x, location.y, location.z
+        ),
+    )?;
+    let found = AsyncWorld.run(|world| {
+        let character = world
+            .query_filtered::<(Entity, &ObjectId, &Name), With<Character>>()
+            .iter(world)
+            .find(|(_, _, character_name)| character_name.as_str() == name)
+            .map(|(entity, object_id, _)| (entity, *object_id));
+        if let Some((entity, object_id)) = character {
+            world.trigger_targets(
+                TeleportToLocation::new(
+                    object_id,
+                    Transform::from_xyz(location.x, location.y, location.z),
+                    TeleportType::default(),
+                ),
+                entity,
+            );
+        }
+        character.is_some()
+    });
+    if !found {
+        return Err(AdminApiError::NotFound(format!(
+            "Character {name} not found"
+        )));
+    }
+    Ok(Json(()))
+}
+
+/// Accounts are kept by the login server, the ban goes through the account access both servers share.
+pub(super) async fn ban(
+    headers: HeaderMap,
+    Path(name): Path<String>,
+) -> AdminApiResult<AccountAction> {
+    authorize_audited(&headers, Capability::Ban, format!("ban account {name}"))?;
+    set_account_access_level(&name, AccessLevel::Banned).await?;
+
+    let kicked = AsyncWorld.run(|world| {
+        let sessions: Vec<Entity> = world
+            .query_filtered::<(Entity, &Account), With<GameServerSession>>()
+            .iter(world)
+            .filter(|(_, account)| account.name() == name)
+            .map(|(session_entity, _)| session_entity)
+            .collect();
+        for session_entity in sessions.iter() {
+            world.trigger_targets(DisconnectSession, *session_entity);
+        }
+        sessions.len()
+    });
+    Ok(Json(AccountAction {
+        account: name,
+        kicked,
+    }))
+}
+
+pub(super) async fn unban(
+    headers: HeaderMap,
+    Path(name): Path<String>,
+) -> AdminApiResult<AccountAction> {
+    authorize_audited(&headers, Capability::Ban, format!("unban account {name}"))?;
+    set_account_access_level(&name, AccessLevel::Player).await?;
+    Ok(Json(AccountAction {
+        account: name,
+        kicked: 0,
+    }))
+}
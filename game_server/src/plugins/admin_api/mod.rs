@@ -0,0 +1,64 @@
+use axum::http::HeaderMap;
+use bevy::prelude::*;
+use bevy_defer::AsyncWorld;
+use config::Config;
+use game_core::admin_menu::audit::GmAction;
+use l2r_core::{
+    admin::{AdminApiAppExt, AdminApiError, AdminApiToken, authorize},
+    model::capability::Capability,
+};
+
+mod characters;
+mod server;
+
+/// Online characters, sessions and server control over the admin API,
+/// every call is dispatched into the world through [`bevy_defer::AsyncWorld`].
+pub(crate) struct GameAdminApiPlugin;
+impl Plugin for GameAdminApiPlugin {
+    fn build(&self, app: &mut App) {
+        app.admin_route("/characters", axum::routing::get(characters::list))
+            .admin_route("/sessions", axum::routing::get(characters::sessions))
+            .admin_route(
+                "/characters/{name}/kick",
+                axum::routing::post(characters::kick),
+            )
+            .admin_route(
+                "/characters/{name}/teleport",
+                axum::routing::post(characters::teleport),
+            )
+            .admin_route("/accounts/{name}/ban", axum::routing::post(characters::ban))
+            .admin_route(
+                "/accounts/{name}/unban",
+                axum::routing::post(characters::unban),
+            )
+            .admin_route("/announce", axum::routing::post(server::announce))
+            .admin_route("/pause", axum::routing::post(server::pause))
+            .admin_route("/shutdown", axum::routing::post(server::shutdown))
+            .admin_route(
+                "/shutdown/abort",
+                axum::routing::post(server::abort_shutdown),
+            )
+            .admin_route("/assets/reload", axum::routing::post(server::reload_assets));
+
+        app.add_systems(Update, sync_token.run_if(resource_changed::<Config>));
+    }
+}
+
+/// Token check of an admin API call, authorized calls go to the GM audit log.
+/// Refused ones are only logged, anyone can make them and they would flood the audit.
+fn authorize_audited(
+    headers: &HeaderMap,
+    capability: Capability,
+    action: impl Into<String>,
+) -> Result<(), AdminApiError> {
+    let action = action.into();
+    authorize(headers, &action)?;
+    AsyncWorld.apply_command(move |world: &mut World| {
+        world.trigger(GmAction::admin_api(capability, action));
+    });
+    Ok(())
+}
+
+fn sync_token(mut commands: Commands, config: Res<Config>) {
+    commands.insert_resource(AdminApiToken::new(config.admin_api().token.clone()));
+}
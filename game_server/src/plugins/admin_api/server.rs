@@ -0,0 +1,114 @@
+use super::authorize_audited;
+use crate::plugins::shutdown::{AbortShutdown, ScheduleShutdown, ShutdownMode};
+use axum::{Json, http::HeaderMap};
+use bevy::prelude::*;
+use bevy_defer::{AsyncAccess, AsyncWorld};
+use game_core::{
+    chat,
+    network::{
+        broadcast::{BroadcastScope, ServerPacketBroadcast},
+        packets::server::{CreatureSay, GameServerPacket},
+    },
+    object_id::ObjectId,
+};
+use l2r_core::{
+    admin::{AdminApiError, AdminApiResult},
+    model::capability::Capability,
+};
+use serde::Deserialize;
+use state::TogglePause;
+use std::time::Duration;
+
+#[derive(Deserialize)]
+pub(super) struct Announcement {
+    message: String,
+}
+
+#[derive(Deserialize)]
+pub(super) struct Countdown {
+    seconds: u64,
+    #[serde(default)]
+    restart: bool,
+}
+
+#[derive(Deserialize)]
+pub(super) struct AssetPath {
+    path: String,
+}
+
+pub(super) async fn announce(
+    headers: HeaderMap,
+    Json(announcement): Json<Announcement>,
+) -> AdminApiResult<()> {
+    authorize_audited(
+        &headers,
+        Capability::Announce,
+        format!("announce {:?}", announcement.message),
+    )?;
+    if announcement.message.is_empty() {
+        return Err(AdminApiError::BadRequest("Message is empty".to_string()));
+    }
+    AsyncWorld.apply_command(move |world: &mut World| {
+        world.trigger(ServerPacketBroadcast {
+            packet: GameServerPacket::from(CreatureSay::new(
+                ObjectId::default(),
+                "Server".to_string(),
+                vec![announcement.message],
+                chat::Kind::Announcement,
+                None,
+            )),
+            scope: BroadcastScope::All,
+        });
+    });
+    Ok(Json(()))
+}
+
+pub(super) async fn pause(headers: HeaderMap) -> AdminApiResult<()> {
+    authorize_audited(&headers, Capability::Pause, "toggle pause")?;
+    AsyncWorld.apply_command(|world: &mut World| world.trigger(TogglePause));
+    Ok(Json(()))
+}
+
+pub(super) async fn shutdown(
+    headers: HeaderMap,
+    Json(countdown): Json<Countdown>,
+) -> AdminApiResult<()> {
+    let mode = if countdown.restart {
+        ShutdownMode::Restart
+    } else {
+        ShutdownMode::Shutdown
+    };
+    authorize_audited(
+        &headers,
+        Capability::Shutdown,
+        format!("{} in {} seconds", mode, countdown.seconds),
+    )?;
+    AsyncWorld.apply_command(move |world: &mut World| {
+        world.trigger(ScheduleShutdown {
+            mode,
+            delay: Duration::from_secs(countdown.seconds),
+        })
+    });
+    Ok(Json(()))
+}
+
+pub(super) async fn abort_shutdown(headers: HeaderMap) -> AdminApiResult<()> {
+    authorize_audited(&headers, Capability::Shutdown, "abort shutdown")?;
+    AsyncWorld.apply_command(|world: &mut World| world.trigger(AbortShutdown));
+    Ok(Json(()))
+}
+
+pub(super) async fn reload_assets(
+    headers: HeaderMap,
+    Json(asset): Json<AssetPath>,
+) -> AdminApiResult<()> {
+    authorize_audited(
+        &headers,
+        Capability::ReloadAssets,
+        format!("reload assets {}", asset.path),
+    )?;
+    AsyncWorld
+        .resource::<AssetServer>()
+        .get(|asset_server| asset_server.reload(asset.path))?;
+    Ok(Json(()))
+}
@@ -16,8 +16,10 @@ mod multisell;
 mod pause;
 mod resurrect;
 mod set_level;
+mod shutdown;
 mod spawn_item;
 mod spawn_npc;
+mod status;
 mod teleport;
 
 #[derive(SystemParam)]
@@ -75,6 +77,11 @@ impl Plugin for AdminMenuCommandsPlugin {
                 CommandVariants::MultiSellList => app.add_observer(multisell::handle_list),
                 CommandVariants::AddSkill => app.add_observer(add_skill::handle),
                 CommandVariants::SetLevel => app.add_observer(set_level::handle),
+                CommandVariants::Noblesse => app.add_observer(status::handle_noblesse),
+                CommandVariants::Hero => app.add_observer(status::handle_hero),
+                CommandVariants::Shutdown => app.add_observer(shutdown::handle_shutdown),
+                CommandVariants::Restart => app.add_observer(shutdown::handle_restart),
+                CommandVariants::AbortShutdown => app.add_observer(shutdown::handle_abort),
             };
         }
     }
@@ -0,0 +1,88 @@
+use super::AdminCommandQuery;
+use crate::plugins::shutdown::{AbortShutdown, ScheduleShutdown, ShutdownMode};
+use bevy::{log, prelude::*};
+use game_core::{
+    admin_menu::{AdminMenuCommand, LastAdminMenuPage},
+    network::packets::client::{BypassCommand, BypassCommandExecuted},
+};
+use std::time::Duration;
+
+pub(super) fn handle_shutdown(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    admin_query: AdminCommandQuery,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+    let entity = trigger.target();
+    admin_query.validate_gm(entity)?;
+    if let BypassCommand::Admin(AdminMenuCommand::Shutdown(seconds)) = cmd {
+        schedule(
+            &mut commands,
+            &admin_query,
+            entity,
+            ShutdownMode::Shutdown,
+            *seconds,
+        )?;
+    }
+    Ok(())
+}
+
+pub(super) fn handle_restart(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    admin_query: AdminCommandQuery,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+    let entity = trigger.target();
+    admin_query.validate_gm(entity)?;
+    if let BypassCommand::Admin(AdminMenuCommand::Restart(seconds)) = cmd {
+        schedule(
+            &mut commands,
+            &admin_query,
+            entity,
+            ShutdownMode::Restart,
+            *seconds,
+        )?;
+    }
+    Ok(())
+}
+
+pub(super) fn handle_abort(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    admin_query: AdminCommandQuery,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+    let entity = trigger.target();
+    admin_query.validate_gm(entity)?;
+    if let BypassCommand::Admin(AdminMenuCommand::AbortShutdown) = cmd {
+        log::info!(
+            "{} aborted the shutdown",
+            admin_query.account(entity)?.name()
+        );
+        commands.trigger(AbortShutdown);
+        commands.trigger_targets(LastAdminMenuPage, entity);
+    }
+    Ok(())
+}
+
+fn schedule(
+    commands: &mut Commands,
+    admin_query: &AdminCommandQuery,
+    entity: Entity,
+    mode: ShutdownMode,
+    seconds: u32,
+) -> Result<()> {
+    log::info!(
+        "{} scheduled {} in {} seconds",
+        admin_query.account(entity)?.name(),
+        mode,
+        seconds
+    );
+    commands.trigger(ScheduleShutdown {
+        mode,
+        delay: Duration::from_secs(seconds.into()),
+    });
+    commands.trigger_targets(LastAdminMenuPage, entity);
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use super::AdminCommandQuery;
+use bevy::prelude::*;
+use game_core::{
+    admin_menu::{AdminMenuCommand, LastAdminMenuPage},
+    character::{Hero, Noblesse, SetHero, SetNoblesse},
+    network::packets::client::{BypassCommand, BypassCommandExecuted},
+};
+
+pub(super) fn handle_noblesse(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    admin_query: AdminCommandQuery,
+    nobles: Query<(), With<Noblesse>>,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+    let entity = trigger.target();
+    if let BypassCommand::Admin(AdminMenuCommand::Noblesse) = cmd {
+        let (_, selected_target) = admin_query.validate_gm(entity)?;
+        let noblesse = !nobles.contains(selected_target);
+        commands.trigger_targets(SetNoblesse(noblesse), selected_target);
+        commands.trigger_targets(LastAdminMenuPage, entity);
+    }
+    Ok(())
+}
+
+pub(super) fn handle_hero(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    admin_query: AdminCommandQuery,
+    heroes: Query<(), With<Hero>>,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+    let entity = trigger.target();
+    if let BypassCommand::Admin(AdminMenuCommand::Hero) = cmd {
+        let (_, selected_target) = admin_query.validate_gm(entity)?;
+        let hero = !heroes.contains(selected_target);
+        commands.trigger_targets(SetHero(hero), selected_target);
+        commands.trigger_targets(LastAdminMenuPage, entity);
+    }
+    Ok(())
+}
@@ -15,6 +15,9 @@ use state::{GameServerStateSystems, LoadingSystems};
 use std::path::PathBuf;
 
 mod commands;
+mod permissions;
+
+pub(crate) use permissions::GmPermissions;
 
 pub struct AdminMenuPlugin;
 impl Plugin for AdminMenuPlugin {
@@ -35,7 +38,8 @@ impl Plugin for AdminMenuPlugin {
         );
 
         app.add_observer(handle_last_admin_menu_page);
-        app.add_plugins(commands::AdminMenuCommandsPlugin);
+        app.add_plugins(commands::AdminMenuCommandsPlugin)
+            .add_plugins(permissions::GmAuditPlugin);
     }
 }
 
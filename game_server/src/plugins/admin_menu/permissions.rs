@@ -0,0 +1,91 @@
+use bevy::{log, prelude::*};
+use bevy_defer::AsyncCommandsExtension;
+use bevy_ecs::system::SystemParam;
+use config::Config;
+use game_core::{account::Account, admin_menu::audit::GmAction, object_id::ObjectId};
+use l2r_core::{
+    db::DbConnection, model::capability::Capability, plugins::custom_hierarchy::DespawnChildOf,
+};
+
+/// Writes every [`GmAction`] to the `gm_audit_log` table.
+pub(super) struct GmAuditPlugin;
+impl Plugin for GmAuditPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(record_gm_action);
+    }
+}
+
+/// Checks GM commands against the capabilities the config grants to the account access level.
+#[derive(SystemParam)]
+pub(crate) struct GmPermissions<'w, 's> {
+    config: Res<'w, Config>,
+    accounts: Query<'w, 's, Ref<'static, Account>>,
+    characters: Query<
+        'w,
+        's,
+        (
+            Ref<'static, DespawnChildOf>,
+            Ref<'static, ObjectId>,
+            Ref<'static, Name>,
+        ),
+    >,
+    commands: Commands<'w, 's>,
+}
+
+impl<'w, 's> GmPermissions<'w, 's> {
+    /// Returns whether the character may run the command, the attempt is audited either way.
+    pub fn authorize(
+        &mut self,
+        entity: Entity,
+        capability: Capability,
+        action: impl Into<String>,
+    ) -> bool {
+        let Ok((session, object_id, name)) = self.characters.get(entity) else {
+            return false;
+        };
+        let Ok(account) = self.accounts.get(**session) else {
+            return false;
+        };
+        let access_level = account.access();
+        let allowed = self.config.permissions().allows(access_level, capability);
+        let action = action.into();
+        if !allowed {
+            log::warn!(
+                "{} of account {:?} ({}) is not allowed to {}: {}",
+                name,
+                account.name(),
+                access_level,
+                capability,
+                action
+            );
+        }
+        self.commands.trigger(GmAction {
+            account: account.name().to_string(),
+            access_level,
+            character_id: *object_id,
+            character_name: name.to_string(),
+            capability,
+            action,
+            allowed,
+        });
+        allowed
+    }
+}
+
+fn record_gm_action(
+    action: Trigger<GmAction>,
+    mut commands: Commands,
+    db_connection: Res<DbConnection>,
+) {
+    if db_connection.is_mock() {
+        return;
+    }
+    let action = action.event().clone();
+    let conn = db_connection.connection();
+    commands.spawn_task(move || async move {
+        if let Err(err) = action.record(&conn).await {
+            log::error!("Failed to write the GM audit log: {:?}", err);
+        }
+        Ok(())
+    });
+}
@@ -5,10 +5,11 @@ use bevy::{
 use game_core::{
     abnormal_effects::AbnormalEffects,
     attack::{AttackHit, Attacking, Dead, DeadTimer, DeathComponentsPlugin, InCombat},
-    character::{Character, CharacterSave},
+    character::{Character, CharacterSave, NOBLESSE_BLESSING},
     network::{broadcast::ServerPacketBroadcast, packets::server::Die},
     npc::{GenerateDropRequest, NpcQuery},
     object_id::ObjectId,
+    olympiad::OlympiadParticipant,
     spawner::Spawner,
     stats::{ProgressLevelStats, ProgressRatesStats, ProgressStats, VitalsStat, VitalsStats},
 };
@@ -60,7 +61,7 @@ fn check_alive(
 fn death(
     death: Trigger<Dead>,
     mut commands: Commands,
-    players: Query<Ref<ObjectId>, With<Character>>,
+    players: Query<(Ref<ObjectId>, Has<OlympiadParticipant>), With<Character>>,
     mut progress_stats: Query<(
         Ref<ProgressLevelStats>,
         Ref<ProgressRatesStats>,
@@ -79,13 +80,24 @@ fn death(
         .insert(*event)
         .remove::<(Attacking, AttackHit, InCombat)>();
 
-    if let Ok(mut effects) = abnormal_effects.get_mut(entity) {
+    // Blessing of Noblesse is spent to keep the rest of the effects
+    if let Ok(mut effects) = abnormal_effects.get_mut(entity)
+        && !effects.remove_by_skill_id(NOBLESSE_BLESSING)
+    {
         effects.remove_all();
     }
 
-    if let Ok(char_oid) = players.get(entity)
+    if let Ok((char_oid, in_olympiad)) = players.get(entity)
         && let Ok((p_level, _, mut p_stats)) = progress_stats.get_mut(entity)
     {
+        // Olympiad opponents lose nothing and are brought back once the match is over
+        if in_olympiad {
+            commands.trigger_targets(
+                ServerPacketBroadcast::new(Die::new(*char_oid).into()),
+                entity,
+            );
+            return;
+        }
         // TODO: make exp loss to respect progress rates based
         // on who killed the character (pvp, pve, raid)
         p_stats.exp_lost(1.0, p_level.level());
@@ -1,3 +1,4 @@
+use crate::plugins::shutdown::ShutdownCountdown;
 use bevy::{log, prelude::*};
 use bevy_defer::{AccessError, AsyncAccess, AsyncCommandsExtension, AsyncWorld};
 use bevy_slinet::server::PacketReceiveEvent;
@@ -10,7 +11,7 @@ use game_core::{
             client::{AuthLoginRequest, GameClientPacket},
             server::{CharSelectionInfo, GameServerPacket},
         },
-        session::{GameServerSession, PacketReceiveParams},
+        session::{DisconnectSession, GameServerSession, PacketReceiveParams},
     },
     object_id::ObjectId,
 };
@@ -31,6 +32,7 @@ impl Plugin for AuthLoginRequestPlugin {
 fn handle(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     receive_params: PacketReceiveParams,
+    shutdown_countdown: Option<Res<ShutdownCountdown>>,
     mut commands: Commands,
 ) -> Result<()> {
     let event = receive.event();
@@ -40,6 +42,15 @@ fn handle(
     };
 
     let session_entity = receive_params.session(&event.connection.id())?;
+    if shutdown_countdown.is_some_and(|countdown| countdown.logins_closed()) {
+        log::info!(
+            "Login of {} refused, the server is shutting down",
+            packet.account
+        );
+        commands.trigger_targets(DisconnectSession, session_entity);
+        return Ok(());
+    }
+
     let packet = packet.clone();
     commands.spawn_task(async move || login_request_task(packet, session_entity).await);
     Ok(())
@@ -65,17 +76,24 @@ async fn login_request_task(packet: AuthLoginRequest, entity: Entity) -> Result<
                     Ok(session_account) => Ok(session_account),
                     Err(err) => {
                         log::error!("#{:?} Failed to deserialize session: {:#?}", session, err);
-                        session.disconnect();
                         Err(AccessError::Custom("Failed to deserialize session"))
                     }
                 },
                 Err(err) => {
                     log::error!("#{:?} Redis error: {:#?}", session, err);
-                    session.disconnect();
                     Err(AccessError::Custom("Redis error"))
                 }
             }
-        })??;
+        })?;
+    let session_account = match session_account {
+        Ok(session_account) => session_account,
+        Err(err) => {
+            AsyncWorld.apply_command(move |world: &mut World| {
+                world.trigger_targets(DisconnectSession, entity);
+            });
+            return Err(err);
+        }
+    };
 
     let account_id = session_account.id;
     let account_name = packet.account.clone();
@@ -109,10 +127,12 @@ async fn login_request_task(packet: AuthLoginRequest, entity: Entity) -> Result<
             existing_sessions.len()
         );
 
-        for (_existing_entity, existing_session, _char_entity_opt) in existing_sessions {
-            // Disconnect the old session (this will trigger despawn of session entity)
-            existing_session.disconnect();
-        }
+        AsyncWorld.run(|world| {
+            for (existing_entity, _existing_session, _char_entity_opt) in existing_sessions {
+                // Disconnect the old session (this will trigger despawn of session entity)
+                world.trigger_targets(DisconnectSession, existing_entity);
+            }
+        });
     }
 
     // Create account and add to entity
@@ -189,7 +209,7 @@ async fn login_request_task(packet: AuthLoginRequest, entity: Entity) -> Result<
             }
             Err(err) => {
                 log::error!("Failed to create chars table: {}, disconnecting.", err);
-                session.disconnect();
+                world.trigger_targets(DisconnectSession, entity);
             }
         }
     });
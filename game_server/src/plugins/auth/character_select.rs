@@ -1,21 +1,27 @@
+use crate::plugins::character::save_detached_until;
 use bevy::{log, prelude::*};
 use bevy_slinet::server::PacketReceiveEvent;
 use game_core::{
-    character,
+    account::Account,
+    character::{self, Detached, Linkdead, Reattached},
     items::InventoryLoad,
     network::{
         config::GameServerNetworkConfig,
         packets::{
             client::GameClientPacket,
-            server::{CharacterSelected, GameServerPacket, SSQInfo},
+            server::{CharacterSelected, GameServerPacket, SSQInfo, SystemMessage},
         },
         session::GameServerSession,
     },
+    object_id::ObjectId,
+    seven_signs::SevenSigns,
 };
 use l2r_core::{
+    db::RepositoryManager,
     model::session::{L2rSession, ServerSessions},
     plugins::custom_hierarchy::DespawnChildOf,
 };
+use system_messages::Id as SmId;
 
 pub(crate) struct CharacterSelectPlugin;
 impl Plugin for CharacterSelectPlugin {
@@ -28,15 +34,48 @@ fn handle(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     sessions: Res<ServerSessions>,
     mut commands: Commands,
-    mut query: Query<(Ref<GameServerSession>, Mut<character::Table>)>,
+    mut query: Query<(Ref<GameServerSession>, Ref<Account>, Mut<character::Table>)>,
+    left_in_world: Query<
+        (
+            Entity,
+            Ref<ObjectId>,
+            Option<Ref<Linkdead>>,
+            Option<Ref<Detached>>,
+        ),
+        Or<(With<Linkdead>, With<Detached>)>,
+    >,
+    repo_manager: Res<RepositoryManager>,
     mut inventory_load: EventWriter<InventoryLoad>,
+    seven_signs: Option<Res<SevenSigns>>,
 ) -> Result<()> {
     let event = receive.event();
     let GameClientPacket::CharacterSelect(ref packet) = event.packet else {
         return Ok(());
     };
     let session_entity = sessions.by_connection(&event.connection.id())?;
-    let (session, mut char_table) = query.get_mut(session_entity)?;
+    let (session, account, mut char_table) = query.get_mut(session_entity)?;
+
+    char_table.select(packet.char_slot)?;
+    let selected_char = char_table.get_bundle()?.clone();
+    let char_selected = CharacterSelected::new(&selected_char, session.id());
+
+    // Only the linkdead character can be picked up, another one would play alongside it
+    let linkdead_other = left_in_world.iter().any(|(_, object_id, linkdead, _)| {
+        *object_id != selected_char.id
+            && linkdead.is_some_and(|linkdead| linkdead.account_id() == account.id())
+    });
+    if linkdead_other {
+        log::warn!(
+            "Refusing character {:?} for session {:?}, another character of the account is linkdead",
+            selected_char.id,
+            session.id()
+        );
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(SmId::YouAreAlreadyLoggedIn)),
+            session_entity,
+        );
+        return Ok(());
+    }
 
     // Despawn existing character if one is already active for this session
     if let Ok(existing_char_entity) = char_table.character() {
@@ -49,16 +88,47 @@ fn handle(
         char_table.unset_character();
     }
 
-    char_table.select(packet.char_slot)?;
-    let selected_char = char_table.get_bundle()?.clone();
-    let char_selected = CharacterSelected::new(&selected_char, session.id());
-    let char_entity = selected_char.spawn(commands.reborrow());
+    // The character may still be in the world after the connection dropped or was detached
+    let left_char = left_in_world
+        .iter()
+        .find(|(_, object_id, linkdead, detached)| {
+            let account_id = linkdead
+                .map(|linkdead| linkdead.account_id())
+                .or_else(|| detached.map(|detached| detached.account_id()));
+            *object_id == selected_char.id && account_id == Some(account.id())
+        })
+        .map(|(entity, _, _, detached)| (entity, detached.is_some()));
+
+    let char_entity = match left_char {
+        Some((char_entity, detached)) => {
+            log::debug!(
+                "Reattaching character {:?} to session {:?}",
+                char_entity,
+                session.id()
+            );
+            if detached {
+                save_detached_until(&mut commands, &repo_manager, selected_char.id, None)?;
+            }
+            commands
+                .entity(char_entity)
+                .remove::<(Linkdead, Detached)>()
+                .insert((session.id(), Reattached));
+            char_entity
+        }
+        None => {
+            let char_entity = selected_char.spawn(commands.reborrow());
+            inventory_load.write(InventoryLoad::from(char_entity));
+            char_entity
+        }
+    };
     char_table.set_character(char_entity);
     commands
         .entity(char_entity)
         .insert(DespawnChildOf(session_entity));
-    commands.trigger_targets(GameServerPacket::from(SSQInfo::default()), session_entity);
+    let sky = seven_signs
+        .map(|seven_signs| seven_signs.sky())
+        .unwrap_or_default();
+    commands.trigger_targets(GameServerPacket::from(SSQInfo::new(sky)), session_entity);
     commands.trigger_targets(GameServerPacket::from(char_selected), session_entity);
-    inventory_load.write(InventoryLoad::from(char_entity));
     Ok(())
 }
@@ -1,8 +1,10 @@
 use bevy::{log, prelude::*};
+use bevy_defer::AsyncCommandsExtension;
 use bevy_slinet::server::PacketReceiveEvent;
 use game_core::{
     attack::Dead,
-    character,
+    character::{self, Reattached},
+    encounters::{KnownBy, KnownEntities},
     network::{
         config::GameServerNetworkConfig,
         packets::{client::GameClientPacket, server::*},
@@ -23,6 +25,8 @@ fn handle(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     receive_params: PacketReceiveParams,
     characters: Query<character::Query>,
+    mut reattached: Query<Mut<KnownEntities>, With<Reattached>>,
+    mut known_by: Query<Mut<KnownBy>>,
     mut commands: Commands,
 ) -> Result<()> {
     let event = receive.event();
@@ -58,5 +62,22 @@ fn handle(
         .entity(char_entity)
         .insert(game_core::encounters::EnteredWorld);
 
+    // Character was linkdead, the new client knows nothing about the world around it
+    if let Ok(mut known_entities) = reattached.get_mut(char_entity) {
+        for known in known_entities.drain() {
+            if let Ok(mut known_by) = known_by.get_mut(known) {
+                known_by.remove(&char_entity);
+            }
+        }
+        commands.entity(char_entity).remove::<Reattached>();
+        commands.trigger_targets(SendUserInfo, char_entity);
+        commands.spawn_task(move || async move {
+            crate::plugins::shortcuts::shortcut_init_task(char_entity).await
+        });
+        commands.spawn_task(move || async move {
+            crate::plugins::macros::macro_list_init_task(char_entity).await
+        });
+    }
+
     Ok(())
 }
@@ -0,0 +1,25 @@
+use bevy::{log, prelude::*};
+use game_core::character::{CharacterSave, Linkdead};
+use state::GameServerStateSystems;
+
+/// Linkdead characters left in the world are saved and despawned when their time is over
+pub(crate) struct LinkdeadPlugin;
+impl Plugin for LinkdeadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, linkdead_timeout.in_set(GameServerStateSystems::Run));
+    }
+}
+
+fn linkdead_timeout(
+    time: Res<Time>,
+    mut characters: Query<(Entity, Mut<Linkdead>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut linkdead) in characters.iter_mut() {
+        if linkdead.tick(time.delta()) {
+            log::debug!("Linkdead character {:?} left the world", entity);
+            commands.trigger_targets(CharacterSave, entity);
+            commands.entity(entity).try_despawn();
+        }
+    }
+}
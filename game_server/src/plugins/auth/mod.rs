@@ -3,6 +3,7 @@ use bevy::prelude::*;
 mod auth_login;
 mod character_select;
 mod enter_world;
+mod linkdead;
 mod net_ping;
 mod protocol_verision;
 mod request_logout;
@@ -17,6 +18,7 @@ impl Plugin for AuthPlugin {
             enter_world::EnterWorldPlugin,
             character_select::CharacterSelectPlugin,
             request_logout::RequestLogoutPlugin,
+            linkdead::LinkdeadPlugin,
         ));
     }
 }
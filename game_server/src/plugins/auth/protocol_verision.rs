@@ -7,9 +7,8 @@ use game_core::network::{
         server::{GameServerPacket, KeyPacket},
     },
     protocol,
-    session::{GameServerSession, PacketReceiveParams},
+    session::{DisconnectSession, PacketReceiveParams},
 };
-use l2r_core::model::session::L2rSession;
 
 pub(crate) struct ClientProtocolVersionPlugin;
 
@@ -22,18 +21,16 @@ impl Plugin for ClientProtocolVersionPlugin {
 fn handle(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     receive_params: PacketReceiveParams,
-    mut game_sessions: Query<Ref<GameServerSession>>,
     mut commands: Commands,
 ) -> Result<()> {
     let event = receive.event();
 
     if let GameClientPacket::ProtocolVersion(ref packet) = event.packet {
         let session_entity = receive_params.session(&event.connection.id())?;
-        let session = game_sessions.get_mut(session_entity)?;
         
         match packet.protocol_version {
             protocol::Version::Unknown => {
-                session.disconnect();
+                commands.trigger_targets(DisconnectSession, session_entity);
             }
             _ => {
                 commands.trigger_targets(GameServerPacket::from(KeyPacket::new()), session_entity);
@@ -1,13 +1,17 @@
 use bevy::prelude::*;
 use bevy_slinet::server::PacketReceiveEvent;
-use game_core::network::{
-    config::GameServerNetworkConfig,
-    packets::{
-        client::GameClientPacket,
-        server::{GameServerPacket, LogoutOk},
+use game_core::{
+    attack::InCombat,
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{ActionFail, GameServerPacket, LogoutOk, SystemMessage},
+        },
+        session::PacketReceiveParams,
     },
-    session::PacketReceiveParams,
 };
+use system_messages::Id as SmId;
 
 pub(crate) struct RequestLogoutPlugin;
 
@@ -20,10 +24,24 @@ impl Plugin for RequestLogoutPlugin {
 fn handle(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     receive_params: PacketReceiveParams,
+    in_combat: Query<(), With<InCombat>>,
     mut commands: Commands,
 ) -> Result<()> {
     let event = receive.event();
     if let GameClientPacket::RequestLogout = event.packet {
+        if let Ok(character) = receive_params.character(&event.connection.id())
+            && in_combat.contains(character)
+        {
+            commands.trigger_targets(
+                GameServerPacket::from(SystemMessage::new_empty(
+                    SmId::YouCannotExitTheGameWhileInCombat,
+                )),
+                character,
+            );
+            commands.trigger_targets(GameServerPacket::from(ActionFail), character);
+            return Ok(());
+        }
+
         if let Ok(session) = receive_params.session(&event.connection.id()) {
             commands.trigger_targets(GameServerPacket::from(LogoutOk), session);
         }
@@ -0,0 +1,81 @@
+use bevy::{log, prelude::*};
+use game_core::clan::castle::{CastleData, CastleDataHandle};
+use l2r_core::chronicles::CHRONICLE;
+use state::{GameServerStateSystems, LoadingSystems};
+use std::path::PathBuf;
+
+pub struct CastleDataPlugin;
+impl Plugin for CastleDataPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CastleDataHandle>();
+
+        app.add_systems(
+            Update,
+            (
+                load_assets.in_set(LoadingSystems::AssetInit),
+                update_assets.in_set(LoadingSystems::AssetInit),
+            ),
+        );
+
+        app.add_systems(Update, update_assets.in_set(GameServerStateSystems::Run));
+    }
+}
+
+fn load_assets(
+    asset_server: Res<AssetServer>,
+    mut castle_data_handle: ResMut<CastleDataHandle>,
+    mut loaded: Local<bool>,
+) {
+    if *loaded {
+        return;
+    }
+    let mut path = PathBuf::from("castle");
+    path.push(CHRONICLE);
+    path.push("castles");
+    path.set_extension("json");
+
+    let handle: Handle<CastleData> = asset_server.load(path.clone());
+    **castle_data_handle = handle;
+    *loaded = true;
+}
+
+fn update_assets(handle: Res<CastleDataHandle>, mut events: EventReader<AssetEvent<CastleData>>) {
+    for event in events.read() {
+        if event.is_loaded_with_dependencies(handle.id()) {
+            log::info!("Castle data updated");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_core::clan::castle::CastleId;
+    use l2r_core::{assets::ASSET_DIR, chronicles::CHRONICLE, utils::get_base_path};
+    use sea_orm::Iterable;
+    use serde_json::from_reader;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_parse_all_from_json() {
+        let mut path = get_base_path();
+        path.push(ASSET_DIR);
+        path.push("castle");
+        path.push(CHRONICLE);
+        path.push("castles");
+        path.set_extension("json");
+
+        let file = File::open(&path).unwrap_or_else(|_| panic!("Failed to open file: {:?}", path));
+        let reader = BufReader::new(file);
+
+        let result: CastleData = from_reader(reader)
+            .unwrap_or_else(|_| panic!("Failed to parse castle data from JSON: {:?}", path));
+
+        for castle_id in CastleId::iter() {
+            assert!(
+                result.contains_key(&castle_id),
+                "No castle data for {castle_id}"
+            );
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use bevy::{ecs::system::SystemParam, log, platform::collections::HashMap, prelude::*};
+use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use chrono::Utc;
+use game_core::{
+    character::Character,
+    clan::{
+        Clans,
+        castle::{
+            self, CastleData, CastleDataHandle, CastleError, CastleId, CastleModel, CastleNpcs,
+            Castles,
+            siege::{Siege, SiegeClan, Sieges, model::SiegeClanPK},
+        },
+    },
+    object_id::ObjectId,
+};
+use l2r_core::db::{
+    DbConnection, PrimaryKeyColumns, Repository, RepositoryManager, TypedRepositoryManager,
+    UpdatableModel,
+};
+use map::{Zone, ZoneKind};
+use sea_orm::{Condition, Iterable, sea_query::OnConflict};
+use spatial::FlatDistance;
+use state::LoadingSystems;
+
+mod data;
+
+pub(crate) mod siege;
+
+/// Castles are loaded at startup, missing ones are created without an owner.
+/// Sieges for them are held every two weeks, see [`siege::SiegePlugin`].
+/// Purchases in the castle territory are taxed into the treasury of the castle,
+/// the owner manages them through the chamberlain, see [`CastleQuery`].
+pub(crate) struct CastlePlugin;
+impl Plugin for CastlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(data::CastleDataPlugin)
+            .add_plugins(siege::SiegePlugin);
+
+        app.add_systems(Update, load_castles.in_set(LoadingSystems::IdInit));
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct CastleQuery<'w, 's> {
+    castles: ResMut<'w, Castles>,
+    sieges: Res<'w, Sieges>,
+    clans: Res<'w, Clans>,
+    characters: Query<'w, 's, Ref<'static, ObjectId>, With<Character>>,
+    castle_zones: Query<'w, 's, Ref<'static, Zone>, With<map::Castle>>,
+    repo_manager: Res<'w, RepositoryManager>,
+    data_handle: Res<'w, CastleDataHandle>,
+    data_assets: Res<'w, Assets<CastleData>>,
+}
+
+impl CastleQuery<'_, '_> {
+    pub fn data(&self) -> Result<&CastleData> {
+        self.data_assets
+            .get(self.data_handle.id())
+            .ok_or_else(|| BevyError::from("Castle data is not loaded"))
+    }
+
+    pub fn castle_npcs(&self, castle_id: CastleId) -> Result<&CastleNpcs> {
+        self.data()?
+            .get(&castle_id)
+            .ok_or_else(|| BevyError::from(format!("No castle data for {castle_id}")))
+    }
+
+    pub fn castle(&self, castle_id: CastleId) -> Result<&CastleModel> {
+        self.castles
+            .get(&castle_id)
+            .ok_or_else(|| BevyError::from(format!("Castle {castle_id} is not loaded")))
+    }
+
+    /// Whether the character leads the clan owning the castle.
+    pub fn is_owner(&self, entity: Entity, castle_id: CastleId) -> Result<bool> {
+        let char_id = self.characters.get(entity)?;
+        Ok(self
+            .castle(castle_id)?
+            .owner_clan_id
+            .and_then(|clan_id| self.clans.get(&clan_id))
+            .is_some_and(|clan| clan.leader_id == *char_id))
+    }
+
+    pub fn siege_in_progress(&self, castle_id: CastleId) -> bool {
+        self.sieges
+            .get(&castle_id)
+            .is_some_and(|siege| siege.in_progress())
+    }
+
+    /// Castle whose territory the position belongs to, that is the one with the nearest castle zone.
+    pub fn territory_of(&self, position: Vec3) -> Option<CastleId> {
+        self.castle_zones
+            .iter()
+            .filter_map(|zone| match zone.kind() {
+                ZoneKind::Castle(kind) => CastleId::try_from(kind.castle_id())
+                    .ok()
+                    .map(|castle_id| (castle_id, zone.center().flat_distance(&position))),
+                _ => None,
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(castle_id, _)| castle_id)
+    }
+
+    /// Tax of the castle territory added to the price.
+    pub fn tax(&self, castle_id: Option<CastleId>, price: u64) -> u64 {
+        castle_id
+            .and_then(|castle_id| self.castles.get(&castle_id))
+            .map(|castle| castle.tax(price))
+            .unwrap_or_default()
+    }
+
+    /// Puts adena into the treasury, collected taxes and manor incomes go there.
+    pub fn deposit(
+        &mut self,
+        commands: &mut Commands,
+        castle_id: CastleId,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let castle = self.castle_mut(castle_id)?;
+        castle.deposit(amount);
+        let castle = castle.clone();
+        save_castle(&self.repo_manager, commands, castle)
+    }
+
+    pub fn set_tax_percent(
+        &mut self,
+        commands: &mut Commands,
+        castle_id: CastleId,
+        tax_percent: i32,
+    ) -> Result<Result<(), CastleError>> {
+        let castle = self.castle_mut(castle_id)?;
+        if let Err(err) = castle.set_tax_percent(tax_percent) {
+            return Ok(Err(err));
+        }
+        let castle = castle.clone();
+        save_castle(&self.repo_manager, commands, castle)?;
+        Ok(Ok(()))
+    }
+
+    pub fn withdraw(
+        &mut self,
+        commands: &mut Commands,
+        castle_id: CastleId,
+        amount: u64,
+    ) -> Result<Result<(), CastleError>> {
+        let castle = self.castle_mut(castle_id)?;
+        if let Err(err) = castle.withdraw(amount) {
+            return Ok(Err(err));
+        }
+        let castle = castle.clone();
+        save_castle(&self.repo_manager, commands, castle)?;
+        Ok(Ok(()))
+    }
+
+    fn castle_mut(&mut self, castle_id: CastleId) -> Result<&mut CastleModel> {
+        self.castles
+            .get_mut(&castle_id)
+            .ok_or_else(|| BevyError::from(format!("Castle {castle_id} is not loaded")))
+    }
+}
+
+fn load_castles(
+    mut commands: Commands,
+    repo_manager: Res<RepositoryManager>,
+    db_connection: Res<DbConnection>,
+    mut loaded: Local<bool>,
+) -> Result<()> {
+    if *loaded {
+        return Ok(());
+    }
+    *loaded = true;
+
+    let now = Utc::now().naive_utc();
+
+    if db_connection.is_mock() {
+        let mut castles = Castles::default();
+        for castle_id in CastleId::iter() {
+            castles.insert(castle_id, CastleModel::with_next_siege(castle_id, now));
+        }
+        commands.insert_resource(castles);
+        return Ok(());
+    }
+
+    let castle_repository = repo_manager.typed::<i32, castle::model::Entity>()?;
+    let siege_repository = repo_manager.typed::<SiegeClanPK, castle::siege::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        let mut castles = Castles::default();
+        for model in castle_repository
+            .find_with_conditions([Condition::all()])
+            .await?
+        {
+            if let Some(castle_id) = model.castle_id() {
+                castles.insert(castle_id, model);
+            }
+        }
+
+        for castle_id in CastleId::iter() {
+            let castle = castles
+                .entry(castle_id)
+                .or_insert_with(|| CastleModel::with_next_siege(castle_id, now));
+
+            // Siege was missed while the server was down
+            if castle.siege_end() <= now {
+                castle.schedule_next_siege(now);
+            }
+
+            castle_repository
+                .create_or_update(castle, castle_on_conflict())
+                .await?;
+        }
+
+        let mut siege_clans: HashMap<CastleId, Vec<SiegeClan>> = HashMap::new();
+        for model in siege_repository
+            .find_with_conditions([Condition::all()])
+            .await?
+        {
+            if let Some(castle_id) = u8::try_from(model.castle_id)
+                .ok()
+                .and_then(|id| CastleId::try_from(id).ok())
+            {
+                siege_clans
+                    .entry(castle_id)
+                    .or_default()
+                    .push(SiegeClan::from(model));
+            }
+        }
+
+        let mut sieges = Sieges::default();
+        for (castle_id, clans) in siege_clans {
+            sieges.insert(castle_id, Siege::new(clans));
+        }
+
+        log::info!("Loaded {} castles from database.", castles.len());
+        AsyncWorld.apply_command(move |world: &mut World| {
+            world.insert_resource(castles);
+            world.insert_resource(sieges);
+        });
+        Ok(())
+    });
+    Ok(())
+}
+
+fn castle_on_conflict() -> OnConflict {
+    OnConflict::columns(CastleModel::pk_columns().to_vec())
+        .update_columns(CastleModel::update_columns().to_vec())
+        .to_owned()
+}
+
+pub(crate) fn save_castle(
+    repo_manager: &RepositoryManager,
+    commands: &mut Commands,
+    castle: CastleModel,
+) -> Result<()> {
+    let castle_repository = repo_manager.typed::<i32, castle::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        if let Err(err) = castle_repository
+            .create_or_update(&castle, castle_on_conflict())
+            .await
+        {
+            log::error!("Castle: {}, Error saving castle: {:?}", castle, err);
+            return Err(err.into());
+        }
+        Ok(())
+    });
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use super::{SiegeQuery, send_packet, siege_error_message};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use chrono::Utc;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{client::GameClientPacket, server::SystemMessage},
+    session::PacketReceiveParams,
+};
+use system_messages::Id;
+
+pub(crate) struct RequestConfirmSiegeWaitingListPlugin;
+impl Plugin for RequestConfirmSiegeWaitingListPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+/// Leader of the castle owning clan approves or declines clans willing to defend the castle.
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut siege_query: SiegeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestConfirmSiegeWaitingList(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    if !siege_query.is_owner_leader(entity, packet.castle_id)? {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new_empty(Id::YouDoNotHaveTheAuthorityToModifyTheCastleDefenderList),
+        );
+        return Ok(());
+    }
+
+    let now = Utc::now().naive_utc();
+    match siege_query.confirm_defender(packet.castle_id, packet.clan_id, packet.approved, now) {
+        Ok(siege_clan) => {
+            siege_query.save_siege_clan(&mut commands, packet.castle_id, siege_clan)?
+        }
+        Err(err) => send_packet(&mut commands, entity, siege_error_message(err)),
+    }
+
+    send_packet(
+        &mut commands,
+        entity,
+        siege_query.defender_list(packet.castle_id),
+    );
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use super::{SiegeQuery, send_packet};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig, packets::client::GameClientPacket,
+    session::PacketReceiveParams,
+};
+
+pub(crate) struct RequestSiegeDefenderListPlugin;
+impl Plugin for RequestSiegeDefenderListPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    siege_query: SiegeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestSiegeDefenderList(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    send_packet(&mut commands, entity, siege_query.defender_list(packet.0));
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use super::{SiegeQuery, send_packet, siege_error_message};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use chrono::Utc;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{client::GameClientPacket, server::SystemMessage},
+    session::PacketReceiveParams,
+};
+use system_messages::{Id, SmParam};
+
+pub(crate) struct RequestJoinSiegePlugin;
+impl Plugin for RequestJoinSiegePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+/// Clan leader registers the clan for the siege or cancels the registration,
+/// defenders have to be approved by the castle owner before the siege starts.
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    names: Query<Ref<Name>>,
+    mut siege_query: SiegeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestJoinSiege(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let Some(clan) = siege_query.led_clan(entity)?.cloned() else {
+        let name = names.get(entity)?.to_string();
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new(Id::S1IsNotAClanLeader, vec![SmParam::Text(name)]),
+        );
+        return Ok(());
+    };
+
+    let now = Utc::now().naive_utc();
+    if packet.join {
+        match siege_query.register(packet.castle_id, &clan, packet.attacker, now) {
+            Ok(siege_clan) => {
+                siege_query.save_siege_clan(&mut commands, packet.castle_id, siege_clan)?
+            }
+            Err(err) => send_packet(&mut commands, entity, siege_error_message(err)),
+        }
+    } else {
+        match siege_query.unregister(packet.castle_id, clan.id, now) {
+            Ok(siege_clan) => siege_query.delete_siege_clans(
+                &mut commands,
+                packet.castle_id,
+                vec![siege_clan.clan_id],
+            )?,
+            Err(err) => send_packet(&mut commands, entity, siege_error_message(err)),
+        }
+    }
+
+    if packet.attacker {
+        send_packet(
+            &mut commands,
+            entity,
+            siege_query.attacker_list(packet.castle_id),
+        );
+    } else {
+        send_packet(
+            &mut commands,
+            entity,
+            siege_query.defender_list(packet.castle_id),
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,571 @@
+use super::save_castle;
+use bevy::{ecs::system::SystemParam, log, prelude::*, time::common_conditions::on_timer};
+use bevy_defer::AsyncCommandsExtension;
+use chrono::{NaiveDateTime, Utc};
+use game_core::{
+    attack::{Attackable, Dead},
+    character::Character,
+    clan::{
+        self, Clan, Clans,
+        castle::{
+            CastleId, Castles,
+            siege::{
+                self, CastleSiegeData, SiegeClan, SiegeData, SiegeDataHandle, SiegeError,
+                SiegeNpcs, SiegeSide, Sieges, model::SiegeClanPK,
+            },
+        },
+    },
+    network::{
+        broadcast::{BroadcastScope, ServerPacketBroadcast},
+        packets::server::{
+            CastleSiegeAttackerList, CastleSiegeDefender, CastleSiegeDefenderList,
+            CastleSiegeDefenderType, CastleSiegeInfo, CastleSiegeInfoOwner, GameServerPacket,
+            SystemMessage,
+        },
+    },
+    npc::{self, Spawned},
+    object_id::ObjectId,
+    stats::FullVitalsRestore,
+    utils::ReflectableDateTime,
+};
+use l2r_core::{
+    db::{
+        PrimaryKeyColumns, Repository, RepositoryManager, TypedRepositoryManager, UpdatableModel,
+    },
+    plugins::custom_hierarchy::DespawnChildOf,
+};
+use map::{Door, Zone, ZoneKind};
+use sea_orm::{Iterable, sea_query::OnConflict};
+use state::GameServerStateSystems;
+use std::time::Duration;
+use system_messages::{Id, SmParam};
+
+mod attacker_list;
+mod confirm_waiting_list;
+mod data;
+mod defender_list;
+mod join;
+mod set_time;
+
+/// Sieges start at the scheduled `siege_date` of the castle and last two hours.
+/// Defenders and towers are spawned and castle doors become attackable for the time of the siege,
+/// clan leader of the attackers who engraves the holy artifact takes the castle over.
+/// uses [`CastleSiegeInfo`], [`CastleSiegeAttackerList`], [`CastleSiegeDefenderList`] server packets
+/// alongside with clients: [`RequestSiegeAttackerList`], [`RequestSiegeDefenderList`], [`RequestJoinSiege`],
+/// [`RequestConfirmSiegeWaitingList`], [`RequestSetCastleSiegeTime`]
+pub(crate) struct SiegePlugin;
+impl Plugin for SiegePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(data::SiegeDataPlugin)
+            .add_plugins(attacker_list::RequestSiegeAttackerListPlugin)
+            .add_plugins(defender_list::RequestSiegeDefenderListPlugin)
+            .add_plugins(join::RequestJoinSiegePlugin)
+            .add_plugins(confirm_waiting_list::RequestConfirmSiegeWaitingListPlugin)
+            .add_plugins(set_time::RequestSetCastleSiegeTimePlugin);
+
+        app.add_observer(siege_npc_spawned)
+            .add_observer(siege_door_added);
+
+        app.add_systems(
+            Update,
+            update_sieges
+                .run_if(on_timer(Duration::from_secs(1)))
+                .in_set(GameServerStateSystems::Run),
+        );
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct SiegeQuery<'w, 's> {
+    castles: ResMut<'w, Castles>,
+    sieges: ResMut<'w, Sieges>,
+    clans: Res<'w, Clans>,
+    characters: Query<'w, 's, Ref<'static, ObjectId>, With<Character>>,
+    repo_manager: Res<'w, RepositoryManager>,
+    data_handle: Res<'w, SiegeDataHandle>,
+    data_assets: Res<'w, Assets<SiegeData>>,
+    doors: Query<'w, 's, (Entity, Ref<'static, Zone>), With<Door>>,
+    siege_npcs: Query<'w, 's, (Entity, Ref<'static, SiegeNpcs>)>,
+}
+
+impl SiegeQuery<'_, '_> {
+    pub fn data(&self) -> Result<&SiegeData> {
+        self.data_assets
+            .get(self.data_handle.id())
+            .ok_or_else(|| BevyError::from("Siege data is not loaded"))
+    }
+
+    pub fn castle_data(&self, castle_id: CastleId) -> Result<&CastleSiegeData> {
+        self.data()?
+            .get(&castle_id)
+            .ok_or_else(|| BevyError::from(format!("No siege data for {castle_id}")))
+    }
+
+    pub fn castles(&self) -> &Castles {
+        &self.castles
+    }
+
+    pub fn sieges(&self) -> &Sieges {
+        &self.sieges
+    }
+
+    /// Clan led by the character, only clan leaders can manage the sieges.
+    pub fn led_clan(&self, entity: Entity) -> Result<Option<&Clan>> {
+        let char_id = self.characters.get(entity)?;
+        Ok(self.clans.led_by(*char_id))
+    }
+
+    pub fn owner_clan(&self, castle_id: CastleId) -> Option<&Clan> {
+        self.castles
+            .get(&castle_id)
+            .and_then(|castle| castle.owner_clan_id)
+            .and_then(|clan_id| self.clans.get(&clan_id))
+    }
+
+    /// Whether the character leads the clan owning the castle.
+    pub fn is_owner_leader(&self, entity: Entity, castle_id: CastleId) -> Result<bool> {
+        let char_id = self.characters.get(entity)?;
+        Ok(self
+            .owner_clan(castle_id)
+            .is_some_and(|clan| clan.leader_id == *char_id))
+    }
+
+    pub fn siege_info(&self, entity: Entity, castle_id: CastleId) -> Result<CastleSiegeInfo> {
+        let castle = self
+            .castles
+            .get(&castle_id)
+            .ok_or_else(|| BevyError::from(format!("Castle {castle_id} is not loaded")))?;
+        let owner = self.owner_clan(castle_id).map(|clan| CastleSiegeInfoOwner {
+            clan_id: clan.id,
+            clan_name: clan.name.clone(),
+            leader_name: clan.leader_name.clone(),
+        });
+        Ok(CastleSiegeInfo::new(
+            castle_id,
+            owner,
+            self.is_owner_leader(entity, castle_id)?,
+            ReflectableDateTime::now(),
+            castle.siege_date,
+        ))
+    }
+
+    pub fn attacker_list(&self, castle_id: CastleId) -> CastleSiegeAttackerList {
+        let attackers = self
+            .sieges
+            .get(&castle_id)
+            .map(|siege| {
+                siege
+                    .attackers()
+                    .filter_map(|attacker| self.clans.get(&attacker.clan_id).cloned())
+                    .collect()
+            })
+            .unwrap_or_default();
+        CastleSiegeAttackerList::new(castle_id, attackers)
+    }
+
+    /// Castle owner goes first, followed by the approved and the waiting defenders.
+    pub fn defender_list(&self, castle_id: CastleId) -> CastleSiegeDefenderList {
+        let owner = self.owner_clan(castle_id).map(|clan| CastleSiegeDefender {
+            clan: clan.clone(),
+            kind: CastleSiegeDefenderType::Owner,
+        });
+        let defenders = self
+            .sieges
+            .get(&castle_id)
+            .into_iter()
+            .flat_map(|siege| siege.defenders())
+            .filter_map(|defender| {
+                let clan = self.clans.get(&defender.clan_id)?.clone();
+                let kind = match defender.side {
+                    SiegeSide::DefenderPending => CastleSiegeDefenderType::Pending,
+                    _ => CastleSiegeDefenderType::Defender,
+                };
+                Some(CastleSiegeDefender { clan, kind })
+            });
+        CastleSiegeDefenderList::new(castle_id, owner.into_iter().chain(defenders).collect())
+    }
+
+    pub fn register(
+        &mut self,
+        castle_id: CastleId,
+        clan: &Clan,
+        attacker: bool,
+        now: NaiveDateTime,
+    ) -> Result<SiegeClan, SiegeError> {
+        self.sieges
+            .register(&self.castles, castle_id, clan, attacker, now)
+    }
+
+    pub fn unregister(
+        &mut self,
+        castle_id: CastleId,
+        clan_id: clan::Id,
+        now: NaiveDateTime,
+    ) -> Result<SiegeClan, SiegeError> {
+        self.check_registration(castle_id, now)?;
+        self.sieges.siege_mut(castle_id).unregister(clan_id)
+    }
+
+    pub fn confirm_defender(
+        &mut self,
+        castle_id: CastleId,
+        clan_id: clan::Id,
+        approved: bool,
+        now: NaiveDateTime,
+    ) -> Result<SiegeClan, SiegeError> {
+        self.check_registration(castle_id, now)?;
+        self.sieges
+            .siege_mut(castle_id)
+            .confirm_defender(clan_id, approved)
+    }
+
+    pub fn set_siege_time(
+        &mut self,
+        commands: &mut Commands,
+        castle_id: CastleId,
+        time: NaiveDateTime,
+        now: NaiveDateTime,
+    ) -> Result<Result<(), SiegeError>> {
+        let Some(castle) = self.castles.get_mut(&castle_id) else {
+            return Ok(Err(SiegeError::InvalidTime));
+        };
+        if let Err(err) = castle.set_siege_time(time, now) {
+            return Ok(Err(err));
+        }
+        save_castle(&self.repo_manager, commands, castle.clone())?;
+        Ok(Ok(()))
+    }
+
+    fn check_registration(
+        &self,
+        castle_id: CastleId,
+        now: NaiveDateTime,
+    ) -> Result<(), SiegeError> {
+        let open = self
+            .castles
+            .get(&castle_id)
+            .is_some_and(|castle| castle.registration_open(now));
+        let in_progress = self
+            .sieges
+            .get(&castle_id)
+            .is_some_and(|siege| siege.in_progress());
+        if !open || in_progress {
+            return Err(SiegeError::RegistrationClosed);
+        }
+        Ok(())
+    }
+
+    pub fn save_siege_clan(
+        &self,
+        commands: &mut Commands,
+        castle_id: CastleId,
+        siege_clan: SiegeClan,
+    ) -> Result<()> {
+        let repository = self
+            .repo_manager
+            .typed::<SiegeClanPK, siege::model::Entity>()?;
+        let model = siege_clan.into_model(castle_id);
+        commands.spawn_task(move || async move {
+            let result = repository
+                .create_or_update(
+                    &model,
+                    OnConflict::columns(siege::model::Model::pk_columns().to_vec())
+                        .update_columns(siege::model::Model::update_columns().to_vec())
+                        .to_owned(),
+                )
+                .await;
+            if let Err(err) = result {
+                log::error!(
+                    "Castle: {}, Error saving siege clan {}: {:?}",
+                    model.castle_id,
+                    model.clan_id,
+                    err
+                );
+                return Err(err.into());
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+
+    pub fn delete_siege_clans(
+        &self,
+        commands: &mut Commands,
+        castle_id: CastleId,
+        clan_ids: Vec<clan::Id>,
+    ) -> Result<()> {
+        if clan_ids.is_empty() {
+            return Ok(());
+        }
+        let repository = self
+            .repo_manager
+            .typed::<SiegeClanPK, siege::model::Entity>()?;
+        let pks: Vec<SiegeClanPK> = clan_ids
+            .into_iter()
+            .map(|clan_id| SiegeClanPK {
+                castle_id: castle_id.into(),
+                clan_id,
+            })
+            .collect();
+        commands.spawn_task(move || async move {
+            if let Err(err) = repository.delete_by_ids(pks).await {
+                log::error!(
+                    "Castle: {}, Failed to delete siege clans: {:?}",
+                    castle_id,
+                    err
+                );
+                return Err(err.into());
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+
+    fn castle_doors(&self, castle_id: CastleId) -> Result<Vec<Entity>> {
+        let data = self.castle_data(castle_id)?;
+        Ok(self
+            .doors
+            .iter()
+            .filter_map(|(entity, zone)| match zone.kind() {
+                ZoneKind::Door(door) if data.doors.contains(&door.id) => Some(entity),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Starts the siege or cancels it when no clan has registered to attack the castle.
+    pub fn start(
+        &mut self,
+        commands: &mut Commands,
+        castle_id: CastleId,
+        now: NaiveDateTime,
+    ) -> Result<()> {
+        let siege = self.sieges.siege_mut(castle_id);
+        if siege.attackers().next().is_none() {
+            let clan_ids = siege.clans().iter().map(|clan| clan.clan_id).collect();
+            siege.finish();
+            self.delete_siege_clans(commands, castle_id, clan_ids)?;
+            self.reschedule(commands, castle_id, now)?;
+            announce(
+                commands,
+                SystemMessage::new(
+                    Id::S1SSiegeWasCanceledBecauseThereWereNoClansThatParticipated,
+                    vec![SmParam::Castle(castle_id.into())],
+                ),
+            );
+            return Ok(());
+        }
+
+        let declined = siege
+            .clans()
+            .iter()
+            .filter(|clan| clan.side == SiegeSide::DefenderPending)
+            .map(|clan| clan.clan_id)
+            .collect();
+        siege.start();
+        self.delete_siege_clans(commands, castle_id, declined)?;
+
+        let data = self.castle_data(castle_id)?;
+        let folder = commands
+            .spawn((
+                SiegeNpcs::from(castle_id),
+                Name::new(format!("{castle_id} siege npcs")),
+            ))
+            .id();
+        for spawn in data.npcs.iter() {
+            commands.trigger_targets(
+                npc::Spawn {
+                    id: spawn.id,
+                    transform: spawn.transform(),
+                },
+                folder,
+            );
+        }
+
+        for door in self.castle_doors(castle_id)? {
+            commands.entity(door).insert(Attackable);
+        }
+
+        log::info!("Siege of {} has started", castle_id);
+        announce(
+            commands,
+            SystemMessage::new(
+                Id::TheS1SiegeHasStarted,
+                vec![SmParam::Castle(castle_id.into())],
+            ),
+        );
+        Ok(())
+    }
+
+    /// Finishes the siege, castle goes to the `new_owner` if it was taken over.
+    pub fn finish(
+        &mut self,
+        commands: &mut Commands,
+        castle_id: CastleId,
+        new_owner: Option<clan::Id>,
+        now: NaiveDateTime,
+    ) -> Result<()> {
+        let siege = self.sieges.siege_mut(castle_id);
+        let clan_ids = siege.clans().iter().map(|clan| clan.clan_id).collect();
+        siege.finish();
+        self.delete_siege_clans(commands, castle_id, clan_ids)?;
+
+        if let Some(new_owner) = new_owner
+            && let Some(castle) = self.castles.get_mut(&castle_id)
+        {
+            castle.owner_clan_id = Some(new_owner);
+        }
+        self.reschedule(commands, castle_id, now)?;
+
+        for (entity, siege_npcs) in self.siege_npcs.iter() {
+            if **siege_npcs == castle_id {
+                commands.entity(entity).despawn();
+            }
+        }
+
+        for door in self.castle_doors(castle_id)? {
+            commands.entity(door).remove::<(Attackable, Dead)>();
+            commands.trigger_targets(FullVitalsRestore::from(door), door);
+        }
+
+        log::info!("Siege of {} has finished", castle_id);
+        announce(
+            commands,
+            SystemMessage::new(
+                Id::TheS1SiegeHasFinished,
+                vec![SmParam::Castle(castle_id.into())],
+            ),
+        );
+        if new_owner.is_some() {
+            announce(
+                commands,
+                SystemMessage::new_empty(Id::ThereIsANewLordOfTheCastle),
+            );
+        }
+        Ok(())
+    }
+
+    fn reschedule(
+        &mut self,
+        commands: &mut Commands,
+        castle_id: CastleId,
+        now: NaiveDateTime,
+    ) -> Result<()> {
+        let castle = self
+            .castles
+            .get_mut(&castle_id)
+            .ok_or_else(|| BevyError::from(format!("Castle {castle_id} is not loaded")))?;
+        castle.schedule_next_siege(now);
+        save_castle(&self.repo_manager, commands, castle.clone())
+    }
+}
+
+pub(crate) fn siege_error_message(err: SiegeError) -> SystemMessage {
+    let message_id = match err {
+        SiegeError::RegistrationClosed => {
+            Id::ThisIsNotTheTimeForSiegeRegistrationAndSoRegistrationAndCancellationCannotBeDone
+        }
+        SiegeError::ClanLevelTooLow => Id::OnlyClansOfLevel5OrHigherMayRegisterForACastleSiege,
+        SiegeError::OwnerIsDefender => {
+            Id::CastleOwningClansAreAutomaticallyRegisteredOnTheDefendingSide
+        }
+        SiegeError::OwnsCastle => Id::AClanThatOwnsACastleCannotParticipateInAnotherSiege,
+        SiegeError::RegisteredElsewhere => {
+            Id::YourApplicationHasBeenDeniedBecauseYouHaveAlreadySubmittedARequestForAnotherCastleSiege
+        }
+        SiegeError::AlreadyAttacker => {
+            Id::YouAreAlreadyRegisteredToTheAttackerSideAndMustCancelYourRegistrationBeforeSubmittingYourRequest
+        }
+        SiegeError::AlreadyDefender => {
+            Id::YouHaveAlreadyRegisteredToTheDefenderSideAndMustCancelYourRegistrationBeforeSubmittingYourRequest
+        }
+        SiegeError::AttackersFull => Id::NoMoreRegistrationsMayBeAcceptedForTheAttackerSide,
+        SiegeError::DefendersFull => Id::NoMoreRegistrationsMayBeAcceptedForTheDefenderSide,
+        SiegeError::NotRegistered => Id::YouAreNotYetRegisteredForTheCastleSiege,
+        SiegeError::InvalidTime => Id::YouDoNotHaveTheAuthorityToModifyTheSiegeTime,
+    };
+    SystemMessage::new_empty(message_id)
+}
+
+pub(crate) fn send_packet(
+    commands: &mut Commands,
+    entity: Entity,
+    packet: impl Into<GameServerPacket>,
+) {
+    commands.trigger_targets(packet.into(), entity);
+}
+
+pub(crate) fn announce(commands: &mut Commands, message: SystemMessage) {
+    commands.trigger(ServerPacketBroadcast {
+        packet: message.into(),
+        scope: BroadcastScope::All,
+    });
+}
+
+fn update_sieges(mut commands: Commands, mut siege_query: SiegeQuery) -> Result<()> {
+    let now = Utc::now().naive_utc();
+    for castle_id in CastleId::iter() {
+        let Some(castle) = siege_query.castles().get(&castle_id) else {
+            continue;
+        };
+        let siege_end = castle.siege_end();
+        let siege_date = *castle.siege_date;
+        let in_progress = siege_query
+            .sieges()
+            .get(&castle_id)
+            .is_some_and(|siege| siege.in_progress());
+
+        if in_progress && now >= siege_end {
+            siege_query.finish(&mut commands, castle_id, None, now)?;
+        } else if !in_progress && now >= siege_date {
+            siege_query.start(&mut commands, castle_id, now)?;
+        }
+    }
+    Ok(())
+}
+
+/// Defenders and towers spawned for the siege can be attacked regardless of their kind.
+fn siege_npc_spawned(
+    spawned: Trigger<Spawned>,
+    mut commands: Commands,
+    parents: Query<Ref<DespawnChildOf>>,
+    siege_npcs: Query<(), With<SiegeNpcs>>,
+) {
+    let entity = spawned.target();
+    if let Ok(parent) = parents.get(entity)
+        && siege_npcs.contains(**parent)
+    {
+        commands.entity(entity).insert(Attackable);
+    }
+}
+
+/// Doors are spawned with their region, which can happen in the middle of the siege.
+fn siege_door_added(
+    added: Trigger<OnAdd, Door>,
+    mut commands: Commands,
+    zones: Query<Ref<Zone>>,
+    sieges: Res<Sieges>,
+    data_handle: Res<SiegeDataHandle>,
+    data_assets: Res<Assets<SiegeData>>,
+) -> Result<()> {
+    let entity = added.target();
+    let zone = zones.get(entity)?;
+    let ZoneKind::Door(door) = zone.kind() else {
+        return Ok(());
+    };
+
+    let Some(castle_id) = data_assets
+        .get(data_handle.id())
+        .and_then(|data| data.castle_of_door(door.id))
+    else {
+        return Ok(());
+    };
+
+    if sieges
+        .get(&castle_id)
+        .is_some_and(|siege| siege.in_progress())
+    {
+        commands.entity(entity).insert(Attackable);
+    }
+    Ok(())
+}
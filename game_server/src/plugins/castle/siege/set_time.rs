@@ -0,0 +1,59 @@
+use super::{SiegeQuery, announce, send_packet, siege_error_message};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use chrono::Utc;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{client::GameClientPacket, server::SystemMessage},
+    session::PacketReceiveParams,
+};
+use system_messages::{Id, SmParam};
+
+pub(crate) struct RequestSetCastleSiegeTimePlugin;
+impl Plugin for RequestSetCastleSiegeTimePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+/// Leader of the castle owning clan picks the hour of the next siege, only once per siege.
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut siege_query: SiegeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestSetCastleSiegeTime(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    if !siege_query.is_owner_leader(entity, packet.castle_id)? {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new_empty(Id::YouDoNotHaveTheAuthorityToModifyTheSiegeTime),
+        );
+        return Ok(());
+    }
+
+    let now = Utc::now().naive_utc();
+    match siege_query.set_siege_time(&mut commands, packet.castle_id, *packet.time, now)? {
+        Ok(()) => announce(
+            &mut commands,
+            SystemMessage::new(
+                Id::S1HasAnnouncedTheNextCastleSiegeTime,
+                vec![SmParam::Castle(packet.castle_id.into())],
+            ),
+        ),
+        Err(err) => send_packet(&mut commands, entity, siege_error_message(err)),
+    }
+
+    send_packet(
+        &mut commands,
+        entity,
+        siege_query.siege_info(entity, packet.castle_id)?,
+    );
+    Ok(())
+}
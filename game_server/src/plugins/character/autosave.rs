@@ -0,0 +1,444 @@
+use super::effects::{self, EffectsRestored, SavedEffects};
+use bevy::{log, prelude::*};
+use bevy_defer::{AsyncAccess, AsyncCommandsExtension, AsyncWorld};
+use bevy_ecs::entity::{EntityHashMap, EntityHashSet};
+use config::Config;
+use game_core::{
+    abnormal_effects::{AbnormalEffects, AbnormalEffectsTimers},
+    character::{self, AutosaveMetric, Character, CharacterSave, model::ModelUpdate},
+    items::{self, Item, ItemLocation, UniqueItem},
+    object_id::{ObjectId, ObjectIdManager},
+    shortcut::{self, Shortcuts},
+    skills::{SkillList, SkillReuseTimers},
+    stats::{NameTitle, ProgressStats, SubClass, SubClassVariant, VitalsStats},
+};
+use l2r_core::{
+    db::{DbConnection, RepositoryModel},
+    metrics::{Metrics, MetricsAppExt},
+};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, IntoActiveModel, QueryFilter,
+    TransactionTrait,
+};
+use state::GameServerStateSystems;
+use std::{collections::VecDeque, time::Instant};
+
+/// Characters, their items, skills, shortcuts and effects changed since the last round
+/// are written to the database every configured interval, a few characters per tick.
+/// Everything of a batch goes in one transaction, so a character is never half saved.
+pub(crate) struct AutosavePlugin;
+impl Plugin for AutosavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AutosaveQueue>();
+
+        app.register_gauge(
+            AutosaveMetric::AutosaveQueueDepth,
+            "Characters waiting for the autosave",
+        )
+        .register_gauge(
+            AutosaveMetric::AutosaveLatencyMs,
+            "Time the last autosave batch took to write",
+        )
+        .register_counter(AutosaveMetric::AutosaveSaved, "Characters autosaved")
+        .register_counter(
+            AutosaveMetric::AutosaveFailed,
+            "Autosave batches failed to write",
+        );
+
+        app.add_systems(
+            Update,
+            (
+                mark_changed_characters,
+                mark_changed_shortcuts,
+                mark_changed_items,
+                mark_changed_effects,
+                start_round,
+                save_batch,
+            )
+                .chain()
+                .in_set(GameServerStateSystems::Run),
+        );
+
+        app.add_observer(save_with_character);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Changes {
+    character: bool,
+    skills: bool,
+    shortcuts: bool,
+    effects: bool,
+}
+
+#[derive(Default, Resource)]
+struct AutosaveQueue {
+    timer: Timer,
+    changed: EntityHashMap<Changes>,
+    changed_items: EntityHashMap<EntityHashSet>,
+    round: VecDeque<(Entity, Changes, EntityHashSet)>,
+}
+
+fn mark_changed_characters(
+    mut queue: ResMut<AutosaveQueue>,
+    characters: Query<
+        (Entity, Option<Ref<SkillList>>),
+        (
+            With<Character>,
+            Or<(
+                Changed<Transform>,
+                Changed<ProgressStats>,
+                Changed<VitalsStats>,
+                Changed<NameTitle>,
+                Changed<SkillList>,
+            )>,
+        ),
+    >,
+) {
+    for (entity, skill_list) in characters.iter() {
+        let changes = queue.changed.entry(entity).or_default();
+        changes.character = true;
+        changes.skills |= skill_list.is_some_and(|skill_list| skill_list.is_changed());
+    }
+}
+
+fn mark_changed_shortcuts(
+    mut queue: ResMut<AutosaveQueue>,
+    characters: Query<(Entity, Ref<Shortcuts>), (With<Character>, Changed<Shortcuts>)>,
+) {
+    for (entity, shortcuts) in characters.iter() {
+        // Just loaded from the database, nothing to write back
+        if shortcuts.is_added() {
+            continue;
+        }
+        queue.changed.entry(entity).or_default().shortcuts = true;
+    }
+}
+
+/// Reuse timers tick every frame, characters with one still running are written every round.
+fn mark_changed_effects(
+    mut queue: ResMut<AutosaveQueue>,
+    characters: Query<
+        (Entity, Ref<AbnormalEffects>, Ref<SkillReuseTimers>),
+        (With<Character>, With<EffectsRestored>),
+    >,
+) {
+    for (entity, effects, reuse_timers) in characters.iter() {
+        if effects.is_changed() || !reuse_timers.is_empty() {
+            queue.changed.entry(entity).or_default().effects = true;
+        }
+    }
+}
+
+fn mark_changed_items(
+    mut queue: ResMut<AutosaveQueue>,
+    items: Query<(Entity, Ref<Item>), Changed<Item>>,
+    object_id_manager: Res<ObjectIdManager>,
+) {
+    for (item_entity, item) in items.iter() {
+        if !matches!(
+            item.location(),
+            ItemLocation::Inventory | ItemLocation::PaperDoll(_)
+        ) || item.count() == 0
+        {
+            continue;
+        }
+        let Some(owner_entity) = item
+            .owner()
+            .and_then(|owner| object_id_manager.entity(owner))
+        else {
+            continue;
+        };
+        queue.changed.entry(owner_entity).or_default();
+        queue
+            .changed_items
+            .entry(owner_entity)
+            .or_default()
+            .insert(item_entity);
+    }
+}
+
+/// Moves everything changed into the round once the interval passes,
+/// a round still being written postpones the next one.
+/// Without an interval the changes wait for the character to be saved on its own.
+fn start_round(
+    mut queue: ResMut<AutosaveQueue>,
+    time: Res<Time>,
+    config: Res<Config>,
+    metrics: Res<Metrics>,
+) -> Result<()> {
+    let Some(interval) = config.autosave().duration() else {
+        return Ok(());
+    };
+    if queue.timer.duration() != interval {
+        queue.timer = Timer::new(interval, TimerMode::Repeating);
+    }
+    queue.timer.tick(time.delta());
+
+    if queue.timer.just_finished() && queue.round.is_empty() {
+        let queue = queue.as_mut();
+        let mut changed_items = std::mem::take(&mut queue.changed_items);
+        queue.round = queue
+            .changed
+            .drain()
+            .map(|(entity, changes)| {
+                let items = changed_items.remove(&entity).unwrap_or_default();
+                (entity, changes, items)
+            })
+            .collect();
+    }
+
+    metrics
+        .gauge(AutosaveMetric::AutosaveQueueDepth)?
+        .set(queue.round.len() as i64);
+    Ok(())
+}
+
+fn save_batch(
+    mut commands: Commands,
+    mut queue: ResMut<AutosaveQueue>,
+    config: Res<Config>,
+    characters: Query<(character::Query, Ref<SubClass>, Option<Ref<Shortcuts>>)>,
+    items: Query<(Ref<ObjectId>, Ref<Item>)>,
+    character_effects: EffectsQuery,
+    db_connection: Res<DbConnection>,
+) {
+    if queue.round.is_empty() {
+        return;
+    }
+    let batch_size = config.autosave().batch_size.max(1);
+    let round = queue
+        .round
+        .drain(..batch_size.min(queue.round.len()))
+        .collect::<Vec<_>>();
+    if db_connection.is_mock() {
+        return;
+    }
+
+    let mut batch = AutosaveBatch::default();
+    for (entity, changes, changed_items) in round {
+        batch.collect(
+            entity,
+            changes,
+            changed_items,
+            &characters,
+            &items,
+            (&character_effects, &config),
+        );
+    }
+    batch.spawn(&mut commands, &db_connection);
+}
+
+/// A character saved on its own, e.g. when leaving the world,
+/// takes what the autosave still has to write for it along.
+fn save_with_character(
+    save: Trigger<CharacterSave>,
+    mut commands: Commands,
+    mut queue: ResMut<AutosaveQueue>,
+    characters: Query<(character::Query, Ref<SubClass>, Option<Ref<Shortcuts>>)>,
+    items: Query<(Ref<ObjectId>, Ref<Item>)>,
+    character_effects: EffectsQuery,
+    config: Res<Config>,
+    db_connection: Res<DbConnection>,
+) {
+    let entity = save.target();
+    let mut changes = queue.changed.remove(&entity);
+    let mut changed_items = queue.changed_items.remove(&entity).unwrap_or_default();
+    if let Some(index) = queue
+        .round
+        .iter()
+        .position(|(queued, ..)| *queued == entity)
+        && let Some((_, queued_changes, queued_items)) = queue.round.remove(index)
+    {
+        let changes = changes.get_or_insert_default();
+        changes.character |= queued_changes.character;
+        changes.skills |= queued_changes.skills;
+        changes.shortcuts |= queued_changes.shortcuts;
+        changed_items.extend(queued_items);
+    }
+    let Some(mut changes) = changes else {
+        return;
+    };
+    // Written by the effects plugin on its own for every saved character
+    changes.effects = false;
+    if db_connection.is_mock() {
+        return;
+    }
+
+    let mut batch = AutosaveBatch::default();
+    batch.collect(
+        entity,
+        changes,
+        changed_items,
+        &characters,
+        &items,
+        (&character_effects, &config),
+    );
+    batch.spawn(&mut commands, &db_connection);
+}
+
+type EffectsQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Ref<'static, AbnormalEffects>,
+        Ref<'static, AbnormalEffectsTimers>,
+        Ref<'static, SkillReuseTimers>,
+    ),
+    With<EffectsRestored>,
+>;
+
+/// Characters, their skills, items, shortcuts and effects of a batch, written in one transaction
+#[derive(Default)]
+struct AutosaveBatch {
+    characters: Vec<character::model::ActiveModel>,
+    skills: Vec<character::skills::Model>,
+    items: Vec<items::model::Model>,
+    shortcuts: Vec<(ObjectId, SubClassVariant, Vec<shortcut::model::Model>)>,
+    effects: Vec<(ObjectId, SavedEffects)>,
+    saved: u64,
+}
+
+impl AutosaveBatch {
+    fn collect(
+        &mut self,
+        entity: Entity,
+        changes: Changes,
+        changed_items: EntityHashSet,
+        characters: &Query<(character::Query, Ref<SubClass>, Option<Ref<Shortcuts>>)>,
+        items: &Query<(Ref<ObjectId>, Ref<Item>)>,
+        (character_effects, config): (&EffectsQuery, &Config),
+    ) {
+        let Ok((character, sub_class, shortcuts)) = characters.get(entity) else {
+            return;
+        };
+        let char_id = *character.object_id;
+        self.saved += 1;
+        if changes.character {
+            self.characters.push(character::model::Model::autosave(
+                char_id,
+                ModelUpdate::from(&character),
+            ));
+        }
+        if changes.skills
+            && let Some(skill_list) = character.skill_list
+        {
+            self.skills.extend(
+                skill_list.values().map(|skill| {
+                    character::skills::Model::new(char_id, *skill, sub_class.variant())
+                }),
+            );
+        }
+        if changes.shortcuts
+            && let Some(shortcuts) = shortcuts
+        {
+            self.shortcuts.push((
+                char_id,
+                shortcuts.class_variant(),
+                shortcuts.models(char_id),
+            ));
+        }
+        if changes.effects
+            && let Ok((abnormal_effects, timers, reuse_timers)) = character_effects.get(entity)
+        {
+            self.effects.push((
+                char_id,
+                effects::saved_effects(char_id, &abnormal_effects, &timers, &reuse_timers, config),
+            ));
+        }
+        self.items
+            .extend(changed_items.into_iter().filter_map(|item_entity| {
+                let (object_id, item) = items.get(item_entity).ok()?;
+                if item.owner() != Some(char_id) || item.count() == 0 {
+                    return None;
+                }
+                let mut item_model = items::model::Model::from(UniqueItem::new(*object_id, *item));
+                item_model.set_owner_id(char_id);
+                Some(item_model)
+            }));
+    }
+
+    fn spawn(self, commands: &mut Commands, db_connection: &DbConnection) {
+        if self.saved == 0 {
+            return;
+        }
+        let conn = db_connection.connection();
+        commands.spawn_task(move || async move {
+            let saved = self.saved;
+            let started = Instant::now();
+            let result = self.write(&conn).await;
+            let latency = started.elapsed();
+
+            if let Err(err) = &result {
+                log::error!("Autosave of {} character(s) failed: {:?}", saved, err);
+            }
+            AsyncWorld.resource::<Metrics>().get(|metrics| {
+                if let Ok(gauge) = metrics.gauge(AutosaveMetric::AutosaveLatencyMs) {
+                    gauge.set(latency.as_millis() as i64);
+                }
+                let counter = if result.is_ok() {
+                    metrics
+                        .counter(AutosaveMetric::AutosaveSaved)
+                        .map(|c| c.inc_by(saved))
+                } else {
+                    metrics
+                        .counter(AutosaveMetric::AutosaveFailed)
+                        .map(|c| c.inc())
+                };
+                if let Err(err) = counter {
+                    log::warn!("Failed to update autosave metrics: {:?}", err);
+                }
+            })?;
+            Ok(())
+        });
+    }
+
+    async fn write(self, conn: &DatabaseConnection) -> Result<(), DbErr> {
+        let txn = conn.begin().await?;
+        for character_model in self.characters {
+            character::model::Entity::update(character_model)
+                .exec(&txn)
+                .await?;
+        }
+        if !self.skills.is_empty() {
+            character::skills::Entity::insert_many(
+                self.skills
+                    .into_iter()
+                    .map(IntoActiveModel::into_active_model),
+            )
+            .on_conflict(character::skills::Model::on_conflict())
+            .exec(&txn)
+            .await?;
+        }
+        if !self.items.is_empty() {
+            items::model::Entity::insert_many(
+                self.items
+                    .into_iter()
+                    .map(IntoActiveModel::into_active_model),
+            )
+            .on_conflict(items::model::Model::on_conflict())
+            .exec(&txn)
+            .await?;
+        }
+        for (char_id, class_variant, shortcut_models) in self.shortcuts {
+            shortcut::model::Entity::delete_many()
+                .filter(shortcut::model::Column::CharId.eq(char_id))
+                .filter(shortcut::model::Column::ClassVariant.eq(class_variant))
+                .exec(&txn)
+                .await?;
+            if !shortcut_models.is_empty() {
+                shortcut::model::Entity::insert_many(
+                    shortcut_models
+                        .into_iter()
+                        .map(IntoActiveModel::into_active_model),
+                )
+                .exec(&txn)
+                .await?;
+            }
+        }
+        for (char_id, saved) in self.effects {
+            effects::overwrite(&txn, char_id, saved).await?;
+        }
+        txn.commit().await
+    }
+}
@@ -0,0 +1,171 @@
+use crate::plugins::items::spawn_inventory;
+use bevy::{log, prelude::*, time::common_conditions::on_timer};
+use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use game_core::{
+    account::Account,
+    character::{self, Character, CharacterSave, DetachCharacter, Detached, model},
+    encounters::EnteredWorld,
+    items,
+    network::packets::server::{GameServerPacket, LogoutOk},
+    object_id::ObjectId,
+    utils::ReflectableDateTime,
+};
+use l2r_core::{
+    db::{Repository, RepositoryManager, TypedRepositoryManager},
+    plugins::custom_hierarchy::DespawnChildOf,
+};
+use sea_orm::{ColumnTrait, QueryFilter, prelude::Expr};
+use state::GameServerStateSystems;
+use std::time::Duration;
+
+/// Characters may stay in the world without a session, they are brought back
+/// from the database on startup until their time is over.
+pub(crate) struct DetachedPlugin;
+impl Plugin for DetachedPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(detach_character);
+
+        app.add_systems(
+            OnEnter(GameServerStateSystems::Run),
+            restore_detached_characters.run_if(run_once),
+        )
+        .add_systems(
+            Update,
+            detached_timeout
+                .run_if(on_timer(Duration::from_secs(1)))
+                .in_set(GameServerStateSystems::Run),
+        );
+    }
+}
+
+/// Keeps the time the detached character leaves the world at, `None` once it is gone or picked up.
+pub(crate) fn save_detached_until(
+    commands: &mut Commands,
+    repo_manager: &RepositoryManager,
+    char_id: ObjectId,
+    until: Option<ReflectableDateTime>,
+) -> Result<()> {
+    if repo_manager.is_mock() {
+        return Ok(());
+    }
+    let repository = repo_manager.typed::<ObjectId, model::Entity>()?;
+    commands.spawn_task(move || async move {
+        repository
+            .update_many(|update| {
+                update
+                    .col_expr(model::Column::DetachedUntil, Expr::value(until))
+                    .filter(model::Column::Id.eq(char_id))
+            })
+            .await?;
+        Ok(())
+    });
+    Ok(())
+}
+
+fn detach_character(
+    detach: Trigger<DetachCharacter>,
+    characters: Query<(Ref<ObjectId>, Ref<DespawnChildOf>), With<Character>>,
+    mut sessions: Query<(Ref<Account>, Mut<character::Table>)>,
+    config: Res<config::Config>,
+    repo_manager: Res<RepositoryManager>,
+    mut commands: Commands,
+) -> Result<()> {
+    let entity = detach.target();
+    let Some(duration) = config.offline().duration() else {
+        return Ok(());
+    };
+    let (object_id, session) = characters.get(entity)?;
+    let session_entity = **session;
+    let (account, mut chars_table) = sessions.get_mut(session_entity)?;
+
+    let detached = Detached::new(account.id(), duration);
+    save_detached_until(
+        &mut commands,
+        &repo_manager,
+        *object_id,
+        Some(detached.until()),
+    )?;
+
+    // Saved while the session is still there to keep the characters table up to date
+    commands.trigger_targets(CharacterSave, entity);
+    commands
+        .entity(entity)
+        .remove::<DespawnChildOf>()
+        .insert(detached);
+    chars_table.unset_character();
+
+    // Client closes the connection by itself, the character is not bound to it anymore
+    commands.trigger_targets(GameServerPacket::from(LogoutOk), session_entity);
+    Ok(())
+}
+
+fn detached_timeout(
+    characters: Query<(Entity, Ref<ObjectId>, Ref<Detached>)>,
+    repo_manager: Res<RepositoryManager>,
+    mut commands: Commands,
+) -> Result<()> {
+    for (entity, object_id, detached) in characters.iter() {
+        if detached.expired() {
+            log::debug!("Detached character {} left the world", *object_id);
+            save_detached_until(&mut commands, &repo_manager, *object_id, None)?;
+            commands.trigger_targets(CharacterSave, entity);
+            commands.entity(entity).try_despawn();
+        }
+    }
+    Ok(())
+}
+
+/// Detached characters have no session, they are only marked with [`Detached`].
+fn restore_detached_characters(
+    mut commands: Commands,
+    repo_manager: Res<RepositoryManager>,
+) -> Result<()> {
+    if repo_manager.is_mock() {
+        return Ok(());
+    }
+
+    let character_repository = repo_manager.typed::<ObjectId, model::Entity>()?;
+    let items_repository = repo_manager.typed::<ObjectId, items::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        let now = ReflectableDateTime::now();
+        // Forget the ones whose time ran out while the server was down
+        character_repository
+            .update_many(|update| {
+                update
+                    .col_expr(
+                        model::Column::DetachedUntil,
+                        Expr::value(None::<ReflectableDateTime>),
+                    )
+                    .filter(model::Column::DetachedUntil.lte(now))
+            })
+            .await?;
+
+        let detached = character_repository
+            .find_with_conditions([model::Column::DetachedUntil.gt(now)])
+            .await?;
+        log::info!("Restoring {} detached character(s).", detached.len());
+
+        for char_model in detached {
+            let item_models = items_repository
+                .find_with_conditions([items::model::Column::OwnerId.eq(char_model.id)])
+                .await?;
+
+            AsyncWorld.apply_command(move |world: &mut World| {
+                let Some(until) = char_model.detached_until else {
+                    return;
+                };
+                let account_id = char_model.account_id;
+                let bundle = character::Bundle::new(char_model, item_models.clone(), None, world);
+                let entity = bundle.spawn(world.commands());
+                world
+                    .commands()
+                    .entity(entity)
+                    .insert((Detached::restored(account_id, until), EnteredWorld));
+                world.flush();
+                spawn_inventory(world, entity, item_models);
+            });
+        }
+        Ok(())
+    });
+    Ok(())
+}
@@ -0,0 +1,321 @@
+use bevy::{log, platform::collections::HashMap, prelude::*};
+use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use chrono::{TimeDelta, Utc};
+use config::Config;
+use game_core::{
+    abnormal_effects::{self, AbnormalEffectTimer, AbnormalEffects, AbnormalEffectsTimers},
+    character::{Character, CharacterSave},
+    encounters::EnteredWorld,
+    network::packets::server::{GameServerPacket, SkillCoolTime},
+    object_id::ObjectId,
+    skills::{SkillList, SkillReuseTimers, reuse_model},
+};
+use l2r_core::db::{DbConnection, RepositoryModel};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, IntoActiveModel,
+    QueryFilter, TransactionTrait,
+};
+
+/// Timed effects and skill reuse timers are written with the rest of the character
+/// and put back once it enters the world again. Effects keep the time they had left,
+/// reuse timers keep running while the character is offline.
+/// Toggles and effects ticking over time are not kept, they are cast again.
+/// The saved rows stay until the next save overwrites them, so a crash restores
+/// what the character had at its last save.
+pub(crate) struct CharacterEffectsPlugin;
+impl Plugin for CharacterEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PendingEffectSaves>();
+
+        app.add_observer(save_effects)
+            .add_observer(schedule_restore)
+            .add_observer(restore_effects);
+    }
+}
+
+pub(super) type SavedEffects = (Vec<abnormal_effects::model::Model>, Vec<reuse_model::Model>);
+
+/// Put on a character once its saved effects are back, until then saves leave
+/// the rows alone so they can't be overwritten with what it has before the restore.
+#[derive(Clone, Copy, Component, Debug, Default)]
+pub(super) struct EffectsRestored;
+
+/// Saves still being written per character, a relog restores only once the
+/// logout save is done so it takes what that save wrote.
+#[derive(Default, Resource)]
+struct PendingEffectSaves {
+    saving: HashMap<ObjectId, usize>,
+    restores: HashMap<ObjectId, Entity>,
+}
+
+impl PendingEffectSaves {
+    fn started(&mut self, char_id: ObjectId) {
+        *self.saving.entry(char_id).or_default() += 1;
+    }
+
+    /// Returns the character waiting for the restore once its last save is done.
+    fn finished(&mut self, char_id: ObjectId) -> Option<Entity> {
+        let saving = self.saving.get_mut(&char_id)?;
+        *saving -= 1;
+        if *saving > 0 {
+            return None;
+        }
+        self.saving.remove(&char_id);
+        self.restores.remove(&char_id)
+    }
+}
+
+#[derive(Clone, Copy, Event)]
+struct RestoreEffects;
+
+fn save_effects(
+    save: Trigger<CharacterSave>,
+    mut commands: Commands,
+    characters: Query<
+        (
+            Ref<ObjectId>,
+            Ref<AbnormalEffects>,
+            Ref<AbnormalEffectsTimers>,
+            Ref<SkillReuseTimers>,
+        ),
+        (With<Character>, With<EffectsRestored>),
+    >,
+    config: Res<Config>,
+    db_connection: Res<DbConnection>,
+    mut pending: ResMut<PendingEffectSaves>,
+) -> Result<()> {
+    if db_connection.is_mock() {
+        return Ok(());
+    }
+    let Ok((object_id, effects, timers, reuse_timers)) = characters.get(save.target()) else {
+        return Ok(());
+    };
+    let char_id = *object_id;
+    let saved = saved_effects(char_id, &effects, &timers, &reuse_timers, &config);
+
+    let conn = db_connection.connection();
+    pending.started(char_id);
+    commands.spawn_task(move || async move {
+        if let Err(err) = save(&conn, char_id, saved).await {
+            log::error!("Failed to save effects of character {}: {:?}", char_id, err);
+        }
+        AsyncWorld.apply_command(move |world: &mut World| {
+            let waiting = world.resource_mut::<PendingEffectSaves>().finished(char_id);
+            if let Some(entity) = waiting {
+                world.trigger_targets(RestoreEffects, entity);
+            }
+        });
+        Ok(())
+    });
+    Ok(())
+}
+
+/// Effects and reuse timers of the character as they are written to the database.
+pub(super) fn saved_effects(
+    char_id: ObjectId,
+    effects: &AbnormalEffects,
+    timers: &AbnormalEffectsTimers,
+    reuse_timers: &SkillReuseTimers,
+    config: &Config,
+) -> SavedEffects {
+    let effect_models = effects
+        .get()
+        .into_iter()
+        .filter(|effect| {
+            !config
+                .effects()
+                .is_dropped_on_logout(&effect.kind().to_string())
+        })
+        .filter_map(|effect| {
+            let timer = timers.get_timer(effect.skill().id())?;
+            if timer.effects_over_time().is_some() {
+                return None;
+            }
+            let remaining = timer.timer()?.remaining();
+            Some(abnormal_effects::model::Model::new(
+                char_id, effect, remaining,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let now = Utc::now().naive_utc();
+    let reuse_models = reuse_timers
+        .iter()
+        .filter(|(_, timer)| !timer.finished())
+        .filter_map(|(skill_id, timer)| {
+            Some(reuse_model::Model {
+                char_id,
+                skill_id: *skill_id,
+                reuse_until: now + TimeDelta::from_std(timer.remaining()).ok()?,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    (effect_models, reuse_models)
+}
+
+fn schedule_restore(
+    entered: Trigger<OnAdd, EnteredWorld>,
+    mut commands: Commands,
+    characters: Query<Ref<ObjectId>, With<Character>>,
+    mut pending: ResMut<PendingEffectSaves>,
+) {
+    let entity = entered.target();
+    let Ok(object_id) = characters.get(entity) else {
+        return;
+    };
+    if pending.saving.contains_key(&*object_id) {
+        pending.restores.insert(*object_id, entity);
+    } else {
+        commands.trigger_targets(RestoreEffects, entity);
+    }
+}
+
+/// The saved rows are only read, a failed restore leaves the character without
+/// [`EffectsRestored`] so its rows aren't overwritten until it enters the world again.
+fn restore_effects(
+    restore: Trigger<RestoreEffects>,
+    mut commands: Commands,
+    characters: Query<Ref<ObjectId>, With<Character>>,
+    db_connection: Res<DbConnection>,
+) {
+    let entity = restore.target();
+    let Ok(object_id) = characters.get(entity) else {
+        return;
+    };
+    if db_connection.is_mock() {
+        commands.entity(entity).insert(EffectsRestored);
+        return;
+    }
+    let char_id = *object_id;
+    let conn = db_connection.connection();
+    commands.spawn_task(move || async move {
+        let (effect_models, reuse_models) = match load(&conn, char_id).await {
+            Ok(saved) => saved,
+            Err(err) => {
+                log::error!(
+                    "Failed to restore effects of character {}: {:?}",
+                    char_id,
+                    err
+                );
+                return Ok(());
+            }
+        };
+        AsyncWorld.apply_command(move |world: &mut World| {
+            let Ok(mut character) = world.get_entity_mut(entity) else {
+                return;
+            };
+            character.insert(EffectsRestored);
+            let now = Utc::now().naive_utc();
+            if let Some(mut reuse_timers) = character.get_mut::<SkillReuseTimers>() {
+                for model in reuse_models {
+                    if let Ok(remaining) = (model.reuse_until - now).to_std() {
+                        reuse_timers.start_reuse(model.skill_id, remaining.as_millis() as u32);
+                    }
+                }
+            }
+            let cool_time = character
+                .get::<SkillReuseTimers>()
+                .map(|reuse_timers| SkillCoolTime::new(reuse_timers, character.get::<SkillList>()));
+
+            let restored = effect_models
+                .iter()
+                .filter_map(|model| {
+                    let effect = model.effect();
+                    if effect.is_none() {
+                        log::warn!(
+                            "Unknown abnormal kind {} of skill {} dropped",
+                            model.kind,
+                            model.skill_id
+                        );
+                    }
+                    effect
+                })
+                .collect::<Vec<_>>();
+            // Effects cast again since entering the world are kept with their own timers
+            let mut added = Vec::new();
+            if let Some(mut effects) = character.get_mut::<AbnormalEffects>() {
+                for (effect, remaining) in restored {
+                    if !effects.has_effect(effect.skill().id()) {
+                        effects.add(effect);
+                        added.push((effect.skill().id(), remaining));
+                    }
+                }
+            }
+            if let Some(mut timers) = character.get_mut::<AbnormalEffectsTimers>() {
+                for (skill_id, remaining) in added {
+                    timers.insert(
+                        skill_id,
+                        AbnormalEffectTimer::new(
+                            Some(Timer::new(remaining, TimerMode::Once)),
+                            None,
+                        ),
+                    );
+                }
+            }
+            if let Some(cool_time) = cool_time {
+                world.trigger_targets(GameServerPacket::from(cool_time), entity);
+            }
+        });
+        Ok(())
+    });
+}
+
+async fn save(
+    conn: &DatabaseConnection,
+    char_id: ObjectId,
+    saved: SavedEffects,
+) -> Result<(), DbErr> {
+    let txn = conn.begin().await?;
+    overwrite(&txn, char_id, saved).await?;
+    txn.commit().await
+}
+
+/// Replaces the saved effects and reuse timers of the character.
+pub(super) async fn overwrite<C: ConnectionTrait>(
+    conn: &C,
+    char_id: ObjectId,
+    (effect_models, reuse_models): SavedEffects,
+) -> Result<(), DbErr> {
+    abnormal_effects::model::Entity::delete_many()
+        .filter(abnormal_effects::model::Column::CharId.eq(char_id))
+        .exec(conn)
+        .await?;
+    reuse_model::Entity::delete_many()
+        .filter(reuse_model::Column::CharId.eq(char_id))
+        .exec(conn)
+        .await?;
+    if !effect_models.is_empty() {
+        abnormal_effects::model::Entity::insert_many(
+            effect_models
+                .into_iter()
+                .map(IntoActiveModel::into_active_model),
+        )
+        .on_conflict(abnormal_effects::model::Model::on_conflict())
+        .exec(conn)
+        .await?;
+    }
+    if !reuse_models.is_empty() {
+        reuse_model::Entity::insert_many(
+            reuse_models
+                .into_iter()
+                .map(IntoActiveModel::into_active_model),
+        )
+        .on_conflict(reuse_model::Model::on_conflict())
+        .exec(conn)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn load(conn: &DatabaseConnection, char_id: ObjectId) -> Result<SavedEffects, DbErr> {
+    let effect_models = abnormal_effects::model::Entity::find()
+        .filter(abnormal_effects::model::Column::CharId.eq(char_id))
+        .all(conn)
+        .await?;
+    let reuse_models = reuse_model::Entity::find()
+        .filter(reuse_model::Column::CharId.eq(char_id))
+        .all(conn)
+        .await?;
+    Ok((effect_models, reuse_models))
+}
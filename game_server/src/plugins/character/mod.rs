@@ -14,17 +14,26 @@ use l2r_core::{
     plugins::custom_hierarchy::*,
 };
 use sea_orm::{ColumnTrait, QueryFilter, UpdateResult, prelude::Expr};
-use std::sync::atomic::Ordering;
 use uuid::Uuid;
 
+mod autosave;
 mod creation_menu;
+mod detached;
+mod effects;
+mod status;
+
+pub(crate) use detached::save_detached_until;
 
 pub struct CharacterPlugin;
 impl Plugin for CharacterPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(CharacterComponentsPlugin);
 
-        app.add_plugins(creation_menu::CharacterCreationPlugin);
+        app.add_plugins(autosave::AutosavePlugin)
+            .add_plugins(creation_menu::CharacterCreationPlugin)
+            .add_plugins(status::CharacterStatusPlugin)
+            .add_plugins(detached::DetachedPlugin)
+            .add_plugins(effects::CharacterEffectsPlugin);
 
         app.add_observer(save_char_to_database);
 
@@ -62,46 +71,60 @@ fn sort_entities_into_folders(
 fn save_char_to_database(
     save: Trigger<CharacterSave>,
     mut commands: Commands,
-    characters: Query<(character::Query, Ref<DespawnChildOf>)>,
+    characters: Query<(character::Query, Option<Ref<DespawnChildOf>>)>,
     items_query: ItemsQuery,
     mut chars_tables: Query<Mut<character::Table>>,
     repo_manager: Res<RepositoryManager>,
+    mut shutdown_state: ResMut<ShutdownState>,
 ) -> Result<()> {
+    let character_entity = save.target();
+    let (character, session) = characters.get(character_entity)?;
     if repo_manager.is_mock() {
+        shutdown_state.save_confirmed(*character.object_id);
         return Ok(());
     }
-    let character_entity = save.target();
     let character_repository = repo_manager.typed::<ObjectId, character::model::Entity>()?;
-    let (character, session) = characters.get(character_entity)?;
-    let mut chars_table = chars_tables.get_mut(**session)?;
-    chars_table.update_bundle(&character, &items_query);
+    // Linkdead characters have no session to keep the table of
+    if let Some(session) = session {
+        chars_tables
+            .get_mut(**session)?
+            .update_bundle(&character, &items_query);
+    }
     let char_name = character.name.to_string().clone();
 
     let char_id = *character.object_id;
     let model_update = ModelUpdate::from(&character);
 
     commands.spawn_task(move || async move {
-        let char_model = character_repository.find_by_id(char_id).await?;
-
-        let char_model = match char_model {
-            Some(model) => model,
-            None => {
-                log::warn!("No character model found for {}, ID {}", char_name, char_id);
-                return Ok(());
-            }
-        };
+        let saved = save_character(char_id, &char_name, model_update, &character_repository).await;
+        AsyncWorld
+            .resource::<ShutdownState>()
+            .get_mut(|state| match &saved {
+                Ok(true) => state.save_confirmed(char_id),
+                Ok(false) | Err(_) => state.save_failed(char_id),
+            })?;
+        saved?;
+        Ok(())
+    });
+    Ok(())
+}
 
-        reset_last_active_character(char_model.account_id, &character_repository).await?;
+/// Returns `false` if the character has no row to update.
+async fn save_character(
+    char_id: ObjectId,
+    char_name: &str,
+    model_update: ModelUpdate,
+    character_repository: &CharacterRepository,
+) -> Result<bool, DbError> {
+    let Some(char_model) = character_repository.find_by_id(char_id).await? else {
+        log::warn!("No character model found for {}, ID {}", char_name, char_id);
+        return Ok(false);
+    };
 
-        character_repository
-            .update(&char_model.update(model_update))
-            .await?;
+    reset_last_active_character(char_model.account_id, character_repository).await?;
 
-        AsyncWorld.resource::<ShutdownState>().get_mut(|state| {
-            if state.shutdown_requested.load(Ordering::Acquire) {
-                state.saved_chars += 1;
-            }
-        })
-    });
-    Ok(())
+    character_repository
+        .update(&char_model.update(model_update))
+        .await?;
+    Ok(true)
 }
@@ -0,0 +1,171 @@
+use bevy::{log, prelude::*};
+use bevy_defer::AsyncCommandsExtension;
+use game_core::{
+    character::{
+        self, Character, CharacterSave, HERO_SKILLS, HERO_WEAPONS, Hero, NOBLESSE_SKILLS, Noblesse,
+        SetHero, SetNoblesse, skills::SkillPK,
+    },
+    items::{DestroyItemRequest, Inventory, Item},
+    network::{
+        broadcast::ServerPacketBroadcast,
+        packets::server::{BroadcastCharInfo, SendUserInfo, Social, SocialAction},
+    },
+    object_id::{ObjectId, ObjectIdManager},
+    skills::{self, Skill, SkillList},
+};
+use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
+use sea_orm::{ColumnTrait, QueryFilter};
+
+pub(super) struct CharacterStatusPlugin;
+impl Plugin for CharacterStatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(grant_noblesse_skills)
+            .add_observer(grant_hero_skills)
+            .add_observer(set_noblesse)
+            .add_observer(set_hero);
+    }
+}
+
+/// Status skills are not learned, they come with the status whenever it is added,
+/// including the spawn of the character.
+fn grant_noblesse_skills(added: Trigger<OnAdd, Noblesse>, skill_lists: Query<Mut<SkillList>>) {
+    grant_skills(skill_lists, added.target(), &NOBLESSE_SKILLS);
+}
+
+fn grant_hero_skills(added: Trigger<OnAdd, Hero>, skill_lists: Query<Mut<SkillList>>) {
+    grant_skills(skill_lists, added.target(), &HERO_SKILLS);
+}
+
+fn grant_skills(mut skill_lists: Query<Mut<SkillList>>, entity: Entity, skill_ids: &[skills::Id]) {
+    let Ok(mut skill_list) = skill_lists.get_mut(entity) else {
+        return;
+    };
+    for skill_id in skill_ids {
+        skill_list.add_skill(Skill::new(*skill_id, 1.into()));
+    }
+}
+
+fn set_noblesse(
+    set: Trigger<SetNoblesse>,
+    mut commands: Commands,
+    characters: Query<(Ref<ObjectId>, Has<Noblesse>), With<Character>>,
+    mut skill_lists: Query<Mut<SkillList>>,
+    repo_manager: Res<RepositoryManager>,
+) -> Result<()> {
+    let entity = set.target();
+    let SetNoblesse(noblesse) = *set.event();
+    let (char_id, current) = characters.get(entity)?;
+    if noblesse == current {
+        return Ok(());
+    }
+
+    if noblesse {
+        commands.entity(entity).insert(Noblesse);
+    } else {
+        commands.entity(entity).remove::<Noblesse>();
+        take_skills(
+            &mut commands,
+            &repo_manager,
+            &mut skill_lists,
+            entity,
+            *char_id,
+            &NOBLESSE_SKILLS,
+        )?;
+    }
+    log::info!("Character {} noblesse is set to {}", *char_id, noblesse);
+    status_changed(&mut commands, entity);
+    Ok(())
+}
+
+/// New heroes salute everybody around, the former ones lose the hero weapons.
+fn set_hero(
+    set: Trigger<SetHero>,
+    mut commands: Commands,
+    characters: Query<(Ref<ObjectId>, Ref<Inventory>, Has<Hero>), With<Character>>,
+    mut skill_lists: Query<Mut<SkillList>>,
+    items: Query<Ref<Item>>,
+    object_id_manager: Res<ObjectIdManager>,
+    repo_manager: Res<RepositoryManager>,
+) -> Result<()> {
+    let entity = set.target();
+    let SetHero(hero) = *set.event();
+    let (char_id, inventory, current) = characters.get(entity)?;
+    if hero == current {
+        return Ok(());
+    }
+
+    if hero {
+        commands.entity(entity).insert(Hero);
+        commands.trigger_targets(
+            ServerPacketBroadcast::new(SocialAction::new(*char_id, Social::Hero).into()),
+            entity,
+        );
+    } else {
+        commands.entity(entity).remove::<Hero>();
+        take_skills(
+            &mut commands,
+            &repo_manager,
+            &mut skill_lists,
+            entity,
+            *char_id,
+            &HERO_SKILLS,
+        )?;
+        for weapon_id in HERO_WEAPONS {
+            for (_, item_oid, _) in
+                inventory.get_by_item_id(weapon_id, &items, object_id_manager.as_ref())
+            {
+                commands.trigger_targets(DestroyItemRequest { item_oid, count: 1 }, entity);
+            }
+        }
+    }
+    log::info!("Character {} hero status is set to {}", *char_id, hero);
+    status_changed(&mut commands, entity);
+    Ok(())
+}
+
+/// Skills of the lost status are removed from the list and from the database,
+/// the list sync only stores the skills the character has.
+fn take_skills(
+    commands: &mut Commands,
+    repo_manager: &RepositoryManager,
+    skill_lists: &mut Query<Mut<SkillList>>,
+    entity: Entity,
+    char_id: ObjectId,
+    skill_ids: &'static [skills::Id],
+) -> Result<()> {
+    if let Ok(mut skill_list) = skill_lists.get_mut(entity) {
+        for skill_id in skill_ids {
+            skill_list.remove(skill_id);
+        }
+    }
+
+    if repo_manager.is_mock() {
+        return Ok(());
+    }
+    let skills_repository = repo_manager.typed::<SkillPK, character::skills::Entity>()?;
+    commands.spawn_task(move || async move {
+        let result = skills_repository
+            .delete_many(|query| {
+                query
+                    .filter(character::skills::Column::CharId.eq(char_id))
+                    .filter(character::skills::Column::SkillId.is_in(skill_ids.iter().copied()))
+            })
+            .await;
+        if let Err(err) = result {
+            log::error!(
+                "Character: {}, Failed to delete status skills: {:?}",
+                char_id,
+                err
+            );
+            return Err(err.into());
+        }
+        Ok(())
+    });
+    Ok(())
+}
+
+fn status_changed(commands: &mut Commands, entity: Entity) {
+    commands.trigger_targets(SendUserInfo, entity);
+    commands.trigger_targets(BroadcastCharInfo, entity);
+    commands.trigger_targets(CharacterSave, entity);
+}
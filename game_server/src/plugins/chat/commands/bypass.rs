@@ -1,3 +1,4 @@
+use crate::plugins::admin_menu::GmPermissions;
 use bevy::prelude::*;
 use bevy_slinet::server::PacketReceiveEvent;
 use game_core::{
@@ -20,15 +21,26 @@ impl Plugin for BypassCommandPlugin {
     }
 }
 
+/// Admin commands only go through when the access level grants their capability.
 fn handle(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     receive_params: PacketReceiveParams,
+    mut permissions: GmPermissions,
     mut commands: Commands,
 ) {
     let event = receive.event();
     if let GameClientPacket::BypassCommand(ref packet) = event.packet
         && let Ok(entity) = receive_params.character(&event.connection.id())
     {
+        if let BypassCommand::Admin(admin_command) = packet
+            && !permissions.authorize(
+                entity,
+                admin_command.capability(),
+                format!("{admin_command:?}"),
+            )
+        {
+            return;
+        }
         commands.trigger_targets(BypassCommandExecuted::from(packet.clone()), entity);
     }
 }
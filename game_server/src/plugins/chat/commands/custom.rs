@@ -1,13 +1,19 @@
-use bevy::{log, prelude::*};
+use avian3d::prelude::*;
+use bevy::{ecs::system::SystemParam, log, prelude::*};
 use game_core::{
+    action::wait_kind::WaitKind,
+    attack::InCombat,
+    character::DetachCharacter,
     chat::{self, CustomCommandExecuted},
     network::{
-        packets::server::{CreatureSay, GameServerPacket},
+        packets::server::{CreatureSay, GameServerPacket, SystemMessage},
         session::GameServerSession,
     },
     object_id::ObjectId,
 };
+use map::Town;
 use std::time::Duration;
+use system_messages::Id as SmId;
 
 pub struct CustomCommandsPlugin;
 impl Plugin for CustomCommandsPlugin {
@@ -22,10 +28,16 @@ fn custom_command_executed(
     mut commands: Commands,
     sessions: Query<Ref<GameServerSession>>,
     object_ids: Query<Ref<ObjectId>>,
+    offline_query: OfflineQuery,
+    config: Res<config::Config>,
 ) {
     let CustomCommandExecuted(cmd) = custom_command.event();
     let entity = custom_command.target();
 
+    if cmd.starts_with(".offline") {
+        offline(entity, &offline_query, &config, &mut commands);
+    }
+
     if cmd.starts_with(".ping")
         && let Ok(session) = sessions.get(entity)
     {
@@ -52,3 +64,56 @@ fn custom_command_executed(
         commands.trigger_targets(GameServerPacket::from(sending_packet), entity);
     }
 }
+
+#[derive(SystemParam)]
+struct OfflineQuery<'w, 's> {
+    characters: Query<
+        'w,
+        's,
+        (
+            Ref<'static, ObjectId>,
+            Ref<'static, Transform>,
+            Ref<'static, WaitKind>,
+            Has<InCombat>,
+        ),
+    >,
+    towns: Query<'w, 's, Ref<'static, Collider>, With<Town>>,
+}
+
+/// Character stays in the world sitting where it is, while the client goes away.
+fn offline(entity: Entity, query: &OfflineQuery, config: &config::Config, commands: &mut Commands) {
+    let Ok((object_id, transform, wait_kind, in_combat)) = query.characters.get(entity) else {
+        return;
+    };
+
+    if in_combat {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(
+                SmId::YouCannotExitTheGameWhileInCombat,
+            )),
+            entity,
+        );
+        return;
+    }
+
+    let in_town = query.towns.iter().any(|collider| {
+        collider.contains_point(Vec3::default(), Quat::default(), transform.translation)
+    });
+    let error = if config.offline().duration().is_none() {
+        "Offline mode is disabled."
+    } else if *wait_kind != WaitKind::Sit || !in_town {
+        "You can go offline only while sitting in a town."
+    } else {
+        commands.trigger_targets(DetachCharacter, entity);
+        return;
+    };
+
+    let sending_packet = CreatureSay::new(
+        *object_id,
+        "System".to_string(),
+        vec![error.to_string()],
+        chat::Kind::Announcement,
+        None,
+    );
+    commands.trigger_targets(GameServerPacket::from(sending_packet), entity);
+}
@@ -1,3 +1,4 @@
+use crate::plugins::admin_menu::GmPermissions;
 use bevy::prelude::*;
 use bevy_ecs::query::QueryData;
 use bevy_slinet::server::PacketReceiveEvent;
@@ -41,6 +42,7 @@ fn handle_packet(
     sessions: Res<ServerSessions>,
     character_tables: Query<Ref<character::Table>>,
     entities: Query<EntityQuery>,
+    mut permissions: GmPermissions,
     mut commands: Commands,
 ) -> Result {
     let event = receive.event();
@@ -53,6 +55,12 @@ fn handle_packet(
         sessions.get_character_entity(&event.connection.id(), &character_tables)?;
     let initiator_object_id = *entities.get(initiator_entity)?.object_id;
 
+    if let Some(capability) = packet.capability()
+        && !permissions.authorize(initiator_entity, capability, format!("//{packet:?}"))
+    {
+        return Ok(());
+    }
+
     match packet {
         DoubleSlashCommand::Unknown => {}
         DoubleSlashCommand::Admin => {
@@ -114,9 +122,7 @@ fn handle_packet(
             }
         }
         DoubleSlashCommand::Summon { id, count } => {
-            const NPC_ID_OFFSET: u32 = 1_000_000;
-
-            if *id < NPC_ID_OFFSET {
+            if *id < DoubleSlashCommand::SUMMON_NPC_ID_OFFSET {
                 commands.send_event(items::SpawnNew {
                     item_ids: vec![(*id).into()],
                     count: *count,
@@ -128,7 +134,7 @@ fn handle_packet(
             } else {
                 commands.trigger_targets(
                     npc::Spawn {
-                        id: (*id - NPC_ID_OFFSET).into(),
+                        id: (*id - DoubleSlashCommand::SUMMON_NPC_ID_OFFSET).into(),
                         transform: *entities.get(initiator_entity)?.transform,
                     },
                     initiator_entity,
@@ -3,6 +3,7 @@ use game_core::chat::Kind;
 use std::time::Duration;
 
 const CHAT_COOLDOWN_SECONDS: f32 = 0.2;
+const HERO_CHAT_COOLDOWN_SECONDS: f32 = 10.0;
 
 pub struct ChatFloodProtectionPlugin;
 impl Plugin for ChatFloodProtectionPlugin {
@@ -42,7 +43,11 @@ impl ChatCooldown {
     }
 
     pub fn start_cooldown(&mut self, kind: Kind) {
-        let timer = Timer::from_seconds(CHAT_COOLDOWN_SECONDS, TimerMode::Once);
+        let seconds = match kind {
+            Kind::Hero => HERO_CHAT_COOLDOWN_SECONDS,
+            _ => CHAT_COOLDOWN_SECONDS,
+        };
+        let timer = Timer::from_seconds(seconds, TimerMode::Once);
         self.timers.insert(kind, timer);
     }
 
@@ -2,8 +2,9 @@ use super::{chat_logger::LogChatMessage, flood_protection::ChatCooldown};
 use bevy::prelude::*;
 use bevy_slinet::server::PacketReceiveEvent;
 use game_core::{
-    character::Character,
+    character::{Character, Hero},
     chat::{CustomCommandExecuted, Kind},
+    friend::BlockList,
     network::{
         broadcast::ServerPacketBroadcast,
         config::GameServerNetworkConfig,
@@ -14,6 +15,7 @@ use game_core::{
         session::PacketReceiveParams,
     },
     object_id::ObjectId,
+    vehicle::{Boarded, Passengers},
 };
 use system_messages::Id as SystemMessageId;
 
@@ -30,21 +32,44 @@ fn handle(
     mut chat_logs: EventWriter<LogChatMessage>,
     mut commands: Commands,
     mut characters: Query<
-        (Entity, Ref<ObjectId>, Ref<Name>, Option<&mut ChatCooldown>),
+        (
+            Entity,
+            Ref<ObjectId>,
+            Ref<Name>,
+            Option<&mut ChatCooldown>,
+            Has<Hero>,
+            Option<Ref<Boarded>>,
+        ),
         With<Character>,
     >,
-    whisper_targets: Query<(Entity, Ref<Name>), With<Character>>,
+    passengers: Query<Ref<Passengers>>,
+    whisper_targets: Query<(Entity, Ref<Name>, Option<Ref<BlockList>>), With<Character>>,
 ) -> Result<()> {
     let event = receive.event();
     if let GameClientPacket::Say(ref packet) = event.packet {
         let character_entity = receive_params.character(&event.connection.id())?;
-        if let Ok((_, char_oid, char_name, cooldown_opt)) = characters.get_mut(character_entity) {
+        if let Ok((_, char_oid, char_name, cooldown_opt, hero, boarded)) =
+            characters.get_mut(character_entity)
+        {
             if packet.text.starts_with('.') {
                 commands
                     .trigger_targets(CustomCommandExecuted(packet.text.clone()), character_entity);
                 return Ok(());
             };
 
+            // Only heroes are heard in the hero chat
+            if packet.chat_type == Kind::Hero && !hero {
+                return Ok(());
+            }
+
+            // Boat chat is heard by the passengers of the same boat only
+            let boat_passengers = boarded
+                .and_then(|boarded| passengers.get(**boarded).ok())
+                .map(|passengers| passengers.iter().collect::<Vec<_>>());
+            if packet.chat_type == Kind::Boat && boat_passengers.is_none() {
+                return Ok(());
+            }
+
             // Check and handle cooldown for this specific chat type
             if let Some(mut cooldown) = cooldown_opt {
                 if !cooldown.can_send(&packet.chat_type) {
@@ -67,19 +92,51 @@ fn handle(
                 commands.entity(character_entity).try_insert(new_cooldown);
             }
 
-            let mut recievers = None;
+            let mut recievers = boat_passengers.filter(|_| packet.chat_type == Kind::Boat);
             let mut target_name_for_log = None;
             if packet.chat_type == Kind::Whisper
                 && let Some(target_name) = &packet.target
             {
                 target_name_for_log = Some(target_name.clone());
+
+                let sender_blocks_all = whisper_targets
+                    .get(character_entity)
+                    .ok()
+                    .and_then(|(_, _, block_list)| block_list)
+                    .is_some_and(|block_list| block_list.block_all);
+                if sender_blocks_all {
+                    commands.trigger_targets(
+                        GameServerPacket::SystemMessage(SystemMessage::new_empty(
+                            SystemMessageId::WhileBlockingEverythingWhisperingIsNotPossible,
+                        )),
+                        character_entity,
+                    );
+                    return Ok(());
+                }
+
                 let target_name_lower = target_name.to_lowercase();
-                let target_entities: Vec<Entity> = whisper_targets
+                let targets: Vec<(Entity, bool)> = whisper_targets
                     .iter()
-                    .filter(|(_, name)| name.to_string().to_lowercase() == target_name_lower)
-                    .map(|(entity, _)| entity)
+                    .filter(|(_, name, _)| name.to_string().to_lowercase() == target_name_lower)
+                    .map(|(entity, _, block_list)| {
+                        let blocked =
+                            block_list.is_some_and(|block_list| block_list.blocks(*char_oid));
+                        (entity, blocked)
+                    })
                     .collect();
 
+                if targets.iter().any(|(_, blocked)| *blocked) {
+                    commands.trigger_targets(
+                        GameServerPacket::SystemMessage(SystemMessage::new_empty(
+                            SystemMessageId::ThatPersonIsInMessageRefusalMode,
+                        )),
+                        character_entity,
+                    );
+                    return Ok(());
+                }
+                let target_entities: Vec<Entity> =
+                    targets.into_iter().map(|(entity, _)| entity).collect();
+
                 if target_entities.is_empty() {
                     commands.trigger_targets(
                         GameServerPacket::SystemMessage(SystemMessage::new(
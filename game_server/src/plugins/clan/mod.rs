@@ -0,0 +1,72 @@
+use bevy::{log, platform::collections::HashMap, prelude::*};
+use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use game_core::{
+    character,
+    clan::{self, Clan, ClanComponentsPlugin, Clans},
+    object_id::ObjectId,
+};
+use l2r_core::db::{DbConnection, Repository, RepositoryManager, TypedRepositoryManager};
+use sea_orm::{ColumnTrait, Condition};
+use state::LoadingSystems;
+
+/// Clans are loaded once at startup, they are created and managed outside of the game for now.
+pub(crate) struct ClanPlugin;
+impl Plugin for ClanPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ClanComponentsPlugin);
+
+        app.add_systems(Update, load_clans.in_set(LoadingSystems::IdInit));
+    }
+}
+
+fn load_clans(
+    mut commands: Commands,
+    repo_manager: Res<RepositoryManager>,
+    db_connection: Res<DbConnection>,
+    mut loaded: Local<bool>,
+) -> Result<()> {
+    if *loaded {
+        return Ok(());
+    }
+    *loaded = true;
+
+    if db_connection.is_mock() {
+        return Ok(());
+    }
+
+    let clans_repository = repo_manager.typed::<clan::Id, clan::model::Entity>()?;
+    let character_repository = repo_manager.typed::<ObjectId, character::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        let models = clans_repository
+            .find_with_conditions([Condition::all()])
+            .await?;
+
+        let leader_ids: Vec<ObjectId> = models.iter().map(|model| model.leader_id).collect();
+        let leader_names: HashMap<ObjectId, String> = if leader_ids.is_empty() {
+            HashMap::new()
+        } else {
+            character_repository
+                .find_with_conditions([character::model::Column::Id.is_in(leader_ids)])
+                .await?
+                .into_iter()
+                .map(|character| (character.id, character.name))
+                .collect()
+        };
+
+        let mut clans = Clans::default();
+        for model in models {
+            let leader_name = leader_names
+                .get(&model.leader_id)
+                .cloned()
+                .unwrap_or_default();
+            clans.insert(Clan::new(model, leader_name));
+        }
+
+        log::info!("Loaded {} clans from database.", clans.len());
+        AsyncWorld.apply_command(move |world: &mut World| {
+            world.insert_resource(clans);
+        });
+        Ok(())
+    });
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use super::castles_init::Castle;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum CastleManor {
+    Table,
+    CastleId,
+    ItemId,
+    NextPeriod,
+    Kind,
+    Amount,
+    StartAmount,
+    Price,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CastleManorMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CastleManorMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CastleManor::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(CastleManor::CastleId).integer().not_null())
+                    .col(ColumnDef::new(CastleManor::ItemId).integer().not_null())
+                    .col(ColumnDef::new(CastleManor::NextPeriod).boolean().not_null())
+                    .col(ColumnDef::new(CastleManor::Kind).small_integer().not_null())
+                    .col(ColumnDef::new(CastleManor::Amount).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(CastleManor::StartAmount)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CastleManor::Price).big_integer().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(CastleManor::CastleId)
+                            .col(CastleManor::ItemId)
+                            .col(CastleManor::NextPeriod),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_manor_castle_id")
+                            .from_tbl(CastleManor::Table)
+                            .from_col(CastleManor::CastleId)
+                            .to_tbl(Castle::Table)
+                            .to_col(Castle::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CastleManor::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,71 @@
+use super::{castles_init::Castle, clans_init::Clans};
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum CastleSiegeClans {
+    Table,
+    CastleId,
+    ClanId,
+    Side,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CastleSiegeClansMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CastleSiegeClansMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CastleSiegeClans::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CastleSiegeClans::CastleId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CastleSiegeClans::ClanId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CastleSiegeClans::Side)
+                            .small_integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(CastleSiegeClans::CastleId)
+                            .col(CastleSiegeClans::ClanId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_siege_castle_id")
+                            .from_tbl(CastleSiegeClans::Table)
+                            .from_col(CastleSiegeClans::CastleId)
+                            .to_tbl(Castle::Table)
+                            .to_col(Castle::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_siege_clan_id")
+                            .from_tbl(CastleSiegeClans::Table)
+                            .from_col(CastleSiegeClans::ClanId)
+                            .to_tbl(Clans::Table)
+                            .to_col(Clans::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CastleSiegeClans::Table).to_owned())
+            .await
+    }
+}
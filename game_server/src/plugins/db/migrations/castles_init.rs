@@ -0,0 +1,89 @@
+use super::clans_init::Clans;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+pub enum Castle {
+    Table,
+    Id,
+    Name,
+    OwnerClanId,
+    TaxPercent,
+    Treasury,
+    SiegeDate,
+    RegTimeOver,
+    RegTimeEnd,
+    ShowNpcCrest,
+    TicketBuyCount,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CastlesMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CastlesMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Castle::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Castle::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Castle::Name).string_len(16).not_null())
+                    .col(ColumnDef::new(Castle::OwnerClanId).integer().null())
+                    .col(
+                        ColumnDef::new(Castle::TaxPercent)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(Castle::Treasury)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(Castle::SiegeDate).timestamp().not_null())
+                    .col(
+                        ColumnDef::new(Castle::RegTimeOver)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(Castle::RegTimeEnd).timestamp().not_null())
+                    .col(
+                        ColumnDef::new(Castle::ShowNpcCrest)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(Castle::TicketBuyCount)
+                            .small_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_castle_owner_clan_id")
+                            .from_tbl(Castle::Table)
+                            .from_col(Castle::OwnerClanId)
+                            .to_tbl(Clans::Table)
+                            .to_col(Clans::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Castle::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,82 @@
+use crate::plugins::db::migrations::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct CharacterEffectsMigration;
+
+#[derive(DeriveIden)]
+pub enum CharacterEffects {
+    Table,
+    CharId,
+    SkillId,
+    SkillLevel,
+    MagicLevel,
+    Kind,
+    Active,
+    RemainingMs,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for CharacterEffectsMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CharacterEffects::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CharacterEffects::CharId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterEffects::SkillId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterEffects::SkillLevel)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterEffects::MagicLevel)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CharacterEffects::Kind).string().not_null())
+                    .col(
+                        ColumnDef::new(CharacterEffects::Active)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterEffects::RemainingMs)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(CharacterEffects::CharId)
+                            .col(CharacterEffects::SkillId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_character_effects_char_id")
+                            .from_tbl(CharacterEffects::Table)
+                            .from_col(CharacterEffects::CharId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CharacterEffects::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,72 @@
+use super::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum CharacterFriends {
+    Table,
+    CharId,
+    FriendId,
+    Blocked,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CharacterFriendsMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CharacterFriendsMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CharacterFriends::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CharacterFriends::CharId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterFriends::FriendId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterFriends::Blocked)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(CharacterFriends::CharId)
+                            .col(CharacterFriends::FriendId)
+                            .col(CharacterFriends::Blocked),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_char_id")
+                            .from_tbl(CharacterFriends::Table)
+                            .from_col(CharacterFriends::CharId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_friend_id")
+                            .from_tbl(CharacterFriends::Table)
+                            .from_col(CharacterFriends::FriendId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CharacterFriends::Table).to_owned())
+            .await
+    }
+}
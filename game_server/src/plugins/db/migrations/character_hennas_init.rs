@@ -0,0 +1,65 @@
+use super::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum CharacterHennas {
+    Table,
+    CharId,
+    Slot,
+    ClassVariant,
+    HennaId,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CharacterHennasMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CharacterHennasMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CharacterHennas::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(CharacterHennas::CharId).integer().not_null())
+                    .col(
+                        ColumnDef::new(CharacterHennas::Slot)
+                            .small_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterHennas::ClassVariant)
+                            .small_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterHennas::HennaId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(CharacterHennas::CharId)
+                            .col(CharacterHennas::Slot)
+                            .col(CharacterHennas::ClassVariant),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_char_id")
+                            .from_tbl(CharacterHennas::Table)
+                            .from_col(CharacterHennas::CharId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CharacterHennas::Table).to_owned())
+            .await
+    }
+}
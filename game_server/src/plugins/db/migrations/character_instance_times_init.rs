@@ -0,0 +1,66 @@
+use crate::plugins::db::migrations::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct CharacterInstanceTimesMigration;
+
+#[derive(DeriveIden)]
+pub enum CharacterInstanceTimes {
+    Table,
+    CharId,
+    TemplateId,
+    ReenterAt,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for CharacterInstanceTimesMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CharacterInstanceTimes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CharacterInstanceTimes::CharId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterInstanceTimes::TemplateId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterInstanceTimes::ReenterAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(CharacterInstanceTimes::CharId)
+                            .col(CharacterInstanceTimes::TemplateId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_instance_times_char_id")
+                            .from_tbl(CharacterInstanceTimes::Table)
+                            .from_col(CharacterInstanceTimes::CharId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(CharacterInstanceTimes::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,70 @@
+use super::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum CharacterMacros {
+    Table,
+    CharId,
+    MacroId,
+    Name,
+    Description,
+    Acronym,
+    Icon,
+    Commands,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CharacterMacrosMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CharacterMacrosMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CharacterMacros::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(CharacterMacros::CharId).integer().not_null())
+                    .col(
+                        ColumnDef::new(CharacterMacros::MacroId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CharacterMacros::Name).string().not_null())
+                    .col(
+                        ColumnDef::new(CharacterMacros::Description)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CharacterMacros::Acronym).string().not_null())
+                    .col(
+                        ColumnDef::new(CharacterMacros::Icon)
+                            .small_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(CharacterMacros::Commands).json().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(CharacterMacros::CharId)
+                            .col(CharacterMacros::MacroId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_char_id")
+                            .from_tbl(CharacterMacros::Table)
+                            .from_col(CharacterMacros::CharId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CharacterMacros::Table).to_owned())
+            .await
+    }
+}
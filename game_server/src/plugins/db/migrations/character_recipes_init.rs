@@ -0,0 +1,62 @@
+use super::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum CharacterRecipes {
+    Table,
+    CharId,
+    RecipeId,
+    Dwarven,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct CharacterRecipesMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CharacterRecipesMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CharacterRecipes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CharacterRecipes::CharId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterRecipes::RecipeId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterRecipes::Dwarven)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(CharacterRecipes::CharId)
+                            .col(CharacterRecipes::RecipeId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_char_id")
+                            .from_tbl(CharacterRecipes::Table)
+                            .from_col(CharacterRecipes::CharId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CharacterRecipes::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,62 @@
+use crate::plugins::db::migrations::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct CharacterSkillReuseMigration;
+
+#[derive(DeriveIden)]
+pub enum CharacterSkillReuse {
+    Table,
+    CharId,
+    SkillId,
+    ReuseUntil,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for CharacterSkillReuseMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CharacterSkillReuse::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CharacterSkillReuse::CharId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterSkillReuse::SkillId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CharacterSkillReuse::ReuseUntil)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(CharacterSkillReuse::CharId)
+                            .col(CharacterSkillReuse::SkillId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_character_skill_reuse_char_id")
+                            .from_tbl(CharacterSkillReuse::Table)
+                            .from_col(CharacterSkillReuse::CharId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CharacterSkillReuse::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,32 @@
+use super::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct CharactersDetachedMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CharactersDetachedMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Characters::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Characters::DetachedUntil).timestamp().null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Characters::Table)
+                    .drop_column(Characters::DetachedUntil)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -22,6 +22,9 @@ pub enum Characters {
     X,
     Y,
     Z,
+    Noblesse,
+    Hero,
+    DetachedUntil,
 }
 
 #[async_trait::async_trait]
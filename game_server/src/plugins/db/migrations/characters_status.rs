@@ -0,0 +1,42 @@
+use super::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct CharactersStatusMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for CharactersStatusMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Characters::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Characters::Noblesse)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Characters::Hero)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Characters::Table)
+                    .drop_column(Characters::Noblesse)
+                    .drop_column(Characters::Hero)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,57 @@
+use super::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+pub enum Clans {
+    Table,
+    Id,
+    Name,
+    LeaderId,
+    Level,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct ClansMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for ClansMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Clans::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Clans::Id).integer().not_null().primary_key())
+                    .col(
+                        ColumnDef::new(Clans::Name)
+                            .string_len(16)
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(Clans::LeaderId).integer().not_null())
+                    .col(
+                        ColumnDef::new(Clans::Level)
+                            .small_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_clan_leader_id")
+                            .from_tbl(Clans::Table)
+                            .from_col(Clans::LeaderId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Clans::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,75 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum GmAuditLog {
+    Table,
+    Id,
+    Account,
+    AccessLevel,
+    CharacterId,
+    CharacterName,
+    Capability,
+    Action,
+    Allowed,
+    CreatedAt,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct GmAuditLogMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for GmAuditLogMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GmAuditLog::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GmAuditLog::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(GmAuditLog::Account).string().not_null())
+                    .col(
+                        ColumnDef::new(GmAuditLog::AccessLevel)
+                            .small_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(GmAuditLog::CharacterId).integer().not_null())
+                    .col(
+                        ColumnDef::new(GmAuditLog::CharacterName)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(GmAuditLog::Capability).string().not_null())
+                    .col(ColumnDef::new(GmAuditLog::Action).string().not_null())
+                    .col(ColumnDef::new(GmAuditLog::Allowed).boolean().not_null())
+                    .col(ColumnDef::new(GmAuditLog::CreatedAt).timestamp().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_gm_audit_log_account")
+                    .table(GmAuditLog::Table)
+                    .col(GmAuditLog::Account)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_gm_audit_log_account").to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(GmAuditLog::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,62 @@
+use super::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum Heroes {
+    Table,
+    CharId,
+    ClassId,
+    Count,
+    Active,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct HeroesMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for HeroesMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Heroes::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Heroes::CharId)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Heroes::ClassId).integer().not_null())
+                    .col(
+                        ColumnDef::new(Heroes::Count)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(Heroes::Active)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_char_id")
+                            .from_tbl(Heroes::Table)
+                            .from_col(Heroes::CharId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Heroes::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum ItemJournal {
+    Table,
+    Id,
+    Kind,
+    Deltas,
+    CreatedAt,
+    AppliedAt,
+    FailedAt,
+    Error,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct ItemJournalMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for ItemJournalMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ItemJournal::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ItemJournal::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ItemJournal::Kind).string().not_null())
+                    .col(ColumnDef::new(ItemJournal::Deltas).json_binary().not_null())
+                    .col(
+                        ColumnDef::new(ItemJournal::CreatedAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ItemJournal::AppliedAt).timestamp().null())
+                    .col(ColumnDef::new(ItemJournal::FailedAt).timestamp().null())
+                    .col(ColumnDef::new(ItemJournal::Error).text().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_item_journal_applied_at")
+                    .table(ItemJournal::Table)
+                    .col(ItemJournal::AppliedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("idx_item_journal_applied_at").to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ItemJournal::Table).to_owned())
+            .await
+    }
+}
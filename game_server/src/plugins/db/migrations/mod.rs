@@ -5,15 +5,57 @@ use sea_orm::{DynIden, prelude::SeaRc, sea_query::Alias};
 use sea_orm_migration::{MigrationTrait, MigratorTrait, async_trait};
 use state::LoadingSystems;
 
+mod castle_manor_init;
+mod castle_siege_clans_init;
+mod castles_init;
+mod character_effects_init;
+mod character_friends_init;
+mod character_hennas_init;
+mod character_instance_times_init;
+mod character_macros_init;
+mod character_recipes_init;
 mod character_shortcuts_init;
+mod character_skill_reuse_init;
+mod characters_detached;
 mod characters_init;
 mod characters_skills_init;
+mod characters_status;
+mod clans_init;
+mod gm_audit_log_init;
+mod heroes_init;
+mod item_journal_init;
 mod items_init;
+mod olympiad_init;
+mod olympiad_nobles_init;
+mod raid_bosses_init;
+mod seven_signs_init;
+mod seven_signs_participants_init;
 
+use castle_manor_init::*;
+use castle_siege_clans_init::*;
+use castles_init::*;
+use character_effects_init::*;
+use character_friends_init::*;
+use character_hennas_init::*;
+use character_instance_times_init::*;
+use character_macros_init::*;
+use character_recipes_init::*;
 use character_shortcuts_init::*;
+use character_skill_reuse_init::*;
+use characters_detached::*;
 use characters_init::*;
 use characters_skills_init::*;
+use characters_status::*;
+use clans_init::*;
+use gm_audit_log_init::*;
+use heroes_init::*;
+use item_journal_init::*;
 use items_init::*;
+use olympiad_init::*;
+use olympiad_nobles_init::*;
+use raid_bosses_init::*;
+use seven_signs_init::*;
+use seven_signs_participants_init::*;
 
 pub struct GameServerMigrationPlugin;
 impl Plugin for GameServerMigrationPlugin {
@@ -83,6 +125,27 @@ impl MigratorTrait for GameServerMigrator {
             Box::new(ItemsMigration),
             Box::new(CharacterShortcutsMigration),
             Box::new(CharacterSkillsMigration),
+            Box::new(CharacterHennasMigration),
+            Box::new(CharacterRecipesMigration),
+            Box::new(CharacterFriendsMigration),
+            Box::new(CharacterMacrosMigration),
+            Box::new(ClansMigration),
+            Box::new(CastlesMigration),
+            Box::new(CastleSiegeClansMigration),
+            Box::new(CastleManorMigration),
+            Box::new(SevenSignsMigration),
+            Box::new(SevenSignsParticipantsMigration),
+            Box::new(OlympiadMigration),
+            Box::new(OlympiadNoblesMigration),
+            Box::new(HeroesMigration),
+            Box::new(CharactersStatusMigration),
+            Box::new(CharacterInstanceTimesMigration),
+            Box::new(RaidBossesMigration),
+            Box::new(CharactersDetachedMigration),
+            Box::new(ItemJournalMigration),
+            Box::new(GmAuditLogMigration),
+            Box::new(CharacterEffectsMigration),
+            Box::new(CharacterSkillReuseMigration),
         ]
     }
 
@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum Olympiad {
+    Table,
+    Id,
+    Cycle,
+    MonthEnd,
+    WeeklyPoints,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct OlympiadMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for OlympiadMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Olympiad::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Olympiad::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Olympiad::Cycle).integer().not_null())
+                    .col(ColumnDef::new(Olympiad::MonthEnd).timestamp().not_null())
+                    .col(
+                        ColumnDef::new(Olympiad::WeeklyPoints)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Olympiad::Table).to_owned())
+            .await
+    }
+}
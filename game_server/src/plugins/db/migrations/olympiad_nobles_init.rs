@@ -0,0 +1,78 @@
+use super::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum OlympiadNobles {
+    Table,
+    CharId,
+    ClassId,
+    Points,
+    CompetitionsDone,
+    CompetitionsWon,
+    CompetitionsLost,
+    CompetitionsDrawn,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct OlympiadNoblesMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for OlympiadNoblesMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(OlympiadNobles::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OlympiadNobles::CharId)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(OlympiadNobles::ClassId).integer().not_null())
+                    .col(ColumnDef::new(OlympiadNobles::Points).integer().not_null())
+                    .col(
+                        ColumnDef::new(OlympiadNobles::CompetitionsDone)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(OlympiadNobles::CompetitionsWon)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(OlympiadNobles::CompetitionsLost)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(OlympiadNobles::CompetitionsDrawn)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_char_id")
+                            .from_tbl(OlympiadNobles::Table)
+                            .from_col(OlympiadNobles::CharId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OlympiadNobles::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum RaidBosses {
+    Table,
+    NpcId,
+    RespawnAt,
+    Hp,
+    Mp,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct RaidBossesMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for RaidBossesMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RaidBosses::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RaidBosses::NpcId)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RaidBosses::RespawnAt).timestamp().null())
+                    .col(ColumnDef::new(RaidBosses::Hp).double().not_null())
+                    .col(ColumnDef::new(RaidBosses::Mp).double().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RaidBosses::Table).to_owned())
+            .await
+    }
+}
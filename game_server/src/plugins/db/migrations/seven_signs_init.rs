@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum SevenSigns {
+    Table,
+    Id,
+    Cycle,
+    Period,
+    PeriodEnd,
+    Winner,
+    AvariceOwner,
+    GnosisOwner,
+    StrifeOwner,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct SevenSignsMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for SevenSignsMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SevenSigns::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SevenSigns::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SevenSigns::Cycle).integer().not_null())
+                    .col(
+                        ColumnDef::new(SevenSigns::Period)
+                            .small_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SevenSigns::PeriodEnd).timestamp().not_null())
+                    .col(
+                        ColumnDef::new(SevenSigns::Winner)
+                            .small_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SevenSigns::AvariceOwner)
+                            .small_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SevenSigns::GnosisOwner)
+                            .small_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SevenSigns::StrifeOwner)
+                            .small_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SevenSigns::Table).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,90 @@
+use super::characters_init::Characters;
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveIden)]
+enum SevenSignsParticipants {
+    Table,
+    CharId,
+    Cabal,
+    Seal,
+    BlueStones,
+    GreenStones,
+    RedStones,
+    AncientAdena,
+}
+
+#[derive(DeriveMigrationName)]
+pub struct SevenSignsParticipantsMigration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for SevenSignsParticipantsMigration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SevenSignsParticipants::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SevenSignsParticipants::CharId)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SevenSignsParticipants::Cabal)
+                            .small_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SevenSignsParticipants::Seal)
+                            .small_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SevenSignsParticipants::BlueStones)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SevenSignsParticipants::GreenStones)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SevenSignsParticipants::RedStones)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SevenSignsParticipants::AncientAdena)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_char_id")
+                            .from_tbl(SevenSignsParticipants::Table)
+                            .from_col(SevenSignsParticipants::CharId)
+                            .to_tbl(Characters::Table)
+                            .to_col(Characters::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(SevenSignsParticipants::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -8,8 +8,55 @@ use game_core::{
         self, CharacterRepository,
         skills::{CharacterSkillsRepository, SkillPK},
     },
+    clan::{
+        self,
+        castle::{
+            self, CastleRepository,
+            siege::{
+                self,
+                model::{CastleSiegeClansRepository, SiegeClanPK},
+            },
+        },
+        model::ClansRepository,
+    },
+    friend::{
+        self,
+        model::{CharacterFriendsRepository, FriendPK},
+    },
+    henna::{
+        self,
+        model::{CharacterHennasRepository, HennaPK},
+    },
+    instance_zone::{
+        self,
+        model::{CharacterInstanceTimesRepository, InstanceTimePK},
+    },
     items::{self, ItemsRepository},
+    macros::{
+        self,
+        model::{CharacterMacrosRepository, MacroPK},
+    },
+    manor::{
+        self,
+        model::{CastleManorRepository, ManorPK},
+    },
     object_id::ObjectId,
+    olympiad::{
+        self,
+        hero::{self, model::HeroesRepository},
+        model::OlympiadRepository,
+        noble::{self, model::OlympiadNoblesRepository},
+    },
+    raid_boss::{self, model::RaidBossesRepository},
+    recipe::{
+        self,
+        model::{CharacterRecipesRepository, RecipePK},
+    },
+    seven_signs::{
+        self,
+        model::SevenSignsRepository,
+        participant::{self, model::SevenSignsParticipantsRepository},
+    },
     shortcut::{
         self,
         model::{CharacterShortcutsRepository, ShortcutPK},
@@ -17,8 +64,12 @@ use game_core::{
     skills,
     stats::SubClassVariant,
 };
-use l2r_core::db::{
-    DbConnection, DbRepositoryPlugin, PostgresPlugin, RedisConfig, RedisPlugin, RepositoryManager,
+use l2r_core::{
+    db::{
+        DbConnection, DbRepositoryPlugin, PostgresPlugin, RedisConfig, RedisPlugin,
+        RepositoryManager,
+    },
+    model::account::{ACCOUNT_ACCESS_REPOSITORY, AccountAccessRepository},
 };
 use sea_orm::ConnectOptions;
 use state::GameServerStateSystems;
@@ -88,6 +139,21 @@ pub enum GameRepoKey {
     Character(ObjectId),
     CharacterSkills(SkillPK),
     CharacterShortcuts(ShortcutPK),
+    CharacterHennas(HennaPK),
+    CharacterRecipes(RecipePK),
+    CharacterFriends(FriendPK),
+    CharacterMacros(MacroPK),
+    Clans(clan::Id),
+    Castles(i32),
+    CastleSiegeClans(SiegeClanPK),
+    CastleManor(ManorPK),
+    SevenSigns(i32),
+    SevenSignsParticipants(ObjectId),
+    Olympiad(i32),
+    OlympiadNobles(ObjectId),
+    Heroes(ObjectId),
+    CharacterInstanceTimes(InstanceTimePK),
+    RaidBosses(i32),
     Items(ObjectId),
 }
 
@@ -96,6 +162,21 @@ pub enum GameRepoModel {
     Character(character::model::Model),
     CharacterSkills(character::skills::Model),
     CharacterShortcuts(shortcut::model::Model),
+    CharacterHennas(henna::model::Model),
+    CharacterRecipes(recipe::model::Model),
+    CharacterFriends(friend::model::Model),
+    CharacterMacros(macros::model::Model),
+    Clans(clan::model::Model),
+    Castles(castle::model::Model),
+    CastleSiegeClans(siege::model::Model),
+    CastleManor(manor::model::Model),
+    SevenSigns(seven_signs::model::Model),
+    SevenSignsParticipants(participant::model::Model),
+    Olympiad(olympiad::model::Model),
+    OlympiadNobles(noble::model::Model),
+    Heroes(hero::model::Model),
+    CharacterInstanceTimes(instance_zone::model::Model),
+    RaidBosses(raid_boss::model::Model),
     Items(items::model::Model),
 }
 
@@ -105,6 +186,21 @@ impl From<&GameRepoModel> for GameRepoName {
             GameRepoModel::Character(_) => GameRepoName::Character,
             GameRepoModel::CharacterSkills(_) => GameRepoName::CharacterSkills,
             GameRepoModel::CharacterShortcuts(_) => GameRepoName::CharacterShortcuts,
+            GameRepoModel::CharacterHennas(_) => GameRepoName::CharacterHennas,
+            GameRepoModel::CharacterRecipes(_) => GameRepoName::CharacterRecipes,
+            GameRepoModel::CharacterFriends(_) => GameRepoName::CharacterFriends,
+            GameRepoModel::CharacterMacros(_) => GameRepoName::CharacterMacros,
+            GameRepoModel::Clans(_) => GameRepoName::Clans,
+            GameRepoModel::Castles(_) => GameRepoName::Castles,
+            GameRepoModel::CastleSiegeClans(_) => GameRepoName::CastleSiegeClans,
+            GameRepoModel::CastleManor(_) => GameRepoName::CastleManor,
+            GameRepoModel::SevenSigns(_) => GameRepoName::SevenSigns,
+            GameRepoModel::SevenSignsParticipants(_) => GameRepoName::SevenSignsParticipants,
+            GameRepoModel::Olympiad(_) => GameRepoName::Olympiad,
+            GameRepoModel::OlympiadNobles(_) => GameRepoName::OlympiadNobles,
+            GameRepoModel::Heroes(_) => GameRepoName::Heroes,
+            GameRepoModel::CharacterInstanceTimes(_) => GameRepoName::CharacterInstanceTimes,
+            GameRepoModel::RaidBosses(_) => GameRepoName::RaidBosses,
             GameRepoModel::Items(_) => GameRepoName::Items,
         }
     }
@@ -135,9 +231,48 @@ impl<'a> TryFrom<(&ReflectReference, WorldAccessGuard<'a>)> for GameRepoModel {
             model_ref.downcast::<game_core::shortcut::model::Model>(world_guard.clone())
         {
             Ok(GameRepoModel::CharacterShortcuts(model))
+        } else if let Ok(model) = model_ref.downcast::<henna::model::Model>(world_guard.clone()) {
+            Ok(GameRepoModel::CharacterHennas(model))
+        } else if let Ok(model) = model_ref.downcast::<recipe::model::Model>(world_guard.clone()) {
+            Ok(GameRepoModel::CharacterRecipes(model))
+        } else if let Ok(model) = model_ref.downcast::<friend::model::Model>(world_guard.clone()) {
+            Ok(GameRepoModel::CharacterFriends(model))
+        } else if let Ok(model) = model_ref.downcast::<macros::model::Model>(world_guard.clone()) {
+            Ok(GameRepoModel::CharacterMacros(model))
+        } else if let Ok(model) = model_ref.downcast::<clan::model::Model>(world_guard.clone()) {
+            Ok(GameRepoModel::Clans(model))
+        } else if let Ok(model) = model_ref.downcast::<castle::model::Model>(world_guard.clone()) {
+            Ok(GameRepoModel::Castles(model))
+        } else if let Ok(model) = model_ref.downcast::<siege::model::Model>(world_guard.clone()) {
+            Ok(GameRepoModel::CastleSiegeClans(model))
+        } else if let Ok(model) = model_ref.downcast::<manor::model::Model>(world_guard.clone()) {
+            Ok(GameRepoModel::CastleManor(model))
+        } else if let Ok(model) =
+            model_ref.downcast::<seven_signs::model::Model>(world_guard.clone())
+        {
+            Ok(GameRepoModel::SevenSigns(model))
+        } else if let Ok(model) =
+            model_ref.downcast::<participant::model::Model>(world_guard.clone())
+        {
+            Ok(GameRepoModel::SevenSignsParticipants(model))
+        } else if let Ok(model) = model_ref.downcast::<olympiad::model::Model>(world_guard.clone())
+        {
+            Ok(GameRepoModel::Olympiad(model))
+        } else if let Ok(model) = model_ref.downcast::<noble::model::Model>(world_guard.clone()) {
+            Ok(GameRepoModel::OlympiadNobles(model))
+        } else if let Ok(model) = model_ref.downcast::<hero::model::Model>(world_guard.clone()) {
+            Ok(GameRepoModel::Heroes(model))
+        } else if let Ok(model) =
+            model_ref.downcast::<instance_zone::model::Model>(world_guard.clone())
+        {
+            Ok(GameRepoModel::CharacterInstanceTimes(model))
+        } else if let Ok(model) = model_ref.downcast::<raid_boss::model::Model>(world_guard.clone())
+        {
+            Ok(GameRepoModel::RaidBosses(model))
         } else {
             Err(InteropError::string_type_mismatch(
-                "one of: Character, CharacterSkills, Items, CharacterShortcuts".to_string(),
+                "one of: Character, CharacterSkills, Items, CharacterShortcuts, CharacterHennas, CharacterRecipes, CharacterFriends, CharacterMacros, Clans, Castles, CastleSiegeClans, CastleManor, SevenSigns, SevenSignsParticipants, Olympiad, OlympiadNobles, Heroes, CharacterInstanceTimes, RaidBosses"
+                    .to_string(),
                 None,
             )
             .with_context("Failed to downcast model to any known repository type"))
@@ -267,6 +402,380 @@ impl TryFrom<(&GameRepoName, &ScriptValue)> for GameRepoKey {
                     ).with_context("CharacterShortcuts key")),
                 }
             }
+            GameRepoName::CharacterHennas => {
+                // For CharacterHennas, we expect a list with [char_id, slot, class_variant]
+                match key_value {
+                    ScriptValue::List(list) if list.len() == 3 => {
+                        let char_id = ObjectId::try_from(&list[0]).map_err(|_| {
+                            InteropError::value_mismatch(
+                                std::any::TypeId::of::<ObjectId>(),
+                                list[0].clone(),
+                            )
+                            .with_context("character ID in HennaPK")
+                        })?;
+
+                        let slot = match &list[1] {
+                            ScriptValue::Integer(slot) => *slot as i16,
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<i64>(),
+                                    other.clone(),
+                                )
+                                .with_context("slot in HennaPK"));
+                            }
+                        };
+
+                        let class_variant = match &list[2] {
+                            ScriptValue::Integer(class) => SubClassVariant::try_from(*class as i16)
+                                .map_err(|e| {
+                                    InteropError::external(e)
+                                        .with_context("Invalid SubClass value in HennaPK")
+                                })?,
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<i64>(),
+                                    other.clone(),
+                                )
+                                .with_context("class_variant in HennaPK"));
+                            }
+                        };
+
+                        Ok(GameRepoKey::CharacterHennas(HennaPK {
+                            char_id,
+                            slot,
+                            class_variant,
+                        }))
+                    }
+                    ScriptValue::List(list) => Err(InteropError::length_mismatch(3, list.len())
+                        .with_context(
+                            "CharacterHennas requires a list of [char_id, slot, class_variant]",
+                        )),
+                    _ => Err(InteropError::string_type_mismatch(
+                        "List[ObjectId, Integer, Integer]".to_string(),
+                        None,
+                    )
+                    .with_context("CharacterHennas key")),
+                }
+            }
+            GameRepoName::CharacterRecipes => {
+                // For CharacterRecipes, we expect a list with [char_id, recipe_id]
+                match key_value {
+                    ScriptValue::List(list) if list.len() == 2 => {
+                        let char_id = ObjectId::try_from(&list[0]).map_err(|_| {
+                            InteropError::value_mismatch(
+                                std::any::TypeId::of::<ObjectId>(),
+                                list[0].clone(),
+                            )
+                            .with_context("character ID in RecipePK")
+                        })?;
+
+                        let recipe_id = match &list[1] {
+                            ScriptValue::Integer(id) => recipe::Id::from(*id as u32),
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<i64>(),
+                                    other.clone(),
+                                )
+                                .with_context("recipe_id in RecipePK"));
+                            }
+                        };
+
+                        Ok(GameRepoKey::CharacterRecipes(RecipePK {
+                            char_id,
+                            recipe_id,
+                        }))
+                    }
+                    ScriptValue::List(list) => Err(InteropError::length_mismatch(2, list.len())
+                        .with_context("CharacterRecipes requires a list of [char_id, recipe_id]")),
+                    _ => Err(InteropError::string_type_mismatch(
+                        "List[ObjectId, Integer]".to_string(),
+                        None,
+                    )
+                    .with_context("CharacterRecipes key")),
+                }
+            }
+            GameRepoName::CharacterFriends => {
+                // For CharacterFriends, we expect a list with [char_id, friend_id, blocked]
+                match key_value {
+                    ScriptValue::List(list) if list.len() == 3 => {
+                        let char_id = ObjectId::try_from(&list[0]).map_err(|_| {
+                            InteropError::value_mismatch(
+                                std::any::TypeId::of::<ObjectId>(),
+                                list[0].clone(),
+                            )
+                            .with_context("character ID in FriendPK")
+                        })?;
+
+                        let friend_id = ObjectId::try_from(&list[1]).map_err(|_| {
+                            InteropError::value_mismatch(
+                                std::any::TypeId::of::<ObjectId>(),
+                                list[1].clone(),
+                            )
+                            .with_context("friend ID in FriendPK")
+                        })?;
+
+                        let blocked = match &list[2] {
+                            ScriptValue::Bool(blocked) => *blocked,
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<bool>(),
+                                    other.clone(),
+                                )
+                                .with_context("blocked in FriendPK"));
+                            }
+                        };
+
+                        Ok(GameRepoKey::CharacterFriends(FriendPK {
+                            char_id,
+                            friend_id,
+                            blocked,
+                        }))
+                    }
+                    ScriptValue::List(list) => Err(InteropError::length_mismatch(3, list.len())
+                        .with_context(
+                            "CharacterFriends requires a list of [char_id, friend_id, blocked]",
+                        )),
+                    _ => Err(InteropError::string_type_mismatch(
+                        "List[ObjectId, ObjectId, Bool]".to_string(),
+                        None,
+                    )
+                    .with_context("CharacterFriends key")),
+                }
+            }
+            GameRepoName::CharacterMacros => {
+                // For CharacterMacros, we expect a list with [char_id, macro_id]
+                match key_value {
+                    ScriptValue::List(list) if list.len() == 2 => {
+                        let char_id = ObjectId::try_from(&list[0]).map_err(|_| {
+                            InteropError::value_mismatch(
+                                std::any::TypeId::of::<ObjectId>(),
+                                list[0].clone(),
+                            )
+                            .with_context("character ID in MacroPK")
+                        })?;
+
+                        let macro_id = match &list[1] {
+                            ScriptValue::Integer(id) => macros::Id::from(*id as u32),
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<i64>(),
+                                    other.clone(),
+                                )
+                                .with_context("macro_id in MacroPK"));
+                            }
+                        };
+
+                        Ok(GameRepoKey::CharacterMacros(MacroPK { char_id, macro_id }))
+                    }
+                    ScriptValue::List(list) => Err(InteropError::length_mismatch(2, list.len())
+                        .with_context("CharacterMacros requires a list of [char_id, macro_id]")),
+                    _ => Err(InteropError::string_type_mismatch(
+                        "List[ObjectId, Integer]".to_string(),
+                        None,
+                    )
+                    .with_context("CharacterMacros key")),
+                }
+            }
+            GameRepoName::Clans => match key_value {
+                ScriptValue::Integer(id) => Ok(GameRepoKey::Clans(clan::Id::from(*id as u32))),
+                other => Err(InteropError::value_mismatch(
+                    std::any::TypeId::of::<i64>(),
+                    other.clone(),
+                )
+                .with_context("Clans key")),
+            },
+            GameRepoName::Castles => match key_value {
+                ScriptValue::Integer(id) => Ok(GameRepoKey::Castles(*id as i32)),
+                other => Err(InteropError::value_mismatch(
+                    std::any::TypeId::of::<i64>(),
+                    other.clone(),
+                )
+                .with_context("Castles key")),
+            },
+            GameRepoName::CastleSiegeClans => {
+                // For CastleSiegeClans, we expect a list with [castle_id, clan_id]
+                match key_value {
+                    ScriptValue::List(list) if list.len() == 2 => {
+                        let castle_id = match &list[0] {
+                            ScriptValue::Integer(id) => *id as i32,
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<i64>(),
+                                    other.clone(),
+                                )
+                                .with_context("castle_id in SiegeClanPK"));
+                            }
+                        };
+
+                        let clan_id = match &list[1] {
+                            ScriptValue::Integer(id) => clan::Id::from(*id as u32),
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<i64>(),
+                                    other.clone(),
+                                )
+                                .with_context("clan_id in SiegeClanPK"));
+                            }
+                        };
+
+                        Ok(GameRepoKey::CastleSiegeClans(SiegeClanPK {
+                            castle_id,
+                            clan_id,
+                        }))
+                    }
+                    ScriptValue::List(list) => Err(InteropError::length_mismatch(2, list.len())
+                        .with_context("CastleSiegeClans requires a list of [castle_id, clan_id]")),
+                    _ => Err(InteropError::string_type_mismatch(
+                        "List[Integer, Integer]".to_string(),
+                        None,
+                    )
+                    .with_context("CastleSiegeClans key")),
+                }
+            }
+            GameRepoName::CastleManor => {
+                // For CastleManor, we expect a list with [castle_id, item_id, next_period]
+                match key_value {
+                    ScriptValue::List(list) if list.len() == 3 => {
+                        let castle_id = match &list[0] {
+                            ScriptValue::Integer(id) => *id as i32,
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<i64>(),
+                                    other.clone(),
+                                )
+                                .with_context("castle_id in ManorPK"));
+                            }
+                        };
+
+                        let item_id = match &list[1] {
+                            ScriptValue::Integer(id) => items::Id::from(*id as u32),
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<i64>(),
+                                    other.clone(),
+                                )
+                                .with_context("item_id in ManorPK"));
+                            }
+                        };
+
+                        let next_period = match &list[2] {
+                            ScriptValue::Bool(next_period) => *next_period,
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<bool>(),
+                                    other.clone(),
+                                )
+                                .with_context("next_period in ManorPK"));
+                            }
+                        };
+
+                        Ok(GameRepoKey::CastleManor(ManorPK {
+                            castle_id,
+                            item_id,
+                            next_period,
+                        }))
+                    }
+                    ScriptValue::List(list) => Err(InteropError::length_mismatch(3, list.len())
+                        .with_context(
+                            "CastleManor requires a list of [castle_id, item_id, next_period]",
+                        )),
+                    _ => Err(InteropError::string_type_mismatch(
+                        "List[Integer, Integer, Boolean]".to_string(),
+                        None,
+                    )
+                    .with_context("CastleManor key")),
+                }
+            }
+            GameRepoName::SevenSigns => match key_value {
+                ScriptValue::Integer(id) => Ok(GameRepoKey::SevenSigns(*id as i32)),
+                other => Err(InteropError::value_mismatch(
+                    std::any::TypeId::of::<i64>(),
+                    other.clone(),
+                )
+                .with_context("SevenSigns key")),
+            },
+            GameRepoName::SevenSignsParticipants => {
+                let char_id = ObjectId::try_from(key_value).map_err(|_| {
+                    InteropError::value_mismatch(
+                        std::any::TypeId::of::<ObjectId>(),
+                        key_value.clone(),
+                    )
+                })?;
+                Ok(GameRepoKey::SevenSignsParticipants(char_id))
+            }
+            GameRepoName::Olympiad => match key_value {
+                ScriptValue::Integer(id) => Ok(GameRepoKey::Olympiad(*id as i32)),
+                other => Err(InteropError::value_mismatch(
+                    std::any::TypeId::of::<i64>(),
+                    other.clone(),
+                )
+                .with_context("Olympiad key")),
+            },
+            GameRepoName::OlympiadNobles => {
+                let char_id = ObjectId::try_from(key_value).map_err(|_| {
+                    InteropError::value_mismatch(
+                        std::any::TypeId::of::<ObjectId>(),
+                        key_value.clone(),
+                    )
+                })?;
+                Ok(GameRepoKey::OlympiadNobles(char_id))
+            }
+            GameRepoName::Heroes => {
+                let char_id = ObjectId::try_from(key_value).map_err(|_| {
+                    InteropError::value_mismatch(
+                        std::any::TypeId::of::<ObjectId>(),
+                        key_value.clone(),
+                    )
+                })?;
+                Ok(GameRepoKey::Heroes(char_id))
+            }
+            GameRepoName::CharacterInstanceTimes => {
+                // For CharacterInstanceTimes, we expect a list with [char_id, template_id]
+                match key_value {
+                    ScriptValue::List(list) if list.len() == 2 => {
+                        let char_id = ObjectId::try_from(&list[0]).map_err(|_| {
+                            InteropError::value_mismatch(
+                                std::any::TypeId::of::<ObjectId>(),
+                                list[0].clone(),
+                            )
+                            .with_context("character ID in InstanceTimePK")
+                        })?;
+
+                        let template_id = match &list[1] {
+                            ScriptValue::Integer(id) => *id as i32,
+                            other => {
+                                return Err(InteropError::value_mismatch(
+                                    std::any::TypeId::of::<i64>(),
+                                    other.clone(),
+                                )
+                                .with_context("template_id in InstanceTimePK"));
+                            }
+                        };
+
+                        Ok(GameRepoKey::CharacterInstanceTimes(InstanceTimePK {
+                            char_id,
+                            template_id,
+                        }))
+                    }
+                    ScriptValue::List(list) => Err(InteropError::length_mismatch(2, list.len())
+                        .with_context(
+                            "CharacterInstanceTimes requires a list of [char_id, template_id]",
+                        )),
+                    _ => Err(InteropError::string_type_mismatch(
+                        "List[Integer, Integer]".to_string(),
+                        None,
+                    )
+                    .with_context("CharacterInstanceTimes key")),
+                }
+            }
+            GameRepoName::RaidBosses => match key_value {
+                ScriptValue::Integer(id) => Ok(GameRepoKey::RaidBosses(*id as i32)),
+                other => Err(InteropError::value_mismatch(
+                    std::any::TypeId::of::<i64>(),
+                    other.clone(),
+                )
+                .with_context("RaidBosses key")),
+            },
             GameRepoName::Items => {
                 let object_id = ObjectId::try_from(key_value).map_err(|_| {
                     InteropError::value_mismatch(
@@ -295,6 +804,41 @@ impl Plugin for DbGameRepositoryPlugin {
             .register(ItemsRepository::new(GameRepoName::Items.as_ref()))
             .register(CharacterShortcutsRepository::new(
                 GameRepoName::CharacterShortcuts.as_ref(),
-            ));
+            ))
+            .register(CharacterHennasRepository::new(
+                GameRepoName::CharacterHennas.as_ref(),
+            ))
+            .register(CharacterRecipesRepository::new(
+                GameRepoName::CharacterRecipes.as_ref(),
+            ))
+            .register(CharacterFriendsRepository::new(
+                GameRepoName::CharacterFriends.as_ref(),
+            ))
+            .register(CharacterMacrosRepository::new(
+                GameRepoName::CharacterMacros.as_ref(),
+            ))
+            .register(ClansRepository::new(GameRepoName::Clans.as_ref()))
+            .register(CastleRepository::new(GameRepoName::Castles.as_ref()))
+            .register(CastleSiegeClansRepository::new(
+                GameRepoName::CastleSiegeClans.as_ref(),
+            ))
+            .register(CastleManorRepository::new(
+                GameRepoName::CastleManor.as_ref(),
+            ))
+            .register(SevenSignsRepository::new(GameRepoName::SevenSigns.as_ref()))
+            .register(SevenSignsParticipantsRepository::new(
+                GameRepoName::SevenSignsParticipants.as_ref(),
+            ))
+            .register(OlympiadRepository::new(GameRepoName::Olympiad.as_ref()))
+            .register(OlympiadNoblesRepository::new(
+                GameRepoName::OlympiadNobles.as_ref(),
+            ))
+            .register(HeroesRepository::new(GameRepoName::Heroes.as_ref()))
+            .register(CharacterInstanceTimesRepository::new(
+                GameRepoName::CharacterInstanceTimes.as_ref(),
+            ))
+            .register(RaidBossesRepository::new(GameRepoName::RaidBosses.as_ref()))
+            // Accounts are the login server's, only their access level is changed from here
+            .register(AccountAccessRepository::new(ACCOUNT_ACCESS_REPOSITORY));
     }
 }
@@ -1,26 +1,39 @@
+use super::{DbTask, completed};
 use crate::plugins::db::GameRepoModel;
 use game_core::{
     character::{self, skills::SkillPK},
+    clan::{
+        self,
+        castle::{
+            self,
+            siege::{self, model::SiegeClanPK},
+        },
+    },
+    friend::{self, model::FriendPK},
+    henna::{self, model::HennaPK},
+    instance_zone::{self, model::InstanceTimePK},
     items,
+    macros::{self, model::MacroPK},
+    manor::{self, model::ManorPK},
     object_id::ObjectId,
+    olympiad::{self, hero, noble},
+    raid_boss,
+    recipe::{self, model::RecipePK},
+    seven_signs::{self, participant},
     shortcut::model::ShortcutPK,
 };
-use l2r_core::{
-    db::{Repository, RepositoryModel, TypedRepositoryManager},
-    utils::block_on,
-};
+use l2r_core::db::{Repository, RepositoryManager, RepositoryModel, TypedRepositoryManager};
 use scripting::{
-    bindings::{FunctionCallContext, InteropError, ReflectReference},
+    bindings::{InteropError, ReflectReference, WorldAccessGuard},
     prelude::ScriptValue,
     utils::{ScriptValueToArguments, ValueWithOptional},
 };
 
-pub(crate) fn script_create_or_update(
-    ctx: FunctionCallContext,
-    data: ScriptValue,
-) -> std::result::Result<ScriptValue, InteropError> {
-    let world_guard = ctx.world()?;
-    let args = ValueWithOptional::from_script_value(&data)?;
+pub(crate) fn repository_model(
+    data: &ScriptValue,
+    world_guard: WorldAccessGuard<'_>,
+) -> Result<GameRepoModel, InteropError> {
+    let args = ValueWithOptional::from_script_value(data)?;
 
     let model_ref = match args.value {
         ScriptValue::Reference(ref_val) => ref_val,
@@ -36,48 +49,192 @@ pub(crate) fn script_create_or_update(
     // now using default on_conflict from the model
     let _on_conflict_value = args.optional_value;
 
-    let downcasted_model = GameRepoModel::try_from((model_ref, world_guard.clone()))?;
-    world_guard.with_global_access(|world| {
-        use l2r_core::db::RepositoryManager;
+    GameRepoModel::try_from((model_ref, world_guard))
+}
 
-        let registry = world.resource::<RepositoryManager>();
-        match downcasted_model {
-            GameRepoModel::Character(character_model) => {
-                let repo = registry.typed_interop::<ObjectId, character::model::Entity>()?;
-                block_on(|| async move {
-                    repo.create_or_update(&character_model, character::model::Model::on_conflict())
-                        .await
-                })?;
-                Ok(true.into())
-            }
-            GameRepoModel::CharacterSkills(skill_model) => {
-                let repo = registry.typed_interop::<SkillPK, character::skills::Entity>()?;
-                block_on(|| async move {
-                    repo.create_or_update(&skill_model, character::skills::Model::on_conflict())
-                        .await
-                })?;
-                Ok(true.into())
-            }
-            GameRepoModel::Items(item_model) => {
-                let repo = registry.typed_interop::<ObjectId, items::model::Entity>()?;
-                block_on(|| async move {
-                    repo.create_or_update(&item_model, items::model::Model::on_conflict())
-                        .await
-                })?;
-                Ok(true.into())
-            }
-            GameRepoModel::CharacterShortcuts(shortcut_model) => {
-                let repo =
-                    registry.typed_interop::<ShortcutPK, game_core::shortcut::model::Entity>()?;
-                block_on(|| async move {
-                    repo.create_or_update(
-                        &shortcut_model,
-                        game_core::shortcut::model::Model::on_conflict(),
-                    )
-                    .await
-                })?;
-                Ok(true.into())
-            }
-        }
-    })?
+/// Builds the upsert for the given model using the model's default on_conflict
+pub(crate) fn create_or_update_task(
+    registry: &RepositoryManager,
+    downcasted_model: GameRepoModel,
+) -> Result<DbTask, InteropError> {
+    Ok(match downcasted_model {
+        GameRepoModel::Character(character_model) => {
+            let repo = registry.typed_interop::<ObjectId, character::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&character_model, character::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::CharacterSkills(skill_model) => {
+            let repo = registry.typed_interop::<SkillPK, character::skills::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&skill_model, character::skills::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::Items(item_model) => {
+            let repo = registry.typed_interop::<ObjectId, items::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&item_model, items::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::CharacterShortcuts(shortcut_model) => {
+            let repo =
+                registry.typed_interop::<ShortcutPK, game_core::shortcut::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(
+                    &shortcut_model,
+                    game_core::shortcut::model::Model::on_conflict(),
+                )
+                .await
+                .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::CharacterHennas(henna_model) => {
+            let repo = registry.typed_interop::<HennaPK, henna::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&henna_model, henna::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::CharacterRecipes(recipe_model) => {
+            let repo = registry.typed_interop::<RecipePK, recipe::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&recipe_model, recipe::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::CharacterFriends(friend_model) => {
+            let repo = registry.typed_interop::<FriendPK, friend::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&friend_model, friend::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::CharacterMacros(macro_model) => {
+            let repo = registry.typed_interop::<MacroPK, macros::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&macro_model, macros::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::Clans(clan_model) => {
+            let repo = registry.typed_interop::<clan::Id, clan::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&clan_model, clan::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::Castles(castle_model) => {
+            let repo = registry.typed_interop::<i32, castle::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&castle_model, castle::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::CastleSiegeClans(siege_model) => {
+            let repo = registry.typed_interop::<SiegeClanPK, siege::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&siege_model, siege::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::CastleManor(manor_model) => {
+            let repo = registry.typed_interop::<ManorPK, manor::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&manor_model, manor::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::SevenSigns(seven_signs_model) => {
+            let repo = registry.typed_interop::<i32, seven_signs::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&seven_signs_model, seven_signs::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::SevenSignsParticipants(participant_model) => {
+            let repo = registry.typed_interop::<ObjectId, participant::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&participant_model, participant::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::Olympiad(olympiad_model) => {
+            let repo = registry.typed_interop::<i32, olympiad::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&olympiad_model, olympiad::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::OlympiadNobles(noble_model) => {
+            let repo = registry.typed_interop::<ObjectId, noble::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&noble_model, noble::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::Heroes(hero_model) => {
+            let repo = registry.typed_interop::<ObjectId, hero::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&hero_model, hero::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::CharacterInstanceTimes(instance_zone_model) => {
+            let repo = registry.typed_interop::<InstanceTimePK, instance_zone::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(
+                    &instance_zone_model,
+                    instance_zone::model::Model::on_conflict(),
+                )
+                .await
+                .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+        GameRepoModel::RaidBosses(raid_boss_model) => {
+            let repo = registry.typed_interop::<i32, raid_boss::model::Entity>()?;
+            Box::pin(async move {
+                repo.create_or_update(&raid_boss_model, raid_boss::model::Model::on_conflict())
+                    .await
+                    .map_err(|e| InteropError::external(Box::new(e)))?;
+                Ok(completed(true.into()))
+            })
+        }
+    })
 }
@@ -1,72 +1,156 @@
+use super::{DbTask, allocated};
 use crate::plugins::db::{GameRepoKey, GameRepoName};
-use bevy::prelude::*;
+use bevy::prelude::Reflect;
 use game_core::{
     character::{self, skills::SkillPK},
+    clan::{
+        self,
+        castle::{
+            self,
+            siege::{self, model::SiegeClanPK},
+        },
+    },
+    friend::{self, model::FriendPK},
+    henna::{self, model::HennaPK},
+    instance_zone::{self, model::InstanceTimePK},
     items,
+    macros::{self, model::MacroPK},
+    manor::{self, model::ManorPK},
     object_id::ObjectId,
+    olympiad::{self, hero, noble},
+    raid_boss,
+    recipe::{self, model::RecipePK},
+    seven_signs::{self, participant},
     shortcut::model::ShortcutPK,
 };
-use l2r_core::{
-    db::{Repository, TypedRepositoryManager},
-    utils::{AllocatedReflectExt, block_on},
-};
+use l2r_core::db::{DbRepository, RepositoryManager, TypedRepositoryManager};
 use scripting::{
-    bindings::{FunctionCallContext, InteropError},
+    bindings::InteropError,
     prelude::ScriptValue,
     utils::{ScriptValueToArguments, StringWithValue},
 };
+use sea_orm::{ConnectionTrait, DbErr, EntityTrait, IntoActiveModel, PrimaryKeyTrait};
 use std::str::FromStr;
 
-pub(crate) fn script_find_by_id(
-    ctx: FunctionCallContext,
-    data: ScriptValue,
-) -> std::result::Result<ScriptValue, InteropError> {
-    let args = StringWithValue::from_script_value(&data)?;
+pub(crate) fn repository_key(data: &ScriptValue) -> Result<GameRepoKey, InteropError> {
+    let args = StringWithValue::from_script_value(data)?;
     let repo_name_str = args.string;
-    let key_value = args.value;
     let repo_name = GameRepoName::from_str(&repo_name_str)
         .map_err(|_e| InteropError::invariant(format!("Unknown repository: {}", repo_name_str)))?;
-    let repository_key = GameRepoKey::try_from((&repo_name, key_value))?;
+    GameRepoKey::try_from((&repo_name, args.value))
+}
+
+/// Builds the lookup for the given key, the found model is allocated when the task completes
+pub(crate) fn find_by_id_task(
+    repo_manager: &RepositoryManager,
+    repository_key: GameRepoKey,
+    read_only: bool,
+) -> Result<DbTask, InteropError> {
+    Ok(match repository_key {
+        GameRepoKey::Character(object_id) => {
+            let repo = repo_manager.typed_interop::<ObjectId, character::model::Entity>()?;
+            lookup(repo, object_id, read_only)
+        }
+        GameRepoKey::CharacterSkills(skill_pk) => {
+            let repo = repo_manager.typed_interop::<SkillPK, character::skills::Entity>()?;
+            lookup(repo, skill_pk, read_only)
+        }
+        GameRepoKey::Items(object_id) => {
+            let repo = repo_manager.typed_interop::<ObjectId, items::model::Entity>()?;
+            lookup(repo, object_id, read_only)
+        }
+        GameRepoKey::CharacterShortcuts(shortcut_pk) => {
+            let repo =
+                repo_manager.typed_interop::<ShortcutPK, game_core::shortcut::model::Entity>()?;
+            lookup(repo, shortcut_pk, read_only)
+        }
+        GameRepoKey::CharacterHennas(henna_pk) => {
+            let repo = repo_manager.typed_interop::<HennaPK, henna::model::Entity>()?;
+            lookup(repo, henna_pk, read_only)
+        }
+        GameRepoKey::CharacterRecipes(recipe_pk) => {
+            let repo = repo_manager.typed_interop::<RecipePK, recipe::model::Entity>()?;
+            lookup(repo, recipe_pk, read_only)
+        }
+        GameRepoKey::CharacterFriends(friend_pk) => {
+            let repo = repo_manager.typed_interop::<FriendPK, friend::model::Entity>()?;
+            lookup(repo, friend_pk, read_only)
+        }
+        GameRepoKey::CharacterMacros(macro_pk) => {
+            let repo = repo_manager.typed_interop::<MacroPK, macros::model::Entity>()?;
+            lookup(repo, macro_pk, read_only)
+        }
+        GameRepoKey::Clans(id) => {
+            let repo = repo_manager.typed_interop::<clan::Id, clan::model::Entity>()?;
+            lookup(repo, id, read_only)
+        }
+        GameRepoKey::Castles(castle_id) => {
+            let repo = repo_manager.typed_interop::<i32, castle::model::Entity>()?;
+            lookup(repo, castle_id, read_only)
+        }
+        GameRepoKey::CastleSiegeClans(siege_clan_pk) => {
+            let repo = repo_manager.typed_interop::<SiegeClanPK, siege::model::Entity>()?;
+            lookup(repo, siege_clan_pk, read_only)
+        }
+        GameRepoKey::CastleManor(manor_pk) => {
+            let repo = repo_manager.typed_interop::<ManorPK, manor::model::Entity>()?;
+            lookup(repo, manor_pk, read_only)
+        }
+        GameRepoKey::SevenSigns(state_id) => {
+            let repo = repo_manager.typed_interop::<i32, seven_signs::model::Entity>()?;
+            lookup(repo, state_id, read_only)
+        }
+        GameRepoKey::SevenSignsParticipants(object_id) => {
+            let repo = repo_manager.typed_interop::<ObjectId, participant::model::Entity>()?;
+            lookup(repo, object_id, read_only)
+        }
+        GameRepoKey::Olympiad(state_id) => {
+            let repo = repo_manager.typed_interop::<i32, olympiad::model::Entity>()?;
+            lookup(repo, state_id, read_only)
+        }
+        GameRepoKey::OlympiadNobles(object_id) => {
+            let repo = repo_manager.typed_interop::<ObjectId, noble::model::Entity>()?;
+            lookup(repo, object_id, read_only)
+        }
+        GameRepoKey::Heroes(object_id) => {
+            let repo = repo_manager.typed_interop::<ObjectId, hero::model::Entity>()?;
+            lookup(repo, object_id, read_only)
+        }
+        GameRepoKey::CharacterInstanceTimes(instance_time_pk) => {
+            let repo =
+                repo_manager.typed_interop::<InstanceTimePK, instance_zone::model::Entity>()?;
+            lookup(repo, instance_time_pk, read_only)
+        }
+        GameRepoKey::RaidBosses(npc_id) => {
+            let repo = repo_manager.typed_interop::<i32, raid_boss::model::Entity>()?;
+            lookup(repo, npc_id, read_only)
+        }
+    })
+}
 
-    let world_guard = ctx.world()?;
-    world_guard.with_global_access(|world| {
-        use l2r_core::db::RepositoryManager;
+/// Lookups of read-only scripts run in a `READ ONLY` transaction, like their raw queries.
+fn lookup<PK, T>(repo: DbRepository<PK, T>, id: PK, read_only: bool) -> DbTask
+where
+    T: EntityTrait + Clone + Send + Sync + 'static,
+    T::Model: IntoActiveModel<T::ActiveModel> + Reflect + Send + Sync,
+    T::ActiveModel: Send + Sync,
+    PK: Into<<<T as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType>
+        + Send
+        + Sync
+        + 'static,
+{
+    Box::pin(async move {
+        let lookup_failed = |e: DbErr| InteropError::external(Box::new(e));
 
-        let repo_manager = world.resource::<RepositoryManager>();
+        let txn = repo.begin().await.map_err(lookup_failed)?;
+        if read_only {
+            txn.execute_unprepared("SET TRANSACTION READ ONLY")
+                .await
+                .map_err(lookup_failed)?;
+        }
+        let model = T::find_by_id(id).one(&txn).await.map_err(lookup_failed)?;
+        txn.commit().await.map_err(lookup_failed)?;
 
-        Ok(match repository_key {
-            GameRepoKey::Character(object_id) => repo_manager
-                .typed::<ObjectId, character::model::Entity>()
-                .map(|repo| {
-                    block_on(|| async move { repo.find_by_id(object_id).await })
-                        .map(|model| world.new_allocated(model))
-                        .unwrap_or_default()
-                })
-                .unwrap_or_default(),
-            GameRepoKey::CharacterSkills(skill_pk) => repo_manager
-                .typed::<SkillPK, character::skills::Entity>()
-                .map(|repo| {
-                    block_on(|| async move { repo.find_by_id(skill_pk).await })
-                        .map(|model| world.new_allocated(model))
-                        .unwrap_or_default()
-                })
-                .unwrap_or_default(),
-            GameRepoKey::Items(object_id) => repo_manager
-                .typed::<ObjectId, items::model::Entity>()
-                .map(|repo| {
-                    block_on(|| async move { repo.find_by_id(object_id).await })
-                        .map(|model| world.new_allocated(model))
-                        .unwrap_or_default()
-                })
-                .unwrap_or_default(),
-            GameRepoKey::CharacterShortcuts(shortcut_pk) => repo_manager
-                .typed::<ShortcutPK, game_core::shortcut::model::Entity>()
-                .map(|repo| {
-                    block_on(|| async move { repo.find_by_id(shortcut_pk).await })
-                        .map(|model| world.new_allocated(model))
-                        .unwrap_or_default()
-                })
-                .unwrap_or_default(),
-        })
-    })?
+        Ok(allocated(model))
+    })
 }
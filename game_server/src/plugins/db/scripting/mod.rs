@@ -1,30 +1,70 @@
-use bevy::prelude::*;
-use scripting::prelude::NamespaceBuilder;
+use bevy::{prelude::*, time::common_conditions::on_timer};
+use l2r_core::utils::AllocatedReflectExt;
+use scripting::{
+    bindings::InteropError,
+    core::callback_labels,
+    prelude::{NamespaceBuilder, ScriptValue},
+    scoped_event_handler,
+};
+use std::{future::Future, pin::Pin, time::Duration};
 
 mod create_or_update;
 mod find_by_id;
+mod pending;
 mod query_raw;
 
+pub use pending::ScriptDbRequests;
+
 // Wrapper struct for database operations namespace
 #[derive(Clone, Debug, Reflect)]
 pub struct DatabaseOps;
 
+/// Turns a finished query into the value handed to the script, runs on the main thread
+pub(crate) type Completion = Box<dyn FnOnce(&mut World) -> ScriptValue + Send>;
+
+/// Database work spawned off the main thread, see [`ScriptDbRequests`]
+pub(crate) type DbTask = Pin<Box<dyn Future<Output = Result<Completion, InteropError>> + Send>>;
+
+pub(crate) fn completed(value: ScriptValue) -> Completion {
+    Box::new(move |_| value)
+}
+
+pub(crate) fn allocated<T>(model: Option<T>) -> Completion
+where
+    T: Reflect + Send + 'static,
+{
+    Box::new(move |world| world.new_allocated(model))
+}
+
 pub struct DatabaseScriptingPlugin;
 
 impl Plugin for DatabaseScriptingPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<ScriptDbRequests>().add_systems(
+            Update,
+            (
+                scoped_event_handler::<OnDbResultFunction>,
+                pending::expire_results.run_if(on_timer(Duration::from_secs(10))),
+            ),
+        );
+
         // Register core database operations
         let world = app.world_mut();
         NamespaceBuilder::<DatabaseOps>::new(world)
-            .register("find_by_id", find_by_id::script_find_by_id)
+            .register("find_by_id_async", pending::script_find_by_id_async)
             .register(
-                "create_or_update",
-                create_or_update::script_create_or_update,
+                "create_or_update_async",
+                pending::script_create_or_update_async,
             )
-            .register("query_raw", query_raw::script_query_raw);
+            .register("query_raw_async", pending::script_query_raw_async)
+            .register("poll", pending::script_poll);
         // .register("find_by_conditions", script_find_by_conditions)
         // .register("update", script_update)
         // .register("delete", script_delete)
         // .register("execute_custom", script_execute_custom);
     }
 }
+
+callback_labels!(
+    OnDbResultFunction => "on_db_result",
+);
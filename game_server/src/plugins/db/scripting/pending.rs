@@ -0,0 +1,312 @@
+use super::{DbTask, OnDbResultFunction, create_or_update, find_by_id, query_raw};
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use config::Config;
+use l2r_core::db::{DbConnection, RepositoryManager};
+use scripting::{
+    CallingScript,
+    bindings::{FunctionCallContext, InteropError},
+    core::{
+        event::{Recipients, ScriptCallbackEvent},
+        script::ScriptAttachment,
+    },
+    prelude::ScriptValue,
+    utils::ScriptValueToArguments,
+};
+use std::{borrow::Cow, time::Duration};
+
+/// Finished results nobody polled are dropped after this time
+const RESULT_TTL: Duration = Duration::from_secs(60);
+
+/// Handles of database queries started by scripts with the `*_async` bindings.
+/// Results are kept until the script polls them or [`RESULT_TTL`] passes.
+#[derive(Default, Resource)]
+pub struct ScriptDbRequests {
+    next_handle: i64,
+    in_flight: HashMap<String, usize>,
+    finished: HashMap<i64, (Duration, ScriptValue)>,
+}
+
+impl ScriptDbRequests {
+    /// `None` while the script already has `max_concurrent` queries in flight
+    fn start(&mut self, script: &str, max_concurrent: usize) -> Option<i64> {
+        let in_flight = self.in_flight.entry(script.to_string()).or_default();
+        if *in_flight >= max_concurrent {
+            return None;
+        }
+        *in_flight += 1;
+        self.next_handle += 1;
+        Some(self.next_handle)
+    }
+
+    fn finish(&mut self, script: &str, handle: i64, result: ScriptValue, now: Duration) {
+        if let Some(in_flight) = self.in_flight.get_mut(script) {
+            *in_flight = in_flight.saturating_sub(1);
+            if *in_flight == 0 {
+                self.in_flight.remove(script);
+            }
+        }
+        self.finished.insert(handle, (now, result));
+    }
+
+    pub fn take(&mut self, handle: i64) -> Option<ScriptValue> {
+        self.finished.remove(&handle).map(|(_, result)| result)
+    }
+}
+
+/// Expects input as Map with keys: "args", "read_only" (optional)
+/// where "args" are the arguments of the query itself.
+/// The script is the one running the binding, scripts can't speak for each other.
+struct AsyncArgs<'a> {
+    read_only: bool,
+    args: &'a ScriptValue,
+}
+
+impl<'a> ScriptValueToArguments<'a> for AsyncArgs<'a> {
+    fn from_script_value(data: &'a ScriptValue) -> Result<Self, InteropError> {
+        let ScriptValue::Map(map) = data else {
+            return Err(InteropError::invariant(
+                "Expected Map with 'args'".to_string(),
+            ));
+        };
+
+        let args = map
+            .get("args")
+            .ok_or_else(|| InteropError::invariant("Missing 'args' parameter".to_string()))?;
+
+        let read_only = match map.get("read_only") {
+            Some(ScriptValue::Bool(b)) => *b,
+            None => false,
+            _ => {
+                return Err(InteropError::invariant(
+                    "'read_only' must be a boolean".to_string(),
+                ));
+            }
+        };
+
+        Ok(AsyncArgs { read_only, args })
+    }
+}
+
+fn calling_script() -> Result<CallingScript, InteropError> {
+    scripting::calling_script().ok_or_else(|| {
+        InteropError::invariant("Database bindings can only be called by scripts".to_string())
+    })
+}
+
+fn read_only(world: &World, script: &CallingScript, args: &AsyncArgs) -> bool {
+    args.read_only
+        || world
+            .resource::<Config>()
+            .script_db()
+            .is_read_only(&script.path)
+}
+
+/// Runs a raw SQL query and returns `{ handle }` right away, or `{ busy = true }`
+/// while the script has too many queries in flight.
+/// The result is delivered to `on_db_result(handle, result)` and can be fetched with `poll`.
+pub(crate) fn script_query_raw_async(
+    ctx: FunctionCallContext,
+    data: ScriptValue,
+) -> std::result::Result<ScriptValue, InteropError> {
+    let script = calling_script()?;
+    let args = AsyncArgs::from_script_value(&data)?;
+    let query = query_raw::QueryArgs::from_script_value(args.args)?;
+
+    let world_guard = ctx.world()?;
+    world_guard.with_global_access(|world| {
+        let read_only = read_only(world, &script, &args);
+        let task = query_raw::query_raw_task(world.resource::<DbConnection>(), query, read_only);
+        spawn(world, script, task)
+    })?
+}
+
+/// Finds a model by its repository key, returns `{ handle }` or `{ busy = true }` right away
+pub(crate) fn script_find_by_id_async(
+    ctx: FunctionCallContext,
+    data: ScriptValue,
+) -> std::result::Result<ScriptValue, InteropError> {
+    let script = calling_script()?;
+    let args = AsyncArgs::from_script_value(&data)?;
+    let repository_key = find_by_id::repository_key(args.args)?;
+
+    let world_guard = ctx.world()?;
+    world_guard.with_global_access(|world| {
+        let read_only = read_only(world, &script, &args);
+        let task = find_by_id::find_by_id_task(
+            world.resource::<RepositoryManager>(),
+            repository_key,
+            read_only,
+        )?;
+        spawn(world, script, task)
+    })?
+}
+
+/// Writes a model to its repository, returns `{ handle }` or `{ busy = true }` right away
+pub(crate) fn script_create_or_update_async(
+    ctx: FunctionCallContext,
+    data: ScriptValue,
+) -> std::result::Result<ScriptValue, InteropError> {
+    let script = calling_script()?;
+    let args = AsyncArgs::from_script_value(&data)?;
+
+    let world_guard = ctx.world()?;
+    let model = create_or_update::repository_model(args.args, world_guard.clone())?;
+    world_guard.with_global_access(|world| {
+        if read_only(world, &script, &args) {
+            return Err(InteropError::invariant(format!(
+                "Script {} is read-only and can't write to the database",
+                script.path
+            )));
+        }
+        let task =
+            create_or_update::create_or_update_task(world.resource::<RepositoryManager>(), model)?;
+        spawn(world, script, task)
+    })?
+}
+
+/// Returns nil while the query is running, otherwise `{ ok, value | error }` once.
+pub(crate) fn script_poll(
+    ctx: FunctionCallContext,
+    data: ScriptValue,
+) -> std::result::Result<ScriptValue, InteropError> {
+    let ScriptValue::Integer(handle) = data else {
+        return Err(InteropError::invariant(
+            "Expected integer query handle".to_string(),
+        ));
+    };
+
+    let world_guard = ctx.world()?;
+    world_guard.with_global_access(|world| {
+        world
+            .resource_mut::<ScriptDbRequests>()
+            .take(handle)
+            .unwrap_or_default()
+    })
+}
+
+fn spawn(
+    world: &mut World,
+    script: CallingScript,
+    task: DbTask,
+) -> Result<ScriptValue, InteropError> {
+    let script_db = world.resource::<Config>().script_db();
+    let timeout = script_db.timeout();
+    let max_concurrent = script_db.max_concurrent;
+    let Some(handle) = world
+        .resource_mut::<ScriptDbRequests>()
+        .start(&script.path, max_concurrent)
+    else {
+        return Ok(status_value("busy", ScriptValue::Bool(true)));
+    };
+
+    world.commands().spawn_task(move || async move {
+        let result = match async_std::future::timeout(timeout, task).await {
+            Ok(Ok(complete)) => Ok(complete),
+            Ok(Err(err)) => Err(err.to_string()),
+            Err(_) => Err(format!("Query timed out after {} ms", timeout.as_millis())),
+        };
+
+        AsyncWorld.apply_command(move |world: &mut World| {
+            let result = match result {
+                Ok(complete) => result_value(Ok(complete(world))),
+                Err(err) => {
+                    warn!("Script {} database query failed: {}", script.path, err);
+                    result_value(Err(err))
+                }
+            };
+            let now = world.resource::<Time>().elapsed();
+            world.resource_mut::<ScriptDbRequests>().finish(
+                &script.path,
+                handle,
+                result.clone(),
+                now,
+            );
+            // Only the script that made the query hears about it
+            world.send_event(ScriptCallbackEvent::new(
+                OnDbResultFunction,
+                vec![ScriptValue::Integer(handle), result],
+                recipient(script.attachment),
+                None,
+            ));
+        });
+        Ok(())
+    });
+
+    Ok(status_value("handle", ScriptValue::Integer(handle)))
+}
+
+fn recipient(attachment: ScriptAttachment) -> Recipients {
+    match attachment {
+        ScriptAttachment::EntityScript(entity, handle) => Recipients::ScriptEntity(handle, entity),
+        ScriptAttachment::StaticScript(handle) => Recipients::StaticScript(handle),
+    }
+}
+
+fn status_value(key: &str, value: ScriptValue) -> ScriptValue {
+    ScriptValue::Map([(key.to_string(), value)].into_iter().collect())
+}
+
+fn result_value(result: Result<ScriptValue, String>) -> ScriptValue {
+    let (ok, key, value) = match result {
+        Ok(value) => (true, "value", value),
+        Err(err) => (false, "error", ScriptValue::String(Cow::Owned(err))),
+    };
+    ScriptValue::Map(
+        [
+            ("ok".to_string(), ScriptValue::Bool(ok)),
+            (key.to_string(), value),
+        ]
+        .into_iter()
+        .collect(),
+    )
+}
+
+pub(super) fn expire_results(time: Res<Time>, mut requests: ResMut<ScriptDbRequests>) {
+    let now = time.elapsed();
+    requests
+        .finished
+        .retain(|_, (finished_at, _)| now.saturating_sub(*finished_at) < RESULT_TTL);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concurrency_limit_is_per_script() {
+        let mut requests = ScriptDbRequests::default();
+        let first = requests.start("scripts/runtime/skills/app.lua", 2).unwrap();
+        let second = requests.start("scripts/runtime/skills/app.lua", 2).unwrap();
+        assert_ne!(first, second);
+        assert!(
+            requests
+                .start("scripts/runtime/skills/app.lua", 2)
+                .is_none()
+        );
+        assert!(
+            requests
+                .start("scripts/runtime/quests/app.lua", 2)
+                .is_some()
+        );
+
+        requests.finish(
+            "scripts/runtime/skills/app.lua",
+            first,
+            ScriptValue::Unit,
+            Duration::ZERO,
+        );
+        assert!(
+            requests
+                .start("scripts/runtime/skills/app.lua", 2)
+                .is_some()
+        );
+        assert!(
+            requests
+                .start("scripts/runtime/skills/app.lua", 2)
+                .is_none()
+        );
+        assert!(requests.take(first).is_some());
+        assert!(requests.take(first).is_none());
+    }
+}
@@ -1,14 +1,10 @@
-use bevy::prelude::*;
-use l2r_core::{
-    db::DbConnection,
-    utils::{ScriptValueFromJson, block_on},
+use super::{DbTask, completed};
+use l2r_core::{db::DbConnection, utils::ScriptValueFromJson};
+use scripting::{bindings::InteropError, prelude::ScriptValue, utils::ScriptValueToArguments};
+use sea_orm::{
+    ConnectionTrait, DbErr, FromQueryResult, JsonValue, QueryResult, TransactionTrait,
+    sea_query::Value,
 };
-use scripting::{
-    bindings::{FunctionCallContext, InteropError},
-    prelude::ScriptValue,
-    utils::ScriptValueToArguments,
-};
-use sea_orm::{ConnectionTrait, FromQueryResult, JsonValue, QueryResult, sea_query::Value};
 
 /// Expects input as Map with keys: "sql", "params" (optional), "return_multiple" (optional)
 pub struct QueryArgs<'a> {
@@ -97,49 +93,42 @@ fn script_value_to_sea_orm_value(value: &ScriptValue) -> Result<Value, InteropEr
     }
 }
 
-/// Script binding for executing raw SQL queries that return QueryResult
-///
-/// Expected parameters (as Map):
-/// - repository_name: String - Name of the repository to use
-/// - sql: String - Raw SQL query
-/// - params: Array - Parameters for the SQL query to replace placeholders (optional)
-/// - return_multiple: bool - Whether to return multiple results (optional, default: false)
-///
-/// Returns: JSON representation of query results
-pub(crate) fn script_query_raw(
-    ctx: FunctionCallContext,
-    data: ScriptValue,
-) -> std::result::Result<ScriptValue, InteropError> {
-    let world_guard = ctx.world()?;
-
-    // Parse arguments from ScriptValue using ScriptValueToArguments
-    let args = QueryArgs::from_script_value(&data)?;
-
-    world_guard.with_global_access(|world| {
-        let connection = world.resource::<DbConnection>().connection();
-        let backend = connection.get_database_backend();
-        let statement = sea_orm::Statement::from_sql_and_values(backend, &args.sql, args.params);
+/// Builds the query future, results are converted to JSON before they reach the script.
+/// Queries of read-only scripts run in a `READ ONLY` transaction, so the database refuses any write.
+pub(crate) fn query_raw_task(
+    db_connection: &DbConnection,
+    args: QueryArgs<'_>,
+    read_only: bool,
+) -> DbTask {
+    let connection = db_connection.connection();
+    let backend = connection.get_database_backend();
+    let statement = sea_orm::Statement::from_sql_and_values(backend, &args.sql, args.params);
+    let return_multiple = args.return_multiple;
+
+    Box::pin(async move {
+        let query_failed =
+            |e: DbErr| InteropError::invariant(format!("Query execution failed: {}", e));
+
+        let txn = connection.begin().await.map_err(query_failed)?;
+        if read_only {
+            txn.execute_unprepared("SET TRANSACTION READ ONLY")
+                .await
+                .map_err(query_failed)?;
+        }
 
         // Execute query based on whether we want single or multiple results
-        let json_result =
-            if args.return_multiple {
-                block_on(|| async move {
-                    connection.query_all(statement).await.map_err(|e| {
-                        InteropError::invariant(format!("Query execution failed: {}", e))
-                    })
-                })
-                .map(query_results_to_json_array)?
-            } else {
-                block_on(|| async move {
-                    connection.query_one(statement).await.map_err(|e| {
-                        InteropError::invariant(format!("Query execution failed: {}", e))
-                    })
-                })
-                .map(query_result_to_json)?
-            };
+        let json_result = if return_multiple {
+            txn.query_all(statement)
+                .await
+                .map(query_results_to_json_array)
+        } else {
+            txn.query_one(statement).await.map(query_result_to_json)
+        }
+        .map_err(query_failed)?;
+        txn.commit().await.map_err(query_failed)?;
 
-        Ok(ScriptValue::from_json(json_result))
-    })?
+        Ok(completed(ScriptValue::from_json(json_result)))
+    })
 }
 
 fn query_result_to_json(result: Option<QueryResult>) -> JsonValue {
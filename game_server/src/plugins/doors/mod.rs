@@ -15,7 +15,9 @@ use game_core::{
         VitalsStats,
     },
 };
-use map::{Door, DoorCommand, DoorStatus, DoorsComponentsPlugin, MeshInfo, Zone, ZoneKind};
+use map::{
+    Door, DoorCommand, DoorKind, DoorStatus, DoorsComponentsPlugin, MeshInfo, Zone, ZoneKind,
+};
 use physics::GameLayer;
 
 mod query;
@@ -128,8 +130,19 @@ fn handle_door_command(
         return Ok(());
     }
 
+    set_door_status(&mut commands, door_entity, door_kind, new_status);
+    Ok(())
+}
+
+/// Opens or closes the door, open doors let characters pass through.
+pub(crate) fn set_door_status(
+    commands: &mut Commands,
+    door_entity: Entity,
+    door_kind: &DoorKind,
+    status: DoorStatus,
+) {
     if door_kind.check_collision {
-        match new_status {
+        match status {
             DoorStatus::Close => {
                 commands
                     .entity(door_entity)
@@ -142,8 +155,7 @@ fn handle_door_command(
             }
         }
     }
-    commands.entity(door_entity).insert(new_status);
-    Ok(())
+    commands.entity(door_entity).insert(status);
 }
 
 fn changed_door_status(
@@ -12,16 +12,19 @@ use bevy::{
 use game_core::{
     character::{self},
     encounters::*,
+    instance_zone::InstanceZoneId,
     items::{Item, ItemsDataAccess, ItemsDataQuery, ItemsQuery},
     movement::Movement,
     network::packets::server::{
         CharInfo, DeleteObject, DoorStatusUpdate, GameServerPacket, MoveToLocation, MoveToPawn,
-        NpcInfo, SpawnItem, StaticObjectInfo, StatusUpdate, StatusUpdateKind,
+        NpcInfo, SpawnItem, StaticObjectInfo, StatusUpdate, StatusUpdateKind, VehicleDeparture,
+        VehicleInfo,
     },
     npc::{self, RegionalNpcInfoQuery},
     object_id::ObjectId,
     stats::*,
     teleport::TeleportInProgress,
+    vehicle::Boat,
 };
 use physics::GameLayer;
 use state::GameServerStateSystems;
@@ -54,6 +57,7 @@ struct WantToKnowQuery<'a> {
     entity: Entity,
     transform: Ref<'a, Transform>,
     known_entities: Mut<'a, KnownEntities>,
+    instance: Option<Ref<'a, InstanceZoneId>>,
 }
 
 #[derive(QueryFilter)]
@@ -67,6 +71,7 @@ struct KnowersFilter {
 struct CanBeKnownQuery<'a> {
     entity: Entity,
     visible: Ref<'a, EncountersVisibility>,
+    instance: Option<Ref<'a, InstanceZoneId>>,
 }
 
 // System to add entities to KnownEntities when they come into range
@@ -86,6 +91,7 @@ fn set_relations(
             let entity_a = query_item.entity;
             let pos_a = query_item.transform;
             let mut known_entities = query_item.known_entities;
+            let instance_a = query_item.instance.as_deref();
 
             // Filter to find only entities on Character, Item, and Door layers
             let filter = SpatialQueryFilter::default()
@@ -103,6 +109,7 @@ fn set_relations(
             for entity_b in nearby_entities {
                 if let Ok(known_query) = can_be_known.get(entity_b)
                     && *known_query.visible == EncountersVisibility::Visible
+                    && known_query.instance.as_deref() == instance_a
                     && known_entities.insert(entity_b)
                 {
                     par_commands.command_scope(|mut commands| {
@@ -132,6 +139,7 @@ fn unset_relations(
             let entity_a = query_item.entity;
             let pos_a = query_item.transform;
             let mut known_entities = query_item.known_entities;
+            let instance_a = query_item.instance.as_deref();
 
             // Filter to find only entities on Character, Item, and Door layers
             let filter = SpatialQueryFilter::default()
@@ -153,14 +161,19 @@ fn unset_relations(
                     }
                 };
 
-                // Check if entity became Hidden
-                let is_hidden = can_be_known
+                // Check if entity became Hidden or moved to another instance
+                let (is_hidden, other_instance) = can_be_known
                     .get(entity_b)
-                    .map(|q| *q.visible == EncountersVisibility::Hidden)
-                    .unwrap_or(false);
-
-                // Remove if entity is no longer in range or became Hidden
-                if !nearby_entities.contains(&entity_b) || is_hidden {
+                    .map(|q| {
+                        (
+                            *q.visible == EncountersVisibility::Hidden,
+                            q.instance.as_deref() != instance_a,
+                        )
+                    })
+                    .unwrap_or((false, false));
+
+                // Remove if entity is no longer in range, became Hidden or is in another instance
+                if !nearby_entities.contains(&entity_b) || is_hidden || other_instance {
                     to_remove.push((entity_b, entity_b_oid));
                 }
             }
@@ -168,7 +181,7 @@ fn unset_relations(
             for (entity_b, entity_b_oid) in to_remove {
                 known_entities.remove(&entity_b);
                 par_commands.command_scope(|mut commands| {
-                    commands.trigger_targets(KnownRemoved::new(entity_b_oid), entity_a);
+                    commands.trigger_targets(KnownRemoved::new(entity_b, entity_b_oid), entity_a);
                 });
             }
         });
@@ -195,6 +208,13 @@ struct ObjectTransformQuery<'a> {
     transform: Ref<'a, Transform>,
 }
 
+#[derive(QueryData)]
+struct BoatQuery<'a> {
+    object_id: Ref<'a, ObjectId>,
+    transform: Ref<'a, Transform>,
+    boat: Ref<'a, Boat>,
+}
+
 #[derive(SystemParam)]
 struct KnownAddedParams<'w, 's> {
     chars: Query<'w, 's, character::Query<'static>>,
@@ -204,6 +224,7 @@ struct KnownAddedParams<'w, 's> {
     items_with_collider: Query<'w, 's, ItemQuery<'static>, With<Collider>>,
     items_query: ItemsQuery<'w, 's>,
     doors: Query<'w, 's, DoorQuery<'static>>,
+    boats: Query<'w, 's, BoatQuery<'static>>,
     movement: Query<'w, 's, MovementQuery<'static>>,
     object_transforms: Query<'w, 's, ObjectTransformQuery<'static>>,
 
@@ -213,11 +234,16 @@ struct KnownAddedParams<'w, 's> {
 fn handle_known_added(
     trigger: Trigger<KnownAdded>,
     params: KnownAddedParams,
+    mut known_by: Query<Mut<KnownBy>>,
     mut commands: Commands,
 ) -> Result<()> {
     let knower = trigger.target();
     let known = trigger.event().entity();
 
+    if let Ok(mut known_by) = known_by.get_mut(known) {
+        known_by.insert(knower);
+    }
+
     if let Ok(character) = params.chars.get(known) {
         let base_class = params
             .stats_table
@@ -329,8 +355,32 @@ fn handle_known_added(
         );
     }
 
-    // New known may moving right now
-    if let Ok(movement_query) = params.movement.get(known) {
+    if let Ok(boat_query) = params.boats.get(known) {
+        commands.trigger_targets(
+            GameServerPacket::from(VehicleInfo::new(
+                *boat_query.object_id,
+                *boat_query.transform,
+            )),
+            knower,
+        );
+
+        if let Some(course) = boat_query.boat.course() {
+            commands.trigger_targets(
+                GameServerPacket::from(VehicleDeparture::new(
+                    *boat_query.object_id,
+                    course.speed,
+                    course.rotation_speed,
+                    course.loc.into(),
+                )),
+                knower,
+            );
+        }
+    }
+
+    // New known may moving right now, boats have already told about their course
+    if !params.boats.contains(known)
+        && let Ok(movement_query) = params.movement.get(known)
+    {
         match movement_query.movement.as_ref() {
             Movement::ToLocation { waypoints } => {
                 if let Some(wp) = waypoints.front() {
@@ -364,25 +414,37 @@ fn handle_known_added(
     Ok(())
 }
 
-fn handle_known_removed(trigger: Trigger<KnownRemoved>, mut commands: Commands) {
+fn handle_known_removed(
+    trigger: Trigger<KnownRemoved>,
+    mut known_by: Query<Mut<KnownBy>>,
+    mut commands: Commands,
+) {
     let event = trigger.event();
     let knower = trigger.target();
 
+    if let Ok(mut known_by) = known_by.get_mut(event.entity()) {
+        known_by.remove(&knower);
+    }
+
     commands.trigger_targets(
         GameServerPacket::from(DeleteObject::new(event.object_id())),
         knower,
     );
 }
 
+/// Only the entities that knew the removed one are told about it.
 fn cleanup_known_entities(
     trigger: Trigger<KnownEntitiesRemoved>,
-    mut knowers: Query<(Entity, Mut<KnownEntities>)>,
+    mut knowers: Query<Mut<KnownEntities>>,
     mut commands: Commands,
 ) {
     let known_entity = trigger.target();
     let known_oid = trigger.event().object_id();
-    for (knower, mut known_entities) in knowers.iter_mut() {
-        known_entities.remove(&known_entity);
-        commands.trigger_targets(KnownRemoved::new(known_oid), knower)
+    for knower in trigger.event().known_by() {
+        if let Ok(mut known_entities) = knowers.get_mut(*knower)
+            && known_entities.remove(&known_entity)
+        {
+            commands.trigger_targets(KnownRemoved::new(known_entity, known_oid), *knower)
+        }
     }
 }
@@ -0,0 +1,87 @@
+use bevy::{log, prelude::*};
+use game_core::fishing::*;
+use l2r_core::chronicles::CHRONICLE;
+use state::{GameServerStateSystems, LoadingSystems};
+use std::path::PathBuf;
+
+pub struct FishingDataPlugin;
+impl Plugin for FishingDataPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FishingDataHandle>();
+
+        app.add_systems(
+            Update,
+            (
+                load_assets.in_set(LoadingSystems::AssetInit),
+                update_assets.in_set(LoadingSystems::AssetInit),
+            ),
+        );
+
+        app.add_systems(Update, update_assets.in_set(GameServerStateSystems::Run));
+    }
+}
+
+fn load_assets(
+    asset_server: Res<AssetServer>,
+    mut data_handle: ResMut<FishingDataHandle>,
+    mut loaded: Local<bool>,
+) {
+    if *loaded {
+        return;
+    }
+    let mut path = PathBuf::from("fishing");
+    path.push(CHRONICLE);
+    path.push("fishing");
+    path.set_extension("json");
+
+    let handle: Handle<FishingData> = asset_server.load(path.clone());
+    **data_handle = handle;
+    *loaded = true;
+}
+
+fn update_assets(handle: Res<FishingDataHandle>, mut events: EventReader<AssetEvent<FishingData>>) {
+    for event in events.read() {
+        if event.is_loaded_with_dependencies(handle.id()) {
+            log::info!("Fishing data updated");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use l2r_core::{assets::ASSET_DIR, chronicles::CHRONICLE, utils::get_base_path};
+    use serde_json::from_reader;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_parse_all_from_json() {
+        let mut path = get_base_path();
+        path.push(ASSET_DIR);
+        path.push("fishing");
+        path.push(CHRONICLE);
+        path.push("fishing");
+        path.set_extension("json");
+
+        let file = File::open(&path).unwrap_or_else(|_| panic!("Failed to open file: {:?}", path));
+        let reader = BufReader::new(file);
+
+        let result: FishingData = from_reader(reader)
+            .unwrap_or_else(|_| panic!("Failed to parse fishing data from JSON: {:?}", path));
+
+        for (place, grades) in result.places.iter() {
+            for (grade, levels) in grades.iter() {
+                assert!(
+                    levels.min <= levels.max,
+                    "Fish levels of {grade} lures at {place} are empty"
+                );
+            }
+            for (lure_id, lure) in result.lures.iter() {
+                assert!(
+                    !result.catch_table(place, lure).is_empty(),
+                    "Nothing takes the lure {lure_id} at {place}"
+                );
+            }
+        }
+    }
+}
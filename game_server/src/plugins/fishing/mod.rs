@@ -0,0 +1,463 @@
+use avian3d::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use game_core::{
+    character::Character,
+    fishing::{
+        CAST_DISTANCE, EXPERTISE_PENALTY_GAP, EXPERTISE_SKILL, FISHING_SKILL, Fishing,
+        FishingAction, FishingComponentsPlugin, FishingData, FishingDataHandle, FishingHit,
+        FishingOutcome, FishingSkillUse,
+    },
+    items::{
+        self, DestroyItemRequest, DollSlot, FishingShot, ItemLocation, ItemsDataAccess,
+        ItemsDataQuery, PaperDoll, SpawnNew,
+    },
+    movement::Movement,
+    network::{
+        broadcast::ServerPacketBroadcast,
+        packets::server::{
+            ActionFail, BroadcastCharInfo, ExFishingEnd, ExFishingHpRegen, ExFishingStart,
+            ExFishingStartCombat, GameServerPacket, SendUserInfo, SystemMessage,
+        },
+    },
+    object_id::ObjectId,
+    skills::SkillList,
+    stats::Movable,
+    vehicle::InVehicle,
+};
+use map::Zone;
+use rand::{Rng, seq::SliceRandom};
+use state::GameServerStateSystems;
+use system_messages::{Id as SmId, SmParam};
+
+mod data;
+mod skills;
+
+pub(crate) use skills::FishingSkillsQuery;
+
+/// Chance in percents the fish resists pumping or reeling.
+const RESIST_CHANCE: u32 = 10;
+
+/// Anglers cast the line into the fishing zones with a rod and a lure equipped.
+/// The hooked fish fights back, it is pumped while it fights and reeled in while it rests.
+/// uses [`ExFishingStart`], [`ExFishingStartCombat`], [`ExFishingHpRegen`], [`ExFishingEnd`] server packets,
+/// the fishing guild skills are learned from the fishermen.
+pub(crate) struct FishingPlugin;
+impl Plugin for FishingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(FishingComponentsPlugin)
+            .add_plugins(data::FishingDataPlugin)
+            .add_plugins(skills::FishingSkillsPlugin);
+
+        app.add_observer(cast)
+            .add_observer(pump_or_reel)
+            .add_observer(cancel_on_move);
+
+        app.add_systems(
+            Update,
+            (wait_for_bite, fight_fish)
+                .chain()
+                .in_set(GameServerStateSystems::Run),
+        );
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct FishingDataQuery<'w> {
+    handle: Res<'w, FishingDataHandle>,
+    assets: Res<'w, Assets<FishingData>>,
+}
+
+impl FishingDataQuery<'_> {
+    pub fn data(&self) -> Result<&FishingData> {
+        self.assets
+            .get(self.handle.id())
+            .ok_or_else(|| BevyError::from("Fishing data is not loaded"))
+    }
+}
+
+fn system_message(commands: &mut Commands, entity: Entity, message: SystemMessage) {
+    commands.trigger_targets(GameServerPacket::from(message), entity);
+}
+
+/// Reels the line in, the caught fish goes to the inventory.
+fn reel_in(
+    commands: &mut Commands,
+    entity: Entity,
+    object_id: ObjectId,
+    caught: Option<items::Id>,
+) {
+    commands.trigger_targets(
+        ServerPacketBroadcast::new(ExFishingEnd::new(object_id, caught.is_some()).into()),
+        entity,
+    );
+    if let Some(fish) = caught {
+        system_message(
+            commands,
+            entity,
+            SystemMessage::new_empty(SmId::YouCaughtSomething),
+        );
+        commands.send_event(SpawnNew {
+            item_ids: vec![fish],
+            count: 1,
+            item_location: ItemLocation::Inventory,
+            dropped_entity: None,
+            owner: Some(entity),
+            silent: false,
+        });
+    }
+    commands.entity(entity).remove::<Fishing>();
+    commands.trigger_targets(SendUserInfo, entity);
+    commands.trigger_targets(BroadcastCharInfo, entity);
+}
+
+fn combat_over(
+    commands: &mut Commands,
+    entity: Entity,
+    object_id: ObjectId,
+    fish: items::Id,
+    outcome: FishingOutcome,
+) {
+    match outcome {
+        FishingOutcome::Caught => reel_in(commands, entity, object_id, Some(fish)),
+        FishingOutcome::GotAway => {
+            system_message(
+                commands,
+                entity,
+                SystemMessage::new_empty(SmId::ThatFishIsMoreDeterminedThanYouAreItSpitTheHook),
+            );
+            reel_in(commands, entity, object_id, None);
+        }
+    }
+}
+
+fn cast(
+    used: Trigger<OnInsert, FishingSkillUse>,
+    mut commands: Commands,
+    fishing_data: FishingDataQuery,
+    items_data: ItemsDataQuery,
+    anglers: Query<
+        (
+            Ref<ObjectId>,
+            Ref<Transform>,
+            Ref<PaperDoll>,
+            Ref<Movable>,
+            Ref<FishingSkillUse>,
+            Has<InVehicle>,
+            Option<Ref<Fishing>>,
+        ),
+        With<Character>,
+    >,
+    zones: Query<(Ref<Zone>, Ref<Collider>), With<map::Fishing>>,
+) -> Result<()> {
+    let entity = used.target();
+    let (object_id, transform, paper_doll, movable, skill_use, in_vehicle, fishing) =
+        anglers.get(entity)?;
+    if skill_use.id() != FISHING_SKILL {
+        return Ok(());
+    }
+    commands.entity(entity).remove::<FishingSkillUse>();
+
+    // Casting again while waiting for a bite reels the line in
+    if let Some(fishing) = fishing {
+        if fishing.combat().is_none() {
+            system_message(
+                &mut commands,
+                entity,
+                SystemMessage::new_empty(SmId::YouReelYourLineInAndStopFishing),
+            );
+            reel_in(&mut commands, entity, *object_id, None);
+        }
+        return Ok(());
+    }
+
+    let mut fail = |message: SmId| {
+        system_message(&mut commands, entity, SystemMessage::new_empty(message));
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        Ok(())
+    };
+
+    let rod_equipped = paper_doll
+        .get(DollSlot::RightHand)
+        .and_then(|oid| items_data.info_by_object_id(oid).ok())
+        .is_some_and(|info| info.kind().fishing_rod());
+    if !rod_equipped {
+        return fail(SmId::YouDoNotHaveAFishingPoleEquipped);
+    }
+
+    let data = fishing_data.data()?;
+    let lure = paper_doll.get(DollSlot::LeftHand).and_then(|oid| {
+        let item = items_data.item_by_object_id(oid).ok()?;
+        let lure = data.lure(item.id())?;
+        Some((oid, item.id(), *lure))
+    });
+    let Some((lure_oid, lure_id, lure)) = lure else {
+        return fail(SmId::YouMustPutBaitOnYourHookBeforeYouCanFish);
+    };
+
+    if in_vehicle {
+        return fail(SmId::YouCannotFishWhileRidingAsAPassengerOfABoatItSAgainstTheRules);
+    }
+    if movable.in_water() {
+        return fail(SmId::YouCannotFishWhileUnderWater);
+    }
+
+    let bobber = transform.translation + transform.forward() * CAST_DISTANCE;
+    let place = zones.iter().find_map(|(zone, collider)| {
+        collider
+            .contains_point(Vec3::default(), Quat::default(), bobber)
+            .then(|| zone.name().cloned())
+            .flatten()
+    });
+    let Some(place) = place else {
+        return fail(SmId::YouCanTFishHere);
+    };
+
+    let bite = data
+        .catch_table(&place, &lure)
+        .choose(&mut rand::thread_rng())
+        .copied();
+
+    commands.trigger_targets(
+        DestroyItemRequest {
+            item_oid: lure_oid,
+            count: 1,
+        },
+        entity,
+    );
+    commands
+        .entity(entity)
+        .remove::<Movement>()
+        .insert(Fishing::new(lure_id, &lure, bobber, bite));
+
+    commands.trigger_targets(
+        ServerPacketBroadcast::new(
+            ExFishingStart::new(*object_id, lure.group.client_id(), bobber).into(),
+        ),
+        entity,
+    );
+    system_message(
+        &mut commands,
+        entity,
+        SystemMessage::new_empty(SmId::YouCastYourLineAndStartToFish),
+    );
+    commands.trigger_targets(SendUserInfo, entity);
+    commands.trigger_targets(BroadcastCharInfo, entity);
+    Ok(())
+}
+
+fn pump_or_reel(
+    used: Trigger<OnInsert, FishingSkillUse>,
+    mut commands: Commands,
+    items_data: ItemsDataQuery,
+    fishing_shots: Query<Has<FishingShot>>,
+    mut anglers: Query<
+        (
+            Ref<ObjectId>,
+            Ref<PaperDoll>,
+            Ref<FishingSkillUse>,
+            Option<Ref<SkillList>>,
+            Option<Mut<Fishing>>,
+        ),
+        With<Character>,
+    >,
+) -> Result<()> {
+    let entity = used.target();
+    let (object_id, paper_doll, skill_use, skill_list, fishing) = anglers.get_mut(entity)?;
+    let Some(action) = FishingAction::from_skill(skill_use.id()) else {
+        return Ok(());
+    };
+    let skill = **skill_use;
+    commands.entity(entity).remove::<FishingSkillUse>();
+
+    let Some(mut fishing) = fishing.filter(|fishing| fishing.combat().is_some()) else {
+        let message = match action {
+            FishingAction::Pumping => SmId::YouMayOnlyUseThePumpingSkillWhileYouAreFishing,
+            FishingAction::Reeling => SmId::YouMayOnlyUseTheReelingSkillWhileYouAreFishing,
+        };
+        system_message(&mut commands, entity, SystemMessage::new_empty(message));
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let mut damage = FishingAction::power(skill.level());
+
+    // Fishing shot doubles the damage
+    let weapon = paper_doll
+        .get(DollSlot::RightHand)
+        .and_then(|oid| items_data.entity(oid).ok())
+        .filter(|weapon| fishing_shots.get(*weapon).unwrap_or(false));
+    if let Some(weapon) = weapon {
+        damage *= 2;
+        commands.entity(weapon).remove::<FishingShot>();
+    }
+
+    let expertise = skill_list
+        .and_then(|skill_list| skill_list.get(&EXPERTISE_SKILL).map(|skill| *skill.level()))
+        .unwrap_or_default();
+    let penalty = if *skill.level() >= expertise + EXPERTISE_PENALTY_GAP {
+        system_message(
+            &mut commands,
+            entity,
+            SystemMessage::new_empty(SmId::DueToYourReelingAndOrPumpingSkillBeingThreeOrMoreLevelsHigherThanYourFishingSkillA50DamagePenaltyWillBeApplied),
+        );
+        damage / 2
+    } else {
+        0
+    };
+    damage -= penalty;
+
+    let resisted = rand::thread_rng().gen_range(0..100) < RESIST_CHANCE;
+    let Some(combat) = fishing.combat_mut() else {
+        return Ok(());
+    };
+    let (hit, outcome) = combat.hit(action, damage, resisted);
+
+    let message = match (hit, action) {
+        (FishingHit::Resisted, _) => {
+            SystemMessage::new_empty(SmId::TheFishHasResistedYourAttemptToBringItIn)
+        }
+        (FishingHit::Damaged(_), FishingAction::Pumping) if penalty > 0 => SystemMessage::new(
+            SmId::YourPumpingWasSuccessfulMasteryPenaltyS1,
+            vec![SmParam::Number(penalty)],
+        ),
+        (FishingHit::Damaged(damage), FishingAction::Pumping) => SystemMessage::new(
+            SmId::YourPumpingIsSuccessfulCausingS1Damage,
+            vec![SmParam::Number(damage)],
+        ),
+        (FishingHit::Damaged(_), FishingAction::Reeling) if penalty > 0 => SystemMessage::new(
+            SmId::YourReelingWasSuccessfulMasteryPenaltyS1,
+            vec![SmParam::Number(penalty)],
+        ),
+        (FishingHit::Damaged(damage), FishingAction::Reeling) => SystemMessage::new(
+            SmId::YouReelThatFishInCloserAndCauseS1Damage,
+            vec![SmParam::Number(damage)],
+        ),
+        (FishingHit::Regained(hp), FishingAction::Pumping) => SystemMessage::new(
+            SmId::YouFailedToDoAnythingWithTheFishAndItRegainsS1Hp,
+            vec![SmParam::Number(hp)],
+        ),
+        (FishingHit::Regained(hp), FishingAction::Reeling) => SystemMessage::new(
+            SmId::YouFailedToReelThatFishInFurtherAndItRegainsS1Hp,
+            vec![SmParam::Number(hp)],
+        ),
+    };
+    system_message(&mut commands, entity, message);
+
+    commands.trigger_targets(
+        GameServerPacket::from(
+            ExFishingHpRegen::new(
+                *object_id,
+                combat.time_left(),
+                combat.hp(),
+                combat.mode().into(),
+            )
+            .with_hit(hit.client_id(), action.client_id(), penalty),
+        ),
+        entity,
+    );
+
+    if let Some(outcome) = outcome {
+        combat_over(&mut commands, entity, *object_id, combat.fish, outcome);
+    }
+    Ok(())
+}
+
+/// Moving away scares the fish off.
+fn cancel_on_move(
+    moved: Trigger<OnInsert, Movement>,
+    mut commands: Commands,
+    anglers: Query<Ref<ObjectId>, With<Fishing>>,
+) {
+    let entity = moved.target();
+    let Ok(object_id) = anglers.get(entity) else {
+        return;
+    };
+    system_message(
+        &mut commands,
+        entity,
+        SystemMessage::new_empty(SmId::YourAttemptAtFishingHasBeenCancelled),
+    );
+    reel_in(&mut commands, entity, *object_id, None);
+}
+
+fn wait_for_bite(
+    time: Res<Time>,
+    mut commands: Commands,
+    fishing_data: FishingDataQuery,
+    mut anglers: Query<(Entity, Ref<ObjectId>, Mut<Fishing>)>,
+) -> Result<()> {
+    for (entity, object_id, mut fishing) in anglers.iter_mut() {
+        if !fishing.wait(time.delta()) {
+            continue;
+        }
+
+        let template = fishing
+            .bite()
+            .and_then(|fish| fishing_data.data().ok()?.fish(fish));
+        let Some(template) = template else {
+            system_message(
+                &mut commands,
+                entity,
+                SystemMessage::new_empty(SmId::TheBaitHasBeenLostBecauseTheFishGotAway),
+            );
+            reel_in(&mut commands, entity, *object_id, None);
+            continue;
+        };
+
+        fishing.hook(template);
+        let lure_type = fishing.lure_grade.client_id();
+        let Some(combat) = fishing.combat() else {
+            continue;
+        };
+
+        system_message(
+            &mut commands,
+            entity,
+            SystemMessage::new_empty(SmId::YouVeGotABite),
+        );
+        commands.trigger_targets(
+            GameServerPacket::from(ExFishingStartCombat::new(
+                *object_id,
+                combat.time_left(),
+                combat.hp(),
+                combat.mode().into(),
+                lure_type,
+            )),
+            entity,
+        );
+    }
+    Ok(())
+}
+
+fn fight_fish(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut anglers: Query<(Entity, Ref<ObjectId>, Mut<Fishing>)>,
+) {
+    let mut rng = rand::thread_rng();
+    for (entity, object_id, mut fishing) in anglers.iter_mut() {
+        let Some(combat) = fishing.combat_mut() else {
+            continue;
+        };
+
+        let seconds = combat.update(time.delta());
+        let outcome = (0..seconds).find_map(|_| combat.second_passed(rng.gen_range(0..100)));
+        match outcome {
+            Some(outcome) => {
+                combat_over(&mut commands, entity, *object_id, combat.fish, outcome);
+            }
+            None if seconds > 0 => {
+                commands.trigger_targets(
+                    GameServerPacket::from(ExFishingHpRegen::new(
+                        *object_id,
+                        combat.time_left(),
+                        combat.hp(),
+                        combat.mode().into(),
+                    )),
+                    entity,
+                );
+            }
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    character::Character,
+    fishing::EXPERTISE_SKILL,
+    items::{self, DestroyItemRequest, Inventory, Item},
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{
+                AcquireSkillDone, AcquireSkillInfo, AcquireSkillList, AcquireSkillListEntry,
+                ActionFail, GameServerPacket, SystemMessage,
+            },
+        },
+        session::PacketReceiveParams,
+    },
+    object_id::{ObjectId, ObjectIdManager},
+    skills::{AcquireSkillType, FishingSkillTreeHandle, Skill, SkillList, SkillTree},
+    stats::{ProgressLevelStats, ProgressStats},
+};
+use system_messages::{Id as SmId, SmParam};
+
+/// Fishing guild skills are learned from the fishermen for SP and items,
+/// uses [`AcquireSkillList`], [`AcquireSkillInfo`], [`AcquireSkillDone`] server packets
+/// alongside with clients: [`RequestAcquireSkillInfo`], [`RequestAcquireSkill`]
+pub(crate) struct FishingSkillsPlugin;
+impl Plugin for FishingSkillsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(skill_info).add_observer(acquire_skill);
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct FishingSkillsQuery<'w, 's> {
+    characters: Query<
+        'w,
+        's,
+        (
+            Ref<'static, ProgressLevelStats>,
+            Ref<'static, Inventory>,
+            Mut<'static, ProgressStats>,
+            Mut<'static, SkillList>,
+        ),
+        With<Character>,
+    >,
+    items: Query<'w, 's, Ref<'static, Item>>,
+    object_id_manager: Res<'w, ObjectIdManager>,
+    tree_handle: Res<'w, FishingSkillTreeHandle>,
+    trees: Res<'w, Assets<SkillTree>>,
+}
+
+impl FishingSkillsQuery<'_, '_> {
+    pub fn tree(&self) -> Result<&SkillTree> {
+        self.trees
+            .get(self.tree_handle.id())
+            .ok_or_else(|| BevyError::from("Fishing skill tree is not loaded"))
+    }
+
+    /// Skills the character may learn next, or the message with the level to come back at.
+    pub fn skill_list_packet(&self, entity: Entity) -> Result<GameServerPacket> {
+        let tree = self.tree()?;
+        let (level_stats, _, _, skill_list) = self.characters.get(entity)?;
+        let level = level_stats.level();
+
+        let entries = tree
+            .learnable(&skill_list, level)
+            .into_iter()
+            .map(|node| AcquireSkillListEntry {
+                id: node.skill_id,
+                next_level: node.skill_level,
+                max_level: tree.max_level(node.skill_id).unwrap_or(node.skill_level),
+                sp: node.sp(),
+                requirements: node.items().count() as u32,
+            })
+            .collect::<Vec<_>>();
+        if !entries.is_empty() {
+            return Ok(AcquireSkillList::new(AcquireSkillType::Fishing, entries).into());
+        }
+
+        let next_level = tree
+            .iter()
+            .filter(|node| {
+                let known_level = skill_list
+                    .get(&node.skill_id)
+                    .map(|skill| *skill.level())
+                    .unwrap_or_default();
+                *node.skill_level == known_level + 1
+            })
+            .filter_map(|node| node.level_required())
+            .filter(|required| *required > level)
+            .min();
+        Ok(match next_level {
+            Some(next_level) => SystemMessage::new(
+                SmId::YouDoNotHaveAnyFurtherSkillsToLearnComeBackWhenYouHaveReachedLevelS1,
+                vec![SmParam::Number(*next_level)],
+            )
+            .into(),
+            None => AcquireSkillList::new(AcquireSkillType::Fishing, vec![]).into(),
+        })
+    }
+
+    fn inventory_item(&self, entity: Entity, item_id: items::Id) -> Option<(ObjectId, u64)> {
+        let (_, inventory, _, _) = self.characters.get(entity).ok()?;
+        let (item_entity, object_id, _) =
+            inventory.single_by_item_id(item_id, &self.items, self.object_id_manager.as_ref())?;
+        let item = self.items.get(item_entity).ok()?;
+        Some((object_id, item.count()))
+    }
+}
+
+fn skill_info(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    skills_query: FishingSkillsQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestAcquireSkillInfo(ref packet) = event.packet else {
+        return Ok(());
+    };
+    if packet.skill_type != AcquireSkillType::Fishing {
+        return Ok(());
+    }
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let Some(node) = skills_query.tree()?.node(packet.id, packet.level) else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    commands.trigger_targets(
+        GameServerPacket::from(AcquireSkillInfo::new(
+            node.skill_id,
+            node.skill_level,
+            node.sp(),
+            AcquireSkillType::Fishing,
+            node.items().collect(),
+        )),
+        entity,
+    );
+    Ok(())
+}
+
+fn acquire_skill(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut skills_query: FishingSkillsQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestAcquireSkill(ref packet) = event.packet else {
+        return Ok(());
+    };
+    if packet.skill_type != AcquireSkillType::Fishing {
+        return Ok(());
+    }
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let tree = skills_query.tree()?;
+    let (level_stats, _, progress_stats, skill_list) = skills_query.characters.get(entity)?;
+    let node = tree
+        .learnable(&skill_list, level_stats.level())
+        .into_iter()
+        .find(|node| node.skill_id == packet.id && node.skill_level == packet.level)
+        .cloned();
+    let sp = progress_stats.sp();
+
+    let mut fail = |message: SmId| {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(message)),
+            entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        Ok(())
+    };
+
+    let Some(node) = node else {
+        return fail(SmId::YouDoNotHaveTheNecessaryMaterialsOrPrerequisitesToLearnThisSkill);
+    };
+    let sp_cost = node.sp();
+    if sp < sp_cost {
+        return fail(SmId::YouDoNotHaveEnoughSpToLearnThisSkill);
+    }
+
+    let mut materials = Vec::new();
+    for (item_id, count) in node.items() {
+        match skills_query.inventory_item(entity, item_id) {
+            Some((item_oid, available)) if available >= count => {
+                materials.push((item_oid, count));
+            }
+            _ => {
+                return fail(
+                    SmId::YouDoNotHaveTheNecessaryMaterialsOrPrerequisitesToLearnThisSkill,
+                );
+            }
+        }
+    }
+
+    for (item_oid, count) in materials {
+        commands.trigger_targets(DestroyItemRequest { item_oid, count }, entity);
+    }
+
+    let (_, _, mut progress_stats, mut skill_list) = skills_query.characters.get_mut(entity)?;
+    progress_stats.set_sp(sp - sp_cost);
+    let mut skill = Skill::from(&node);
+    if skill.id() == EXPERTISE_SKILL {
+        skill.set_passive();
+    }
+    skill_list.add_skill(skill);
+
+    commands.trigger_targets(
+        GameServerPacket::from(SystemMessage::new(
+            SmId::YouHaveEarnedS1,
+            vec![SmParam::Skill((*skill.id(), *skill.level()))],
+        )),
+        entity,
+    );
+    commands.trigger_targets(GameServerPacket::from(AcquireSkillDone), entity);
+    commands.trigger_targets(skills_query.skill_list_packet(entity)?, entity);
+    Ok(())
+}
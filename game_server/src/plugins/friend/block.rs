@@ -0,0 +1,180 @@
+use super::{FriendQuery, delete_relations, save_relations, send_packet};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    friend::{
+        RelationError,
+        model::{self, FriendPK},
+    },
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::{GameClientPacket, RequestBlock},
+            server::SystemMessage,
+        },
+        session::PacketReceiveParams,
+    },
+};
+use l2r_core::db::RepositoryManager;
+use system_messages::{Id, SmParam};
+
+pub(crate) struct RequestBlockPlugin;
+impl Plugin for RequestBlockPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+/// Block list is edited with `/block`, `/unblock`, `/blocklist`, `/allblock` and `/allunblock` commands.
+/// Only the listed characters are persisted, blocking everything lasts until logout.
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    repo_manager: Res<RepositoryManager>,
+    friend_query: FriendQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestBlock(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let mut block_list = friend_query.block_list(entity)?;
+
+    match packet {
+        RequestBlock::Block(name) => {
+            let target = friend_query
+                .online_by_name(name)
+                .filter(|target| *target != entity);
+            let Some(target) = target else {
+                send_packet(
+                    &mut commands,
+                    entity,
+                    SystemMessage::new_empty(Id::YouHaveFailedToRegisterTheUserToYourIgnoreList),
+                );
+                return Ok(());
+            };
+
+            let target_entry = friend_query.entry(target)?;
+            match block_list.add(target_entry.clone()) {
+                Ok(()) => {}
+                Err(RelationError::ListIsFull) => {
+                    send_packet(
+                        &mut commands,
+                        entity,
+                        SystemMessage::new_empty(Id::YouCanOnlyEnterUpTo128NamesInYourBlockList),
+                    );
+                    return Ok(());
+                }
+                Err(RelationError::AlreadyAdded) => {
+                    send_packet(
+                        &mut commands,
+                        entity,
+                        SystemMessage::new_empty(
+                            Id::YouHaveFailedToRegisterTheUserToYourIgnoreList,
+                        ),
+                    );
+                    return Ok(());
+                }
+            }
+            commands.entity(entity).insert(block_list);
+
+            let name = friend_query.name(entity)?;
+            send_packet(
+                &mut commands,
+                entity,
+                SystemMessage::new(
+                    Id::S1HasBeenAddedToYourIgnoreList,
+                    vec![SmParam::Text(target_entry.name.clone())],
+                ),
+            );
+            send_packet(
+                &mut commands,
+                target,
+                SystemMessage::new(
+                    Id::S1HasPlacedYouOnHisHerIgnoreList,
+                    vec![SmParam::Text(name)],
+                ),
+            );
+
+            save_relations(
+                &repo_manager,
+                &mut commands,
+                vec![model::Model {
+                    char_id: friend_query.char_id(entity)?,
+                    friend_id: target_entry.id,
+                    blocked: true,
+                }],
+            )?;
+        }
+        RequestBlock::Unblock(name) => {
+            let Some(blocked_id) = block_list.blocked.by_name(name).map(|entry| entry.id) else {
+                return Ok(());
+            };
+            let Some(blocked) = block_list.blocked.remove(blocked_id) else {
+                return Ok(());
+            };
+            commands.entity(entity).insert(block_list);
+
+            send_packet(
+                &mut commands,
+                entity,
+                SystemMessage::new(
+                    Id::S1HasBeenRemovedFromYourIgnoreList,
+                    vec![SmParam::Text(blocked.name)],
+                ),
+            );
+
+            delete_relations(
+                &repo_manager,
+                &mut commands,
+                vec![FriendPK {
+                    char_id: friend_query.char_id(entity)?,
+                    friend_id: blocked.id,
+                    blocked: true,
+                }],
+            )?;
+        }
+        RequestBlock::List => {
+            send_packet(
+                &mut commands,
+                entity,
+                SystemMessage::new_empty(Id::IgnoreList),
+            );
+            for (index, blocked) in block_list.blocked.iter().enumerate() {
+                send_packet(
+                    &mut commands,
+                    entity,
+                    SystemMessage::new(
+                        Id::C1C2,
+                        vec![
+                            SmParam::Text((index + 1).to_string()),
+                            SmParam::Text(blocked.name.clone()),
+                        ],
+                    ),
+                );
+            }
+            send_packet(&mut commands, entity, SystemMessage::new_empty(Id::Empty3));
+        }
+        RequestBlock::BlockAll => {
+            block_list.block_all = true;
+            commands.entity(entity).insert(block_list);
+            send_packet(
+                &mut commands,
+                entity,
+                SystemMessage::new_empty(Id::YouAreNowBlockingEverything),
+            );
+        }
+        RequestBlock::UnblockAll => {
+            block_list.block_all = false;
+            commands.entity(entity).insert(block_list);
+            send_packet(
+                &mut commands,
+                entity,
+                SystemMessage::new_empty(Id::YouAreNoLongerBlockingEverything),
+            );
+        }
+    }
+    Ok(())
+}
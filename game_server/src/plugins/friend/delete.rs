@@ -0,0 +1,100 @@
+use super::{FriendQuery, delete_relations, send_packet};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    friend::model::FriendPK,
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{L2Friend, L2FriendAction, SystemMessage},
+        },
+        session::PacketReceiveParams,
+    },
+};
+use l2r_core::db::RepositoryManager;
+use system_messages::{Id, SmParam};
+
+pub(crate) struct RequestFriendDelPlugin;
+impl Plugin for RequestFriendDelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    repo_manager: Res<RepositoryManager>,
+    friend_query: FriendQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestFriendDel(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let entry = friend_query.entry(entity)?;
+
+    let mut friends = friend_query.friends(entity)?;
+    let Some(friend_id) = friends.by_name(&packet.0).map(|friend| friend.id) else {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new(
+                Id::C1IsNotOnYourFriendList,
+                vec![SmParam::Player(packet.0.clone())],
+            ),
+        );
+        return Ok(());
+    };
+    let Some(friend) = friends.remove(friend_id) else {
+        return Ok(());
+    };
+    commands.entity(entity).insert(friends);
+
+    send_packet(
+        &mut commands,
+        entity,
+        SystemMessage::new(
+            Id::S1HasBeenRemovedFromYourFriendsList,
+            vec![SmParam::Text(friend.name.clone())],
+        ),
+    );
+    send_packet(
+        &mut commands,
+        entity,
+        L2Friend::new(L2FriendAction::Remove, friend.id, friend.name, false),
+    );
+
+    // Friendship is mutual, so the friend loses this character as well
+    if let Some(friend_entity) = friend_query.online_by_id(friend.id) {
+        let mut friend_friends = friend_query.friends(friend_entity)?;
+        if friend_friends.remove(entry.id).is_some() {
+            commands.entity(friend_entity).insert(friend_friends);
+            send_packet(
+                &mut commands,
+                friend_entity,
+                L2Friend::new(L2FriendAction::Remove, entry.id, entry.name, false),
+            );
+        }
+    }
+
+    delete_relations(
+        &repo_manager,
+        &mut commands,
+        vec![
+            FriendPK {
+                char_id: entry.id,
+                friend_id: friend.id,
+                blocked: false,
+            },
+            FriendPK {
+                char_id: friend.id,
+                friend_id: entry.id,
+                blocked: false,
+            },
+        ],
+    )
+}
@@ -0,0 +1,243 @@
+use super::{FriendQuery, save_relations, send_packet};
+use bevy::{log, prelude::*};
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    friend::{self, FriendInvite},
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{FriendAddRequest, L2Friend, L2FriendAction, SystemMessage},
+        },
+        session::PacketReceiveParams,
+    },
+};
+use l2r_core::db::RepositoryManager;
+use system_messages::{Id, SmParam};
+
+pub(crate) struct RequestFriendInvitePlugin;
+impl Plugin for RequestFriendInvitePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(invite).add_observer(answer);
+    }
+}
+
+fn invite(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    friend_query: FriendQuery,
+    invites: Query<(), With<FriendInvite>>,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestFriendInvite(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let target_name = packet.0.clone();
+
+    let Some(target) = friend_query.online_by_name(&target_name) else {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new_empty(Id::TheUserWhoRequestedToBecomeFriendsIsNotFoundInTheGame),
+        );
+        return Ok(());
+    };
+
+    if target == entity {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new_empty(Id::YouCannotAddYourselfToYourOwnFriendList),
+        );
+        return Ok(());
+    }
+
+    let friends = friend_query.friends(entity)?;
+    let target_id = friend_query.char_id(target)?;
+    if friends.contains(target_id) {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new(
+                Id::C1IsAlreadyOnYourFriendList,
+                vec![SmParam::Player(target_name)],
+            ),
+        );
+        return Ok(());
+    }
+    if friends.len() >= friend::FRIEND_LIST_LIMIT {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new_empty(Id::YouCanOnlyEnterUp128NamesInYourFriendsList),
+        );
+        return Ok(());
+    }
+
+    let target_block_list = friend_query.block_list(target)?;
+    if target_block_list.block_all {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new_empty(Id::TheOtherPlayerIsRejectingFriendInvitations),
+        );
+        return Ok(());
+    }
+    if target_block_list.blocks(friend_query.char_id(entity)?) {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new(
+                Id::S1HasPlacedYouOnHisHerIgnoreList,
+                vec![SmParam::Text(target_name)],
+            ),
+        );
+        return Ok(());
+    }
+
+    if invites.contains(target) {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new(
+                Id::C1IsOnAnotherTaskPleaseTryAgainLater,
+                vec![SmParam::Player(target_name)],
+            ),
+        );
+        return Ok(());
+    }
+
+    commands
+        .entity(target)
+        .insert(FriendInvite { requestor: entity });
+    send_packet(
+        &mut commands,
+        target,
+        FriendAddRequest::new(friend_query.name(entity)?),
+    );
+    send_packet(
+        &mut commands,
+        entity,
+        SystemMessage::new(
+            Id::YouVeRequestedC1ToBeOnYourFriendsList,
+            vec![SmParam::Player(target_name)],
+        ),
+    );
+    Ok(())
+}
+
+fn answer(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    repo_manager: Res<RepositoryManager>,
+    friend_query: FriendQuery,
+    invites: Query<Ref<FriendInvite>>,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestAnswerFriendInvite(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let Ok(invite) = invites.get(entity) else {
+        return Ok(());
+    };
+    let requestor = invite.requestor;
+    commands.entity(entity).remove::<FriendInvite>();
+
+    let Ok(requestor_entry) = friend_query.entry(requestor) else {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new_empty(Id::TheUserWhoRequestedToBecomeFriendsIsNotFoundInTheGame),
+        );
+        return Ok(());
+    };
+
+    if !packet.0 {
+        send_packet(
+            &mut commands,
+            requestor,
+            SystemMessage::new_empty(Id::YouHaveFailedToAddAFriendToYourFriendsList),
+        );
+        return Ok(());
+    }
+
+    let entry = friend_query.entry(entity)?;
+    let mut requestor_friends = friend_query.friends(requestor)?;
+    let mut friends = friend_query.friends(entity)?;
+
+    let result = requestor_friends
+        .add(entry.clone())
+        .and_then(|_| friends.add(requestor_entry.clone()));
+    if let Err(err) = result {
+        log::debug!(
+            "Friend invite of {} to {} failed: {}",
+            requestor_entry.name,
+            entry.name,
+            err
+        );
+        send_packet(
+            &mut commands,
+            requestor,
+            SystemMessage::new_empty(Id::YouHaveFailedToAddAFriendToYourFriendsList),
+        );
+        return Ok(());
+    }
+
+    commands.entity(requestor).insert(requestor_friends);
+    commands.entity(entity).insert(friends);
+
+    send_packet(
+        &mut commands,
+        requestor,
+        SystemMessage::new(
+            Id::S1HasBeenAddedToYourFriendsList,
+            vec![SmParam::Text(entry.name.clone())],
+        ),
+    );
+    send_packet(
+        &mut commands,
+        requestor,
+        L2Friend::new(L2FriendAction::Add, entry.id, entry.name.clone(), true),
+    );
+    send_packet(
+        &mut commands,
+        entity,
+        SystemMessage::new(
+            Id::S1HasJoinedAsAFriend,
+            vec![SmParam::Text(requestor_entry.name.clone())],
+        ),
+    );
+    send_packet(
+        &mut commands,
+        entity,
+        L2Friend::new(
+            L2FriendAction::Add,
+            requestor_entry.id,
+            requestor_entry.name.clone(),
+            true,
+        ),
+    );
+
+    save_relations(
+        &repo_manager,
+        &mut commands,
+        vec![
+            friend::model::Model {
+                char_id: requestor_entry.id,
+                friend_id: entry.id,
+                blocked: false,
+            },
+            friend::model::Model {
+                char_id: entry.id,
+                friend_id: requestor_entry.id,
+                blocked: false,
+            },
+        ],
+    )
+}
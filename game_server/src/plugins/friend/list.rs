@@ -0,0 +1,51 @@
+use super::{FriendQuery, send_packet};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{client::GameClientPacket, server::SystemMessage},
+    session::PacketReceiveParams,
+};
+use system_messages::{Id, SmParam};
+
+pub(crate) struct RequestFriendListPlugin;
+impl Plugin for RequestFriendListPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+/// Prints the friend list to the system chat, `/friendlist` command.
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    friend_query: FriendQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestFriendList = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+
+    send_packet(
+        &mut commands,
+        entity,
+        SystemMessage::new_empty(Id::FriendsList),
+    );
+    for friend in friend_query.friends(entity)?.iter() {
+        let id = if friend_query.online_by_id(friend.id).is_some() {
+            Id::S1CurrentlyOnline
+        } else {
+            Id::S1CurrentlyOffline
+        };
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new(id, vec![SmParam::Text(friend.name.clone())]),
+        );
+    }
+    send_packet(&mut commands, entity, SystemMessage::new_empty(Id::Empty3));
+    Ok(())
+}
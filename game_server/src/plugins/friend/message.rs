@@ -0,0 +1,85 @@
+use super::{FriendQuery, send_packet};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{
+        client::GameClientPacket,
+        server::{L2FriendSay, SystemMessage},
+    },
+    session::PacketReceiveParams,
+};
+use system_messages::{Id, SmParam};
+
+pub(crate) struct RequestSendFriendMsgPlugin;
+impl Plugin for RequestSendFriendMsgPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    friend_query: FriendQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestSendFriendMsg(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+
+    if packet.message.is_empty() {
+        return Ok(());
+    }
+
+    let Some(receiver) = friend_query
+        .friends(entity)?
+        .by_name(&packet.receiver)
+        .map(|friend| friend.id)
+    else {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new(
+                Id::C1IsNotOnYourFriendList,
+                vec![SmParam::Player(packet.receiver.clone())],
+            ),
+        );
+        return Ok(());
+    };
+
+    let Some(receiver_entity) = friend_query.online_by_id(receiver) else {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new(
+                Id::S1IsNotCurrentlyLoggedIn,
+                vec![SmParam::Text(packet.receiver.clone())],
+            ),
+        );
+        return Ok(());
+    };
+
+    if friend_query.blocks(receiver_entity, entity)? {
+        send_packet(
+            &mut commands,
+            entity,
+            SystemMessage::new_empty(Id::ThatPersonIsInMessageRefusalMode),
+        );
+        return Ok(());
+    }
+
+    send_packet(
+        &mut commands,
+        receiver_entity,
+        L2FriendSay::new(
+            friend_query.name(receiver_entity)?,
+            friend_query.name(entity)?,
+            packet.message.clone(),
+        ),
+    );
+    Ok(())
+}
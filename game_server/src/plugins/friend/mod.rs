@@ -0,0 +1,293 @@
+use bevy::{ecs::system::SystemParam, log, prelude::*};
+use bevy_defer::{AccessError, AsyncAccess, AsyncCommandsExtension, AsyncWorld};
+use game_core::{
+    character::{self, Character},
+    encounters::EnteredWorld,
+    friend::{self, BlockList, FriendComponentsPlugin, Friends, RelationEntry, model::FriendPK},
+    network::packets::server::{GameServerPacket, L2FriendList, L2FriendListEntry},
+    object_id::{ObjectId, ObjectIdManager},
+};
+use l2r_core::db::{
+    PrimaryKeyColumns, Repository, RepositoryManager, TypedRepositoryManager, UpdatableModel,
+};
+use sea_orm::{ColumnTrait, sea_query::OnConflict};
+
+mod block;
+mod delete;
+mod invite;
+mod list;
+mod message;
+mod status;
+
+/// Friends are added by a mutual invitation and stored for both characters,
+/// they are notified when a friend logs in or out and can exchange private messages.
+/// Block list refuses whispers and requests from the listed characters or from everyone.
+/// uses [`FriendAddRequest`], [`L2FriendList`], [`L2Friend`], [`L2FriendStatus`], [`L2FriendSay`] server packets
+/// alongside with clients: [`RequestFriendInvite`], [`RequestAnswerFriendInvite`], [`RequestFriendList`],
+/// [`RequestFriendDel`], [`RequestSendFriendMsg`], [`RequestBlock`]
+pub(crate) struct FriendPlugin;
+impl Plugin for FriendPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(FriendComponentsPlugin)
+            .add_plugins(invite::RequestFriendInvitePlugin)
+            .add_plugins(list::RequestFriendListPlugin)
+            .add_plugins(delete::RequestFriendDelPlugin)
+            .add_plugins(message::RequestSendFriendMsgPlugin)
+            .add_plugins(block::RequestBlockPlugin)
+            .add_plugins(status::FriendStatusPlugin);
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct FriendQuery<'w, 's> {
+    characters: Query<
+        'w,
+        's,
+        (
+            Entity,
+            Ref<'static, ObjectId>,
+            Ref<'static, Name>,
+            Option<Ref<'static, Friends>>,
+            Option<Ref<'static, BlockList>>,
+            Has<EnteredWorld>,
+        ),
+        With<Character>,
+    >,
+    object_id_manager: Res<'w, ObjectIdManager>,
+}
+
+impl FriendQuery<'_, '_> {
+    pub fn char_id(&self, entity: Entity) -> Result<ObjectId> {
+        Ok(*self.characters.get(entity)?.1)
+    }
+
+    pub fn name(&self, entity: Entity) -> Result<String> {
+        Ok(self.characters.get(entity)?.2.to_string())
+    }
+
+    pub fn entry(&self, entity: Entity) -> Result<RelationEntry> {
+        let (_, id, name, ..) = self.characters.get(entity)?;
+        Ok(RelationEntry {
+            id: *id,
+            name: name.to_string(),
+        })
+    }
+
+    pub fn friends(&self, entity: Entity) -> Result<Friends> {
+        Ok(self
+            .characters
+            .get(entity)?
+            .3
+            .as_deref()
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    pub fn block_list(&self, entity: Entity) -> Result<BlockList> {
+        Ok(self
+            .characters
+            .get(entity)?
+            .4
+            .as_deref()
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Whether `entity` refuses whispers and requests from `from`.
+    pub fn blocks(&self, entity: Entity, from: Entity) -> Result<bool> {
+        let from_id = self.char_id(from)?;
+        Ok(self
+            .characters
+            .get(entity)?
+            .4
+            .is_some_and(|block_list| block_list.blocks(from_id)))
+    }
+
+    /// Character that entered the world with the given name, case insensitive.
+    pub fn online_by_name(&self, name: &str) -> Option<Entity> {
+        self.characters
+            .iter()
+            .find(|(_, _, char_name, .., online)| {
+                *online && char_name.as_str().eq_ignore_ascii_case(name)
+            })
+            .map(|(entity, ..)| entity)
+    }
+
+    pub fn online_by_id(&self, id: ObjectId) -> Option<Entity> {
+        let entity = self.object_id_manager.entity(id)?;
+        let (.., online) = self.characters.get(entity).ok()?;
+        online.then_some(entity)
+    }
+
+    /// Characters in the world that have `id` on their friend list.
+    pub fn online_friends_of(&self, id: ObjectId) -> Vec<Entity> {
+        self.characters
+            .iter()
+            .filter(|(_, _, _, friends, _, online)| {
+                *online && friends.as_ref().is_some_and(|friends| friends.contains(id))
+            })
+            .map(|(entity, ..)| entity)
+            .collect()
+    }
+
+    pub fn friend_list(&self, entity: Entity) -> Result<L2FriendList> {
+        let friends = self.friends(entity)?;
+        Ok(L2FriendList::new(
+            friends
+                .iter()
+                .map(|friend| L2FriendListEntry {
+                    id: friend.id,
+                    name: friend.name.clone(),
+                    online: self.online_by_id(friend.id).is_some(),
+                })
+                .collect(),
+        ))
+    }
+}
+
+pub(crate) fn send_packet(
+    commands: &mut Commands,
+    entity: Entity,
+    packet: impl Into<GameServerPacket>,
+) {
+    commands.trigger_targets(packet.into(), entity);
+}
+
+pub(crate) fn save_relations(
+    repo_manager: &RepositoryManager,
+    commands: &mut Commands,
+    models: Vec<friend::model::Model>,
+) -> Result<()> {
+    let friend_repository = repo_manager.typed::<FriendPK, friend::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        for model in models {
+            let result = friend_repository
+                .create_or_update(
+                    &model,
+                    OnConflict::columns(friend::model::Model::pk_columns().to_vec())
+                        .update_columns(friend::model::Model::update_columns().to_vec())
+                        .to_owned(),
+                )
+                .await;
+            if let Err(err) = result {
+                log::error!(
+                    "Character: {}, Error saving relation to {}: {:?}",
+                    model.char_id,
+                    model.friend_id,
+                    err
+                );
+                return Err(err.into());
+            }
+        }
+        Ok(())
+    });
+    Ok(())
+}
+
+pub(crate) fn delete_relations(
+    repo_manager: &RepositoryManager,
+    commands: &mut Commands,
+    pks: Vec<FriendPK>,
+) -> Result<()> {
+    let friend_repository = repo_manager.typed::<FriendPK, friend::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        for pk in pks {
+            if let Err(err) = friend_repository.delete_by_id(pk).await {
+                log::error!(
+                    "Character: {}, Failed to delete relation to {}: {:?}",
+                    pk.char_id,
+                    pk.friend_id,
+                    err
+                );
+                return Err(err.into());
+            }
+        }
+        Ok(())
+    });
+    Ok(())
+}
+
+pub async fn friend_list_init_task(char_entity: Entity) -> Result<(), AccessError> {
+    let Ok((friend_repository, character_repository)) =
+        AsyncWorld.resource::<RepositoryManager>().get(|registry| {
+            Ok::<_, BevyError>((
+                registry.typed::<FriendPK, friend::model::Entity>()?,
+                registry.typed::<ObjectId, character::model::Entity>()?,
+            ))
+        })?
+    else {
+        return Ok(());
+    };
+
+    let char_id = AsyncWorld
+        .entity(char_entity)
+        .component::<ObjectId>()
+        .get(|component| *component)?;
+
+    let relations = match friend_repository
+        .find_with_conditions([friend::model::Column::CharId.eq(char_id)])
+        .await
+    {
+        Ok(relations) => relations,
+        Err(err) => {
+            log::error!("Character: {}, Error loading friends: {:?}", char_id, err);
+            return Err(err.into());
+        }
+    };
+
+    let ids: Vec<ObjectId> = relations.iter().map(|model| model.friend_id).collect();
+    let characters = if ids.is_empty() {
+        Vec::new()
+    } else {
+        match character_repository
+            .find_with_conditions([character::model::Column::Id.is_in(ids)])
+            .await
+        {
+            Ok(characters) => characters,
+            Err(err) => {
+                log::error!(
+                    "Character: {}, Error loading friend names: {:?}",
+                    char_id,
+                    err
+                );
+                return Err(err.into());
+            }
+        }
+    };
+
+    let mut friends = Friends::default();
+    let mut block_list = BlockList::default();
+    for relation in relations {
+        let Some(character) = characters
+            .iter()
+            .find(|character| character.id == relation.friend_id)
+        else {
+            continue;
+        };
+        let entry = RelationEntry {
+            id: character.id,
+            name: character.name.clone(),
+        };
+        let result = if relation.blocked {
+            block_list.add(entry)
+        } else {
+            friends.add(entry)
+        };
+        if let Err(err) = result {
+            log::warn!(
+                "Character: {}, Skipping relation to {}: {}",
+                char_id,
+                relation.friend_id,
+                err
+            );
+        }
+    }
+
+    AsyncWorld.apply_command(move |world: &mut World| {
+        if let Ok(mut character) = world.get_entity_mut(char_entity) {
+            character.insert((friends, block_list));
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use super::{FriendQuery, send_packet};
+use bevy::prelude::*;
+use game_core::{
+    encounters::EnteredWorld,
+    network::packets::server::{L2FriendStatus, SystemMessage},
+};
+use system_messages::{Id, SmParam};
+
+pub(crate) struct FriendStatusPlugin;
+impl Plugin for FriendStatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(logged_in).add_observer(logged_out);
+    }
+}
+
+fn logged_in(
+    entered: Trigger<OnAdd, EnteredWorld>,
+    friend_query: FriendQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let entity = entered.target();
+    let Ok(entry) = friend_query.entry(entity) else {
+        return Ok(());
+    };
+
+    send_packet(&mut commands, entity, friend_query.friend_list(entity)?);
+
+    for friend in friend_query.online_friends_of(entry.id) {
+        if friend == entity {
+            continue;
+        }
+        send_packet(
+            &mut commands,
+            friend,
+            L2FriendStatus::new(true, entry.name.clone(), entry.id),
+        );
+        send_packet(
+            &mut commands,
+            friend,
+            SystemMessage::new(
+                Id::YourFriendS1JustLoggedIn,
+                vec![SmParam::Text(entry.name.clone())],
+            ),
+        );
+    }
+    Ok(())
+}
+
+fn logged_out(
+    left: Trigger<OnRemove, EnteredWorld>,
+    friend_query: FriendQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let entity = left.target();
+    let Ok(entry) = friend_query.entry(entity) else {
+        return Ok(());
+    };
+
+    for friend in friend_query.online_friends_of(entry.id) {
+        if friend == entity {
+            continue;
+        }
+        send_packet(
+            &mut commands,
+            friend,
+            L2FriendStatus::new(false, entry.name.clone(), entry.id),
+        );
+    }
+    Ok(())
+}
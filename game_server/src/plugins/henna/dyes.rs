@@ -0,0 +1,74 @@
+use bevy::{log, prelude::*};
+use game_core::henna::*;
+use l2r_core::chronicles::CHRONICLE;
+use state::{GameServerStateSystems, LoadingSystems};
+use std::path::PathBuf;
+
+pub struct HennaDyesPlugin;
+impl Plugin for HennaDyesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HennaDyesHandle>();
+
+        app.add_systems(
+            Update,
+            (
+                load_assets.in_set(LoadingSystems::AssetInit),
+                update_assets.in_set(LoadingSystems::AssetInit),
+            ),
+        );
+
+        app.add_systems(Update, update_assets.in_set(GameServerStateSystems::Run));
+    }
+}
+
+fn load_assets(
+    asset_server: Res<AssetServer>,
+    mut henna_dyes_handle: ResMut<HennaDyesHandle>,
+    mut loaded: Local<bool>,
+) {
+    if *loaded {
+        return;
+    }
+    let mut path = PathBuf::from("henna");
+    path.push(CHRONICLE);
+    path.push("dyes");
+    path.set_extension("json");
+
+    let handle: Handle<HennaDyes> = asset_server.load(path.clone());
+    **henna_dyes_handle = handle;
+    *loaded = true;
+}
+
+fn update_assets(handle: Res<HennaDyesHandle>, mut events: EventReader<AssetEvent<HennaDyes>>) {
+    for event in events.read() {
+        if event.is_loaded_with_dependencies(handle.id()) {
+            log::info!("Henna dyes updated");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use l2r_core::{assets::ASSET_DIR, chronicles::CHRONICLE, utils::get_base_path};
+    use serde_json::from_reader;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_parse_all_from_json() {
+        let mut path = get_base_path();
+        path.push(ASSET_DIR);
+        path.push("henna");
+        path.push(CHRONICLE);
+        path.push("dyes");
+        path.set_extension("json");
+
+        let file = File::open(&path).unwrap_or_else(|_| panic!("Failed to open file: {:?}", path));
+        let reader = BufReader::new(file);
+
+        let result: HennaDyes = from_reader(reader)
+            .unwrap_or_else(|_| panic!("Failed to parse henna dyes from JSON: {:?}", path));
+
+        assert!(!result.is_empty());
+    }
+}
@@ -0,0 +1,141 @@
+use super::HennaQuery;
+use bevy::{log, prelude::*};
+use bevy_defer::AsyncCommandsExtension;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    henna,
+    items::{ADENA_ID, DestroyItemRequest},
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{ActionFail, GameServerPacket, SystemMessage},
+        },
+        session::PacketReceiveParams,
+    },
+};
+use l2r_core::db::{
+    PrimaryKeyColumns, Repository, RepositoryManager, TypedRepositoryManager, UpdatableModel,
+};
+use sea_orm::sea_query::OnConflict;
+use system_messages::Id;
+
+pub(crate) struct RequestHennaEquipPlugin;
+impl Plugin for RequestHennaEquipPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    repo_manager: Res<RepositoryManager>,
+    henna_query: HennaQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestHennaEquip(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let henna_id = packet.0;
+
+    let Some(dye) = henna_query.dyes()?.get(&henna_id).cloned() else {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(
+                Id::TheSymbolInformationCannotBeFound,
+            )),
+            entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let sub_class = henna_query.sub_class(entity)?;
+    let mut hennas = henna_query.hennas(entity)?;
+
+    let Some(slot) = hennas.free_slot(henna_query.available_slots(entity)?) else {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(Id::NoSlotExistsToDrawTheSymbol)),
+            entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let dye_stack = henna_query
+        .inventory_item(entity, dye.item_id)
+        .filter(|(_, count)| *count >= dye.wear.count);
+
+    let Some((dye_oid, _)) = dye_stack.filter(|_| dye.allowed_for(sub_class.class_id())) else {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(Id::TheSymbolCannotBeDrawn)),
+            entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let adena_stack = henna_query.inventory_item(entity, ADENA_ID);
+    if dye.wear.fee > 0 && adena_stack.is_none_or(|(_, count)| count < dye.wear.fee) {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(Id::YouDoNotHaveEnoughAdena)),
+            entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    commands.trigger_targets(
+        DestroyItemRequest {
+            item_oid: dye_oid,
+            count: dye.wear.count,
+        },
+        entity,
+    );
+    if let Some((adena_oid, _)) = adena_stack.filter(|_| dye.wear.fee > 0) {
+        commands.trigger_targets(
+            DestroyItemRequest {
+                item_oid: adena_oid,
+                count: dye.wear.fee,
+            },
+            entity,
+        );
+    }
+
+    hennas.set(slot, Some(henna_id));
+    commands.entity(entity).insert(hennas);
+
+    commands.trigger_targets(
+        GameServerPacket::from(SystemMessage::new_empty(Id::TheSymbolHasBeenAdded)),
+        entity,
+    );
+
+    let char_id = henna_query.char_id(entity)?;
+    let henna_repository = repo_manager.typed::<henna::model::HennaPK, henna::model::Entity>()?;
+    let henna_model = henna::model::Model {
+        char_id,
+        slot: slot as i16,
+        class_variant: sub_class.variant(),
+        henna_id,
+    };
+
+    commands.spawn_task(move || async move {
+        let result = henna_repository
+            .create_or_update(
+                &henna_model,
+                OnConflict::columns(henna::model::Model::pk_columns().to_vec())
+                    .update_columns(henna::model::Model::update_columns().to_vec())
+                    .to_owned(),
+            )
+            .await;
+        if let Err(err) = result {
+            log::error!("Character: {}, Error saving henna: {:?}", char_id, err);
+            return Err(err.into());
+        }
+        Ok(())
+    });
+    Ok(())
+}
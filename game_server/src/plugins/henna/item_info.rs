@@ -0,0 +1,59 @@
+use super::HennaQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{
+        client::GameClientPacket,
+        server::{ActionFail, GameServerPacket, HennaItemInfo},
+    },
+    session::PacketReceiveParams,
+};
+
+pub(crate) struct RequestHennaItemInfoPlugin;
+impl Plugin for RequestHennaItemInfoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    henna_query: HennaQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestHennaItemInfo(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let henna_id = packet.0;
+
+    let Some(dye) = henna_query.dyes()?.get(&henna_id).cloned() else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let hennas = henna_query.hennas(entity)?;
+    let available_slots = henna_query.available_slots(entity)?;
+    let mut drawn = hennas;
+    if let Some(slot) = hennas.free_slot(available_slots) {
+        drawn.set(slot, Some(henna_id));
+    }
+
+    let allowed = dye.allowed_for(henna_query.sub_class(entity)?.class_id())
+        && henna_query.item_count(entity, dye.item_id) >= dye.wear.count;
+
+    let item_info = HennaItemInfo::new(
+        henna_id,
+        dye,
+        henna_query.adena(entity),
+        allowed,
+        henna_query.primal_stats(entity)?,
+        henna_query.bonus_change(&hennas, &drawn)?,
+    );
+    commands.trigger_targets(GameServerPacket::from(item_info), entity);
+    Ok(())
+}
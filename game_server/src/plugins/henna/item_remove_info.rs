@@ -0,0 +1,55 @@
+use super::HennaQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{
+        client::GameClientPacket,
+        server::{ActionFail, GameServerPacket, HennaUnequipInfo},
+    },
+    session::PacketReceiveParams,
+};
+
+pub(crate) struct RequestHennaItemRemoveInfoPlugin;
+impl Plugin for RequestHennaItemRemoveInfoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    henna_query: HennaQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestHennaItemRemoveInfo(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let henna_id = packet.0;
+
+    let hennas = henna_query.hennas(entity)?;
+    let (Some(slot), Some(dye)) = (
+        hennas.slot_of(henna_id),
+        henna_query.dyes()?.get(&henna_id).cloned(),
+    ) else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let mut removed = hennas;
+    removed.set(slot, None);
+
+    let unequip_info = HennaUnequipInfo::new(
+        henna_id,
+        dye,
+        henna_query.adena(entity),
+        henna_query.primal_stats(entity)?,
+        henna_query.bonus_change(&hennas, &removed)?,
+    );
+    commands.trigger_targets(GameServerPacket::from(unequip_info), entity);
+    Ok(())
+}
@@ -0,0 +1,318 @@
+use bevy::{
+    ecs::{entity::EntityHashSet, system::SystemParam},
+    log,
+    platform::collections::HashMap,
+    prelude::*,
+};
+use bevy_defer::{AccessError, AsyncAccess, AsyncCommandsExtension, AsyncWorld};
+use game_core::{
+    character::Character,
+    encounters::EnteredWorld,
+    henna::{self, HennaComponentsPlugin, HennaDyes, HennaDyesHandle, Hennas},
+    items::{ADENA_ID, Inventory, Item},
+    network::packets::server::{
+        GameServerPacket, HennaEquipList, HennaEquipListEntry, HennaInfo, HennaUnequipList,
+        SendUserInfo, UserInfoUpdated,
+    },
+    object_id::{ObjectId, ObjectIdManager},
+    stats::{PrimalStat, PrimalStats, StatModifiers, StatsTableQuery, SubClass, SubClassVariant},
+};
+use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
+use sea_orm::ColumnTrait;
+use state::GameMechanicsSystems;
+
+mod dyes;
+mod equip;
+mod item_info;
+mod item_list;
+mod item_remove_info;
+mod remove;
+mod remove_list;
+
+/// Symbols (hennas) are drawn by symbol maker NPCs using dyes,
+/// each symbol adds some primal stats at the cost of others.
+/// uses [`HennaInfo`], [`HennaEquipList`], [`HennaItemInfo`], [`HennaUnequipList`], [`HennaUnequipInfo`] server packets
+/// alongside with clients: [`RequestHennaItemList`], [`RequestHennaItemInfo`], [`RequestHennaEquip`],
+/// [`RequestHennaRemoveList`], [`RequestHennaItemRemoveInfo`], [`RequestHennaRemove`]
+pub(crate) struct HennaPlugin;
+impl Plugin for HennaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(HennaComponentsPlugin)
+            .add_plugins(dyes::HennaDyesPlugin)
+            .add_plugins(item_list::RequestHennaItemListPlugin)
+            .add_plugins(item_info::RequestHennaItemInfoPlugin)
+            .add_plugins(equip::RequestHennaEquipPlugin)
+            .add_plugins(remove_list::RequestHennaRemoveListPlugin)
+            .add_plugins(item_remove_info::RequestHennaItemRemoveInfoPlugin)
+            .add_plugins(remove::RequestHennaRemovePlugin);
+
+        app.init_resource::<PendingHennaInfo>()
+            .add_observer(henna_info_on_enter_world)
+            .add_observer(henna_info_on_user_info);
+
+        app.add_systems(
+            Update,
+            (
+                reload_hennas_on_sub_class_change,
+                collect_henna_info_requests,
+                on_hennas_changed.run_if(henna_dyes_loaded),
+                send_henna_info.run_if(henna_dyes_loaded),
+            )
+                .chain()
+                .in_set(GameMechanicsSystems::StatsCalculation),
+        );
+    }
+}
+
+/// Characters waiting for a [`HennaInfo`], they stay here until the dyes are loaded.
+#[derive(Default, Deref, DerefMut, Resource)]
+struct PendingHennaInfo(EntityHashSet);
+
+/// Class variant the [`Hennas`] of the character were loaded for.
+#[derive(Clone, Component, Copy, Debug)]
+struct HennasVariant(SubClassVariant);
+
+#[derive(SystemParam)]
+pub(crate) struct HennaQuery<'w, 's> {
+    characters: Query<
+        'w,
+        's,
+        (
+            Ref<'static, ObjectId>,
+            Ref<'static, Hennas>,
+            Ref<'static, SubClass>,
+            Ref<'static, Inventory>,
+            Ref<'static, PrimalStats>,
+        ),
+        With<Character>,
+    >,
+    items: Query<'w, 's, Ref<'static, Item>>,
+    object_id_manager: Res<'w, ObjectIdManager>,
+    stats_table: StatsTableQuery<'w>,
+    dyes_handle: Res<'w, HennaDyesHandle>,
+    dyes_assets: Res<'w, Assets<HennaDyes>>,
+}
+
+impl HennaQuery<'_, '_> {
+    pub fn dyes(&self) -> Result<&HennaDyes> {
+        self.dyes_assets
+            .get(self.dyes_handle.id())
+            .ok_or_else(|| BevyError::from("Henna dyes are not loaded"))
+    }
+
+    pub fn char_id(&self, entity: Entity) -> Result<ObjectId> {
+        Ok(*self.characters.get(entity)?.0)
+    }
+
+    pub fn hennas(&self, entity: Entity) -> Result<Hennas> {
+        Ok(*self.characters.get(entity)?.1)
+    }
+
+    pub fn sub_class(&self, entity: Entity) -> Result<SubClass> {
+        Ok(*self.characters.get(entity)?.2)
+    }
+
+    pub fn primal_stats(&self, entity: Entity) -> Result<PrimalStats> {
+        Ok(self.characters.get(entity)?.4.clone())
+    }
+
+    pub fn available_slots(&self, entity: Entity) -> Result<usize> {
+        let sub_class = self.sub_class(entity)?;
+        let profession_level = self
+            .stats_table
+            .class_tree()
+            .profession_level(sub_class.class_id());
+        Ok(Hennas::available_slots(profession_level))
+    }
+
+    /// Object id and count of the item stack in character inventory.
+    pub fn inventory_item(
+        &self,
+        entity: Entity,
+        item_id: game_core::items::Id,
+    ) -> Option<(ObjectId, u64)> {
+        let (_, _, _, inventory, _) = self.characters.get(entity).ok()?;
+        let (item_entity, object_id, _) =
+            inventory.single_by_item_id(item_id, &self.items, self.object_id_manager.as_ref())?;
+        let item = self.items.get(item_entity).ok()?;
+        Some((object_id, item.count()))
+    }
+
+    pub fn item_count(&self, entity: Entity, item_id: game_core::items::Id) -> u64 {
+        self.inventory_item(entity, item_id)
+            .map(|(_, count)| count)
+            .unwrap_or_default()
+    }
+
+    pub fn adena(&self, entity: Entity) -> u64 {
+        self.item_count(entity, ADENA_ID)
+    }
+
+    /// Difference between current symbol bonuses and bonuses of `changed` symbols.
+    pub fn bonus_change(
+        &self,
+        current: &Hennas,
+        changed: &Hennas,
+    ) -> Result<HashMap<PrimalStat, i32>> {
+        let dyes = self.dyes()?;
+        let current = current.stat_bonuses(dyes);
+        let changed = changed.stat_bonuses(dyes);
+        let mut bonus_change = HashMap::new();
+        for stat in current.keys().chain(changed.keys()) {
+            let change = changed.get(stat).copied().unwrap_or_default()
+                - current.get(stat).copied().unwrap_or_default();
+            bonus_change.insert(*stat, change);
+        }
+        Ok(bonus_change)
+    }
+
+    pub fn equip_list(&self, entity: Entity) -> Result<HennaEquipList> {
+        let dyes = self.dyes()?;
+        let sub_class = self.sub_class(entity)?;
+        let entries = dyes
+            .for_class(sub_class.class_id())
+            .into_iter()
+            .map(|(id, dye)| HennaEquipListEntry {
+                id,
+                dye: dye.clone(),
+                allowed: self.item_count(entity, dye.item_id) >= dye.wear.count,
+            })
+            .collect();
+        Ok(HennaEquipList::new(self.adena(entity), entries))
+    }
+
+    pub fn unequip_list(&self, entity: Entity) -> Result<HennaUnequipList> {
+        let dyes = self.dyes()?;
+        let hennas = self
+            .hennas(entity)?
+            .equipped()
+            .filter_map(|(_, id)| dyes.get(&id).map(|dye| (id, dye.clone())))
+            .collect();
+        Ok(HennaUnequipList::new(self.adena(entity), hennas))
+    }
+}
+
+fn henna_dyes_loaded(
+    dyes_handle: Res<HennaDyesHandle>,
+    dyes_assets: Res<Assets<HennaDyes>>,
+) -> bool {
+    dyes_assets.contains(dyes_handle.id())
+}
+
+fn henna_info_on_enter_world(
+    entered: Trigger<OnAdd, EnteredWorld>,
+    mut pending: ResMut<PendingHennaInfo>,
+) {
+    pending.insert(entered.target());
+}
+
+fn henna_info_on_user_info(trigger: Trigger<SendUserInfo>, mut pending: ResMut<PendingHennaInfo>) {
+    pending.insert(trigger.target());
+}
+
+/// Symbol slots depend on the class, so every class transfer and user info refresh resends them.
+fn collect_henna_info_requests(
+    mut pending: ResMut<PendingHennaInfo>,
+    mut user_info_updated: EventReader<UserInfoUpdated>,
+    sub_classes: Query<Entity, (With<Hennas>, Changed<SubClass>)>,
+) {
+    pending.extend(user_info_updated.read().map(|event| event.0));
+    pending.extend(sub_classes.iter());
+}
+
+/// Symbols are kept per class variant, switching to another one loads its symbols.
+fn reload_hennas_on_sub_class_change(
+    mut commands: Commands,
+    characters: Query<(Entity, Ref<SubClass>, Ref<HennasVariant>), Changed<SubClass>>,
+) {
+    for (entity, sub_class, loaded_variant) in characters.iter() {
+        if sub_class.variant() != loaded_variant.0 {
+            commands.spawn_task(move || async move { henna_init_task(entity).await });
+        }
+    }
+}
+
+fn on_hennas_changed(
+    mut pending: ResMut<PendingHennaInfo>,
+    mut characters: Query<(Entity, Ref<Hennas>, Mut<StatModifiers>), Changed<Hennas>>,
+    dyes_handle: Res<HennaDyesHandle>,
+    dyes_assets: Res<Assets<HennaDyes>>,
+) {
+    let Some(dyes) = dyes_assets.get(dyes_handle.id()) else {
+        return;
+    };
+    for (entity, hennas, mut stat_modifiers) in characters.iter_mut() {
+        hennas.apply_modifiers(dyes, &mut stat_modifiers);
+        pending.insert(entity);
+    }
+}
+
+fn send_henna_info(
+    mut commands: Commands,
+    mut pending: ResMut<PendingHennaInfo>,
+    characters: Query<(Ref<Hennas>, Ref<SubClass>), With<Character>>,
+    dyes_handle: Res<HennaDyesHandle>,
+    dyes_assets: Res<Assets<HennaDyes>>,
+    stats_table: StatsTableQuery,
+) {
+    let Some(dyes) = dyes_assets.get(dyes_handle.id()) else {
+        return;
+    };
+    for entity in pending.drain() {
+        let Ok((hennas, sub_class)) = characters.get(entity) else {
+            continue;
+        };
+        let profession_level = stats_table
+            .class_tree()
+            .profession_level(sub_class.class_id());
+        let available_slots = Hennas::available_slots(profession_level);
+        commands.trigger_targets(
+            GameServerPacket::from(HennaInfo::new(&hennas, dyes, available_slots)),
+            entity,
+        );
+    }
+}
+
+pub async fn henna_init_task(char_entity: Entity) -> Result<(), AccessError> {
+    let Ok(henna_repository) = AsyncWorld
+        .resource::<RepositoryManager>()
+        .get(|registry| registry.typed::<henna::model::HennaPK, henna::model::Entity>())?
+    else {
+        return Ok(());
+    };
+
+    let char_id = AsyncWorld
+        .entity(char_entity)
+        .component::<ObjectId>()
+        .get(|component| *component)?;
+
+    let sub_class = AsyncWorld
+        .entity(char_entity)
+        .component::<SubClass>()
+        .get(|component| *component)?;
+
+    let result = henna_repository
+        .find_with_conditions([
+            henna::model::Column::CharId.eq(char_id),
+            henna::model::Column::ClassVariant.eq(sub_class.variant()),
+        ])
+        .await;
+
+    if let Err(err) = result {
+        log::error!("Character: {}, Error loading hennas: {:?}", char_id, err);
+        return Err(err.into());
+    }
+
+    let mut hennas = Hennas::default();
+    for model in result.unwrap_or_default() {
+        hennas.set(model.slot as usize, Some(model.henna_id));
+    }
+
+    AsyncWorld.apply_command(move |world: &mut World| {
+        if let Ok(mut character) = world.get_entity_mut(char_entity) {
+            character.insert((hennas, HennasVariant(sub_class.variant())));
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use super::HennaQuery;
+use bevy::{log, prelude::*};
+use bevy_defer::AsyncCommandsExtension;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    henna,
+    items::{ADENA_ID, DestroyItemRequest, ItemLocation, SpawnNew},
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{ActionFail, GameServerPacket, SystemMessage},
+        },
+        session::PacketReceiveParams,
+    },
+};
+use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
+use system_messages::Id;
+
+pub(crate) struct RequestHennaRemovePlugin;
+impl Plugin for RequestHennaRemovePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    repo_manager: Res<RepositoryManager>,
+    henna_query: HennaQuery,
+    mut items_spawn: EventWriter<SpawnNew>,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestHennaRemove(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let henna_id = packet.0;
+
+    let mut hennas = henna_query.hennas(entity)?;
+    let (Some(slot), Some(dye)) = (
+        hennas.slot_of(henna_id),
+        henna_query.dyes()?.get(&henna_id).cloned(),
+    ) else {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(
+                Id::TheSymbolInformationCannotBeFound,
+            )),
+            entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let adena_stack = henna_query.inventory_item(entity, ADENA_ID);
+    if dye.cancel.fee > 0 {
+        let Some((adena_oid, _)) = adena_stack.filter(|(_, count)| *count >= dye.cancel.fee) else {
+            commands.trigger_targets(
+                GameServerPacket::from(SystemMessage::new_empty(Id::YouDoNotHaveEnoughAdena)),
+                entity,
+            );
+            commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+            return Ok(());
+        };
+
+        commands.trigger_targets(
+            DestroyItemRequest {
+                item_oid: adena_oid,
+                count: dye.cancel.fee,
+            },
+            entity,
+        );
+    }
+
+    // Part of the dyes is returned back to the character
+    if dye.cancel.count > 0 {
+        items_spawn.write(SpawnNew {
+            item_ids: vec![dye.item_id],
+            count: dye.cancel.count,
+            item_location: ItemLocation::Inventory,
+            dropped_entity: None,
+            owner: Some(entity),
+            silent: false,
+        });
+    }
+
+    hennas.set(slot, None);
+    commands.entity(entity).insert(hennas);
+
+    commands.trigger_targets(
+        GameServerPacket::from(SystemMessage::new_empty(Id::TheSymbolHasBeenDeleted)),
+        entity,
+    );
+
+    let char_id = henna_query.char_id(entity)?;
+    let class_variant = henna_query.sub_class(entity)?.variant();
+    let henna_repository = repo_manager.typed::<henna::model::HennaPK, henna::model::Entity>()?;
+
+    commands.spawn_task(move || async move {
+        let result = henna_repository
+            .delete_by_id(henna::model::HennaPK {
+                char_id,
+                slot: slot as i16,
+                class_variant,
+            })
+            .await;
+
+        if let Err(err) = result {
+            log::error!("Character: {}, Failed to delete henna: {:?}", char_id, err);
+            return Err(err.into());
+        }
+
+        Ok(())
+    });
+    Ok(())
+}
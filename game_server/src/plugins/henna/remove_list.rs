@@ -0,0 +1,32 @@
+use super::HennaQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{client::GameClientPacket, server::GameServerPacket},
+    session::PacketReceiveParams,
+};
+
+pub(crate) struct RequestHennaRemoveListPlugin;
+impl Plugin for RequestHennaRemoveListPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    henna_query: HennaQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestHennaRemoveList(_) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let unequip_list = henna_query.unequip_list(entity)?;
+    commands.trigger_targets(GameServerPacket::from(unequip_list), entity);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use bevy::{log, prelude::*};
+use game_core::instance_zone::*;
+use l2r_core::chronicles::CHRONICLE;
+use state::{GameServerStateSystems, LoadingSystems};
+use std::path::PathBuf;
+
+pub struct InstanceTemplatesPlugin;
+impl Plugin for InstanceTemplatesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InstanceTemplatesHandle>();
+
+        app.add_systems(
+            Update,
+            (
+                load_assets.in_set(LoadingSystems::AssetInit),
+                update_assets.in_set(LoadingSystems::AssetInit),
+            ),
+        );
+
+        app.add_systems(Update, update_assets.in_set(GameServerStateSystems::Run));
+    }
+}
+
+fn load_assets(
+    asset_server: Res<AssetServer>,
+    mut templates_handle: ResMut<InstanceTemplatesHandle>,
+    mut loaded: Local<bool>,
+) {
+    if *loaded {
+        return;
+    }
+    let mut path = PathBuf::from("instance_zone");
+    path.push(CHRONICLE);
+    path.push("instances");
+    path.set_extension("json");
+
+    let handle: Handle<InstanceTemplates> = asset_server.load(path.clone());
+    **templates_handle = handle;
+    *loaded = true;
+}
+
+fn update_assets(
+    handle: Res<InstanceTemplatesHandle>,
+    mut events: EventReader<AssetEvent<InstanceTemplates>>,
+) {
+    for event in events.read() {
+        if event.is_loaded_with_dependencies(handle.id()) {
+            log::info!("Instance templates updated");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use l2r_core::{assets::ASSET_DIR, chronicles::CHRONICLE, utils::get_base_path};
+    use serde_json::from_reader;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_parse_all_from_json() {
+        let mut path = get_base_path();
+        path.push(ASSET_DIR);
+        path.push("instance_zone");
+        path.push(CHRONICLE);
+        path.push("instances");
+        path.set_extension("json");
+
+        let file = File::open(&path).unwrap_or_else(|_| panic!("Failed to open file: {:?}", path));
+        let reader = BufReader::new(file);
+
+        let result: InstanceTemplates = from_reader(reader)
+            .unwrap_or_else(|_| panic!("Failed to parse instance templates from JSON: {:?}", path));
+
+        for (template_id, template) in result.iter() {
+            assert!(
+                template.min_level <= template.max_level,
+                "Instance {template_id} has no levels to enter"
+            );
+            assert!(
+                template.min_members <= template.max_members,
+                "Instance {template_id} has no party size to enter"
+            );
+        }
+    }
+}
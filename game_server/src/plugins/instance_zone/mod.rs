@@ -0,0 +1,385 @@
+use bevy::{ecs::system::SystemParam, log, prelude::*, time::common_conditions::on_timer};
+use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use chrono::Utc;
+use game_core::{
+    character::Character,
+    instance_zone::{
+        self, EnterInstanceZone, InstanceExit, InstanceTemplate, InstanceTemplateId,
+        InstanceTemplates, InstanceTemplatesHandle, InstanceZone, InstanceZoneComponentsPlugin,
+        InstanceZoneError, InstanceZoneId, InstanceZones, LeaveInstanceZone, model::InstanceTimePK,
+    },
+    network::packets::server::{GameServerPacket, SystemMessage, TeleportToLocation},
+    npc::{self, Spawned},
+    object_id::ObjectId,
+    stats::ProgressLevelStats,
+    teleport::TeleportType,
+};
+use l2r_core::{
+    db::{DbConnection, Repository, RepositoryManager, RepositoryModel, TypedRepositoryManager},
+    plugins::custom_hierarchy::DespawnChildOf,
+};
+use map::{Door, NamedZones, Zone, ZoneKind};
+use sea_orm::{ColumnTrait, Condition, QueryFilter};
+use state::{GameServerStateSystems, LoadingSystems};
+use std::time::Duration;
+use system_messages::{Id as SmId, SmParam};
+
+mod data;
+
+/// Instances are copies of the template doors, zones and NPCs for a party of their own.
+/// Everything inside of an instance carries its id and never meets the rest of the world.
+pub(crate) struct InstanceZonePlugin;
+impl Plugin for InstanceZonePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(InstanceZoneComponentsPlugin)
+            .add_plugins(data::InstanceTemplatesPlugin);
+
+        app.add_observer(enter_instance_zone)
+            .add_observer(leave_instance_zone)
+            .add_observer(instance_npc_spawned);
+
+        app.add_systems(Update, load_instance_zones.in_set(LoadingSystems::IdInit));
+
+        app.add_systems(
+            Update,
+            update_instance_zones
+                .run_if(resource_exists::<InstanceZones>)
+                .run_if(on_timer(Duration::from_secs(1)))
+                .in_set(GameServerStateSystems::Run),
+        );
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct InstanceZoneQuery<'w, 's> {
+    zones: ResMut<'w, InstanceZones>,
+    templates_handle: Res<'w, InstanceTemplatesHandle>,
+    templates_assets: Res<'w, Assets<InstanceTemplates>>,
+    instances: Query<'w, 's, (Entity, Ref<'static, InstanceZone>)>,
+    doors: Query<'w, 's, Ref<'static, Zone>, (With<Door>, Without<InstanceZoneId>)>,
+    named_zones: Res<'w, NamedZones>,
+    zones_query: Query<'w, 's, Ref<'static, Zone>>,
+    repo_manager: Res<'w, RepositoryManager>,
+}
+
+impl InstanceZoneQuery<'_, '_> {
+    pub fn template(&self, template_id: InstanceTemplateId) -> Result<&InstanceTemplate> {
+        self.templates_assets
+            .get(self.templates_handle.id())
+            .ok_or_else(|| BevyError::from("Instance templates are not loaded"))?
+            .get(&template_id)
+            .ok_or_else(|| InstanceZoneError::UnknownTemplate(template_id).into())
+    }
+
+    /// Running instance of the template the character has been let in before.
+    pub fn running(
+        &self,
+        template_id: InstanceTemplateId,
+        char_id: ObjectId,
+    ) -> Option<(Entity, InstanceZoneId)> {
+        self.instances.iter().find_map(|(entity, instance)| {
+            (instance.template_id == template_id && instance.member(char_id))
+                .then_some((entity, instance.id))
+        })
+    }
+
+    /// Spawns a new copy of the template and starts the re-entry delay of the characters.
+    pub fn create(
+        &mut self,
+        commands: &mut Commands,
+        template_id: InstanceTemplateId,
+        members: Vec<ObjectId>,
+    ) -> Result<(Entity, InstanceZoneId)> {
+        let now = Utc::now().naive_utc();
+        let id = self.zones.next_id();
+        let template = self.template(template_id)?.clone();
+
+        let root = commands
+            .spawn((
+                InstanceZone::new(id, template_id, &template, members.clone(), now),
+                Name::new(format!("{} instance {}", template.name, id)),
+            ))
+            .id();
+
+        for door in self.doors.iter().filter(
+            |zone| matches!(zone.kind(), ZoneKind::Door(door) if template.doors.contains(&door.id)),
+        ) {
+            commands.spawn((
+                door.name_component(),
+                door.clone(),
+                id,
+                DespawnChildOf(root),
+            ));
+        }
+
+        for name in template.zones.iter() {
+            let Some(zone) = self
+                .named_zones
+                .get(name)
+                .and_then(|entity| self.zones_query.get(*entity).ok())
+            else {
+                log::warn!(
+                    "Zone {} of the instance {} is not found",
+                    name,
+                    template.name
+                );
+                continue;
+            };
+            commands.spawn((
+                zone.name_component(),
+                zone.clone(),
+                id,
+                DespawnChildOf(root),
+            ));
+        }
+
+        for spawn in template.npcs.iter() {
+            commands.trigger_targets(
+                npc::Spawn {
+                    id: spawn.id,
+                    transform: spawn.transform(),
+                },
+                root,
+            );
+        }
+
+        let models = self
+            .zones
+            .lock_reentry(template_id, &template, &members, now);
+        self.save(commands, models)?;
+
+        log::info!("Instance {} of {} is created", id, template.name);
+        Ok((root, id))
+    }
+
+    fn save(
+        &self,
+        commands: &mut Commands,
+        models: Vec<instance_zone::model::Model>,
+    ) -> Result<()> {
+        if self.repo_manager.is_mock() {
+            return Ok(());
+        }
+        let repository = self
+            .repo_manager
+            .typed::<InstanceTimePK, instance_zone::model::Entity>()?;
+        commands.spawn_task(move || async move {
+            for model in models {
+                if let Err(err) = repository
+                    .create_or_update(&model, instance_zone::model::Model::on_conflict())
+                    .await
+                {
+                    log::error!(
+                        "Character: {}, Error saving instance re-entry time: {:?}",
+                        model.char_id,
+                        err
+                    );
+                    return Err(err.into());
+                }
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+}
+
+fn load_instance_zones(
+    mut commands: Commands,
+    repo_manager: Res<RepositoryManager>,
+    db_connection: Res<DbConnection>,
+    mut loaded: Local<bool>,
+) -> Result<()> {
+    if *loaded {
+        return Ok(());
+    }
+    *loaded = true;
+
+    if db_connection.is_mock() {
+        commands.insert_resource(InstanceZones::default());
+        return Ok(());
+    }
+
+    let now = Utc::now().naive_utc();
+    let repository = repo_manager.typed::<InstanceTimePK, instance_zone::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        // Passed re-entry times are of no use anymore
+        repository
+            .delete_many(|query| query.filter(instance_zone::model::Column::ReenterAt.lte(now)))
+            .await?;
+        let reentries = repository.find_with_conditions([Condition::all()]).await?;
+
+        log::info!("Loaded {} instance re-entry times.", reentries.len());
+        AsyncWorld.apply_command(move |world: &mut World| {
+            world.insert_resource(InstanceZones::new(reentries));
+        });
+        Ok(())
+    });
+    Ok(())
+}
+
+/// Characters come back to the running instance they were let in,
+/// otherwise a new one is created once everybody passes the entry checks.
+fn enter_instance_zone(
+    enter: Trigger<EnterInstanceZone>,
+    mut commands: Commands,
+    characters: Query<
+        (
+            Ref<ObjectId>,
+            Ref<Name>,
+            Ref<Transform>,
+            Ref<ProgressLevelStats>,
+            Option<Ref<InstanceZoneId>>,
+        ),
+        With<Character>,
+    >,
+    mut instance_query: InstanceZoneQuery,
+) -> Result<()> {
+    let entity = enter.target();
+    let EnterInstanceZone(template_id) = *enter.event();
+    let (char_id, name, transform, level, current) = characters.get(entity)?;
+    let char_id = *char_id;
+
+    let running = instance_query.running(template_id, char_id);
+    if current.is_some_and(|current| running.is_none_or(|(_, id)| id != *current)) {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(
+                SmId::YouHaveEnteredAnotherInstanceZoneThereforeYouCannotEnterCorrespondingDungeon,
+            )),
+            entity,
+        );
+        return Ok(());
+    }
+
+    let id = match running {
+        Some((_, id)) => id,
+        None => {
+            // Parties do not exist yet, the character enters on its own
+            let entrants = [(char_id, level.level())];
+            let template = instance_query.template(template_id)?;
+            let now = Utc::now().naive_utc();
+            if let Err(err) =
+                instance_query
+                    .zones
+                    .check_entry(template_id, template, &entrants, now)
+            {
+                let player = || vec![SmParam::Player(name.to_string())];
+                let sm = match err {
+                    InstanceZoneError::NotInParty => {
+                        SystemMessage::new_empty(SmId::YouAreNotCurrentlyInAPartySoYouCannotEnter)
+                    }
+                    InstanceZoneError::PartyTooLarge => SystemMessage::new_empty(
+                        SmId::YouCannotEnterDueToThePartyHavingExceededTheLimit,
+                    ),
+                    InstanceZoneError::Level(_) => SystemMessage::new(
+                        SmId::C1SLevelDoesNotCorrespondToTheRequirementsForEntry,
+                        player(),
+                    ),
+                    InstanceZoneError::Reentry(_) => {
+                        SystemMessage::new(SmId::C1MayNotReEnterYet, player())
+                    }
+                    InstanceZoneError::UnknownTemplate(_) => return Err(err.into()),
+                };
+                commands.trigger_targets(GameServerPacket::from(sm), entity);
+                return Ok(());
+            }
+            let members = entrants.iter().map(|(char_id, _)| *char_id).collect();
+            instance_query
+                .create(&mut commands, template_id, members)?
+                .1
+        }
+    };
+
+    let enter = instance_query.template(template_id)?.enter;
+    if current.is_none() {
+        commands
+            .entity(entity)
+            .insert(InstanceExit::from(transform.translation));
+    }
+    commands.entity(entity).insert(id);
+    commands.trigger_targets(
+        TeleportToLocation::new(
+            char_id,
+            Transform::from_translation(enter.into()),
+            TeleportType::default(),
+        ),
+        entity,
+    );
+    log::debug!("Character {} entered instance {}", char_id, id);
+    Ok(())
+}
+
+fn leave_instance_zone(
+    leave: Trigger<LeaveInstanceZone>,
+    mut commands: Commands,
+    characters: Query<(Ref<ObjectId>, Ref<InstanceExit>), With<Character>>,
+) -> Result<()> {
+    let entity = leave.target();
+    let Ok((char_id, exit)) = characters.get(entity) else {
+        return Ok(());
+    };
+
+    commands
+        .entity(entity)
+        .remove::<(InstanceZoneId, InstanceExit)>();
+    commands.trigger_targets(
+        TeleportToLocation::new(
+            *char_id,
+            Transform::from_translation(**exit),
+            TeleportType::default(),
+        ),
+        entity,
+    );
+    Ok(())
+}
+
+/// NPCs of the template belong to the instance they are spawned for.
+fn instance_npc_spawned(
+    spawned: Trigger<Spawned>,
+    mut commands: Commands,
+    parents: Query<Ref<DespawnChildOf>>,
+    instances: Query<Ref<InstanceZone>>,
+) {
+    let entity = spawned.target();
+    if let Ok(parent) = parents.get(entity)
+        && let Ok(instance) = instances.get(**parent)
+    {
+        commands.entity(entity).insert(instance.id);
+    }
+}
+
+/// Members are warned before the end of the instance and moved out once it is closed,
+/// be it the time is up or nobody has been inside for long enough. Members who are offline
+/// are saved at the exit and come back there.
+fn update_instance_zones(
+    mut commands: Commands,
+    mut instances: Query<(Entity, Mut<InstanceZone>)>,
+    characters: Query<(Entity, Ref<InstanceZoneId>), (With<Character>, With<InstanceExit>)>,
+) {
+    let now = Utc::now().naive_utc();
+    for (root, mut instance) in instances.iter_mut() {
+        let inside = characters
+            .iter()
+            .filter(|(_, id)| **id == instance.id)
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>();
+
+        if let Some(minutes) = instance.end_announcement(now) {
+            let sm = SystemMessage::new(
+                SmId::ThisInstanceZoneWillBeTerminatedInS1MinuteSYouWillBeForcedOutOfTheDungeonWhenTheTimeExpires,
+                vec![SmParam::Number(minutes as u32)],
+            );
+            for entity in inside.iter() {
+                commands.trigger_targets(GameServerPacket::from(sm.clone()), *entity);
+            }
+        }
+
+        if !instance.update(!inside.is_empty(), now) {
+            continue;
+        }
+        for entity in inside {
+            commands.trigger_targets(LeaveInstanceZone, entity);
+        }
+        log::info!("Instance {} is closed", instance.id);
+        commands.entity(root).despawn();
+    }
+}
@@ -1,19 +1,12 @@
 use bevy::prelude::*;
-use bevy_defer::AsyncCommandsExtension;
 use game_core::{
     items::{
-        self,
-        model::{ActiveModelSetCoordinates, Model},
+        journal::{ItemDelta, ItemTransaction, ItemTransactionKind},
         *,
     },
     network::packets::server::{GameServerPacket, InventoryUpdate, SystemMessage},
-    object_id::ObjectId,
 };
-use l2r_core::{
-    db::{Repository, RepositoryManager, TypedRepositoryManager},
-    plugins::custom_hierarchy::DespawnChildOf,
-};
-use sea_orm::{ActiveValue::Set, IntoActiveModel};
+use l2r_core::plugins::custom_hierarchy::DespawnChildOf;
 use smallvec::smallvec;
 use system_messages;
 
@@ -28,7 +21,6 @@ impl Plugin for AddInInventoryPlugin {
 fn add_in_inventory(
     trigger: Trigger<AddInInventory>,
     mut commands: Commands,
-    repo_manager: Res<RepositoryManager>,
     mut inventories: Query<InventoriesQueryMut>,
     mut items_data: ItemsDataQueryMut,
 ) -> Result<()> {
@@ -68,25 +60,14 @@ fn add_in_inventory(
             let inventory_update = InventoryUpdate::new(smallvec![unique_item], UpdateType::Modify);
             commands.trigger_targets(GameServerPacket::from(inventory_update), inventory_target);
 
-            if !repo_manager.is_mock() {
-                let items_repository = repo_manager.typed::<ObjectId, items::model::Entity>()?;
-                // Update the existing item count
-                commands.spawn_task(move || async move {
-                    let item_count = unique_item.item().count() as i64;
-                    let item_model = Model::from(unique_item);
-                    let mut active_model = item_model.into_active_model();
-                    active_model.count = Set(item_count);
-
-                    items_repository.update(&active_model).await?;
-                    Ok(())
-                });
-                // Delete the merged item from database
-                let items_repository = repo_manager.typed::<ObjectId, items::model::Entity>()?;
-                commands.spawn_task(move || async move {
-                    items_repository.delete_by_id(new_item_oid).await?;
-                    Ok(())
-                });
-            }
+            commands.trigger(ItemTransaction::new(
+                ItemTransactionKind::Obtain,
+                vec![
+                    ItemDelta::from(unique_item),
+                    ItemDelta::removed(UniqueItem::new(new_item_oid, new_item)),
+                ],
+            ));
+
             items_data.object_id_manager.release_id(new_item_oid);
             commands.entity(item_entity).try_despawn();
 
@@ -131,20 +112,10 @@ fn add_in_inventory(
         .entity(item_entity)
         .insert(DespawnChildOf(inventory_target));
 
-    if !repo_manager.is_mock() {
-        let items_repository = repo_manager.typed::<ObjectId, items::model::Entity>()?;
-        commands.spawn_task(move || async move {
-            let item_model = Model::from(unique_item);
-            let mut active_model = item_model.into_active_model();
-
-            active_model.count = Set(unique_item.item().count() as i64);
-            active_model.owner_id = Set(unique_item.item().owner());
-            active_model.set_location(unique_item.item().location());
-
-            items_repository.update(&active_model).await?;
-            Ok(())
-        });
-    }
+    commands.trigger(ItemTransaction::new(
+        ItemTransactionKind::Obtain,
+        vec![ItemDelta::from(unique_item)],
+    ));
 
     if !event.silent {
         commands.trigger_targets(
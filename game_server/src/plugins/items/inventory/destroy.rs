@@ -4,8 +4,10 @@ use game_core::{
     items::{
         DestroyItemRequest, Inventory, ItemsDataAccess, ItemsDataQueryMut, UnequipItem, UniqueItem,
         UpdateType,
+        journal::{ItemDelta, ItemTransaction, ItemTransactionKind},
     },
     network::packets::server::{GameServerPacket, InventoryUpdate},
+    object_id::ObjectId,
 };
 use smallvec::smallvec;
 
@@ -20,25 +22,51 @@ pub fn destroy_item(
 
     let mut inventory = inventories.get_mut(inventory_entity)?;
 
-    inventory.get_item(request.item_oid)?;
+    let delta = take_from_inventory(
+        &mut commands,
+        inventory_entity,
+        &mut inventory,
+        &mut items_data,
+        request.item_oid,
+        request.count,
+    )?;
+    commands.trigger(ItemTransaction::new(
+        ItemTransactionKind::Destroy,
+        vec![delta],
+    ));
 
-    let item_entity = items_data.entity(request.item_oid)?;
+    Ok(())
+}
+
+/// Takes the count of the item out of the inventory, the item is gone once nothing is left.
+/// Returns the state of the item to persist.
+pub(crate) fn take_from_inventory(
+    commands: &mut Commands,
+    inventory_entity: Entity,
+    inventory: &mut Inventory,
+    items_data: &mut ItemsDataQueryMut,
+    item_oid: ObjectId,
+    count: u64,
+) -> Result<ItemDelta> {
+    inventory.get_item(item_oid)?;
 
-    let item = *items_data.item_by_object_id(request.item_oid)?;
+    let item_entity = items_data.entity(item_oid)?;
+
+    let item = *items_data.item_by_object_id(item_oid)?;
 
     let item_id = item.id();
     let item_count = item.count();
     let item_info = items_data.item_info(item_id)?;
     // Check if item is stackable and if we're destroying the entire stack or just part
-    let destroy_full_stack = !item_info.stackable() || request.count >= item_count;
+    let destroy_full_stack = !item_info.stackable() || count >= item_count;
 
     if destroy_full_stack {
-        inventory.remove_item(request.item_oid)?;
+        inventory.remove_item(item_oid)?;
 
         if item.equipped() {
             commands.trigger_targets(
                 UnequipItem {
-                    item_object_id: request.item_oid,
+                    item_object_id: item_oid,
                     skip_db_update: true,
                 },
                 inventory_entity,
@@ -47,9 +75,9 @@ pub fn destroy_item(
 
         // Remove the item entity from the world
         commands.entity(item_entity).despawn();
-        items_data.object_id_manager.release_id(request.item_oid);
+        items_data.object_id_manager.release_id(item_oid);
 
-        let unique_item = UniqueItem::new(request.item_oid, item);
+        let unique_item = UniqueItem::new(item_oid, item);
         commands.trigger_targets(
             GameServerPacket::from(InventoryUpdate::new(
                 smallvec![unique_item],
@@ -57,11 +85,11 @@ pub fn destroy_item(
             )),
             inventory_entity,
         );
+        Ok(ItemDelta::removed(unique_item))
     } else {
         // Partial destroy - split the stack, inventory update will be handled in ItemsPlugin::count_changed
-        let mut item = items_data.item_by_object_id_mut(request.item_oid)?;
-        item.set_count(item_count - request.count);
+        let mut item = items_data.item_by_object_id_mut(item_oid)?;
+        item.set_count(item_count - count);
+        Ok(ItemDelta::from(UniqueItem::new(item_oid, *item)))
     }
-
-    Ok(())
 }
@@ -1,12 +1,11 @@
 use bevy::prelude::*;
-use bevy_defer::AsyncCommandsExtension;
 use game_core::{
     active_action::ActiveAction,
     items::{
-        self, DropIfPossible, InventoriesQueryMut, InventoriesQueryMutItem,
+        DropIfPossible, InventoriesQueryMut, InventoriesQueryMutItem,
         InventoriesQueryMutReadOnlyItem, Item, ItemInWorld, ItemLocation, ItemMetric,
         ItemsDataAccess, ItemsDataQueryMut, PaperDoll, UniqueItem, UpdateType,
-        model::{ActiveModelSetCoordinates, Model},
+        journal::{ItemDelta, ItemTransaction, ItemTransactionKind},
     },
     network::{
         broadcast::ServerPacketBroadcast,
@@ -14,15 +13,9 @@ use game_core::{
             DropItem as DropItemPacket, GameServerPacket, InventoryUpdate, SystemMessage,
         },
     },
-    object_id::ObjectId,
-};
-use l2r_core::{
-    db::{Repository, RepositoryManager, TypedRepositoryManager},
-    metrics::Metrics,
-    plugins::custom_hierarchy::DespawnChildOf,
 };
+use l2r_core::{metrics::Metrics, plugins::custom_hierarchy::DespawnChildOf};
 use map::WorldMap;
-use sea_orm::{ActiveValue::Set, IntoActiveModel};
 use smallvec::smallvec;
 use spatial::FlatDistance;
 use system_messages::{self, Id, SmParam};
@@ -40,7 +33,6 @@ pub fn drop_if_possible(
     mut commands: Commands,
     mut items_data: ItemsDataQueryMut,
     mut inventories: Query<(InventoriesQueryMut, Ref<Transform>), Without<ActiveAction>>,
-    repo_manager: Res<RepositoryManager>,
     metrics: Res<Metrics>,
 ) -> Result<()> {
     let dropper_entity = drop_request.target();
@@ -79,7 +71,6 @@ pub fn drop_if_possible(
             &mut items_data,
             doll_query,
             true,
-            repo_manager.as_ref(),
         )?;
     }
 
@@ -118,19 +109,10 @@ pub fn drop_if_possible(
             dropper_entity,
         );
 
-        if !repo_manager.is_mock() {
-            let items_repository = repo_manager.typed::<ObjectId, items::model::Entity>()?;
-            commands.spawn_task(move || async move {
-                let item_model = Model::from(unique_item);
-                let mut active_model = item_model.into_active_model();
-
-                active_model.set_location(unique_item.item().location());
-                active_model.owner_id = Set(unique_item.item().owner());
-
-                items_repository.update(&active_model).await?;
-                Ok(())
-            });
-        }
+        commands.trigger(ItemTransaction::new(
+            ItemTransactionKind::Drop,
+            vec![ItemDelta::from(unique_item)],
+        ));
 
         event.item_oid
     } else {
@@ -160,15 +142,13 @@ pub fn drop_if_possible(
         let item_info = items_data.item_info(item_id)?;
         let dropped_entity = new_unique_item.spawn(&mut commands, item_info).id();
 
-        if !repo_manager.is_mock() {
-            let items_repository = repo_manager.typed::<ObjectId, items::model::Entity>()?;
-
-            commands.spawn_task(move || async move {
-                let new_model = Model::from(new_unique_item);
-                items_repository.create(&new_model).await?;
-                Ok(())
-            });
-        }
+        commands.trigger(ItemTransaction::new(
+            ItemTransactionKind::Drop,
+            vec![
+                ItemDelta::from(updated_inventory_item),
+                ItemDelta::from(new_unique_item),
+            ],
+        ));
 
         if let Some(region_entity) = world_map.get_by_loc(event.location) {
             commands
@@ -1,14 +1,13 @@
 use bevy::prelude::*;
-use bevy_defer::AsyncCommandsExtension;
 use game_core::{
     attack::Attacking,
-    items::{model as items_model, *},
+    items::{
+        journal::{ItemDelta, ItemTransaction, ItemTransactionKind},
+        *,
+    },
     network::packets::server::*,
-    object_id::ObjectId,
     stats::{AttackEffects, StatModifiers, Weapon},
 };
-use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
-use sea_orm::IntoActiveModel;
 use smallvec::smallvec;
 use system_messages::{Id as SmId, SmParam};
 
@@ -26,7 +25,6 @@ fn handle_equip_item(
     mut commands: Commands,
     mut characters: Query<(InventoriesQueryMut, Mut<StatModifiers>), Without<Attacking>>,
     mut items_query: ItemsDataQueryMut,
-    repo_manager: Res<RepositoryManager>,
 ) -> Result<()> {
     let character_entity = trigger.target();
     let item_object_id = trigger.event().0;
@@ -54,7 +52,6 @@ fn handle_equip_item(
                 &mut items_query,
                 doll_query,
                 false,
-                &repo_manager,
             )?;
         }
     }
@@ -71,7 +68,8 @@ fn handle_equip_item(
     let item = items_query.item_by_object_id(item_object_id)?;
     let item_info = items_query.item_info(item.id())?;
 
-    if item_info.kind().ammo() && !paper_doll.is_ammo_valid_for_weapon(item_object_id, &items_query)
+    if (item_info.kind().ammo() || item_info.kind().lure())
+        && !paper_doll.is_ammo_valid_for_weapon(item_object_id, &items_query)
     {
         return Ok(());
     }
@@ -111,20 +109,10 @@ fn handle_equip_item(
     if let Some(unique_item) = unique_item {
         let inventory_update = InventoryUpdate::new(smallvec![unique_item], UpdateType::Modify);
         commands.trigger_targets(GameServerPacket::from(inventory_update), character_entity);
-
-        if !repo_manager.is_mock() {
-            let items_repository = repo_manager.typed::<ObjectId, items_model::Entity>()?;
-            let item_model = items_model::Model::from(unique_item);
-            commands.spawn_task(move || async move {
-                let mut item_active = item_model.into_active_model();
-                items_model::ActiveModelSetCoordinates::set_location(
-                    &mut item_active,
-                    unique_item.item().location(),
-                );
-                items_repository.update(&item_active).await?;
-                Ok(())
-            });
-        }
+        commands.trigger(ItemTransaction::new(
+            ItemTransactionKind::Equip,
+            vec![ItemDelta::from(unique_item)],
+        ));
     }
 
     commands.trigger_targets(
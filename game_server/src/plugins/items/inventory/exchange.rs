@@ -0,0 +1,56 @@
+use super::take_from_inventory;
+use bevy::prelude::*;
+use game_core::{
+    active_action::ActiveAction,
+    items::{
+        ExchangeItems, Inventory, Item, ItemLocation, ItemsDataAccess, ItemsDataQueryMut,
+        UniqueItem,
+        journal::{ItemDelta, ItemTransaction},
+    },
+    object_id::ObjectId,
+};
+
+/// Given items are spawned in the inventory of the owner and stacked once added there.
+pub fn exchange_items(
+    exchange: Trigger<ExchangeItems>,
+    mut commands: Commands,
+    mut inventories: Query<(Ref<ObjectId>, Mut<Inventory>), Without<ActiveAction>>,
+    mut items_data: ItemsDataQueryMut,
+) -> Result<()> {
+    let inventory_entity = exchange.target();
+    let event = exchange.event();
+
+    let (owner_id, mut inventory) = inventories.get_mut(inventory_entity)?;
+    let owner_id = *owner_id;
+
+    // Nothing changes unless every taken item is there
+    for &(item_oid, _) in event.taken.iter() {
+        inventory.get_item(item_oid)?;
+    }
+
+    let mut deltas = Vec::with_capacity(event.taken.len() + event.given.len());
+    for &(item_oid, count) in event.taken.iter() {
+        deltas.push(take_from_inventory(
+            &mut commands,
+            inventory_entity,
+            &mut inventory,
+            &mut items_data,
+            item_oid,
+            count,
+        )?);
+    }
+
+    for &(item_id, count) in event.given.iter() {
+        let object_id = items_data.object_id_manager.next_id();
+        let item_info = items_data.item_info(item_id)?;
+        let mut item = Item::new_with_count(item_id, count, ItemLocation::Inventory, item_info);
+        item.set_owner(Some(owner_id));
+
+        let unique_item = UniqueItem::new(object_id, item);
+        unique_item.spawn(&mut commands, item_info);
+        deltas.push(ItemDelta::from(unique_item));
+    }
+
+    commands.trigger(ItemTransaction::new(event.kind, deltas));
+    Ok(())
+}
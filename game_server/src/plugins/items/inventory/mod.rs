@@ -14,12 +14,14 @@ mod added;
 mod destroy;
 mod drop;
 mod equip;
+mod exchange;
 mod unequip;
 
 use added::*;
 pub use destroy::*;
 pub use drop::*;
 pub use equip::*;
+pub use exchange::*;
 pub use unequip::*;
 
 pub struct InventoryPlugin;
@@ -27,7 +29,7 @@ impl Plugin for InventoryPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(InventoryComponentsPlugin);
 
-        app.add_observer(destroy_item);
+        app.add_observer(destroy_item).add_observer(exchange_items);
 
         app.add_plugins(AddInInventoryPlugin)
             .add_plugins(DropItemPlugin)
@@ -59,22 +61,39 @@ async fn load_inventory_from_db() -> Result<(), AccessError> {
             .await?;
 
         AsyncWorld.apply_command(move |world: &mut World| {
-            world.trigger_targets(
-                SpawnExisting {
-                    item_models,
-                    dropped_entity: None,
-                    silent: true, // Don't show system messages during initial inventory loading
-                },
-                entity,
-            );
-            world.commands().spawn_task(move || async move {
-                crate::plugins::shortcuts::shortcut_init_task(entity).await
-            });
+            spawn_inventory(world, entity, item_models);
         });
     }
 
     Ok(())
 }
 
+/// Spawns the loaded items of the character and initializes the rest of its belongings.
+pub(crate) fn spawn_inventory(world: &mut World, entity: Entity, item_models: Vec<model::Model>) {
+    world.trigger_targets(
+        SpawnExisting {
+            item_models,
+            dropped_entity: None,
+            silent: true, // Don't show system messages during initial inventory loading
+        },
+        entity,
+    );
+    world.commands().spawn_task(move || async move {
+        crate::plugins::shortcuts::shortcut_init_task(entity).await
+    });
+    world.commands().spawn_task(move || async move {
+        crate::plugins::macros::macro_list_init_task(entity).await
+    });
+    world
+        .commands()
+        .spawn_task(move || async move { crate::plugins::henna::henna_init_task(entity).await });
+    world.commands().spawn_task(move || async move {
+        crate::plugins::recipe::recipe_book_init_task(entity).await
+    });
+    world.commands().spawn_task(move || async move {
+        crate::plugins::friend::friend_list_init_task(entity).await
+    });
+}
+
 #[cfg(test)]
 mod tests;
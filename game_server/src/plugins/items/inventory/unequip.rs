@@ -1,11 +1,10 @@
 use bevy::prelude::*;
-use bevy_defer::AsyncCommandsExtension;
 use game_core::{
     active_action::ActiveAction,
     items::{
-        self, DollSlot, ItemLocationVariant, ItemUnequipped, ItemUnequippedMessage,
-        ItemsDataAccess, ItemsDataQuery, ItemsDataQueryMut, PaperDoll, UnequipItem, UniqueItem,
-        UpdateType,
+        DollSlot, ItemUnequipped, ItemUnequippedMessage, ItemsDataAccess, ItemsDataQuery,
+        ItemsDataQueryMut, PaperDoll, UnequipItem, UniqueItem, UpdateType,
+        journal::{ItemDelta, ItemTransaction, ItemTransactionKind},
     },
     network::packets::server::{
         BroadcastCharInfo, GameServerPacket, InventoryUpdate, SendUserInfo, SystemMessage,
@@ -13,8 +12,6 @@ use game_core::{
     object_id::ObjectId,
     stats::{AttackEffects, StatModifiers, Weapon},
 };
-use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
-use sea_orm::{ActiveValue, IntoActiveModel};
 use smallvec::smallvec;
 use system_messages::{Id as SmId, SmParam};
 
@@ -34,7 +31,6 @@ pub fn process_unequip(
     items_data: &mut ItemsDataQueryMut,
     mut paperdolls: Query<Mut<PaperDoll>>,
     skip_db_update: bool,
-    repo_manager: &RepositoryManager,
 ) -> Result<()> {
     let mut paperdoll = paperdolls.get_mut(entity)?;
 
@@ -59,18 +55,19 @@ pub fn process_unequip(
         )));
     };
 
-    // Check if we need to also unequip ammo from left hand
+    // Check if we need to also unequip ammo or lure from left hand
     let ammo_to_unequip = items_data
         .item_info(item_id)
         .ok()
-        .filter(|template| template.kind().bow_or_crossbow())
+        .filter(|template| template.kind().bow_or_crossbow() || template.kind().fishing_rod())
         .and_then(|weapon_info| {
             paperdoll[DollSlot::LeftHand].and_then(|oid| {
                 items_data
                     .info_by_object_id(oid)
                     .ok()
                     .filter(|ammo_info| {
-                        ammo_info.kind().ammo() && weapon_info.ammo_matches(ammo_info)
+                        (ammo_info.kind().ammo() || ammo_info.kind().lure())
+                            && weapon_info.ammo_matches(ammo_info)
                     })
                     .map(|_| oid)
             })
@@ -92,16 +89,11 @@ pub fn process_unequip(
     let inventory_update = InventoryUpdate::new(smallvec![unique_item], UpdateType::Modify);
     commands.trigger_targets(GameServerPacket::from(inventory_update), entity);
 
-    // Update database
-    if !skip_db_update && !repo_manager.is_mock() {
-        let items_repository = repo_manager.typed::<ObjectId, items::model::Entity>()?;
-        let item_model = items::model::Model::from(unique_item);
-        commands.spawn_task(move || async move {
-            let mut item_active = item_model.into_active_model();
-            item_active.location = ActiveValue::Set(ItemLocationVariant::Inventory);
-            items_repository.update(&item_active).await?;
-            Ok(())
-        });
+    if !skip_db_update {
+        commands.trigger(ItemTransaction::new(
+            ItemTransactionKind::Unequip,
+            vec![ItemDelta::from(unique_item)],
+        ));
     }
 
     Ok(())
@@ -112,7 +104,6 @@ fn handle_unequip_item(
     mut commands: Commands,
     mut paperdolls: Query<Mut<PaperDoll>, Without<ActiveAction>>,
     mut items_data: ItemsDataQueryMut,
-    repo_manager: Res<RepositoryManager>,
 ) -> Result<()> {
     let character_entity = trigger.target();
     let item_object_id = trigger.event().item_object_id;
@@ -128,7 +119,6 @@ fn handle_unequip_item(
         &mut items_data,
         doll_query,
         skip_db_update,
-        repo_manager.as_ref(),
     )
 }
 
@@ -0,0 +1,160 @@
+use bevy::{log, prelude::*};
+use bevy_defer::{AsyncAccess, AsyncCommandsExtension, AsyncWorld};
+use chrono::{TimeDelta, Utc};
+use game_core::items::journal::{self, AppliedEntries, ItemJournalReplayed, ItemTransaction};
+use l2r_core::db::DbConnection;
+use state::{GameServerStateSystems, LoadingSystems};
+
+/// Entries applied longer ago than this are deleted, no entry written before them is still pending
+const KEEP_APPLIED: TimeDelta = TimeDelta::minutes(10);
+
+/// Inventory mutations are written to the journal before anything is stored,
+/// the ones of a tick are written together off the main thread, in the order they were made.
+/// Written entries are applied to the items table one by one in the order they were written,
+/// so a transaction never lands before the one it depends on.
+/// Entries the previous run didn't apply are replayed before object ids are loaded.
+pub(crate) struct ItemJournalPlugin;
+impl Plugin for ItemJournalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ItemJournal>();
+
+        app.add_observer(queue_transaction);
+
+        app.add_systems(
+            Update,
+            replay_journal.in_set(LoadingSystems::RepositoryInit),
+        )
+        // Written in every state, transactions made right before the shutdown are kept too
+        .add_systems(Update, write_journal)
+        .add_systems(Update, apply_journal.in_set(GameServerStateSystems::Run));
+    }
+}
+
+#[derive(Default, Resource)]
+pub(super) struct ItemJournal {
+    queued: Vec<ItemTransaction>,
+    writing: bool,
+    pending: bool,
+    applying: bool,
+}
+
+impl ItemJournal {
+    /// Entries written off the main thread are applied once this is called.
+    pub(super) fn written(&mut self) {
+        self.pending = true;
+    }
+}
+
+fn queue_transaction(
+    transaction: Trigger<ItemTransaction>,
+    mut journal: ResMut<ItemJournal>,
+    db_connection: Res<DbConnection>,
+) {
+    if db_connection.is_mock() || transaction.deltas.is_empty() {
+        return;
+    }
+    journal.queued.push(transaction.event().clone());
+}
+
+/// A batch that couldn't be written goes back in front of the queue and is retried,
+/// the next batch waits for it so the entries keep their order.
+fn write_journal(
+    mut commands: Commands,
+    mut journal: ResMut<ItemJournal>,
+    db_connection: Res<DbConnection>,
+) {
+    if journal.writing || journal.queued.is_empty() {
+        return;
+    }
+    journal.writing = true;
+    let batch = std::mem::take(&mut journal.queued);
+    let conn = db_connection.connection();
+    commands.spawn_task(move || async move {
+        let result = journal::write_batch(&conn, batch.clone()).await;
+        AsyncWorld
+            .resource::<ItemJournal>()
+            .get_mut(move |journal| {
+                journal.writing = false;
+                match result {
+                    Ok(()) => journal.written(),
+                    Err(err) => {
+                        log::error!(
+                            "{} item transaction(s) couldn't be journaled, retrying: {:?}",
+                            batch.len(),
+                            err
+                        );
+                        journal.queued.splice(0..0, batch);
+                    }
+                }
+            })?;
+        Ok(())
+    });
+}
+
+fn apply_journal(
+    mut commands: Commands,
+    mut journal: ResMut<ItemJournal>,
+    db_connection: Res<DbConnection>,
+) {
+    if journal.applying || !journal.pending {
+        return;
+    }
+    journal.applying = true;
+    journal.pending = false;
+    let conn = db_connection.connection();
+    commands.spawn_task(move || async move {
+        match journal::apply_pending(&conn).await {
+            Ok(_) => {
+                let applied_before = Utc::now().naive_utc() - KEEP_APPLIED;
+                if let Err(err) = journal::compact(&conn, applied_before).await {
+                    log::warn!("Failed to delete applied item transactions: {:?}", err);
+                }
+            }
+            Err(err) => log::error!("Failed to apply item transactions: {:?}", err),
+        }
+        AsyncWorld.resource::<ItemJournal>().get_mut(|journal| {
+            journal.applying = false;
+        })?;
+        Ok(())
+    });
+}
+
+fn replay_journal(
+    mut commands: Commands,
+    db_connection: Res<DbConnection>,
+    mut started: Local<bool>,
+) {
+    if *started {
+        return;
+    }
+    *started = true;
+
+    if db_connection.is_mock() {
+        commands.init_resource::<ItemJournalReplayed>();
+        return;
+    }
+
+    let conn = db_connection.connection();
+    commands.spawn_task(move || async move {
+        match journal::apply_pending(&conn).await {
+            Ok(AppliedEntries { applied, failed }) => {
+                if applied + failed > 0 {
+                    log::info!(
+                        "Replayed {} item transactions, {} failed and were set aside.",
+                        applied,
+                        failed
+                    );
+                }
+                // Nothing else writes to the journal yet, everything applied so far can go
+                if let Err(err) = journal::compact(&conn, Utc::now().naive_utc()).await {
+                    log::warn!("Failed to delete applied item transactions: {:?}", err);
+                }
+            }
+            Err(err) => log::error!("Failed to replay item transactions: {:?}", err),
+        }
+        AsyncWorld.apply_command(|world: &mut World| {
+            world.init_resource::<ItemJournalReplayed>();
+        });
+        Ok(())
+    });
+}
@@ -3,16 +3,16 @@ use bevy_common_assets::json::JsonAssetPlugin;
 use bevy_defer::{AccessError, AppReactorExtension, AsyncAccess, AsyncExtension, AsyncWorld};
 use game_core::{
     items::{
-        self, AddInInventory, Inventory, Item, ItemInWorld, ItemLocation, ItemMetric,
+        AddInInventory, Inventory, Item, ItemInWorld, ItemLocation, ItemMetric,
         ItemsComponentsPlugin, ItemsDataAccess, ItemsDataQuery, ItemsInfo, SilentSpawn,
-        SpawnExisting, SpawnNew, UniqueItem, model,
+        SpawnExisting, SpawnNew, UniqueItem,
+        journal::{ItemDelta, ItemTransaction, ItemTransactionKind},
+        model,
     },
     object_id::{ObjectId, ObjectIdManager, QueryByObjectId},
 };
 use l2r_core::{
-    db::{Repository, RepositoryManager, TypedRepositoryManager},
-    metrics::MetricsAppExt,
-    plugins::custom_hierarchy::DespawnChildOf,
+    db::DbConnection, metrics::MetricsAppExt, plugins::custom_hierarchy::DespawnChildOf,
 };
 use map::WorldMap;
 use state::{GameMechanicsSystems, LoadingSystems};
@@ -22,6 +22,7 @@ mod admin_shop;
 mod assets;
 mod inventory;
 mod item;
+mod journal;
 mod request_destroy_item;
 mod request_drop_item;
 mod request_item_list;
@@ -40,6 +41,7 @@ impl Plugin for ItemsPlugin {
             .add_plugins(request_destroy_item::RequestDestroyItemPlugin)
             .add_plugins(use_item::UseItemPlugin)
             .add_plugins(InventoryPlugin)
+            .add_plugins(journal::ItemJournalPlugin)
             .add_plugins(UseShotPlugin)
             .add_plugins(admin_shop::AdminShopPlugin)
             .add_plugins(JsonAssetPlugin::<ItemsInfo>::new(&["json"]));
@@ -72,43 +74,45 @@ impl Plugin for ItemsPlugin {
 
 async fn spawn_new_items() -> Result<(), AccessError> {
     while let Ok(spawn_event) = AsyncWorld.get_next_event::<SpawnNew>().await {
-        let Ok(items_repository) = AsyncWorld
-            .resource::<RepositoryManager>()
-            .get(|manager| manager.typed::<ObjectId, items::model::Entity>())?
-        else {
-            return Ok(());
-        };
+        let conn = AsyncWorld
+            .resource::<DbConnection>()
+            .get(|db_connection| (!db_connection.is_mock()).then(|| db_connection.connection()))?;
+
+        let owner_id = spawn_event.owner.and_then(|owner| {
+            AsyncWorld
+                .entity(owner)
+                .component::<ObjectId>()
+                .get(|id| *id)
+                .ok()
+        });
 
         let mut created_items = Vec::with_capacity(spawn_event.item_ids.len());
-
         for &item_id in spawn_event.item_ids.iter() {
             let object_id = AsyncWorld
                 .resource::<ObjectIdManager>()
                 .get_mut(|object_id_manager| object_id_manager.next_id())?;
 
-            let owner_id = spawn_event.owner.and_then(|owner| {
-                AsyncWorld
-                    .entity(owner)
-                    .component::<ObjectId>()
-                    .get(|id| *id)
-                    .ok()
-            });
-
-            let new_item = model::Model::new(
+            created_items.push(model::Model::new(
                 object_id,
                 item_id,
                 spawn_event.count,
                 spawn_event.item_location,
                 owner_id,
-            );
+            ));
+        }
 
-            let result = items_repository.create(&new_item).await;
-            match result {
-                Ok(created_item) => created_items.push(created_item),
-                Err(e) => {
-                    error!("{}: Failed to add Item to DB: {:?}", stringify!(Self), e);
-                }
+        if let Some(conn) = conn {
+            let transaction = ItemTransaction::new(
+                ItemTransactionKind::Create,
+                created_items.iter().map(ItemDelta::from).collect(),
+            );
+            if let Err(e) = transaction.write(&conn).await {
+                error!("{}: Failed to add Items to DB: {:?}", stringify!(Self), e);
+                continue;
             }
+            AsyncWorld
+                .resource::<journal::ItemJournal>()
+                .get_mut(|journal| journal.written())?;
         }
 
         if !created_items.is_empty() {
@@ -4,12 +4,14 @@ use bevy_slinet::server::PacketReceiveEvent;
 use game_core::{
     items::{
         ConsumableKind, EquipItem, InventoriesQuery, InventoriesQueryItem, ItemsDataAccess,
-        ItemsDataQuery, Kind, UnequipItem, UseShot,
+        ItemsDataQuery, Kind, ManorItemKind, UnequipItem, UseShot,
     },
+    manor::{UseHarvester, UseSeed},
     network::{
         config::GameServerNetworkConfig, packets::client::GameClientPacket,
         session::PacketReceiveParams,
     },
+    recipe::UseRecipe,
 };
 
 pub struct UseItemPlugin;
@@ -57,6 +59,35 @@ fn handle(
             return Ok(());
         }
 
+        if let Kind::Recipe(_) = item_info.kind() {
+            commands.trigger_targets(
+                UseRecipe {
+                    item_object_id,
+                    item_id: item.id(),
+                },
+                character_entity,
+            );
+            return Ok(());
+        }
+
+        match item_info.kind() {
+            Kind::Manor(ManorItemKind::Seed | ManorItemKind::AltSeed) => {
+                commands.trigger_targets(
+                    UseSeed {
+                        item_object_id,
+                        item_id: item.id(),
+                    },
+                    character_entity,
+                );
+                return Ok(());
+            }
+            Kind::Manor(ManorItemKind::Harvest) => {
+                commands.trigger_targets(UseHarvester, character_entity);
+                return Ok(());
+            }
+            _ => {}
+        }
+
         if item_info.bodypart().is_some() {
             if item.equipped() {
                 commands.trigger_targets(
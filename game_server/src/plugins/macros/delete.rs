@@ -0,0 +1,95 @@
+use super::macro_list;
+use bevy::{log, prelude::*};
+use bevy_defer::AsyncCommandsExtension;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    macros::{self, Macros},
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{GameServerPacket, ShortcutInit},
+        },
+        session::PacketReceiveParams,
+    },
+    object_id::ObjectId,
+    shortcut::{self, ShortcutKind, ShortcutKindVariant, Shortcuts},
+};
+use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
+use sea_orm::{ColumnTrait, QueryFilter};
+
+pub(crate) struct RequestDeleteMacroPlugin;
+impl Plugin for RequestDeleteMacroPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    repo_manager: Res<RepositoryManager>,
+    mut characters: Query<(Ref<ObjectId>, Option<Ref<Macros>>, Option<Mut<Shortcuts>>)>,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestDeleteMacro(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let (char_id, macros, shortcuts) = characters.get_mut(entity)?;
+    let char_id = *char_id;
+    let mut macros = macros.as_deref().cloned().unwrap_or_default();
+
+    let macro_id = packet.0;
+    if macros.remove(macro_id).is_none() {
+        return Ok(());
+    }
+
+    commands.trigger_targets(macro_list(&macros), entity);
+    commands.entity(entity).insert(macros);
+
+    if let Some(mut shortcuts) = shortcuts {
+        shortcuts.retain(|shortcut| shortcut.kind != ShortcutKind::Macro(macro_id));
+        commands.trigger_targets(
+            GameServerPacket::from(ShortcutInit::from(shortcuts.as_ref())),
+            entity,
+        );
+    }
+
+    let macro_repository = repo_manager.typed::<macros::model::MacroPK, macros::model::Entity>()?;
+    let shortcut_repository =
+        repo_manager.typed::<shortcut::model::ShortcutPK, shortcut::model::Entity>()?;
+
+    commands.spawn_task(move || async move {
+        let result = macro_repository
+            .delete_by_id(macros::model::MacroPK { char_id, macro_id })
+            .await;
+        if let Err(err) = result {
+            log::error!("Character: {}, Failed to delete macro: {:?}", char_id, err);
+            return Err(err.into());
+        }
+
+        // Shortcuts of the deleted macro are removed on every sub class,
+        // the ones of the current sub class are already gone from the world
+        let result = shortcut_repository
+            .delete_many(|query| {
+                query
+                    .filter(shortcut::model::Column::CharId.eq(char_id))
+                    .filter(shortcut::model::Column::Kind.eq(ShortcutKindVariant::Macro))
+                    .filter(shortcut::model::Column::ShortcutId.eq(u32::from(macro_id) as i32))
+            })
+            .await;
+        if let Err(err) = result {
+            log::error!(
+                "Character: {}, Failed to delete macro shortcuts: {:?}",
+                char_id,
+                err
+            );
+            return Err(err.into());
+        }
+        Ok(())
+    });
+    Ok(())
+}
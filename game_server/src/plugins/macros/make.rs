@@ -0,0 +1,87 @@
+use super::macro_list;
+use bevy::{log, prelude::*};
+use bevy_defer::AsyncCommandsExtension;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    macros::{self, MacroError, Macros},
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{GameServerPacket, SystemMessage},
+        },
+        session::PacketReceiveParams,
+    },
+    object_id::ObjectId,
+};
+use l2r_core::db::{
+    PrimaryKeyColumns, Repository, RepositoryManager, TypedRepositoryManager, UpdatableModel,
+};
+use sea_orm::sea_query::OnConflict;
+use system_messages::Id;
+
+pub(crate) struct RequestMakeMacroPlugin;
+impl Plugin for RequestMakeMacroPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    repo_manager: Res<RepositoryManager>,
+    characters: Query<(Ref<ObjectId>, Option<Ref<Macros>>)>,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestMakeMacro(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let (char_id, macros) = characters.get(entity)?;
+    let char_id = *char_id;
+    let mut macros = macros.as_deref().cloned().unwrap_or_default();
+
+    let macro_ = match macros.register(packet.0.clone()) {
+        Ok(macro_) => macro_.clone(),
+        Err(err) => {
+            let message_id = match err {
+                MacroError::EmptyName => Id::EnterTheNameOfTheMacro,
+                MacroError::DescriptionTooLong => Id::MacroDescriptionsMayContainUpTo32Characters,
+                MacroError::InvalidCommands => Id::InvalidMacroReferToTheHelpFileForInstructions,
+                MacroError::NameIsTaken => Id::ThatNameIsAlreadyAssignedToAnotherMacro,
+                MacroError::LimitReached => Id::YouMayCreateUpTo48Macros,
+            };
+            commands.trigger_targets(
+                GameServerPacket::from(SystemMessage::new_empty(message_id)),
+                entity,
+            );
+            return Ok(());
+        }
+    };
+
+    commands.trigger_targets(macro_list(&macros), entity);
+    commands.entity(entity).insert(macros);
+
+    let macro_repository = repo_manager.typed::<macros::model::MacroPK, macros::model::Entity>()?;
+    let macro_model = macro_.into_model(char_id);
+
+    commands.spawn_task(move || async move {
+        let result = macro_repository
+            .create_or_update(
+                &macro_model,
+                OnConflict::columns(macros::model::Model::pk_columns().to_vec())
+                    .update_columns(macros::model::Model::update_columns().to_vec())
+                    .to_owned(),
+            )
+            .await;
+        if let Err(err) = result {
+            log::error!("Character: {}, Error saving macro: {:?}", char_id, err);
+            return Err(err.into());
+        }
+        Ok(())
+    });
+    Ok(())
+}
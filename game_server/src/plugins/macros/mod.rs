@@ -0,0 +1,77 @@
+use bevy::{log, prelude::*};
+use bevy_defer::{AccessError, AsyncAccess, AsyncWorld};
+use game_core::{
+    macros::{self, MacroComponentsPlugin, Macros},
+    network::packets::server::{GameServerPacket, GameServerPackets, MacroList},
+    object_id::ObjectId,
+};
+use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
+use sea_orm::ColumnTrait;
+
+mod delete;
+mod make;
+
+/// Macros are created in the macro window and stored per character,
+/// they can be linked to shortcuts by their id.
+/// uses [`MacroList`] server packet
+/// alongside with clients: [`RequestMakeMacro`], [`RequestDeleteMacro`]
+pub(crate) struct MacroPlugin;
+impl Plugin for MacroPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(MacroComponentsPlugin)
+            .add_plugins(make::RequestMakeMacroPlugin)
+            .add_plugins(delete::RequestDeleteMacroPlugin);
+    }
+}
+
+pub(crate) fn macro_list(macros: &Macros) -> GameServerPackets {
+    let revision = macros.revision();
+    let count = macros.macros().len() as u8;
+    if count == 0 {
+        return GameServerPackets::from(vec![MacroList::new(revision, 0, None).into()]);
+    }
+    GameServerPackets::from(
+        macros
+            .macros()
+            .iter()
+            .map(|macro_| MacroList::new(revision, count, Some(macro_.clone())).into())
+            .collect::<Vec<GameServerPacket>>(),
+    )
+}
+
+pub async fn macro_list_init_task(char_entity: Entity) -> Result<(), AccessError> {
+    let Ok(macro_repository) = AsyncWorld
+        .resource::<RepositoryManager>()
+        .get(|registry| registry.typed::<macros::model::MacroPK, macros::model::Entity>())?
+    else {
+        return Ok(());
+    };
+
+    let char_id = AsyncWorld
+        .entity(char_entity)
+        .component::<ObjectId>()
+        .get(|component| *component)?;
+
+    let result = macro_repository
+        .find_with_conditions([macros::model::Column::CharId.eq(char_id)])
+        .await;
+
+    if let Err(err) = result {
+        log::error!("Character: {}, Error loading macros: {:?}", char_id, err);
+        return Err(err.into());
+    }
+
+    let mut models = result.unwrap_or_default();
+    models.sort_by_key(|model| model.macro_id);
+    let macros = Macros::new(models.into_iter().map(macros::Macro::from).collect());
+    let macro_list = macro_list(&macros);
+
+    AsyncWorld.apply_command(move |world: &mut World| {
+        if let Ok(mut character) = world.get_entity_mut(char_entity) {
+            character.insert(macros);
+        }
+        world.trigger_targets(macro_list, char_entity);
+    });
+
+    Ok(())
+}
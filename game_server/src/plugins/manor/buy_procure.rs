@@ -0,0 +1,115 @@
+use super::ManorQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    items::{ADENA_ID, DestroyItemRequest, ItemLocation, SpawnNew},
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{ActionFail, GameServerPacket, SystemMessage},
+        },
+        session::PacketReceiveParams,
+    },
+};
+use system_messages::{Id as SmId, SmParam};
+
+pub(crate) struct RequestBuyProcurePlugin;
+impl Plugin for RequestBuyProcurePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+/// Crops are put into the current procure and paid out in adena,
+/// the treasury has already paid for them when the period was approved.
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut commands: Commands,
+    mut manor_query: ManorQuery,
+    mut items_spawn: EventWriter<SpawnNew>,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestBuyProcure(ref packet) = event.packet else {
+        return Ok(());
+    };
+    let entity = receive_params.character(&event.connection.id())?;
+
+    let fail = |commands: &mut Commands, sm_id: Option<SmId>| {
+        if let Some(sm_id) = sm_id {
+            commands.trigger_targets(
+                GameServerPacket::from(SystemMessage::new_empty(sm_id)),
+                entity,
+            );
+        }
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+    };
+
+    if manor_query.manor().under_maintenance() {
+        fail(
+            &mut commands,
+            Some(SmId::TheManorSystemIsCurrentlyUnderMaintenance),
+        );
+        return Ok(());
+    }
+    let Some(castle_id) = manor_query.visited_manor(entity, packet.manor_id)? else {
+        fail(&mut commands, None);
+        return Ok(());
+    };
+    if packet.items.is_empty() {
+        return Ok(());
+    }
+
+    let mut castle_manor = manor_query.castle_manor(castle_id);
+    let mut crops = Vec::with_capacity(packet.items.len());
+    let mut total_price: u64 = 0;
+    for (crop_id, count) in packet.items.iter() {
+        let Some((crop_oid, _)) = manor_query
+            .inventory_item(entity, *crop_id)
+            .filter(|(_, available)| available >= count)
+        else {
+            fail(&mut commands, Some(SmId::TheNumberOfCropsIsIncorrect));
+            return Ok(());
+        };
+        let price = castle_manor
+            .sell_crops(*crop_id, *count)
+            .ok()
+            .and_then(|price| total_price.checked_add(price));
+        let Some(price) = price else {
+            fail(&mut commands, Some(SmId::NoCropsCanBePurchasedAtThisTime));
+            return Ok(());
+        };
+        total_price = price;
+        crops.push((crop_oid, *crop_id, *count));
+    }
+
+    for (crop_oid, crop_id, count) in crops {
+        commands.trigger_targets(
+            DestroyItemRequest {
+                item_oid: crop_oid,
+                count,
+            },
+            entity,
+        );
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new(
+                SmId::TradedS2OfS1Crops,
+                vec![SmParam::Item(*crop_id), SmParam::LongNumber(count)],
+            )),
+            entity,
+        );
+    }
+    if total_price > 0 {
+        items_spawn.write(SpawnNew {
+            item_ids: vec![ADENA_ID],
+            count: total_price,
+            item_location: ItemLocation::Inventory,
+            dropped_entity: None,
+            owner: Some(entity),
+            silent: false,
+        });
+    }
+
+    manor_query.set_castle_manor(&mut commands, castle_id, castle_manor)
+}
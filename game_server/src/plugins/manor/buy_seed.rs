@@ -0,0 +1,110 @@
+use super::ManorQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    items::{ADENA_ID, DestroyItemRequest, ItemLocation, SpawnNew},
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{ActionFail, GameServerPacket, SystemMessage},
+        },
+        session::PacketReceiveParams,
+    },
+};
+use system_messages::Id as SmId;
+
+pub(crate) struct RequestBuySeedPlugin;
+impl Plugin for RequestBuySeedPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+/// Seeds are taken from the current production, adena paid for them goes to the castle treasury.
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut commands: Commands,
+    mut manor_query: ManorQuery,
+    mut items_spawn: EventWriter<SpawnNew>,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestBuySeed(ref packet) = event.packet else {
+        return Ok(());
+    };
+    let entity = receive_params.character(&event.connection.id())?;
+
+    let fail = |commands: &mut Commands, sm_id: Option<SmId>| {
+        if let Some(sm_id) = sm_id {
+            commands.trigger_targets(
+                GameServerPacket::from(SystemMessage::new_empty(sm_id)),
+                entity,
+            );
+        }
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+    };
+
+    if manor_query.manor().under_maintenance() {
+        fail(
+            &mut commands,
+            Some(SmId::TheManorSystemIsCurrentlyUnderMaintenance),
+        );
+        return Ok(());
+    }
+    let Some(castle_id) = manor_query.visited_manor(entity, packet.manor_id)? else {
+        fail(&mut commands, None);
+        return Ok(());
+    };
+    if packet.items.is_empty() {
+        return Ok(());
+    }
+
+    let mut castle_manor = manor_query.castle_manor(castle_id);
+    let mut total_price: u64 = 0;
+    for (seed_id, count) in packet.items.iter() {
+        let price = castle_manor
+            .buy_seeds(*seed_id, *count)
+            .ok()
+            .and_then(|price| total_price.checked_add(price));
+        let Some(price) = price else {
+            fail(
+                &mut commands,
+                Some(SmId::YourSeedOrRemainingPurchaseAmountIsInadequate),
+            );
+            return Ok(());
+        };
+        total_price = price;
+    }
+
+    let Some((adena_oid, _)) = manor_query
+        .inventory_item(entity, ADENA_ID)
+        .filter(|(_, adena)| *adena >= total_price)
+    else {
+        fail(&mut commands, Some(SmId::YouDoNotHaveEnoughAdena));
+        return Ok(());
+    };
+
+    commands.trigger_targets(
+        DestroyItemRequest {
+            item_oid: adena_oid,
+            count: total_price,
+        },
+        entity,
+    );
+    for (seed_id, count) in packet.items.iter() {
+        items_spawn.write(SpawnNew {
+            item_ids: vec![*seed_id],
+            count: *count,
+            item_location: ItemLocation::Inventory,
+            dropped_entity: None,
+            owner: Some(entity),
+            silent: false,
+        });
+    }
+
+    manor_query
+        .castle_query
+        .deposit(&mut commands, castle_id, total_price)?;
+    manor_query.set_castle_manor(&mut commands, castle_id, castle_manor)
+}
@@ -0,0 +1,81 @@
+use bevy::{log, prelude::*};
+use game_core::manor::{Seeds, SeedsHandle};
+use l2r_core::chronicles::CHRONICLE;
+use state::{GameServerStateSystems, LoadingSystems};
+use std::path::PathBuf;
+
+pub struct SeedsDataPlugin;
+impl Plugin for SeedsDataPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SeedsHandle>();
+
+        app.add_systems(
+            Update,
+            (
+                load_assets.in_set(LoadingSystems::AssetInit),
+                update_assets.in_set(LoadingSystems::AssetInit),
+            ),
+        );
+
+        app.add_systems(Update, update_assets.in_set(GameServerStateSystems::Run));
+    }
+}
+
+fn load_assets(
+    asset_server: Res<AssetServer>,
+    mut seeds_handle: ResMut<SeedsHandle>,
+    mut loaded: Local<bool>,
+) {
+    if *loaded {
+        return;
+    }
+    let mut path = PathBuf::from("manor");
+    path.push(CHRONICLE);
+    path.push("seeds");
+    path.set_extension("json");
+
+    let handle: Handle<Seeds> = asset_server.load(path.clone());
+    **seeds_handle = handle;
+    *loaded = true;
+}
+
+fn update_assets(handle: Res<SeedsHandle>, mut events: EventReader<AssetEvent<Seeds>>) {
+    for event in events.read() {
+        if event.is_loaded_with_dependencies(handle.id()) {
+            log::info!("Manor seeds updated");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_core::clan::castle::CastleId;
+    use l2r_core::{assets::ASSET_DIR, chronicles::CHRONICLE, utils::get_base_path};
+    use sea_orm::Iterable;
+    use serde_json::from_reader;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_parse_all_from_json() {
+        let mut path = get_base_path();
+        path.push(ASSET_DIR);
+        path.push("manor");
+        path.push(CHRONICLE);
+        path.push("seeds");
+        path.set_extension("json");
+
+        let file = File::open(&path).unwrap_or_else(|_| panic!("Failed to open file: {:?}", path));
+        let reader = BufReader::new(file);
+
+        let result: Seeds = from_reader(reader)
+            .unwrap_or_else(|_| panic!("Failed to parse seeds from JSON: {:?}", path));
+
+        for castle_id in CastleId::iter() {
+            assert!(
+                result.of_castle(castle_id).next().is_some(),
+                "No seeds for {castle_id}"
+            );
+        }
+    }
+}
@@ -1,10 +1,246 @@
-use bevy::prelude::*;
+use crate::plugins::castle::CastleQuery;
+use bevy::{
+    ecs::system::SystemParam, log, platform::collections::HashMap, prelude::*,
+    time::common_conditions::on_timer,
+};
+use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use chrono::Utc;
+use game_core::{
+    character::Character,
+    clan::castle::CastleId,
+    items::{Inventory, Item},
+    manor::{
+        self, CastleManor, Manor, ManorComponentsPlugin, ManorMode, Seeds, SeedsHandle,
+        VisitedManor, model::ManorPK,
+    },
+    object_id::{ObjectId, ObjectIdManager},
+};
+use l2r_core::db::{DbConnection, Repository, RepositoryManager, TypedRepositoryManager};
+use sea_orm::{ColumnTrait, Condition, Iterable, QueryFilter};
+use spatial::FlatDistance;
+use state::{GameServerStateSystems, LoadingSystems};
+use std::time::Duration;
 
+mod buy_procure;
+mod buy_seed;
+mod data;
 mod request_manor_list;
+mod settings;
+mod sowing;
 
+pub(crate) const MANOR_NPC_DISTANCE: f32 = 150.0;
+
+/// Manor of each castle sells seeds and procures crops by the settings of the castle lord.
+/// Settings of the next period are approved and paid from the treasury at 4:30,
+/// at 20:00 the manor closes for maintenance and the next period becomes the current one.
 pub(crate) struct ManorPlugin;
 impl Plugin for ManorPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(request_manor_list::RequestManorListPlugin);
+        app.add_plugins(ManorComponentsPlugin)
+            .add_plugins(data::SeedsDataPlugin)
+            .add_plugins(request_manor_list::RequestManorListPlugin)
+            .add_plugins(buy_seed::RequestBuySeedPlugin)
+            .add_plugins(buy_procure::RequestBuyProcurePlugin)
+            .add_plugins(settings::ManorSettingsPlugin)
+            .add_plugins(sowing::SowingPlugin);
+
+        app.add_systems(Update, load_manor.in_set(LoadingSystems::IdInit));
+
+        app.add_systems(
+            Update,
+            update_manor_mode
+                .run_if(on_timer(Duration::from_secs(1)))
+                .in_set(GameServerStateSystems::Run),
+        );
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct ManorQuery<'w, 's> {
+    manor: ResMut<'w, Manor>,
+    seeds_handle: Res<'w, SeedsHandle>,
+    seeds_assets: Res<'w, Assets<Seeds>>,
+    repo_manager: Res<'w, RepositoryManager>,
+    object_id_manager: Res<'w, ObjectIdManager>,
+    inventories: Query<'w, 's, Ref<'static, Inventory>, With<Character>>,
+    items: Query<'w, 's, Ref<'static, Item>>,
+    transforms: Query<'w, 's, Ref<'static, Transform>>,
+    visited: Query<'w, 's, Ref<'static, VisitedManor>>,
+    pub castle_query: CastleQuery<'w, 's>,
+}
+
+impl ManorQuery<'_, '_> {
+    pub fn seeds(&self) -> Result<&Seeds> {
+        self.seeds_assets
+            .get(self.seeds_handle.id())
+            .ok_or_else(|| BevyError::from("Manor seeds are not loaded"))
+    }
+
+    pub fn manor(&self) -> &Manor {
+        &self.manor
+    }
+
+    pub fn castle_manor(&self, castle_id: CastleId) -> CastleManor {
+        self.manor.get(&castle_id).cloned().unwrap_or_default()
+    }
+
+    /// Replaces the manor of the castle and saves it.
+    pub fn set_castle_manor(
+        &mut self,
+        commands: &mut Commands,
+        castle_id: CastleId,
+        castle_manor: CastleManor,
+    ) -> Result<()> {
+        self.manor.insert(castle_id, castle_manor);
+        self.save(commands, castle_id)
+    }
+
+    /// Manor of the manager the character talked to, if the character still stands close to it.
+    pub fn visited_manor(&self, entity: Entity, manor_id: u32) -> Result<Option<CastleId>> {
+        let Ok(visited) = self.visited.get(entity) else {
+            return Ok(None);
+        };
+        if u32::from(u8::from(visited.castle_id)) != manor_id {
+            return Ok(None);
+        }
+        let npc_transform = self.transforms.get(visited.npc)?;
+        let transform = self.transforms.get(entity)?;
+        let near = npc_transform
+            .translation
+            .flat_distance(&transform.translation)
+            <= MANOR_NPC_DISTANCE;
+        Ok(near.then_some(visited.castle_id))
+    }
+
+    /// Object id and count of the item stack in character inventory.
+    pub fn inventory_item(
+        &self,
+        entity: Entity,
+        item_id: game_core::items::Id,
+    ) -> Option<(ObjectId, u64)> {
+        let inventory = self.inventories.get(entity).ok()?;
+        let (item_entity, object_id, _) =
+            inventory.single_by_item_id(item_id, &self.items, self.object_id_manager.as_ref())?;
+        let item = self.items.get(item_entity).ok()?;
+        Some((object_id, item.count()))
+    }
+
+    /// Adena for the crops nobody sold goes back to the treasury, the next period becomes the current one.
+    fn start_maintenance(&mut self, commands: &mut Commands) -> Result<()> {
+        for castle_id in CastleId::iter() {
+            let mut castle_manor = self.castle_manor(castle_id);
+            if self.castle_query.castle(castle_id)?.owner_clan_id.is_some() {
+                self.castle_query
+                    .deposit(commands, castle_id, castle_manor.procure_refund())?;
+            } else {
+                castle_manor.reset_next();
+            }
+            castle_manor.rollover();
+            self.set_castle_manor(commands, castle_id, castle_manor)?;
+        }
+        log::info!("Manor period is over, the next one starts after the maintenance");
+        Ok(())
+    }
+
+    /// Castles pay in advance for the crops of the next period, the settings are dropped if they can not.
+    fn approve_next_period(&mut self, commands: &mut Commands) -> Result<()> {
+        for castle_id in CastleId::iter() {
+            let mut castle_manor = self.castle_manor(castle_id);
+            if self.castle_query.castle(castle_id)?.owner_clan_id.is_none() {
+                castle_manor.reset_next();
+            } else if self
+                .castle_query
+                .withdraw(commands, castle_id, castle_manor.next_procure_cost())?
+                .is_err()
+            {
+                log::info!(
+                    "Treasury of {} can not pay for the crops, manor settings are reset",
+                    castle_id
+                );
+                castle_manor.reset_next();
+            }
+            self.set_castle_manor(commands, castle_id, castle_manor)?;
+        }
+        log::info!("Manor settings of the next period are approved");
+        Ok(())
+    }
+
+    fn save(&self, commands: &mut Commands, castle_id: CastleId) -> Result<()> {
+        let repository = self.repo_manager.typed::<ManorPK, manor::model::Entity>()?;
+        let models = self.castle_manor(castle_id).models(castle_id);
+        commands.spawn_task(move || async move {
+            repository
+                .delete_many(|query| {
+                    query.filter(manor::model::Column::CastleId.eq(i32::from(castle_id)))
+                })
+                .await?;
+            if let Err(err) = repository.create_many(models).await {
+                log::error!("Castle: {}, Error saving manor: {:?}", castle_id, err);
+                return Err(err.into());
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+}
+
+fn load_manor(
+    mut commands: Commands,
+    repo_manager: Res<RepositoryManager>,
+    db_connection: Res<DbConnection>,
+    mut loaded: Local<bool>,
+) -> Result<()> {
+    if *loaded {
+        return Ok(());
+    }
+    *loaded = true;
+
+    let mode = ManorMode::at(Utc::now().time());
+
+    if db_connection.is_mock() {
+        commands.insert_resource(Manor::new(HashMap::new(), mode));
+        return Ok(());
+    }
+
+    let repository = repo_manager.typed::<ManorPK, manor::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        let mut models: HashMap<CastleId, Vec<manor::model::Model>> = HashMap::new();
+        for model in repository.find_with_conditions([Condition::all()]).await? {
+            if let Some(castle_id) = u8::try_from(model.castle_id)
+                .ok()
+                .and_then(|id| CastleId::try_from(id).ok())
+            {
+                models.entry(castle_id).or_default().push(model);
+            }
+        }
+
+        let castles = models
+            .iter()
+            .map(|(castle_id, models)| (*castle_id, CastleManor::from_models(models)))
+            .collect::<HashMap<_, _>>();
+
+        log::info!("Loaded manor settings of {} castles.", castles.len());
+        AsyncWorld.apply_command(move |world: &mut World| {
+            world.insert_resource(Manor::new(castles, mode));
+        });
+        Ok(())
+    });
+    Ok(())
+}
+
+fn update_manor_mode(mut commands: Commands, mut manor_query: ManorQuery) -> Result<()> {
+    let mode = ManorMode::at(Utc::now().time());
+    if manor_query.manor.mode() == mode {
+        return Ok(());
+    }
+    manor_query.manor.set_mode(mode);
+
+    match mode {
+        ManorMode::Maintenance => manor_query.start_maintenance(&mut commands),
+        ManorMode::Approved => manor_query.approve_next_period(&mut commands),
+        ManorMode::Modifiable => {
+            log::info!("Manor settings of the next period can be changed");
+            Ok(())
+        }
     }
 }
@@ -1,7 +1,11 @@
-use bevy::{log, prelude::*};
+use bevy::prelude::*;
 use bevy_slinet::server::PacketReceiveEvent;
 use game_core::network::{
-    config::GameServerNetworkConfig, packets::client::GameClientPacket,
+    config::GameServerNetworkConfig,
+    packets::{
+        client::GameClientPacket,
+        server::{ExSendManorList, GameServerPacket},
+    },
     session::PacketReceiveParams,
 };
 
@@ -15,11 +19,12 @@ impl Plugin for RequestManorListPlugin {
 fn handle(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     receive_params: PacketReceiveParams,
+    mut commands: Commands,
 ) -> Result<()> {
     let event = receive.event();
     if let GameClientPacket::RequestManorList = event.packet {
         let entity = receive_params.character(&event.connection.id())?;
-        log::trace!("[{}]RequestManorListPlugin is not implemented yet!", entity);
+        commands.trigger_targets(GameServerPacket::from(ExSendManorList), entity);
     }
     Ok(())
 }
@@ -0,0 +1,206 @@
+use super::ManorQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    clan::castle::CastleId,
+    items::{self, ItemsDataAccess, ItemsDataQuery},
+    manor::{ManorEntry, ManorError, ManorKind, ManorMode, Seeds, ShowManorSetting, price_range},
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{
+                ActionFail, ExShowCropSetting, ExShowSeedSetting, GameServerPacket, ManorSetting,
+                SystemMessage,
+            },
+        },
+        session::PacketReceiveParams,
+    },
+};
+use std::collections::BTreeMap;
+use system_messages::Id as SmId;
+
+pub(crate) struct ManorSettingsPlugin;
+impl Plugin for ManorSettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(show_setting).add_observer(handle_set);
+    }
+}
+
+/// Seeds of the castle manor, or crops of them when procure settings are shown, ordered by item id.
+fn settings_items(
+    seeds: &Seeds,
+    castle_id: CastleId,
+    kind: ManorKind,
+) -> BTreeMap<items::Id, (u32, u64)> {
+    seeds
+        .of_castle(castle_id)
+        .map(|(seed_id, seed)| match kind {
+            ManorKind::Production => (seed_id, (seed.level, seed.limit_seeds)),
+            ManorKind::Procure => (seed.crop_id, (seed.level, seed.limit_crops)),
+        })
+        .collect()
+}
+
+fn show_setting(
+    show: Trigger<ShowManorSetting>,
+    mut commands: Commands,
+    manor_query: ManorQuery,
+    items_data: ItemsDataQuery,
+) -> Result<()> {
+    let entity = show.target();
+    let ShowManorSetting { castle_id, kind } = *show.event();
+
+    let castle_manor = manor_query.castle_manor(castle_id);
+    let entries = |next_period| match kind {
+        ManorKind::Production => castle_manor.production(next_period),
+        ManorKind::Procure => castle_manor.procure(next_period),
+    };
+    let find = |next_period, item_id| {
+        entries(next_period)
+            .iter()
+            .find(|entry: &&ManorEntry| entry.item_id == item_id)
+            .copied()
+    };
+
+    let mut settings = Vec::new();
+    for (item_id, (level, limit)) in settings_items(manor_query.seeds()?, castle_id, kind) {
+        settings.push(ManorSetting {
+            item_id,
+            level,
+            limit,
+            reference_price: items_data.item_info(item_id)?.price().into(),
+            current: find(false, item_id),
+            next: find(true, item_id),
+        });
+    }
+
+    let packet = match kind {
+        ManorKind::Production => {
+            GameServerPacket::from(ExShowSeedSetting::new(castle_id, settings))
+        }
+        ManorKind::Procure => GameServerPacket::from(ExShowCropSetting::new(castle_id, settings)),
+    };
+    commands.trigger_targets(packet, entity);
+    Ok(())
+}
+
+/// Entries of the next period checked against the limits and the price ranges of the manor.
+fn next_period_entries(
+    seeds: &Seeds,
+    items_data: &ItemsDataQuery,
+    castle_id: CastleId,
+    kind: ManorKind,
+    requested: &[(items::Id, u64, u64)],
+) -> Result<Result<Vec<ManorEntry>, ManorError>> {
+    let (unknown, invalid_amount, invalid_price) = match kind {
+        ManorKind::Production => (
+            ManorError::UnknownSeed,
+            ManorError::InvalidSeedAmount,
+            ManorError::InvalidSeedPrice,
+        ),
+        ManorKind::Procure => (
+            ManorError::UnknownCrop,
+            ManorError::InvalidCropAmount,
+            ManorError::InvalidCropPrice,
+        ),
+    };
+    let items = settings_items(seeds, castle_id, kind);
+
+    let mut entries: Vec<ManorEntry> = Vec::with_capacity(requested.len());
+    for (item_id, amount, price) in requested.iter().copied() {
+        let Some((_, limit)) = items.get(&item_id) else {
+            return Ok(Err(unknown));
+        };
+        if amount == 0 {
+            continue;
+        }
+        if amount > *limit || entries.iter().any(|entry| entry.item_id == item_id) {
+            return Ok(Err(invalid_amount));
+        }
+        let reference_price = u64::from(items_data.item_info(item_id)?.price());
+        if !price_range(reference_price).contains(&price) {
+            return Ok(Err(invalid_price));
+        }
+        entries.push(ManorEntry::new(item_id, amount, price));
+    }
+    Ok(Ok(entries))
+}
+
+fn handle_set(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut commands: Commands,
+    mut manor_query: ManorQuery,
+    items_data: ItemsDataQuery,
+) -> Result<()> {
+    let event = receive.event();
+    let (manor_id, kind, requested) = match event.packet {
+        GameClientPacket::RequestSetSeed(ref packet) => {
+            (packet.manor_id, ManorKind::Production, &packet.entries)
+        }
+        GameClientPacket::RequestSetCrop(ref packet) => {
+            (packet.manor_id, ManorKind::Procure, &packet.entries)
+        }
+        _ => return Ok(()),
+    };
+    let entity = receive_params.character(&event.connection.id())?;
+
+    let Some(castle_id) = u8::try_from(manor_id)
+        .ok()
+        .and_then(|id| CastleId::try_from(id).ok())
+    else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+    if !manor_query.castle_query.is_owner(entity, castle_id)? {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let result = if manor_query.manor().mode() != ManorMode::Modifiable {
+        Err(ManorError::NotModifiable)
+    } else {
+        next_period_entries(
+            manor_query.seeds()?,
+            &items_data,
+            castle_id,
+            kind,
+            requested,
+        )?
+    };
+
+    let entries = match result {
+        Ok(entries) => entries,
+        Err(err) => {
+            let sm_id = match err {
+                ManorError::NotModifiable => SmId::AManorCannotBeSetUpBetween430AmAnd8Pm,
+                ManorError::InvalidSeedAmount => SmId::TheSeedQuantityIsIncorrect,
+                ManorError::InvalidCropAmount => SmId::TheNumberOfCropsIsIncorrect,
+                ManorError::InvalidCropPrice => SmId::TheCropsArePricedIncorrectly,
+                _ => SmId::TheSeedInformationIsIncorrect,
+            };
+            commands.trigger_targets(
+                GameServerPacket::from(SystemMessage::new_empty(sm_id)),
+                entity,
+            );
+            commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+            return Ok(());
+        }
+    };
+
+    let mut castle_manor = manor_query.castle_manor(castle_id);
+    match kind {
+        ManorKind::Production => castle_manor.set_next_production(entries),
+        ManorKind::Procure => castle_manor.set_next_procure(entries),
+    }
+    manor_query.set_castle_manor(&mut commands, castle_id, castle_manor)?;
+
+    commands.trigger_targets(
+        GameServerPacket::from(SystemMessage::new_empty(
+            SmId::TheManorInformationHasBeenUpdated,
+        )),
+        entity,
+    );
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use super::ManorQuery;
+use bevy::prelude::*;
+use game_core::{
+    action::target::SelectedTarget,
+    attack::Dead,
+    items::{DestroyItemRequest, ItemLocation, SpawnNew},
+    manor::{MAX_LEVEL_DIFF, Seeded, UseHarvester, UseSeed, success_chance},
+    network::packets::server::{ActionFail, GameServerPacket, SystemMessage},
+    npc::kind::Monster,
+    object_id::ObjectId,
+    stats::ProgressLevelStats,
+};
+use rand::Rng;
+use system_messages::{Id as SmId, SmParam};
+
+pub(crate) struct SowingPlugin;
+impl Plugin for SowingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(use_seed).add_observer(use_harvester);
+    }
+}
+
+fn send_message(commands: &mut Commands, entity: Entity, sm_id: SmId) {
+    commands.trigger_targets(
+        GameServerPacket::from(SystemMessage::new_empty(sm_id)),
+        entity,
+    );
+}
+
+fn roll(chance: u32) -> bool {
+    rand::thread_rng().gen_range(0..100) < chance
+}
+
+/// Seed is sown on the living monster standing in the territory of the castle the seed belongs to.
+fn use_seed(
+    use_seed: Trigger<UseSeed>,
+    mut commands: Commands,
+    manor_query: ManorQuery,
+    selected: Query<Ref<SelectedTarget>>,
+    monsters: Query<Has<Seeded>, (With<Monster>, Without<Dead>)>,
+    levels: Query<Ref<ProgressLevelStats>>,
+    transforms: Query<Ref<Transform>>,
+    object_ids: Query<Ref<ObjectId>>,
+) -> Result<()> {
+    let entity = use_seed.target();
+    let UseSeed {
+        item_object_id,
+        item_id,
+    } = *use_seed.event();
+
+    let target = selected
+        .get(entity)
+        .ok()
+        .map(|selected| selected.0)
+        .filter(|target| monsters.get(*target).is_ok_and(|seeded| !seeded));
+    let Some(target) = target else {
+        send_message(
+            &mut commands,
+            entity,
+            SmId::TheTargetIsUnavailableForSeeding,
+        );
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let Some(seed) = manor_query.seeds()?.get(&item_id) else {
+        return Ok(());
+    };
+    let territory = manor_query
+        .castle_query
+        .territory_of(transforms.get(target)?.translation);
+    if territory != Some(seed.castle) {
+        send_message(&mut commands, entity, SmId::ThisSeedMayNotBeSownHere);
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let level_diff = levels.get(target)?.level().abs_diff(seed.level);
+    commands.trigger_targets(
+        DestroyItemRequest {
+            item_oid: item_object_id,
+            count: 1,
+        },
+        entity,
+    );
+
+    if roll(success_chance(level_diff)) {
+        let sower = *object_ids.get(entity)?;
+        commands.entity(target).insert(Seeded {
+            seed_id: item_id,
+            sower,
+        });
+        send_message(&mut commands, entity, SmId::TheSeedWasSuccessfullySown);
+    } else {
+        send_message(&mut commands, entity, SmId::TheSeedWasNotSown);
+    }
+    Ok(())
+}
+
+/// Crops are harvested once from the corpse of the monster, only by the one who sowed it.
+fn use_harvester(
+    use_harvester: Trigger<UseHarvester>,
+    mut commands: Commands,
+    manor_query: ManorQuery,
+    selected: Query<Ref<SelectedTarget>>,
+    corpses: Query<Option<Ref<Seeded>>, (With<Monster>, With<Dead>)>,
+    levels: Query<Ref<ProgressLevelStats>>,
+    object_ids: Query<Ref<ObjectId>>,
+    names: Query<Ref<Name>>,
+    mut items_spawn: EventWriter<SpawnNew>,
+) -> Result<()> {
+    let entity = use_harvester.target();
+
+    let Some((target, seeded)) = selected
+        .get(entity)
+        .ok()
+        .and_then(|selected| Some((selected.0, corpses.get(selected.0).ok()?)))
+    else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+    let Some(seeded) = seeded.map(|seeded| *seeded) else {
+        send_message(
+            &mut commands,
+            entity,
+            SmId::TheHarvestFailedBecauseTheSeedWasNotSown,
+        );
+        return Ok(());
+    };
+    if seeded.sower != *object_ids.get(entity)? {
+        send_message(&mut commands, entity, SmId::YouAreNotAuthorizedToHarvest);
+        return Ok(());
+    }
+    commands.entity(target).remove::<Seeded>();
+
+    let Some(seed) = manor_query.seeds()?.get(&seeded.seed_id) else {
+        return Ok(());
+    };
+    let monster_level = levels.get(target)?.level();
+    if !roll(success_chance(monster_level.abs_diff(seed.level))) {
+        send_message(&mut commands, entity, SmId::TheHarvestHasFailed);
+        return Ok(());
+    }
+
+    // Monsters much stronger than the seed give more crops
+    let count = 1 + u64::from(monster_level.saturating_sub(seed.level + MAX_LEVEL_DIFF));
+    items_spawn.write(SpawnNew {
+        item_ids: vec![seed.crop_id],
+        count,
+        item_location: ItemLocation::Inventory,
+        dropped_entity: None,
+        owner: Some(entity),
+        silent: true,
+    });
+    commands.trigger_targets(
+        GameServerPacket::from(SystemMessage::new(
+            SmId::C1HarvestedS3S2S,
+            vec![
+                SmParam::Player(names.get(entity)?.to_string()),
+                SmParam::Item(*seed.crop_id),
+                SmParam::LongNumber(count),
+            ],
+        )),
+        entity,
+    );
+    Ok(())
+}
@@ -1,30 +1,43 @@
 mod abnormal_effects;
 mod action;
 mod active_action;
+mod admin_api;
 mod admin_menu;
 mod attack;
 mod auth;
+mod castle;
 mod character;
 mod chat;
+mod clan;
 pub mod db;
 mod doors;
 mod encounters;
+mod fishing;
+mod friend;
 #[cfg(feature = "gui")]
 mod gui;
+mod henna;
+mod instance_zone;
 mod items;
+mod macros;
 mod manor;
 mod movement;
 mod multisell;
 mod network;
 mod npc;
 mod object_id;
+mod olympiad;
 mod player_specific;
+mod raid_boss;
+mod recipe;
+mod seven_signs;
 mod shortcuts;
 mod shutdown;
 mod skills;
 mod state;
 mod stats;
 mod teleport;
+mod vehicle;
 mod world_map;
 
 use crate::plugins::state::GameStateProcessPlugin;
@@ -40,6 +53,7 @@ use game_core::consts::{FIXED_UPDATE_TICK_RATE, UPDATE_TICK_RATE};
 #[cfg(feature = "gui")]
 use gui::ServerUIPlugin;
 use l2r_core::{
+    admin::AdminApiPlugin,
     assets::{CustomAssetPlugin, CustomAssetWatcherPlugin},
     metrics::MetricsPlugin,
 };
@@ -104,9 +118,21 @@ impl PluginGroup for Core {
             .add(items::ItemsPlugin)
             .add(multisell::MultisellPlugin)
             .add(shortcuts::ShortcutPlugin)
+            .add(macros::MacroPlugin)
+            .add(henna::HennaPlugin)
+            .add(recipe::RecipePlugin)
+            .add(friend::FriendPlugin)
             .add(player_specific::PlayerSpecificPlugin)
             .add(doors::DoorsPlugin)
-            .add(manor::ManorPlugin);
+            .add(manor::ManorPlugin)
+            .add(clan::ClanPlugin)
+            .add(castle::CastlePlugin)
+            .add(seven_signs::SevenSignsPlugin)
+            .add(olympiad::OlympiadPlugin)
+            .add(instance_zone::InstanceZonePlugin)
+            .add(raid_boss::RaidBossPlugin)
+            .add(vehicle::VehiclePlugin)
+            .add(fishing::FishingPlugin);
         {
             builder = builder.add(scripting::CustomScriptingPlugin);
         }
@@ -122,9 +148,11 @@ impl PluginGroup for CoreWithInfrastructure {
             // 1. It adds bevy_webgate which internally adds AsyncPlugin
             // 2. It creates the Metrics resource that Core plugins need
             .add(MetricsPlugin::new("gameserver_".to_string(), 8031))
+            .add(AdminApiPlugin::new(8031))
             .add(shutdown::ShutdownPlugin)
             .add_group(Core)
             .add(db::GameDbPlugin)
             .add(network::NetworkPlugin)
+            .add(admin_api::GameAdminApiPlugin)
     }
 }
@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use game_core::{active_action::ActiveAction, movement::Falling, stats::Movable};
+use game_core::{active_action::ActiveAction, movement::Falling, stats::Movable, vehicle::Boarded};
 use map::{WorldMap, WorldMapQuery};
 use state::GameServerStateSystems;
 use std::time::Duration;
@@ -21,7 +21,8 @@ const FALL_DETECTION_DISTANCE: f32 = 16.0;
 
 fn detect_falling(
     par_cmd: ParallelCommands,
-    query: Query<(Entity, Ref<Transform>, Ref<Movable>), Without<Falling>>,
+    // Passengers stand on the deck of the vehicle
+    query: Query<(Entity, Ref<Transform>, Ref<Movable>), (Without<Falling>, Without<Boarded>)>,
     map_query: WorldMapQuery,
 ) -> Result<()> {
     query.par_iter().for_each(|(entity, transform, movable)| {
@@ -29,6 +29,7 @@ use game_core::{
     npc::kind::{Guard, Pet},
     object_id::ObjectId,
     stats::Movable,
+    vehicle::Boat,
 };
 use map::{WorldMap, WorldMapQuery};
 use physics::GameLayer;
@@ -81,6 +82,8 @@ struct MovementChangeFilter {
     movement_changed: Changed<Movement>,
     not_in_action: Without<ActiveAction>,
     not_falling: Without<Falling>,
+    // Boats tell about their course with vehicle packets
+    not_boat: Without<Boat>,
 }
 
 #[derive(SystemParam)]
@@ -252,17 +255,21 @@ fn handle_movement_step(
 
     let mut transform = queries.transforms.get_mut(entity)?;
 
-    let Ok(geodata) = queries
+    let free_moving = moving_entity.movable.in_water() || moving_entity.movable.is_flying();
+
+    // Swimming and flying entities don't touch the ground, so they cross regions without geodata
+    let geodata = queries
         .map_query
         .inner
         .region_geodata_from_pos(transform.translation)
-    else {
+        .ok();
+    if geodata.is_none() && !free_moving {
         commands.entity(entity).remove::<Movement>();
         return Ok(());
-    };
+    }
 
-    if !moving_entity.movable.in_water()
-        && !moving_entity.movable.is_flying()
+    if !free_moving
+        && let Some(geodata) = geodata
         && let Some(geodata_height) =
             geodata.nearest_height(WorldMap::vec3_to_geo(transform.translation))
     {
@@ -286,27 +293,28 @@ fn handle_movement_step(
 
     moving_entity.movable.step();
 
-    let can_move = moving_entity.movable.in_water()
-        || moving_entity.movable.is_flying()
-        || if moving_entity.movable.exiting_water() {
-            // Prevent movement through terrain when transitioning from water to land
-            geodata
-                .nearest_height(WorldMap::vec3_to_geo(transform.translation))
-                .is_some_and(|geodata_height| {
-                    let distance_to_ground =
-                        (transform.translation.y - geodata_height as f32).abs();
-                    distance_to_ground < MAX_GROUND_SNAP_DISTANCE
-                        && geodata.can_move_to(
-                            WorldMap::vec3_to_geo(transform.translation),
-                            WorldMap::vec3_to_geo(new_position),
-                        )
-                })
-        } else {
-            geodata.can_move_to(
-                WorldMap::vec3_to_geo(transform.translation),
-                WorldMap::vec3_to_geo(new_position),
-            )
-        };
+    let can_move = free_moving
+        || geodata.is_some_and(|geodata| {
+            if moving_entity.movable.exiting_water() {
+                // Prevent movement through terrain when transitioning from water to land
+                geodata
+                    .nearest_height(WorldMap::vec3_to_geo(transform.translation))
+                    .is_some_and(|geodata_height| {
+                        let distance_to_ground =
+                            (transform.translation.y - geodata_height as f32).abs();
+                        distance_to_ground < MAX_GROUND_SNAP_DISTANCE
+                            && geodata.can_move_to(
+                                WorldMap::vec3_to_geo(transform.translation),
+                                WorldMap::vec3_to_geo(new_position),
+                            )
+                    })
+            } else {
+                geodata.can_move_to(
+                    WorldMap::vec3_to_geo(transform.translation),
+                    WorldMap::vec3_to_geo(new_position),
+                )
+            }
+        });
 
     if !can_move {
         commands.entity(entity).remove::<Movement>();
@@ -6,13 +6,18 @@ use game_core::{
     attack::Attacking,
     movement::Following,
     network::{
-        config::GameServerNetworkConfig, packets::client::GameClientPacket,
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{ActionFail, GameServerPacket},
+        },
         session::PacketReceiveParams,
     },
     npc::DialogRequest,
     path_finding::DirectMoveRequest,
     player_specific::next_intention::NextIntention,
     stats::Movable,
+    vehicle::Boarded,
 };
 
 pub(crate) struct MoveBackwardToLocationPlugin;
@@ -27,13 +32,21 @@ fn handle(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     receive_params: PacketReceiveParams,
     mut commands: Commands,
-    movable_objects: Query<(&Transform, Has<ActiveAction>), With<Movable>>,
+    movable_objects: Query<(&Transform, Has<ActiveAction>, Has<Boarded>), With<Movable>>,
 ) -> Result<()> {
     let event = receive.event();
     if let GameClientPacket::MoveBackwardToLocation(ref packet) = event.packet {
         let character_entity = receive_params.character(&event.connection.id())?;
 
-        if let Ok((transform, has_active_active_action)) = movable_objects.get(character_entity) {
+        if let Ok((transform, has_active_active_action, boarded)) =
+            movable_objects.get(character_entity)
+        {
+            // Passengers walk on the deck with the vehicle packets
+            if boarded {
+                commands.trigger_targets(GameServerPacket::from(ActionFail), character_entity);
+                return Ok(());
+            }
+
             // Cancel any active actions when player manually moves to a different location
             if has_active_active_action {
                 commands
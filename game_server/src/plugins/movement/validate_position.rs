@@ -6,15 +6,17 @@ use game_core::{
         config::GameServerNetworkConfig,
         packets::{
             client::GameClientPacket,
-            server::{GameServerPacket, ValidateLocation},
+            server::{GameServerPacket, ValidateLocation, ValidateLocationInVehicle},
         },
         session::PacketReceiveParams,
     },
     object_id::ObjectId,
     stats::{LastKnownPosition, Movable},
     teleport::TeleportInProgress,
+    vehicle::InVehicle,
 };
 use map::{WorldMap, WorldMapQuery, id::RegionId};
+use spatial::Heading;
 
 pub(crate) struct ValidatePositionPlugin;
 impl Plugin for ValidatePositionPlugin {
@@ -59,6 +61,7 @@ fn validate_position_handle(
         ),
         With<Movable>,
     >,
+    passengers: Query<Ref<InVehicle>>,
     map_query: WorldMapQuery,
 ) -> Result<()> {
     let event = receive.event();
@@ -72,6 +75,22 @@ fn validate_position_handle(
             return Ok(());
         }
 
+        // Passengers move with the vehicle, only their place on board is validated
+        if let Ok(in_vehicle) = passengers.get(character_entity) {
+            commands.trigger_targets(
+                GameServerPacket::from(ValidateLocationInVehicle::new(
+                    *object_id,
+                    in_vehicle.vehicle_oid,
+                    in_vehicle.position,
+                    Heading::from(transform.rotation),
+                )),
+                character_entity,
+            );
+            known_pos.position = transform.translation;
+            known_pos.timestamp = time.elapsed_secs_f64();
+            return Ok(());
+        }
+
         // Skip validation if entity is falling - gravity system handles position
         if is_falling {
             known_pos.position = transform.translation;
@@ -1,7 +1,7 @@
+use crate::plugins::admin_menu::GmPermissions;
 use bevy::{log, prelude::*};
 use bevy_slinet::server::PacketReceiveEvent;
 use game_core::{
-    account::Account,
     items::{ItemLocation, SpawnNew},
     multisell::{MultisellComponentsPlugin, admin_shop::AdminShopMultiSells},
     network::{
@@ -9,11 +9,15 @@ use game_core::{
         session::PacketReceiveParams,
     },
 };
+use l2r_core::model::capability::Capability;
+
+pub(crate) mod npc;
 
 pub struct MultisellPlugin;
 impl Plugin for MultisellPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(MultisellComponentsPlugin);
+        app.add_plugins(MultisellComponentsPlugin)
+            .add_plugins(npc::NpcMultisellPlugin);
         app.add_observer(handle_packet);
     }
 }
@@ -21,7 +25,7 @@ impl Plugin for MultisellPlugin {
 fn handle_packet(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     receive_params: PacketReceiveParams,
-    accounts: Query<Ref<Account>>,
+    mut permissions: GmPermissions,
     admin_shop_items: Res<AdminShopMultiSells>,
     mut items_spawn: EventWriter<SpawnNew>,
 ) -> Result<()> {
@@ -31,12 +35,18 @@ fn handle_packet(
     };
     let character_entity = receive_params.character(&event.connection.id())?;
     let list_id = packet.list_id();
-    // if id >= 1_000_000_000 it is admin shop
+    // if id >= 1_000_000_000 it is admin shop, lower ids are handled by npc multisells
     if list_id >= 1_000_000_000.into() {
-        let session = receive_params.session(&event.connection.id())?;
-        let account = accounts.get(session)?;
-        if !account.access().gm() {
-            log::error!("Account {:?} is not GM", account.name());
+        if !permissions.authorize(
+            character_entity,
+            Capability::Multisell,
+            format!(
+                "admin shop {:?} entry {} x{}",
+                list_id,
+                packet.entry_id(),
+                packet.amount()
+            ),
+        ) {
             return Ok(());
         }
 
@@ -77,14 +87,6 @@ fn handle_packet(
                 list_id
             );
         }
-    } else {
-        // MultiSell From assets
-        log::debug!(
-            "MultisellChoose packet received: list_id={:?}, entry_id={:?}, amount={}",
-            packet.list_id(),
-            packet.entry_id(),
-            packet.amount(),
-        );
     }
 
     Ok(())
@@ -0,0 +1,325 @@
+use crate::plugins::castle::CastleQuery;
+use bevy::{ecs::system::SystemParam, log, prelude::*};
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    character::Character,
+    items::{
+        ExchangeItems, Inventory, Item, ItemLocation, ItemsDataAccess, ItemsDataQuery,
+        journal::ItemTransactionKind,
+    },
+    multisell::{
+        Entry, Good, Id, Ingredient, NpcMultisell, NpcMultisells, NpcMultisellsHandle,
+        ViewedMultisell,
+    },
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{ActionFail, GameServerPacket, MultisellList, SystemMessage},
+        },
+        session::PacketReceiveParams,
+    },
+    npc,
+    object_id::{ObjectId, ObjectIdManager},
+};
+use l2r_core::chronicles::CHRONICLE;
+use spatial::FlatDistance;
+use state::{GameServerStateSystems, LoadingSystems};
+use std::path::PathBuf;
+use system_messages::Id as SmId;
+
+pub(crate) const MULTISELL_NPC_DISTANCE: f32 = 150.0;
+
+/// Multisells of the merchants, purchases are taxed by the castle owning the territory of the merchant.
+pub struct NpcMultisellPlugin;
+impl Plugin for NpcMultisellPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NpcMultisellsHandle>();
+
+        app.add_systems(
+            Update,
+            (
+                load_assets.in_set(LoadingSystems::AssetInit),
+                update_assets.in_set(LoadingSystems::AssetInit),
+            ),
+        );
+
+        app.add_systems(Update, update_assets.in_set(GameServerStateSystems::Run));
+
+        app.add_observer(handle_choose);
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct NpcMultisellQuery<'w, 's> {
+    multisells_handle: Res<'w, NpcMultisellsHandle>,
+    multisells_assets: Res<'w, Assets<NpcMultisells>>,
+    items_data: ItemsDataQuery<'w, 's>,
+    inventories: Query<'w, 's, Ref<'static, Inventory>, With<Character>>,
+    items: Query<'w, 's, Ref<'static, Item>>,
+    object_id_manager: Res<'w, ObjectIdManager>,
+    npcs: Query<'w, 's, (Ref<'static, npc::Id>, Ref<'static, Transform>)>,
+    transforms: Query<'w, 's, Ref<'static, Transform>>,
+    pub castle_query: CastleQuery<'w, 's>,
+}
+
+impl NpcMultisellQuery<'_, '_> {
+    pub fn multisell(&self, id: Id) -> Result<&NpcMultisell> {
+        self.multisells_assets
+            .get(self.multisells_handle.id())
+            .ok_or_else(|| BevyError::from("NPC multisells are not loaded"))?
+            .get(&id)
+            .ok_or_else(|| BevyError::from(format!("NPC multisell {id} not found")))
+    }
+
+    /// Multisell is sold by the NPC and the character stands close to it.
+    pub fn available(&self, entity: Entity, npc_entity: Entity, id: Id) -> Result<bool> {
+        let (npc_id, npc_transform) = self.npcs.get(npc_entity)?;
+        let transform = self.transforms.get(entity)?;
+        Ok(self.multisell(id)?.sold_by(*npc_id)
+            && npc_transform
+                .translation
+                .flat_distance(&transform.translation)
+                <= MULTISELL_NPC_DISTANCE)
+    }
+
+    /// Count of the ingredient including the tax of the territory the NPC stands in.
+    fn taxed_count(&self, npc_entity: Entity, ingredient: &Ingredient) -> Result<(u64, u64)> {
+        if !ingredient.tax {
+            return Ok((ingredient.count, 0));
+        }
+        let (_, npc_transform) = self.npcs.get(npc_entity)?;
+        let castle_id = self.castle_query.territory_of(npc_transform.translation);
+        let tax = self.castle_query.tax(castle_id, ingredient.count);
+        Ok((ingredient.count + tax, tax))
+    }
+
+    fn good(&self, item_id: game_core::items::Id, count: u64) -> Result<Good> {
+        let item_info = self.items_data.item_info(item_id)?;
+        Ok(Good::new(Item::new_with_count(
+            item_id,
+            count,
+            ItemLocation::Store,
+            item_info,
+        )))
+    }
+
+    pub fn list(&self, npc_entity: Entity, id: Id) -> Result<Vec<Entry>> {
+        let multisell = self.multisell(id)?;
+        let mut entries = Vec::with_capacity(multisell.entries.len());
+        for entry in multisell.entries.iter() {
+            let rewards = entry
+                .rewards
+                .iter()
+                .map(|reward| self.good(reward.item_id, reward.count))
+                .collect::<Result<Vec<_>>>()?;
+            let requirements = entry
+                .requirements
+                .iter()
+                .map(|requirement| {
+                    let (count, _) = self.taxed_count(npc_entity, requirement)?;
+                    let mut good = self.good(requirement.item_id, count)?;
+                    good.tax = requirement.tax;
+                    Ok(good)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let stackable = entry.rewards.iter().all(|reward| {
+                self.items_data
+                    .item_info(reward.item_id)
+                    .is_ok_and(|info| info.stackable())
+            });
+            entries.push(Entry::new(stackable, rewards, requirements));
+        }
+        Ok(entries)
+    }
+
+    /// Object id and count of the item stack in character inventory.
+    fn inventory_item(
+        &self,
+        entity: Entity,
+        item_id: game_core::items::Id,
+    ) -> Option<(ObjectId, u64)> {
+        let inventory = self.inventories.get(entity).ok()?;
+        let (item_entity, object_id, _) =
+            inventory.single_by_item_id(item_id, &self.items, self.object_id_manager.as_ref())?;
+        let item = self.items.get(item_entity).ok()?;
+        Some((object_id, item.count()))
+    }
+}
+
+fn load_assets(
+    asset_server: Res<AssetServer>,
+    mut multisells_handle: ResMut<NpcMultisellsHandle>,
+    mut loaded: Local<bool>,
+) {
+    if *loaded {
+        return;
+    }
+    let mut path = PathBuf::from("multisell");
+    path.push(CHRONICLE);
+    path.push("npc");
+    path.set_extension("json");
+
+    let handle: Handle<NpcMultisells> = asset_server.load(path.clone());
+    **multisells_handle = handle;
+    *loaded = true;
+}
+
+fn update_assets(
+    handle: Res<NpcMultisellsHandle>,
+    mut events: EventReader<AssetEvent<NpcMultisells>>,
+) {
+    for event in events.read() {
+        if event.is_loaded_with_dependencies(handle.id()) {
+            log::info!("NPC multisells updated");
+        }
+    }
+}
+
+/// Requirements are taken from the inventory and the tax part of them goes to the castle treasury.
+fn handle_choose(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut commands: Commands,
+    viewed: Query<Ref<ViewedMultisell>>,
+    mut multisell_query: NpcMultisellQuery,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::MultisellChoose(ref packet) = event.packet else {
+        return Ok(());
+    };
+    if packet.list_id() >= 1_000_000_000.into() {
+        return Ok(());
+    }
+    let entity = receive_params.character(&event.connection.id())?;
+
+    let fail = |commands: &mut Commands| {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+    };
+
+    let Ok(viewed) = viewed.get(entity) else {
+        fail(&mut commands);
+        return Ok(());
+    };
+    let ViewedMultisell {
+        id,
+        npc: npc_entity,
+    } = *viewed;
+    if id != packet.list_id() || !multisell_query.available(entity, npc_entity, id)? {
+        fail(&mut commands);
+        return Ok(());
+    }
+
+    let amount = packet.amount().max(1);
+    let Some(entry) = multisell_query
+        .multisell(id)?
+        .entries
+        .get(packet.entry_id() as usize)
+        .cloned()
+    else {
+        log::warn!(
+            "MultisellChoose: entry_id {} not found in NPC multisell {}",
+            packet.entry_id(),
+            id
+        );
+        fail(&mut commands);
+        return Ok(());
+    };
+
+    let mut requirements = Vec::with_capacity(entry.requirements.len());
+    let mut total_tax = 0;
+    for requirement in entry.requirements.iter() {
+        let (count, tax) = multisell_query.taxed_count(npc_entity, requirement)?;
+        let (Some(count), Some(tax)) = (count.checked_mul(amount), tax.checked_mul(amount)) else {
+            fail(&mut commands);
+            return Ok(());
+        };
+        match multisell_query.inventory_item(entity, requirement.item_id) {
+            Some((item_oid, available)) if available >= count => {
+                requirements.push((item_oid, count));
+                total_tax += tax;
+            }
+            _ => {
+                commands.trigger_targets(
+                    GameServerPacket::from(SystemMessage::new_empty(
+                        SmId::YouDoNotHaveEnoughRequiredItems,
+                    )),
+                    entity,
+                );
+                fail(&mut commands);
+                return Ok(());
+            }
+        }
+    }
+
+    commands.trigger_targets(
+        ExchangeItems {
+            kind: ItemTransactionKind::Multisell,
+            taken: requirements,
+            given: entry
+                .rewards
+                .iter()
+                .map(|reward| (reward.item_id, reward.count * amount))
+                .collect(),
+        },
+        entity,
+    );
+
+    let (_, npc_transform) = multisell_query.npcs.get(npc_entity)?;
+    if let Some(castle_id) = multisell_query
+        .castle_query
+        .territory_of(npc_transform.translation)
+    {
+        multisell_query
+            .castle_query
+            .deposit(&mut commands, castle_id, total_tax)?;
+    }
+    Ok(())
+}
+
+/// Opens the multisell of the NPC, remembering it for the following choice.
+pub(crate) fn open(
+    commands: &mut Commands,
+    multisell_query: &NpcMultisellQuery,
+    entity: Entity,
+    npc_entity: Entity,
+    id: Id,
+) -> Result<()> {
+    if !multisell_query.available(entity, npc_entity, id)? {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+    let entries = multisell_query.list(npc_entity, id)?;
+    commands.entity(entity).insert(ViewedMultisell {
+        id,
+        npc: npc_entity,
+    });
+    commands.trigger_targets(MultisellList::multipage_list(id, entries), entity);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use l2r_core::{assets::ASSET_DIR, chronicles::CHRONICLE, utils::get_base_path};
+    use serde_json::from_reader;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_parse_all_from_json() {
+        let mut path = get_base_path();
+        path.push(ASSET_DIR);
+        path.push("multisell");
+        path.push(CHRONICLE);
+        path.push("npc");
+        path.set_extension("json");
+
+        let file = File::open(&path).unwrap_or_else(|_| panic!("Failed to open file: {:?}", path));
+        let reader = BufReader::new(file);
+
+        let result: NpcMultisells = from_reader(reader)
+            .unwrap_or_else(|_| panic!("Failed to parse NPC multisells from JSON: {:?}", path));
+
+        assert!(!result.is_empty());
+    }
+}
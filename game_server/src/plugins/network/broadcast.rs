@@ -3,12 +3,18 @@ use bevy::prelude::*;
 use bevy_ecs::entity::EntityHashSet;
 use game_core::{
     character::Character,
-    encounters::{EnteredWorld, KnownEntities},
+    encounters::{EnteredWorld, KnownBy, KnownEntities},
+    instance_zone::InstanceZoneId,
     network::{
         broadcast::{BroadcastScope, ServerPacketBroadcast, ServerPacketsBroadcast},
+        packets::{
+            GameServerPacketMetric,
+            server::{GameServerPacket, SerializedPacket},
+        },
         session::GameServerSession,
     },
 };
+use l2r_core::metrics::Metrics;
 use map::id::RegionId;
 use physics::GameLayer;
 
@@ -43,102 +49,223 @@ fn server_packet_broadcast(
     sessions: Query<Entity, With<GameServerSession>>,
     characters: Query<(Entity, &Transform), (With<Character>, With<EnteredWorld>)>,
     broadcasters: Query<&Transform>,
-    known_entities: Query<(Entity, Ref<KnownEntities>)>,
+    known_entities: Query<Ref<KnownEntities>>,
+    known_by: Query<Ref<KnownBy>>,
+    instances: Query<Ref<InstanceZoneId>>,
     spatial_query: SpatialQuery,
+    metrics: Res<Metrics>,
     mut commands: Commands,
-) {
+) -> Result<()> {
     let event = broadcast.event();
     let broadcaster = broadcast.target();
+    // Packets are not heard outside of the instance the broadcaster is in
+    let broadcaster_instance = instances.get(broadcaster).ok().map(|instance| *instance);
+    let same_instance = |entity: Entity| {
+        instances.get(entity).ok().map(|instance| *instance) == broadcaster_instance
+    };
 
-    match &event.scope {
-        BroadcastScope::All => {
-            for session_entity in sessions.iter() {
-                commands.trigger_targets(event.packet.clone(), session_entity);
-            }
+    // Characters known to the broadcaster and the ones that know it
+    let known_targets = || {
+        let mut targets = EntityHashSet::new();
+        if let Ok(known) = known_entities.get(broadcaster) {
+            targets.extend(known.iter().copied());
         }
+        if let Ok(known_by) = known_by.get(broadcaster) {
+            targets.extend(known_by.iter().copied());
+        }
+        targets.retain(|entity| characters.contains(*entity));
+        targets
+    };
+
+    let broadcast_targets: Vec<Entity> = match &event.scope {
+        BroadcastScope::All => sessions.iter().collect(),
         BroadcastScope::Known => {
-            let mut broadcast_targets = EntityHashSet::new();
-            // Entities that are known to the broadcaster
-            if let Ok((_, known)) = known_entities.get(broadcaster) {
-                for entity in known.iter() {
-                    if characters.contains(*entity) {
-                        broadcast_targets.insert(*entity);
-                    }
-                }
-            }
-            // Entities that know the broadcaster
-            for (entity, known) in known_entities.iter() {
-                if known.contains(&broadcaster) && characters.contains(entity) {
-                    broadcast_targets.insert(entity);
-                }
-            }
+            let mut broadcast_targets = known_targets();
             // Exclude the broadcaster
             broadcast_targets.remove(&broadcaster);
-
-            for entity in broadcast_targets.iter() {
-                commands.trigger_targets(event.packet.clone(), *entity);
-            }
+            broadcast_targets.into_iter().collect()
         }
         BroadcastScope::KnownAndSelf => {
-            let mut broadcast_targets = EntityHashSet::new();
-            // Entities that are known to the broadcaster
-            if let Ok((_, known)) = known_entities.get(broadcaster) {
-                for entity in known.iter() {
-                    if characters.contains(*entity) {
-                        broadcast_targets.insert(*entity);
-                    }
-                }
-            }
-            // Entities that know the broadcaster
-            for (entity, known) in known_entities.iter() {
-                if known.contains(&broadcaster) && characters.contains(entity) {
-                    broadcast_targets.insert(entity);
-                }
-            }
+            let mut broadcast_targets = known_targets();
             // Include the broadcaster
             broadcast_targets.insert(broadcaster);
-
-            for entity in broadcast_targets.iter() {
-                commands.trigger_targets(event.packet.clone(), *entity);
-            }
+            broadcast_targets.into_iter().collect()
         }
         BroadcastScope::Radius(radius) => {
-            if let Ok(broadcaster_transform) = broadcasters.get(broadcaster) {
-                let query_sphere = Collider::sphere(*radius);
+            let Ok(broadcaster_transform) = broadcasters.get(broadcaster) else {
+                return Ok(());
+            };
+            let query_sphere = Collider::sphere(*radius);
 
-                let filter = SpatialQueryFilter::default().with_mask(GameLayer::broadcast_mask());
+            let filter = SpatialQueryFilter::default().with_mask(GameLayer::broadcast_mask());
 
-                let nearby_entities = spatial_query.shape_intersections(
+            spatial_query
+                .shape_intersections(
                     &query_sphere,
                     broadcaster_transform.translation,
                     Quat::IDENTITY,
                     &filter,
-                );
-
-                for entity in nearby_entities {
-                    if characters.contains(entity) {
-                        commands.trigger_targets(event.packet.clone(), entity);
-                    }
-                }
-            }
+                )
+                .into_iter()
+                .filter(|entity| characters.contains(*entity) && same_instance(*entity))
+                .collect()
         }
         BroadcastScope::InRegion => {
-            if let Ok(broadcaster_transform) = broadcasters.get(broadcaster) {
-                let broadcaster_region = RegionId::from(broadcaster_transform.translation);
-                for (session_entity, session_transform) in characters.iter() {
-                    let session_region = RegionId::from(session_transform.translation);
-                    if session_region == broadcaster_region {
-                        commands.trigger_targets(event.packet.clone(), session_entity);
-                    }
-                }
-            }
+            let Ok(broadcaster_transform) = broadcasters.get(broadcaster) else {
+                return Ok(());
+            };
+            let broadcaster_region = RegionId::from(broadcaster_transform.translation);
+            characters
+                .iter()
+                .filter(|(session_entity, session_transform)| {
+                    RegionId::from(session_transform.translation) == broadcaster_region
+                        && same_instance(*session_entity)
+                })
+                .map(|(session_entity, _)| session_entity)
+                .collect()
+        }
+        BroadcastScope::Entities(entities) => entities
+            .iter()
+            .copied()
+            .filter(|entity| characters.contains(*entity) || sessions.contains(*entity))
+            .collect(),
+    };
+
+    send_shared(&mut commands, &metrics, &event.packet, broadcast_targets)
+}
+
+/// The packet body is serialized once for all the targets, only the encryption is left per session.
+fn send_shared(
+    commands: &mut Commands,
+    metrics: &Metrics,
+    packet: &GameServerPacket,
+    targets: Vec<Entity>,
+) -> Result<()> {
+    if targets.len() < 2 {
+        for entity in targets {
+            commands.trigger_targets(packet.clone(), entity);
         }
-        BroadcastScope::Entities(entities) => {
-            for &entity in entities {
-                if characters.contains(entity) || sessions.contains(entity) {
-                    commands.trigger_targets(event.packet.clone(), entity);
-                }
+        return Ok(());
+    }
+
+    let shared = SerializedPacket::from(packet.clone());
+    let saved = targets.len() as u64 - 1;
+    metrics
+        .counter(GameServerPacketMetric::SerializationsSaved)?
+        .inc_by(saved);
+    metrics
+        .counter(GameServerPacketMetric::SerializedBytesSaved)?
+        .inc_by(saved * shared.len() as u64);
+
+    let packet = GameServerPacket::from(shared);
+    for entity in targets {
+        commands.trigger_targets(packet.clone(), entity);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{create_test_app, get_character_entity_and_oid, serial};
+    use game_core::{network::packets::server::ActionFail, object_id::ObjectIdManager};
+    use std::time::Instant;
+
+    /// Crowd of the benchmark, a busy town or a siege front.
+    const BENCH_CROWD: usize = 400;
+
+    /// Updates for the crowd to enter the run state and get to know each other.
+    const SETTLE_UPDATES: usize = 10;
+
+    #[derive(Default, Resource)]
+    struct Received(usize);
+
+    fn count_received(_: Trigger<GameServerPacket>, mut received: ResMut<Received>) {
+        received.0 += 1;
+    }
+
+    /// Crowd of characters standing in the same spot, everyone knows everyone.
+    fn crowded_app(size: usize) -> (App, Vec<Entity>) {
+        let mut app = create_test_app();
+        let (first, _) = get_character_entity_and_oid(&mut app);
+        let mut crowd = vec![first];
+        for _ in 1..size {
+            let world = app.world_mut();
+            let char_id = world.resource_mut::<ObjectIdManager>().next_id();
+            let entity = crate::tests::test_character_bundle_data(char_id).spawn(world.commands());
+            world.flush();
+            world.entity_mut(entity).insert(EnteredWorld);
+            crowd.push(entity);
+        }
+
+        for _ in 0..SETTLE_UPDATES {
+            app.update();
+        }
+        let world = app.world();
+        assert!(
+            crowd.iter().all(|entity| {
+                world
+                    .get::<KnownBy>(*entity)
+                    .is_some_and(|known_by| known_by.len() >= size - 1)
+            }),
+            "Crowd did not get to know each other"
+        );
+
+        app.init_resource::<Received>().add_observer(count_received);
+        (app, crowd)
+    }
+
+    #[test]
+    #[serial]
+    fn test_known_by_is_reverse_of_known() {
+        let (mut app, crowd) = crowded_app(20);
+        let world = app.world_mut();
+        for knower in crowd.iter() {
+            let known = world.get::<KnownEntities>(*knower).unwrap();
+            for known_entity in known.iter() {
+                let known_by = world.get::<KnownBy>(*known_entity).unwrap();
+                assert!(known_by.contains(knower));
             }
         }
     }
+
+    #[test]
+    #[serial]
+    fn test_crowd_broadcast_reaches_everyone_once() {
+        let (mut app, crowd) = crowded_app(20);
+
+        for broadcaster in crowd.iter() {
+            let world = app.world_mut();
+            world.trigger_targets(ServerPacketBroadcast::new(ActionFail.into()), *broadcaster);
+            world.flush();
+        }
+
+        let received = app.world().resource::<Received>().0;
+        assert_eq!(received, crowd.len() * crowd.len());
+    }
+
+    #[test]
+    #[serial]
+    #[ignore = "benchmark, run with --ignored --nocapture"]
+    fn bench_crowded_region_broadcast() {
+        let (mut app, crowd) = crowded_app(BENCH_CROWD);
+
+        let started = Instant::now();
+        for broadcaster in crowd.iter() {
+            let world = app.world_mut();
+            world.trigger_targets(ServerPacketBroadcast::new(ActionFail.into()), *broadcaster);
+            world.flush();
+        }
+        let elapsed = started.elapsed();
+
+        println!(
+            "{} broadcasts in a crowd of {}: {:?} total, {:?} per broadcast",
+            crowd.len(),
+            crowd.len(),
+            elapsed,
+            elapsed / crowd.len() as u32
+        );
+        let received = app.world().resource::<Received>().0;
+        assert_eq!(received, BENCH_CROWD * BENCH_CROWD);
+    }
 }
@@ -12,7 +12,7 @@ use game_core::{
                 GameServerPackets, SendCharSelectionInfo, SendUserInfo, UserInfo, UserInfoUpdated,
             },
         },
-        session::GameServerSession,
+        session::{DisconnectSession, GameServerSession, OutgoingPackets},
     },
     stats::StatsTableQuery,
 };
@@ -125,9 +125,10 @@ pub fn send_char_selection_info(
     }
 }
 
+/// Packets are queued for the session, see [`send_outgoing_packets`].
 pub fn send_server_packet(
     packet: Trigger<GameServerPacket>,
-    sessions: Query<Ref<GameServerSession>>,
+    mut sessions: Query<Mut<OutgoingPackets>, With<GameServerSession>>,
     characters: Query<Ref<DespawnChildOf>, With<Character>>,
     metrics: Res<Metrics>,
 ) -> Result<()> {
@@ -135,19 +136,59 @@ pub fn send_server_packet(
     let packet = packet.event();
 
     if let Ok(child_of) = characters.get(entity)
-        && let Ok(session) = sessions.get(**child_of)
+        && let Ok(mut outgoing) = sessions.get_mut(**child_of)
     {
         metrics.counter(GameServerPacketMetric::PacketsSent)?.inc();
-        session.send(packet.clone());
+        outgoing.push(packet.clone());
     }
 
-    if let Ok(session) = sessions.get(entity) {
+    if let Ok(mut outgoing) = sessions.get_mut(entity) {
         metrics.counter(GameServerPacketMetric::PacketsSent)?.inc();
-        session.send(packet.clone());
+        outgoing.push(packet.clone());
     }
     Ok(())
 }
 
+/// Packets of the tick go to every session in one socket write.
+pub fn send_outgoing_packets(
+    mut sessions: Query<(Ref<GameServerSession>, Mut<OutgoingPackets>)>,
+    metrics: Res<Metrics>,
+) -> Result<()> {
+    for (session, mut outgoing) in sessions.iter_mut() {
+        flush_outgoing_packets(&session, &mut outgoing, &metrics)?;
+    }
+    Ok(())
+}
+
+pub fn disconnect_session(
+    disconnect: Trigger<DisconnectSession>,
+    mut sessions: Query<(Ref<GameServerSession>, Mut<OutgoingPackets>)>,
+    metrics: Res<Metrics>,
+) -> Result<()> {
+    let (session, mut outgoing) = sessions.get_mut(disconnect.target())?;
+    flush_outgoing_packets(&session, &mut outgoing, &metrics)?;
+    session.disconnect();
+    Ok(())
+}
+
+fn flush_outgoing_packets(
+    session: &GameServerSession,
+    outgoing: &mut OutgoingPackets,
+    metrics: &Metrics,
+) -> Result<()> {
+    if outgoing.is_empty() {
+        return Ok(());
+    }
+    let coalesced = outgoing.len() as u64 - 1;
+    if let Some(packet) = outgoing.take() {
+        session.send(packet);
+    }
+    metrics
+        .counter(GameServerPacketMetric::WritesSaved)?
+        .inc_by(coalesced);
+    Ok(())
+}
+
 pub fn send_multiple_server_packets(packets: Trigger<GameServerPackets>, mut commands: Commands) {
     let entity = packets.target();
     packets.event().iter().for_each(|packet| {
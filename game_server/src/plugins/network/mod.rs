@@ -1,8 +1,9 @@
 use crate::plugins::network::{
     broadcast::NetworkBroadcastPlugin,
     functions::{
-        send_char_info_handler, send_char_selection_info, send_multiple_server_packets,
-        send_server_packet, send_user_info_handler, send_user_info_when_updated,
+        disconnect_session, send_char_info_handler, send_char_selection_info,
+        send_multiple_server_packets, send_outgoing_packets, send_server_packet,
+        send_user_info_handler, send_user_info_when_updated,
     },
     scripting::{ClientPacketScriptingPlugin, GameServerPacketScriptingPlugin},
 };
@@ -13,7 +14,8 @@ use bevy_slinet::{
 };
 use game_core::{
     account::Account,
-    character::{self, CharacterSave},
+    attack::InCombat,
+    character::{self, CharacterSave, Linkdead},
     network::{
         GameNetworkMetric,
         config::GameServerNetworkConfig,
@@ -47,6 +49,7 @@ impl Plugin for NetworkPlugin {
             .add_observer(send_char_selection_info)
             .add_observer(send_server_packet)
             .add_observer(send_multiple_server_packets)
+            .add_observer(disconnect_session)
             .add_observer(Self::new_connection)
             .add_observer(Self::disconnection_handler);
 
@@ -69,12 +72,25 @@ impl Plugin for NetworkPlugin {
             .register_counter(GameNetworkMetric::NewConnections, "New connections count")
             .register_gauge(GameNetworkMetric::ActiveSessions, "Active sessions count");
 
-        app.register_counter(GameServerPacketMetric::PacketsSent, "Total packets sent");
+        app.register_counter(GameServerPacketMetric::PacketsSent, "Total packets sent")
+            .register_counter(
+                GameServerPacketMetric::SerializationsSaved,
+                "Broadcast packets encrypted from the shared buffer",
+            )
+            .register_counter(
+                GameServerPacketMetric::SerializedBytesSaved,
+                "Bytes not serialized again thanks to the shared broadcast buffer",
+            )
+            .register_counter(
+                GameServerPacketMetric::WritesSaved,
+                "Packets written to the socket together with the others of the tick",
+            );
 
         app.init_resource::<ServerSessions>()
             .add_systems(Startup, Self::spawn_server_sessions_entity);
 
         app.add_systems(Update, NetPing::ping);
+        app.add_systems(Last, send_outgoing_packets);
     }
 }
 
@@ -117,6 +133,7 @@ impl NetworkPlugin {
 
         let event = new_connection.event();
 
+        // Only sessions are counted, characters left in the world without one are not
         let max_players: usize = config.general().max_players.into();
 
         if sessions.len() >= max_players {
@@ -150,7 +167,9 @@ impl NetworkPlugin {
         mut commands: Commands,
         mut sessions: ResMut<ServerSessions>,
         metrics: Res<Metrics>,
+        config: Res<::config::Config>,
         session_entities: Query<(Ref<character::Table>, Ref<Account>)>,
+        in_combat: Query<(), With<InCombat>>,
     ) -> Result<()> {
         let event = disconnect.event();
 
@@ -167,6 +186,22 @@ impl NetworkPlugin {
 
         if let Ok(character_entity) = character_table.character() {
             commands.trigger_targets(CharacterSave, character_entity);
+
+            // Character stays in the world for a while, so killing the client doesn't save from the fight
+            let linkdead = config.linkdead();
+            if let Some(duration) = linkdead.duration()
+                && (!linkdead.only_in_combat || in_combat.contains(character_entity))
+            {
+                log::debug!(
+                    "Character {:?} is linkdead for {:?}",
+                    character_entity,
+                    duration
+                );
+                commands
+                    .entity(character_entity)
+                    .remove::<DespawnChildOf>()
+                    .insert(Linkdead::new(account.id(), duration));
+            }
         }
 
         commands.entity(session_entity).despawn();
@@ -8,9 +8,9 @@ use game_core::network::{
 };
 use scripting::{
     bindings::{AppReflectAllocator, FunctionCallContext, InteropError, ReflectReference},
-    core::{callback_labels, event::ScriptCallbackEvent, handler::event_handler},
-    lua::LuaScriptingPlugin,
+    core::{callback_labels, event::ScriptCallbackEvent},
     prelude::{NamespaceBuilder, ScriptValue},
+    scoped_event_handler,
 };
 use std::any::TypeId;
 
@@ -127,10 +127,7 @@ impl Plugin for ClientPacketScriptingPlugin {
     fn build(&self, app: &mut App) {
         app.add_observer(Self::send_to_scripts);
 
-        app.add_systems(
-            Update,
-            event_handler::<OnPacketReceivedFunction, LuaScriptingPlugin>,
-        );
+        app.add_systems(Update, scoped_event_handler::<OnPacketReceivedFunction>);
     }
 }
 
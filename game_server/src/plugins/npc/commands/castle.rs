@@ -0,0 +1,249 @@
+use crate::plugins::{castle::CastleQuery, doors::set_door_status};
+use bevy::{log, prelude::*};
+use game_core::{
+    character::Character,
+    clan::castle::{CastleId, MAX_TAX_PERCENT, MIN_TAX_PERCENT},
+    items::{self, ADENA_ID, ItemLocation, SpawnNew},
+    manor::{ManorKind, ShowManorSetting},
+    network::packets::{
+        client::{BypassCommand, BypassCommandExecuted},
+        server::{ActionFail, GameServerPacket, NpcHtmlMessage, TeleportToLocation},
+    },
+    npc::{self, CastleCommand, DialogTemplater, NpcAction, NpcCommand},
+    object_id::{ObjectId, ObjectIdManager, QueryByObjectId},
+    teleport::{TeleportDestinations, TeleportDestinationsHandle, TeleportType},
+};
+use l2r_core::assets::html::TeraHtmlTemplater;
+use map::{Door, DoorStatus, Zone, ZoneKind};
+use serde::Serialize;
+use spatial::FlatDistance;
+
+const CASTLE_NPC_DISTANCE: f32 = 150.0;
+
+#[derive(Serialize)]
+struct CastleTeleport<'a> {
+    id: u32,
+    name: &'a str,
+}
+
+/// Chamberlain manages taxes and the treasury, doormen open the gates and the teleporter moves
+/// around the territory, all of them serve the leader of the clan owning the castle only.
+pub(super) fn handle(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    npcs: Query<(Ref<npc::Id>, Ref<Transform>)>,
+    characters: Query<(Ref<ObjectId>, Ref<Transform>), With<Character>>,
+    doors: Query<(Entity, Ref<Zone>, Ref<DoorStatus>), With<Door>>,
+    dialog_templater: Res<DialogTemplater>,
+    teleport_dest_handle: Res<TeleportDestinationsHandle>,
+    teleport_dest_assets: Res<Assets<TeleportDestinations>>,
+    mut castle_query: CastleQuery,
+    mut items_spawn: EventWriter<SpawnNew>,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+
+    let BypassCommand::Npc(NpcAction {
+        npc_oid,
+        command: NpcCommand::Castle(castle_command),
+    }) = cmd
+    else {
+        return Ok(());
+    };
+
+    let entity = trigger.target();
+
+    let (npc_id, npc_transform) = npcs.by_object_id(*npc_oid, object_id_manager.as_ref())?;
+    let (object_id, transform) = characters.get(entity)?;
+
+    if npc_transform
+        .translation
+        .flat_distance(&transform.translation)
+        > CASTLE_NPC_DISTANCE
+    {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let Some(castle_id) = castle_query.data()?.castle_of_npc(*npc_id) else {
+        log::warn!("NPC {} does not serve any castle", npc_oid);
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let render = |commands: &mut Commands, path: &str, context: &tera::Context| {
+        match dialog_templater.render_with_fallback(path, context) {
+            Ok(html) => {
+                commands.trigger_targets(
+                    GameServerPacket::from(NpcHtmlMessage::new(
+                        *npc_oid,
+                        html,
+                        items::Id::default(),
+                    )),
+                    entity,
+                );
+            }
+            Err(err) => {
+                log::error!(
+                    "Failed to render HTML for NPC with ID: {}: {}",
+                    *npc_id,
+                    err
+                );
+            }
+        }
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+    };
+
+    let mut context = tera::Context::new();
+    context.insert("object_id", npc_oid);
+    context.insert("castle", &castle_id.to_string());
+
+    if !castle_query.is_owner(entity, castle_id)? {
+        render(
+            &mut commands,
+            "teleporter/_common/castleteleporter-no.html",
+            &context,
+        );
+        return Ok(());
+    }
+
+    let castle_npcs = castle_query.castle_npcs(castle_id)?.clone();
+    let is_chamberlain = castle_npcs.chamberlain == *npc_id;
+    let is_teleporter = castle_npcs.teleporter == *npc_id;
+    let doors_of_doorman = castle_npcs.doormen.get(&*npc_id).cloned();
+
+    if !is_chamberlain && castle_query.siege_in_progress(castle_id) {
+        render(
+            &mut commands,
+            "teleporter/_common/castleteleporter-busy.html",
+            &context,
+        );
+        return Ok(());
+    }
+
+    match castle_command {
+        CastleCommand::Manage | CastleCommand::Tax(_) | CastleCommand::Withdraw(_)
+            if is_chamberlain =>
+        {
+            let result = match castle_command {
+                CastleCommand::Tax(tax_percent) => {
+                    castle_query.set_tax_percent(&mut commands, castle_id, *tax_percent)?
+                }
+                CastleCommand::Withdraw(amount) => {
+                    let result = castle_query.withdraw(&mut commands, castle_id, *amount)?;
+                    if result.is_ok() {
+                        log::info!(
+                            "Character {} withdrew {} adena from the {} treasury",
+                            *object_id,
+                            amount,
+                            castle_id
+                        );
+                        items_spawn.write(SpawnNew {
+                            item_ids: vec![ADENA_ID],
+                            count: *amount,
+                            item_location: ItemLocation::Inventory,
+                            dropped_entity: None,
+                            owner: Some(entity),
+                            silent: false,
+                        });
+                    }
+                    result
+                }
+                _ => Ok(()),
+            };
+            if let Err(err) = result {
+                context.insert("error", &err.to_string());
+            }
+            manage_context(&castle_query, castle_id, &mut context)?;
+            render(&mut commands, "_common/castle/manage.html", &context);
+        }
+        CastleCommand::SeedSetting | CastleCommand::CropSetting if is_chamberlain => {
+            let kind = if *castle_command == CastleCommand::SeedSetting {
+                ManorKind::Production
+            } else {
+                ManorKind::Procure
+            };
+            commands.trigger_targets(ShowManorSetting { castle_id, kind }, entity);
+            commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        }
+        CastleCommand::OpenDoors | CastleCommand::CloseDoors => {
+            let Some(door_ids) = doors_of_doorman else {
+                commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+                return Ok(());
+            };
+            let status = if *castle_command == CastleCommand::OpenDoors {
+                DoorStatus::Open
+            } else {
+                DoorStatus::Close
+            };
+            for (door_entity, zone, door_status) in doors.iter() {
+                if let ZoneKind::Door(door) = zone.kind()
+                    && door_ids.contains(&door.id)
+                    && *door_status != status
+                {
+                    set_door_status(&mut commands, door_entity, door, status);
+                }
+            }
+            commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        }
+        CastleCommand::Teleports if is_teleporter => {
+            let Some(destinations_table) = teleport_dest_assets.get(teleport_dest_handle.id())
+            else {
+                log::error!("Teleport destinations not found for NPC: {}", npc_oid);
+                commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+                return Ok(());
+            };
+            let destinations = destinations_table
+                .get_many(castle_npcs.teleports.iter().copied())
+                .into_iter()
+                .map(|(id, destination)| CastleTeleport {
+                    id: id.into(),
+                    name: destination.name.as_str(),
+                })
+                .collect::<Vec<_>>();
+            context.insert("destinations", &destinations);
+            render(&mut commands, "_common/castle/teleports.html", &context);
+        }
+        CastleCommand::Tp(tp_id) if is_teleporter && castle_npcs.teleports.contains(tp_id) => {
+            let Some(destination) = teleport_dest_assets
+                .get(teleport_dest_handle.id())
+                .and_then(|destinations| destinations.get(tp_id))
+            else {
+                log::error!("Teleport destination not found: {}", tp_id);
+                commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+                return Ok(());
+            };
+            commands.trigger_targets(
+                TeleportToLocation::new(
+                    *object_id,
+                    Transform::from_translation(destination.location.into()),
+                    TeleportType::default(),
+                ),
+                entity,
+            );
+        }
+        _ => {
+            log::warn!(
+                "NPC {} of {} can not execute castle command {:?}",
+                npc_oid,
+                castle_id,
+                castle_command
+            );
+            commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        }
+    }
+    Ok(())
+}
+
+fn manage_context(
+    castle_query: &CastleQuery,
+    castle_id: CastleId,
+    context: &mut tera::Context,
+) -> Result<()> {
+    let castle = castle_query.castle(castle_id)?;
+    context.insert("tax_percent", &castle.tax_percent);
+    context.insert("treasury", &castle.treasury);
+    context.insert("min_tax_percent", &MIN_TAX_PERCENT);
+    context.insert("max_tax_percent", &MAX_TAX_PERCENT);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::plugins::fishing::FishingSkillsQuery;
+use bevy::{log, prelude::*};
+use game_core::{
+    network::packets::{
+        client::{BypassCommand, BypassCommandExecuted},
+        server::{ActionFail, GameServerPacket},
+    },
+    npc::{self, FishingCommand, NpcAction, NpcCommand},
+    object_id::{ObjectIdManager, QueryByObjectId},
+};
+use spatial::FlatDistance;
+
+const FISHERMAN_DISTANCE: f32 = 150.0;
+
+pub(super) fn handle(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    npcs: Query<(Ref<npc::Kind>, Ref<Transform>)>,
+    transforms: Query<Ref<Transform>>,
+    skills_query: FishingSkillsQuery,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+
+    let BypassCommand::Npc(NpcAction {
+        npc_oid,
+        command: NpcCommand::Fishing(fishing_command),
+    }) = cmd
+    else {
+        return Ok(());
+    };
+
+    let entity = trigger.target();
+
+    let (npc_kind, npc_transform) = npcs.by_object_id(*npc_oid, object_id_manager.as_ref())?;
+    let transform = transforms.get(entity)?;
+
+    if *npc_kind != npc::Kind::Fisherman {
+        log::warn!("NPC {} is not a fisherman", npc_oid);
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    if npc_transform
+        .translation
+        .flat_distance(&transform.translation)
+        > FISHERMAN_DISTANCE
+    {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let packet = match fishing_command {
+        FishingCommand::Skills => skills_query.skill_list_packet(entity)?,
+    };
+    commands.trigger_targets(packet, entity);
+    Ok(())
+}
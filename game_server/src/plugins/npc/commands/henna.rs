@@ -0,0 +1,59 @@
+use crate::plugins::henna::HennaQuery;
+use bevy::{log, prelude::*};
+use game_core::{
+    network::packets::{
+        client::{BypassCommand, BypassCommandExecuted},
+        server::{ActionFail, GameServerPacket},
+    },
+    npc::{self, HennaCommand, NpcAction, NpcCommand},
+    object_id::{ObjectIdManager, QueryByObjectId},
+};
+use spatial::FlatDistance;
+
+const SYMBOL_MAKER_DISTANCE: f32 = 150.0;
+
+pub(super) fn handle(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    npcs: Query<(Ref<npc::Kind>, Ref<Transform>)>,
+    transforms: Query<Ref<Transform>>,
+    henna_query: HennaQuery,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+
+    let BypassCommand::Npc(NpcAction {
+        npc_oid,
+        command: NpcCommand::Henna(henna_command),
+    }) = cmd
+    else {
+        return Ok(());
+    };
+
+    let entity = trigger.target();
+
+    let (npc_kind, npc_transform) = npcs.by_object_id(*npc_oid, object_id_manager.as_ref())?;
+    let transform = transforms.get(entity)?;
+
+    if *npc_kind != npc::Kind::SymbolMaker {
+        log::warn!("NPC {} is not a symbol maker", npc_oid);
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    if npc_transform
+        .translation
+        .flat_distance(&transform.translation)
+        > SYMBOL_MAKER_DISTANCE
+    {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let packet = match henna_command {
+        HennaCommand::Draw => GameServerPacket::from(henna_query.equip_list(entity)?),
+        HennaCommand::Remove => GameServerPacket::from(henna_query.unequip_list(entity)?),
+    };
+    commands.trigger_targets(packet, entity);
+    Ok(())
+}
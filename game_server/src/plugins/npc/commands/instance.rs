@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use game_core::{
+    character::Character,
+    instance_zone::{EnterInstanceZone, InstanceZoneId, LeaveInstanceZone},
+    network::packets::{
+        client::{BypassCommand, BypassCommandExecuted},
+        server::{ActionFail, GameServerPacket},
+    },
+    npc::{InstanceCommand, NpcAction, NpcCommand},
+    object_id::{ObjectIdManager, QueryByObjectId},
+};
+use spatial::FlatDistance;
+
+const NPC_DISTANCE: f32 = 150.0;
+
+/// Gatekeepers of the instances let the characters in, the escape devices inside take them out.
+pub(super) fn handle(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    npcs: Query<(Ref<Transform>, Option<Ref<InstanceZoneId>>)>,
+    characters: Query<(Ref<Transform>, Option<Ref<InstanceZoneId>>), With<Character>>,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+
+    let BypassCommand::Npc(NpcAction {
+        npc_oid,
+        command: NpcCommand::Instance(instance_command),
+    }) = cmd
+    else {
+        return Ok(());
+    };
+
+    let entity = trigger.target();
+
+    let (npc_transform, npc_instance) = npcs.by_object_id(*npc_oid, object_id_manager.as_ref())?;
+    let (transform, instance) = characters.get(entity)?;
+
+    if npc_transform
+        .translation
+        .flat_distance(&transform.translation)
+        > NPC_DISTANCE
+    {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    match *instance_command {
+        InstanceCommand::Enter(template_id) => {
+            commands.trigger_targets(EnterInstanceZone(template_id), entity);
+        }
+        InstanceCommand::Leave => {
+            // Only the escape devices of the instance the character is in can take it out
+            if instance.is_some() && instance.as_deref() == npc_instance.as_deref() {
+                commands.trigger_targets(LeaveInstanceZone, entity);
+            } else {
+                commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use crate::plugins::manor::{MANOR_NPC_DISTANCE, ManorQuery};
+use bevy::{log, prelude::*};
+use game_core::{
+    items::ADENA_ID,
+    manor::VisitedManor,
+    network::packets::{
+        client::{BypassCommand, BypassCommandExecuted},
+        server::{ActionFail, BuyListSeed, GameServerPacket, SellListProcure, SystemMessage},
+    },
+    npc::{self, ManorCommand, NpcAction, NpcCommand},
+    object_id::{ObjectIdManager, QueryByObjectId},
+};
+use spatial::FlatDistance;
+use system_messages::Id as SmId;
+
+/// Manor managers sell seeds of their castle and procure crops for it.
+pub(super) fn handle(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    npcs: Query<(Entity, Ref<npc::Id>, Ref<Transform>)>,
+    transforms: Query<Ref<Transform>>,
+    manor_query: ManorQuery,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+
+    let BypassCommand::Npc(NpcAction {
+        npc_oid,
+        command: NpcCommand::Manor(manor_command),
+    }) = cmd
+    else {
+        return Ok(());
+    };
+
+    let entity = trigger.target();
+
+    let (npc_entity, npc_id, npc_transform) =
+        npcs.by_object_id(*npc_oid, object_id_manager.as_ref())?;
+    let transform = transforms.get(entity)?;
+
+    if npc_transform
+        .translation
+        .flat_distance(&transform.translation)
+        > MANOR_NPC_DISTANCE
+    {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let Some(castle_id) = manor_query
+        .castle_query
+        .data()?
+        .castle_of_manor_manager(*npc_id)
+    else {
+        log::warn!("NPC {} is not a manor manager of any castle", npc_oid);
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    if manor_query.manor().under_maintenance() {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(
+                SmId::TheManorSystemIsCurrentlyUnderMaintenance,
+            )),
+            entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    commands.entity(entity).insert(VisitedManor {
+        castle_id,
+        npc: npc_entity,
+    });
+
+    let adena = manor_query
+        .inventory_item(entity, ADENA_ID)
+        .map(|(_, count)| count)
+        .unwrap_or_default();
+    let castle_manor = manor_query.castle_manor(castle_id);
+
+    let packet = match manor_command {
+        ManorCommand::BuySeeds => GameServerPacket::from(BuyListSeed::new(
+            adena,
+            castle_id,
+            castle_manor.production(false).to_vec(),
+        )),
+        ManorCommand::SellCrops => {
+            let crops = castle_manor
+                .procure(false)
+                .iter()
+                .filter(|entry| entry.amount > 0)
+                .filter_map(|entry| {
+                    manor_query
+                        .inventory_item(entity, entry.item_id)
+                        .map(|(object_id, count)| (object_id, entry.item_id, count))
+                })
+                .collect();
+            GameServerPacket::from(SellListProcure::new(adena, crops))
+        }
+    };
+    commands.trigger_targets(packet, entity);
+    Ok(())
+}
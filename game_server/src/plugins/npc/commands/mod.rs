@@ -2,8 +2,18 @@ use bevy::{log, prelude::*};
 use game_core::npc::NpcCommandVariants;
 use sea_orm::Iterable;
 
+mod castle;
 mod chat;
+mod fishing;
+mod henna;
+mod instance;
+mod manor;
+mod multisell;
+mod olympiad;
+mod seven_signs;
+mod siege;
 mod tp;
+mod ziggurat;
 
 pub struct NpcCommandsPlugin;
 impl Plugin for NpcCommandsPlugin {
@@ -16,6 +26,38 @@ impl Plugin for NpcCommandsPlugin {
                 NpcCommandVariants::Chat => {
                     app.add_observer(chat::handle);
                 }
+                NpcCommandVariants::Henna => {
+                    app.add_observer(henna::handle);
+                }
+                NpcCommandVariants::Siege => {
+                    app.add_observer(siege::handle);
+                }
+                NpcCommandVariants::Castle => {
+                    app.add_observer(castle::handle);
+                }
+                NpcCommandVariants::Multisell => {
+                    app.add_observer(multisell::handle);
+                }
+                NpcCommandVariants::Manor => {
+                    app.add_observer(manor::handle);
+                }
+                NpcCommandVariants::Ssq => {
+                    app.add_observer(seven_signs::handle);
+                }
+                NpcCommandVariants::Necro => {
+                    app.add_observer(ziggurat::handle);
+                }
+                // Handled by the same observer as the necropolis teleports
+                NpcCommandVariants::Cata => {}
+                NpcCommandVariants::Olympiad => {
+                    app.add_observer(olympiad::handle);
+                }
+                NpcCommandVariants::Instance => {
+                    app.add_observer(instance::handle);
+                }
+                NpcCommandVariants::Fishing => {
+                    app.add_observer(fishing::handle);
+                }
                 _ => {
                     log::trace!("Npc command {:?} is not implemented yet", command);
                 }
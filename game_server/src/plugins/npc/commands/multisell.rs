@@ -0,0 +1,37 @@
+use crate::plugins::multisell::npc::{NpcMultisellQuery, open};
+use bevy::prelude::*;
+use game_core::{
+    multisell,
+    network::packets::client::{BypassCommand, BypassCommandExecuted},
+    npc::{self, NpcAction, NpcCommand},
+    object_id::{ObjectIdManager, QueryByObjectId},
+};
+
+/// Merchants show their multisells, purchases are taxed by the castle of the territory.
+pub(super) fn handle(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    npcs: Query<Entity, With<npc::Id>>,
+    multisell_query: NpcMultisellQuery,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+
+    let BypassCommand::Npc(NpcAction {
+        npc_oid,
+        command: NpcCommand::Multisell(list_id),
+    }) = cmd
+    else {
+        return Ok(());
+    };
+
+    let entity = trigger.target();
+    let npc_entity = npcs.by_object_id(*npc_oid, object_id_manager.as_ref())?;
+    open(
+        &mut commands,
+        &multisell_query,
+        entity,
+        npc_entity,
+        multisell::Id::from(*list_id),
+    )
+}
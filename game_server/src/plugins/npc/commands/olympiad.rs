@@ -0,0 +1,240 @@
+use crate::plugins::olympiad::OlympiadQuery;
+use bevy::{log, prelude::*};
+use game_core::{
+    attack::Dead,
+    character::{Character, HERO_WEAPONS, Hero, Noblesse},
+    items::{self, Inventory, Item, ItemLocation, SpawnNew},
+    network::packets::{
+        client::{BypassCommand, BypassCommandExecuted},
+        server::{ActionFail, GameServerPacket, NpcHtmlMessage, SystemMessage},
+    },
+    npc::{self, DialogTemplater, NpcAction, NpcCommand, OlympiadCommand},
+    object_id::{ObjectId, ObjectIdManager, QueryByObjectId},
+    olympiad::{CompetitionType, ObserveStadium, OlympiadError, OlympiadGames},
+    stats::SubClass,
+};
+use l2r_core::assets::html::TeraHtmlTemplater;
+use spatial::FlatDistance;
+use system_messages::{Id as SmId, SmParam};
+
+const MANAGER_DISTANCE: f32 = 150.0;
+
+/// Grand Olympiad managers put the nobles on the waiting lists, tell them their points
+/// and let everybody watch the matches, the Monuments of Heroes give out the hero weapons.
+pub(super) fn handle(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    npcs: Query<(Ref<npc::Id>, Ref<npc::Kind>, Ref<Transform>)>,
+    characters: Query<
+        (
+            Ref<Name>,
+            Ref<SubClass>,
+            Ref<Transform>,
+            Ref<Inventory>,
+            Has<Noblesse>,
+            Has<Hero>,
+            Has<Dead>,
+        ),
+        With<Character>,
+    >,
+    items: Query<Ref<Item>>,
+    dialog_templater: Res<DialogTemplater>,
+    games: Res<OlympiadGames>,
+    mut olympiad_query: OlympiadQuery,
+    mut items_spawn: EventWriter<SpawnNew>,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+
+    let BypassCommand::Npc(NpcAction {
+        npc_oid,
+        command: NpcCommand::Olympiad(olympiad_command),
+    }) = cmd
+    else {
+        return Ok(());
+    };
+
+    let entity = trigger.target();
+
+    let (npc_id, npc_kind, npc_transform) =
+        npcs.by_object_id(*npc_oid, object_id_manager.as_ref())?;
+    let (name, sub_class, transform, inventory, noblesse, hero, dead) = characters.get(entity)?;
+
+    if *npc_kind != npc::Kind::OlympiadManager
+        || npc_transform
+            .translation
+            .flat_distance(&transform.translation)
+            > MANAGER_DISTANCE
+    {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let send_message = |commands: &mut Commands, sm: SystemMessage| {
+        commands.trigger_targets(GameServerPacket::from(sm), entity);
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+    };
+    let player = || vec![SmParam::Player(name.to_string())];
+    let char_id = olympiad_query.char_id(entity)?;
+
+    match *olympiad_command {
+        OlympiadCommand::Register(kind) => {
+            let sm = if !noblesse {
+                SystemMessage::new(
+                    SmId::C1DoesNotMeetTheParticipationRequirementsOnlyNoblesseCharactersCanParticipateInTheOlympiad,
+                    player(),
+                )
+            } else if !matches!(*sub_class, SubClass::Main(_)) {
+                SystemMessage::new(
+                    SmId::C1DoesNotMeetTheParticipationRequirementsASubclassCharacterCannotParticipateInTheOlympiad,
+                    player(),
+                )
+            } else if dead {
+                SystemMessage::new(
+                    SmId::C1IsCurrentlyDeadAndCannotParticipateInTheOlympiad,
+                    player(),
+                )
+            } else if games.of_character(char_id).is_some() {
+                SystemMessage::new(SmId::C1IsAlreadyRegisteredOnTheMatchWaitingList, player())
+            } else {
+                match olympiad_query.register(&mut commands, entity, kind)? {
+                    Ok(()) => SystemMessage::new_empty(match kind {
+                        CompetitionType::Classed => {
+                            SmId::YouHaveBeenRegisteredForTheGrandOlympiadWaitingListForAClassSpecificMatch
+                        }
+                        CompetitionType::NonClassed => {
+                            SmId::YouAreCurrentlyRegisteredForA1v1ClassIrrelevantMatch
+                        }
+                    }),
+                    Err(OlympiadError::NotInCompetition) => SystemMessage::new_empty(
+                        SmId::TheGrandOlympiadGamesAreNotCurrentlyInProgress,
+                    ),
+                    Err(OlympiadError::AlreadyRegistered(CompetitionType::Classed)) => {
+                        SystemMessage::new(
+                            SmId::C1IsAlreadyRegisteredOnTheClassMatchWaitingList,
+                            player(),
+                        )
+                    }
+                    Err(OlympiadError::AlreadyRegistered(CompetitionType::NonClassed)) => {
+                        SystemMessage::new(
+                            SmId::C1IsAlreadyRegisteredOnTheWaitingListForTheClassIrrelevantIndividualMatch,
+                            player(),
+                        )
+                    }
+                    Err(err) => {
+                        send_html_message(
+                            &mut commands,
+                            &dialog_templater,
+                            entity,
+                            *npc_oid,
+                            *npc_id,
+                            &err.to_string(),
+                        );
+                        return Ok(());
+                    }
+                }
+            };
+            send_message(&mut commands, sm);
+        }
+
+        OlympiadCommand::Unregister => {
+            let sm_id = if !noblesse {
+                SmId::ThisCommandCanOnlyBeUsedByANoblesse
+            } else {
+                match olympiad_query.unregister(entity)? {
+                    Ok(()) => SmId::YouHaveBeenRemovedFromTheGrandOlympiadWaitingList,
+                    Err(_) => SmId::YouAreNotCurrentlyRegisteredForTheGrandOlympiad,
+                }
+            };
+            send_message(&mut commands, SystemMessage::new_empty(sm_id));
+        }
+
+        OlympiadCommand::Points => {
+            let noble = olympiad_query.olympiad().noble(char_id).copied();
+            let sm = match noble {
+                Some(noble) if noblesse => SystemMessage::new(
+                    SmId::ForTheCurrentGrandOlympiadYouHaveParticipatedInS1MatchEsS2WinSAndS3DefeatSYouCurrentlyHaveS4OlympiadPointS,
+                    [
+                        noble.competitions_done,
+                        noble.competitions_won,
+                        noble.competitions_lost,
+                        noble.points,
+                    ]
+                    .map(|value| SmParam::Number(value.max(0) as u32))
+                    .to_vec(),
+                ),
+                _ if noblesse => SystemMessage::new_empty(
+                    SmId::YouAreNotCurrentlyRegisteredForTheGrandOlympiad,
+                ),
+                _ => SystemMessage::new_empty(SmId::ThisCommandCanOnlyBeUsedByANoblesse),
+            };
+            send_message(&mut commands, sm);
+        }
+
+        OlympiadCommand::Observe(stadium) => {
+            commands.trigger_targets(ObserveStadium(stadium), entity);
+        }
+
+        OlympiadCommand::HeroWeapon(weapon_id) => {
+            let owns_weapon = HERO_WEAPONS.iter().any(|weapon_id| {
+                inventory
+                    .single_by_item_id(*weapon_id, &items, object_id_manager.as_ref())
+                    .is_some()
+            });
+            let message = if !hero {
+                Some("Only heroes may take the weapons of the Monument")
+            } else if owns_weapon {
+                Some("You already have a hero weapon")
+            } else if !HERO_WEAPONS.contains(&weapon_id) {
+                Some("There is no such weapon at the Monument")
+            } else {
+                None
+            };
+            if let Some(message) = message {
+                send_html_message(
+                    &mut commands,
+                    &dialog_templater,
+                    entity,
+                    *npc_oid,
+                    *npc_id,
+                    message,
+                );
+                return Ok(());
+            }
+            items_spawn.write(SpawnNew {
+                item_ids: vec![weapon_id],
+                count: 1,
+                item_location: ItemLocation::Inventory,
+                dropped_entity: None,
+                owner: Some(entity),
+                silent: false,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn send_html_message(
+    commands: &mut Commands,
+    dialog_templater: &DialogTemplater,
+    entity: Entity,
+    npc_oid: ObjectId,
+    npc_id: npc::Id,
+    message: &str,
+) {
+    let mut context = tera::Context::new();
+    context.insert("object_id", &npc_oid);
+    context.insert("message", message);
+    match dialog_templater.render_with_fallback("_common/olympiad/message.html", &context) {
+        Ok(html) => {
+            commands.trigger_targets(
+                GameServerPacket::from(NpcHtmlMessage::new(npc_oid, html, items::Id::default())),
+                entity,
+            );
+        }
+        Err(err) => {
+            log::error!("Failed to render HTML for NPC with ID: {}: {}", npc_id, err);
+        }
+    }
+    commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+}
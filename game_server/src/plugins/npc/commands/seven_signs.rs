@@ -0,0 +1,236 @@
+use crate::plugins::{castle::CastleQuery, seven_signs::SevenSignsQuery};
+use bevy::{log, prelude::*};
+use game_core::{
+    character::Character,
+    clan::castle::CastleId,
+    items::{self, ADENA_ID, DestroyItemRequest, Inventory, Item, ItemLocation, SpawnNew},
+    network::packets::{
+        client::{BypassCommand, BypassCommandExecuted},
+        server::{ActionFail, GameServerPacket, NpcHtmlMessage, SsqStatus, SystemMessage},
+    },
+    npc::{self, DialogTemplater, NpcAction, NpcCommand, SsqCommand},
+    object_id::{ObjectId, ObjectIdManager, QueryByObjectId},
+    seven_signs::{
+        ANCIENT_ADENA_ID, CERTIFICATE_OF_APPROVAL_ID, Cabal, DAWN_JOIN_FEE, Seal, SevenSignsError,
+        participant::SEAL_STONES,
+    },
+};
+use l2r_core::assets::html::TeraHtmlTemplater;
+use sea_orm::Iterable;
+use spatial::FlatDistance;
+use system_messages::{Id as SmId, SmParam};
+
+const PRIEST_DISTANCE: f32 = 150.0;
+
+fn joined_messages(cabal: Cabal, seal: Seal) -> [SmId; 2] {
+    let cabal_sm_id = match cabal {
+        Cabal::Dusk => SmId::YouWillParticipateInTheSevenSignsAsAMemberOfTheRevolutionariesOfDusk,
+        _ => SmId::YouWillParticipateInTheSevenSignsAsAMemberOfTheLordsOfDawn,
+    };
+    let seal_sm_id = match seal {
+        Seal::Avarice => SmId::YouVeChosenToFightForTheSealOfAvariceDuringThisQuestEventPeriod,
+        Seal::Gnosis => SmId::YouVeChosenToFightForTheSealOfGnosisDuringThisQuestEventPeriod,
+        Seal::Strife => SmId::YouVeChosenToFightForTheSealOfStrifeDuringThisQuestEventPeriod,
+    };
+    [cabal_sm_id, seal_sm_id]
+}
+
+/// Priests of dawn and priestesses of dusk register the participants of their side,
+/// take the seal stones and pay the ancient adena to the winners.
+pub(super) fn handle(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    npcs: Query<(Ref<npc::Id>, Ref<npc::Kind>, Ref<Transform>)>,
+    characters: Query<(Ref<Inventory>, Ref<Transform>), With<Character>>,
+    items: Query<Ref<Item>>,
+    dialog_templater: Res<DialogTemplater>,
+    castle_query: CastleQuery,
+    mut ssq_query: SevenSignsQuery,
+    mut items_spawn: EventWriter<SpawnNew>,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+
+    let BypassCommand::Npc(NpcAction {
+        npc_oid,
+        command: NpcCommand::Ssq(ssq_command),
+    }) = cmd
+    else {
+        return Ok(());
+    };
+
+    let entity = trigger.target();
+
+    let (npc_id, npc_kind, npc_transform) =
+        npcs.by_object_id(*npc_oid, object_id_manager.as_ref())?;
+    let (inventory, transform) = characters.get(entity)?;
+
+    let priest_cabal = match npc_kind.as_ref() {
+        npc::Kind::DawnPriest => Cabal::Dawn,
+        npc::Kind::DuskPriest => Cabal::Dusk,
+        _ => Cabal::None,
+    };
+    if priest_cabal == Cabal::None
+        || npc_transform
+            .translation
+            .flat_distance(&transform.translation)
+            > PRIEST_DISTANCE
+    {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let send_message = |commands: &mut Commands, sm: SystemMessage| {
+        commands.trigger_targets(GameServerPacket::from(sm), entity);
+    };
+    let render_error = |commands: &mut Commands, message: String| {
+        let mut context = tera::Context::new();
+        context.insert("object_id", npc_oid);
+        context.insert("message", &message);
+        match dialog_templater.render_with_fallback("_common/seven_signs/message.html", &context) {
+            Ok(html) => {
+                commands.trigger_targets(
+                    GameServerPacket::from(NpcHtmlMessage::new(
+                        *npc_oid,
+                        html,
+                        items::Id::default(),
+                    )),
+                    entity,
+                );
+            }
+            Err(err) => {
+                log::error!(
+                    "Failed to render HTML for NPC with ID: {}: {}",
+                    *npc_id,
+                    err
+                );
+            }
+        }
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+    };
+    let inventory_item = |item_id: items::Id| -> Option<(ObjectId, u64)> {
+        let (item_entity, object_id, _) =
+            inventory.single_by_item_id(item_id, &items, object_id_manager.as_ref())?;
+        Some((object_id, items.get(item_entity).ok()?.count()))
+    };
+
+    // Participants settle their account only with the priests of their own side
+    let char_id = ssq_query.char_id(entity)?;
+    let other_side = ssq_query
+        .seven_signs()
+        .participant(char_id)
+        .is_some_and(|participant| participant.cabal != priest_cabal);
+    if other_side && matches!(ssq_command, SsqCommand::Contribute | SsqCommand::Reward) {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    match *ssq_command {
+        SsqCommand::Status(page) => match SsqStatus::new(page, ssq_query.seven_signs(), char_id) {
+            Some(status) => commands.trigger_targets(GameServerPacket::from(status), entity),
+            None => commands.trigger_targets(GameServerPacket::from(ActionFail), entity),
+        },
+
+        SsqCommand::Join(cabal, seal) => {
+            if cabal != priest_cabal {
+                commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+                return Ok(());
+            }
+
+            let castle_owner = CastleId::iter()
+                .map(|castle_id| castle_query.is_owner(entity, castle_id))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .any(|owner| owner);
+
+            // Lords of Dawn take castle owners for free, others bring the certificate or pay
+            let fee = match cabal {
+                Cabal::Dawn if !castle_owner => match inventory_item(CERTIFICATE_OF_APPROVAL_ID) {
+                    Some((certificate_oid, _)) => Some((certificate_oid, 1)),
+                    None => match inventory_item(ADENA_ID) {
+                        Some((adena_oid, adena)) if adena >= DAWN_JOIN_FEE => {
+                            Some((adena_oid, DAWN_JOIN_FEE))
+                        }
+                        _ => {
+                            send_message(
+                                &mut commands,
+                                SystemMessage::new_empty(SmId::YouDoNotHaveEnoughAdena),
+                            );
+                            commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+                            return Ok(());
+                        }
+                    },
+                },
+                _ => None,
+            };
+
+            if let Err(err) = ssq_query.join(&mut commands, entity, cabal, seal, castle_owner)? {
+                render_error(&mut commands, err.to_string());
+                return Ok(());
+            }
+            if let Some((item_oid, count)) = fee {
+                commands.trigger_targets(DestroyItemRequest { item_oid, count }, entity);
+            }
+            for sm_id in joined_messages(cabal, seal) {
+                send_message(&mut commands, SystemMessage::new_empty(sm_id));
+            }
+            commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        }
+
+        SsqCommand::Contribute => {
+            let stones = SEAL_STONES.map(|(stone_id, _)| inventory_item(stone_id));
+            let counts = stones.map(|stone| stone.map(|(_, count)| count).unwrap_or_default());
+
+            match ssq_query.contribute(&mut commands, entity, counts)? {
+                Ok(points) => {
+                    for (item_oid, count) in stones.into_iter().flatten() {
+                        commands.trigger_targets(DestroyItemRequest { item_oid, count }, entity);
+                    }
+                    send_message(
+                        &mut commands,
+                        SystemMessage::new(
+                            SmId::YourContributionScoreHasIncreasedByS1,
+                            vec![SmParam::LongNumber(points)],
+                        ),
+                    );
+                    commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+                }
+                Err(SevenSignsError::NotCompetition) => {
+                    send_message(
+                        &mut commands,
+                        SystemMessage::new_empty(
+                            SmId::SealStonesMayOnlyBeTransferredDuringTheQuestEventPeriod,
+                        ),
+                    );
+                    commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+                }
+                Err(err) => render_error(&mut commands, err.to_string()),
+            }
+        }
+
+        SsqCommand::Reward => match ssq_query.collect_reward(&mut commands, entity)? {
+            Ok(reward) => {
+                items_spawn.write(SpawnNew {
+                    item_ids: vec![ANCIENT_ADENA_ID],
+                    count: reward,
+                    item_location: ItemLocation::Inventory,
+                    dropped_entity: None,
+                    owner: Some(entity),
+                    silent: false,
+                });
+                commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+            }
+            Err(SevenSignsError::NotValidation) => {
+                send_message(
+                    &mut commands,
+                    SystemMessage::new_empty(
+                        SmId::OnlyDuringTheSealValidationPeriodMayYouSettleYourAccount,
+                    ),
+                );
+                commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+            }
+            Err(err) => render_error(&mut commands, err.to_string()),
+        },
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use crate::plugins::castle::siege::{SiegeQuery, announce};
+use bevy::{log, prelude::*};
+use chrono::Utc;
+use game_core::{
+    clan::castle::siege::SiegeSide,
+    network::packets::{
+        client::{BypassCommand, BypassCommandExecuted},
+        server::{ActionFail, GameServerPacket, SystemMessage},
+    },
+    npc::{self, NpcAction, NpcCommand, SiegeCommand},
+    object_id::{ObjectIdManager, QueryByObjectId},
+};
+use spatial::FlatDistance;
+use system_messages::{Id, SmParam};
+
+const SIEGE_NPC_DISTANCE: f32 = 150.0;
+
+/// Messengers tell about the upcoming siege, holy artifacts are engraved by the attackers to take the castle.
+pub(super) fn handle(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    npcs: Query<(Ref<npc::Id>, Ref<npc::Kind>, Ref<Transform>)>,
+    transforms: Query<Ref<Transform>>,
+    mut siege_query: SiegeQuery,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+
+    let BypassCommand::Npc(NpcAction {
+        npc_oid,
+        command: NpcCommand::Siege(siege_command),
+    }) = cmd
+    else {
+        return Ok(());
+    };
+
+    let entity = trigger.target();
+
+    let (npc_id, npc_kind, npc_transform) =
+        npcs.by_object_id(*npc_oid, object_id_manager.as_ref())?;
+    let transform = transforms.get(entity)?;
+
+    if npc_transform
+        .translation
+        .flat_distance(&transform.translation)
+        > SIEGE_NPC_DISTANCE
+    {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let Some(castle_id) = siege_query.data()?.castle_of_npc(*npc_id) else {
+        log::warn!("NPC {} does not belong to any castle", npc_oid);
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    match siege_command {
+        SiegeCommand::Info => {
+            let packet = siege_query.siege_info(entity, castle_id)?;
+            commands.trigger_targets(GameServerPacket::from(packet), entity);
+        }
+        SiegeCommand::Seal => {
+            if *npc_kind != npc::Kind::Artefact {
+                log::warn!("NPC {} is not a holy artifact", npc_oid);
+                commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+                return Ok(());
+            }
+
+            let clan = siege_query.led_clan(entity)?.cloned();
+            let side = clan.as_ref().and_then(|clan| {
+                siege_query
+                    .sieges()
+                    .get(&castle_id)
+                    .filter(|siege| siege.in_progress())
+                    .and_then(|siege| siege.side_of(clan.id))
+            });
+            let (Some(clan), Some(SiegeSide::Attacker)) = (clan, side) else {
+                commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+                return Ok(());
+            };
+
+            announce(
+                &mut commands,
+                SystemMessage::new(
+                    Id::ClanS1HasSuccessfullyEngravedTheHolyArtifact,
+                    vec![SmParam::Text(clan.name.clone())],
+                ),
+            );
+            siege_query.finish(
+                &mut commands,
+                castle_id,
+                Some(clan.id),
+                Utc::now().naive_utc(),
+            )?;
+        }
+    }
+    Ok(())
+}
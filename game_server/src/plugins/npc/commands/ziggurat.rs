@@ -0,0 +1,70 @@
+use bevy::{log, prelude::*};
+use game_core::{
+    items,
+    network::packets::{
+        client::{BypassCommand, BypassCommandExecuted},
+        server::{ActionFail, GameServerPacket, NpcHtmlMessage},
+    },
+    npc::{self, DialogTemplater, NpcAction, NpcCommand},
+    object_id::{ObjectId, ObjectIdManager, QueryByObjectId},
+    seven_signs::{SevenSigns, SsqDungeon},
+};
+use l2r_core::assets::html::TeraHtmlTemplater;
+
+/// Ziggurat gatekeepers let into the necropolises and the catacombs only those the Seven Signs
+/// allow to, the teleport itself is the regular one of the gatekeeper.
+pub(super) fn handle(
+    trigger: Trigger<BypassCommandExecuted>,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    npcs: Query<Ref<npc::Id>>,
+    object_ids: Query<Ref<ObjectId>>,
+    dialog_templater: Res<DialogTemplater>,
+    seven_signs: Res<SevenSigns>,
+) -> Result<()> {
+    let BypassCommandExecuted(cmd) = trigger.event();
+
+    let BypassCommand::Npc(NpcAction { npc_oid, command }) = cmd else {
+        return Ok(());
+    };
+    let (dungeon, tp_id) = match command {
+        NpcCommand::Necro(tp_id) => (SsqDungeon::Necropolis, *tp_id),
+        NpcCommand::Cata(tp_id) => (SsqDungeon::Catacomb, *tp_id),
+        _ => return Ok(()),
+    };
+
+    let entity = trigger.target();
+    let char_id = *object_ids.get(entity)?;
+
+    if seven_signs.can_enter(char_id, dungeon) {
+        commands.trigger_targets(
+            BypassCommandExecuted(BypassCommand::Npc(NpcAction {
+                npc_oid: *npc_oid,
+                command: NpcCommand::Tp(tp_id),
+            })),
+            entity,
+        );
+        return Ok(());
+    }
+
+    let npc_id = npcs.by_object_id(*npc_oid, object_id_manager.as_ref())?;
+    let mut context = tera::Context::new();
+    context.insert("object_id", npc_oid);
+    match dialog_templater.render_with_fallback("teleporter/_common/ziggurat-no.html", &context) {
+        Ok(html) => {
+            commands.trigger_targets(
+                GameServerPacket::from(NpcHtmlMessage::new(*npc_oid, html, items::Id::default())),
+                entity,
+            );
+        }
+        Err(err) => {
+            log::error!(
+                "Failed to render HTML for NPC with ID: {}: {}",
+                *npc_id,
+                err
+            );
+        }
+    }
+    commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+    Ok(())
+}
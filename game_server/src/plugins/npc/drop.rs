@@ -1,9 +1,12 @@
 use bevy::prelude::*;
-use bevy_defer::AsyncCommandsExtension;
 use bevy_ecs::system::SystemParam;
 use game_core::{
     active_action::ActiveAction,
-    items::{ItemLocation, ItemsDataAccess, ItemsDataQueryMut, UniqueItem, model},
+    items::{
+        ItemLocation, ItemsDataAccess, ItemsDataQueryMut, UniqueItem,
+        journal::{ItemDelta, ItemTransaction, ItemTransactionKind},
+        model,
+    },
     network::{
         broadcast::{BroadcastScope, ServerPacketBroadcast},
         packets::server::{DropItem, GameServerPacket},
@@ -11,7 +14,7 @@ use game_core::{
     npc::{GenerateDropRequest, RegionalNpcInfoQuery},
     object_id::ObjectId,
 };
-use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
+use l2r_core::db::RepositoryManager;
 use map::{WorldMapQuery, id::RegionId};
 use smallvec::SmallVec;
 
@@ -112,13 +115,9 @@ pub fn generate_drop_request_handler(
         spawned_items.push(new_item);
     }
 
-    let items_repository = params.repo_manager.typed::<ObjectId, model::Entity>()?;
-
-    if !spawned_items.is_empty() {
-        commands.spawn_task(move || async move {
-            items_repository.create_many(spawned_items).await?;
-            Ok(())
-        });
-    }
+    commands.trigger(ItemTransaction::new(
+        ItemTransactionKind::Create,
+        spawned_items.iter().map(ItemDelta::from).collect(),
+    ));
     Ok(())
 }
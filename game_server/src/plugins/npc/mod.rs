@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use game_core::{
     npc::{Bundle as NpcBundle, NpcComponentsPlugin, NpcInfo, Spawn, Spawned},
     object_id::ObjectIdManager,
-    spawner::Spawner,
+    spawner::{SpawnFolder, Spawner},
     stats::StatFormulaRegistry,
 };
 use l2r_core::{
@@ -35,6 +35,7 @@ pub fn spawn_npc_bundle_handler(
     mut commands: Commands,
     world_map: Res<WorldMap>,
     mut spawners: Query<Mut<Spawner>>,
+    spawn_folders: Query<(), With<SpawnFolder>>,
     formula_registry: Res<StatFormulaRegistry>,
     npc_assets: Res<Assets<NpcInfo>>,
     mut object_id_manager: ResMut<ObjectIdManager>,
@@ -84,6 +85,10 @@ pub fn spawn_npc_bundle_handler(
                 npc_spawn_info.inc_count_alive();
             }
 
+            commands
+                .entity(npc_entity)
+                .insert(DespawnChildOf(spawner_entity));
+        } else if spawn_folders.contains(spawner_entity) {
             commands
                 .entity(npc_entity)
                 .insert(DespawnChildOf(spawner_entity));
@@ -0,0 +1,495 @@
+use super::OlympiadQuery;
+use bevy::{ecs::system::SystemParam, log, prelude::*, time::common_conditions::on_timer};
+use chrono::Utc;
+use game_core::{
+    abnormal_effects::AbnormalEffects,
+    attack::Dead,
+    character::Character,
+    network::packets::server::{
+        ExOlympiadMatchEnd, ExOlympiadMode, ExOlympiadUserInfo, GameServerPacket, SystemMessage,
+        TeleportToLocation,
+    },
+    object_id::{ObjectId, ObjectIdManager},
+    olympiad::{
+        GameStage, Observing, Olympiad, OlympiadGame, OlympiadGames, OlympiadParticipant,
+        OlympiadSide, competition_open,
+    },
+    stats::{FullVitalsRestore, Resurrect, SubClass, VitalsStat, VitalsStats},
+    teleport::TeleportType,
+};
+use map::{SpawnPointKind, SpawnPointsGetter, Zone, ZoneKind};
+use state::GameServerStateSystems;
+use std::time::Duration;
+use system_messages::{Id as SmId, SmParam};
+
+/// Spawn points of a stadium, three for each side.
+const SIDE_SPAWN_POINTS: usize = 3;
+
+pub(super) struct OlympiadGamesPlugin;
+impl Plugin for OlympiadGamesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(opponent_dead);
+
+        app.add_systems(
+            Update,
+            (start_matches, tick_games)
+                .chain()
+                .run_if(resource_exists::<Olympiad>)
+                .run_if(on_timer(Duration::from_secs(1)))
+                .in_set(GameServerStateSystems::Run),
+        );
+    }
+}
+
+#[derive(SystemParam)]
+pub(super) struct StadiumQuery<'w, 's> {
+    object_id_manager: Res<'w, ObjectIdManager>,
+    stadiums: Query<'w, 's, (Entity, Ref<'static, Zone>), With<map::OlympiadStadium>>,
+    observers: Query<'w, 's, (Entity, Ref<'static, Observing>)>,
+}
+
+impl StadiumQuery<'_, '_> {
+    /// Stadiums ordered by their names, the manager dialogs number them the same way.
+    pub fn stadiums(&self) -> Vec<Entity> {
+        let mut stadiums = self
+            .stadiums
+            .iter()
+            .map(|(entity, zone)| (zone.name().cloned().unwrap_or_default(), entity))
+            .collect::<Vec<_>>();
+        stadiums.sort();
+        stadiums.into_iter().map(|(_, entity)| entity).collect()
+    }
+
+    fn spawn_point(&self, stadium: Entity, side: OlympiadSide) -> Option<Vec3> {
+        let (_, zone) = self.stadiums.get(stadium).ok()?;
+        let ZoneKind::OlympiadStadium(kind) = zone.kind() else {
+            return None;
+        };
+        let point = kind.spawn_points().get(side.index() * SIDE_SPAWN_POINTS)?;
+        Some((*point).into())
+    }
+
+    pub fn spectator_point(&self, stadium: Entity) -> Option<Vec3> {
+        let (_, zone) = self.stadiums.get(stadium).ok()?;
+        let ZoneKind::OlympiadStadium(kind) = zone.kind() else {
+            return None;
+        };
+        let point = kind
+            .spawn_points()
+            .iter()
+            .find(|point| point.kind == SpawnPointKind::SpectatorSpawn)?;
+        Some((*point).into())
+    }
+
+    fn entity(&self, char_id: ObjectId) -> Option<Entity> {
+        self.object_id_manager.entity(char_id)
+    }
+
+    /// Sends the packet to the opponents and everybody watching the match.
+    fn send(
+        &self,
+        commands: &mut Commands,
+        stadium: Entity,
+        game: &OlympiadGame,
+        packet: GameServerPacket,
+    ) {
+        let opponents = game
+            .opponents
+            .iter()
+            .filter_map(|char_id| self.entity(*char_id));
+        let observers = self
+            .observers
+            .iter()
+            .filter(|(_, observing)| observing.stadium == stadium)
+            .map(|(entity, _)| entity);
+        for entity in opponents.chain(observers) {
+            commands.trigger_targets(packet.clone(), entity);
+        }
+    }
+}
+
+type OpponentQuery<'a> = (
+    Ref<'a, ObjectId>,
+    Ref<'a, Name>,
+    Ref<'a, SubClass>,
+    Ref<'a, VitalsStats>,
+    Ref<'a, Transform>,
+    Mut<'a, AbnormalEffects>,
+    Has<Dead>,
+    Option<Ref<'a, OlympiadParticipant>>,
+);
+
+/// Nobles waiting long enough are paired into the free stadiums.
+fn start_matches(
+    mut games: ResMut<OlympiadGames>,
+    mut olympiad_query: OlympiadQuery,
+    stadium_query: StadiumQuery,
+) {
+    if !competition_open(Utc::now().time()) {
+        return;
+    }
+    for stadium in stadium_query.stadiums() {
+        if games.contains_key(&stadium) {
+            continue;
+        }
+        let Some((kind, opponents)) = olympiad_query.next_pair() else {
+            break;
+        };
+        log::debug!(
+            "Olympiad {} match of {} and {} starts",
+            kind,
+            opponents[0],
+            opponents[1]
+        );
+        games.insert(stadium, OlympiadGame::new(kind, opponents));
+    }
+}
+
+fn countdown_message(stage: GameStage) -> Option<SmId> {
+    match stage {
+        GameStage::Teleport => Some(SmId::YouWillBeMovedToTheOlympiadStadiumInS1SecondS),
+        GameStage::Preparation => Some(SmId::TheMatchWillStartInS1SecondS),
+        GameStage::Fight => None,
+        GameStage::Return => Some(SmId::YouWillBeMovedBackToTownInS1SecondS),
+    }
+}
+
+fn tick_games(
+    mut commands: Commands,
+    mut games: ResMut<OlympiadGames>,
+    mut olympiad_query: OlympiadQuery,
+    stadium_query: StadiumQuery,
+    mut opponents: Query<OpponentQuery, With<Character>>,
+) -> Result<()> {
+    let stadiums = games.keys().copied().collect::<Vec<_>>();
+    for stadium in stadiums {
+        let Some(game) = games.get_mut(&stadium) else {
+            continue;
+        };
+
+        // Opponent who left before the match gets nothing, the one left in the stadium wins
+        let present = game.opponents.map(|char_id| {
+            stadium_query
+                .entity(char_id)
+                .filter(|e| opponents.contains(*e))
+        });
+        if present.iter().any(Option::is_none) {
+            match game.stage() {
+                GameStage::Teleport => {
+                    for entity in present.into_iter().flatten() {
+                        commands.trigger_targets(
+                            GameServerPacket::from(SystemMessage::new_empty(
+                                SmId::YourOpponentMadeHasteWithTheirTailBetweenTheirLegsTheMatchHasBeenCancelled,
+                            )),
+                            entity,
+                        );
+                    }
+                    games.remove(&stadium);
+                    continue;
+                }
+                GameStage::Preparation | GameStage::Fight => {
+                    let winner = game
+                        .opponents
+                        .into_iter()
+                        .zip(present)
+                        .find_map(|(char_id, entity)| entity.map(|_| char_id));
+                    end_match(
+                        &mut commands,
+                        &mut olympiad_query,
+                        &stadium_query,
+                        &opponents,
+                        stadium,
+                        game,
+                        winner,
+                    )?;
+                }
+                GameStage::Return => {}
+            }
+        }
+
+        if game.announce()
+            && let Some(sm_id) = countdown_message(game.stage())
+        {
+            stadium_query.send(
+                &mut commands,
+                stadium,
+                game,
+                SystemMessage::new(sm_id, vec![SmParam::Number(game.remaining() as u32)]).into(),
+            );
+        }
+
+        let stage = game.stage();
+        match (stage, game.tick()) {
+            (GameStage::Teleport, Some(GameStage::Preparation)) => {
+                let entered =
+                    enter_stadium(&mut commands, &stadium_query, &mut opponents, stadium, game)?;
+                if !entered {
+                    games.remove(&stadium);
+                }
+            }
+            (GameStage::Preparation, Some(GameStage::Fight)) => {
+                stadium_query.send(
+                    &mut commands,
+                    stadium,
+                    game,
+                    SystemMessage::new_empty(SmId::TheMatchHasStartedFight).into(),
+                );
+            }
+            (GameStage::Fight, None) => {
+                for char_id in game.opponents {
+                    let Some(entity) = stadium_query.entity(char_id) else {
+                        continue;
+                    };
+                    let Some(side) = game.side(char_id) else {
+                        continue;
+                    };
+                    let (object_id, name, sub_class, vitals, ..) = opponents.get(entity)?;
+                    let info = ExOlympiadUserInfo::new(
+                        side,
+                        *object_id,
+                        &name,
+                        sub_class.class_id(),
+                        &vitals,
+                    );
+                    stadium_query.send(&mut commands, stadium, game, info.into());
+                }
+            }
+            (GameStage::Fight, Some(GameStage::Return)) => {
+                let winner = timeout_winner(&stadium_query, &opponents, game);
+                // Stage is already over, the result is told before the return countdown
+                end_match(
+                    &mut commands,
+                    &mut olympiad_query,
+                    &stadium_query,
+                    &opponents,
+                    stadium,
+                    game,
+                    winner,
+                )?;
+            }
+            (GameStage::Return, Some(_)) => {
+                return_opponents(&mut commands, &stadium_query, &mut opponents, game)?;
+                games.remove(&stadium);
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Opponents are moved to their sides of the stadium with the buffs taken away and the vitals
+/// restored. The match is cancelled if any of them can not fight.
+fn enter_stadium(
+    commands: &mut Commands,
+    stadium_query: &StadiumQuery,
+    opponents: &mut Query<OpponentQuery, With<Character>>,
+    stadium: Entity,
+    game: &OlympiadGame,
+) -> Result<bool> {
+    let entities = game.opponents.map(|char_id| stadium_query.entity(char_id));
+    let dead = entities.map(|entity| {
+        entity
+            .and_then(|entity| opponents.get(entity).ok())
+            .is_none_or(|q| q.6)
+    });
+    if let Some(side) = dead.iter().position(|dead| *dead) {
+        for (index, entity) in entities.into_iter().enumerate() {
+            let Some(entity) = entity else {
+                continue;
+            };
+            let sm = if index == side {
+                SystemMessage::new(
+                    SmId::C1IsCurrentlyDeadAndCannotParticipateInTheOlympiad,
+                    vec![SmParam::Player(
+                        opponents
+                            .get(entity)
+                            .map(|q| q.1.to_string())
+                            .unwrap_or_default(),
+                    )],
+                )
+            } else {
+                SystemMessage::new_empty(
+                    SmId::YourOpponentDoesNotMeetTheRequirementsToDoBattleTheMatchHasBeenCancelled,
+                )
+            };
+            commands.trigger_targets(GameServerPacket::from(sm), entity);
+        }
+        return Ok(false);
+    }
+
+    for (char_id, entity) in game.opponents.into_iter().zip(entities) {
+        let (Some(entity), Some(side)) = (entity, game.side(char_id)) else {
+            continue;
+        };
+        let Some(position) = stadium_query.spawn_point(stadium, side) else {
+            return Err(BevyError::from("Olympiad stadium has no spawn points"));
+        };
+        let (object_id, _, _, _, transform, mut effects, ..) = opponents.get_mut(entity)?;
+
+        commands.entity(entity).insert(OlympiadParticipant {
+            stadium,
+            side,
+            return_position: transform.translation,
+            effects: effects.clone(),
+        });
+        effects.remove_all();
+        commands.trigger_targets(FullVitalsRestore::from(entity), entity);
+        commands.trigger_targets(
+            TeleportToLocation::new(
+                *object_id,
+                Transform::from_translation(position),
+                TeleportType::default(),
+            ),
+            entity,
+        );
+        commands.trigger_targets(
+            GameServerPacket::from(ExOlympiadMode::Participant(side)),
+            entity,
+        );
+    }
+    Ok(true)
+}
+
+/// Opponents get their buffs back and are moved to where they came from.
+fn return_opponents(
+    commands: &mut Commands,
+    stadium_query: &StadiumQuery,
+    opponents: &mut Query<OpponentQuery, With<Character>>,
+    game: &OlympiadGame,
+) -> Result<()> {
+    for char_id in game.opponents {
+        let Some(entity) = stadium_query.entity(char_id) else {
+            continue;
+        };
+        let Ok((object_id, .., dead, participant)) = opponents.get(entity) else {
+            continue;
+        };
+        let Some(participant) = participant else {
+            continue;
+        };
+
+        commands
+            .entity(entity)
+            .insert(participant.effects.clone())
+            .remove::<OlympiadParticipant>();
+        if dead {
+            commands.trigger_targets(Resurrect, entity);
+        } else {
+            commands.trigger_targets(FullVitalsRestore::from(entity), entity);
+        }
+        commands.trigger_targets(
+            TeleportToLocation::new(
+                *object_id,
+                Transform::from_translation(participant.return_position),
+                TeleportType::default(),
+            ),
+            entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(ExOlympiadMode::Normal), entity);
+    }
+    Ok(())
+}
+
+/// Opponent with more of the health left wins when the time runs out.
+fn timeout_winner(
+    stadium_query: &StadiumQuery,
+    opponents: &Query<OpponentQuery, With<Character>>,
+    game: &OlympiadGame,
+) -> Option<ObjectId> {
+    let health = game.opponents.map(|char_id| {
+        stadium_query
+            .entity(char_id)
+            .and_then(|entity| opponents.get(entity).ok())
+            .map(|(.., vitals, _, _, _, _)| {
+                vitals.get(VitalsStat::Hp) / vitals.get(VitalsStat::MaxHp).max(1.0)
+            })
+            .unwrap_or_default()
+    });
+    match health[0].partial_cmp(&health[1])? {
+        std::cmp::Ordering::Greater => Some(game.opponents[0]),
+        std::cmp::Ordering::Less => Some(game.opponents[1]),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+fn end_match(
+    commands: &mut Commands,
+    olympiad_query: &mut OlympiadQuery,
+    stadium_query: &StadiumQuery,
+    opponents: &Query<OpponentQuery, With<Character>>,
+    stadium: Entity,
+    game: &mut OlympiadGame,
+    winner: Option<ObjectId>,
+) -> Result<()> {
+    let points = olympiad_query.finish_match(commands, game.opponents, winner)?;
+    let name = |char_id: ObjectId| {
+        stadium_query
+            .entity(char_id)
+            .and_then(|entity| opponents.get(entity).ok())
+            .map(|q| q.1.to_string())
+            .unwrap_or_default()
+    };
+
+    let messages = match winner.zip(winner.and_then(|winner| game.opponent(winner))) {
+        Some((winner, loser)) => vec![
+            SystemMessage::new(
+                SmId::CongratulationsC1YouWinTheMatch,
+                vec![SmParam::Player(name(winner))],
+            ),
+            SystemMessage::new(
+                SmId::C1HasEarnedS2PointsInTheGrandOlympiadGames,
+                vec![
+                    SmParam::Player(name(winner)),
+                    SmParam::Number(points as u32),
+                ],
+            ),
+            SystemMessage::new(
+                SmId::C1HasLostS2PointsInTheGrandOlympiadGames,
+                vec![SmParam::Player(name(loser)), SmParam::Number(points as u32)],
+            ),
+        ],
+        None => vec![SystemMessage::new_empty(
+            SmId::ThereIsNoVictorTheMatchEndsInATie,
+        )],
+    };
+    for sm in messages {
+        stadium_query.send(commands, stadium, game, sm.into());
+    }
+    stadium_query.send(commands, stadium, game, ExOlympiadMatchEnd.into());
+    game.finish();
+    Ok(())
+}
+
+/// Opponent killed in the fight loses the match.
+fn opponent_dead(
+    dead: Trigger<Dead>,
+    mut commands: Commands,
+    mut games: ResMut<OlympiadGames>,
+    mut olympiad_query: OlympiadQuery,
+    stadium_query: StadiumQuery,
+    participants: Query<Ref<OlympiadParticipant>>,
+    opponents: Query<OpponentQuery, With<Character>>,
+) -> Result<()> {
+    let entity = dead.target();
+    let Ok(participant) = participants.get(entity) else {
+        return Ok(());
+    };
+    let stadium = participant.stadium;
+    let char_id = olympiad_query.char_id(entity)?;
+    let Some(game) = games.get_mut(&stadium) else {
+        return Ok(());
+    };
+    if game.stage() != GameStage::Fight {
+        return Ok(());
+    }
+    let winner = game.opponent(char_id);
+    end_match(
+        &mut commands,
+        &mut olympiad_query,
+        &stadium_query,
+        &opponents,
+        stadium,
+        game,
+        winner,
+    )
+}
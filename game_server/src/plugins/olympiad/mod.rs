@@ -0,0 +1,376 @@
+use crate::plugins::castle::siege::announce;
+use bevy::{ecs::system::SystemParam, log, prelude::*, time::common_conditions::on_timer};
+use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use chrono::{NaiveDateTime, Utc};
+use game_core::{
+    character::{self, Character, HERO_SKILLS, HERO_WEAPONS, SetHero},
+    items,
+    network::packets::server::SystemMessage,
+    object_id::{ObjectId, ObjectIdManager},
+    olympiad::{
+        self, CompetitionType, Hero, Noble, Olympiad, OlympiadComponentsPlugin, OlympiadError,
+        OlympiadGames, competition_open, hero, noble,
+    },
+    stats::SubClass,
+};
+use l2r_core::db::{
+    DbConnection, PrimaryKeyColumns, Repository, RepositoryManager, TypedRepositoryManager,
+    UpdatableModel,
+};
+use sea_orm::{ColumnTrait, Condition, QueryFilter, prelude::Expr, sea_query::OnConflict};
+use state::{GameServerStateSystems, LoadingSystems};
+use std::time::Duration;
+use system_messages::{Id as SmId, SmParam};
+
+mod game;
+mod observer;
+
+/// Grand Olympiad, nobles fight each other one on one in the stadiums every evening
+/// for the points, the best of each class becomes the hero at the end of the month.
+pub(crate) struct OlympiadPlugin;
+impl Plugin for OlympiadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(OlympiadComponentsPlugin)
+            .add_plugins(game::OlympiadGamesPlugin)
+            .add_plugins(observer::ObserverPlugin);
+
+        app.init_resource::<OlympiadGames>();
+
+        app.add_systems(Update, load_olympiad.in_set(LoadingSystems::IdInit));
+
+        app.add_systems(
+            Update,
+            update_olympiad
+                .run_if(resource_exists::<Olympiad>)
+                .run_if(on_timer(Duration::from_secs(1)))
+                .in_set(GameServerStateSystems::Run),
+        );
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct OlympiadQuery<'w, 's> {
+    olympiad: ResMut<'w, Olympiad>,
+    characters: Query<'w, 's, (Ref<'static, ObjectId>, Ref<'static, SubClass>), With<Character>>,
+    object_id_manager: Res<'w, ObjectIdManager>,
+    repo_manager: Res<'w, RepositoryManager>,
+}
+
+impl OlympiadQuery<'_, '_> {
+    pub fn olympiad(&self) -> &Olympiad {
+        &self.olympiad
+    }
+
+    pub fn char_id(&self, entity: Entity) -> Result<ObjectId> {
+        Ok(*self.characters.get(entity)?.0)
+    }
+
+    /// Puts the character on the waiting list by its main class.
+    pub fn register(
+        &mut self,
+        commands: &mut Commands,
+        entity: Entity,
+        kind: CompetitionType,
+    ) -> Result<Result<(), OlympiadError>> {
+        let (char_id, sub_class) = self.characters.get(entity)?;
+        let (char_id, class_id) = (*char_id, sub_class.class_id());
+        let now = Utc::now().naive_utc();
+        let noble = match self.olympiad.register(char_id, class_id, kind, now) {
+            Ok(noble) => *noble,
+            Err(err) => return Ok(Err(err)),
+        };
+        self.save_nobles(commands, vec![noble])?;
+        Ok(Ok(()))
+    }
+
+    pub fn unregister(&mut self, entity: Entity) -> Result<Result<(), OlympiadError>> {
+        let char_id = self.char_id(entity)?;
+        Ok(self.olympiad.unregister(char_id))
+    }
+
+    /// Opponents of the next match, if enough nobles are waiting.
+    pub fn next_pair(&mut self) -> Option<(CompetitionType, [ObjectId; 2])> {
+        self.olympiad.next_pair()
+    }
+
+    /// Counts the match for both opponents, returns the points the loser gave.
+    pub fn finish_match(
+        &mut self,
+        commands: &mut Commands,
+        opponents: [ObjectId; 2],
+        winner: Option<ObjectId>,
+    ) -> Result<i32> {
+        let points = self.olympiad.finish_match(opponents, winner);
+        let nobles = opponents
+            .iter()
+            .filter_map(|char_id| self.olympiad.noble(*char_id).copied())
+            .collect();
+        self.save_nobles(commands, nobles)?;
+        Ok(points)
+    }
+
+    fn add_weekly_points(&mut self, commands: &mut Commands, now: NaiveDateTime) -> Result<()> {
+        self.olympiad.add_weekly_points(now);
+        self.save_all(commands)
+    }
+
+    fn end_month(&mut self, commands: &mut Commands, now: NaiveDateTime) -> Result<Vec<Hero>> {
+        let former = self
+            .olympiad
+            .heroes()
+            .filter(|hero| hero.active)
+            .map(|hero| hero.char_id)
+            .collect::<Vec<_>>();
+        let heroes = self.olympiad.end_month(now);
+        self.save_all(commands)?;
+        self.crown_heroes(commands, former, &heroes)?;
+        Ok(heroes)
+    }
+
+    /// Hero status follows the heroes of the month. Characters in the game get it right away,
+    /// the offline ones have it changed in the database together with the hero skills and weapons.
+    fn crown_heroes(
+        &self,
+        commands: &mut Commands,
+        former: Vec<ObjectId>,
+        heroes: &[Hero],
+    ) -> Result<()> {
+        let heroes = heroes.iter().map(|hero| hero.char_id).collect::<Vec<_>>();
+        let stepped_down = former
+            .into_iter()
+            .filter(|char_id| !heroes.contains(char_id))
+            .collect::<Vec<_>>();
+
+        let mut offline = Vec::with_capacity(stepped_down.len());
+        for (char_id, hero) in stepped_down
+            .iter()
+            .map(|char_id| (*char_id, false))
+            .chain(heroes.iter().map(|char_id| (*char_id, true)))
+        {
+            match self.object_id_manager.entity(char_id) {
+                Some(entity) => commands.trigger_targets(SetHero(hero), entity),
+                None if !hero => offline.push(char_id),
+                None => {}
+            }
+        }
+
+        if self.repo_manager.is_mock() {
+            return Ok(());
+        }
+        let characters_repository = self
+            .repo_manager
+            .typed::<ObjectId, character::model::Entity>()?;
+        let skills_repository = self
+            .repo_manager
+            .typed::<character::skills::SkillPK, character::skills::Entity>()?;
+        let items_repository = self
+            .repo_manager
+            .typed::<ObjectId, items::model::Entity>()?;
+        commands.spawn_task(move || async move {
+            characters_repository
+                .update_many(|update| {
+                    update
+                        .col_expr(character::model::Column::Hero, Expr::value(false))
+                        .filter(character::model::Column::Id.is_in(offline.clone()))
+                })
+                .await?;
+            characters_repository
+                .update_many(|update| {
+                    update
+                        .col_expr(character::model::Column::Hero, Expr::value(true))
+                        .filter(character::model::Column::Id.is_in(heroes))
+                })
+                .await?;
+            skills_repository
+                .delete_many(|query| {
+                    query
+                        .filter(character::skills::Column::CharId.is_in(offline.clone()))
+                        .filter(character::skills::Column::SkillId.is_in(HERO_SKILLS))
+                })
+                .await?;
+            items_repository
+                .delete_many(|query| {
+                    query
+                        .filter(items::model::Column::OwnerId.is_in(offline))
+                        .filter(items::model::Column::ItemId.is_in(HERO_WEAPONS))
+                })
+                .await?;
+            Ok(())
+        });
+        Ok(())
+    }
+
+    fn save_nobles(&self, commands: &mut Commands, nobles: Vec<Noble>) -> Result<()> {
+        let repository = self
+            .repo_manager
+            .typed::<ObjectId, noble::model::Entity>()?;
+        commands.spawn_task(move || async move {
+            for noble in nobles {
+                if let Err(err) = repository
+                    .create_or_update(&noble, noble_on_conflict())
+                    .await
+                {
+                    log::error!(
+                        "Character: {}, Error saving olympiad noble: {:?}",
+                        noble.char_id,
+                        err
+                    );
+                    return Err(err.into());
+                }
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+
+    fn save_all(&self, commands: &mut Commands) -> Result<()> {
+        self.save_nobles(commands, self.olympiad.nobles().copied().collect())?;
+
+        let state = self.olympiad.state().clone();
+        let heroes = self.olympiad.heroes().copied().collect::<Vec<_>>();
+        let state_repository = self.repo_manager.typed::<i32, olympiad::model::Entity>()?;
+        let heroes_repository = self.repo_manager.typed::<ObjectId, hero::model::Entity>()?;
+        commands.spawn_task(move || async move {
+            for hero in heroes {
+                heroes_repository
+                    .create_or_update(&hero, hero_on_conflict())
+                    .await?;
+            }
+            if let Err(err) = state_repository
+                .create_or_update(&state, state_on_conflict())
+                .await
+            {
+                log::error!("Error saving olympiad state: {:?}", err);
+                return Err(err.into());
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+}
+
+fn state_on_conflict() -> OnConflict {
+    OnConflict::columns(olympiad::model::Model::pk_columns().to_vec())
+        .update_columns(olympiad::model::Model::update_columns().to_vec())
+        .to_owned()
+}
+
+fn noble_on_conflict() -> OnConflict {
+    OnConflict::columns(Noble::pk_columns().to_vec())
+        .update_columns(Noble::update_columns().to_vec())
+        .to_owned()
+}
+
+fn hero_on_conflict() -> OnConflict {
+    OnConflict::columns(Hero::pk_columns().to_vec())
+        .update_columns(Hero::update_columns().to_vec())
+        .to_owned()
+}
+
+fn load_olympiad(
+    mut commands: Commands,
+    repo_manager: Res<RepositoryManager>,
+    db_connection: Res<DbConnection>,
+    mut loaded: Local<bool>,
+) -> Result<()> {
+    if *loaded {
+        return Ok(());
+    }
+    *loaded = true;
+
+    let now = Utc::now().naive_utc();
+
+    if db_connection.is_mock() {
+        commands.insert_resource(Olympiad::new(olympiad::model::Model::new(now), [], []));
+        return Ok(());
+    }
+
+    let state_repository = repo_manager.typed::<i32, olympiad::model::Entity>()?;
+    let nobles_repository = repo_manager.typed::<ObjectId, noble::model::Entity>()?;
+    let heroes_repository = repo_manager.typed::<ObjectId, hero::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        let state = match state_repository
+            .find_with_conditions([Condition::all()])
+            .await?
+            .into_iter()
+            .next()
+        {
+            Some(state) => state,
+            None => {
+                let state = olympiad::model::Model::new(now);
+                state_repository
+                    .create_or_update(&state, state_on_conflict())
+                    .await?;
+                state
+            }
+        };
+        let nobles = nobles_repository
+            .find_with_conditions([Condition::all()])
+            .await?;
+        let heroes = heroes_repository
+            .find_with_conditions([Condition::all()])
+            .await?;
+
+        log::info!(
+            "Loaded olympiad cycle {} with {} nobles and {} heroes.",
+            state.cycle,
+            nobles.len(),
+            heroes.len()
+        );
+        AsyncWorld.apply_command(move |world: &mut World| {
+            world.insert_resource(Olympiad::new(state, nobles, heroes));
+        });
+        Ok(())
+    });
+    Ok(())
+}
+
+/// Announces the evening matches, gives the weekly points and ends the month.
+fn update_olympiad(
+    mut commands: Commands,
+    mut olympiad_query: OlympiadQuery,
+    mut competition: Local<Option<bool>>,
+) -> Result<()> {
+    let now = Utc::now().naive_utc();
+
+    let open = competition_open(now.time());
+    if competition.is_some_and(|was_open| was_open != open) {
+        let sm_id = if open {
+            SmId::SharpenYourSwordsTightenTheStitchingInYourArmorAndMakeHasteToAGrandOlympiadManagerBattlesInTheGrandOlympiadGamesAreNowTakingPlace
+        } else {
+            SmId::MuchCarnageHasBeenLeftForTheCleanupCrewOfTheOlympiadStadiumBattlesInTheGrandOlympiadGamesAreNowOver
+        };
+        announce(&mut commands, SystemMessage::new_empty(sm_id));
+    }
+    *competition = Some(open);
+
+    if olympiad_query.olympiad().weekly_points_due(now) {
+        olympiad_query.add_weekly_points(&mut commands, now)?;
+        log::info!("Olympiad weekly points are given to the nobles");
+    }
+
+    if olympiad_query.olympiad().month_over(now) {
+        let cycle = olympiad_query.olympiad().state().cycle;
+        let heroes = olympiad_query.end_month(&mut commands, now)?;
+        announce(
+            &mut commands,
+            SystemMessage::new(
+                SmId::RoundS1OfTheGrandOlympiadGamesHasNowEnded,
+                vec![SmParam::Number(cycle as u32)],
+            ),
+        );
+        announce(
+            &mut commands,
+            SystemMessage::new(
+                SmId::RoundS1OfTheGrandOlympiadGamesHasStarted,
+                vec![SmParam::Number(cycle as u32 + 1)],
+            ),
+        );
+        log::info!(
+            "Olympiad cycle {} is over, {} heroes are chosen",
+            cycle,
+            heroes.len()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use super::{OlympiadQuery, game::StadiumQuery};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    character::Character,
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{
+                ActionFail, ExOlympiadMode, GameServerPacket, ObserverEnd, ObserverStart,
+                SystemMessage,
+            },
+        },
+        session::PacketReceiveParams,
+    },
+    olympiad::{ObserveStadium, Observing, OlympiadParticipant},
+    stats::EncountersVisibility,
+};
+use system_messages::Id as SmId;
+
+pub(super) struct ObserverPlugin;
+impl Plugin for ObserverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(observe_stadium)
+            .add_observer(handle_return);
+    }
+}
+
+/// Character is hidden and moved over the stadium, the client camera follows it
+/// until the character asks to return.
+fn observe_stadium(
+    observe: Trigger<ObserveStadium>,
+    mut commands: Commands,
+    olympiad_query: OlympiadQuery,
+    stadium_query: StadiumQuery,
+    mut characters: Query<
+        (
+            Mut<Transform>,
+            Option<Ref<Observing>>,
+            Has<OlympiadParticipant>,
+        ),
+        With<Character>,
+    >,
+) -> Result<()> {
+    let entity = observe.target();
+    let ObserveStadium(index) = *observe.event();
+
+    let char_id = olympiad_query.char_id(entity)?;
+    let (mut transform, observing, participant) = characters.get_mut(entity)?;
+
+    let sm_id = if participant {
+        Some(SmId::AUserParticipatingInTheOlympiadCannotWitnessTheBattle)
+    } else if olympiad_query.olympiad().registered(char_id).is_some() {
+        Some(SmId::YouMayNotObserveAGrandOlympiadGamesMatchWhileYouAreOnTheWaitingList)
+    } else {
+        None
+    };
+    if let Some(sm_id) = sm_id {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(sm_id)),
+            entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let Some((stadium, position)) = stadium_query
+        .stadiums()
+        .get(index)
+        .and_then(|stadium| Some((*stadium, stadium_query.spectator_point(*stadium)?)))
+    else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    // Switching between the stadiums keeps the place the character came from
+    let return_position = observing
+        .map(|observing| observing.return_position)
+        .unwrap_or(transform.translation);
+    commands.entity(entity).insert((
+        Observing {
+            stadium,
+            return_position,
+        },
+        EncountersVisibility::Hidden,
+    ));
+    transform.translation = position;
+
+    commands.trigger_targets(GameServerPacket::from(ObserverStart::new(position)), entity);
+    commands.trigger_targets(GameServerPacket::from(ExOlympiadMode::Spectator), entity);
+    Ok(())
+}
+
+fn handle_return(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut commands: Commands,
+    mut observers: Query<(Mut<Transform>, Ref<Observing>), With<Character>>,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::ObserverReturn = event.packet else {
+        return Ok(());
+    };
+    let entity = receive_params.character(&event.connection.id())?;
+    let Ok((mut transform, observing)) = observers.get_mut(entity) else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let position = observing.return_position;
+    transform.translation = position;
+    commands
+        .entity(entity)
+        .remove::<Observing>()
+        .insert(EncountersVisibility::Visible);
+
+    commands.trigger_targets(GameServerPacket::from(ObserverEnd::new(position)), entity);
+    commands.trigger_targets(GameServerPacket::from(ExOlympiadMode::Normal), entity);
+    Ok(())
+}
@@ -0,0 +1,305 @@
+use bevy::{ecs::relationship::Relationship, log, prelude::*, time::common_conditions::on_timer};
+use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use chrono::{TimeDelta, Utc};
+use game_core::{
+    abnormal_effects::{
+        AbnormalEffect, AbnormalEffectTimer, AbnormalEffects, AbnormalEffectsTimers, AbnormalKind,
+    },
+    attack::{Attacking, AttackingList, Dead},
+    character::Character,
+    chat,
+    network::{
+        broadcast::{BroadcastScope, ServerPacketBroadcast},
+        packets::server::{CreatureSay, GameServerPacket},
+    },
+    npc::{
+        self, Spawned,
+        kind::{GrandBoss, RaidBoss},
+    },
+    object_id::ObjectId,
+    raid_boss::{
+        self, RAID_CURSE, RAID_CURSE_DURATION, RaidBossComponentsPlugin, RaidBosses, raid_curse,
+    },
+    skills::Skill,
+    spawner::Spawner,
+    stats::{ProgressLevelStats, VitalsStat, VitalsStats},
+};
+use l2r_core::{
+    db::{DbConnection, Repository, RepositoryManager, RepositoryModel, TypedRepositoryManager},
+    plugins::custom_hierarchy::DespawnChildOf,
+};
+use sea_orm::Condition;
+use state::{GameServerStateSystems, LoadingSystems};
+use std::time::Duration;
+
+type Boss = Or<(With<RaidBoss>, With<GrandBoss>)>;
+
+/// Raid and grand bosses keep their health, mana and respawn time in the database,
+/// so neither a restart nor an unloaded region brings them back early or healed.
+pub(crate) struct RaidBossPlugin;
+impl Plugin for RaidBossPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RaidBossComponentsPlugin);
+
+        app.add_observer(hold_boss_spawns)
+            .add_observer(boss_spawned)
+            .add_observer(boss_died)
+            .add_observer(boss_despawned);
+
+        app.add_systems(Update, load_raid_bosses.in_set(LoadingSystems::IdInit));
+
+        app.add_systems(
+            Update,
+            curse_over_leveled_attackers
+                .run_if(resource_exists::<RaidBosses>)
+                .in_set(GameServerStateSystems::Run),
+        );
+
+        app.add_systems(
+            Update,
+            save_raid_bosses
+                .run_if(resource_exists::<RaidBosses>)
+                .run_if(on_timer(Duration::from_secs(60)))
+                .in_set(GameServerStateSystems::Run),
+        );
+    }
+}
+
+fn load_raid_bosses(
+    mut commands: Commands,
+    repo_manager: Res<RepositoryManager>,
+    db_connection: Res<DbConnection>,
+    mut loaded: Local<bool>,
+) -> Result<()> {
+    if *loaded {
+        return Ok(());
+    }
+    *loaded = true;
+
+    if db_connection.is_mock() {
+        commands.insert_resource(RaidBosses::default());
+        return Ok(());
+    }
+
+    let repository = repo_manager.typed::<i32, raid_boss::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        let bosses = repository.find_with_conditions([Condition::all()]).await?;
+
+        log::info!("Loaded {} raid bosses.", bosses.len());
+        AsyncWorld.apply_command(move |world: &mut World| {
+            world.insert_resource(RaidBosses::new(bosses));
+        });
+        Ok(())
+    });
+    Ok(())
+}
+
+fn save(
+    commands: &mut Commands,
+    repo_manager: &RepositoryManager,
+    models: Vec<raid_boss::model::Model>,
+) -> Result<()> {
+    if repo_manager.is_mock() || models.is_empty() {
+        return Ok(());
+    }
+    let repository = repo_manager.typed::<i32, raid_boss::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        for model in models {
+            if let Err(err) = repository
+                .create_or_update(&model, raid_boss::model::Model::on_conflict())
+                .await
+            {
+                log::error!("Raid boss: {}, Error saving: {:?}", model.npc_id, err);
+                return Err(err.into());
+            }
+        }
+        Ok(())
+    });
+    Ok(())
+}
+
+fn announce(commands: &mut Commands, message: String) {
+    commands.trigger(ServerPacketBroadcast {
+        packet: GameServerPacket::from(CreatureSay::new(
+            ObjectId::default(),
+            "Raid Boss".to_string(),
+            vec![message],
+            chat::Kind::CriticalAnnounce,
+            None,
+        )),
+        scope: BroadcastScope::All,
+    });
+}
+
+/// Spawners of the regions are created with their timers elapsed,
+/// the dead bosses wait for the rest of their respawn time instead.
+fn hold_boss_spawns(
+    added: Trigger<OnAdd, Spawner>,
+    mut spawners: Query<Mut<Spawner>>,
+    bosses: Option<Res<RaidBosses>>,
+) -> Result<()> {
+    let Some(bosses) = bosses else {
+        return Ok(());
+    };
+    let mut spawner = spawners.get_mut(added.target())?;
+    let now = Utc::now().naive_utc();
+    for npc in spawner.npcs.iter_mut() {
+        if let Some(delay) = bosses.respawn_delay(npc.id(), now) {
+            npc.timer_mut().delay(delay);
+        }
+    }
+    Ok(())
+}
+
+/// Bosses seen alive before get their health and mana back, the respawned ones are announced.
+fn boss_spawned(
+    spawned: Trigger<Spawned>,
+    mut commands: Commands,
+    mut npcs: Query<(Ref<npc::Id>, Ref<Name>, Mut<VitalsStats>), Boss>,
+    mut bosses: ResMut<RaidBosses>,
+    repo_manager: Res<RepositoryManager>,
+) -> Result<()> {
+    let Ok((npc_id, name, mut vitals)) = npcs.get_mut(spawned.target()) else {
+        return Ok(());
+    };
+
+    match bosses.spawned(*npc_id) {
+        Some((hp, mp)) if hp > 0.0 => {
+            vitals.insert(VitalsStat::Hp, hp as f32);
+            vitals.insert(VitalsStat::Mp, mp as f32);
+        }
+        _ => announce(&mut commands, format!("{} has appeared.", name)),
+    }
+
+    let models = bosses
+        .update_vitals(
+            *npc_id,
+            vitals.get(VitalsStat::Hp) as f64,
+            vitals.get(VitalsStat::Mp) as f64,
+        )
+        .into_iter()
+        .collect();
+    save(&mut commands, &repo_manager, models)
+}
+
+/// Respawn time is counted from the death of the boss within the window of its spawner.
+fn boss_died(
+    death: Trigger<Dead>,
+    mut commands: Commands,
+    npcs: Query<(Ref<npc::Id>, Ref<Name>, Option<Ref<DespawnChildOf>>), Boss>,
+    mut spawners: Query<Mut<Spawner>>,
+    mut bosses: ResMut<RaidBosses>,
+    repo_manager: Res<RepositoryManager>,
+) -> Result<()> {
+    let Ok((npc_id, name, child_of)) = npcs.get(death.target()) else {
+        return Ok(());
+    };
+    announce(&mut commands, format!("{} has been defeated.", name));
+
+    // Bosses spawned by scripts may come back whenever the script says so
+    let mut respawn_in = Duration::ZERO;
+    if let Some(child_of) = child_of
+        && let Ok(mut spawner) = spawners.get_mut(child_of.get())
+        && let Some(npc) = spawner.npc_mut(*npc_id)
+    {
+        npc.timer_mut().reset();
+        respawn_in = npc.timer().remaining();
+    }
+
+    let respawn_at = Utc::now().naive_utc() + TimeDelta::from_std(respawn_in)?;
+    let model = bosses.died(*npc_id, respawn_at);
+    log::info!("Raid boss {} respawns at {}", *npc_id, respawn_at);
+    save(&mut commands, &repo_manager, vec![model])
+}
+
+/// Living bosses leave with their region, their health and mana are kept till it is back.
+fn boss_despawned(
+    removed: Trigger<OnRemove, npc::Id>,
+    mut commands: Commands,
+    npcs: Query<(Ref<npc::Id>, Ref<VitalsStats>), (Boss, Without<Dead>)>,
+    bosses: Option<ResMut<RaidBosses>>,
+    repo_manager: Res<RepositoryManager>,
+) -> Result<()> {
+    let (Ok((npc_id, vitals)), Some(mut bosses)) = (npcs.get(removed.target()), bosses) else {
+        return Ok(());
+    };
+    let models = bosses
+        .update_vitals(
+            *npc_id,
+            vitals.get(VitalsStat::Hp) as f64,
+            vitals.get(VitalsStat::Mp) as f64,
+        )
+        .into_iter()
+        .collect();
+    save(&mut commands, &repo_manager, models)
+}
+
+fn save_raid_bosses(
+    mut commands: Commands,
+    npcs: Query<(Ref<npc::Id>, Ref<VitalsStats>), (Boss, Without<Dead>)>,
+    mut bosses: ResMut<RaidBosses>,
+    repo_manager: Res<RepositoryManager>,
+) -> Result<()> {
+    let models = npcs
+        .iter()
+        .filter_map(|(npc_id, vitals)| {
+            bosses.update_vitals(
+                *npc_id,
+                vitals.get(VitalsStat::Hp) as f64,
+                vitals.get(VitalsStat::Mp) as f64,
+            )
+        })
+        .collect();
+    save(&mut commands, &repo_manager, models)
+}
+
+/// Characters too strong for the boss are petrified by the Raid Curse
+/// and taken off its attackers, so they neither keep hitting it nor share its reward.
+fn curse_over_leveled_attackers(
+    mut commands: Commands,
+    mut npcs: Query<(Ref<ProgressLevelStats>, Mut<AttackingList>), (Boss, Changed<AttackingList>)>,
+    mut attackers: Query<
+        (
+            Ref<ProgressLevelStats>,
+            Mut<AbnormalEffects>,
+            Mut<AbnormalEffectsTimers>,
+        ),
+        With<Character>,
+    >,
+) {
+    for (boss_level, mut attacking_list) in npcs.iter_mut() {
+        let cursed = attacking_list
+            .get_attackers()
+            .map(|(attacker, _)| attacker)
+            .filter(|attacker| {
+                attackers
+                    .get(*attacker)
+                    .is_ok_and(|(level, ..)| raid_curse(level.level(), boss_level.level()))
+            })
+            .collect::<Vec<_>>();
+
+        for attacker in cursed {
+            attacking_list.remove(attacker);
+            commands.entity(attacker).remove::<Attacking>();
+
+            let Ok((_, mut effects, mut timers)) = attackers.get_mut(attacker) else {
+                continue;
+            };
+            if effects.has_effect(RAID_CURSE) {
+                continue;
+            }
+            effects.add(AbnormalEffect::new(
+                Skill::new(RAID_CURSE, 1.into()),
+                AbnormalKind::Paralyze,
+                true,
+            ));
+            timers.insert(
+                RAID_CURSE,
+                AbnormalEffectTimer::new(
+                    Some(Timer::new(RAID_CURSE_DURATION, TimerMode::Once)),
+                    None,
+                ),
+            );
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use super::RecipeQuery;
+use bevy::{log, prelude::*};
+use bevy_defer::AsyncCommandsExtension;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{GameServerPacket, SystemMessage},
+        },
+        session::PacketReceiveParams,
+    },
+    recipe,
+};
+use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
+use system_messages::{Id, SmParam};
+
+pub(crate) struct RequestRecipeBookDestroyPlugin;
+impl Plugin for RequestRecipeBookDestroyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    repo_manager: Res<RepositoryManager>,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestRecipeBookDestroy(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let recipe_id = packet.0;
+
+    if recipe_query.manufacturing(entity) {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(
+                Id::YouMayNotAlterYourRecipeBookWhileEngagedInManufacturing,
+            )),
+            entity,
+        );
+        return Ok(());
+    }
+
+    let mut book = recipe_query.book(entity)?;
+    let Some(kind) = book.remove(recipe_id) else {
+        return Ok(());
+    };
+    commands.entity(entity).insert(book);
+
+    if let Some(info) = recipe_query.recipe(recipe_id)? {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new(
+                Id::S1HasBeenDeleted,
+                vec![SmParam::Item(*info.item_id)],
+            )),
+            entity,
+        );
+    }
+    commands.trigger_targets(
+        GameServerPacket::from(recipe_query.book_item_list(entity, kind)?),
+        entity,
+    );
+
+    let char_id = recipe_query.char_id(entity)?;
+    let recipe_repository =
+        repo_manager.typed::<recipe::model::RecipePK, recipe::model::Entity>()?;
+
+    commands.spawn_task(move || async move {
+        let result = recipe_repository
+            .delete_by_id(recipe::model::RecipePK { char_id, recipe_id })
+            .await;
+
+        if let Err(err) = result {
+            log::error!("Character: {}, Failed to delete recipe: {:?}", char_id, err);
+            return Err(err.into());
+        }
+
+        Ok(())
+    });
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use super::RecipeQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{
+        client::GameClientPacket,
+        server::{GameServerPacket, SystemMessage},
+    },
+    session::PacketReceiveParams,
+};
+use system_messages::Id;
+
+pub(crate) struct RequestRecipeBookOpenPlugin;
+impl Plugin for RequestRecipeBookOpenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestRecipeBookOpen(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+
+    if recipe_query.manufacturing(entity) {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(
+                Id::YouMayNotAlterYourRecipeBookWhileEngagedInManufacturing,
+            )),
+            entity,
+        );
+        return Ok(());
+    }
+
+    commands.trigger_targets(
+        GameServerPacket::from(recipe_query.book_item_list(entity, packet.0)?),
+        entity,
+    );
+    Ok(())
+}
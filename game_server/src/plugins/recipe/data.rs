@@ -0,0 +1,74 @@
+use bevy::{log, prelude::*};
+use game_core::recipe::*;
+use l2r_core::chronicles::CHRONICLE;
+use state::{GameServerStateSystems, LoadingSystems};
+use std::path::PathBuf;
+
+pub struct RecipesDataPlugin;
+impl Plugin for RecipesDataPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RecipesHandle>();
+
+        app.add_systems(
+            Update,
+            (
+                load_assets.in_set(LoadingSystems::AssetInit),
+                update_assets.in_set(LoadingSystems::AssetInit),
+            ),
+        );
+
+        app.add_systems(Update, update_assets.in_set(GameServerStateSystems::Run));
+    }
+}
+
+fn load_assets(
+    asset_server: Res<AssetServer>,
+    mut recipes_handle: ResMut<RecipesHandle>,
+    mut loaded: Local<bool>,
+) {
+    if *loaded {
+        return;
+    }
+    let mut path = PathBuf::from("recipe");
+    path.push(CHRONICLE);
+    path.push("recipes");
+    path.set_extension("json");
+
+    let handle: Handle<Recipes> = asset_server.load(path.clone());
+    **recipes_handle = handle;
+    *loaded = true;
+}
+
+fn update_assets(handle: Res<RecipesHandle>, mut events: EventReader<AssetEvent<Recipes>>) {
+    for event in events.read() {
+        if event.is_loaded_with_dependencies(handle.id()) {
+            log::info!("Recipes updated");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use l2r_core::{assets::ASSET_DIR, chronicles::CHRONICLE, utils::get_base_path};
+    use serde_json::from_reader;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_parse_all_from_json() {
+        let mut path = get_base_path();
+        path.push(ASSET_DIR);
+        path.push("recipe");
+        path.push(CHRONICLE);
+        path.push("recipes");
+        path.set_extension("json");
+
+        let file = File::open(&path).unwrap_or_else(|_| panic!("Failed to open file: {:?}", path));
+        let reader = BufReader::new(file);
+
+        let result: Recipes = from_reader(reader)
+            .unwrap_or_else(|_| panic!("Failed to parse recipes from JSON: {:?}", path));
+
+        assert!(!result.is_empty());
+    }
+}
@@ -0,0 +1,141 @@
+use super::RecipeQuery;
+use bevy::{log, prelude::*};
+use bevy_defer::AsyncCommandsExtension;
+use game_core::{
+    items::DestroyItemRequest,
+    network::packets::server::{GameServerPacket, SystemMessage},
+    recipe::{self, RecipeBook, RecipeBookError, RecipeBookKind, UseRecipe},
+};
+use l2r_core::db::{
+    PrimaryKeyColumns, Repository, RepositoryManager, TypedRepositoryManager, UpdatableModel,
+};
+use sea_orm::sea_query::OnConflict;
+use system_messages::{Id, SmParam};
+
+pub(crate) struct UseRecipePlugin;
+impl Plugin for UseRecipePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    use_recipe: Trigger<UseRecipe>,
+    repo_manager: Res<RepositoryManager>,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let entity = use_recipe.target();
+    let UseRecipe {
+        item_object_id,
+        item_id,
+    } = *use_recipe.event();
+
+    let send_message = |commands: &mut Commands, message: SystemMessage| {
+        commands.trigger_targets(GameServerPacket::from(message), entity);
+    };
+
+    let Some((recipe_id, info)) = recipe_query
+        .recipes()?
+        .by_item(item_id)
+        .map(|(id, info)| (id, info.clone()))
+    else {
+        send_message(
+            &mut commands,
+            SystemMessage::new_empty(Id::TheRecipeIsIncorrect),
+        );
+        return Ok(());
+    };
+
+    if recipe_query.manufacturing(entity) {
+        send_message(
+            &mut commands,
+            SystemMessage::new_empty(Id::YouMayNotAlterYourRecipeBookWhileEngagedInManufacturing),
+        );
+        return Ok(());
+    }
+
+    let craft_level = recipe_query.craft_level(entity, info.kind)?;
+    if craft_level == 0 {
+        send_message(
+            &mut commands,
+            SystemMessage::new_empty(
+                Id::TheRecipeCannotBeRegisteredYouDoNotHaveTheAbilityToCreateItems,
+            ),
+        );
+        return Ok(());
+    }
+    if craft_level < info.level {
+        send_message(
+            &mut commands,
+            SystemMessage::new_empty(Id::YourCreateItemLevelIsTooLowToRegisterThisRecipe),
+        );
+        return Ok(());
+    }
+
+    let mut book = recipe_query.book(entity)?;
+    match book.register(info.kind, recipe_id) {
+        Ok(()) => {}
+        Err(RecipeBookError::AlreadyRegistered) => {
+            send_message(
+                &mut commands,
+                SystemMessage::new_empty(Id::ThatRecipeIsAlreadyRegistered),
+            );
+            return Ok(());
+        }
+        Err(RecipeBookError::BookIsFull) => {
+            send_message(
+                &mut commands,
+                SystemMessage::new(
+                    Id::UpToS1RecipesCanBeRegistered,
+                    vec![SmParam::Number(RecipeBook::limit(info.kind) as u32)],
+                ),
+            );
+            return Ok(());
+        }
+    }
+
+    commands.trigger_targets(
+        DestroyItemRequest {
+            item_oid: item_object_id,
+            count: 1,
+        },
+        entity,
+    );
+    commands.entity(entity).insert(book);
+
+    send_message(
+        &mut commands,
+        SystemMessage::new(Id::S1HasBeenAdded, vec![SmParam::Item(*item_id)]),
+    );
+    commands.trigger_targets(
+        GameServerPacket::from(recipe_query.book_item_list(entity, info.kind)?),
+        entity,
+    );
+
+    let char_id = recipe_query.char_id(entity)?;
+    let recipe_repository =
+        repo_manager.typed::<recipe::model::RecipePK, recipe::model::Entity>()?;
+    let recipe_model = recipe::model::Model {
+        char_id,
+        recipe_id,
+        dwarven: info.kind == RecipeBookKind::Dwarven,
+    };
+
+    commands.spawn_task(move || async move {
+        let result = recipe_repository
+            .create_or_update(
+                &recipe_model,
+                OnConflict::columns(recipe::model::Model::pk_columns().to_vec())
+                    .update_columns(recipe::model::Model::update_columns().to_vec())
+                    .to_owned(),
+            )
+            .await;
+        if let Err(err) = result {
+            log::error!("Character: {}, Error saving recipe: {:?}", char_id, err);
+            return Err(err.into());
+        }
+        Ok(())
+    });
+    Ok(())
+}
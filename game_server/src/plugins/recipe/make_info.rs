@@ -0,0 +1,47 @@
+use super::RecipeQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{
+        client::GameClientPacket,
+        server::{ActionFail, CraftResult, GameServerPacket},
+    },
+    session::PacketReceiveParams,
+};
+
+pub(crate) struct RequestRecipeItemMakeInfoPlugin;
+impl Plugin for RequestRecipeItemMakeInfoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestRecipeItemMakeInfo(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+
+    let make_info = recipe_query
+        .item_make_info(entity, packet.0, CraftResult::Info)?
+        .filter(|_| {
+            recipe_query
+                .book(entity)
+                .is_ok_and(|book| book.contains(packet.0))
+        });
+
+    let packet = match make_info {
+        Some(make_info) => GameServerPacket::from(make_info),
+        None => GameServerPacket::from(ActionFail),
+    };
+    commands.trigger_targets(packet, entity);
+    Ok(())
+}
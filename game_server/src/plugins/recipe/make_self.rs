@@ -0,0 +1,53 @@
+use super::{CraftOrder, RecipeQuery};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{
+        client::GameClientPacket,
+        server::{CraftResult, GameServerPacket, SystemMessage},
+    },
+    session::PacketReceiveParams,
+};
+use system_messages::Id;
+
+pub(crate) struct RequestRecipeItemMakeSelfPlugin;
+impl Plugin for RequestRecipeItemMakeSelfPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestRecipeItemMakeSelf(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let recipe_id = packet.0;
+
+    if recipe_query.manufacturing(entity) {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(
+                Id::YouMayNotAlterYourRecipeBookWhileEngagedInManufacturing,
+            )),
+            entity,
+        );
+        return Ok(());
+    }
+
+    let result = recipe_query
+        .craft(entity, recipe_id, CraftOrder::Own, &mut commands)?
+        .unwrap_or(CraftResult::Info);
+
+    if let Some(make_info) = recipe_query.item_make_info(entity, recipe_id, result)? {
+        commands.trigger_targets(GameServerPacket::from(make_info), entity);
+    }
+    Ok(())
+}
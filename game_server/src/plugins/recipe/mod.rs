@@ -0,0 +1,396 @@
+use bevy::{ecs::system::SystemParam, log, prelude::*};
+use bevy_defer::{AccessError, AsyncAccess, AsyncWorld};
+use game_core::{
+    character::Character,
+    items::{ADENA_ID, DestroyItemRequest, Inventory, Item, ItemLocation, SpawnNew},
+    network::packets::server::{
+        CraftResult, GameServerPacket, RecipeBookItemList, RecipeItemMakeInfo, SystemMessage,
+    },
+    object_id::{ObjectId, ObjectIdManager},
+    recipe::{
+        self, RecipeBook, RecipeBookKind, RecipeComponentsPlugin, RecipeInfo, RecipeShop, Recipes,
+        RecipesHandle,
+    },
+    skills::SkillList,
+    stats::{VitalsStat, VitalsStats},
+};
+use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
+use rand::Rng;
+use sea_orm::ColumnTrait;
+use system_messages::{Id, SmParam};
+
+mod book_destroy;
+mod book_open;
+mod data;
+mod learn;
+mod make_info;
+mod make_self;
+mod shop_make_info;
+mod shop_make_item;
+mod shop_manage;
+mod shop_visit;
+
+/// Recipes are learned from recipe items into the dwarven or common recipe book,
+/// crafting consumes MP and materials and succeeds with the recipe success rate.
+/// Manufacture shops let one player craft for another at a posted fee.
+/// uses [`RecipeBookItemList`], [`RecipeItemMakeInfo`], [`RecipeShopManageList`], [`RecipeShopSellList`],
+/// [`RecipeShopItemInfo`], [`RecipeShopMsg`] server packets
+/// alongside with clients: [`RequestRecipeBookOpen`], [`RequestRecipeBookDestroy`], [`RequestRecipeItemMakeInfo`],
+/// [`RequestRecipeItemMakeSelf`], [`RequestRecipeShopListSet`], [`RequestRecipeShopMakeItem`] and others
+pub(crate) struct RecipePlugin;
+impl Plugin for RecipePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RecipeComponentsPlugin)
+            .add_plugins(data::RecipesDataPlugin)
+            .add_plugins(learn::UseRecipePlugin)
+            .add_plugins(book_open::RequestRecipeBookOpenPlugin)
+            .add_plugins(book_destroy::RequestRecipeBookDestroyPlugin)
+            .add_plugins(make_info::RequestRecipeItemMakeInfoPlugin)
+            .add_plugins(make_self::RequestRecipeItemMakeSelfPlugin)
+            .add_plugins(shop_manage::RecipeShopManagePlugin)
+            .add_plugins(shop_visit::RecipeShopVisitPlugin)
+            .add_plugins(shop_make_info::RequestRecipeShopMakeInfoPlugin)
+            .add_plugins(shop_make_item::RequestRecipeShopMakeItemPlugin);
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct RecipeQuery<'w, 's> {
+    characters: Query<
+        'w,
+        's,
+        (
+            Ref<'static, ObjectId>,
+            Ref<'static, Name>,
+            Ref<'static, RecipeBook>,
+            Ref<'static, SkillList>,
+            Ref<'static, Inventory>,
+        ),
+        With<Character>,
+    >,
+    shops: Query<'w, 's, Ref<'static, RecipeShop>>,
+    vitals: Query<'w, 's, Mut<'static, VitalsStats>, With<Character>>,
+    items: Query<'w, 's, Ref<'static, Item>>,
+    object_id_manager: Res<'w, ObjectIdManager>,
+    recipes_handle: Res<'w, RecipesHandle>,
+    recipes_assets: Res<'w, Assets<Recipes>>,
+    items_spawn: EventWriter<'w, SpawnNew>,
+}
+
+/// Who pays for the craft: the crafter itself or a buyer in the manufacture shop.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum CraftOrder {
+    Own,
+    Shop { buyer: Entity, fee: u64 },
+}
+
+impl RecipeQuery<'_, '_> {
+    pub fn recipes(&self) -> Result<&Recipes> {
+        self.recipes_assets
+            .get(self.recipes_handle.id())
+            .ok_or_else(|| BevyError::from("Recipes are not loaded"))
+    }
+
+    pub fn recipe(&self, id: recipe::Id) -> Result<Option<RecipeInfo>> {
+        Ok(self.recipes()?.get(&id).cloned())
+    }
+
+    pub fn char_id(&self, entity: Entity) -> Result<ObjectId> {
+        Ok(*self.characters.get(entity)?.0)
+    }
+
+    pub fn name(&self, entity: Entity) -> Result<String> {
+        Ok(self.characters.get(entity)?.1.to_string())
+    }
+
+    pub fn book(&self, entity: Entity) -> Result<RecipeBook> {
+        Ok(self.characters.get(entity)?.2.clone())
+    }
+
+    pub fn craft_level(&self, entity: Entity, kind: RecipeBookKind) -> Result<u32> {
+        Ok(RecipeBook::craft_level(
+            kind,
+            &self.characters.get(entity)?.3,
+        ))
+    }
+
+    pub fn shop(&self, entity: Entity) -> Option<RecipeShop> {
+        self.shops.get(entity).ok().map(|shop| shop.clone())
+    }
+
+    /// Character has an opened manufacture shop.
+    pub fn manufacturing(&self, entity: Entity) -> bool {
+        self.shops.get(entity).is_ok_and(|shop| shop.opened)
+    }
+
+    pub fn entity(&self, object_id: ObjectId) -> Option<Entity> {
+        self.object_id_manager.entity(object_id)
+    }
+
+    /// Current and max MP of the character.
+    pub fn mp(&self, entity: Entity) -> Result<(u32, u32)> {
+        let vitals = self.vitals.get(entity)?;
+        Ok((
+            vitals.get(VitalsStat::Mp) as u32,
+            vitals.get(VitalsStat::MaxMp) as u32,
+        ))
+    }
+
+    /// Object id and count of the item stack in character inventory.
+    pub fn inventory_item(
+        &self,
+        entity: Entity,
+        item_id: game_core::items::Id,
+    ) -> Option<(ObjectId, u64)> {
+        let (_, _, _, _, inventory) = self.characters.get(entity).ok()?;
+        let (item_entity, object_id, _) =
+            inventory.single_by_item_id(item_id, &self.items, self.object_id_manager.as_ref())?;
+        let item = self.items.get(item_entity).ok()?;
+        Some((object_id, item.count()))
+    }
+
+    pub fn adena(&self, entity: Entity) -> u64 {
+        self.inventory_item(entity, ADENA_ID)
+            .map(|(_, count)| count)
+            .unwrap_or_default()
+    }
+
+    pub fn book_item_list(
+        &self,
+        entity: Entity,
+        kind: RecipeBookKind,
+    ) -> Result<RecipeBookItemList> {
+        let (_, max_mp) = self.mp(entity)?;
+        let recipes = self.book(entity)?.recipes(kind).to_vec();
+        Ok(RecipeBookItemList::new(kind, max_mp, recipes))
+    }
+
+    pub fn item_make_info(
+        &self,
+        entity: Entity,
+        id: recipe::Id,
+        result: CraftResult,
+    ) -> Result<Option<RecipeItemMakeInfo>> {
+        let Some(info) = self.recipe(id)? else {
+            return Ok(None);
+        };
+        let (current_mp, max_mp) = self.mp(entity)?;
+        Ok(Some(RecipeItemMakeInfo::new(
+            id, info.kind, current_mp, max_mp, result,
+        )))
+    }
+
+    /// Checks crafting requirements, takes MP, materials and fee, and rolls the success.
+    /// Returns `None` if crafting didn't start, the reason is already sent to the characters.
+    pub fn craft(
+        &mut self,
+        crafter: Entity,
+        id: recipe::Id,
+        order: CraftOrder,
+        commands: &mut Commands,
+    ) -> Result<Option<CraftResult>> {
+        let buyer = match order {
+            CraftOrder::Own => crafter,
+            CraftOrder::Shop { buyer, .. } => buyer,
+        };
+        let fail = |commands: &mut Commands, message: Id| -> Result<Option<CraftResult>> {
+            commands.trigger_targets(
+                GameServerPacket::from(SystemMessage::new_empty(message)),
+                buyer,
+            );
+            Ok(None)
+        };
+
+        let Some(info) = self.recipe(id)? else {
+            return fail(commands, Id::TheRecipeIsIncorrect);
+        };
+
+        if !self.book(crafter)?.contains(id) || self.craft_level(crafter, info.kind)? < info.level {
+            return fail(commands, Id::TheRecipeIsIncorrect);
+        }
+
+        if info.materials.is_empty() {
+            log::warn!("Recipe {} ({}) has no materials", id, info.name);
+            return fail(commands, Id::TheRecipeIsIncorrect);
+        }
+
+        let (current_mp, _) = self.mp(crafter)?;
+        if current_mp < info.mp_cost {
+            return fail(commands, Id::NotEnoughMp);
+        }
+
+        let mut materials = Vec::with_capacity(info.materials.len());
+        for material in info.materials.iter() {
+            match self.inventory_item(buyer, material.id) {
+                Some((object_id, count)) if count >= material.count => {
+                    materials.push((object_id, material.count));
+                }
+                _ => return fail(commands, Id::YouDoNotHaveEnoughMaterialsToPerformThatAction),
+            }
+        }
+
+        let fee = match order {
+            CraftOrder::Shop { fee, .. } if fee > 0 => match self.inventory_item(buyer, ADENA_ID) {
+                Some((adena_oid, count)) if count >= fee => Some((adena_oid, fee)),
+                _ => return fail(commands, Id::YouDoNotHaveEnoughAdena),
+            },
+            _ => None,
+        };
+
+        let mut vitals = self.vitals.get_mut(crafter)?;
+        let mp = vitals.get(VitalsStat::Mp);
+        vitals.insert(VitalsStat::Mp, mp - info.mp_cost as f32);
+
+        for (item_oid, count) in materials {
+            commands.trigger_targets(DestroyItemRequest { item_oid, count }, buyer);
+        }
+
+        if let Some((adena_oid, fee)) = fee {
+            commands.trigger_targets(
+                DestroyItemRequest {
+                    item_oid: adena_oid,
+                    count: fee,
+                },
+                buyer,
+            );
+            self.items_spawn.write(SpawnNew {
+                item_ids: vec![ADENA_ID],
+                count: fee,
+                item_location: ItemLocation::Inventory,
+                dropped_entity: None,
+                owner: Some(crafter),
+                silent: false,
+            });
+        }
+
+        let success = rand::thread_rng().gen_range(0..100) < info.success_rate;
+        if success {
+            self.items_spawn.write(SpawnNew {
+                item_ids: vec![info.product.id],
+                count: info.product.count,
+                item_location: ItemLocation::Inventory,
+                dropped_entity: None,
+                owner: Some(buyer),
+                silent: false,
+            });
+        }
+
+        self.send_craft_messages(crafter, order, &info, success, commands)?;
+
+        Ok(Some(if success {
+            CraftResult::Success
+        } else {
+            CraftResult::Failure
+        }))
+    }
+
+    fn send_craft_messages(
+        &self,
+        crafter: Entity,
+        order: CraftOrder,
+        info: &RecipeInfo,
+        success: bool,
+        commands: &mut Commands,
+    ) -> Result<()> {
+        let product = SmParam::Item(*info.product.id);
+        let messages = match order {
+            CraftOrder::Own if success => vec![],
+            CraftOrder::Own => vec![(
+                crafter,
+                SystemMessage::new(Id::YouFailedToManufactureS1, vec![product]),
+            )],
+            CraftOrder::Shop { buyer, fee } => {
+                let crafter_name = SmParam::Player(self.name(crafter)?);
+                let buyer_name = SmParam::Player(self.name(buyer)?);
+                let fee = SmParam::LongNumber(fee);
+                if success {
+                    vec![
+                        (
+                            crafter,
+                            SystemMessage::new(
+                                Id::S2HasBeenCreatedForC1AfterThePaymentOfS3AdenaWasReceived,
+                                vec![buyer_name, product.clone(), fee.clone()],
+                            ),
+                        ),
+                        (
+                            buyer,
+                            SystemMessage::new(
+                                Id::C1CreatedS2AfterReceivingS3Adena,
+                                vec![crafter_name, product, fee],
+                            ),
+                        ),
+                    ]
+                } else {
+                    vec![
+                        (
+                            crafter,
+                            SystemMessage::new(
+                                Id::YourAttemptToCreateS2ForC1AtThePriceOfS3AdenaHasFailed,
+                                vec![buyer_name, product.clone(), fee.clone()],
+                            ),
+                        ),
+                        (
+                            buyer,
+                            SystemMessage::new(
+                                Id::C1HasFailedToCreateS2AtThePriceOfS3Adena,
+                                vec![crafter_name, product, fee],
+                            ),
+                        ),
+                    ]
+                }
+            }
+        };
+        for (entity, message) in messages {
+            commands.trigger_targets(GameServerPacket::from(message), entity);
+        }
+        Ok(())
+    }
+}
+
+pub async fn recipe_book_init_task(char_entity: Entity) -> Result<(), AccessError> {
+    let Ok(recipe_repository) = AsyncWorld
+        .resource::<RepositoryManager>()
+        .get(|registry| registry.typed::<recipe::model::RecipePK, recipe::model::Entity>())?
+    else {
+        return Ok(());
+    };
+
+    let char_id = AsyncWorld
+        .entity(char_entity)
+        .component::<ObjectId>()
+        .get(|component| *component)?;
+
+    let result = recipe_repository
+        .find_with_conditions([recipe::model::Column::CharId.eq(char_id)])
+        .await;
+
+    if let Err(err) = result {
+        log::error!("Character: {}, Error loading recipes: {:?}", char_id, err);
+        return Err(err.into());
+    }
+
+    let mut book = RecipeBook::default();
+    for model in result.unwrap_or_default() {
+        let kind = if model.dwarven {
+            RecipeBookKind::Dwarven
+        } else {
+            RecipeBookKind::Common
+        };
+        if let Err(err) = book.register(kind, model.recipe_id) {
+            log::warn!(
+                "Character: {}, Skipping recipe {}: {}",
+                char_id,
+                model.recipe_id,
+                err
+            );
+        }
+    }
+
+    AsyncWorld.apply_command(move |world: &mut World| {
+        if let Ok(mut character) = world.get_entity_mut(char_entity) {
+            character.insert(book);
+        }
+    });
+
+    Ok(())
+}
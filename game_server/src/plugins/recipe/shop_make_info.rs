@@ -0,0 +1,55 @@
+use super::RecipeQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{
+        client::GameClientPacket,
+        server::{ActionFail, CraftResult, GameServerPacket, RecipeShopItemInfo},
+    },
+    session::PacketReceiveParams,
+};
+
+pub(crate) struct RequestRecipeShopMakeInfoPlugin;
+impl Plugin for RequestRecipeShopMakeInfoPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestRecipeShopMakeInfo(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+
+    let seller = recipe_query.entity(packet.seller).filter(|seller| {
+        recipe_query
+            .shop(*seller)
+            .is_some_and(|shop| shop.opened && shop.fee(packet.recipe_id).is_some())
+    });
+    let Some(seller) = seller else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+
+    let (current_mp, max_mp) = recipe_query.mp(seller)?;
+    commands.trigger_targets(
+        GameServerPacket::from(RecipeShopItemInfo::new(
+            packet.seller,
+            packet.recipe_id,
+            current_mp,
+            max_mp,
+            CraftResult::Info,
+        )),
+        entity,
+    );
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use super::{CraftOrder, RecipeQuery};
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::network::{
+    config::GameServerNetworkConfig,
+    packets::{
+        client::GameClientPacket,
+        server::{ActionFail, CraftResult, GameServerPacket, RecipeShopItemInfo, SystemMessage},
+    },
+    session::PacketReceiveParams,
+};
+use system_messages::Id;
+
+pub(crate) struct RequestRecipeShopMakeItemPlugin;
+impl Plugin for RequestRecipeShopMakeItemPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestRecipeShopMakeItem(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let buyer = receive_params.character(&event.connection.id())?;
+
+    let Some(seller) = recipe_query
+        .entity(packet.seller)
+        .filter(|seller| *seller != buyer)
+    else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), buyer);
+        return Ok(());
+    };
+
+    let Some(shop) = recipe_query.shop(seller).filter(|shop| shop.opened) else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), buyer);
+        return Ok(());
+    };
+
+    let Some(fee) = shop.fee(packet.recipe_id) else {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(Id::TheRecipeIsIncorrect)),
+            buyer,
+        );
+        return Ok(());
+    };
+
+    if fee != packet.fee {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(
+                Id::TheOwnerOfThePrivateManufacturingStoreHasChangedThePriceForCreatingThisItemPleaseCheckTheNewPriceBeforeTryingAgain,
+            )),
+            buyer,
+        );
+        return Ok(());
+    }
+
+    let result = recipe_query
+        .craft(
+            seller,
+            packet.recipe_id,
+            CraftOrder::Shop { buyer, fee },
+            &mut commands,
+        )?
+        .unwrap_or(CraftResult::Info);
+
+    let (current_mp, max_mp) = recipe_query.mp(seller)?;
+    commands.trigger_targets(
+        GameServerPacket::from(RecipeShopItemInfo::new(
+            packet.seller,
+            packet.recipe_id,
+            current_mp,
+            max_mp,
+            result,
+        )),
+        buyer,
+    );
+    Ok(())
+}
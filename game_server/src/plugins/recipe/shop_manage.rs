@@ -0,0 +1,232 @@
+use super::RecipeQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    action::{
+        model::CoreAction,
+        wait_kind::{Sit, WaitKind},
+    },
+    network::{
+        broadcast::ServerPacketBroadcast,
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{
+                ActionFail, BroadcastCharInfo, GameServerPacket, RecipeShopManageList,
+                RecipeShopMsg, SendUserInfo, SystemMessage,
+            },
+        },
+        session::PacketReceiveParams,
+    },
+    recipe::{MAX_SHOP_MESSAGE_LENGTH, RecipeBookKind, RecipeShop, RecipeShopEntry},
+};
+use system_messages::Id;
+
+pub(crate) struct RecipeShopManagePlugin;
+impl Plugin for RecipeShopManagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle_action)
+            .add_observer(handle_manage_list)
+            .add_observer(handle_message_set)
+            .add_observer(handle_list_set)
+            .add_observer(handle_quit);
+
+        app.add_systems(Update, close_shop_on_stand);
+    }
+}
+
+fn handle_action(
+    action: Trigger<CoreAction>,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let kind = match action.event() {
+        CoreAction::DwarvenManufacture => RecipeBookKind::Dwarven,
+        CoreAction::GeneralManufacture => RecipeBookKind::Common,
+        _ => return Ok(()),
+    };
+    send_manage_list(action.target(), kind, &recipe_query, &mut commands)
+}
+
+fn handle_manage_list(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestRecipeShopManageList = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let kind = recipe_query
+        .shop(entity)
+        .map(|shop| shop.kind)
+        .unwrap_or_default();
+    send_manage_list(entity, kind, &recipe_query, &mut commands)
+}
+
+/// Closes an opened shop and sends the setup window for the given recipe book.
+fn send_manage_list(
+    entity: Entity,
+    kind: RecipeBookKind,
+    recipe_query: &RecipeQuery,
+    commands: &mut Commands,
+) -> Result<()> {
+    let recipes = recipe_query.book(entity)?.recipes(kind).to_vec();
+    if recipes.is_empty() {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(Id::NoRecipesHaveBeenRegistered)),
+            entity,
+        );
+        return Ok(());
+    }
+
+    let mut shop = recipe_query.shop(entity).unwrap_or_default();
+    if shop.opened {
+        shop.opened = false;
+        close_shop(entity, commands);
+    }
+    if shop.kind != kind {
+        shop.kind = kind;
+        shop.entries.clear();
+    }
+
+    commands.trigger_targets(
+        GameServerPacket::from(RecipeShopManageList::new(
+            recipe_query.char_id(entity)?,
+            recipe_query.adena(entity),
+            kind,
+            recipes,
+            shop.entries.clone(),
+        )),
+        entity,
+    );
+    commands.entity(entity).insert(shop);
+    Ok(())
+}
+
+fn handle_message_set(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestRecipeShopMessageSet(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let mut shop = recipe_query.shop(entity).unwrap_or_default();
+    shop.message = packet.0.chars().take(MAX_SHOP_MESSAGE_LENGTH).collect();
+
+    if shop.opened {
+        let shop_msg = RecipeShopMsg::new(recipe_query.char_id(entity)?, shop.message.clone());
+        commands.trigger_targets(ServerPacketBroadcast::new(shop_msg.into()), entity);
+    }
+    commands.entity(entity).insert(shop);
+    Ok(())
+}
+
+fn handle_list_set(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestRecipeShopListSet(ref packet) = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let mut shop = recipe_query.shop(entity).unwrap_or_default();
+
+    if packet.entries.is_empty() {
+        shop.opened = false;
+        shop.entries.clear();
+        commands.entity(entity).insert(shop);
+        close_shop(entity, &mut commands);
+        return Ok(());
+    }
+
+    let book = recipe_query.book(entity)?;
+    let recipes = book.recipes(shop.kind);
+    if packet.entries.iter().any(|(id, _)| !recipes.contains(id)) {
+        commands.trigger_targets(
+            GameServerPacket::from(SystemMessage::new_empty(Id::TheRecipeIsIncorrect)),
+            entity,
+        );
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    shop.entries = packet
+        .entries
+        .iter()
+        .map(|(id, fee)| RecipeShopEntry { id: *id, fee: *fee })
+        .collect();
+    shop.opened = true;
+
+    commands
+        .entity(entity)
+        .try_insert(WaitKind::Sit)
+        .try_insert(Sit);
+    commands.trigger_targets(SendUserInfo, entity);
+    commands.trigger_targets(BroadcastCharInfo, entity);
+
+    let shop_msg = RecipeShopMsg::new(recipe_query.char_id(entity)?, shop.message.clone());
+    commands.trigger_targets(ServerPacketBroadcast::new(shop_msg.into()), entity);
+    commands.entity(entity).insert(shop);
+    Ok(())
+}
+
+fn handle_quit(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    if !matches!(
+        event.packet,
+        GameClientPacket::RequestRecipeShopManageQuit
+            | GameClientPacket::RequestRecipeShopManageCancel
+    ) {
+        return Ok(());
+    }
+
+    let entity = receive_params.character(&event.connection.id())?;
+    let Some(mut shop) = recipe_query.shop(entity).filter(|shop| shop.opened) else {
+        return Ok(());
+    };
+    shop.opened = false;
+    commands.entity(entity).insert(shop);
+    close_shop(entity, &mut commands);
+    Ok(())
+}
+
+fn close_shop(entity: Entity, commands: &mut Commands) {
+    commands
+        .entity(entity)
+        .remove::<Sit>()
+        .try_insert(WaitKind::Stand);
+    commands.trigger_targets(SendUserInfo, entity);
+    commands.trigger_targets(BroadcastCharInfo, entity);
+}
+
+/// Crafter stood up, so the shop is closed.
+fn close_shop_on_stand(
+    mut commands: Commands,
+    mut shops: Query<(Entity, Mut<RecipeShop>), Without<Sit>>,
+) {
+    for (entity, mut shop) in shops.iter_mut() {
+        if shop.opened {
+            shop.opened = false;
+            commands.trigger_targets(SendUserInfo, entity);
+            commands.trigger_targets(BroadcastCharInfo, entity);
+        }
+    }
+}
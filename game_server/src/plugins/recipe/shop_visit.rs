@@ -0,0 +1,70 @@
+use super::RecipeQuery;
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    action::target::SelectedTarget,
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{ActionFail, GameServerPacket, RecipeShopSellList},
+        },
+        session::PacketReceiveParams,
+    },
+    recipe::RecipeShopVisit,
+};
+
+pub(crate) struct RecipeShopVisitPlugin;
+impl Plugin for RecipeShopVisitPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle_visit)
+            .add_observer(handle_manage_prev);
+    }
+}
+
+fn handle_visit(
+    visit: Trigger<RecipeShopVisit>,
+    recipe_query: RecipeQuery,
+    mut commands: Commands,
+) -> Result<()> {
+    let buyer = visit.target();
+    let seller = **visit.event();
+
+    let Some(shop) = recipe_query.shop(seller).filter(|shop| shop.opened) else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), buyer);
+        return Ok(());
+    };
+
+    let (current_mp, max_mp) = recipe_query.mp(seller)?;
+    commands.trigger_targets(
+        GameServerPacket::from(RecipeShopSellList::new(
+            recipe_query.char_id(seller)?,
+            current_mp,
+            max_mp,
+            recipe_query.adena(buyer),
+            shop.entries,
+        )),
+        buyer,
+    );
+    Ok(())
+}
+
+/// Buyer returns from the craft window to the list of the shop recipes.
+fn handle_manage_prev(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    selected_targets: Query<Ref<SelectedTarget>>,
+    mut commands: Commands,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestRecipeShopManagePrev = event.packet else {
+        return Ok(());
+    };
+
+    let entity = receive_params.character(&event.connection.id())?;
+    match selected_targets.get(entity) {
+        Ok(target) => commands.trigger_targets(RecipeShopVisit(**target), entity),
+        Err(_) => commands.trigger_targets(GameServerPacket::from(ActionFail), entity),
+    }
+    Ok(())
+}
@@ -0,0 +1,297 @@
+use crate::plugins::castle::siege::announce;
+use bevy::{ecs::system::SystemParam, log, prelude::*, time::common_conditions::on_timer};
+use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use chrono::{NaiveDateTime, Utc};
+use game_core::{
+    character::Character,
+    network::{
+        broadcast::{BroadcastScope, ServerPacketBroadcast},
+        packets::server::{SSQInfo, SystemMessage},
+    },
+    object_id::ObjectId,
+    seven_signs::{
+        self, Cabal, Participant, Seal, SevenSigns, SevenSignsComponentsPlugin, SevenSignsError,
+        SsqPeriod, participant,
+    },
+};
+use l2r_core::db::{
+    DbConnection, PrimaryKeyColumns, Repository, RepositoryManager, TypedRepositoryManager,
+    UpdatableModel,
+};
+use sea_orm::{Condition, Iterable, sea_query::OnConflict};
+use state::{GameServerStateSystems, LoadingSystems};
+use std::time::Duration;
+use system_messages::Id as SmId;
+
+mod status;
+
+/// Seven Signs cycle of a week long competition between the Lords of Dawn and the
+/// Revolutionaries of Dusk, followed by a week long seal validation period when the winners
+/// own the seals. Periods change on monday at 18:00.
+pub(crate) struct SevenSignsPlugin;
+impl Plugin for SevenSignsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(SevenSignsComponentsPlugin)
+            .add_plugins(status::RequestSsqStatusPlugin);
+
+        app.add_systems(Update, load_seven_signs.in_set(LoadingSystems::IdInit));
+
+        app.add_systems(
+            Update,
+            update_period
+                .run_if(resource_exists::<SevenSigns>)
+                .run_if(on_timer(Duration::from_secs(1)))
+                .in_set(GameServerStateSystems::Run),
+        );
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct SevenSignsQuery<'w, 's> {
+    seven_signs: ResMut<'w, SevenSigns>,
+    characters: Query<'w, 's, Ref<'static, ObjectId>, With<Character>>,
+    repo_manager: Res<'w, RepositoryManager>,
+}
+
+impl SevenSignsQuery<'_, '_> {
+    pub fn seven_signs(&self) -> &SevenSigns {
+        &self.seven_signs
+    }
+
+    pub fn char_id(&self, entity: Entity) -> Result<ObjectId> {
+        Ok(*self.characters.get(entity)?)
+    }
+
+    pub fn join(
+        &mut self,
+        commands: &mut Commands,
+        entity: Entity,
+        cabal: Cabal,
+        seal: Seal,
+        castle_owner: bool,
+    ) -> Result<Result<(), SevenSignsError>> {
+        let char_id = self.char_id(entity)?;
+        let participant = match self.seven_signs.join(char_id, cabal, seal, castle_owner) {
+            Ok(participant) => *participant,
+            Err(err) => return Ok(Err(err)),
+        };
+        self.save_participant(commands, participant)?;
+        Ok(Ok(()))
+    }
+
+    /// Seal stones are added to the score of the character side, returns the points they gave.
+    pub fn contribute(
+        &mut self,
+        commands: &mut Commands,
+        entity: Entity,
+        stones: [u64; 3],
+    ) -> Result<Result<u64, SevenSignsError>> {
+        let char_id = self.char_id(entity)?;
+        let points = match self.seven_signs.contribute(char_id, stones) {
+            Ok(points) => points,
+            Err(err) => return Ok(Err(err)),
+        };
+        if let Some(participant) = self.seven_signs.participant(char_id).copied() {
+            self.save_participant(commands, participant)?;
+        }
+        Ok(Ok(points))
+    }
+
+    /// Ancient adena earned by the character of the winning side, to be given to it.
+    pub fn collect_reward(
+        &mut self,
+        commands: &mut Commands,
+        entity: Entity,
+    ) -> Result<Result<u64, SevenSignsError>> {
+        let char_id = self.char_id(entity)?;
+        let reward = match self.seven_signs.collect_reward(char_id) {
+            Ok(reward) => reward,
+            Err(err) => return Ok(Err(err)),
+        };
+        if let Some(participant) = self.seven_signs.participant(char_id).copied() {
+            self.save_participant(commands, participant)?;
+        }
+        Ok(Ok(reward))
+    }
+
+    fn next_period(&mut self, commands: &mut Commands, now: NaiveDateTime) -> Result<SsqPeriod> {
+        let period = self.seven_signs.next_period(now);
+
+        let state = self.seven_signs.state().clone();
+        let state_repository = self
+            .repo_manager
+            .typed::<i32, seven_signs::model::Entity>()?;
+        let participants_repository = self
+            .repo_manager
+            .typed::<ObjectId, participant::model::Entity>()?;
+        commands.spawn_task(move || async move {
+            if state.period == SsqPeriod::Competition {
+                participants_repository.delete_many(|query| query).await?;
+            }
+            if let Err(err) = state_repository
+                .create_or_update(&state, state_on_conflict())
+                .await
+            {
+                log::error!("Error saving seven signs state: {:?}", err);
+                return Err(err.into());
+            }
+            Ok(())
+        });
+        Ok(period)
+    }
+
+    fn save_participant(&self, commands: &mut Commands, participant: Participant) -> Result<()> {
+        let repository = self
+            .repo_manager
+            .typed::<ObjectId, participant::model::Entity>()?;
+        commands.spawn_task(move || async move {
+            if let Err(err) = repository
+                .create_or_update(&participant, participant_on_conflict())
+                .await
+            {
+                log::error!(
+                    "Character: {}, Error saving seven signs participant: {:?}",
+                    participant.char_id,
+                    err
+                );
+                return Err(err.into());
+            }
+            Ok(())
+        });
+        Ok(())
+    }
+}
+
+fn state_on_conflict() -> OnConflict {
+    OnConflict::columns(seven_signs::model::Model::pk_columns().to_vec())
+        .update_columns(seven_signs::model::Model::update_columns().to_vec())
+        .to_owned()
+}
+
+fn participant_on_conflict() -> OnConflict {
+    OnConflict::columns(Participant::pk_columns().to_vec())
+        .update_columns(Participant::update_columns().to_vec())
+        .to_owned()
+}
+
+fn load_seven_signs(
+    mut commands: Commands,
+    repo_manager: Res<RepositoryManager>,
+    db_connection: Res<DbConnection>,
+    mut loaded: Local<bool>,
+) -> Result<()> {
+    if *loaded {
+        return Ok(());
+    }
+    *loaded = true;
+
+    let now = Utc::now().naive_utc();
+
+    if db_connection.is_mock() {
+        commands.insert_resource(SevenSigns::new(seven_signs::model::Model::new(now), []));
+        return Ok(());
+    }
+
+    let state_repository = repo_manager.typed::<i32, seven_signs::model::Entity>()?;
+    let participants_repository = repo_manager.typed::<ObjectId, participant::model::Entity>()?;
+    commands.spawn_task(move || async move {
+        let state = match state_repository
+            .find_with_conditions([Condition::all()])
+            .await?
+            .into_iter()
+            .next()
+        {
+            Some(state) => state,
+            None => {
+                let state = seven_signs::model::Model::new(now);
+                state_repository
+                    .create_or_update(&state, state_on_conflict())
+                    .await?;
+                state
+            }
+        };
+        let participants = participants_repository
+            .find_with_conditions([Condition::all()])
+            .await?;
+
+        log::info!(
+            "Loaded seven signs cycle {} with {} participants.",
+            state.cycle,
+            participants.len()
+        );
+        AsyncWorld.apply_command(move |world: &mut World| {
+            world.insert_resource(SevenSigns::new(state, participants));
+        });
+        Ok(())
+    });
+    Ok(())
+}
+
+fn seal_obtained_message(cabal: Cabal, seal: Seal) -> Option<SmId> {
+    Some(match (cabal, seal) {
+        (Cabal::Dawn, Seal::Avarice) => SmId::SevenSignsTheLordsOfDawnHaveObtainedTheSealOfAvarice,
+        (Cabal::Dawn, Seal::Gnosis) => SmId::SevenSignsTheLordsOfDawnHaveObtainedTheSealOfGnosis,
+        (Cabal::Dawn, Seal::Strife) => SmId::SevenSignsTheLordsOfDawnHaveObtainedTheSealOfStrife,
+        (Cabal::Dusk, Seal::Avarice) => {
+            SmId::SevenSignsTheRevolutionariesOfDuskHaveObtainedTheSealOfAvarice
+        }
+        (Cabal::Dusk, Seal::Gnosis) => {
+            SmId::SevenSignsTheRevolutionariesOfDuskHaveObtainedTheSealOfGnosis
+        }
+        (Cabal::Dusk, Seal::Strife) => {
+            SmId::SevenSignsTheRevolutionariesOfDuskHaveObtainedTheSealOfStrife
+        }
+        (Cabal::None, _) => return None,
+    })
+}
+
+fn update_period(mut commands: Commands, mut ssq_query: SevenSignsQuery) -> Result<()> {
+    let now = Utc::now().naive_utc();
+    if !ssq_query.seven_signs().period_over(now) {
+        return Ok(());
+    }
+
+    let mut messages = Vec::new();
+    match ssq_query.next_period(&mut commands, now)? {
+        SsqPeriod::SealValidation => {
+            messages.push(
+                SmId::SevenSignsTheCompetitionPeriodHasEndedTheNextQuestEventWillStartInOneWeek,
+            );
+            let seven_signs = ssq_query.seven_signs();
+            match seven_signs.state().winner {
+                Cabal::Dawn => messages.push(SmId::SevenSignsTheLordsOfDawnHaveWon),
+                Cabal::Dusk => messages.push(SmId::SevenSignsTheRevolutionariesOfDuskHaveWon),
+                Cabal::None => messages
+                    .push(SmId::TheCompetitionHasEndedInATieThereforeNobodyHasBeenAwardedTheSeal),
+            }
+            messages.extend(
+                Seal::iter()
+                    .filter_map(|seal| seal_obtained_message(seven_signs.seal_owner(seal), seal)),
+            );
+            messages.push(SmId::SevenSignsTheSealValidationPeriodHasBegun);
+            log::info!(
+                "Seven signs competition is over, winner: {}",
+                seven_signs.state().winner
+            );
+        }
+        SsqPeriod::Competition => {
+            messages.push(SmId::SevenSignsTheSealValidationPeriodHasEnded);
+            messages.push(
+                SmId::SevenSignsTheCompetitionPeriodHasBegunVisitAPriestOfDawnOrPriestessOfDuskToParticipateInTheEvent,
+            );
+            log::info!(
+                "Seven signs cycle {} competition has begun",
+                ssq_query.seven_signs().state().cycle
+            );
+        }
+    }
+
+    for sm_id in messages {
+        announce(&mut commands, SystemMessage::new_empty(sm_id));
+    }
+    commands.trigger(ServerPacketBroadcast {
+        packet: SSQInfo::new(ssq_query.seven_signs().sky()).into(),
+        scope: BroadcastScope::All,
+    });
+    Ok(())
+}
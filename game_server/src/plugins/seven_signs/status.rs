@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    network::{
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{ActionFail, GameServerPacket, SsqStatus},
+        },
+        session::PacketReceiveParams,
+    },
+    object_id::ObjectId,
+    seven_signs::SevenSigns,
+};
+
+pub(crate) struct RequestSsqStatusPlugin;
+impl Plugin for RequestSsqStatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle);
+    }
+}
+
+fn handle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut commands: Commands,
+    seven_signs: Res<SevenSigns>,
+    object_ids: Query<Ref<ObjectId>>,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestSsqStatus(ref packet) = event.packet else {
+        return Ok(());
+    };
+    let entity = receive_params.character(&event.connection.id())?;
+    let char_id = *object_ids.get(entity)?;
+
+    match SsqStatus::new(packet.0, &seven_signs, char_id) {
+        Some(status) => commands.trigger_targets(GameServerPacket::from(status), entity),
+        None => commands.trigger_targets(GameServerPacket::from(ActionFail), entity),
+    }
+    Ok(())
+}
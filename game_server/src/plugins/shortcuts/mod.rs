@@ -3,7 +3,7 @@ use bevy_defer::{AccessError, AsyncAccess, AsyncWorld};
 use game_core::{
     network::packets::server::{GameServerPacket, ShortcutInit},
     object_id::ObjectId,
-    shortcut::{self, ShortcutComponentsPlugin},
+    shortcut::{self, ShortcutComponentsPlugin, Shortcuts},
     stats::SubClass,
 };
 use l2r_core::db::{Repository, RepositoryManager, TypedRepositoryManager};
@@ -16,6 +16,7 @@ mod shortcut_registration;
 /// Player can register shortcuts for items, skills, actions, macros, recipes etc.
 /// uses [`ShortcutRegistered`], [`ShortcutInit`] server packets
 /// alongside with clients: [`RequestShortcutRegistration`], [`RequestShortcutDelete`]
+/// Shortcuts of the current sub class are kept in [`Shortcuts`] and written by the autosave.
 pub(crate) struct ShortcutPlugin;
 
 impl Plugin for ShortcutPlugin {
@@ -56,15 +57,20 @@ pub async fn shortcut_init_task(char_entity: Entity) -> Result<(), AccessError>
         return Err(err.into());
     }
 
-    let shortcuts = result
-        .unwrap_or_default()
-        .into_iter()
-        .map(shortcut::Shortcut::from)
-        .collect::<Vec<_>>();
-
-    let shortcut_init = ShortcutInit::from(shortcuts);
+    let shortcuts = Shortcuts::new(
+        sub_class.variant(),
+        result
+            .unwrap_or_default()
+            .into_iter()
+            .map(shortcut::Shortcut::from)
+            .collect(),
+    );
 
     AsyncWorld.apply_command(move |world: &mut World| {
+        let shortcut_init = ShortcutInit::from(&shortcuts);
+        if let Ok(mut character) = world.get_entity_mut(char_entity) {
+            character.insert(shortcuts);
+        }
         world.trigger_targets(GameServerPacket::from(shortcut_init), char_entity);
     });
 
@@ -1,5 +1,4 @@
-use bevy::{log, prelude::*};
-use bevy_defer::{AsyncCommandsExtension, AsyncWorld};
+use bevy::prelude::*;
 use bevy_slinet::server::PacketReceiveEvent;
 use game_core::{
     network::{
@@ -10,14 +9,9 @@ use game_core::{
         },
         session::PacketReceiveParams,
     },
-    object_id::ObjectId,
-    shortcut,
+    shortcut::{self, Shortcuts},
     stats::SubClass,
 };
-use l2r_core::db::{
-    PrimaryKeyColumns, Repository, RepositoryManager, TypedRepositoryManager, UpdatableModel,
-};
-use sea_orm::sea_query::OnConflict;
 
 pub(crate) struct RequestShortcutRegistrationPlugin;
 
@@ -30,8 +24,7 @@ impl Plugin for RequestShortcutRegistrationPlugin {
 fn handle(
     receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
     receive_params: PacketReceiveParams,
-    repo_manager: Res<RepositoryManager>,
-    characters: Query<(Ref<ObjectId>, Ref<SubClass>)>,
+    mut characters: Query<(Ref<SubClass>, Mut<Shortcuts>)>,
     mut commands: Commands,
 ) -> Result<()> {
     let event = receive.event();
@@ -40,37 +33,14 @@ fn handle(
     };
 
     let entity = receive_params.character(&event.connection.id())?;
-    let (char_id, sub_class) = characters.get(entity)?;
-    let sub_class = *sub_class;
-    let char_id = *char_id;
+    let (sub_class, mut shortcuts) = characters.get_mut(entity)?;
 
-    let shortcut = shortcut::Shortcut::from_packet(sub_class.into(), packet);
-    let shortcut_repository =
-        repo_manager.typed::<shortcut::model::ShortcutPK, shortcut::model::Entity>()?;
-    let shotcut_registered = ShortcutRegistered::from(shortcut);
+    let shortcut = shortcut::Shortcut::from_packet(sub_class.variant(), packet);
+    shortcuts.register(shortcut);
 
-    commands.spawn_task(move || async move {
-        let shortcut_model = shortcut.into_model(char_id);
-        let result = shortcut_repository
-            .create_or_update(
-                &shortcut_model,
-                OnConflict::columns(shortcut::model::Model::pk_columns().to_vec())
-                    .update_columns(shortcut::model::Model::update_columns().to_vec())
-                    .to_owned(),
-            )
-            .await;
-        if let Err(err) = result {
-            log::error!(
-                "Character: {}, Error creating or updating shortcut: {:?}",
-                char_id,
-                err
-            );
-            return Err(err.into());
-        }
-        AsyncWorld.apply_command(move |world: &mut World| {
-            world.trigger_targets(GameServerPacket::from(shotcut_registered), entity);
-        });
-        Ok(())
-    });
+    commands.trigger_targets(
+        GameServerPacket::from(ShortcutRegistered::from(shortcut)),
+        entity,
+    );
     Ok(())
 }
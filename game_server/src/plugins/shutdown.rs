@@ -1,5 +1,15 @@
-use bevy::{app::AppExit, log, prelude::*};
-use game_core::character::{Character, CharacterSave};
+use bevy::{app::AppExit, log, platform::collections::HashMap, prelude::*};
+use config::Config;
+use game_core::{
+    character::{Character, CharacterSave},
+    network::{
+        broadcast::{BroadcastScope, ServerPacketBroadcast},
+        packets::server::SystemMessage,
+        session::{DisconnectSession, GameServerSession},
+    },
+    object_id::ObjectId,
+};
+use l2r_core::db::{LOGINS_CLOSED_TTL, RedisClient, logins_closed_key};
 use state::GameServerStateSystems;
 use std::{
     sync::{
@@ -8,6 +18,25 @@ use std::{
     },
     time::{Duration, Instant},
 };
+use strum::Display;
+use system_messages::{Id as SmId, SmParam};
+
+/// Exit code telling the supervisor to start the server again.
+pub const RESTART_EXIT_CODE: u8 = 2;
+
+/// Seconds left when the countdown is announced to the players.
+const ANNOUNCE_AT: [u64; 16] = [
+    3600, 1800, 900, 600, 300, 240, 180, 120, 60, 30, 10, 5, 4, 3, 2, 1,
+];
+
+/// New logins are refused once the countdown gets this low.
+const LOGINS_CLOSED_AT: Duration = Duration::from_secs(180);
+
+/// Time given to the character saves before an announced shutdown is aborted.
+const SAVE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time for the last announcements to reach the clients before the sessions are closed.
+const DISCONNECT_DELAY: Duration = Duration::from_secs(1);
 
 pub struct ShutdownPlugin;
 
@@ -17,9 +46,14 @@ impl Plugin for ShutdownPlugin {
 
         let shutdown_state = ShutdownState {
             shutdown_requested: shutdown_requested.clone(),
-            total_chars: 0,
-            saved_chars: 0,
+            mode: ShutdownMode::default(),
             shutdown_start: None,
+            resume_state: GameServerStateSystems::Run,
+            announced: false,
+            pending_saves: HashMap::default(),
+            failed_saves: Vec::new(),
+            disconnect_at: None,
+            sessions_closed: false,
         };
 
         let shutdown_requested_clone = shutdown_requested.clone();
@@ -43,64 +77,333 @@ impl Plugin for ShutdownPlugin {
             }
         }
 
-        app.insert_resource(shutdown_state);
-        app.add_systems(Update, check_shutdown_signal);
+        app.insert_resource(shutdown_state)
+            .init_resource::<ShutdownCountdown>();
+
+        app.add_observer(schedule_shutdown)
+            .add_observer(abort_shutdown);
+
+        app.add_systems(
+            Update,
+            (tick_countdown, check_shutdown_signal, publish_logins_closed).chain(),
+        );
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Display, Eq, PartialEq)]
+pub enum ShutdownMode {
+    #[default]
+    Shutdown,
+    Restart,
+}
+
+impl ShutdownMode {
+    fn exit(self) -> AppExit {
+        match self {
+            Self::Shutdown => AppExit::Success,
+            Self::Restart => AppExit::from_code(RESTART_EXIT_CODE),
+        }
+    }
+}
+
+/// Starts the countdown to a graceful shutdown or restart, replacing the one in progress.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct ScheduleShutdown {
+    pub mode: ShutdownMode,
+    pub delay: Duration,
+}
+
+/// Stops the countdown in progress, the server keeps running.
+#[derive(Clone, Copy, Debug, Event)]
+pub struct AbortShutdown;
+
 #[derive(Resource)]
 pub struct ShutdownState {
     pub shutdown_requested: Arc<AtomicBool>,
-    pub total_chars: usize,
-    pub saved_chars: usize,
+    mode: ShutdownMode,
     shutdown_start: Option<Instant>,
+    resume_state: GameServerStateSystems,
+    /// Scheduled by a GM or the admin API rather than a signal, it can be aborted and scheduled again
+    announced: bool,
+    pending_saves: HashMap<ObjectId, String>,
+    failed_saves: Vec<String>,
+    disconnect_at: Option<Instant>,
+    sessions_closed: bool,
 }
 
+impl ShutdownState {
+    /// Marks the character as saved, no-op outside of a shutdown.
+    /// Only the character row is confirmed, items go through the item journal
+    /// and effects are written by their own save.
+    pub fn save_confirmed(&mut self, object_id: ObjectId) {
+        self.pending_saves.remove(&object_id);
+    }
+
+    /// Marks the character as not saved, the shutdown is aborted once the other saves are done.
+    pub fn save_failed(&mut self, object_id: ObjectId) {
+        if let Some(name) = self.pending_saves.remove(&object_id) {
+            self.failed_saves.push(name);
+        }
+    }
+
+    /// Names of the characters whose save failed or did not finish in time.
+    fn unsaved(&self) -> Vec<String> {
+        let mut unsaved = self.failed_saves.clone();
+        unsaved.extend(self.pending_saves.values().cloned());
+        unsaved.sort();
+        unsaved
+    }
+
+    fn reset(&mut self) {
+        self.shutdown_requested.store(false, Ordering::Release);
+        self.announced = false;
+        self.shutdown_start = None;
+        self.pending_saves.clear();
+        self.failed_saves.clear();
+        self.disconnect_at = None;
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct ShutdownCountdown {
+    mode: ShutdownMode,
+    timer: Option<Timer>,
+    announced: Option<u64>,
+    logins_closed: bool,
+}
+
+impl ShutdownCountdown {
+    /// Set in the final minutes of the countdown and for the whole shutdown.
+    pub fn logins_closed(&self) -> bool {
+        self.logins_closed
+    }
+}
+
+fn schedule_shutdown(
+    trigger: Trigger<ScheduleShutdown>,
+    mut commands: Commands,
+    mut countdown: ResMut<ShutdownCountdown>,
+    shutdown_state: Res<ShutdownState>,
+) {
+    if shutdown_state.shutdown_requested.load(Ordering::Acquire) {
+        log::warn!("Shutdown is already in progress");
+        return;
+    }
+    let ScheduleShutdown { mode, delay } = *trigger.event();
+    log::info!("{} scheduled in {} seconds", mode, delay.as_secs());
+    *countdown = ShutdownCountdown {
+        mode,
+        timer: Some(Timer::new(delay, TimerMode::Once)),
+        announced: Some(delay.as_secs()),
+        logins_closed: delay <= LOGINS_CLOSED_AT,
+    };
+    announce(
+        &mut commands,
+        SystemMessage::new(
+            SmId::TheServerWillBeComingDownInS1SecondSPleaseFindASafePlaceToLogOut,
+            vec![SmParam::Number(delay.as_secs() as u32)],
+        ),
+    );
+}
+
+fn abort_shutdown(
+    _: Trigger<AbortShutdown>,
+    mut commands: Commands,
+    mut countdown: ResMut<ShutdownCountdown>,
+) {
+    if countdown.timer.is_none() {
+        log::warn!("No shutdown to abort");
+        return;
+    }
+    let mode = countdown.mode;
+    log::info!("{} aborted", mode);
+    *countdown = ShutdownCountdown::default();
+    announce(
+        &mut commands,
+        SystemMessage::new(
+            SmId::S1HasBeenAborted,
+            vec![SmParam::Text(mode.to_string())],
+        ),
+    );
+}
+
+fn tick_countdown(
+    mut commands: Commands,
+    mut countdown: ResMut<ShutdownCountdown>,
+    mut shutdown_state: ResMut<ShutdownState>,
+    time: Res<Time>,
+) {
+    let Some(timer) = countdown.timer.as_mut() else {
+        return;
+    };
+    let remaining = timer.tick(time.delta()).remaining();
+    if timer.finished() {
+        countdown.timer = None;
+        shutdown_state.mode = countdown.mode;
+        shutdown_state.announced = true;
+        shutdown_state
+            .shutdown_requested
+            .store(true, Ordering::Release);
+        return;
+    }
+
+    countdown.logins_closed |= remaining <= LOGINS_CLOSED_AT;
+    let seconds = remaining.as_millis().div_ceil(1000) as u64;
+    if countdown.announced != Some(seconds) && ANNOUNCE_AT.contains(&seconds) {
+        countdown.announced = Some(seconds);
+        announce(
+            &mut commands,
+            SystemMessage::new(
+                SmId::TheServerWillBeComingDownInS1SecondSPleaseFindASafePlaceToLogOut,
+                vec![SmParam::Number(seconds as u32)],
+            ),
+        );
+    }
+}
+
+fn announce(commands: &mut Commands, message: SystemMessage) {
+    commands.trigger(ServerPacketBroadcast {
+        packet: message.into(),
+        scope: BroadcastScope::All,
+    });
+}
+
+/// Characters are saved first, the sessions are closed once every save is confirmed
+/// and the last announcements had time to go out, the process exits on the next tick.
+/// A save that fails or doesn't finish in time aborts an announced shutdown,
+/// a signal has to be obeyed, so the unsaved characters are only logged then.
 fn check_shutdown_signal(
     mut shutdown_state: ResMut<ShutdownState>,
+    mut countdown: ResMut<ShutdownCountdown>,
     mut commands: Commands,
-    characters: Query<Entity, With<Character>>,
+    characters: Query<(Entity, &ObjectId, &Name), With<Character>>,
+    sessions: Query<Entity, With<GameServerSession>>,
     mut exit: EventWriter<AppExit>,
+    state: Res<State<GameServerStateSystems>>,
     mut next_state: ResMut<NextState<GameServerStateSystems>>,
 ) {
-    if shutdown_state.shutdown_requested.load(Ordering::Acquire) {
-        // Initialize shutdown if this is the first time we detect it
-        if shutdown_state.shutdown_start.is_none() {
-            let char_count = characters.iter().count();
-            shutdown_state.total_chars = char_count;
-            shutdown_state.shutdown_start = Some(Instant::now());
-
-            log::info!("Graceful shutdown initiated. Saving world state...");
-            next_state.set(GameServerStateSystems::Shutdown);
-            log::info!("Saving {} active character(s)...", char_count);
-            for entity in characters.iter() {
-                commands.trigger_targets(CharacterSave, entity);
+    if !shutdown_state.shutdown_requested.load(Ordering::Acquire) {
+        return;
+    }
+
+    if shutdown_state.sessions_closed {
+        log::info!("{} completed", shutdown_state.mode);
+        exit.write(shutdown_state.mode.exit());
+        return;
+    }
+
+    if let Some(disconnect_at) = shutdown_state.disconnect_at {
+        if Instant::now() >= disconnect_at {
+            log::info!("Closing {} session(s)...", sessions.iter().count());
+            for session_entity in sessions.iter() {
+                commands.trigger_targets(DisconnectSession, session_entity);
             }
-            log::info!("Waiting up to 10 seconds for pending operations...");
+            shutdown_state.sessions_closed = true;
         }
+        return;
+    }
 
-        let elapsed = shutdown_state.shutdown_start.unwrap().elapsed();
-        let saved = shutdown_state.saved_chars;
-        let total = shutdown_state.total_chars;
-
-        // Check if all characters are saved or timeout exceeded
-        if saved >= total {
-            log::info!(
-                "Character save completed ({}/{} in {:.2}s)",
-                saved,
-                total,
-                elapsed.as_secs_f32()
-            );
-            log::info!("All pending operations completed. Shutting down gracefully...");
-            exit.write(AppExit::Success);
-        } else if elapsed >= Duration::from_secs(10) {
-            log::warn!("Graceful shutdown timeout reached after 10 seconds!");
-            log::warn!(
-                "{}/{} character(s) saved. Forcing shutdown...",
-                saved,
-                total
-            );
-            exit.write(AppExit::Success);
+    // Initialize shutdown if this is the first time we detect it
+    if shutdown_state.shutdown_start.is_none() {
+        shutdown_state.pending_saves = characters
+            .iter()
+            .map(|(_, object_id, name)| (*object_id, name.to_string()))
+            .collect();
+        shutdown_state.shutdown_start = Some(Instant::now());
+        shutdown_state.resume_state = match state.get() {
+            GameServerStateSystems::Shutdown => GameServerStateSystems::Run,
+            current => current.clone(),
+        };
+        countdown.logins_closed = true;
+
+        log::info!("Graceful shutdown initiated. Saving world state...");
+        next_state.set(GameServerStateSystems::Shutdown);
+        log::info!(
+            "Saving {} active character(s)...",
+            shutdown_state.pending_saves.len()
+        );
+        for (entity, _, _) in characters.iter() {
+            commands.trigger_targets(CharacterSave, entity);
         }
+        log::info!(
+            "Waiting up to {} seconds for the saves to be confirmed...",
+            SAVE_TIMEOUT.as_secs()
+        );
+    }
+
+    let elapsed = shutdown_state.shutdown_start.unwrap().elapsed();
+    if !shutdown_state.pending_saves.is_empty() && elapsed < SAVE_TIMEOUT {
+        return;
+    }
+
+    let unsaved = shutdown_state.unsaved();
+    if !unsaved.is_empty() && shutdown_state.announced {
+        let mode = shutdown_state.mode;
+        log::error!(
+            "{} aborted, {} character(s) could not be saved: {}",
+            mode,
+            unsaved.len(),
+            unsaved.join(", ")
+        );
+        next_state.set(shutdown_state.resume_state.clone());
+        shutdown_state.reset();
+        *countdown = ShutdownCountdown::default();
+        announce(
+            &mut commands,
+            SystemMessage::new(
+                SmId::S1HasBeenAborted,
+                vec![SmParam::Text(mode.to_string())],
+            ),
+        );
+        return;
+    }
+    if !unsaved.is_empty() {
+        log::error!(
+            "{} character(s) could not be saved before the {}: {}",
+            unsaved.len(),
+            shutdown_state.mode,
+            unsaved.join(", ")
+        );
+    }
+
+    log::info!(
+        "Character save finished in {:.2}s. Closing sessions...",
+        elapsed.as_secs_f32()
+    );
+    shutdown_state.disconnect_at = Some(Instant::now() + DISCONNECT_DELAY);
+}
+
+/// Keeps the login server informed whether this server still accepts logins.
+/// The mark expires on its own, it is refreshed as long as the logins stay closed.
+fn publish_logins_closed(
+    countdown: Res<ShutdownCountdown>,
+    config: Res<Config>,
+    redis_client: Option<ResMut<RedisClient>>,
+    mut published: Local<Option<(bool, Instant)>>,
+) {
+    let logins_closed = countdown.logins_closed();
+    let up_to_date = published.is_some_and(|(closed, at)| {
+        closed == logins_closed && (!closed || at.elapsed() < LOGINS_CLOSED_TTL / 3)
+    });
+    if up_to_date {
+        return;
+    }
+    let Some(mut redis_client) = redis_client else {
+        return;
+    };
+    *published = Some((logins_closed, Instant::now()));
+    let key = logins_closed_key(config.general().server_id.into());
+    let result = if logins_closed {
+        redis::Commands::set_ex::<_, _, ()>(
+            &mut redis_client.connection,
+            &key,
+            true,
+            LOGINS_CLOSED_TTL.as_secs(),
+        )
+    } else {
+        redis::Commands::del::<_, ()>(&mut redis_client.connection, &key)
+    };
+    if let Err(err) = result {
+        log::error!("Failed to publish logins closed ({logins_closed}) to Redis: {err}");
     }
 }
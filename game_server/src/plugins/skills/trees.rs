@@ -1,6 +1,6 @@
 use bevy::{platform::collections::HashMap, prelude::*};
 use game_core::{
-    skills::{SkillTreesComponentsPlugin, SkillTreesHandlers},
+    skills::{FishingSkillTreeHandle, SkillTreesComponentsPlugin, SkillTreesHandlers},
     stats::ClassId,
 };
 use l2r_core::chronicles::CHRONICLE;
@@ -13,6 +13,7 @@ pub struct SkillTreesPlugin;
 impl Plugin for SkillTreesPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(SkillTreesComponentsPlugin);
+        app.init_resource::<FishingSkillTreeHandle>();
 
         app.add_systems(Update, load_assets.in_set(LoadingSystems::AssetInit));
     }
@@ -31,5 +32,12 @@ fn load_assets(asset_server: Res<AssetServer>, mut commands: Commands, mut loade
         skill_trees.insert(class_id, asset_server.load(path.clone()));
     }
     commands.insert_resource(skill_trees);
+
+    let mut path = PathBuf::from("skills_trees");
+    path.push(CHRONICLE);
+    path.push("special");
+    path.push("fishing");
+    path.set_extension("json");
+    commands.insert_resource(FishingSkillTreeHandle::from(asset_server.load(path)));
     *loaded = true;
 }
@@ -1,6 +1,8 @@
 use super::GameServerStateSystems;
 use bevy::{log, prelude::*};
-use game_core::{object_id::ObjectIdManager, stats::StatsTable};
+use game_core::{
+    items::journal::ItemJournalReplayed, object_id::ObjectIdManager, stats::StatsTable,
+};
 use l2r_core::db::{DbConnection, RepositoryManager};
 use scripting;
 use state::{LoadingPlugin, LoadingSystems};
@@ -53,6 +55,7 @@ fn set_id_init(
     mut state: ResMut<NextState<LoadingSystems>>,
     repo_manager: Res<RepositoryManager>,
     db_connection: Option<Res<DbConnection>>,
+    journal_replayed: Option<Res<ItemJournalReplayed>>,
 ) {
     // Skip repository check for mock connections (tests)
     if let Some(db_conn) = db_connection
@@ -63,7 +66,7 @@ fn set_id_init(
         return;
     }
 
-    if !repo_manager.all_ready() {
+    if !repo_manager.all_ready() || journal_replayed.is_none() {
         return;
     }
 
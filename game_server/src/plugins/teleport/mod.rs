@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use game_core::{
     character::Character,
-    encounters::KnownEntities,
+    encounters::{KnownBy, KnownEntities},
     network::packets::server::{GameServerPacket, TeleportToLocation},
     teleport::{TeleportComponentsPlugin, TeleportInProgress},
 };
@@ -21,13 +21,18 @@ impl Plugin for TeleportPlugin {
 fn teleport(
     teleport: Trigger<TeleportToLocation>,
     mut characters: Query<(Mut<Transform>, Mut<KnownEntities>), With<Character>>,
+    mut known_by: Query<Mut<KnownBy>>,
     mut commands: Commands,
 ) {
     let entity = teleport.target();
     if let Ok((mut transform, mut known_entities)) = characters.get_mut(entity) {
         commands.entity(entity).try_insert(TeleportInProgress);
         *transform = teleport.transform();
-        known_entities.clear();
+        for known in known_entities.drain() {
+            if let Ok(mut known_by) = known_by.get_mut(known) {
+                known_by.remove(&entity);
+            }
+        }
     }
 
     commands.trigger_targets(GameServerPacket::from(teleport.event().clone()), entity);
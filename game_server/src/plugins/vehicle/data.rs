@@ -0,0 +1,100 @@
+use bevy::{log, prelude::*};
+use game_core::vehicle::*;
+use l2r_core::chronicles::CHRONICLE;
+use state::{GameServerStateSystems, LoadingSystems};
+use std::path::PathBuf;
+
+pub struct BoatRoutesPlugin;
+impl Plugin for BoatRoutesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BoatRoutesHandle>();
+
+        app.add_systems(
+            Update,
+            (
+                load_assets.in_set(LoadingSystems::AssetInit),
+                update_assets.in_set(LoadingSystems::AssetInit),
+            ),
+        );
+
+        app.add_systems(Update, update_assets.in_set(GameServerStateSystems::Run));
+    }
+}
+
+fn load_assets(
+    asset_server: Res<AssetServer>,
+    mut routes_handle: ResMut<BoatRoutesHandle>,
+    mut loaded: Local<bool>,
+) {
+    if *loaded {
+        return;
+    }
+    let mut path = PathBuf::from("boat");
+    path.push(CHRONICLE);
+    path.push("routes");
+    path.set_extension("json");
+
+    let handle: Handle<BoatRoutes> = asset_server.load(path.clone());
+    **routes_handle = handle;
+    *loaded = true;
+}
+
+fn update_assets(handle: Res<BoatRoutesHandle>, mut events: EventReader<AssetEvent<BoatRoutes>>) {
+    for event in events.read() {
+        if event.is_loaded_with_dependencies(handle.id()) {
+            log::info!("Boat routes updated");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use l2r_core::{assets::ASSET_DIR, chronicles::CHRONICLE, utils::get_base_path};
+    use serde_json::from_reader;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn test_parse_all_from_json() {
+        let mut path = get_base_path();
+        path.push(ASSET_DIR);
+        path.push("boat");
+        path.push(CHRONICLE);
+        path.push("routes");
+        path.set_extension("json");
+
+        let file = File::open(&path).unwrap_or_else(|_| panic!("Failed to open file: {:?}", path));
+        let reader = BufReader::new(file);
+
+        let result: BoatRoutes = from_reader(reader)
+            .unwrap_or_else(|_| panic!("Failed to parse boat routes from JSON: {:?}", path));
+
+        for (route_id, route) in result.iter() {
+            assert!(!route.docks.is_empty(), "Route {route_id} has no docks");
+            for (index, dock) in route.docks.iter().enumerate() {
+                let next_dock = &route.docks[route.next_dock(index)];
+                assert_eq!(
+                    dock.path.last().map(|waypoint| waypoint.loc),
+                    Some(next_dock.loc),
+                    "Route {route_id} doesn't lead from {} to {}",
+                    dock.name,
+                    next_dock.name
+                );
+                assert!(
+                    dock.announcements
+                        .windows(2)
+                        .all(|pair| pair[0].before >= pair[1].before),
+                    "Announcements at {} of the route {route_id} are not in order",
+                    dock.name
+                );
+                assert!(
+                    dock.announcements
+                        .iter()
+                        .all(|announcement| announcement.before <= dock.anchor_time),
+                    "Announcement at {} of the route {route_id} comes before the boat arrives",
+                    dock.name
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use bevy::{ecs::system::SystemParam, log, prelude::*};
+use game_core::{
+    items::{DestroyItemRequest, Inventory, Item},
+    movement::Movement,
+    network::{
+        broadcast::{BroadcastScope, ServerPacketBroadcast},
+        packets::server::{
+            GameServerPacket, SystemMessage, TeleportToLocation, VehicleCheckLocation,
+            VehicleDeparture, VehicleStarted,
+        },
+    },
+    object_id::{ObjectId, ObjectIdManager},
+    stats::{Movable, MovementStat},
+    teleport::TeleportType,
+    vehicle::{
+        Boarded, Boat, BoatBundle, BoatRoute, BoatRouteId, BoatRoutes, BoatRoutesHandle, InVehicle,
+        Passengers, VehicleComponentsPlugin,
+    },
+};
+use spatial::WayPoint;
+use state::GameServerStateSystems;
+use system_messages::Id as SmId;
+
+mod data;
+mod passengers;
+
+/// Characters around the dock hear the boat announcements.
+const ANNOUNCEMENT_RADIUS: f32 = 10000.0;
+
+/// Boats sail their routes on their own. Passengers board them at the docks
+/// and pay with the dock ticket once the boat leaves.
+pub(crate) struct VehiclePlugin;
+impl Plugin for VehiclePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(VehicleComponentsPlugin)
+            .add_plugins(data::BoatRoutesPlugin)
+            .add_plugins(passengers::PassengersPlugin);
+
+        app.add_observer(boat_arrived);
+
+        app.add_systems(
+            Update,
+            (spawn_boats, anchor_boats, set_course)
+                .chain()
+                .in_set(GameServerStateSystems::Run),
+        );
+
+        app.add_systems(
+            FixedUpdate,
+            carry_passengers.in_set(GameServerStateSystems::Run),
+        );
+    }
+}
+
+#[derive(SystemParam)]
+pub(crate) struct BoatRoutesQuery<'w> {
+    handle: Res<'w, BoatRoutesHandle>,
+    assets: Res<'w, Assets<BoatRoutes>>,
+}
+
+impl BoatRoutesQuery<'_> {
+    pub fn routes(&self) -> Option<&BoatRoutes> {
+        self.assets.get(self.handle.id())
+    }
+
+    pub fn route(&self, route_id: BoatRouteId) -> Result<&BoatRoute> {
+        self.routes()
+            .ok_or_else(|| BevyError::from("Boat routes are not loaded"))?
+            .get(&route_id)
+            .ok_or_else(|| BevyError::from(format!("Boat route {route_id} is not found")))
+    }
+}
+
+fn spawn_boats(
+    routes: BoatRoutesQuery,
+    mut object_id_manager: ResMut<ObjectIdManager>,
+    mut commands: Commands,
+    mut spawned: Local<bool>,
+) {
+    if *spawned {
+        return;
+    }
+    let Some(routes) = routes.routes() else {
+        return;
+    };
+
+    for (route_id, route) in routes.iter() {
+        if route.docks.is_empty() {
+            log::warn!("Boat route {} has no docks", route_id);
+            continue;
+        }
+        if let Some(boat) = BoatBundle::new(*route_id, route, object_id_manager.next_id()) {
+            commands.spawn(boat);
+        }
+    }
+    *spawned = true;
+}
+
+fn announce(commands: &mut Commands, boat: Entity, message: u32) {
+    commands.trigger_targets(
+        ServerPacketBroadcast {
+            packet: SystemMessage::new_with_int_id(message, vec![]).into(),
+            scope: BroadcastScope::Radius(ANNOUNCEMENT_RADIUS),
+        },
+        boat,
+    );
+}
+
+#[derive(SystemParam)]
+struct TicketsQuery<'w, 's> {
+    passengers: Query<'w, 's, (Ref<'static, ObjectId>, Ref<'static, Inventory>), With<InVehicle>>,
+    items: Query<'w, 's, Ref<'static, Item>>,
+    object_id_manager: Res<'w, ObjectIdManager>,
+}
+
+fn anchor_boats(
+    time: Res<Time>,
+    routes: BoatRoutesQuery,
+    tickets: TicketsQuery,
+    mut boats: Query<(
+        Entity,
+        Ref<ObjectId>,
+        Ref<Transform>,
+        Mut<Boat>,
+        Option<Ref<Passengers>>,
+    )>,
+    mut commands: Commands,
+) -> Result<()> {
+    for (entity, object_id, transform, mut boat, passengers) in boats.iter_mut() {
+        if !boat.is_docked() {
+            continue;
+        }
+        let Some(dock) = routes.route(boat.route_id)?.dock(boat.dock()) else {
+            continue;
+        };
+
+        let (announcements, leaves) = boat.update(time.delta(), dock);
+        for message in announcements {
+            announce(&mut commands, entity, message);
+        }
+        if !leaves {
+            continue;
+        }
+
+        for passenger in passengers.iter().flat_map(|passengers| passengers.iter()) {
+            let Ok((passenger_oid, inventory)) = tickets.passengers.get(passenger) else {
+                continue;
+            };
+
+            if let Some((_, ticket_oid, _)) = inventory.single_by_item_id(
+                dock.ticket,
+                &tickets.items,
+                tickets.object_id_manager.as_ref(),
+            ) {
+                commands.trigger_targets(
+                    DestroyItemRequest {
+                        item_oid: ticket_oid,
+                        count: 1,
+                    },
+                    passenger,
+                );
+                continue;
+            }
+
+            let oust = dock.oust.into();
+            passengers::get_off(&mut commands, passenger, *passenger_oid, *object_id, oust);
+            commands.trigger_targets(
+                TeleportToLocation::new(
+                    *passenger_oid,
+                    Transform::from_translation(oust),
+                    TeleportType::default(),
+                ),
+                passenger,
+            );
+            commands.trigger_targets(
+                GameServerPacket::from(SystemMessage::new_empty(
+                    SmId::YouDoNotPossessTheCorrectTicketToBoardTheBoat,
+                )),
+                passenger,
+            );
+        }
+
+        if let Some(message) = dock.departure {
+            announce(&mut commands, entity, message);
+        }
+        commands.trigger_targets(
+            ServerPacketBroadcast {
+                packet: VehicleStarted::new(*object_id, true).into(),
+                scope: BroadcastScope::Known,
+            },
+            entity,
+        );
+
+        let mut origin = transform.translation;
+        let waypoints = dock
+            .path
+            .iter()
+            .map(|waypoint| {
+                let target = waypoint.loc.into();
+                let waypoint = WayPoint::new(origin, target);
+                origin = target;
+                waypoint
+            })
+            .collect();
+        commands
+            .entity(entity)
+            .insert(Movement::to_location(waypoints));
+    }
+    Ok(())
+}
+
+/// Sets the speed of the boat for the waypoint it sails to and tells about it around.
+fn set_course(
+    routes: BoatRoutesQuery,
+    mut boats: Query<
+        (
+            Entity,
+            Ref<ObjectId>,
+            Ref<Transform>,
+            Mut<Boat>,
+            Mut<Movable>,
+            Ref<Movement>,
+        ),
+        Changed<Movement>,
+    >,
+    mut commands: Commands,
+) -> Result<()> {
+    for (entity, object_id, transform, mut boat, mut movable, movement) in boats.iter_mut() {
+        let Some(waypoints) = movement.waypoints() else {
+            continue;
+        };
+        let Some(dock) = routes.route(boat.route_id)?.dock(boat.dock()) else {
+            continue;
+        };
+        let Some(course) = dock
+            .path
+            .len()
+            .checked_sub(waypoints.len())
+            .and_then(|index| dock.path.get(index))
+        else {
+            continue;
+        };
+
+        movable.set_speed_stat(MovementStat::Swim, course.speed);
+        boat.set_course(*course);
+
+        commands.trigger_targets(
+            ServerPacketBroadcast {
+                packet: VehicleDeparture::new(
+                    *object_id,
+                    course.speed,
+                    course.rotation_speed,
+                    course.loc.into(),
+                )
+                .into(),
+                scope: BroadcastScope::Known,
+            },
+            entity,
+        );
+        commands.trigger_targets(
+            ServerPacketBroadcast {
+                packet: VehicleCheckLocation::new(*object_id, *transform).into(),
+                scope: BroadcastScope::Known,
+            },
+            entity,
+        );
+    }
+    Ok(())
+}
+
+fn boat_arrived(
+    trigger: Trigger<OnRemove, Movement>,
+    routes: BoatRoutesQuery,
+    mut boats: Query<(Ref<ObjectId>, Mut<Boat>)>,
+    mut commands: Commands,
+) -> Result<()> {
+    let entity = trigger.target();
+    let Ok((object_id, mut boat)) = boats.get_mut(entity) else {
+        return Ok(());
+    };
+
+    let route = routes.route(boat.route_id)?;
+    let index = route.next_dock(boat.dock());
+    let Some(dock) = route.dock(index) else {
+        return Ok(());
+    };
+    boat.anchor(index, dock);
+
+    commands.trigger_targets(
+        ServerPacketBroadcast {
+            packet: VehicleStarted::new(*object_id, false).into(),
+            scope: BroadcastScope::Known,
+        },
+        entity,
+    );
+    if let Some(message) = dock.arrival {
+        announce(&mut commands, entity, message);
+    }
+    Ok(())
+}
+
+/// Passengers keep their place on board while the boat sails.
+fn carry_passengers(
+    boats: Query<Ref<Transform>, With<Boat>>,
+    mut passengers: Query<(Mut<Transform>, Ref<InVehicle>, Ref<Boarded>), Without<Boat>>,
+) {
+    for (mut transform, in_vehicle, boarded) in passengers.iter_mut() {
+        if let Ok(boat_transform) = boats.get(**boarded) {
+            let translation = in_vehicle.translation(&boat_transform);
+            if transform.translation != translation {
+                transform.translation = translation;
+            }
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use bevy::prelude::*;
+use bevy_slinet::server::PacketReceiveEvent;
+use game_core::{
+    attack::Dead,
+    character::Character,
+    movement::Movement,
+    network::{
+        broadcast::ServerPacketBroadcast,
+        config::GameServerNetworkConfig,
+        packets::{
+            client::GameClientPacket,
+            server::{
+                ActionFail, GameServerPacket, GetOffVehicle, GetOnVehicle, MoveToLocationInVehicle,
+                StopMoveInVehicle,
+            },
+        },
+        session::PacketReceiveParams,
+    },
+    object_id::{ObjectId, ObjectIdManager},
+    vehicle::{BOARDING_DISTANCE, Boarded, Boat, DECK_RADIUS, InVehicle},
+};
+use spatial::Heading;
+
+pub(super) struct PassengersPlugin;
+impl Plugin for PassengersPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(handle_get_on)
+            .add_observer(handle_get_off)
+            .add_observer(handle_move_in_vehicle)
+            .add_observer(handle_stop_in_vehicle);
+    }
+}
+
+/// Puts the passenger ashore at the location.
+pub(super) fn get_off(
+    commands: &mut Commands,
+    passenger: Entity,
+    object_id: ObjectId,
+    vehicle_oid: ObjectId,
+    location: Vec3,
+) {
+    commands.entity(passenger).remove::<(Boarded, InVehicle)>();
+    commands.trigger_targets(
+        ServerPacketBroadcast::new(GetOffVehicle::new(object_id, vehicle_oid, location).into()),
+        passenger,
+    );
+}
+
+fn handle_get_on(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut commands: Commands,
+    object_id_manager: Res<ObjectIdManager>,
+    mut characters: Query<
+        (Ref<ObjectId>, Mut<Transform>, Has<Boarded>, Has<Dead>),
+        (With<Character>, Without<Boat>),
+    >,
+    boats: Query<(Ref<Transform>, Ref<Boat>), Without<Character>>,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestGetOnVehicle(ref packet) = event.packet else {
+        return Ok(());
+    };
+    let entity = receive_params.character(&event.connection.id())?;
+    let (object_id, mut transform, boarded, dead) = characters.get_mut(entity)?;
+
+    let boat = object_id_manager
+        .entity(packet.vehicle_oid)
+        .and_then(|boat_entity| boats.get(boat_entity).ok().map(|boat| (boat_entity, boat)));
+    let Some((boat_entity, (boat_transform, boat))) = boat else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+    if boarded
+        || dead
+        || !boat.is_docked()
+        || boat_transform.translation.distance(transform.translation) > BOARDING_DISTANCE
+    {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    let in_vehicle = InVehicle::new(packet.vehicle_oid, packet.position, transform.translation);
+    transform.translation = in_vehicle.translation(&boat_transform);
+    commands
+        .entity(entity)
+        .remove::<Movement>()
+        .insert((Boarded(boat_entity), in_vehicle));
+
+    commands.trigger_targets(
+        ServerPacketBroadcast::new(
+            GetOnVehicle::new(*object_id, packet.vehicle_oid, packet.position).into(),
+        ),
+        entity,
+    );
+    Ok(())
+}
+
+fn handle_get_off(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut commands: Commands,
+    mut characters: Query<(Ref<ObjectId>, Mut<Transform>, Ref<Boarded>), With<Character>>,
+    boats: Query<Ref<Boat>>,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestGetOffVehicle(ref packet) = event.packet else {
+        return Ok(());
+    };
+    let entity = receive_params.character(&event.connection.id())?;
+
+    // Nobody jumps off the boat at sea
+    let Ok((object_id, mut transform, boarded)) = characters.get_mut(entity) else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+    if !boats.get(**boarded).is_ok_and(|boat| boat.is_docked()) {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    transform.translation = packet.location;
+    get_off(
+        &mut commands,
+        entity,
+        *object_id,
+        packet.vehicle_oid,
+        packet.location,
+    );
+    commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+    Ok(())
+}
+
+fn handle_move_in_vehicle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut commands: Commands,
+    mut passengers: Query<(Ref<ObjectId>, Mut<InVehicle>), With<Character>>,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::RequestMoveToLocationInVehicle(ref packet) = event.packet else {
+        return Ok(());
+    };
+    let entity = receive_params.character(&event.connection.id())?;
+
+    let Ok((object_id, mut in_vehicle)) = passengers.get_mut(entity) else {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    };
+    if in_vehicle.vehicle_oid != packet.vehicle_oid || packet.target.length() > DECK_RADIUS {
+        commands.trigger_targets(GameServerPacket::from(ActionFail), entity);
+        return Ok(());
+    }
+
+    // Steps on the deck are short, so the passenger is put at the target at once
+    in_vehicle.position = packet.target;
+    commands.trigger_targets(
+        ServerPacketBroadcast::new(
+            MoveToLocationInVehicle::new(
+                *object_id,
+                packet.vehicle_oid,
+                packet.origin,
+                packet.target,
+            )
+            .into(),
+        ),
+        entity,
+    );
+    Ok(())
+}
+
+fn handle_stop_in_vehicle(
+    receive: Trigger<PacketReceiveEvent<GameServerNetworkConfig>>,
+    receive_params: PacketReceiveParams,
+    mut commands: Commands,
+    mut passengers: Query<(Ref<ObjectId>, Mut<InVehicle>), With<Character>>,
+) -> Result<()> {
+    let event = receive.event();
+    let GameClientPacket::CannotMoveAnymoreInVehicle(ref packet) = event.packet else {
+        return Ok(());
+    };
+    let entity = receive_params.character(&event.connection.id())?;
+
+    let Ok((object_id, mut in_vehicle)) = passengers.get_mut(entity) else {
+        return Ok(());
+    };
+    if in_vehicle.vehicle_oid != packet.vehicle_oid || packet.position.length() > DECK_RADIUS {
+        return Ok(());
+    }
+
+    in_vehicle.position = packet.position;
+    commands.trigger_targets(
+        ServerPacketBroadcast::new(
+            StopMoveInVehicle::new(
+                *object_id,
+                packet.vehicle_oid,
+                packet.position,
+                Heading::new(packet.heading),
+            )
+            .into(),
+        ),
+        entity,
+    );
+    Ok(())
+}
@@ -273,7 +273,7 @@ pub fn test_character_bundle_data(id: ObjectId) -> character::Bundle {
         id,
         name: Name::new("TestCharacter".to_string()),
         title: NameTitle::new("TestTitle".to_string()),
-        session_id: ConnectionId::next().into(),
+        session_id: Some(ConnectionId::next().into()),
         movable: Movable::from(base_class_stats),
         collider: base_class_stats.collider(appearance.gender),
         collision_layers: GameLayer::player(),
@@ -305,5 +305,7 @@ pub fn test_character_bundle_data(id: ObjectId) -> character::Bundle {
         abnormal_effects: AbnormalEffects::default(),
         last_known_pos: LastKnownPosition::default(),
         targetable: Targetable,
+        noblesse: false,
+        hero: false,
     }
 }
@@ -1,7 +1,10 @@
 pub mod model;
 
 use bevy::prelude::*;
-use l2r_core::db::RepositoryManager;
+use l2r_core::{
+    db::RepositoryManager,
+    model::account::{ACCOUNT_ACCESS_REPOSITORY, AccountAccessRepository},
+};
 
 pub struct AccountsPlugin;
 
@@ -11,6 +14,7 @@ impl Plugin for AccountsPlugin {
 
         app.world_mut()
             .resource_mut::<RepositoryManager>()
-            .register(repo);
+            .register(repo)
+            .register(AccountAccessRepository::new(ACCOUNT_ACCESS_REPOSITORY));
     }
 }
@@ -0,0 +1,102 @@
+use crate::plugins::{accounts::model, config::Config, network::LoginServerSession};
+use axum::{Json, extract::Path, http::HeaderMap};
+use bevy::prelude::*;
+use bevy_defer::AsyncWorld;
+use l2r_core::{
+    admin::{
+        AdminApiAppExt, AdminApiError, AdminApiResult, AdminApiToken, authorize,
+        set_account_access_level,
+    },
+    model::{access_level::AccessLevel, session::L2rSession},
+};
+use serde::Serialize;
+
+/// Login sessions and account bans over the admin API.
+pub struct LoginAdminApiPlugin;
+impl Plugin for LoginAdminApiPlugin {
+    fn build(&self, app: &mut App) {
+        app.admin_route("/sessions", axum::routing::get(sessions))
+            .admin_route("/accounts/{name}/kick", axum::routing::post(kick))
+            .admin_route("/accounts/{name}/ban", axum::routing::post(ban))
+            .admin_route("/accounts/{name}/unban", axum::routing::post(unban));
+
+        app.add_systems(Update, sync_token.run_if(resource_changed::<Config>));
+    }
+}
+
+fn sync_token(mut commands: Commands, config: Res<Config>) {
+    commands.insert_resource(AdminApiToken::new(config.admin_api().token.clone()));
+}
+
+#[derive(Serialize)]
+struct SessionInfo {
+    id: usize,
+    address: String,
+    account: Option<String>,
+}
+
+#[derive(Serialize)]
+struct AccountAction {
+    account: String,
+    kicked: usize,
+}
+
+async fn sessions(headers: HeaderMap) -> AdminApiResult<Vec<SessionInfo>> {
+    authorize(&headers, "list sessions")?;
+    let sessions = AsyncWorld.run(|world| {
+        world
+            .query::<(&LoginServerSession, Option<&model::Model>)>()
+            .iter(world)
+            .map(|(session, account)| SessionInfo {
+                id: *session.id(),
+                address: session.peer_addr().to_string(),
+                account: account.map(|account| account.name().to_string()),
+            })
+            .collect()
+    });
+    Ok(Json(sessions))
+}
+
+async fn kick(headers: HeaderMap, Path(name): Path<String>) -> AdminApiResult<AccountAction> {
+    authorize(&headers, format!("kick account {name}"))?;
+    let kicked = kick_account(&name);
+    if kicked == 0 {
+        return Err(AdminApiError::NotFound(format!(
+            "Account {name} has no session"
+        )));
+    }
+    Ok(Json(AccountAction {
+        account: name,
+        kicked,
+    }))
+}
+
+async fn ban(headers: HeaderMap, Path(name): Path<String>) -> AdminApiResult<AccountAction> {
+    authorize(&headers, format!("ban account {name}"))?;
+    set_account_access_level(&name, AccessLevel::Banned).await?;
+    let kicked = kick_account(&name);
+    Ok(Json(AccountAction {
+        account: name,
+        kicked,
+    }))
+}
+
+async fn unban(headers: HeaderMap, Path(name): Path<String>) -> AdminApiResult<AccountAction> {
+    authorize(&headers, format!("unban account {name}"))?;
+    set_account_access_level(&name, AccessLevel::Player).await?;
+    Ok(Json(AccountAction {
+        account: name,
+        kicked: 0,
+    }))
+}
+
+fn kick_account(name: &str) -> usize {
+    AsyncWorld.run(|world| {
+        world
+            .query::<(&LoginServerSession, &model::Model)>()
+            .iter(world)
+            .filter(|(_, account)| account.name() == name)
+            .map(|(session, _)| session.disconnect())
+            .count()
+    })
+}
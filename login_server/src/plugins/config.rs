@@ -45,11 +45,19 @@ pub struct GeneralConfig {
     pub redis_url: RedisUrl,
 }
 
+/// Bearer token of the admin API, the API refuses every request while it's empty.
+#[derive(Clone, Debug, Default, Deserialize, Reflect, Serialize)]
+#[serde(default)]
+pub struct AdminApiConfig {
+    pub token: String,
+}
+
 #[derive(Asset, Clone, Debug, Default, Deserialize, Reflect, Resource, Serialize)]
 #[reflect(Resource)]
 #[serde(default)]
 pub struct Config {
     general: GeneralConfig,
+    admin_api: AdminApiConfig,
     #[serde(skip)]
     #[reflect(ignore)]
     handle: Handle<Config>,
@@ -85,11 +93,17 @@ impl Config {
         &self.general
     }
 
+    pub fn admin_api(&self) -> &AdminApiConfig {
+        &self.admin_api
+    }
+
     /// Merge another config into self, overriding fields if present in other.
     fn merge(&mut self, other: &Config) {
         // General
         self.general.database_url = other.general.database_url.clone();
         self.general.redis_url = other.general.redis_url.clone();
+        // Admin API
+        self.admin_api.token = other.admin_api.token.clone();
     }
 
     /// Override fields present in env vars.
@@ -98,6 +112,7 @@ impl Config {
             match key.trim_start_matches(ENV_PREFIX).to_uppercase().as_str() {
                 "DATABASE_URL" => self.general.database_url = value.into(),
                 "REDIS_URL" => self.general.redis_url = value.into(),
+                "ADMIN_API_TOKEN" => self.admin_api.token = value,
                 _ => {}
             }
         }
@@ -3,10 +3,11 @@ use bevy::{
     app::{PluginGroupBuilder, ScheduleRunnerPlugin},
     prelude::*,
 };
-use l2r_core::{assets::CustomAssetPlugin, metrics::MetricsPlugin};
+use l2r_core::{admin::AdminApiPlugin, assets::CustomAssetPlugin, metrics::MetricsPlugin};
 use std::time::Duration;
 
 pub mod accounts;
+pub mod admin_api;
 pub mod config;
 pub mod db;
 pub mod network;
@@ -41,10 +42,12 @@ impl PluginGroup for Core {
             // MetricsPlugin must come before plugins that register metrics
             // It also adds bevy_webgate which internally adds AsyncPlugin
             .add(MetricsPlugin::new("loginserver_".to_string(), 8032))
+            .add(AdminApiPlugin::new(8032))
             .add(db::LoginDbPlugin)
             .add(AccountsPlugin)
             .add(network::NetworkPlugin)
             .add(server_manager::ServerManagerPlugin)
+            .add(admin_api::LoginAdminApiPlugin)
     }
 
     fn name() -> String {
@@ -225,6 +225,16 @@ async fn handle_task(
         }
     }
 
+    if account_model.access_level().banned() {
+        warn!("Banned account tried to log in: {}", account_model.name());
+        send_login_fail_and_disconnect(
+            entity,
+            LoginFailReason::IncorrectAccountInfoContactCustomerSupport,
+        )
+        .await?;
+        return Ok(());
+    }
+
     let session_key = SessionKey::new();
 
     let account_session = SessionAccount {
@@ -9,12 +9,13 @@ use crate::plugins::{
             server_list::ServerListResponse,
         },
     },
-    server_manager::GameServerTable,
+    server_manager::{GameServerTable, Status},
 };
 use bevy::{log, prelude::*};
 use bevy_slinet::server::PacketReceiveEvent;
 use l2r_core::{
     crypt::session_keys::SessionKey,
+    db::{RedisClient, logins_closed_key},
     model::session::{L2rSession, ServerSessions},
     packets::{ClientPacketBuffer, L2rSerializeError},
 };
@@ -58,6 +59,7 @@ fn handle(
     mut commands: Commands,
     query: Query<(&LoginServerSession, &Model, &SessionKey)>,
     server_table: Res<GameServerTable>,
+    mut redis_client: ResMut<RedisClient>,
 ) -> Result<()> {
     let event = receive.event();
     let LoginClientPacket::ServerList(ref packet) = event.packet else {
@@ -84,10 +86,18 @@ fn handle(
         session.disconnect();
         commands.entity(session_entity).despawn();
     } else {
+        // Servers about to shut down refuse new logins, the others are listed as they are
+        let server_table = server_table.with_status(Status::Down, |server| {
+            redis::Commands::exists(&mut redis_client.connection, logins_closed_key(server.id()))
+                .unwrap_or_else(|err| {
+                    log::error!("Redis error: {:?}", err);
+                    false
+                })
+        });
         commands.trigger_targets(
             LoginServerPacket::from(
                 // TODO: Calculate real last server and char values here
-                ServerListResponse::new(server_table.clone(), 9, 0, 0),
+                ServerListResponse::new(server_table, 9, 0, 0),
             ),
             session_entity,
         );
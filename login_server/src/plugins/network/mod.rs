@@ -7,7 +7,7 @@ use l2r_core::{
     model::session::{L2rSession, ServerSessions, SessionId},
 };
 use server::LoginServerPacketPlugin;
-use std::time::SystemTime;
+use std::{net::SocketAddr, time::SystemTime};
 use strum::Display;
 
 pub mod client;
@@ -105,6 +105,10 @@ impl LoginServerSession {
     pub fn _protocol_version(&self) -> LoginServerProtocol {
         self.protocol_version
     }
+
+    pub fn peer_addr(&self) -> SocketAddr {
+        self.connection.peer_addr()
+    }
 }
 
 fn new_connection(
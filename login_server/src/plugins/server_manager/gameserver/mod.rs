@@ -103,8 +103,10 @@ impl GameServer {
         self.age_limit
     }
     pub fn status(&self) -> Status {
-        // TODO: implement getting proper status
-        Status::Good
+        self.status.unwrap_or(Status::Good)
+    }
+    pub fn set_status(&mut self, status: Status) {
+        self.status = Some(status);
     }
     // TODO: return server address based on client's ip
     pub fn server_address(&self) -> Ipv4Addr {
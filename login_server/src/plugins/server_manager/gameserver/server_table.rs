@@ -1,4 +1,4 @@
-use super::GameServer;
+use super::{GameServer, Status};
 use bevy::{log, platform::collections::HashSet, prelude::Resource};
 
 #[derive(Clone, Default, Resource)]
@@ -14,4 +14,24 @@ impl GameServerTable {
     pub fn load_registered_game_servers(&self) -> Vec<GameServer> {
         self.0.iter().cloned().collect()
     }
+
+    /// Copy of the table with the matching servers shown in the given status.
+    pub fn with_status(
+        &self,
+        status: Status,
+        mut matches: impl FnMut(&GameServer) -> bool,
+    ) -> Self {
+        Self(
+            self.0
+                .iter()
+                .cloned()
+                .map(|mut server| {
+                    if matches(&server) {
+                        server.set_status(status);
+                    }
+                    server
+                })
+                .collect(),
+        )
+    }
 }
@@ -54,9 +54,16 @@ fn handle_server_names_loaded(
 pub fn register_default_game_servers(mut server_table: ResMut<GameServerTable>) {
     use std::net::ToSocketAddrs;
 
+    const DEFAULT_ID: i32 = 9;
     const DEFAULT_IP: Ipv4Addr = Ipv4Addr::new(127, 0, 0, 1);
     const DEFAULT_PORT: u32 = 7777;
 
+    // Must match the `server_id` of the game server
+    let server_id = std::env::var("LS_GAME_SERVER_ID")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_ID);
+
     let server_addr =
         std::env::var("LS_GAME_SERVER_ADDR").unwrap_or_else(|_| DEFAULT_IP.to_string());
     let port_env = std::env::var("LS_GAME_SERVER_PORT").ok();
@@ -102,7 +109,7 @@ pub fn register_default_game_servers(mut server_table: ResMut<GameServerTable>)
     };
 
     server_table.register_game_server(GameServer::new(
-        9,
+        server_id,
         true,
         ip_addrs,
         port,